@@ -0,0 +1,39 @@
+use std::env;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+use php_parser_rs::parse;
+
+const ROUNDS: usize = 5;
+const ITERATIONS: usize = 200;
+
+// Parses a single large file, the `standard` stubs, which are what an IDE
+// parses first when it starts. The results are kept in `benches/README.md`.
+fn main() {
+    let manifest = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let source = std::fs::read(manifest.join("tests/stubs/standard.php")).unwrap();
+
+    black_box(parse(&source).unwrap());
+
+    // The fastest round is the one least disturbed by the rest of the machine.
+    let mut fastest = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            black_box(parse(&source).unwrap());
+        }
+
+        fastest = fastest.min(start.elapsed());
+    }
+
+    let megabytes = (source.len() * ITERATIONS) as f64 / 1024.0 / 1024.0;
+    println!(
+        "standard.php: parsed {} bytes {} times in {:.2?}, {:.2} MiB/s",
+        source.len(),
+        ITERATIONS,
+        fastest,
+        megabytes / fastest.as_secs_f64()
+    );
+}
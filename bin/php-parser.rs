@@ -0,0 +1,106 @@
+use std::io::Read;
+
+use clap::Parser;
+use clap::Subcommand;
+use clap::ValueEnum;
+
+use php_parser_rs::features;
+use php_parser_rs::parser::ast::debug_tree;
+use php_parser_rs::source::SourceFile;
+
+#[derive(Parser, Debug)]
+#[clap(version, about = "A PHP Parser")]
+struct Arguments {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Print the AST of a file
+    Ast {
+        /// The file to parse, or `-` to read from stdin
+        file: String,
+        #[clap(long, value_enum, default_value_t = Format::Debug)]
+        format: Format,
+    },
+    /// Print the PHP features that the parser supports
+    Features {
+        #[clap(long, value_enum, default_value_t = FeaturesFormat::Markdown)]
+        format: FeaturesFormat,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Format {
+    /// The AST serialized as JSON
+    Json,
+    /// An indented tree of nodes, with their scalar fields and position
+    Debug,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum FeaturesFormat {
+    /// The table of features serialized as JSON
+    Json,
+    /// The table of features as a Markdown document, as in `FEATURES.md`
+    Markdown,
+}
+
+fn main() {
+    match Arguments::parse().command {
+        Command::Ast { file, format } => ast(&file, format),
+        Command::Features { format } => match format {
+            FeaturesFormat::Json => json(&features::FEATURES),
+            FeaturesFormat::Markdown => print!("{}", features::markdown()),
+        },
+    }
+}
+
+fn ast(file: &str, format: Format) {
+    let source = match read(file) {
+        Ok(source) => source,
+        Err(error) => {
+            eprintln!("Failed to read `{}`: {}", file, error);
+
+            std::process::exit(1);
+        }
+    };
+
+    if !source.diagnostics().is_empty() {
+        for error in source.diagnostics() {
+            eprintln!("{}", error);
+        }
+
+        std::process::exit(1);
+    }
+
+    let ast = source.program();
+
+    match format {
+        Format::Json => json(ast),
+        Format::Debug => print!("{}", debug_tree(ast)),
+    }
+}
+
+fn json<T: serde::Serialize + ?Sized>(value: &T) {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => println!("{}", json),
+        Err(error) => {
+            eprintln!("Failed to convert to json: {}", error);
+
+            std::process::exit(1);
+        }
+    }
+}
+
+fn read(file: &str) -> std::io::Result<SourceFile> {
+    if file == "-" {
+        let mut contents = vec![];
+        std::io::stdin().read_to_end(&mut contents)?;
+
+        Ok(SourceFile::from_bytes("stdin", contents))
+    } else {
+        SourceFile::from_path(file)
+    }
+}
@@ -4,6 +4,7 @@ use std::io;
 use std::path::PathBuf;
 
 use php_parser_rs::parse;
+use php_parser_rs::parser::ast::debug_tree;
 
 fn main() -> io::Result<()> {
     let manifest = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
@@ -19,6 +20,8 @@ fn main() -> io::Result<()> {
         let code_filename = entry.join("code.php");
         let ast_filename = entry.join("ast.txt");
         let error_filename = entry.join("error.txt");
+        let tree_filename = entry.join("tree.txt");
+        let json_filename = entry.join("ast.json");
 
         if !code_filename.exists() {
             continue;
@@ -38,6 +41,21 @@ fn main() -> io::Result<()> {
             Ok(ast) => {
                 std::fs::write(ast_filename, format!("{:#?}\n", ast))?;
                 println!("✅ generated `ast.txt` for `{}`", entry.to_string_lossy());
+
+                // Only fixtures that opt in by having a `tree.txt` snapshot it.
+                if tree_filename.exists() {
+                    std::fs::write(tree_filename, debug_tree(&ast))?;
+                    println!("✅ generated `tree.txt` for `{}`", entry.to_string_lossy());
+                }
+
+                // Likewise for `ast.json`, the AST as serialized with serde.
+                if json_filename.exists() {
+                    std::fs::write(
+                        json_filename,
+                        format!("{}\n", serde_json::to_string_pretty(&ast)?),
+                    )?;
+                    println!("✅ generated `ast.json` for `{}`", entry.to_string_lossy());
+                }
             }
             Err(error) => {
                 std::fs::write(
@@ -0,0 +1,117 @@
+use std::convert::Infallible;
+
+use php_parser_rs::downcast::downcast;
+use php_parser_rs::node::Node;
+use php_parser_rs::parser::ast::attributes::Attribute;
+use php_parser_rs::parser::ast::classes::ClassStatement;
+use php_parser_rs::parser::ast::constant::ClassishConstant;
+use php_parser_rs::parser::ast::enums::{BackedEnumCase, UnitEnumCase};
+use php_parser_rs::parser::ast::functions::{
+    AbstractMethod, ConcreteConstructor, ConcreteMethod, FunctionStatement,
+};
+use php_parser_rs::parser::ast::identifiers::SimpleIdentifier;
+use php_parser_rs::source::SourceFile;
+use php_parser_rs::traverser::{Flow, Visitor};
+
+const CODE: &str = r#"<?php
+
+#[\Deprecated(message: "use bar() instead", since: "2.0")]
+function foo() {}
+
+function bar() {}
+
+class Http
+{
+    #[Deprecated]
+    public const CONTINUE = 100;
+
+    #[\deprecated("use send()")]
+    public function post() {}
+
+    public function send() {}
+}
+
+enum Status
+{
+    #[\Deprecated]
+    case Pending;
+
+    case Done;
+}
+"#;
+
+// Collects the functions, methods and constants marked with PHP's native
+// `#[\Deprecated]` attribute.
+#[derive(Default)]
+struct Deprecations {
+    symbols: Vec<(&'static str, String, u32)>,
+}
+
+impl Deprecations {
+    fn check(
+        &mut self,
+        kind: &'static str,
+        name: &SimpleIdentifier,
+        attribute: Option<&Attribute>,
+    ) {
+        if attribute.is_some() {
+            self.symbols
+                .push((kind, name.value.to_string(), name.span.line));
+        }
+    }
+}
+
+impl Visitor<Infallible> for Deprecations {
+    fn visit(&mut self, node: &mut dyn Node) -> Result<Flow, Infallible> {
+        const DEPRECATED: &str = "Deprecated";
+
+        if let Some(function) = downcast::<FunctionStatement>(node) {
+            self.check(
+                "function",
+                &function.name,
+                function.find_attribute(DEPRECATED),
+            );
+        } else if let Some(method) = downcast::<ConcreteMethod>(node) {
+            self.check("method", &method.name, method.find_attribute(DEPRECATED));
+        } else if let Some(method) = downcast::<AbstractMethod>(node) {
+            self.check("method", &method.name, method.find_attribute(DEPRECATED));
+        } else if let Some(constructor) = downcast::<ConcreteConstructor>(node) {
+            self.check(
+                "method",
+                &constructor.name,
+                constructor.find_attribute(DEPRECATED),
+            );
+        } else if let Some(constant) = downcast::<ClassishConstant>(node) {
+            let attribute = constant.find_attribute(DEPRECATED);
+            for entry in &constant.entries {
+                self.check("constant", &entry.name, attribute);
+            }
+        } else if let Some(case) = downcast::<UnitEnumCase>(node) {
+            self.check("case", &case.name, case.find_attribute(DEPRECATED));
+        } else if let Some(case) = downcast::<BackedEnumCase>(node) {
+            self.check("case", &case.name, case.find_attribute(DEPRECATED));
+        } else if let Some(class) = downcast::<ClassStatement>(node) {
+            self.check("class", &class.name, class.find_attribute(DEPRECATED));
+        }
+
+        Ok(Flow::Continue)
+    }
+}
+
+fn main() {
+    let file = SourceFile::from_bytes("deprecated.php", CODE);
+
+    let mut program = file.program().clone();
+    let mut deprecations = Deprecations::default();
+    let _ = program.edit(|statements| deprecations.visit_node(statements));
+
+    for (kind, name, line) in deprecations.symbols {
+        println!(
+            "{}:{}: {} `{}` is deprecated",
+            file.name(),
+            line,
+            kind,
+            name
+        );
+    }
+}
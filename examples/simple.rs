@@ -1,6 +1,6 @@
 use std::io::Result;
 
-use php_parser_rs::parser;
+use php_parser_rs::source::SourceFile;
 
 const CODE: &str = r#"<?php
 
@@ -15,15 +15,14 @@ final class User {
 "#;
 
 fn main() -> Result<()> {
-    match parser::parse(CODE) {
-        Ok(ast) => {
-            println!("{:#?}", ast);
-        }
-        Err(err) => {
-            println!("{}", err.report(CODE, None, true, false)?);
+    let file = SourceFile::from_bytes("simple.php", CODE);
 
-            println!("parsed so far: {:#?}", err.partial);
-        }
+    if file.diagnostics().is_empty() {
+        println!("{:#?}", file.program());
+    } else {
+        println!("{}", file.report(true, false)?);
+
+        println!("parsed so far: {:#?}", file.program());
     }
 
     Ok(())
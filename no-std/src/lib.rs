@@ -0,0 +1,77 @@
+//! A `no_std` crate depending on the parser with its default features turned
+//! off, so that building it proves the parser only needs `core` and `alloc`.
+//!
+//! Features are unified across a workspace build, so run it on its own, with
+//! `cargo test -p php-parser-rs-no-std`, for the parser to be built without
+//! `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use php_parser_rs::lexer::token::TokenKind;
+use php_parser_rs::lexer::Lexer;
+use php_parser_rs::parser::ast::Program;
+use php_parser_rs::Error;
+
+const SOURCE: &str = r#"<?php
+
+function add(int $a, int $b): int {
+    return $a + $b;
+}
+
+echo add(1, 2), "\n";
+"#;
+
+pub fn tokenize() -> Vec<TokenKind> {
+    Lexer::new()
+        .tokenize(SOURCE)
+        .map(|tokens| tokens.iter().map(|token| token.kind).collect())
+        .unwrap_or_default()
+}
+
+pub fn parse() -> Result<Program, Error> {
+    php_parser_rs::parse(SOURCE)
+}
+
+#[cfg(test)]
+mod tests {
+    use php_parser_rs::lexer::token::OpenTagKind;
+    use php_parser_rs::lexer::token::TokenKind;
+    use php_parser_rs::parser::ast::Statement;
+    use php_parser_rs::Error;
+
+    #[test]
+    fn test_tokenize() {
+        let tokens = super::tokenize();
+
+        assert_eq!(tokens.first(), Some(&TokenKind::OpenTag(OpenTagKind::Full)));
+        assert_eq!(tokens.get(1), Some(&TokenKind::Function));
+        assert_eq!(tokens.last(), Some(&TokenKind::Eof));
+    }
+
+    #[test]
+    fn test_parse() {
+        let program = super::parse().unwrap();
+
+        assert!(matches!(
+            program.as_slice(),
+            [
+                Statement::FullOpeningTag(_),
+                Statement::Function(_),
+                Statement::Echo(_)
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let Err(Error::Parse(stack)) = php_parser_rs::parse("<?php echo 1") else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(stack.errors.len(), 1);
+    }
+}
@@ -0,0 +1,57 @@
+use alloc::sync::Arc;
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::Ordering;
+
+/// A flag, shared between threads, that tells the lexer and the parser to
+/// give up on the source they are working on.
+///
+/// Clones share the same flag, so a language server can keep one while an
+/// in-flight parse gets the other, and cancel the parse when a newer edit
+/// comes in.
+///
+/// # Example
+///
+/// ```
+/// use php_parser_rs::parser::{parse_with_options, ParserOptions};
+/// use php_parser_rs::{CancellationToken, Error};
+///
+/// let token = CancellationToken::new();
+/// let options = ParserOptions {
+///     cancellation: Some(token.clone()),
+///     ..ParserOptions::default()
+/// };
+///
+/// token.cancel();
+///
+/// assert!(matches!(
+///     parse_with_options("<?php echo 1;", &options),
+///     Err(Error::Cancelled)
+/// ));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+// Tokens are the same when they share their flag.
+impl PartialEq for CancellationToken {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.cancelled, &other.cancelled)
+    }
+}
+
+impl Eq for CancellationToken {}
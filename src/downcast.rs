@@ -1,5 +1,5 @@
 use crate::node::Node;
-use std::any::TypeId;
+use core::any::TypeId;
 
 pub fn downcast<T: Node + 'static>(node: &dyn Node) -> Option<&T> {
     // Get `TypeId` of the type this function is instantiated with.
@@ -0,0 +1,143 @@
+use alloc::string::String;
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use crate::lexer::error::SyntaxError;
+#[cfg(feature = "std")]
+use crate::parser::error::ParseError;
+use crate::parser::error::ParseErrorStack;
+
+/// The error returned by every function that parses PHP, such as
+/// [`parse`](crate::parse), [`construct`](crate::construct) and
+/// [`parse_file`](crate::parse_file), which tells whether the file could not
+/// be read, the source could not be split into tokens, the tokens could not be
+/// parsed, or the parse was cancelled.
+///
+/// ```
+/// use php_parser_rs::Error;
+///
+/// assert!(matches!(php_parser_rs::parse("<?php 'foo"), Err(Error::Lex(_))));
+/// assert!(matches!(php_parser_rs::parse("<?php foo("), Err(Error::Parse(_))));
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    /// The file given to [`parse_file`](crate::parse_file) could not be read.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+    Lex(SyntaxError),
+    Parse(ParseErrorStack),
+    /// The [`CancellationToken`](crate::CancellationToken) of the parse was
+    /// cancelled before it was done.
+    Cancelled,
+    /// The parser found one of its own invariants broken, which is a bug in
+    /// the parser rather than in the source.
+    Internal {
+        detail: String,
+    },
+}
+
+impl Error {
+    /// Renders the error like [`ParseErrorStack::report`], with a lexer error
+    /// rendered as the parse error it converts into.
+    #[cfg(feature = "std")]
+    pub fn report<'a>(
+        &self,
+        source: &'a str,
+        origin: Option<&'a str>,
+        colored: bool,
+        ascii: bool,
+    ) -> std::io::Result<String> {
+        match self {
+            Self::Lex(error) => {
+                ParseError::from(error.clone()).report(source, origin, colored, ascii)
+            }
+            Self::Parse(stack) => stack.report(source, origin, colored, ascii),
+            Self::Io(_) | Self::Cancelled | Self::Internal { .. } => Ok(self.to_string()),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            Self::Io(error) => write!(f, "{}", error),
+            Self::Lex(error) => write!(f, "{}", error),
+            Self::Parse(stack) => write!(f, "{}", stack),
+            Self::Cancelled => write!(f, "the parse was cancelled"),
+            Self::Internal { detail } => write!(f, "internal error: {}", detail),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Lex(error) => Some(error),
+            Self::Parse(stack) => Some(stack),
+            Self::Cancelled | Self::Internal { .. } => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<SyntaxError> for Error {
+    fn from(error: SyntaxError) -> Self {
+        match error {
+            SyntaxError::Cancelled(_) => Self::Cancelled,
+            error => Self::Lex(error),
+        }
+    }
+}
+
+impl From<ParseErrorStack> for Error {
+    fn from(stack: ParseErrorStack) -> Self {
+        Self::Parse(stack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_str;
+
+    #[test]
+    fn test_bad_token_is_a_lex_error() {
+        let error = parse_str("<?php $a = 'foo").unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::Lex(SyntaxError::UnexpectedEndOfFile(_))
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Syntax Error: unexpected end of file on line 1 column 16"
+        );
+    }
+
+    #[test]
+    fn test_bad_syntax_is_a_parse_error() {
+        let Err(Error::Parse(stack)) = parse_str("<?php $a = ;") else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(stack.errors[0].id, "E003");
+    }
+
+    #[test]
+    fn test_question_mark() -> Result<(), Box<dyn std::error::Error>> {
+        let program = parse_str("<?php echo 1;")?;
+
+        assert_eq!(program.len(), 2);
+
+        Ok(())
+    }
+}
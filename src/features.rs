@@ -0,0 +1,341 @@
+//! What the parser supports of the syntax of PHP, as a table that tools can
+//! show to their users.
+//!
+//! The table is checked against the parser by parsing a snippet of every
+//! feature, so that it stays true as features land.
+//!
+//! # Example
+//!
+//! ```
+//! use php_parser_rs::features::{support, FeatureId, SupportStatus};
+//!
+//! assert_eq!(support(FeatureId::Enums), SupportStatus::Supported);
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+
+use serde::Serialize;
+
+use crate::parser::PhpVersion;
+
+// Declares `FeatureId` along with the table of every feature, in the same
+// order, so that no feature can be left out of the table.
+macro_rules! features {
+    ($($(#[doc = $doc:literal])* $id:ident($name:literal, $since:ident, $status:expr $(,)?),)*) => {
+        /// A construct of the PHP language.
+        #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize)]
+        pub enum FeatureId {
+            $($(#[doc = $doc])* $id,)*
+        }
+
+        impl FeatureId {
+            /// The name of the feature, as documentation shows it.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$id => $name,)*
+                }
+            }
+        }
+
+        /// Every feature, oldest first.
+        pub static FEATURES: &[Feature] = &[
+            $(Feature {
+                id: FeatureId::$id,
+                since: PhpVersion::$since,
+                status: $status,
+            },)*
+        ];
+
+        /// How much of the feature the parser supports.
+        pub fn support(id: FeatureId) -> SupportStatus {
+            match id {
+                $(FeatureId::$id => $status,)*
+            }
+        }
+    };
+}
+
+/// How much of a feature the parser supports.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+#[serde(tag = "type")]
+pub enum SupportStatus {
+    Supported,
+    /// The feature parses, but with the shortcoming in `note`.
+    Partial {
+        note: &'static str,
+    },
+    /// Code using the feature fails to parse with the error `diagnostic`.
+    Unsupported {
+        note: &'static str,
+        diagnostic: &'static str,
+    },
+}
+
+/// A feature along with the first version of PHP that has it, or PHP 7.4, the
+/// earliest the parser targets, for older features.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+pub struct Feature {
+    pub id: FeatureId,
+    pub since: PhpVersion,
+    pub status: SupportStatus,
+}
+
+features! {
+    /// `fn ($a) => $a * 2`
+    ArrowFunctions("Arrow functions", Php74, SupportStatus::Supported),
+    /// `$a ??= 1;`
+    NullCoalescingAssignment("Null coalescing assignment", Php74, SupportStatus::Supported),
+    /// `1_000_000`
+    NumericLiteralSeparators("Numeric literal separators", Php74, SupportStatus::Supported),
+    /// Variables and expressions inside heredocs, whose closing marker may be
+    /// indented.
+    HeredocInterpolation(
+        "Heredoc interpolation",
+        Php74,
+        SupportStatus::Partial {
+            note: "the indentation of the lines of an interpolation that spans lines is not checked",
+        },
+    ),
+    /// `declare(ticks=1) echo 1;`
+    DeclareWithStatement(
+        "`declare` with a statement",
+        Php74,
+        SupportStatus::Unsupported {
+            note: "only an expression can follow `declare(...)`, besides a block",
+            diagnostic: "E003",
+        },
+    ),
+    /// `#[Attribute]`
+    Attributes("Attributes", Php80, SupportStatus::Supported),
+    /// `match ($a) { 1 => 'one' }`
+    MatchExpressions("Match expressions", Php80, SupportStatus::Supported),
+    /// `$a?->b`
+    NullsafeOperator("Nullsafe operator", Php80, SupportStatus::Supported),
+    /// `foo(bar: 1)`
+    NamedArguments("Named arguments", Php80, SupportStatus::Supported),
+    /// `public function __construct(private $a) {}`
+    PromotedProperties("Promoted properties", Php80, SupportStatus::Supported),
+    /// `int|string`
+    UnionTypes("Union types", Php80, SupportStatus::Supported),
+    /// `throw` as an expression, as in `$a ?? throw new Exception()`.
+    ThrowExpressions("Throw expressions", Php80, SupportStatus::Supported),
+    /// `enum Suit { case Hearts; }`
+    Enums("Enums", Php81, SupportStatus::Supported),
+    /// `public readonly int $a;`
+    ReadonlyProperties("Readonly properties", Php81, SupportStatus::Supported),
+    /// `strlen(...)`
+    FirstClassCallables("First-class callables", Php81, SupportStatus::Supported),
+    /// `Countable&Iterator`
+    IntersectionTypes("Intersection types", Php81, SupportStatus::Supported),
+    /// `function foo(): never {}`
+    NeverType("`never` type", Php81, SupportStatus::Supported),
+    /// `function foo($a = new Foo()) {}`
+    NewInInitializers("`new` in initializers", Php81, SupportStatus::Supported),
+    /// `readonly class Point {}`
+    ReadonlyClasses("Readonly classes", Php82, SupportStatus::Supported),
+    /// `(A&B)|null`
+    DnfTypes("Disjunctive normal form types", Php82, SupportStatus::Supported),
+    /// `null`, `false` and `true` as types of their own.
+    StandaloneNullFalseTrueTypes(
+        "Standalone `null`, `false` and `true` types",
+        Php82,
+        SupportStatus::Supported,
+    ),
+    /// `trait T { const A = 1; }`
+    ConstantsInTraits("Constants in traits", Php82, SupportStatus::Supported),
+    /// `const string A = 'a';`
+    TypedClassConstants("Typed class constants", Php83, SupportStatus::Supported),
+    /// `Foo::{$name}`
+    DynamicClassConstantFetch("Dynamic class constant fetches", Php83, SupportStatus::Supported),
+    /// `new readonly class {}`
+    ReadonlyAnonymousClasses("Readonly anonymous classes", Php83, SupportStatus::Supported),
+    /// `public int $a { get => 1; }`
+    PropertyHooks(
+        "Property hooks",
+        Php84,
+        SupportStatus::Partial {
+            note: "hooked properties cannot be `final` or `abstract`, and hooks in interfaces are not checked for bodies",
+        },
+    ),
+    /// `public private(set) int $a;`
+    AsymmetricVisibility("Asymmetric visibility", Php84, SupportStatus::Supported),
+    /// `final` and `abstract` properties.
+    FinalAndAbstractProperties(
+        "Final and abstract properties",
+        Php84,
+        SupportStatus::Unsupported {
+            note: "`final` and `abstract` are rejected as property modifiers",
+            diagnostic: "E023",
+        },
+    ),
+    /// `new Foo()->bar()`
+    NewWithoutParentheses("`new` without parentheses", Php84, SupportStatus::Supported),
+    /// `exit` and `die` as functions, as in `exit(...)` or `exit(status: 1)`.
+    ExitAsFunction("`exit` and `die` as functions", Php84, SupportStatus::Supported),
+}
+
+/// The table as a Markdown document, which is kept in `FEATURES.md`.
+pub fn markdown() -> String {
+    let mut document = String::from(
+        "# Supported PHP features\n\n\
+         <!-- Generated by `php-parser features`, do not edit. -->\n\n\
+         | Feature | Since | Support |\n\
+         | --- | --- | --- |\n",
+    );
+
+    for feature in FEATURES {
+        let support = match feature.status {
+            SupportStatus::Supported => String::from("Supported"),
+            SupportStatus::Partial { note } => format!("Partial: {}", note),
+            SupportStatus::Unsupported { note, diagnostic } => {
+                format!("Unsupported ({}): {}", diagnostic, note)
+            }
+        };
+
+        document.push_str(&format!(
+            "| {} | PHP {} | {} |\n",
+            feature.id.name(),
+            feature.since,
+            support
+        ));
+    }
+
+    document
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::parser::{parse_with_options, ParserOptions};
+    use crate::Error;
+
+    // A snippet of code using the feature.
+    fn snippet(id: FeatureId) -> &'static str {
+        match id {
+            FeatureId::ArrowFunctions => "<?php $double = fn ($a) => $a * 2;",
+            FeatureId::NullCoalescingAssignment => "<?php $a ??= 1;",
+            FeatureId::NumericLiteralSeparators => "<?php $a = 1_000_000 + 0x7F_FF + 1_0.5e1_0;",
+            FeatureId::HeredocInterpolation => {
+                "<?php\n$a = <<<EOT\n    $b {$c['d']} $e->f $g[0]\n    EOT;\n"
+            }
+            FeatureId::DeclareWithStatement => "<?php declare(ticks=1) echo 1;",
+            FeatureId::Attributes => "<?php #[Pure] function foo() {}",
+            FeatureId::MatchExpressions => "<?php match ($a) { 1, 2 => 'low', default => 'high' };",
+            FeatureId::NullsafeOperator => "<?php $a?->b?->c();",
+            FeatureId::NamedArguments => "<?php foo(bar: 1);",
+            FeatureId::PromotedProperties => {
+                "<?php class A { public function __construct(private int $a) {} }"
+            }
+            FeatureId::UnionTypes => "<?php function foo(int|string $a): int|false {}",
+            FeatureId::ThrowExpressions => "<?php $a = $b ?? throw new Exception();",
+            FeatureId::Enums => "<?php enum Suit: string { case Hearts = 'H'; }",
+            FeatureId::ReadonlyProperties => "<?php class A { public readonly int $a; }",
+            FeatureId::FirstClassCallables => "<?php $a = strlen(...);",
+            FeatureId::IntersectionTypes => "<?php function foo(Countable&Iterator $a) {}",
+            FeatureId::NeverType => "<?php function foo(): never { exit; }",
+            FeatureId::NewInInitializers => "<?php function foo($a = new Foo()) {}",
+            FeatureId::ReadonlyClasses => "<?php readonly class Point {}",
+            FeatureId::DnfTypes => "<?php function foo((A&B)|null $a) {}",
+            FeatureId::StandaloneNullFalseTrueTypes => {
+                "<?php function foo(): null {} function bar(): false {} function baz(): true {}"
+            }
+            FeatureId::ConstantsInTraits => "<?php trait T { const A = 1; }",
+            FeatureId::TypedClassConstants => "<?php class A { const string A = 'a'; }",
+            FeatureId::DynamicClassConstantFetch => "<?php Foo::{$name};",
+            FeatureId::ReadonlyAnonymousClasses => "<?php $a = new readonly class {};",
+            FeatureId::PropertyHooks => {
+                "<?php class A { public int $a { get => 1; set { $this->a = $value; } } }"
+            }
+            FeatureId::AsymmetricVisibility => "<?php class A { public private(set) int $a; }",
+            FeatureId::FinalAndAbstractProperties => "<?php class A { final public int $a = 1; }",
+            FeatureId::NewWithoutParentheses => "<?php new Foo()->bar();",
+            FeatureId::ExitAsFunction => "<?php $a = exit(...); die(status: 1);",
+        }
+    }
+
+    // The identifiers of the errors found while parsing the code.
+    fn errors(code: &str, version: PhpVersion) -> Vec<String> {
+        let options = ParserOptions {
+            version,
+            ..ParserOptions::default()
+        };
+
+        match parse_with_options(code, &options) {
+            Ok(_) => Vec::new(),
+            Err(Error::Parse(stack)) => stack.errors.into_iter().map(|error| error.id).collect(),
+            Err(error) => panic!("unexpected error: {}", error),
+        }
+    }
+
+    // The version of PHP before `version`, if the parser targets it.
+    fn previous(version: PhpVersion) -> Option<PhpVersion> {
+        match version {
+            PhpVersion::Php74 => None,
+            PhpVersion::Php80 => Some(PhpVersion::Php74),
+            PhpVersion::Php81 => Some(PhpVersion::Php80),
+            PhpVersion::Php82 => Some(PhpVersion::Php81),
+            PhpVersion::Php83 => Some(PhpVersion::Php82),
+            PhpVersion::Php84 => Some(PhpVersion::Php83),
+        }
+    }
+
+    #[test]
+    fn test_features_match_the_parser() {
+        for feature in FEATURES {
+            let code = snippet(feature.id);
+            let found = errors(code, feature.since);
+
+            match feature.status {
+                SupportStatus::Supported | SupportStatus::Partial { .. } => {
+                    assert_eq!(found, Vec::<String>::new(), "{:?}: {}", feature.id, code);
+
+                    // The version before is told that it lacks the feature.
+                    if let Some(previous) = previous(feature.since) {
+                        let found = errors(code, previous);
+
+                        assert!(
+                            !found.is_empty(),
+                            "{:?} parses in PHP {}",
+                            feature.id,
+                            previous
+                        );
+                        assert!(
+                            found.iter().all(|id| id == "E070"),
+                            "{:?}: {:?}",
+                            feature.id,
+                            found
+                        );
+                    }
+                }
+                SupportStatus::Unsupported { diagnostic, .. } => {
+                    assert!(!found.is_empty(), "{:?} parses: {}", feature.id, code);
+                    assert!(
+                        found.iter().all(|id| id == diagnostic),
+                        "{:?}: {:?}",
+                        feature.id,
+                        found
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_table() {
+        assert!(FEATURES
+            .windows(2)
+            .all(|pair| pair[0].since <= pair[1].since));
+
+        for feature in FEATURES {
+            assert_eq!(support(feature.id), feature.status);
+        }
+    }
+
+    #[test]
+    fn test_markdown_is_up_to_date() {
+        assert_eq!(include_str!("../FEATURES.md"), markdown());
+    }
+}
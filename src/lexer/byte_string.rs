@@ -1,10 +1,16 @@
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
+use serde::de::MapAccess;
+use serde::de::Unexpected;
+use serde::de::Visitor;
+use serde::ser::SerializeMap;
 use serde::Deserialize;
 use serde::Serialize;
 
-use std::ops::Deref;
-use std::ops::DerefMut;
-use std::str::from_utf8;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Deref;
+use core::ops::DerefMut;
 
 /// A wrapper for Vec<u8> that provides a human-readable Debug impl and
 /// a few other conveniences.
@@ -28,8 +34,8 @@ impl Default for ByteString {
     }
 }
 
-impl std::fmt::Display for ByteString {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for ByteString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         for &b in &self.bytes {
             match b {
                 0 => write!(f, "\\0")?,
@@ -43,7 +49,7 @@ impl std::fmt::Display for ByteString {
     }
 }
 
-impl std::str::FromStr for ByteString {
+impl core::str::FromStr for ByteString {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -51,8 +57,8 @@ impl std::str::FromStr for ByteString {
     }
 }
 
-impl std::fmt::Debug for ByteString {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for ByteString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "\"")?;
         for &b in &self.bytes {
             match b {
@@ -67,12 +73,21 @@ impl std::fmt::Debug for ByteString {
     }
 }
 
+/// Byte strings serialize as a plain string when they are valid UTF-8, and as
+/// `{"base64": "..."}` otherwise, so that no byte is lost in a round trip.
 impl Serialize for ByteString {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        match core::str::from_utf8(&self.bytes) {
+            Ok(string) => serializer.serialize_str(string),
+            Err(_) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("base64", &base64::encode(&self.bytes))?;
+                map.end()
+            }
+        }
     }
 }
 
@@ -81,20 +96,66 @@ impl<'de> Deserialize<'de> for ByteString {
     where
         D: serde::Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        Ok(ByteString::new(s.into_bytes()))
+        struct ByteStringVisitor;
+
+        impl<'de> Visitor<'de> for ByteStringVisitor {
+            type Value = ByteString;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "a string or a `base64` encoded byte string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<ByteString, E> {
+                Ok(ByteString::from(value))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<ByteString, A::Error> {
+                let mut bytes = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    if key != "base64" {
+                        return Err(serde::de::Error::unknown_field(&key, &["base64"]));
+                    }
+
+                    let encoded = map.next_value::<String>()?;
+                    bytes = Some(base64::decode(&encoded).ok_or_else(|| {
+                        serde::de::Error::invalid_value(Unexpected::Str(&encoded), &self)
+                    })?);
+                }
+
+                bytes
+                    .map(ByteString::new)
+                    .ok_or_else(|| serde::de::Error::missing_field("base64"))
+            }
+        }
+
+        deserializer.deserialize_any(ByteStringVisitor)
     }
 }
 
+#[cfg(feature = "std")]
 impl JsonSchema for ByteString {
     fn schema_name() -> String {
         "ByteString".to_string()
     }
 
-    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let encoded = schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties: [("base64".to_string(), gen.subschema_for::<String>())]
+                    .into_iter()
+                    .collect(),
+                required: ["base64".to_string()].into_iter().collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
         schemars::schema::SchemaObject {
-            instance_type: Some(schemars::schema::InstanceType::String.into()),
-            format: Some("byte-string".to_string()),
+            subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+                any_of: Some(vec![gen.subschema_for::<String>(), encoded.into()]),
+                ..Default::default()
+            })),
             ..Default::default()
         }
         .into()
@@ -151,7 +212,7 @@ impl From<String> for ByteString {
 
 impl From<ByteString> for String {
     fn from(bytes: ByteString) -> Self {
-        String::from(from_utf8(&bytes.bytes).unwrap())
+        String::from_utf8_lossy(&bytes.bytes).into_owned()
     }
 }
 
@@ -169,6 +230,81 @@ impl DerefMut for ByteString {
     }
 }
 
+// Standard base64 with padding, which is all that byte strings need.
+mod base64 {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    pub fn encode(bytes: &[u8]) -> String {
+        let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let group = chunk
+                .iter()
+                .chain(core::iter::repeat(&0))
+                .take(3)
+                .fold(0u32, |group, &byte| group << 8 | u32::from(byte));
+
+            for index in 0..4 {
+                if index <= chunk.len() {
+                    encoded.push(digit_to_char((group >> (18 - index * 6)) as u8 & 0x3f));
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+
+        encoded
+    }
+
+    pub fn decode(encoded: &str) -> Option<Vec<u8>> {
+        let digits = encoded
+            .trim_end_matches('=')
+            .bytes()
+            .map(char_to_digit)
+            .collect::<Option<Vec<u8>>>()?;
+
+        let mut bytes = Vec::with_capacity(digits.len() * 3 / 4);
+        for chunk in digits.chunks(4) {
+            if chunk.len() == 1 {
+                return None;
+            }
+
+            let group = chunk
+                .iter()
+                .chain(core::iter::repeat(&0))
+                .take(4)
+                .fold(0u32, |group, &digit| group << 6 | u32::from(digit));
+
+            for index in 0..chunk.len() - 1 {
+                bytes.push((group >> (16 - index * 8)) as u8);
+            }
+        }
+
+        Some(bytes)
+    }
+
+    fn digit_to_char(digit: u8) -> char {
+        (match digit {
+            0..=25 => b'A' + digit,
+            26..=51 => b'a' + digit - 26,
+            52..=61 => b'0' + digit - 52,
+            62 => b'+',
+            _ => b'/',
+        }) as char
+    }
+
+    fn char_to_digit(char: u8) -> Option<u8> {
+        match char {
+            b'A'..=b'Z' => Some(char - b'A'),
+            b'a'..=b'z' => Some(char - b'a' + 26),
+            b'0'..=b'9' => Some(char - b'0' + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,4 +321,34 @@ mod tests {
             r#""\x01\x10\x7f\xff""#
         );
     }
+
+    #[test]
+    fn test_byte_string_serde() {
+        let utf8 = ByteString::from("héllo\n\\x80");
+        assert_eq!(serde_json::to_string(&utf8).unwrap(), r#""héllo\n\\x80""#);
+
+        let binary = ByteString::from(b"\xff\x00ab\x80");
+        let json = serde_json::to_string(&binary).unwrap();
+        assert_eq!(json, r#"{"base64":"/wBhYoA="}"#);
+
+        for bytes in [utf8, binary] {
+            let json = serde_json::to_string(&bytes).unwrap();
+            assert_eq!(serde_json::from_str::<ByteString>(&json).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_base64() {
+        for length in 0..8 {
+            let bytes = (0..length).map(|byte| 255 - byte * 37).collect::<Vec<u8>>();
+
+            assert_eq!(base64::decode(&base64::encode(&bytes)), Some(bytes));
+        }
+
+        assert_eq!(base64::encode(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64::encode(b"fooba"), "Zm9vYmE=");
+        assert_eq!(base64::decode("Zm9vYg=="), Some(b"foob".to_vec()));
+        assert_eq!(base64::decode("Zm9v!"), None);
+        assert_eq!(base64::decode("Zm9vY"), None);
+    }
 }
@@ -1,22 +1,30 @@
-use std::fmt::Display;
+use core::fmt::Display;
 
+use crate::lexer::byte_string::ByteString;
 use crate::lexer::token::Span;
 
 pub type SyntaxResult<T> = Result<T, SyntaxError>;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub enum SyntaxError {
     UnexpectedEndOfFile(Span),
     UnexpectedError(Span),
     UnexpectedCharacter(u8, Span),
     InvalidHaltCompiler(Span),
-    InvalidOctalEscape(Span),
     InvalidOctalLiteral(Span),
     InvalidUnicodeEscape(Span),
     UnpredictableState(Span),
-    InvalidDocIndentation(Span),
-    InvalidDocBodyIndentationLevel(usize, Span),
+    /// A line of a heredoc or nowdoc that does not start with the indentation
+    /// of its closing label: the whitespace the line starts with and where the
+    /// line starts, then the indentation of the label and where its line starts.
+    InvalidDocBodyIndentation(ByteString, Span, ByteString, Span),
     UnrecognisedToken(u8, Span),
+    /// The [`CancellationToken`](crate::CancellationToken) of the lexer was
+    /// cancelled before the end of the input.
+    Cancelled(Span),
+    /// The input is longer than the positions of spans, which are `u32`, can
+    /// point into.
+    InputTooLarge(Span),
 }
 
 impl SyntaxError {
@@ -26,19 +34,19 @@ impl SyntaxError {
             Self::UnexpectedError(span) => *span,
             Self::UnexpectedCharacter(_, span) => *span,
             Self::InvalidHaltCompiler(span) => *span,
-            Self::InvalidOctalEscape(span) => *span,
             Self::InvalidOctalLiteral(span) => *span,
             Self::InvalidUnicodeEscape(span) => *span,
             Self::UnpredictableState(span) => *span,
-            Self::InvalidDocIndentation(span) => *span,
-            Self::InvalidDocBodyIndentationLevel(_, span) => *span,
+            Self::InvalidDocBodyIndentation(_, span, ..) => *span,
             Self::UnrecognisedToken(_, span) => *span,
+            Self::Cancelled(span) => *span,
+            Self::InputTooLarge(span) => *span,
         }
     }
 }
 
 impl Display for SyntaxError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::UnexpectedEndOfFile(span) => write!(
                 f,
@@ -60,11 +68,6 @@ impl Display for SyntaxError {
                 "Syntax Error: invalid halt compiler on line {} column {}",
                 span.line, span.column
             ),
-            Self::InvalidOctalEscape(span) => write!(
-                f,
-                "Syntax Error: invalid octal escape on line {} column {}",
-                span.line, span.column
-            ),
             Self::InvalidOctalLiteral(span) => write!(
                 f,
                 "Syntax Error: invalid octal literal on line {} column {}",
@@ -80,16 +83,11 @@ impl Display for SyntaxError {
                 "Syntax Error: Reached an unpredictable state on line {} column {}",
                 span.line, span.column
             ),
-            Self::InvalidDocIndentation(span) => write!(
-                f,
-                "Syntax Error: Invalid indentation - cannot use tabs and spaces on line {}",
-                span.line
-            ),
-            Self::InvalidDocBodyIndentationLevel(expected, span) => write!(
+            Self::InvalidDocBodyIndentation(_, span, _, closing) => write!(
                 f,
-                "Syntax Error: Invalid body indentation level - expecting an indentation level of at least {} on line {}",
-                expected,
-                span.line
+                "Syntax Error: Invalid body indentation - line {} does not start with the indentation of the closing label on line {}",
+                span.line,
+                closing.line
             ),
             Self::UnrecognisedToken(token, span) => write!(
                 f,
@@ -97,7 +95,69 @@ impl Display for SyntaxError {
                 token,
                 span.line,
                 span.column
-            )
+            ),
+            Self::Cancelled(span) => write!(
+                f,
+                "Syntax Error: cancelled on line {} column {}",
+                span.line, span.column
+            ),
+            Self::InputTooLarge(_) => write!(
+                f,
+                "Syntax Error: input is larger than {} bytes",
+                u32::MAX
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SyntaxError {}
+
+/// Code that the lexer accepts, but that likely does not do what was meant.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum SyntaxWarning {
+    /// A line of a heredoc or nowdoc that starts with its closing label, such
+    /// as `EOTX` in a heredoc closed by `EOT`, but that is part of its body.
+    DocStringLabelNotClosing(ByteString, Span),
+    /// An octal escape above `\377`, such as `\400`, which wraps around to
+    /// `\0` as the escape is a single byte.
+    OctalEscapeOverflow(ByteString, Span),
+}
+
+impl SyntaxWarning {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::DocStringLabelNotClosing(_, span) => *span,
+            Self::OctalEscapeOverflow(_, span) => *span,
+        }
+    }
+
+    /// Why the code does not do what it looks like it does.
+    pub fn note(&self) -> &'static str {
+        match self {
+            Self::DocStringLabelNotClosing(..) => {
+                "a closing label cannot be followed by a letter, a digit, or an underscore"
+            }
+            Self::OctalEscapeOverflow(..) => {
+                "an octal escape is a single byte, so only the lowest 8 bits of its value are kept"
+            }
+        }
+    }
+}
+
+impl Display for SyntaxWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::DocStringLabelNotClosing(label, span) => write!(
+                f,
+                "Syntax Warning: line starting with `{}` does not close the doc string on line {} column {}",
+                label, span.line, span.column
+            ),
+            Self::OctalEscapeOverflow(escape, span) => write!(
+                f,
+                "Syntax Warning: octal escape sequence overflow `{}` is greater than `\\377` on line {} column {}",
+                escape, span.line, span.column
+            ),
         }
     }
 }
@@ -0,0 +1,185 @@
+/// An escape sequence in a double quoted string or a heredoc, such as `\n`,
+/// `\x41` or `\u{1F418}`.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Escape {
+    value: [u8; 4],
+    length: usize,
+    /// How many bytes of source code the escape sequence is written with.
+    pub width: usize,
+    /// Whether this is an octal escape above `\377`, which wraps around as it
+    /// does in PHP, so `\400` is `\0`.
+    pub overflow: bool,
+}
+
+impl Escape {
+    fn byte(byte: u8, width: usize) -> Self {
+        Self {
+            value: [byte, 0, 0, 0],
+            length: 1,
+            width,
+            overflow: false,
+        }
+    }
+
+    /// The bytes the escape sequence stands for.
+    pub fn bytes(&self) -> &[u8] {
+        self.value.get(..self.length).unwrap_or_default()
+    }
+}
+
+/// A `\u{...}` escape that is not closed, has no digits, or is not a code
+/// point, along with how many bytes of source code were read to find out.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct InvalidEscape {
+    pub width: usize,
+}
+
+/// Decodes the escape sequence `source` starts with, if it starts with one.
+///
+/// A backslash that does not start an escape sequence, as in `"\q"`, is
+/// written as is, and so is not decoded here.
+pub(crate) fn decode(source: &[u8]) -> Result<Option<Escape>, InvalidEscape> {
+    let escape = match source {
+        &[b'\\', b @ (b'"' | b'\\' | b'$'), ..] => Escape::byte(b, 2),
+        [b'\\', b'n', ..] => Escape::byte(b'\n', 2),
+        [b'\\', b'r', ..] => Escape::byte(b'\r', 2),
+        [b'\\', b't', ..] => Escape::byte(b'\t', 2),
+        [b'\\', b'v', ..] => Escape::byte(b'\x0b', 2),
+        [b'\\', b'e', ..] => Escape::byte(b'\x1b', 2),
+        [b'\\', b'f', ..] => Escape::byte(b'\x0c', 2),
+        [b'\\', b'x', rest @ ..] => {
+            let digits = digits(rest, 2, 16);
+            match number(digits, 16) {
+                Some(value) if !digits.is_empty() => Escape::byte(value as u8, 2 + digits.len()),
+                _ => return Ok(None),
+            }
+        }
+        [b'\\', b'u', b'{', rest @ ..] => {
+            let digits = digits(rest, usize::MAX, 16);
+            let width = 3 + digits.len();
+            if digits.is_empty() || rest.get(digits.len()) != Some(&b'}') {
+                return Err(InvalidEscape { width });
+            }
+
+            let width = width + 1;
+            let c = number(digits, 16)
+                .and_then(char::from_u32)
+                .ok_or(InvalidEscape { width })?;
+
+            let mut value = [0; 4];
+            let length = c.encode_utf8(&mut value).len();
+
+            Escape {
+                value,
+                length,
+                width,
+                overflow: false,
+            }
+        }
+        [b'\\', rest @ ..] => {
+            let digits = digits(rest, 3, 8);
+            match number(digits, 8) {
+                Some(value) if !digits.is_empty() => Escape {
+                    overflow: value > 0o377,
+                    ..Escape::byte(value as u8, 1 + digits.len())
+                },
+                _ => return Ok(None),
+            }
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(escape))
+}
+
+// The digits, at most `limit` of them, that `source` starts with.
+fn digits(source: &[u8], limit: usize, radix: u32) -> &[u8] {
+    let count = source
+        .iter()
+        .take(limit)
+        .take_while(|b| (**b as char).is_digit(radix))
+        .count();
+
+    source.get(..count).unwrap_or_default()
+}
+
+fn number(digits: &[u8], radix: u32) -> Option<u32> {
+    digits.iter().try_fold(0u32, |number, b| {
+        number
+            .checked_mul(radix)?
+            .checked_add((*b as char).to_digit(radix)?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use super::decode;
+    use super::InvalidEscape;
+    use crate::lexer::error::SyntaxWarning;
+    use crate::lexer::Lexer;
+
+    fn bytes(source: &[u8]) -> Option<(Vec<u8>, usize)> {
+        decode(source)
+            .unwrap()
+            .map(|escape| (escape.bytes().to_vec(), escape.width))
+    }
+
+    #[test]
+    fn test_escapes() {
+        assert_eq!(bytes(br#"\"a"#), Some((b"\"".to_vec(), 2)));
+        assert_eq!(bytes(br"\na"), Some((b"\n".to_vec(), 2)));
+        assert_eq!(bytes(br"\x41a"), Some((b"A".to_vec(), 4)));
+        assert_eq!(bytes(br"\x4g"), Some((b"\x04".to_vec(), 3)));
+        assert_eq!(bytes(br"\101a"), Some((b"A".to_vec(), 4)));
+        assert_eq!(bytes(br"\0"), Some((b"\0".to_vec(), 2)));
+        assert_eq!(bytes(br"\u{1F418}"), Some(("🐘".into(), 9)));
+        assert_eq!(bytes(br"\u{00000041}"), Some((b"A".to_vec(), 12)));
+
+        assert_eq!(bytes(br"\q"), None);
+        assert_eq!(bytes(br"\xg"), None);
+        assert_eq!(bytes(br"\u41"), None);
+        assert_eq!(bytes(br"\"), None);
+        assert_eq!(bytes(b"a"), None);
+    }
+
+    #[test]
+    fn test_octal_overflow() {
+        let escape = decode(br"\400").unwrap().unwrap();
+        assert_eq!(escape.bytes(), b"\0");
+        assert!(escape.overflow);
+
+        let escape = decode(br"\777").unwrap().unwrap();
+        assert_eq!(escape.bytes(), b"\xFF");
+        assert!(escape.overflow);
+
+        assert!(!decode(br"\377").unwrap().unwrap().overflow);
+
+        let code = "<?php\n$a = \"a\\400\";\n$b = <<<EOT\n\\7000\nEOT;";
+        let (_, warnings) = Lexer::new().tokenize_with_warnings(code).unwrap();
+
+        let warnings = warnings
+            .iter()
+            .map(|warning| match warning {
+                SyntaxWarning::OctalEscapeOverflow(escape, span) => {
+                    (escape.to_string(), span.line, span.column)
+                }
+                warning => panic!("unexpected warning {:?}", warning),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(warnings, [(r"\400".into(), 2, 8), (r"\700".into(), 4, 1)]);
+    }
+
+    #[test]
+    fn test_invalid_unicode_escapes() {
+        assert_eq!(decode(br"\u{"), Err(InvalidEscape { width: 3 }));
+        assert_eq!(decode(br"\u{}"), Err(InvalidEscape { width: 3 }));
+        assert_eq!(decode(br"\u{42"), Err(InvalidEscape { width: 5 }));
+        assert_eq!(decode(br"\u{110000}"), Err(InvalidEscape { width: 10 }));
+        assert_eq!(decode(br"\u{D800}"), Err(InvalidEscape { width: 8 }));
+        assert_eq!(decode(br"\u{100000000}"), Err(InvalidEscape { width: 13 }));
+    }
+}
@@ -1,39 +1,152 @@
+// `tokenize` never panics, whatever the input: invariants that turn out to be
+// broken are reported as `SyntaxError::UnpredictableState` instead. Any
+// exception to these lints must be allowed where it occurs, with the reason
+// it cannot panic.
+#![deny(
+    clippy::indexing_slicing,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::todo,
+    clippy::unimplemented,
+    clippy::unreachable
+)]
+
+use alloc::vec::Vec;
+
+use crate::cancellation::CancellationToken;
 use crate::ident;
 use crate::ident_start;
 use crate::lexer::byte_string::ByteString;
 use crate::lexer::error::SyntaxError;
 use crate::lexer::error::SyntaxResult;
+use crate::lexer::error::SyntaxWarning;
+use crate::lexer::escape::InvalidEscape;
 use crate::lexer::state::source::Source;
 use crate::lexer::state::StackFrame;
 use crate::lexer::state::State;
 use crate::lexer::token::DocStringIndentationKind;
 use crate::lexer::token::DocStringKind;
 use crate::lexer::token::OpenTagKind;
+use crate::lexer::token::Span;
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
+use crate::lexer::trivia::ConcreteToken;
 
 pub mod byte_string;
 pub mod error;
+pub(crate) mod escape;
 pub mod stream;
 pub mod token;
+pub mod trivia;
 
 mod state;
 
 mod macros;
 
+// The number of tokens, roughly, that are read between two checks of the
+// cancellation token.
+const CANCELLATION_INTERVAL: usize = 256;
+
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
-pub struct Lexer;
+pub struct Lexer {
+    cancellation: Option<CancellationToken>,
+}
 
 impl Lexer {
     pub const fn new() -> Self {
-        Self {}
+        Self { cancellation: None }
+    }
+
+    /// A lexer that fails with [`SyntaxError::Cancelled`] soon after the token
+    /// is cancelled.
+    pub fn with_cancellation(cancellation: CancellationToken) -> Self {
+        Self {
+            cancellation: Some(cancellation),
+        }
     }
 
     pub fn tokenize<B: ?Sized + AsRef<[u8]>>(&self, input: &B) -> SyntaxResult<Vec<Token>> {
-        let mut state = State::new(Source::new(input.as_ref()));
+        self.lex(input.as_ref(), |_| {}).map(|(tokens, _)| tokens)
+    }
+
+    /// Splits the input into tokens, along with warnings about code that is
+    /// valid but likely does not do what was meant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use php_parser_rs::lexer::Lexer;
+    ///
+    /// let code = "<?php $a = <<<EOT\nEOTS are read here\nEOT;";
+    /// let (_, warnings) = Lexer::new().tokenize_with_warnings(code).unwrap();
+    ///
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(warnings[0].span().line, 2);
+    /// ```
+    pub fn tokenize_with_warnings<B: ?Sized + AsRef<[u8]>>(
+        &self,
+        input: &B,
+    ) -> SyntaxResult<(Vec<Token>, Vec<SyntaxWarning>)> {
+        self.lex(input.as_ref(), |_| {})
+    }
+
+    /// Splits the input into tokens, keeping the whitespace and comments
+    /// around each token as its trivia, so that the input can be written
+    /// back byte for byte.
+    ///
+    /// Comments are trivia here, rather than tokens of their own.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use php_parser_rs::lexer::Lexer;
+    ///
+    /// let code = b"<?php\n\n$a  =  \"\\x41\"; // A\n";
+    /// let tokens = Lexer::new().tokenize_lossless(code).unwrap();
+    ///
+    /// let mut output = Vec::new();
+    /// for token in &tokens {
+    ///     token.write(&mut output);
+    /// }
+    ///
+    /// assert_eq!(output, code);
+    /// ```
+    pub fn tokenize_lossless<B: ?Sized + AsRef<[u8]>>(
+        &self,
+        input: &B,
+    ) -> SyntaxResult<Vec<ConcreteToken>> {
+        let input = input.as_ref();
+        let mut whitespace = Vec::new();
+        let (tokens, _) = self.lex(input, |position| whitespace.push(position))?;
+
+        Ok(trivia::attach(input, tokens, &whitespace))
+    }
+
+    // Tokenizes the input, calling `whitespace` with the position of each run
+    // of whitespace that is skipped between tokens.
+    fn lex(
+        &self,
+        input: &[u8],
+        mut whitespace: impl FnMut(usize),
+    ) -> SyntaxResult<(Vec<Token>, Vec<SyntaxWarning>)> {
+        let mut state = State::new(Source::new(input));
+        if u32::try_from(input.len()).is_err() {
+            return Err(SyntaxError::InputTooLarge(state.source.span()));
+        }
+
         let mut tokens = Vec::new();
+        let mut steps: usize = 0;
 
         while !state.source.eof() {
+            if let Some(cancellation) = &self.cancellation {
+                if steps.is_multiple_of(CANCELLATION_INTERVAL) && cancellation.is_cancelled() {
+                    return Err(SyntaxError::Cancelled(state.source.span()));
+                }
+
+                steps = steps.wrapping_add(1);
+            }
+
             match state.frame()? {
                 // The "Initial" state is used to parse inline HTML. It is essentially a catch-all
                 // state that will build up a single token buffer until it encounters an open tag
@@ -42,7 +155,11 @@ impl Lexer {
                 // The scripting state is entered when an open tag is encountered in the source code.
                 // This tells the lexer to start analysing characters at PHP tokens instead of inline HTML.
                 StackFrame::Scripting => {
+                    let position = state.source.span().offset();
                     self.skip_whitespace(&mut state);
+                    if state.source.span().offset() > position {
+                        whitespace(position);
+                    }
 
                     // If we have consumed whitespace and then reached the end of the file, we should break.
                     if state.source.eof() {
@@ -55,10 +172,14 @@ impl Lexer {
                 // In this state, all the text that follows is no longer parsed as PHP as is collected
                 // into a single "InlineHtml" token (kind of cheating, oh well).
                 StackFrame::Halted => {
+                    let span = state.source.span();
+                    let value = state.source.read_remaining();
+
                     tokens.push(Token {
                         kind: TokenKind::InlineHtml,
-                        span: state.source.span(),
-                        value: state.source.read_remaining().into(),
+                        span,
+                        end: span.after(value),
+                        value: value.into(),
                     });
                     break;
                 }
@@ -68,12 +189,16 @@ impl Lexer {
                 // The shell exec state is entered when inside of a execution string (`).
                 StackFrame::ShellExec => self.shell_exec(&mut state, &mut tokens)?,
                 // The doc string state is entered when tokenizing heredocs and nowdocs.
-                StackFrame::DocString(kind, label, ..) => {
-                    let label = label.clone();
+                StackFrame::DocString(kind, label, indentation, closing) => {
+                    let end = DocStringEnd {
+                        label: label.clone(),
+                        indentation: indentation.clone(),
+                        closing: *closing,
+                    };
 
                     match kind {
-                        DocStringKind::Heredoc => self.heredoc(&mut state, &mut tokens, label)?,
-                        DocStringKind::Nowdoc => self.nowdoc(&mut state, &mut tokens, label)?,
+                        DocStringKind::Heredoc => self.heredoc(&mut state, &mut tokens, end)?,
+                        DocStringKind::Nowdoc => self.nowdoc(&mut state, &mut tokens, end)?,
                     }
                 }
                 // LookingForProperty is entered inside double quotes,
@@ -103,10 +228,11 @@ impl Lexer {
         tokens.push(Token {
             kind: TokenKind::Eof,
             span: state.source.span(),
+            end: state.source.span(),
             value: ByteString::default(),
         });
 
-        Ok(tokens)
+        Ok((tokens, state.warnings))
     }
 
     fn skip_whitespace(&self, state: &mut State) {
@@ -117,8 +243,8 @@ impl Lexer {
 
     fn read_and_skip_whitespace(&self, state: &mut State) -> Vec<u8> {
         let mut buffer = Vec::new();
-        while let Some(true) = state.source.current().map(|u: &u8| u.is_ascii_whitespace()) {
-            buffer.push(*state.source.current().unwrap());
+        while let Some(b) = state.source.current().filter(|b| b.is_ascii_whitespace()) {
+            buffer.push(*b);
             state.source.next();
         }
         buffer
@@ -138,6 +264,7 @@ impl Lexer {
                     tokens.push(Token {
                         kind: TokenKind::InlineHtml,
                         span: inline_span,
+                        end: tag_span,
                         value: buffer.into(),
                     });
                 }
@@ -145,6 +272,7 @@ impl Lexer {
                 tokens.push(Token {
                     kind: TokenKind::OpenTag(OpenTagKind::Full),
                     span: tag_span,
+                    end: state.source.span(),
                     value: tag.into(),
                 });
 
@@ -159,6 +287,7 @@ impl Lexer {
                     tokens.push(Token {
                         kind: TokenKind::InlineHtml,
                         span: inline_span,
+                        end: tag_span,
                         value: buffer.into(),
                     });
                 }
@@ -166,6 +295,7 @@ impl Lexer {
                 tokens.push(Token {
                     kind: TokenKind::OpenTag(OpenTagKind::Echo),
                     span: tag_span,
+                    end: state.source.span(),
                     value: b"<?=".into(),
                 });
 
@@ -180,6 +310,7 @@ impl Lexer {
                     tokens.push(Token {
                         kind: TokenKind::InlineHtml,
                         span: inline_span,
+                        end: tag_span,
                         value: buffer.into(),
                     });
                 }
@@ -187,6 +318,7 @@ impl Lexer {
                 tokens.push(Token {
                     kind: TokenKind::OpenTag(OpenTagKind::Short),
                     span: tag_span,
+                    end: state.source.span(),
                     value: b"<?".into(),
                 });
 
@@ -200,6 +332,7 @@ impl Lexer {
         tokens.push(Token {
             kind: TokenKind::InlineHtml,
             span: inline_span,
+            end: state.source.span(),
             value: buffer.into(),
         });
 
@@ -349,7 +482,13 @@ impl Lexer {
                         kind: TokenKind::Null,
                         ..
                     } => (TokenKind::FullyQualifiedIdentifier, b"\\null".into()),
-                    s => unreachable!("{:?}", s),
+                    // Reserved words are valid fully qualified names, e.g. `\Class\foo()` or `\enum`.
+                    Token { value, .. } => {
+                        let mut bytes = value;
+                        bytes.insert(0, b'\\');
+
+                        (TokenKind::FullyQualifiedIdentifier, bytes)
+                    }
                 }
             }
             [b'\\', ..] => {
@@ -377,7 +516,11 @@ impl Lexer {
                     }
                 }
 
-                if buffer.starts_with(b"/**") {
+                // As in PHP, only `/**` followed by whitespace opens a document
+                // comment, so `/**/` and `/*****` dividers are ordinary ones.
+                if buffer.starts_with(b"/**")
+                    && matches!(buffer.get(3), Some(b' ' | b'\t' | b'\n' | b'\r'))
+                {
                     (TokenKind::DocumentComment, buffer.into())
                 } else {
                     (TokenKind::MultiLineComment, buffer.into())
@@ -483,32 +626,32 @@ impl Lexer {
                             buffer.push(b'\'');
                             state.source.next();
                         }
-                        _ => {
-                            // TODO(azjezz) this is most likely a bug, what if current is none?
-                            return Err(SyntaxError::UnexpectedCharacter(
-                                *state.source.current().unwrap(),
-                                state.source.span(),
-                            ));
+                        Some(c) => {
+                            return Err(SyntaxError::UnexpectedCharacter(*c, state.source.span()));
                         }
+                        None => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
                     };
                 } else if let Some(b'"') = state.source.current() {
                     buffer.push(b'"');
                     state.source.next();
                 }
 
-                if !matches!(state.source.current(), Some(b'\n')) {
-                    return Err(SyntaxError::UnexpectedCharacter(
-                        *state.source.current().unwrap(),
-                        state.source.span(),
-                    ));
+                match state.source.current() {
+                    Some(b'\n') => {}
+                    Some(c) => {
+                        return Err(SyntaxError::UnexpectedCharacter(*c, state.source.span()));
+                    }
+                    None => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
                 }
 
                 state.source.next();
+
+                let (indentation, closing) = self.closing_indentation(state, &label);
                 state.replace(StackFrame::DocString(
-                    doc_string_kind.clone(),
-                    label.clone(),
-                    DocStringIndentationKind::None,
-                    0,
+                    doc_string_kind,
+                    label,
+                    indentation,
+                    closing,
                 ));
 
                 (TokenKind::StartDocString(doc_string_kind), buffer.into())
@@ -560,9 +703,8 @@ impl Lexer {
                 let mut buffer = b"(".to_vec();
 
                 // Inlined so we can add whitespace to the buffer.
-                while let Some(true) = state.source.current().map(|u: &u8| u.is_ascii_whitespace())
-                {
-                    buffer.push(*state.source.current().unwrap());
+                while let Some(b) = state.source.current().filter(|b| b.is_ascii_whitespace()) {
+                    buffer.push(*b);
                     state.source.next();
                 }
 
@@ -825,24 +967,31 @@ impl Lexer {
                     (kind, buffer.into())
                 }
             }
-            [b, ..] => unimplemented!(
-                "<scripting> char: {}, line: {}, col: {}",
-                *b as char,
-                state.source.span().line,
-                state.source.span().column
-            ),
+            [b, ..] => return Err(SyntaxError::UnrecognisedToken(*b, state.source.span())),
             // We should never reach this point since we have the empty checks surrounding
             // the call to this function, but it's better to be safe than sorry.
             [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
         };
 
-        Ok(Token { kind, span, value })
+        Ok(Token {
+            kind,
+            span,
+            end: state.source.span(),
+            value,
+        })
     }
 
     fn double_quote(&self, state: &mut State, tokens: &mut Vec<Token>) -> SyntaxResult<()> {
         let span = state.source.span();
         let mut buffer = Vec::new();
+        let mut token_span;
         let (kind, value) = loop {
+            token_span = state.source.span();
+
+            if self.escape(state, &mut buffer)? {
+                continue;
+            }
+
             match state.source.read(3) {
                 [b'$', b'{', ..] => {
                     state.source.skip(2);
@@ -860,97 +1009,6 @@ impl Lexer {
                     state.replace(StackFrame::Scripting);
                     break (TokenKind::DoubleQuote, b'"'.into());
                 }
-                &[b'\\', b @ (b'"' | b'\\' | b'$'), ..] => {
-                    state.source.skip(2);
-                    buffer.push(b);
-                }
-                &[b'\\', b'n', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\n');
-                }
-                &[b'\\', b'r', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\r');
-                }
-                &[b'\\', b't', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\t');
-                }
-                &[b'\\', b'v', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0b');
-                }
-                &[b'\\', b'e', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x1b');
-                }
-                &[b'\\', b'f', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0c');
-                }
-                &[b'\\', b'x', b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')] => {
-                    state.source.skip(3);
-
-                    let mut hex = String::from(b as char);
-                    if let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        hex.push(*b as char);
-                    }
-
-                    let b = u8::from_str_radix(&hex, 16).unwrap();
-                    buffer.push(b);
-                }
-                &[b'\\', b'u', b'{'] => {
-                    state.source.skip(3);
-
-                    let mut code_point = String::new();
-                    while let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        code_point.push(*b as char);
-                    }
-
-                    if code_point.is_empty() || state.source.current() != Some(&b'}') {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
-                    state.source.next();
-
-                    let c = if let Ok(c) = u32::from_str_radix(&code_point, 16) {
-                        c
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    };
-
-                    if let Some(c) = char::from_u32(c) {
-                        let mut tmp = [0; 4];
-                        let bytes = c.encode_utf8(&mut tmp);
-                        buffer.extend(bytes.as_bytes());
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
-                }
-                &[b'\\', b @ b'0'..=b'7', ..] => {
-                    state.source.skip(2);
-
-                    let mut octal = String::from(b as char);
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
-                    }
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
-                    }
-
-                    if let Ok(b) = u8::from_str_radix(&octal, 8) {
-                        buffer.push(b);
-                    } else {
-                        return Err(SyntaxError::InvalidOctalEscape(state.source.span()));
-                    }
-                }
                 [b'$', ident_start!(), ..] => {
                     let mut var = state.source.read_and_skip(1).to_vec();
                     var.extend(self.consume_identifier(state));
@@ -977,18 +1035,27 @@ impl Lexer {
             tokens.push(Token {
                 kind: TokenKind::StringPart,
                 span,
+                end: token_span,
                 value: buffer.into(),
             })
         }
 
-        tokens.push(Token { kind, span, value });
+        tokens.push(Token {
+            kind,
+            span: token_span,
+            end: state.source.span(),
+            value,
+        });
         Ok(())
     }
 
     fn shell_exec(&self, state: &mut State, tokens: &mut Vec<Token>) -> SyntaxResult<()> {
         let span = state.source.span();
         let mut buffer = Vec::new();
+        let mut token_span;
         let (kind, value) = loop {
+            token_span = state.source.span();
+
             match state.source.read(2) {
                 [b'$', b'{'] => {
                     state.source.skip(2);
@@ -1032,11 +1099,17 @@ impl Lexer {
             tokens.push(Token {
                 kind: TokenKind::StringPart,
                 span,
+                end: token_span,
                 value: buffer.into(),
             })
         }
 
-        tokens.push(Token { kind, span, value });
+        tokens.push(Token {
+            kind,
+            span: token_span,
+            end: state.source.span(),
+            value,
+        });
 
         Ok(())
     }
@@ -1045,12 +1118,27 @@ impl Lexer {
         &self,
         state: &mut State,
         tokens: &mut Vec<Token>,
-        label: ByteString,
+        end: DocStringEnd,
     ) -> SyntaxResult<()> {
         let span = state.source.span();
         let mut buffer: Vec<u8> = Vec::new();
 
+        let mut token_span;
         let (kind, value) = loop {
+            // The closing label can only be found at the start of a line.
+            if state.source.span().column == 1 {
+                if let Some(token) = self.doc_string_end(state, &end, &mut buffer)? {
+                    token_span = token.span;
+                    break (token.kind, token.value);
+                }
+            }
+
+            token_span = state.source.span();
+
+            if self.escape(state, &mut buffer)? {
+                continue;
+            }
+
             match state.source.read(3) {
                 [b'$', b'{', ..] => {
                     state.source.skip(2);
@@ -1063,97 +1151,6 @@ impl Lexer {
                     state.enter(StackFrame::Scripting);
                     break (TokenKind::LeftBrace, b"{".into());
                 }
-                &[b'\\', b @ (b'"' | b'\\' | b'$'), ..] => {
-                    state.source.skip(2);
-                    buffer.push(b);
-                }
-                &[b'\\', b'n', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\n');
-                }
-                &[b'\\', b'r', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\r');
-                }
-                &[b'\\', b't', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\t');
-                }
-                &[b'\\', b'v', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0b');
-                }
-                &[b'\\', b'e', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x1b');
-                }
-                &[b'\\', b'f', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0c');
-                }
-                &[b'\\', b'x', b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')] => {
-                    state.source.skip(3);
-
-                    let mut hex = String::from(b as char);
-                    if let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        hex.push(*b as char);
-                    }
-
-                    let b = u8::from_str_radix(&hex, 16).unwrap();
-                    buffer.push(b);
-                }
-                &[b'\\', b'u', b'{'] => {
-                    state.source.skip(3);
-
-                    let mut code_point = String::new();
-                    while let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        code_point.push(*b as char);
-                    }
-
-                    if code_point.is_empty() || state.source.current() != Some(&b'}') {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
-                    state.source.next();
-
-                    let c = if let Ok(c) = u32::from_str_radix(&code_point, 16) {
-                        c
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    };
-
-                    if let Some(c) = char::from_u32(c) {
-                        let mut tmp = [0; 4];
-                        let bytes = c.encode_utf8(&mut tmp);
-                        buffer.extend(bytes.as_bytes());
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
-                }
-                &[b'\\', b @ b'0'..=b'7', ..] => {
-                    state.source.skip(2);
-
-                    let mut octal = String::from(b as char);
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
-                    }
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
-                    }
-
-                    if let Ok(b) = u8::from_str_radix(&octal, 8) {
-                        buffer.push(b);
-                    } else {
-                        return Err(SyntaxError::InvalidOctalEscape(state.source.span()));
-                    }
-                }
                 [b'$', ident_start!(), ..] => {
                     let mut var = state.source.read_and_skip(1).to_vec();
                     var.extend(self.consume_identifier(state));
@@ -1168,84 +1165,9 @@ impl Lexer {
 
                     break (TokenKind::Variable, var.into());
                 }
-                // If we find a new-line, we can start to check if we can see the EndHeredoc token.
                 [b'\n', ..] => {
                     buffer.push(b'\n');
                     state.source.next();
-
-                    // Check if we can see the closing label right here.
-                    if state.source.at(&label, label.len()) {
-                        state.source.skip(label.len());
-                        state.replace(StackFrame::Scripting);
-                        break (
-                            TokenKind::EndDocString(DocStringIndentationKind::None, 0),
-                            label,
-                        );
-                    }
-
-                    // Check if there's any whitespace first.
-                    let (whitespace_kind, whitespace_amount) = match state.source.read(1) {
-                        [b' '] => {
-                            let mut amount = 0;
-                            while state.source.read(1) == [b' '] {
-                                amount += 1;
-                                state.source.next();
-                            }
-                            (DocStringIndentationKind::Space, amount)
-                        }
-                        [b'\t'] => {
-                            let mut amount = 0;
-                            while state.source.read(1) == [b'\t'] {
-                                amount += 1;
-                                state.source.next();
-                            }
-                            (DocStringIndentationKind::Tab, amount)
-                        }
-                        _ => (DocStringIndentationKind::None, 0),
-                    };
-
-                    // We've figured out what type of whitespace was being used
-                    // at the start of the line.
-                    // We should now check for any extra whitespace, of any kind.
-                    let mut extra_whitespace_buffer = Vec::new();
-                    while let [b @ b' ' | b @ b'\t'] = state.source.read(1) {
-                        extra_whitespace_buffer.push(b);
-                        state.source.next();
-                    }
-
-                    // We've consumed all leading whitespace on this line now,
-                    // so let's try to read the label again.
-                    if state.source.at(&label, label.len()) {
-                        // We've found the label, finally! We need to do 1 last
-                        // check to make sure there wasn't a mixture of indentation types.
-                        if whitespace_kind != DocStringIndentationKind::None
-                            && !extra_whitespace_buffer.is_empty()
-                        {
-                            return Err(SyntaxError::InvalidDocIndentation(state.source.span()));
-                        }
-
-                        // If we get here, only 1 type of indentation was found. We can move
-                        // the process along by reading over the label and breaking out
-                        // with the EndHeredoc token, storing the kind and amount of whitespace.
-                        state.source.skip(label.len());
-                        state.replace(StackFrame::Scripting);
-                        break (
-                            TokenKind::EndDocString(whitespace_kind, whitespace_amount),
-                            label,
-                        );
-                    } else {
-                        // We didn't find the label. The buffer still needs to know about
-                        // the whitespace, so let's extend the buffer with the whitespace
-                        // and let the loop run again to handle the rest of the line.
-                        if whitespace_kind != DocStringIndentationKind::None {
-                            let whitespace_char: u8 = whitespace_kind.into();
-                            for _ in 0..whitespace_amount {
-                                buffer.push(whitespace_char);
-                            }
-                        }
-
-                        buffer.extend(extra_whitespace_buffer);
-                    }
                 }
                 &[b, ..] => {
                     state.source.next();
@@ -1264,11 +1186,17 @@ impl Lexer {
             tokens.push(Token {
                 kind: TokenKind::StringPart,
                 span,
+                end: token_span,
                 value: buffer.into(),
             })
         }
 
-        tokens.push(Token { kind, span, value });
+        tokens.push(Token {
+            kind,
+            span: token_span,
+            end: state.source.span(),
+            value,
+        });
 
         Ok(())
     }
@@ -1277,91 +1205,25 @@ impl Lexer {
         &self,
         state: &mut State,
         tokens: &mut Vec<Token>,
-        label: ByteString,
+        end: DocStringEnd,
     ) -> SyntaxResult<()> {
         let span = state.source.span();
         let mut buffer: Vec<u8> = Vec::new();
 
+        let token_span;
         let (kind, value) = loop {
+            // The closing label can only be found at the start of a line.
+            if state.source.span().column == 1 {
+                if let Some(token) = self.doc_string_end(state, &end, &mut buffer)? {
+                    token_span = token.span;
+                    break (token.kind, token.value);
+                }
+            }
+
             match state.source.read(3) {
-                // If we find a new-line, we can start to check if we can see the EndHeredoc token.
                 [b'\n', ..] => {
                     buffer.push(b'\n');
                     state.source.next();
-
-                    // Check if we can see the closing label right here.
-                    if state.source.at(&label, label.len()) {
-                        state.source.skip(label.len());
-                        state.replace(StackFrame::Scripting);
-                        break (
-                            TokenKind::EndDocString(DocStringIndentationKind::None, 0),
-                            label,
-                        );
-                    }
-
-                    // Check if there's any whitespace first.
-                    let (whitespace_kind, whitespace_amount) = match state.source.read(1) {
-                        [b' '] => {
-                            let mut amount = 0;
-                            while state.source.read(1) == [b' '] {
-                                amount += 1;
-                                state.source.next();
-                            }
-                            (DocStringIndentationKind::Space, amount)
-                        }
-                        [b'\t'] => {
-                            let mut amount = 0;
-                            while state.source.read(1) == [b'\t'] {
-                                amount += 1;
-                                state.source.next();
-                            }
-                            (DocStringIndentationKind::Tab, amount)
-                        }
-                        _ => (DocStringIndentationKind::None, 0),
-                    };
-
-                    // We've figured out what type of whitespace was being used
-                    // at the start of the line.
-                    // We should now check for any extra whitespace, of any kind.
-                    let mut extra_whitespace_buffer = Vec::new();
-                    while let [b @ b' ' | b @ b'\t'] = state.source.read(1) {
-                        extra_whitespace_buffer.push(b);
-                        state.source.next();
-                    }
-
-                    // We've consumed all leading whitespace on this line now,
-                    // so let's try to read the label again.
-                    if state.source.at(&label, label.len()) {
-                        // We've found the label, finally! We need to do 1 last
-                        // check to make sure there wasn't a mixture of indentation types.
-                        if whitespace_kind != DocStringIndentationKind::None
-                            && !extra_whitespace_buffer.is_empty()
-                        {
-                            return Err(SyntaxError::InvalidDocIndentation(state.source.span()));
-                        }
-
-                        // If we get here, only 1 type of indentation was found. We can move
-                        // the process along by reading over the label and breaking out
-                        // with the EndHeredoc token, storing the kind and amount of whitespace.
-                        state.source.skip(label.len());
-                        state.replace(StackFrame::Scripting);
-                        break (
-                            TokenKind::EndDocString(whitespace_kind, whitespace_amount),
-                            label,
-                        );
-                    } else {
-                        // We didn't find the label. The buffer still needs to know about
-                        // the whitespace, so let's extend the buffer with the whitespace
-                        // and let the loop run again to handle the rest of the line.
-                        if whitespace_kind != DocStringIndentationKind::None {
-                            let whitespace_char: u8 = whitespace_kind.into();
-                            for _ in 0..whitespace_amount {
-                                buffer.push(whitespace_char);
-                            }
-                        }
-
-                        buffer.extend(extra_whitespace_buffer);
-                    }
                 }
                 &[b, ..] => {
                     state.source.next();
@@ -1380,15 +1242,116 @@ impl Lexer {
             tokens.push(Token {
                 kind: TokenKind::StringPart,
                 span,
+                end: token_span,
                 value: buffer.into(),
             })
         }
 
-        tokens.push(Token { kind, span, value });
+        tokens.push(Token {
+            kind,
+            span: token_span,
+            end: state.source.span(),
+            value,
+        });
 
         Ok(())
     }
 
+    // Finds the line that closes the heredoc or nowdoc starting at the current
+    // line, and returns the indentation of its label, which every line of the
+    // body starts with, along with where the line starts. A doc string that is
+    // never closed has no indentation to remove.
+    fn closing_indentation(&self, state: &State, label: &ByteString) -> (ByteString, Span) {
+        let mut span = state.source.span();
+
+        for line in state.source.read_remaining().split(|b| *b == b'\n') {
+            let (indentation, rest) = line.split_at(
+                line.iter()
+                    .take_while(|b| matches!(b, b' ' | b'\t'))
+                    .count(),
+            );
+
+            if let Some(rest) = rest.strip_prefix(&label[..]) {
+                if !matches!(rest.first(), Some(ident!())) {
+                    return (indentation.into(), span);
+                }
+            }
+
+            span = Span::new(span.line + 1, 1, span.position + line.len() as u32 + 1);
+        }
+
+        (ByteString::default(), state.source.span())
+    }
+
+    // Reads the indentation at the start of a line of a heredoc or nowdoc, and
+    // returns the token of the closing label if it follows. Otherwise, the
+    // indentation of the closing label is removed, and the rest is added to
+    // the buffer.
+    fn doc_string_end(
+        &self,
+        state: &mut State,
+        end: &DocStringEnd,
+        buffer: &mut Vec<u8>,
+    ) -> SyntaxResult<Option<Token>> {
+        let line = state.source.span();
+        let label = &end.label;
+
+        let mut whitespace = Vec::new();
+        while let [b @ (b' ' | b'\t')] = state.source.read(1) {
+            whitespace.push(*b);
+            state.source.next();
+        }
+
+        if state.source.at(label, label.len()) {
+            // A label that goes on, such as `EOTX` for `EOT`, does not close
+            // the doc string, however much it looks like it does.
+            if let [ident!()] = state.source.peek(label.len(), 1) {
+                state.warnings.push(SyntaxWarning::DocStringLabelNotClosing(
+                    label.clone(),
+                    state.source.span(),
+                ));
+            } else {
+                let kind = if whitespace.is_empty() {
+                    DocStringIndentationKind::None
+                } else if !whitespace.contains(&b'\t') {
+                    DocStringIndentationKind::Space
+                } else if !whitespace.contains(&b' ') {
+                    DocStringIndentationKind::Tab
+                } else {
+                    DocStringIndentationKind::Both
+                };
+
+                let span = state.source.span();
+                state.source.skip(label.len());
+                state.replace(StackFrame::Scripting);
+
+                return Ok(Some(Token {
+                    kind: TokenKind::EndDocString(kind, whitespace.len()),
+                    span,
+                    end: state.source.span(),
+                    value: label.clone(),
+                }));
+            }
+        }
+
+        // Each line has to start with the same whitespace as the closing label,
+        // byte for byte. Lines with nothing but whitespace can stop short.
+        if let Some(rest) = whitespace.strip_prefix(&end.indentation[..]) {
+            buffer.extend(rest);
+        } else if !matches!(state.source.read(1), [] | [b'\n' | b'\r'])
+            || !end.indentation.starts_with(&whitespace)
+        {
+            return Err(SyntaxError::InvalidDocBodyIndentation(
+                whitespace.into(),
+                line,
+                end.indentation.clone(),
+                end.closing,
+            ));
+        }
+
+        Ok(None)
+    }
+
     fn looking_for_varname(&self, state: &mut State) -> SyntaxResult<Option<Token>> {
         let identifier = self.peek_identifier(state);
 
@@ -1401,6 +1364,7 @@ impl Lexer {
                 return Ok(Some(Token {
                     kind: TokenKind::Identifier,
                     span,
+                    end: state.source.span(),
                     value: ident.into(),
                 }));
             }
@@ -1428,10 +1392,15 @@ impl Lexer {
                 (TokenKind::Identifier, buffer.into())
             }
             // Should be impossible as we already looked ahead this far inside double_quote.
-            _ => unreachable!(),
+            _ => return Err(SyntaxError::UnpredictableState(span)),
         };
 
-        Ok(Token { kind, span, value })
+        Ok(Token {
+            kind,
+            span,
+            end: state.source.span(),
+            value,
+        })
     }
 
     fn var_offset(&self, state: &mut State) -> SyntaxResult<Token> {
@@ -1464,7 +1433,12 @@ impl Lexer {
             &[b, ..] => return Err(SyntaxError::UnrecognisedToken(b, state.source.span())),
             [] => return Err(SyntaxError::UnexpectedEndOfFile(state.source.span())),
         };
-        Ok(Token { kind, span, value })
+        Ok(Token {
+            kind,
+            span,
+            end: state.source.span(),
+            value,
+        })
     }
 
     fn tokenize_single_quote_string(
@@ -1504,104 +1478,16 @@ impl Lexer {
         let mut buffer = opening.to_vec();
 
         let constant = loop {
+            if self.escape(state, &mut buffer)? {
+                continue;
+            }
+
             match state.source.read(3) {
                 [b'"', ..] => {
                     buffer.push(b'"');
                     state.source.next();
                     break true;
                 }
-                &[b'\\', b @ (b'"' | b'\\' | b'$'), ..] => {
-                    state.source.skip(2);
-                    buffer.push(b);
-                }
-                &[b'\\', b'n', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\n');
-                }
-                &[b'\\', b'r', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\r');
-                }
-                &[b'\\', b't', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\t');
-                }
-                &[b'\\', b'v', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0b');
-                }
-                &[b'\\', b'e', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x1b');
-                }
-                &[b'\\', b'f', ..] => {
-                    state.source.skip(2);
-                    buffer.push(b'\x0c');
-                }
-                &[b'\\', b'x', b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')] => {
-                    state.source.skip(3);
-
-                    let mut hex = String::from(b as char);
-                    if let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        hex.push(*b as char);
-                    }
-
-                    let b = u8::from_str_radix(&hex, 16).unwrap();
-                    buffer.push(b);
-                }
-                &[b'\\', b'u', b'{'] => {
-                    state.source.skip(3);
-
-                    let mut code_point = String::new();
-                    while let Some(b @ (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F')) =
-                        state.source.current()
-                    {
-                        state.source.next();
-                        code_point.push(*b as char);
-                    }
-
-                    if code_point.is_empty() || state.source.current() != Some(&b'}') {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
-                    state.source.next();
-
-                    let c = if let Ok(c) = u32::from_str_radix(&code_point, 16) {
-                        c
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    };
-
-                    if let Some(c) = char::from_u32(c) {
-                        let mut tmp = [0; 4];
-                        let bytes = c.encode_utf8(&mut tmp);
-                        buffer.extend(bytes.as_bytes());
-                    } else {
-                        return Err(SyntaxError::InvalidUnicodeEscape(state.source.span()));
-                    }
-                }
-                &[b'\\', b @ b'0'..=b'7', ..] => {
-                    state.source.skip(2);
-
-                    let mut octal = String::from(b as char);
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
-                    }
-
-                    if let Some(b @ b'0'..=b'7') = state.source.current() {
-                        state.source.next();
-                        octal.push(*b as char);
-                    }
-
-                    if let Ok(b) = u8::from_str_radix(&octal, 8) {
-                        buffer.push(b);
-                    } else {
-                        return Err(SyntaxError::InvalidOctalEscape(state.source.span()));
-                    }
-                }
                 [b'$', ident_start!(), ..] | [b'{', b'$', ..] | [b'$', b'{', ..] => {
                     break false;
                 }
@@ -1621,6 +1507,32 @@ impl Lexer {
         })
     }
 
+    // Decodes the escape sequence the source is at onto the end of `buffer`,
+    // returning whether there was one.
+    fn escape(&self, state: &mut State, buffer: &mut Vec<u8>) -> SyntaxResult<bool> {
+        match escape::decode(state.source.read_remaining()) {
+            Ok(Some(escape)) => {
+                if escape.overflow {
+                    state.warnings.push(SyntaxWarning::OctalEscapeOverflow(
+                        state.source.read(escape.width).into(),
+                        state.source.span(),
+                    ));
+                }
+
+                state.source.skip(escape.width);
+                buffer.extend(escape.bytes());
+
+                Ok(true)
+            }
+            Ok(None) => Ok(false),
+            Err(InvalidEscape { width }) => {
+                state.source.skip(width);
+
+                Err(SyntaxError::InvalidUnicodeEscape(state.source.span()))
+            }
+        }
+    }
+
     fn peek_identifier<'a>(&'a self, state: &'a State) -> Option<&'a [u8]> {
         let mut size = 0;
 
@@ -1637,7 +1549,10 @@ impl Lexer {
     }
 
     fn consume_identifier(&self, state: &mut State) -> Vec<u8> {
-        let ident = self.peek_identifier(state).unwrap().to_vec();
+        let ident = self
+            .peek_identifier(state)
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
         state.source.skip(ident.len());
 
         ident
@@ -1649,6 +1564,8 @@ impl Lexer {
         (TokenKind::Variable, var.into())
     }
 
+    // The value of the token is the number as written, underscores and the
+    // case of its prefix and exponent included, so that it prints back the same.
     fn tokenize_number(&self, state: &mut State) -> SyntaxResult<(TokenKind, ByteString)> {
         let mut buffer = Vec::new();
 
@@ -1699,8 +1616,8 @@ impl Lexer {
             self.read_digits(state, &mut buffer, 10);
         }
 
-        if let Some(b'e' | b'E') = state.source.current() {
-            buffer.push(b'e');
+        if let Some(e @ (b'e' | b'E')) = state.source.current() {
+            buffer.push(*e);
             state.source.next();
             if let Some(b @ (b'-' | b'+')) = state.source.current() {
                 buffer.push(*b);
@@ -1745,6 +1662,7 @@ impl Lexer {
                 [b'_', b] if is_digit(b) => {
                     state.source.next();
                     state.source.next();
+                    buffer.push(b'_');
                     buffer.push(*b);
                 }
                 _ => {
@@ -1860,3 +1778,12 @@ enum NumberKind {
     IntOrFloat,
     OctalOrFloat,
 }
+
+// How a heredoc or nowdoc is closed: its label, and the indentation of the
+// label along with where the line of the label starts.
+#[derive(Debug)]
+struct DocStringEnd {
+    label: ByteString,
+    indentation: ByteString,
+    closing: Span,
+}
@@ -1,12 +1,13 @@
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
 
 use crate::lexer::byte_string::ByteString;
 use crate::lexer::error::SyntaxError;
 use crate::lexer::error::SyntaxResult;
+use crate::lexer::error::SyntaxWarning;
 use crate::lexer::state::source::Source;
-use crate::lexer::token::DocStringIndentationAmount;
-use crate::lexer::token::DocStringIndentationKind;
 use crate::lexer::token::DocStringKind;
+use crate::lexer::token::Span;
 
 pub mod source;
 
@@ -17,12 +18,9 @@ pub enum StackFrame {
     Halted,
     DoubleQuote,
     ShellExec,
-    DocString(
-        DocStringKind,
-        ByteString,
-        DocStringIndentationKind,
-        DocStringIndentationAmount,
-    ),
+    /// The kind and label of the doc string, and the indentation of its
+    /// closing label along with where the line of the label starts.
+    DocString(DocStringKind, ByteString, ByteString, Span),
     LookingForVarname,
     LookingForProperty,
     VarOffset,
@@ -32,6 +30,7 @@ pub enum StackFrame {
 pub struct State<'a> {
     pub source: Source<'a>,
     pub stack: VecDeque<StackFrame>,
+    pub warnings: Vec<SyntaxWarning>,
 }
 
 impl<'a> State<'a> {
@@ -39,6 +38,7 @@ impl<'a> State<'a> {
         Self {
             source,
             stack: VecDeque::from([StackFrame::Initial]),
+            warnings: Vec::new(),
         }
     }
 
@@ -49,9 +49,10 @@ impl<'a> State<'a> {
     }
 
     pub fn replace(&mut self, state: StackFrame) {
-        let i = self.stack.len() - 1;
-
-        self.stack[i] = state;
+        match self.stack.back_mut() {
+            Some(frame) => *frame = state,
+            None => self.stack.push_back(state),
+        }
     }
 
     pub fn enter(&mut self, state: StackFrame) {
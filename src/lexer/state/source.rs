@@ -9,7 +9,6 @@ pub struct Source<'a> {
 
 impl<'a> Source<'a> {
     pub fn new(input: &'a [u8]) -> Self {
-        let input = input;
         let length = input.len();
 
         Self {
@@ -23,22 +22,26 @@ impl<'a> Source<'a> {
         self.span
     }
 
+    // Where the source is, as an offset into the input.
+    const fn offset(&self) -> usize {
+        self.span.offset()
+    }
+
     pub const fn eof(&self) -> bool {
-        self.span.position >= self.length
+        self.offset() >= self.length
     }
 
     pub fn next(&mut self) {
-        if !self.eof() {
-            match self.input[self.span.position] {
-                b'\n' => {
-                    self.span.line += 1;
-                    self.span.column = 1;
-                }
-                _ => self.span.column += 1,
+        match self.input.get(self.offset()) {
+            Some(b'\n') => {
+                self.span.line = self.span.line.saturating_add(1);
+                self.span.column = 1;
             }
+            Some(_) => self.span.column += 1,
+            None => {}
         }
 
-        self.span.position += 1;
+        self.span.position = self.span.position.saturating_add(1);
     }
 
     pub fn skip(&mut self, count: usize) {
@@ -52,30 +55,22 @@ impl<'a> Source<'a> {
 
         self.skip(count);
 
-        &self.input[from..until]
+        self.input.get(from..until).unwrap_or_default()
     }
 
     pub fn current(&self) -> Option<&'a u8> {
-        if self.span.position >= self.length {
-            None
-        } else {
-            Some(&self.input[self.span.position])
-        }
+        self.input.get(self.offset())
     }
 
     pub fn read(&self, n: usize) -> &'a [u8] {
         let (from, until) = self.to_bound(n);
 
-        &self.input[from..until]
+        self.input.get(from..until).unwrap_or_default()
     }
 
     #[inline(always)]
     pub fn read_remaining(&self) -> &'a [u8] {
-        &self.input[(if self.span.position >= self.length {
-            self.length
-        } else {
-            self.span.position
-        })..]
+        self.input.get(self.offset()..).unwrap_or_default()
     }
 
     pub fn at(&self, search: &[u8], len: usize) -> bool {
@@ -85,38 +80,26 @@ impl<'a> Source<'a> {
     pub fn at_case_insensitive(&self, search: &[u8], len: usize) -> bool {
         let (from, until) = self.to_bound(len);
 
-        let slice = &self.input[from..until];
+        let slice = self.input.get(from..until).unwrap_or_default();
 
         slice.eq_ignore_ascii_case(search)
     }
 
     pub fn peek(&self, i: usize, n: usize) -> &'a [u8] {
-        let from = self.span.position + i;
-        if from >= self.length {
-            return &self.input[self.length..self.length];
-        }
+        let from = self.offset().saturating_add(i).min(self.length);
+        let until = from.saturating_add(n).min(self.length);
 
-        let mut until = from + n;
-        if until >= self.length {
-            until = self.length;
-        }
-
-        &self.input[from..until]
+        self.input.get(from..until).unwrap_or_default()
     }
 
     pub fn peek_ignoring_whitespace(&self, i: usize, n: usize) -> &'a [u8] {
         let mut i = i;
 
         loop {
-            let c = self.peek(i, 1);
-
-            if c.is_empty() {
-                return &[];
-            }
-
-            match c[0] {
-                b' ' | b'\t' | b'\r' | b'\n' => i += 1,
-                _ => break,
+            match self.peek(i, 1).first() {
+                None => return &[],
+                Some(b' ' | b'\t' | b'\r' | b'\n') => i += 1,
+                Some(_) => break,
             }
         }
 
@@ -124,16 +107,16 @@ impl<'a> Source<'a> {
     }
 
     const fn to_bound(&self, n: usize) -> (usize, usize) {
-        if self.span.position >= self.length {
+        if self.offset() >= self.length {
             return (self.length, self.length);
         }
 
-        let mut until = self.span.position + n;
+        let mut until = self.offset() + n;
 
         if until >= self.length {
             until = self.length;
         }
 
-        (self.span.position, until)
+        (self.offset(), until)
     }
 }
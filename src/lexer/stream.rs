@@ -1,10 +1,32 @@
+use alloc::vec::Vec;
+
+use crate::lexer::byte_string::ByteString;
+use crate::lexer::token::Span;
 use crate::lexer::token::Token;
+use crate::lexer::token::TokenCategory;
 use crate::lexer::token::TokenKind;
 
 use crate::parser::ast::comments::Comment;
 use crate::parser::ast::comments::CommentFormat;
 use crate::parser::ast::comments::CommentGroup;
 
+// Returned by a stream that has no tokens at all, so that an empty stream
+// behaves as if it only held the end of the file.
+static EOF: Token = Token {
+    kind: TokenKind::Eof,
+    span: Span {
+        line: 0,
+        column: 0,
+        position: 0,
+    },
+    end: Span {
+        line: 0,
+        column: 0,
+        position: 0,
+    },
+    value: ByteString { bytes: Vec::new() },
+};
+
 /// Token stream.
 ///
 /// # Examples
@@ -65,7 +87,7 @@ pub struct TokenStream<'a> {
 
 /// Token stream.
 impl<'a> TokenStream<'a> {
-    pub fn new(tokens: &'a [Token]) -> TokenStream {
+    pub fn new(tokens: &'a [Token]) -> TokenStream<'a> {
         let length = tokens.len();
 
         let mut stream = TokenStream {
@@ -89,34 +111,32 @@ impl<'a> TokenStream<'a> {
     }
 
     /// Get current token.
-    pub const fn current(&self) -> &'a Token {
-        let position = if self.cursor >= self.length {
-            self.length - 1
-        } else {
-            self.cursor
-        };
-
-        &self.tokens[position]
+    pub fn current(&self) -> &'a Token {
+        self.tokens
+            .get(self.cursor)
+            .or(self.tokens.last())
+            .unwrap_or(&EOF)
     }
 
     /// Get previous token.
-    pub const fn previous(&self) -> &'a Token {
-        let position = if self.cursor == 0 { 0 } else { self.cursor - 1 };
-
-        &self.tokens[position]
+    pub fn previous(&self) -> &'a Token {
+        self.tokens
+            .get(self.cursor.saturating_sub(1))
+            .or(self.tokens.last())
+            .unwrap_or(&EOF)
     }
 
     /// Peek next token.
     ///
     /// All comments are skipped.
-    pub const fn peek(&self) -> &'a Token {
+    pub fn peek(&self) -> &'a Token {
         self.peek_nth(1)
     }
 
     /// Peek nth+1 token.
     ///
     /// All comments are skipped.
-    pub const fn lookahead(&self, n: usize) -> &'a Token {
+    pub fn lookahead(&self, n: usize) -> &'a Token {
         self.peek_nth(n + 1)
     }
 
@@ -124,15 +144,13 @@ impl<'a> TokenStream<'a> {
     ///
     /// All comments are skipped.
     #[inline(always)]
-    const fn peek_nth(&self, n: usize) -> &'a Token {
+    fn peek_nth(&self, n: usize) -> &'a Token {
         let mut cursor = self.cursor + 1;
         let mut target = 1;
         loop {
-            if cursor >= self.length {
-                return &self.tokens[self.length - 1];
-            }
-
-            let current = &self.tokens[cursor];
+            let Some(current) = self.tokens.get(cursor) else {
+                return self.tokens.last().unwrap_or(&EOF);
+            };
 
             if matches!(
                 current.kind,
@@ -154,13 +172,37 @@ impl<'a> TokenStream<'a> {
         }
     }
 
+    /// Get the tokens that have not been consumed yet, starting with the
+    /// current token.
+    ///
+    /// Peeking does not consume tokens, so peeked tokens are included.
+    pub fn remaining(&self) -> &'a [Token] {
+        self.tokens.get(self.cursor..).unwrap_or(&[])
+    }
+
     /// Check if current token is EOF.
     pub fn is_eof(&self) -> bool {
-        if self.cursor >= self.length {
-            return true;
-        }
+        self.tokens
+            .get(self.cursor)
+            .is_none_or(|token| token.kind == TokenKind::Eof)
+    }
+
+    /// Get the token before the one at `span`, skipping comments.
+    pub fn before(&self, span: Span) -> Option<&'a Token> {
+        let index = self
+            .tokens
+            .partition_point(|token| token.span.position < span.position);
+
+        self.tokens
+            .get(..index)?
+            .iter()
+            .rev()
+            .find(|token| token.kind.category() != TokenCategory::Comment)
+    }
 
-        self.tokens[self.cursor].kind == TokenKind::Eof
+    /// Forget the comments that start before `offset`.
+    pub fn skip_comments_before(&mut self, offset: usize) {
+        self.comments.retain(|token| token.span.offset() >= offset);
     }
 
     /// Get all comments.
@@ -168,62 +210,60 @@ impl<'a> TokenStream<'a> {
     pub fn comments(&mut self) -> CommentGroup {
         let mut comments = vec![];
 
-        std::mem::swap(&mut self.comments, &mut comments);
+        core::mem::swap(&mut self.comments, &mut comments);
 
         CommentGroup {
             comments: comments
                 .iter()
-                .map(|token| match token {
+                .filter_map(|token| match token {
                     Token {
                         kind: TokenKind::SingleLineComment,
                         span,
                         value,
-                    } => Comment {
+                        ..
+                    } => Some(Comment {
                         span: *span,
                         format: CommentFormat::SingleLine,
                         content: value.clone(),
-                    },
+                    }),
                     Token {
                         kind: TokenKind::MultiLineComment,
                         span,
                         value,
-                    } => Comment {
+                        ..
+                    } => Some(Comment {
                         span: *span,
                         format: CommentFormat::MultiLine,
                         content: value.clone(),
-                    },
+                    }),
                     Token {
                         kind: TokenKind::HashMarkComment,
                         span,
                         value,
-                    } => Comment {
+                        ..
+                    } => Some(Comment {
                         span: *span,
                         format: CommentFormat::HashMark,
                         content: value.clone(),
-                    },
+                    }),
                     Token {
                         kind: TokenKind::DocumentComment,
                         span,
                         value,
-                    } => Comment {
+                        ..
+                    } => Some(Comment {
                         span: *span,
                         format: CommentFormat::Document,
                         content: value.clone(),
-                    },
-                    _ => unreachable!(),
+                    }),
+                    _ => None,
                 })
                 .collect(),
         }
     }
 
     fn collect_comments(&mut self) {
-        loop {
-            if self.cursor >= self.length {
-                break;
-            }
-
-            let current = &self.tokens[self.cursor];
-
+        while let Some(current) = self.tokens.get(self.cursor) {
             if !matches!(
                 current.kind,
                 TokenKind::SingleLineComment
@@ -1,39 +1,61 @@
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
-use std::fmt::Display;
+use core::fmt::Display;
 
 use crate::lexer::byte_string::ByteString;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct Span {
-    pub line: usize,
-    pub column: usize,
-    pub position: usize,
+    pub line: u32,
+    pub column: u32,
+    pub position: u32,
 }
 
 impl Span {
-    pub fn new(line: usize, column: usize, position: usize) -> Self {
+    pub fn new(line: u32, column: u32, position: u32) -> Self {
         Self {
             line,
             column,
             position,
         }
     }
-}
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+    /// The position as an offset into the input. The lexer rejects inputs
+    /// that a `u32` cannot count the bytes of, so every position fits.
+    pub const fn offset(&self) -> usize {
+        self.position as usize
+    }
+
+    /// The position that follows `text`, when it starts at this one.
+    pub fn after(mut self, text: &[u8]) -> Span {
+        for byte in text {
+            if *byte == b'\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+
+        self.position = self.position.saturating_add(text.len() as u32);
+        self
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub enum OpenTagKind {
     Full,  // `<?php`
     Short, // `<?`
     Echo,  // `<?=`
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub enum DocStringKind {
     Heredoc,
     Nowdoc,
@@ -41,8 +63,8 @@ pub enum DocStringKind {
 
 pub type DocStringIndentationAmount = usize;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub enum DocStringIndentationKind {
     Space,
     Tab,
@@ -50,28 +72,32 @@ pub enum DocStringIndentationKind {
     Both,
 }
 
-impl From<u8> for DocStringIndentationKind {
-    fn from(byte: u8) -> Self {
+impl TryFrom<u8> for DocStringIndentationKind {
+    type Error = ();
+
+    fn try_from(byte: u8) -> Result<Self, Self::Error> {
         match byte {
-            b' ' => Self::Space,
-            b'\t' => Self::Tab,
-            _ => unreachable!(),
+            b' ' => Ok(Self::Space),
+            b'\t' => Ok(Self::Tab),
+            _ => Err(()),
         }
     }
 }
 
-impl From<DocStringIndentationKind> for u8 {
-    fn from(kind: DocStringIndentationKind) -> Self {
+impl TryFrom<DocStringIndentationKind> for u8 {
+    type Error = ();
+
+    fn try_from(kind: DocStringIndentationKind) -> Result<Self, Self::Error> {
         match kind {
-            DocStringIndentationKind::Space => b' ',
-            DocStringIndentationKind::Tab => b'\t',
-            _ => unreachable!(),
+            DocStringIndentationKind::Space => Ok(b' '),
+            DocStringIndentationKind::Tab => Ok(b'\t'),
+            _ => Err(()),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub enum TokenKind {
     Die,
     // Can't use `Self` as a name here, so suffixing with an underscore.
@@ -227,8 +253,15 @@ pub enum TokenKind {
     Pow,
     PowEquals,
     Private,
+    /// `private(set)`, which the parser puts together from its four tokens
+    /// where modifiers are expected.
+    PrivateSet,
     Protected,
+    /// `protected(set)`, put together by the parser.
+    ProtectedSet,
     Public,
+    /// `public(set)`, put together by the parser.
+    PublicSet,
     QualifiedIdentifier,
     Question,
     QuestionColon,
@@ -262,11 +295,228 @@ pub enum TokenKind {
     LogicalXor,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+/// A coarse class of tokens, such as a syntax highlighter colors alike.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+pub enum TokenCategory {
+    Keyword,
+    Identifier,
+    Variable,
+    String,
+    Number,
+    Comment,
+    /// Operators and punctuation, casts included.
+    Operator,
+    Html,
+    /// The tags that open and close PHP code.
+    Tag,
+}
 
+impl TokenKind {
+    /// The category of the token, for highlighting it without parsing.
+    ///
+    /// Words are keywords whatever the context, so `class` in `$a->class` is
+    /// one too. The end of file token, which closes PHP code just like `?>`,
+    /// is a tag.
+    pub fn category(&self) -> TokenCategory {
+        match self {
+            Self::Abstract
+            | Self::Array
+            | Self::As
+            | Self::Break
+            | Self::Callable
+            | Self::Case
+            | Self::Catch
+            | Self::Class
+            | Self::ClassConstant
+            | Self::Clone
+            | Self::CompilerHaltOffsetConstant
+            | Self::Const
+            | Self::Continue
+            | Self::Declare
+            | Self::Default
+            | Self::Die
+            | Self::DirConstant
+            | Self::Do
+            | Self::Echo
+            | Self::Else
+            | Self::ElseIf
+            | Self::Empty
+            | Self::EndDeclare
+            | Self::EndFor
+            | Self::EndForeach
+            | Self::EndIf
+            | Self::EndSwitch
+            | Self::EndWhile
+            | Self::Enum
+            | Self::Eval
+            | Self::Exit
+            | Self::Extends
+            | Self::False
+            | Self::FileConstant
+            | Self::Final
+            | Self::Finally
+            | Self::Fn
+            | Self::For
+            | Self::Foreach
+            | Self::From
+            | Self::Function
+            | Self::FunctionConstant
+            | Self::Global
+            | Self::Goto
+            | Self::HaltCompiler
+            | Self::If
+            | Self::Implements
+            | Self::Include
+            | Self::IncludeOnce
+            | Self::Instanceof
+            | Self::Insteadof
+            | Self::Interface
+            | Self::Isset
+            | Self::LineConstant
+            | Self::List
+            | Self::LogicalAnd
+            | Self::LogicalOr
+            | Self::LogicalXor
+            | Self::Match
+            | Self::MethodConstant
+            | Self::Namespace
+            | Self::NamespaceConstant
+            | Self::New
+            | Self::Null
+            | Self::Parent
+            | Self::Print
+            | Self::Private
+            | Self::PrivateSet
+            | Self::Protected
+            | Self::ProtectedSet
+            | Self::Public
+            | Self::PublicSet
+            | Self::Readonly
+            | Self::Require
+            | Self::RequireOnce
+            | Self::Return
+            | Self::Self_
+            | Self::Static
+            | Self::Switch
+            | Self::Throw
+            | Self::Trait
+            | Self::TraitConstant
+            | Self::True
+            | Self::Try
+            | Self::Unset
+            | Self::Use
+            | Self::Var
+            | Self::While
+            | Self::Yield => TokenCategory::Keyword,
+            Self::Identifier | Self::QualifiedIdentifier | Self::FullyQualifiedIdentifier => {
+                TokenCategory::Identifier
+            }
+            Self::Variable | Self::Dollar => TokenCategory::Variable,
+            Self::LiteralString
+            | Self::StringPart
+            | Self::DoubleQuote
+            | Self::Backtick
+            | Self::StartDocString(_)
+            | Self::EndDocString(..) => TokenCategory::String,
+            Self::LiteralInteger | Self::LiteralFloat => TokenCategory::Number,
+            Self::SingleLineComment
+            | Self::HashMarkComment
+            | Self::MultiLineComment
+            | Self::DocumentComment => TokenCategory::Comment,
+            Self::InlineHtml => TokenCategory::Html,
+            Self::OpenTag(_) | Self::CloseTag | Self::Eof => TokenCategory::Tag,
+            Self::Ampersand
+            | Self::AmpersandEquals
+            | Self::And
+            | Self::AndEquals
+            | Self::AngledLeftRight
+            | Self::ArrayCast
+            | Self::Arrow
+            | Self::Asterisk
+            | Self::AsteriskEquals
+            | Self::At
+            | Self::Attribute
+            | Self::Bang
+            | Self::BangDoubleEquals
+            | Self::BangEquals
+            | Self::BinaryCast
+            | Self::BitwiseNot
+            | Self::BoolCast
+            | Self::BooleanAnd
+            | Self::BooleanCast
+            | Self::BooleanOr
+            | Self::Caret
+            | Self::CaretEquals
+            | Self::Colon
+            | Self::Comma
+            | Self::CurlyOpen
+            | Self::DivEquals
+            | Self::DollarLeftBrace
+            | Self::Dot
+            | Self::DotEquals
+            | Self::DoubleArrow
+            | Self::DoubleCast
+            | Self::DoubleColon
+            | Self::DoubleEquals
+            | Self::DoubleQuestion
+            | Self::DoubleQuestionEquals
+            | Self::Decrement
+            | Self::Ellipsis
+            | Self::Equals
+            | Self::FloatCast
+            | Self::GreaterThan
+            | Self::GreaterThanEquals
+            | Self::Increment
+            | Self::IntCast
+            | Self::IntegerCast
+            | Self::LeftBrace
+            | Self::LeftBracket
+            | Self::LeftParen
+            | Self::LeftShift
+            | Self::LeftShiftEquals
+            | Self::LessThan
+            | Self::LessThanEquals
+            | Self::Minus
+            | Self::MinusEquals
+            | Self::NamespaceSeparator
+            | Self::ObjectCast
+            | Self::Percent
+            | Self::PercentEquals
+            | Self::Pipe
+            | Self::PipeEquals
+            | Self::Plus
+            | Self::PlusEquals
+            | Self::Pow
+            | Self::PowEquals
+            | Self::Question
+            | Self::QuestionArrow
+            | Self::QuestionColon
+            | Self::RealCast
+            | Self::RightBrace
+            | Self::RightBracket
+            | Self::RightParen
+            | Self::RightShift
+            | Self::RightShiftEquals
+            | Self::SemiColon
+            | Self::Slash
+            | Self::SlashEquals
+            | Self::Spaceship
+            | Self::StringCast
+            | Self::TripleEquals
+            | Self::UnsetCast => TokenCategory::Operator,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct Token {
     pub kind: TokenKind,
     pub span: Span,
+    /// The position just past the last byte of the token in the source,
+    /// which escape sequences in `value` may not tell.
+    pub end: Span,
     pub value: ByteString,
 }
 
@@ -275,27 +525,28 @@ impl Default for Token {
         Self {
             kind: TokenKind::Eof,
             span: Span::new(0, 0, 0),
+            end: Span::new(0, 0, 0),
             value: ByteString::default(),
         }
     }
 }
 
 impl Display for Token {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.value)
     }
 }
 
 impl Display for TokenKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let s = match self {
             Self::CompilerHaltOffsetConstant => "__COMPILER_HALT_OFFSET__",
             Self::Die => "die",
             Self::Self_ => "self",
             Self::Parent => "parent",
             Self::Backtick => "`",
-            Self::StartDocString(_) => todo!(),
-            Self::EndDocString(..) => todo!(),
+            Self::StartDocString(_) => "<<<",
+            Self::EndDocString(..) => "end of doc string",
             Self::BangEquals => "!=",
             Self::From => "from",
             Self::Print => "print",
@@ -414,8 +665,11 @@ impl Display for TokenKind {
             Self::PlusEquals => "+=",
             Self::Pow => "**",
             Self::Private => "private",
+            Self::PrivateSet => "private(set)",
             Self::Protected => "protected",
+            Self::ProtectedSet => "protected(set)",
             Self::Public => "public",
+            Self::PublicSet => "public(set)",
             Self::Question => "?",
             Self::QuestionColon => "?:",
             Self::Require => "require",
@@ -469,20 +723,53 @@ impl Display for TokenKind {
             Self::Interface => "interface",
             Self::NamespaceConstant => "__NAMESPACE__",
             Self::PowEquals => "**=",
-            Self::StringPart
-            | Self::Variable
-            | Self::QualifiedIdentifier
-            | Self::Identifier
-            | Self::FullyQualifiedIdentifier
-            | Self::LiteralString
-            | Self::SingleLineComment
-            | Self::MultiLineComment
-            | Self::HashMarkComment
-            | Self::DocumentComment => {
-                return write!(f, "{:?}", self);
-            }
+            Self::StringPart => "string",
+            Self::Variable => "variable",
+            Self::QualifiedIdentifier => "qualified identifier",
+            Self::Identifier => "identifier",
+            Self::FullyQualifiedIdentifier => "fully qualified identifier",
+            Self::LiteralString => "string literal",
+            Self::SingleLineComment | Self::MultiLineComment | Self::HashMarkComment => "comment",
+            Self::DocumentComment => "doc comment",
         };
 
         write!(f, "{}", s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TokenCategory::*;
+    use crate::lexer::Lexer;
+
+    #[test]
+    fn test_categories() {
+        let code = include_str!("../../tests/fixtures/0419-token-categories/code.php");
+        let categories = Lexer::new()
+            .tokenize(code)
+            .unwrap()
+            .iter()
+            .map(|token| token.kind.category())
+            .collect::<Vec<_>>();
+
+        let expected = [
+            // `<h1><?= $title ?></h1>`
+            vec![Html, Tag, Variable, Tag, Html],
+            // `<?php // Greets.`
+            vec![Tag, Comment],
+            // `function greet(string $name = "World"): string {`
+            vec![
+                Keyword, Identifier, Operator, Identifier, Variable, Operator, String, Operator,
+                Operator, Identifier, Operator,
+            ],
+            // `return 'Hello, ' . $name . 1.5;`
+            vec![
+                Keyword, String, Operator, Variable, Operator, Number, Operator,
+            ],
+            // `}`, and the end of the file
+            vec![Operator, Tag],
+        ];
+
+        assert_eq!(categories, expected.concat());
+    }
+}
@@ -0,0 +1,223 @@
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::lexer::byte_string::ByteString;
+use crate::lexer::token::Span;
+use crate::lexer::token::Token;
+use crate::lexer::token::TokenCategory;
+use crate::lexer::token::TokenKind;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TriviaKind {
+    Whitespace,
+    SingleLineComment,
+    HashMarkComment,
+    MultiLineComment,
+    DocumentComment,
+}
+
+/// Whitespace or a comment, as it is written in the source.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+    pub value: ByteString,
+}
+
+impl Trivia {
+    /// The comment token that the parser sees for this trivia, if it is a
+    /// comment.
+    pub fn token(&self) -> Option<Token> {
+        let kind = match self.kind {
+            TriviaKind::Whitespace => return None,
+            TriviaKind::SingleLineComment => TokenKind::SingleLineComment,
+            TriviaKind::HashMarkComment => TokenKind::HashMarkComment,
+            TriviaKind::MultiLineComment => TokenKind::MultiLineComment,
+            TriviaKind::DocumentComment => TokenKind::DocumentComment,
+        };
+
+        Some(Token {
+            kind,
+            span: self.span,
+            end: self.span.after(&self.value),
+            value: self.value.clone(),
+        })
+    }
+
+    /// The category of the trivia, if it is a comment.
+    pub fn category(&self) -> Option<TokenCategory> {
+        self.token().map(|token| token.kind.category())
+    }
+}
+
+/// A token along with the exact text it was read from and the trivia around
+/// it.
+///
+/// The trivia that follows a token on the same line, up to and including the
+/// line break, is its trailing trivia, along with the whole of a block comment
+/// that starts on that line. Anything after that is the leading trivia of the
+/// next token, which is the end of file token for whatever follows the last
+/// one.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ConcreteToken {
+    pub token: Token,
+    /// The source of the token, before escape sequences and the indentation
+    /// of heredocs are processed.
+    pub text: ByteString,
+    pub leading: Vec<Trivia>,
+    pub trailing: Vec<Trivia>,
+}
+
+impl ConcreteToken {
+    /// Writes the token back as it was in the source, trivia included.
+    pub fn write(&self, output: &mut Vec<u8>) {
+        for trivia in &self.leading {
+            output.extend_from_slice(&trivia.value);
+        }
+
+        output.extend_from_slice(&self.text);
+
+        for trivia in &self.trailing {
+            output.extend_from_slice(&trivia.value);
+        }
+    }
+}
+
+// Attaches the trivia between the tokens to them, given the positions at which
+// the lexer skipped whitespace.
+//
+// Every byte of the input belongs to a token or to a run of whitespace that
+// follows it, so the text of a token runs from its start to the whitespace
+// after it, or to the start of the next token.
+pub(crate) fn attach(input: &[u8], tokens: Vec<Token>, whitespace: &[usize]) -> Vec<ConcreteToken> {
+    let mut builder = Builder::default();
+    let mut whitespace = whitespace.iter().peekable();
+
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let start = token.span.offset();
+        let end = tokens
+            .peek()
+            .map_or(input.len(), |next| next.span.offset())
+            .max(start);
+
+        let mut text_end = end;
+        if let Some(&&position) = whitespace.peek() {
+            if position >= start && position < end {
+                text_end = position;
+                whitespace.next();
+            }
+        }
+
+        let comment = match token.kind {
+            TokenKind::SingleLineComment => Some(TriviaKind::SingleLineComment),
+            TokenKind::HashMarkComment => Some(TriviaKind::HashMarkComment),
+            TokenKind::MultiLineComment => Some(TriviaKind::MultiLineComment),
+            TokenKind::DocumentComment => Some(TriviaKind::DocumentComment),
+            _ => None,
+        };
+
+        // Single-line comments read the line break that ends them, which is
+        // whitespace like the rest.
+        if comment.is_some() {
+            text_end = text_end.min(start + token.value.len());
+        }
+
+        let text = input.get(start..text_end).unwrap_or_default();
+        let span = token.span;
+        let after = span.after(text);
+
+        match comment {
+            Some(kind) => builder.trivia(Trivia {
+                kind,
+                span,
+                value: text.into(),
+            }),
+            None => builder.token(ConcreteToken {
+                token,
+                text: text.into(),
+                leading: Vec::new(),
+                trailing: Vec::new(),
+            }),
+        }
+
+        let rest = input.get(text_end..end).unwrap_or_default();
+        if !rest.is_empty() {
+            builder.trivia(Trivia {
+                kind: TriviaKind::Whitespace,
+                span: after,
+                value: rest.into(),
+            });
+        }
+    }
+
+    builder.tokens
+}
+
+#[derive(Default)]
+struct Builder {
+    tokens: Vec<ConcreteToken>,
+    leading: Vec<Trivia>,
+    /// Whether trivia is still on the line of the last token.
+    trailing: bool,
+}
+
+impl Builder {
+    fn token(&mut self, mut token: ConcreteToken) {
+        token.leading = mem::take(&mut self.leading);
+
+        self.tokens.push(token);
+        self.trailing = true;
+    }
+
+    fn trivia(&mut self, mut trivia: Trivia) {
+        let last = match self.tokens.last_mut() {
+            // Document comments describe what follows them.
+            Some(last) if self.trailing && trivia.kind != TriviaKind::DocumentComment => last,
+            _ => {
+                self.trailing = false;
+                self.push_leading(trivia);
+
+                return;
+            }
+        };
+
+        // A block comment that starts on the line of the token is kept whole,
+        // even if it goes on over several lines.
+        let line_break = match trivia.value.iter().position(|b| *b == b'\n') {
+            Some(line_break) if trivia.kind != TriviaKind::MultiLineComment => line_break,
+            _ => {
+                last.trailing.push(trivia);
+
+                return;
+            }
+        };
+
+        self.trailing = false;
+
+        let rest = trivia.value.bytes.split_off(line_break + 1);
+        let span = trivia.span.after(&trivia.value);
+        last.trailing.push(trivia);
+
+        if !rest.is_empty() {
+            self.push_leading(Trivia {
+                kind: TriviaKind::Whitespace,
+                span,
+                value: rest.into(),
+            });
+        }
+    }
+
+    // Runs of whitespace are kept together, as a single-line comment and the
+    // whitespace after it come in two parts.
+    fn push_leading(&mut self, trivia: Trivia) {
+        match self.leading.last_mut() {
+            Some(last)
+                if last.kind == TriviaKind::Whitespace && trivia.kind == TriviaKind::Whitespace =>
+            {
+                last.value.extend_from_slice(&trivia.value);
+            }
+            _ => self.leading.push(trivia),
+        }
+    }
+}
@@ -1,9 +1,25 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg_attr(not(feature = "std"), macro_use)]
+extern crate alloc;
+
+pub mod cancellation;
 pub mod downcast;
+pub mod error;
+pub mod features;
 pub mod lexer;
 pub mod node;
 pub mod parser;
 pub mod printer;
+pub mod source;
 pub mod traverser;
 
+pub use cancellation::CancellationToken;
+pub use error::Error;
 pub use lexer::stream::TokenStream;
-pub use parser::{construct, parse};
+#[cfg(feature = "std")]
+pub use parser::parse_file;
+pub use parser::{
+    construct, construct_with_options, parse, parse_lossless, parse_str, parse_with_deprecations,
+    parse_with_options,
+};
@@ -1,4 +1,5 @@
 use clap::Parser;
+use php_parser_rs::source::SourceFile;
 use std::io::Result;
 
 #[derive(Parser, Default, Debug)]
@@ -16,38 +17,36 @@ struct Arguments {
 fn main() -> Result<()> {
     let args = Arguments::parse();
 
-    let file = args.file;
-    let contents = std::fs::read_to_string(&file)?;
+    let file = SourceFile::from_path(&args.file)?;
     let silent = args.silent;
     let print_json = args.json;
 
-    match php_parser_rs::parse(&contents) {
-        Ok(ast) => {
-            // if --silent is passed, don't print anything
-            if silent {
-                return Ok(());
-            }
+    if !file.diagnostics().is_empty() {
+        println!("{}", file.report(true, false)?);
 
-            // if --json is passed, print as json
-            if print_json {
-                match serde_json::to_string_pretty(&ast) {
-                    Ok(json) => println!("{}", json),
-                    Err(error) => {
-                        eprintln!("Failed to convert ast to json: {}", error);
-
-                        std::process::exit(1);
-                    }
-                }
-            } else {
-                // if --json is not passed, print as text
-                println!("{:#?}", ast);
-            }
-        }
-        Err(error) => {
-            println!("{}", error.report(&contents, Some(&file), true, false)?);
+        std::process::exit(1);
+    }
+
+    let ast = file.program();
 
-            std::process::exit(1);
+    // if --silent is passed, don't print anything
+    if silent {
+        return Ok(());
+    }
+
+    // if --json is passed, print as json
+    if print_json {
+        match serde_json::to_string_pretty(ast) {
+            Ok(json) => println!("{}", json),
+            Err(error) => {
+                eprintln!("Failed to convert ast to json: {}", error);
+
+                std::process::exit(1);
+            }
         }
+    } else {
+        // if --json is not passed, print as text
+        println!("{:#?}", ast);
     }
 
     Ok(())
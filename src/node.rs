@@ -1,7 +1,23 @@
-use std::any::Any;
+use alloc::vec::Vec;
+use core::any::Any;
 
 pub trait Node: Any {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    /// Returns the nodes directly beneath this one, in source order.
+    fn children(&self) -> Vec<&dyn Node> {
         vec![]
     }
+
+    /// Returns the same nodes as [`Node::children`], mutably.
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![]
+    }
+
+    /// Returns the name of the node's type, without its module path or
+    /// generic arguments, such as `EchoStatement`.
+    fn name(&self) -> &'static str {
+        let name = core::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+
+        name.rsplit("::").next().unwrap_or(name)
+    }
 }
@@ -1,5 +1,7 @@
-use std::slice::Iter;
+use alloc::vec::Vec;
+use core::slice::Iter;
 
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -9,8 +11,11 @@ use crate::node::Node;
 use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::Expression;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct PositionalArgument {
     pub comments: CommentGroup,
     pub ellipsis: Option<Span>, // `...`
@@ -18,12 +23,25 @@ pub struct PositionalArgument {
 }
 
 impl Node for PositionalArgument {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.value]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.value]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for PositionalArgument {
+    fn span(&self) -> SpanRange {
+        let value = self.value.span();
+
+        SpanRange::new(self.ellipsis.unwrap_or(value.start), value.end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct NamedArgument {
     pub comments: CommentGroup,
     pub name: SimpleIdentifier, // `foo`
@@ -33,12 +51,23 @@ pub struct NamedArgument {
 }
 
 impl Node for NamedArgument {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.name, &self.value]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.name, &mut self.value]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for NamedArgument {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.name.span, self.value.span().end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum Argument {
     Positional(PositionalArgument),
@@ -46,7 +75,14 @@ pub enum Argument {
 }
 
 impl Node for Argument {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            Argument::Positional(argument) => vec![argument],
+            Argument::Named(argument) => vec![argument],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             Argument::Positional(argument) => vec![argument],
             Argument::Named(argument) => vec![argument],
@@ -54,8 +90,17 @@ impl Node for Argument {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Argument {
+    fn span(&self) -> SpanRange {
+        match self {
+            Argument::Positional(argument) => argument.span(),
+            Argument::Named(argument) => argument.span(),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ArgumentList {
     pub comments: CommentGroup,
     pub left_parenthesis: Span,   // `(`
@@ -71,7 +116,7 @@ impl ArgumentList {
 
 impl IntoIterator for ArgumentList {
     type Item = Argument;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.arguments.into_iter()
@@ -79,7 +124,11 @@ impl IntoIterator for ArgumentList {
 }
 
 impl Node for ArgumentList {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.arguments.iter().map(|a| a as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.arguments
             .iter_mut()
             .map(|a| a as &mut dyn Node)
@@ -87,8 +136,14 @@ impl Node for ArgumentList {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ArgumentList {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_parenthesis, self.right_parenthesis.after(b")"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct SingleArgument {
     pub comments: CommentGroup,
     pub left_parenthesis: Span,  // `(`
@@ -97,16 +152,32 @@ pub struct SingleArgument {
 }
 
 impl Node for SingleArgument {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.argument]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.argument]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for SingleArgument {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_parenthesis, self.right_parenthesis.after(b")"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ArgumentPlaceholder {
     pub comments: CommentGroup,
     pub left_parenthesis: Span,  // `(`
     pub ellipsis: Span,          // `...`
     pub right_parenthesis: Span, // `)`
 }
+
+impl Spanned for ArgumentPlaceholder {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_parenthesis, self.right_parenthesis.after(b")"))
+    }
+}
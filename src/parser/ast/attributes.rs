@@ -1,30 +1,110 @@
-use std::slice::Iter;
+use alloc::vec::Vec;
+use core::slice::Iter;
 
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::lexer::token::Span;
+use crate::node::Node;
 use crate::parser::ast::arguments::ArgumentList;
 use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct Attribute {
     pub start: Span,
-    pub end: Span,
+    pub end: Span, // `)`, or the name when there are no arguments
     pub name: SimpleIdentifier,
     pub arguments: Option<ArgumentList>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Node for Attribute {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name];
+        if let Some(arguments) = &self.arguments {
+            children.push(arguments);
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
+        if let Some(arguments) = &mut self.arguments {
+            children.push(arguments);
+        }
+        children
+    }
+}
+
+impl Spanned for Attribute {
+    fn span(&self) -> SpanRange {
+        let end = match self.arguments {
+            Some(_) => self.end.after(b")"),
+            None => self.name.span().end,
+        };
+
+        SpanRange::new(self.start, end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct AttributeGroup {
     pub start: Span,
     pub end: Span,
     pub members: Vec<Attribute>,
 }
 
+impl Node for AttributeGroup {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.members.iter().map(|x| x as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.members
+            .iter_mut()
+            .map(|x| x as &mut dyn Node)
+            .collect()
+    }
+}
+
+impl Spanned for AttributeGroup {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(b"]"))
+    }
+}
+
+impl Attribute {
+    /// Whether the attribute is named `name`, ignoring case and a leading
+    /// backslash, as class names are compared in PHP.
+    ///
+    /// The name is compared as written: `#[Deprecated]` inside a namespace
+    /// refers to a class of that namespace, unless it is imported.
+    pub fn is_named(&self, name: &str) -> bool {
+        let written = self
+            .name
+            .value
+            .strip_prefix(b"\\")
+            .unwrap_or(&self.name.value);
+        let name = name.strip_prefix('\\').unwrap_or(name);
+
+        written.eq_ignore_ascii_case(name.as_bytes())
+    }
+}
+
+/// The first attribute in `groups` named `name`, as matched by
+/// [`Attribute::is_named`].
+pub fn find_attribute<'a>(groups: &'a [AttributeGroup], name: &str) -> Option<&'a Attribute> {
+    groups
+        .iter()
+        .flat_map(AttributeGroup::iter)
+        .find(|attribute| attribute.is_named(name))
+}
+
 impl AttributeGroup {
     pub fn iter(&self) -> Iter<'_, Attribute> {
         self.members.iter()
@@ -33,9 +113,42 @@ impl AttributeGroup {
 
 impl IntoIterator for AttributeGroup {
     type Item = Attribute;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.members.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::ast::functions::FunctionStatement;
+    use crate::parser::ast::Statement;
+
+    fn parse_function(code: &str) -> FunctionStatement {
+        match crate::parse(code).unwrap().into_iter().nth(1) {
+            Some(Statement::Function(function)) => function,
+            statement => panic!("expected a function, found {:?}", statement),
+        }
+    }
+
+    #[test]
+    fn test_find_attribute() {
+        let function = parse_function("<?php #[Pure, \\Deprecated('no')] function foo() {}");
+
+        for name in ["Deprecated", "\\Deprecated", "deprecated", "\\DEPRECATED"] {
+            let attribute = function.find_attribute(name).unwrap();
+
+            assert_eq!(attribute.name.value, b"\\Deprecated");
+        }
+
+        assert!(function.find_attribute("Pure").is_some());
+        assert!(function.find_attribute("Deprecate").is_none());
+        assert!(function.find_attribute("Foo\\Deprecated").is_none());
+
+        let function = parse_function("<?php #[Foo\\Deprecated] function foo() {}");
+
+        assert!(function.find_attribute("Deprecated").is_none());
+        assert!(function.find_attribute("\\foo\\deprecated").is_some());
+    }
+}
@@ -0,0 +1,402 @@
+use alloc::vec::Vec;
+
+use crate::lexer::byte_string::ByteString;
+use crate::lexer::escape;
+use crate::lexer::token::Span;
+use crate::parser::ast::literals::Literal;
+use crate::parser::ast::literals::LiteralString;
+use crate::parser::ast::ArrayItem;
+use crate::parser::ast::Expression;
+use crate::parser::ast::ParenthesizedExpression;
+
+/// A name written inside a string literal, such as `bar` in `'Foo::bar'`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CallableName {
+    /// The name once escape sequences are resolved.
+    pub value: ByteString,
+    /// Where the name starts in the source code.
+    pub span: Span,
+    /// The length of the name in the source code, which is longer than the
+    /// value when the name contains escape sequences.
+    pub length: usize,
+}
+
+/// An expression that may be used as a callable, as returned by [`detect`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CallableShape<'a> {
+    /// A function name in a string, such as `'strlen'` or `'\Ns\func'`.
+    Function { name: CallableName },
+    /// A static method in a string, such as `'Foo::bar'`.
+    StaticMethod {
+        class: CallableName,
+        method: CallableName,
+    },
+    /// An object or a class followed by a method name, such as `[$foo, 'bar']`,
+    /// `[Foo::class, 'bar']` or `['Foo', 'bar']`. `class` is only set when the
+    /// class name is a string.
+    Array {
+        target: &'a Expression,
+        class: Option<CallableName>,
+        method: CallableName,
+    },
+    /// A first-class callable, such as `strlen(...)` or `$foo->bar(...)`.
+    FirstClass(&'a Expression),
+    /// A closure or an arrow function.
+    Closure(&'a Expression),
+}
+
+/// Classifies an expression as a potential callable literal.
+///
+/// `source` must be the code the expression was parsed from: names are found
+/// in string literals once escape sequences are resolved, and the source is
+/// needed to point back at the characters they were written with.
+pub fn detect<'a>(expression: &'a Expression, source: &[u8]) -> Option<CallableShape<'a>> {
+    match expression {
+        Expression::Parenthesized(ParenthesizedExpression { expr, .. }) => detect(expr, source),
+        Expression::Literal(Literal::String(literal)) => {
+            let string = DecodedString::new(literal, source)?;
+
+            match string.split_double_colon() {
+                Some((class, method)) => Some(CallableShape::StaticMethod {
+                    class: string.class_name(class)?,
+                    method: string.method_name(method)?,
+                }),
+                None => Some(CallableShape::Function {
+                    name: string.class_name(0..string.value.len())?,
+                }),
+            }
+        }
+        Expression::ShortArray(array) => array_callable(&array.items.inner, source),
+        Expression::Array(array) => array_callable(&array.items.inner, source),
+        Expression::FunctionClosureCreation(_)
+        | Expression::MethodClosureCreation(_)
+        | Expression::StaticMethodClosureCreation(_)
+        | Expression::StaticVariableMethodClosureCreation(_) => {
+            Some(CallableShape::FirstClass(expression))
+        }
+        Expression::Closure(_) | Expression::ArrowFunction(_) => {
+            Some(CallableShape::Closure(expression))
+        }
+        _ => None,
+    }
+}
+
+fn array_callable<'a>(items: &'a [ArrayItem], source: &[u8]) -> Option<CallableShape<'a>> {
+    let [ArrayItem::Value { value: target }, ArrayItem::Value { value: method }] = items else {
+        return None;
+    };
+
+    let method = match method {
+        Expression::Literal(Literal::String(literal)) => {
+            let string = DecodedString::new(literal, source)?;
+
+            string.method_name(0..string.value.len())?
+        }
+        _ => return None,
+    };
+
+    let class = match target {
+        Expression::Literal(Literal::String(literal)) => {
+            let string = DecodedString::new(literal, source)?;
+
+            Some(string.class_name(0..string.value.len())?)
+        }
+        Expression::Variable(_) => None,
+        Expression::ConstantFetch(fetch) if fetch.is_class_name() => None,
+        _ => return None,
+    };
+
+    Some(CallableShape::Array {
+        target,
+        class,
+        method,
+    })
+}
+
+// The contents of a string literal, along with the offset in the source code
+// of the escape sequence, or character, each byte was written with.
+struct DecodedString {
+    value: Vec<u8>,
+    offsets: Vec<usize>,
+    span: Span,
+}
+
+impl DecodedString {
+    // Mirrors the lexer, see `Lexer::tokenize_single_quote_string` and
+    // `Lexer::tokenize_double_quote_string`, which share the decoding of
+    // escape sequences with it.
+    fn new(literal: &LiteralString, source: &[u8]) -> Option<Self> {
+        let mut offset = literal.span.offset();
+        if let Some(b'b' | b'B') = source.get(offset) {
+            offset += 1;
+        }
+
+        let quote = *source.get(offset)?;
+        if quote != b'\'' && quote != b'"' {
+            return None;
+        }
+        offset += 1;
+
+        let mut value = vec![];
+        let mut offsets = vec![];
+        loop {
+            let rest = source.get(offset..)?;
+            let (bytes, length): (Vec<u8>, usize) = match rest {
+                [b, ..] if *b == quote => break,
+                [b'\\', b @ (b'\'' | b'\\'), ..] if quote == b'\'' => (vec![*b], 2),
+                [b, ..] if quote == b'\'' => (vec![*b], 1),
+                [b'\\', ..] => match escape::decode(rest).ok()? {
+                    Some(escape) => (escape.bytes().to_vec(), escape.width),
+                    None => (vec![b'\\'], 1),
+                },
+                [b, ..] => (vec![*b], 1),
+                [] => return None,
+            };
+
+            offsets.extend(core::iter::repeat_n(offset, bytes.len()));
+            value.extend(bytes);
+            offset += length;
+        }
+        offsets.push(offset);
+
+        Some(Self {
+            value,
+            offsets,
+            span: literal.span,
+        })
+    }
+
+    fn split_double_colon(&self) -> Option<(core::ops::Range<usize>, core::ops::Range<usize>)> {
+        let index = self.value.windows(2).position(|window| window == b"::")?;
+
+        Some((0..index, index + 2..self.value.len()))
+    }
+
+    // A possibly qualified name, such as `Foo`, `Ns\Foo` or `\Ns\Foo`.
+    fn class_name(&self, range: core::ops::Range<usize>) -> Option<CallableName> {
+        let value = self.value.get(range.clone())?;
+        let name = value.strip_prefix(b"\\").unwrap_or(value);
+        if !name.split(|b| *b == b'\\').all(is_identifier) {
+            return None;
+        }
+
+        self.name(range)
+    }
+
+    fn method_name(&self, range: core::ops::Range<usize>) -> Option<CallableName> {
+        if !is_identifier(self.value.get(range.clone())?) {
+            return None;
+        }
+
+        self.name(range)
+    }
+
+    fn name(&self, range: core::ops::Range<usize>) -> Option<CallableName> {
+        let start = *self.offsets.get(range.start)?;
+        let end = *self.offsets.get(range.end)?;
+
+        Some(CallableName {
+            value: self.value.get(range)?.into(),
+            span: self.span_at(start),
+            length: end - start,
+        })
+    }
+
+    // A name is only found when everything before it in the literal is part
+    // of a name too, so there is no new line to account for.
+    fn span_at(&self, offset: usize) -> Span {
+        let distance = offset - self.span.offset();
+
+        Span {
+            line: self.span.line,
+            column: self.span.column + distance as u32,
+            position: offset as u32,
+        }
+    }
+}
+
+fn is_identifier(name: &[u8]) -> bool {
+    match name {
+        [first, rest @ ..] => {
+            (first.is_ascii_alphabetic() || *first == b'_' || *first >= 0x80)
+                && rest
+                    .iter()
+                    .all(|b| b.is_ascii_alphanumeric() || *b == b'_' || *b >= 0x80)
+        }
+        [] => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::parser::ast::Statement;
+
+    fn expression(source: &str) -> Expression {
+        let program = crate::parse(source).unwrap();
+
+        match program.into_iter().nth(1) {
+            Some(Statement::Expression(statement)) => statement.expression,
+            statement => panic!("expected an expression statement, found {:?}", statement),
+        }
+    }
+
+    fn name(source: &str, name: &CallableName) -> (String, String) {
+        let written = &source[name.span.offset()..name.span.offset() + name.length];
+
+        (name.value.to_string(), written.to_string())
+    }
+
+    #[test]
+    fn test_function() {
+        let source = "<?php 'strlen';";
+        let Some(CallableShape::Function { name: function }) =
+            detect(&expression(source), source.as_bytes())
+        else {
+            panic!();
+        };
+
+        assert_eq!(name(source, &function), ("strlen".into(), "strlen".into()));
+        assert_eq!(function.span.column, 8);
+    }
+
+    #[test]
+    fn test_namespaced_function_with_escaped_backslashes() {
+        let source = r"<?php '\\Ns\\func';";
+        let Some(CallableShape::Function { name: function }) =
+            detect(&expression(source), source.as_bytes())
+        else {
+            panic!();
+        };
+
+        assert_eq!(
+            name(source, &function),
+            (r"\Ns\func".into(), r"\\Ns\\func".into())
+        );
+
+        // Unknown escape sequences are left as they are in single quotes.
+        let source = r"<?php '\Ns\func';";
+        let Some(CallableShape::Function { name: function }) =
+            detect(&expression(source), source.as_bytes())
+        else {
+            panic!();
+        };
+
+        assert_eq!(
+            name(source, &function),
+            (r"\Ns\func".into(), r"\Ns\func".into())
+        );
+    }
+
+    #[test]
+    fn test_static_method_with_escape_sequences() {
+        let source = r#"<?php b"\\Ns\\Foo::b\x61r";"#;
+        let Some(CallableShape::StaticMethod { class, method }) =
+            detect(&expression(source), source.as_bytes())
+        else {
+            panic!();
+        };
+
+        assert_eq!(
+            name(source, &class),
+            (r"\Ns\Foo".into(), r"\\Ns\\Foo".into())
+        );
+        assert_eq!(name(source, &method), ("bar".into(), r"b\x61r".into()));
+        assert_eq!(method.span.column, 20);
+    }
+
+    #[test]
+    fn test_octal_escape_overflow() {
+        // `\541` wraps around to `\141`, which is `a`.
+        let source = r#"<?php "\541rray_map";"#;
+        let Some(CallableShape::Function { name: function }) =
+            detect(&expression(source), source.as_bytes())
+        else {
+            panic!();
+        };
+
+        assert_eq!(
+            name(source, &function),
+            ("array_map".into(), r"\541rray_map".into())
+        );
+    }
+
+    #[test]
+    fn test_arrays() {
+        let source = "<?php [$foo, 'bar'];";
+        let array = expression(source);
+        let Some(CallableShape::Array {
+            target,
+            class: None,
+            method,
+        }) = detect(&array, source.as_bytes())
+        else {
+            panic!();
+        };
+
+        assert!(matches!(target, Expression::Variable(_)));
+        assert_eq!(name(source, &method), ("bar".into(), "bar".into()));
+
+        let source = "<?php array(Foo::class, 'bar');";
+        assert!(matches!(
+            detect(&expression(source), source.as_bytes()),
+            Some(CallableShape::Array { class: None, .. })
+        ));
+
+        let source = r#"<?php ['Ns\Foo', "bar"];"#;
+        let Some(CallableShape::Array {
+            class: Some(class), ..
+        }) = detect(&expression(source), source.as_bytes())
+        else {
+            panic!();
+        };
+
+        assert_eq!(name(source, &class), (r"Ns\Foo".into(), r"Ns\Foo".into()));
+    }
+
+    #[test]
+    fn test_closures() {
+        for source in [
+            "<?php strlen(...);",
+            "<?php $foo->bar(...);",
+            "<?php Foo::bar(...);",
+        ] {
+            assert!(matches!(
+                detect(&expression(source), source.as_bytes()),
+                Some(CallableShape::FirstClass(_))
+            ));
+        }
+
+        for source in ["<?php function () {};", "<?php (fn () => 1);"] {
+            assert!(matches!(
+                detect(&expression(source), source.as_bytes()),
+                Some(CallableShape::Closure(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_not_callables() {
+        for source in [
+            "<?php 'not a function';",
+            "<?php 'Foo::';",
+            "<?php 'Foo::bar::baz';",
+            r"<?php '\\\\Foo';",
+            "<?php [$foo, 'Ns\\bar'];",
+            "<?php [$foo, 'bar', 'baz'];",
+            "<?php [1, 'bar'];",
+            "<?php ['foo' => $foo, 'bar'];",
+            "<?php 1;",
+        ] {
+            assert_eq!(
+                detect(&expression(source), source.as_bytes()),
+                None,
+                "{}",
+                source
+            );
+        }
+    }
+}
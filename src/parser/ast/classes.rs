@@ -1,12 +1,18 @@
-use std::slice::Iter;
+use alloc::vec::Vec;
+use core::slice::Iter;
 
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::lexer::token::Span;
 use crate::node::Node;
+use crate::parser::ast::attributes::find_attribute;
+use crate::parser::ast::attributes::Attribute;
 use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::ast::comments::Comment;
+use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::constant::ClassishConstant;
 use crate::parser::ast::functions::AbstractConstructor;
 use crate::parser::ast::functions::AbstractMethod;
@@ -18,9 +24,11 @@ use crate::parser::ast::properties::Property;
 use crate::parser::ast::properties::VariableProperty;
 use crate::parser::ast::traits::TraitUsage;
 use crate::parser::ast::utils::CommaSeparated;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ClassBody {
     pub left_brace: Span, // `{`
     pub members: Vec<ClassMember>,
@@ -35,7 +43,7 @@ impl ClassBody {
 
 impl IntoIterator for ClassBody {
     type Item = ClassMember;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.members.into_iter()
@@ -43,7 +51,14 @@ impl IntoIterator for ClassBody {
 }
 
 impl Node for ClassBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.members
+            .iter()
+            .map(|member| member as &dyn Node)
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.members
             .iter_mut()
             .map(|member| member as &mut dyn Node)
@@ -51,9 +66,16 @@ impl Node for ClassBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ClassBody {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_brace, self.right_brace.after(b"}"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ClassStatement {
+    pub comments: CommentGroup,          // `/** Foo */`
     pub attributes: Vec<AttributeGroup>, // `#[Qux]`
     #[serde(flatten)]
     pub modifiers: ClassModifierGroup, // `abstract`, `final`
@@ -65,8 +87,30 @@ pub struct ClassStatement {
 }
 
 impl Node for ClassStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        if let Some(extends) = &self.extends {
+            children.push(extends);
+        }
+        if let Some(implements) = &self.implements {
+            children.push(implements);
+        }
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
         if let Some(extends) = &mut self.extends {
             children.push(extends);
         }
@@ -78,8 +122,33 @@ impl Node for ClassStatement {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ClassStatement {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or_else(|| {
+                self.modifiers
+                    .modifiers
+                    .first()
+                    .map(|modifier| modifier.span())
+            })
+            .unwrap_or(self.class);
+
+        SpanRange::new(start, self.body.right_brace.after(b"}"))
+    }
+}
+
+impl ClassStatement {
+    /// The attribute of the class named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct AnonymousClassBody {
     pub left_brace: Span, // `{`
     pub members: Vec<AnonymousClassMember>,
@@ -94,7 +163,7 @@ impl AnonymousClassBody {
 
 impl IntoIterator for AnonymousClassBody {
     type Item = AnonymousClassMember;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.members.into_iter()
@@ -102,7 +171,14 @@ impl IntoIterator for AnonymousClassBody {
 }
 
 impl Node for AnonymousClassBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.members
+            .iter()
+            .map(|member| member as &dyn Node)
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.members
             .iter_mut()
             .map(|member| member as &mut dyn Node)
@@ -110,10 +186,17 @@ impl Node for AnonymousClassBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for AnonymousClassBody {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_brace, self.right_brace.after(b"}"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct AnonymousClassExpression {
     pub attributes: Vec<AttributeGroup>,     // `#[Qux]`
+    pub readonly: Option<Span>,              // `readonly`
     pub class: Span,                         // `class`
     pub extends: Option<ClassExtends>,       // `extends Foo`
     pub implements: Option<ClassImplements>, // `implements Baz, Baz`
@@ -121,8 +204,28 @@ pub struct AnonymousClassExpression {
 }
 
 impl Node for AnonymousClassExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        if let Some(extends) = &self.extends {
+            children.push(extends);
+        }
+        if let Some(implements) = &self.implements {
+            children.push(implements);
+        }
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
         if let Some(extends) = &mut self.extends {
             children.push(extends);
         }
@@ -134,21 +237,44 @@ impl Node for AnonymousClassExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for AnonymousClassExpression {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or(self.readonly)
+            .unwrap_or(self.class);
 
+        SpanRange::new(start, self.body.right_brace.after(b"}"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ClassExtends {
     pub extends: Span,            // `extends`
     pub parent: SimpleIdentifier, // `Foo`
 }
 
 impl Node for ClassExtends {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.parent]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.parent]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ClassExtends {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.extends, self.parent.span().end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ClassImplements {
     pub implements: Span,                             // `implements`
     pub interfaces: CommaSeparated<SimpleIdentifier>, // `Bar, Baz`
@@ -162,7 +288,7 @@ impl ClassImplements {
 
 impl IntoIterator for ClassImplements {
     type Item = SimpleIdentifier;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.interfaces.into_iter()
@@ -170,12 +296,31 @@ impl IntoIterator for ClassImplements {
 }
 
 impl Node for ClassImplements {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
         self.interfaces.children()
     }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.interfaces.children_mut()
+    }
+}
+
+impl Spanned for ClassImplements {
+    fn span(&self) -> SpanRange {
+        let end = self
+            .interfaces
+            .inner
+            .last()
+            .map_or(self.implements.after(b"implements"), |interface| {
+                interface.span().end
+            });
+
+        SpanRange::new(self.implements, end)
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum ClassMember {
     Constant(ClassishConstant),
@@ -186,10 +331,25 @@ pub enum ClassMember {
     AbstractConstructor(AbstractConstructor),
     ConcreteMethod(ConcreteMethod),
     ConcreteConstructor(ConcreteConstructor),
+    Comment(Comment),
 }
 
 impl Node for ClassMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            ClassMember::Constant(constant) => vec![constant],
+            ClassMember::TraitUsage(usage) => vec![usage],
+            ClassMember::Property(property) => vec![property],
+            ClassMember::VariableProperty(property) => vec![property],
+            ClassMember::AbstractMethod(method) => vec![method],
+            ClassMember::AbstractConstructor(method) => vec![method],
+            ClassMember::ConcreteMethod(method) => vec![method],
+            ClassMember::ConcreteConstructor(method) => vec![method],
+            ClassMember::Comment(comment) => vec![comment],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             ClassMember::Constant(constant) => vec![constant],
             ClassMember::TraitUsage(usage) => vec![usage],
@@ -199,11 +359,29 @@ impl Node for ClassMember {
             ClassMember::AbstractConstructor(method) => vec![method],
             ClassMember::ConcreteMethod(method) => vec![method],
             ClassMember::ConcreteConstructor(method) => vec![method],
+            ClassMember::Comment(comment) => vec![comment],
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ClassMember {
+    fn span(&self) -> SpanRange {
+        match self {
+            ClassMember::Constant(member) => member.span(),
+            ClassMember::TraitUsage(member) => member.span(),
+            ClassMember::Property(member) => member.span(),
+            ClassMember::VariableProperty(member) => member.span(),
+            ClassMember::AbstractMethod(member) => member.span(),
+            ClassMember::AbstractConstructor(member) => member.span(),
+            ClassMember::ConcreteMethod(member) => member.span(),
+            ClassMember::ConcreteConstructor(member) => member.span(),
+            ClassMember::Comment(member) => member.span(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum AnonymousClassMember {
     Constant(ClassishConstant),
@@ -212,10 +390,11 @@ pub enum AnonymousClassMember {
     VariableProperty(VariableProperty),
     ConcreteMethod(ConcreteMethod),
     ConcreteConstructor(ConcreteConstructor),
+    Comment(Comment),
 }
 
 impl Node for AnonymousClassMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
         match self {
             AnonymousClassMember::Constant(constant) => vec![constant],
             AnonymousClassMember::TraitUsage(usage) => vec![usage],
@@ -223,6 +402,33 @@ impl Node for AnonymousClassMember {
             AnonymousClassMember::VariableProperty(property) => vec![property],
             AnonymousClassMember::ConcreteMethod(method) => vec![method],
             AnonymousClassMember::ConcreteConstructor(method) => vec![method],
+            AnonymousClassMember::Comment(comment) => vec![comment],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            AnonymousClassMember::Constant(constant) => vec![constant],
+            AnonymousClassMember::TraitUsage(usage) => vec![usage],
+            AnonymousClassMember::Property(property) => vec![property],
+            AnonymousClassMember::VariableProperty(property) => vec![property],
+            AnonymousClassMember::ConcreteMethod(method) => vec![method],
+            AnonymousClassMember::ConcreteConstructor(method) => vec![method],
+            AnonymousClassMember::Comment(comment) => vec![comment],
+        }
+    }
+}
+
+impl Spanned for AnonymousClassMember {
+    fn span(&self) -> SpanRange {
+        match self {
+            AnonymousClassMember::Constant(member) => member.span(),
+            AnonymousClassMember::TraitUsage(member) => member.span(),
+            AnonymousClassMember::Property(member) => member.span(),
+            AnonymousClassMember::VariableProperty(member) => member.span(),
+            AnonymousClassMember::ConcreteMethod(member) => member.span(),
+            AnonymousClassMember::ConcreteConstructor(member) => member.span(),
+            AnonymousClassMember::Comment(member) => member.span(),
         }
     }
 }
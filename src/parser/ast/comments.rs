@@ -1,5 +1,7 @@
-use std::slice::Iter;
+use alloc::vec::Vec;
+use core::slice::Iter;
 
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -7,8 +9,11 @@ use serde::Serialize;
 use crate::lexer::byte_string::ByteString;
 use crate::lexer::token::Span;
 use crate::node::Node;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub enum CommentFormat {
     SingleLine,
@@ -17,8 +22,8 @@ pub enum CommentFormat {
     Document,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct Comment {
     pub span: Span,
     pub format: CommentFormat,
@@ -27,8 +32,14 @@ pub struct Comment {
 
 impl Node for Comment {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Comment {
+    fn span(&self) -> SpanRange {
+        SpanRange::token(self.span, &self.content)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct CommentGroup {
     pub comments: Vec<Comment>,
 }
@@ -41,7 +52,7 @@ impl CommentGroup {
 
 impl IntoIterator for CommentGroup {
     type Item = Comment;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.comments.into_iter()
@@ -0,0 +1,155 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+/// Returns `true` if `a` and `b` have the same structure and values once every
+/// [`Span`](crate::lexer::token::Span) in them is ignored, so that the same
+/// code laid out differently compares equal.
+///
+/// Usually called with two [`Program`](crate::parser::ast::Program)s, but works
+/// with any node.
+pub fn eq_ignoring_spans<T: Serialize + ?Sized>(a: &T, b: &T) -> bool {
+    without_spans(a) == without_spans(b)
+}
+
+/// Returns a line diff of `a` and `b` with every span left out, or `None` if
+/// they are equal ignoring spans. Lines only in `a` start with `-`, lines only
+/// in `b` with `+`.
+pub fn diff_ignoring_spans<T: Serialize + ?Sized>(a: &T, b: &T) -> Option<String> {
+    let (a, b) = (without_spans(a), without_spans(b));
+    if a == b {
+        return None;
+    }
+
+    let a = serde_json::to_string_pretty(&a).unwrap_or_default();
+    let b = serde_json::to_string_pretty(&b).unwrap_or_default();
+
+    Some(diff(
+        &a.lines().collect::<Vec<_>>(),
+        &b.lines().collect::<Vec<_>>(),
+    ))
+}
+
+/// Asserts that two nodes are equal ignoring spans, printing a diff of the two
+/// otherwise.
+///
+/// ```ignore
+/// assert_ast_eq!(parse("<?php echo 1;")?, parse("<?php\n\necho   1 ;")?);
+/// ```
+#[macro_export]
+macro_rules! assert_ast_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        if let Some(diff) = $crate::parser::ast::compare::diff_ignoring_spans(&$left, &$right) {
+            panic!(
+                "assertion failed: `(left == right)` ignoring spans\n\n{}",
+                diff
+            );
+        }
+    };
+}
+
+// Spans are recognised by their shape rather than their field name, as they
+// are stored under many names (`span`, `colon`, `left_brace`, ...) and in tuple
+// variants. They are replaced rather than removed, so that `Some(span)` is
+// still told apart from `None`.
+fn without_spans<T: Serialize + ?Sized>(value: &T) -> Value {
+    fn strip(value: Value) -> Value {
+        match value {
+            Value::Object(object) if is_span(&object) => Value::Object(Map::new()),
+            Value::Object(object) => Value::Object(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (key, strip(value)))
+                    .collect(),
+            ),
+            Value::Array(values) => Value::Array(values.into_iter().map(strip).collect()),
+            value => value,
+        }
+    }
+
+    // The AST only contains types that serialize without errors.
+    strip(serde_json::to_value(value).unwrap_or(Value::Null))
+}
+
+fn is_span(object: &Map<String, Value>) -> bool {
+    object.len() == 3
+        && ["line", "column", "position"]
+            .iter()
+            .all(|key| object.get(*key).is_some_and(Value::is_u64))
+}
+
+// A longest common subsequence diff, which is plenty for the size of an AST
+// that a test would compare. Every index is bounded by the loop conditions.
+#[allow(clippy::indexing_slicing)]
+fn diff(a: &[&str], b: &[&str]) -> String {
+    let mut lengths = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, left) in a.iter().enumerate().rev() {
+        for (j, right) in b.iter().enumerate().rev() {
+            let length = if left == right {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+
+            lengths[i][j] = length;
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut output = String::new();
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            output.push_str(&format!("  {}\n", a[i]));
+            i += 1;
+            j += 1;
+        } else if j < b.len() && (i == a.len() || lengths[i][j + 1] >= lengths[i + 1][j]) {
+            output.push_str(&format!("+ {}\n", b[j]));
+            j += 1;
+        } else {
+            output.push_str(&format!("- {}\n", a[i]));
+            i += 1;
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_layout_is_ignored() {
+        let a = parse("<?php function foo(int $a) { return $a + 1; }").unwrap();
+        let b = parse("<?php\n\nfunction   foo (\n    int $a\n) {\n    return $a+1;\n}\n").unwrap();
+
+        assert_ne!(a, b);
+        assert!(eq_ignoring_spans(&a, &b));
+        assert_eq!(diff_ignoring_spans(&a, &b), None);
+        assert_ast_eq!(a, b);
+    }
+
+    #[test]
+    fn test_values_are_compared() {
+        let a = parse("<?php foo($a);").unwrap();
+        let b = parse("<?php foo($b);").unwrap();
+
+        assert!(!eq_ignoring_spans(&a, &b));
+
+        let diff = diff_ignoring_spans(&a, &b).unwrap();
+        assert!(diff.contains("- "));
+        assert!(diff.contains("+ "));
+    }
+
+    #[test]
+    fn test_optional_spans_are_compared() {
+        let a = parse("<?php function foo($a) {}").unwrap();
+        let b = parse("<?php function foo(&$a) {}").unwrap();
+
+        assert!(!eq_ignoring_spans(&a, &b));
+    }
+}
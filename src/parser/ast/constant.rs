@@ -1,19 +1,26 @@
-use std::slice::Iter;
+use alloc::vec::Vec;
+use core::slice::Iter;
 
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::lexer::token::Span;
 use crate::node::Node;
+use crate::parser::ast::attributes::find_attribute;
+use crate::parser::ast::attributes::Attribute;
 use crate::parser::ast::attributes::AttributeGroup;
 use crate::parser::ast::comments::CommentGroup;
+use crate::parser::ast::data_type::Type;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::modifiers::ConstantModifierGroup;
 use crate::parser::ast::Expression;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ConstantEntry {
     pub name: SimpleIdentifier, // `FOO`
     pub equals: Span,           // `=`
@@ -21,13 +28,23 @@ pub struct ConstantEntry {
 }
 
 impl Node for ConstantEntry {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.name, &self.value]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.name, &mut self.value]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ConstantEntry {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.name.span, self.value.span().end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ConstantStatement {
     pub comments: CommentGroup,
     pub r#const: Span,               // `const`
@@ -43,7 +60,7 @@ impl ConstantStatement {
 
 impl IntoIterator for ConstantStatement {
     type Item = ConstantEntry;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.entries.into_iter()
@@ -51,7 +68,11 @@ impl IntoIterator for ConstantStatement {
 }
 
 impl Node for ConstantStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.entries.iter().map(|e| e as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.entries
             .iter_mut()
             .map(|e| e as &mut dyn Node)
@@ -59,19 +80,31 @@ impl Node for ConstantStatement {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ConstantStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#const, self.semicolon.after(b";"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ClassishConstant {
     pub comments: CommentGroup,
-    pub attributes: Vec<AttributeGroup>,  // `#[Foo]`
-    pub modifiers: ConstantModifierGroup, // `public`
-    pub r#const: Span,                    // `const`
+    pub attributes: Vec<AttributeGroup>, // `#[Foo]`
     #[serde(flatten)]
-    pub entries: Vec<ConstantEntry>, // `FOO = 123`
-    pub semicolon: Span,                  // `;`
+    pub modifiers: ConstantModifierGroup, // `public`
+    pub r#const: Span,                   // `const`
+    pub r#type: Option<Type>,            // `int`
+    pub entries: Vec<ConstantEntry>,     // `FOO = 123`
+    pub semicolon: Span,                 // `;`
 }
 
 impl ClassishConstant {
+    /// The attribute of the constant named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+
     pub fn iter(&self) -> Iter<'_, ConstantEntry> {
         self.entries.iter()
     }
@@ -79,7 +112,7 @@ impl ClassishConstant {
 
 impl IntoIterator for ClassishConstant {
     type Item = ConstantEntry;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.entries.into_iter()
@@ -87,10 +120,47 @@ impl IntoIterator for ClassishConstant {
 }
 
 impl Node for ClassishConstant {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.entries
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        if let Some(r#type) = &self.r#type {
+            children.push(r#type);
+        }
+        children.extend(self.entries.iter().map(|e| e as &dyn Node));
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
             .iter_mut()
-            .map(|e| e as &mut dyn Node)
-            .collect()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        if let Some(r#type) = &mut self.r#type {
+            children.push(r#type);
+        }
+        children.extend(self.entries.iter_mut().map(|e| e as &mut dyn Node));
+        children
+    }
+}
+
+impl Spanned for ClassishConstant {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or_else(|| {
+                self.modifiers
+                    .modifiers
+                    .first()
+                    .map(|modifier| modifier.span())
+            })
+            .unwrap_or(self.r#const);
+
+        SpanRange::new(start, self.semicolon.after(b";"))
     }
 }
@@ -1,3 +1,7 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -6,10 +10,12 @@ use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::Ending;
 use crate::parser::ast::Expression;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 use crate::parser::ast::Statement;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct IfStatement {
     pub r#if: Span,              // `if`
     pub left_parenthesis: Span,  // `(`
@@ -19,12 +25,23 @@ pub struct IfStatement {
 }
 
 impl Node for IfStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.condition, &self.body]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.condition, &mut self.body]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for IfStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#if, self.body.span().end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum IfStatementBody {
     Statement {
@@ -43,7 +60,43 @@ pub enum IfStatementBody {
 }
 
 impl Node for IfStatementBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            IfStatementBody::Statement {
+                statement,
+                elseifs,
+                r#else,
+            } => {
+                let mut children: Vec<&dyn Node> = vec![statement.as_ref()];
+                children.extend(
+                    elseifs
+                        .iter()
+                        .map(|elseif| elseif as &dyn Node)
+                        .collect::<Vec<&dyn Node>>(),
+                );
+                if let Some(r#else) = r#else {
+                    children.push(r#else as &dyn Node);
+                }
+                children
+            }
+            IfStatementBody::Block {
+                statements,
+                elseifs,
+                r#else,
+                ..
+            } => {
+                let mut children: Vec<&dyn Node> = vec![];
+                children.extend(statements.iter().map(|statement| statement as &dyn Node));
+                children.extend(elseifs.iter().map(|elseif| elseif as &dyn Node));
+                if let Some(r#else) = r#else {
+                    children.push(r#else as &dyn Node);
+                }
+                children
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             IfStatementBody::Statement {
                 statement,
@@ -84,8 +137,31 @@ impl Node for IfStatementBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for IfStatementBody {
+    fn span(&self) -> SpanRange {
+        match self {
+            IfStatementBody::Statement {
+                statement,
+                elseifs,
+                r#else,
+            } => {
+                let end = r#else
+                    .as_ref()
+                    .map(|r#else| r#else.span())
+                    .or_else(|| elseifs.last().map(|elseif| elseif.span()))
+                    .unwrap_or_else(|| statement.span());
+
+                statement.span().to(end)
+            }
+            IfStatementBody::Block { colon, ending, .. } => {
+                SpanRange::new(*colon, ending.span().end)
+            }
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct IfStatementElseIf {
     pub elseif: Span,              // `elseif`
     pub left_parenthesis: Span,    // `(`
@@ -95,26 +171,46 @@ pub struct IfStatementElseIf {
 }
 
 impl Node for IfStatementElseIf {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.condition, self.statement.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.condition, self.statement.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for IfStatementElseIf {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.elseif, self.statement.span().end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct IfStatementElse {
     pub r#else: Span,              // `else`
     pub statement: Box<Statement>, // `*statement*`
 }
 
 impl Node for IfStatementElse {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.statement.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.statement.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for IfStatementElse {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#else, self.statement.span().end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct IfStatementElseIfBlock {
     pub elseif: Span,               // `elseif`
     pub left_parenthesis: Span,     // `(`
@@ -125,7 +221,17 @@ pub struct IfStatementElseIfBlock {
 }
 
 impl Node for IfStatementElseIfBlock {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.condition];
+        children.extend(
+            self.statements
+                .iter()
+                .map(|statement| statement as &dyn Node),
+        );
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![&mut self.condition];
         children.extend(
             self.statements
@@ -136,8 +242,19 @@ impl Node for IfStatementElseIfBlock {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for IfStatementElseIfBlock {
+    fn span(&self) -> SpanRange {
+        let end = self
+            .statements
+            .last()
+            .map_or(self.colon.after(b":"), |statement| statement.span().end);
+
+        SpanRange::new(self.elseif, end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct IfStatementElseBlock {
     pub r#else: Span,               // `else`
     pub colon: Span,                // `:`
@@ -145,10 +262,28 @@ pub struct IfStatementElseBlock {
 }
 
 impl Node for IfStatementElseBlock {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.statements
+            .iter()
+            .map(|statement| statement as &dyn Node)
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.statements
             .iter_mut()
             .map(|statement| statement as &mut dyn Node)
             .collect()
     }
 }
+
+impl Spanned for IfStatementElseBlock {
+    fn span(&self) -> SpanRange {
+        let end = self
+            .statements
+            .last()
+            .map_or(self.colon.after(b":"), |statement| statement.span().end);
+
+        SpanRange::new(self.r#else, end)
+    }
+}
@@ -1,14 +1,21 @@
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
-use std::fmt::Display;
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::Display;
 
 use crate::lexer::byte_string::ByteString;
 use crate::lexer::token::Span;
 use crate::node::Node;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum Type {
     Named(Span, ByteString),
@@ -34,6 +41,17 @@ pub enum Type {
     ParentReference(Span),
 }
 
+/// How a type that accepts `null` is written.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NullableSyntax {
+    /// `?Foo`
+    QuestionMark,
+    /// `Foo|null`, or `(A&B)|null`
+    Union,
+    /// `null` or `mixed`, which accept `null` by themselves.
+    Implicit,
+}
+
 impl Type {
     pub fn standalone(&self) -> bool {
         matches!(
@@ -42,10 +60,30 @@ impl Type {
         )
     }
 
+    /// Whether the type is written with a `?`, as in `?Foo`. See
+    /// [`Type::is_nullable`] for whether it accepts `null` at all.
     pub fn nullable(&self) -> bool {
         matches!(self, Type::Nullable(_, _))
     }
 
+    /// Whether the type accepts `null`, however it is written: `?Foo`,
+    /// `Foo|null`, `null` and `mixed` all do.
+    pub fn is_nullable(&self) -> bool {
+        self.syntax().is_some()
+    }
+
+    /// How the type says that it accepts `null`, if it does.
+    pub fn syntax(&self) -> Option<NullableSyntax> {
+        match self {
+            Type::Nullable(_, _) => Some(NullableSyntax::QuestionMark),
+            Type::Union(types) if types.iter().any(|ty| matches!(ty, Type::Null(_))) => {
+                Some(NullableSyntax::Union)
+            }
+            Type::Null(_) | Type::Mixed(_) => Some(NullableSyntax::Implicit),
+            _ => None,
+        }
+    }
+
     pub fn includes_callable(&self) -> bool {
         match &self {
             Self::Callable(_) => true,
@@ -56,6 +94,17 @@ impl Type {
         }
     }
 
+    pub fn includes_static(&self) -> bool {
+        match &self {
+            Self::StaticReference(_) => true,
+            Self::Nullable(_, inner) => inner.includes_static(),
+            Self::Union(types) | Self::Intersection(types) => {
+                types.iter().any(|x| x.includes_static())
+            }
+            _ => false,
+        }
+    }
+
     pub fn includes_class_scoped(&self) -> bool {
         match &self {
             Self::StaticReference(_) | Self::SelfReference(_) | Self::ParentReference(_) => true,
@@ -70,6 +119,8 @@ impl Type {
         matches!(self, Type::Never(_) | Type::Void(_))
     }
 
+    // The parser never produces an empty union or intersection.
+    #[allow(clippy::indexing_slicing)]
     pub fn first_span(&self) -> Span {
         match &self {
             Type::Named(span, _) => *span,
@@ -98,28 +149,12 @@ impl Type {
 }
 
 impl Display for Type {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match &self {
             Type::Named(_, inner) => write!(f, "{}", inner),
             Type::Nullable(_, inner) => write!(f, "?{}", inner),
-            Type::Union(inner) => write!(
-                f,
-                "{}",
-                inner
-                    .iter()
-                    .map(|t| t.to_string())
-                    .collect::<Vec<String>>()
-                    .join("|")
-            ),
-            Type::Intersection(inner) => write!(
-                f,
-                "{}",
-                inner
-                    .iter()
-                    .map(|t| t.to_string())
-                    .collect::<Vec<String>>()
-                    .join("&")
-            ),
+            Type::Union(inner) => write_joined(f, inner, "|"),
+            Type::Intersection(inner) => write_joined(f, inner, "&"),
             Type::Void(_) => write!(f, "void"),
             Type::Null(_) => write!(f, "null"),
             Type::True(_) => write!(f, "true"),
@@ -141,8 +176,38 @@ impl Display for Type {
     }
 }
 
+// A union nested in an intersection, or the other way around, is a
+// disjunctive normal form type such as `A|(B&C)`, which needs parentheses.
+fn write_joined(
+    f: &mut core::fmt::Formatter<'_>,
+    types: &[Type],
+    separator: &str,
+) -> core::fmt::Result {
+    for (index, ty) in types.iter().enumerate() {
+        if index > 0 {
+            write!(f, "{}", separator)?;
+        }
+
+        match ty {
+            Type::Union(_) | Type::Intersection(_) => write!(f, "({})", ty)?,
+            _ => write!(f, "{}", ty)?,
+        }
+    }
+
+    Ok(())
+}
+
 impl Node for Type {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            Type::Nullable(_, t) => vec![t.as_ref() as &dyn Node],
+            Type::Union(ts) => ts.iter().map(|x| x as &dyn Node).collect(),
+            Type::Intersection(ts) => ts.iter().map(|x| x as &dyn Node).collect(),
+            _ => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             Type::Nullable(_, t) => vec![t.as_mut() as &mut dyn Node],
             Type::Union(ts) => ts.iter_mut().map(|x| x as &mut dyn Node).collect(),
@@ -151,3 +216,87 @@ impl Node for Type {
         }
     }
 }
+
+impl Spanned for Type {
+    // The parser never produces an empty union or intersection.
+    #[allow(clippy::indexing_slicing)]
+    fn span(&self) -> SpanRange {
+        match self {
+            Type::Nullable(span, inner) => SpanRange::new(*span, inner.span().end),
+            // The parentheses of a type in disjunctive normal form, as in
+            // `(A&B)|null`, are not kept, so its range starts and ends inside them.
+            Type::Union(inner) | Type::Intersection(inner) => {
+                inner[0].span().to(inner[inner.len() - 1].span())
+            }
+            _ => SpanRange::token(self.first_span(), self.to_string().as_bytes()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NullableSyntax;
+    use super::Type;
+    use crate::parser::ast::functions::FunctionStatement;
+    use crate::parser::ast::Statement;
+
+    fn return_type(ty: &str) -> Type {
+        let program = crate::parse(&format!("<?php function foo(): {} {{}}", ty)).unwrap();
+
+        match program.get(1) {
+            Some(Statement::Function(FunctionStatement {
+                return_type: Some(return_type),
+                ..
+            })) => return_type.data_type.clone(),
+            statement => panic!("expected a function, found {:?}", statement),
+        }
+    }
+
+    fn render(ty: &str) -> String {
+        return_type(ty).to_string()
+    }
+
+    #[test]
+    fn test_display() {
+        for ty in [
+            "int",
+            "?array",
+            "null",
+            "mixed",
+            "static",
+            "Foo",
+            "\\Foo\\Bar",
+            "Foo\\Bar",
+            "int|string|null",
+            "Foo&Bar",
+            "(A&B)|null",
+            "A|(B&C)|D",
+            "(A&B)|(C&D)",
+            "(\\A&B)|C|null",
+        ] {
+            assert_eq!(render(ty), ty);
+        }
+
+        assert_eq!(render("BOOL"), "bool");
+        assert_eq!(render("Integer"), "Integer");
+    }
+
+    #[test]
+    fn test_nullable_syntax() {
+        for (ty, syntax) in [
+            ("?Foo", Some(NullableSyntax::QuestionMark)),
+            ("Foo|null", Some(NullableSyntax::Union)),
+            ("null|Foo", Some(NullableSyntax::Union)),
+            ("(A&B)|null", Some(NullableSyntax::Union)),
+            ("null", Some(NullableSyntax::Implicit)),
+            ("mixed", Some(NullableSyntax::Implicit)),
+            ("Foo", None),
+            ("Foo|false", None),
+        ] {
+            let ty = return_type(ty);
+
+            assert_eq!(ty.syntax(), syntax, "{}", ty);
+            assert_eq!(ty.is_nullable(), syntax.is_some(), "{}", ty);
+        }
+    }
+}
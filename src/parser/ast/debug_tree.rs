@@ -0,0 +1,323 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::node::Node;
+use crate::parser::ast::paths;
+use crate::parser::ast::paths::WithSpanned;
+use crate::parser::ast::Program;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
+
+/// Renders the program as an indented tree, one node per line, holding the
+/// kind of the node, its scalar fields, such as names, literal values and
+/// operators, flags such as the `&` and `...` of a parameter, and the
+/// `@line:column..line:column` source range of the node, as given by
+/// [`Spanned::span`](crate::parser::ast::Spanned::span). Nodes without a
+/// range of their own, such as array items, span their children, and tokens,
+/// such as modifiers or the `;` ending a statement, show where they start.
+/// Comma separated lists are shown as the list of their items. Spans,
+/// comments, empty lists and missing optional nodes are left out.
+///
+/// ```text
+/// Function @3:1..5:2
+///   name: SimpleIdentifier value="foo" @3:10..3:13
+///   parameters: FunctionParameterList @3:13..3:15
+///   body: FunctionBody @3:16..5:2
+///     statements[0]: Return @4:5..4:14
+///       value: Literal::Integer value="1" @4:12..4:13
+///       ending: Semicolon @4:13
+/// ```
+pub fn debug_tree(program: &Program) -> String {
+    let mut renderer = Renderer {
+        output: String::new(),
+        ranges: BTreeMap::new(),
+        name: "",
+    };
+
+    for statement in program {
+        renderer.collect(statement);
+    }
+
+    // The AST only contains types that serialize without errors.
+    let value = serde_json::to_value(program).unwrap_or(Value::Null);
+    for statement in value.as_array().into_iter().flatten() {
+        renderer.tree(None, statement, 0);
+    }
+
+    renderer.output
+}
+
+struct Renderer {
+    output: String,
+    // The range, the name and the serialized form of the spanned nodes, by
+    // the earliest span in their serialized form.
+    ranges: BTreeMap<u64, Vec<(SpanRange, &'static str, Value)>>,
+    // The name of the node handed to `with`.
+    name: &'static str,
+}
+
+impl WithSpanned for Renderer {
+    fn with<T: Spanned + Serialize>(&mut self, node: &T) {
+        let value = serde_json::to_value(node).unwrap_or(Value::Null);
+        if let Some((position, _, _)) = start(&value) {
+            self.ranges
+                .entry(position)
+                .or_default()
+                .push((node.span(), self.name, value));
+        }
+    }
+}
+
+impl Renderer {
+    fn collect(&mut self, node: &dyn Node) {
+        self.name = node.name();
+        paths::with_spanned(node, self);
+
+        for child in node.children() {
+            self.collect(child);
+        }
+    }
+
+    // The range and the name of the node that serializes to the value, if it
+    // is spanned.
+    fn spanned(&self, value: &Value) -> Option<(SpanRange, &'static str)> {
+        let (position, _, _) = start(value)?;
+
+        self.ranges
+            .get(&position)?
+            .iter()
+            .find(|(_, _, node)| node == value)
+            .map(|(range, name, _)| (*range, *name))
+    }
+
+    // The range spanned by the spanned nodes within the value.
+    fn extent(&self, value: &Value) -> Option<SpanRange> {
+        if let Some((range, _)) = self.spanned(value) {
+            return Some(range);
+        }
+
+        match value {
+            Value::Object(object) => object.values().fold(None, |extent, value| {
+                paths::union(extent, self.extent(value))
+            }),
+            Value::Array(items) => items.iter().fold(None, |extent, value| {
+                paths::union(extent, self.extent(value))
+            }),
+            _ => None,
+        }
+    }
+
+    // The tree is rendered from the JSON representation of the AST, which gives a
+    // uniform view of every node.
+    //
+    // Enum variants are serialized as `{"type": ..., "value": ...}`, which gives
+    // the kind of a node. Nested variants, such as `Literal(Literal::Integer(..))`,
+    // are rendered as `Literal::Integer`. Statement structs also carry their own
+    // name as `type`, which is left out when the variant already names them.
+    fn tree(&mut self, label: Option<&str>, value: &Value, depth: usize) {
+        // The variants of a wrapper, such as `Statement`, share its range.
+        let spanned = self.spanned(value);
+        let mut range = spanned.map(|(range, _)| range);
+        let mut kinds = vec![];
+        let mut value = value;
+        while let Some(object) = value.as_object() {
+            let Some(kind) = object.get("type").and_then(Value::as_str) else {
+                break;
+            };
+
+            match object.get("value") {
+                Some(inner) if object.len() == 2 => {
+                    kinds.push(kind);
+                    value = inner;
+                    range = range.or_else(|| self.spanned(value).map(|(range, _)| range));
+                }
+                _ => {
+                    if kinds.is_empty() || object.len() == 1 {
+                        kinds.push(kind);
+                    }
+
+                    break;
+                }
+            }
+        }
+
+        // Nodes that are not variants are named after their type, and nodes
+        // without a range of their own span their children.
+        if kinds.is_empty() {
+            kinds.extend(spanned.map(|(_, name)| name));
+        }
+        let range = range.or_else(|| self.extent(value));
+
+        let mut node = TreeNode::default();
+        match value {
+            Value::Object(object) if is_span(object) => {}
+            Value::Object(object) => {
+                for (key, field) in object.iter().filter(|(key, _)| *key != "type") {
+                    node.push(Some(key), field);
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    node.push(None, item);
+                }
+            }
+            value => node.push(None, value),
+        }
+
+        let location = match range {
+            Some(range) => Some(format!(
+                "@{}:{}..{}:{}",
+                range.start.line, range.start.column, range.end.line, range.end.column
+            )),
+            None => start(value).map(|(_, line, column)| format!("@{}:{}", line, column)),
+        };
+
+        let mut line = "  ".repeat(depth);
+        if let Some(label) = label {
+            line.push_str(label);
+            line.push(':');
+        }
+
+        for part in core::iter::once(kinds.join("::"))
+            .chain(node.fields)
+            .chain(location)
+            .filter(|part| !part.is_empty())
+        {
+            if !line.trim_start().is_empty() {
+                line.push(' ');
+            }
+
+            line.push_str(&part);
+        }
+
+        self.output.push_str(&line);
+        self.output.push('\n');
+
+        for (key, child) in node.children {
+            match (key, child) {
+                (Some(key), Value::Array(items)) => {
+                    for (index, item) in items.iter().enumerate() {
+                        let label = format!("{}[{}]", key, index);
+                        self.tree(Some(&label), item, depth + 1);
+                    }
+                }
+                (None, Value::Array(items)) => {
+                    for item in items {
+                        self.tree(None, item, depth + 1);
+                    }
+                }
+                (key, child) => self.tree(key, child, depth + 1),
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct TreeNode<'a> {
+    fields: Vec<String>,
+    children: Vec<(Option<&'a str>, &'a Value)>,
+}
+
+impl<'a> TreeNode<'a> {
+    fn push(&mut self, key: Option<&'a str>, value: &'a Value) {
+        match value {
+            // Flags that are only recorded as the span of their token.
+            Value::Object(object) if is_span(object) => match key {
+                Some("ampersand") => self.fields.push("&".to_string()),
+                Some("ellipsis") => self.fields.push("...".to_string()),
+                _ => {}
+            },
+            Value::String(_) | Value::Number(_) | Value::Bool(_) => match key {
+                Some(key) => self.fields.push(format!("{}={}", key, value)),
+                None => self.fields.push(value.to_string()),
+            },
+            // The `(...)` of a first-class callable.
+            Value::Object(_) if key == Some("placeholder") => self.fields.push("(...)".to_string()),
+            // Comments are not part of the tree.
+            Value::Object(_) if key == Some("comments") => {}
+            // Strings that are not valid UTF-8.
+            Value::Object(object)
+                if object.len() == 1 && object.get("base64").is_some_and(Value::is_string) =>
+            {
+                let encoded = &object["base64"];
+                match key {
+                    Some(key) => self.fields.push(format!("{}=base64:{}", key, encoded)),
+                    None => self.fields.push(format!("base64:{}", encoded)),
+                }
+            }
+            Value::Null => {}
+            // Comma separated lists are rendered as the list of their items.
+            Value::Object(object) if is_comma_separated(object) => {
+                self.children.push((key, &object["inner"]))
+            }
+            // Lists of tokens, such as the commas between arguments.
+            Value::Array(items)
+                if items
+                    .iter()
+                    .all(|item| item.as_object().is_some_and(is_span)) => {}
+            // Groups that only hold empty lists, such as a group without comments.
+            Value::Object(object)
+                if object
+                    .values()
+                    .all(|value| value.as_array().is_some_and(Vec::is_empty)) => {}
+            _ => self.children.push((key, value)),
+        }
+    }
+}
+
+fn is_span(object: &Map<String, Value>) -> bool {
+    object.len() == 3
+        && ["line", "column", "position"]
+            .iter()
+            .all(|key| object.get(*key).is_some_and(Value::is_u64))
+}
+
+fn is_comma_separated(object: &Map<String, Value>) -> bool {
+    object.len() == 2
+        && object.get("inner").is_some_and(Value::is_array)
+        && object.contains_key("commas")
+}
+
+// The earliest span found anywhere in the value, as a `(position, line,
+// column)` triple.
+fn start(value: &Value) -> Option<(u64, u64, u64)> {
+    match value {
+        Value::Object(object) if is_span(object) => {
+            let field = |key: &str| object.get(key).and_then(Value::as_u64);
+
+            Some((field("position")?, field("line")?, field("column")?))
+        }
+        Value::Object(object) => object.values().filter_map(start).min(),
+        Value::Array(items) => items.iter().filter_map(start).min(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_debug_tree() {
+        let program = parse("<?php\n\nfunction foo() {\n    return 1;\n}\n").unwrap();
+
+        assert_eq!(
+            debug_tree(&program),
+            "FullOpeningTag @1:1..1:6\n\
+             Function @3:1..5:2\n  \
+               name: SimpleIdentifier value=\"foo\" @3:10..3:13\n  \
+               parameters: FunctionParameterList @3:13..3:15\n  \
+               body: FunctionBody @3:16..5:2\n    \
+                 statements[0]: Return @4:5..4:14\n      \
+                   value: Literal::Integer value=\"1\" @4:12..4:13\n      \
+                   ending: Semicolon @4:13\n"
+        );
+    }
+}
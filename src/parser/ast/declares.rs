@@ -1,3 +1,6 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -6,11 +9,15 @@ use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::literals::Literal;
+use crate::parser::ast::namespaces::NamespaceStatement;
+use crate::parser::ast::Ending;
 use crate::parser::ast::Expression;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 use crate::parser::ast::Statement;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct DeclareEntry {
     pub key: SimpleIdentifier, // `strict_types`
     pub equals: Span,          // `=`
@@ -18,13 +25,23 @@ pub struct DeclareEntry {
 }
 
 impl Node for DeclareEntry {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.key, &self.value]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.key, &mut self.value]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for DeclareEntry {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.key.span, self.value.span().end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct DeclareEntryGroup {
     pub left_parenthesis: Span,     // `(`
     pub right_parenthesis: Span,    // `)`
@@ -32,7 +49,11 @@ pub struct DeclareEntryGroup {
 }
 
 impl Node for DeclareEntryGroup {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.entries.iter().map(|e| e as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.entries
             .iter_mut()
             .map(|e| e as &mut dyn Node)
@@ -40,7 +61,15 @@ impl Node for DeclareEntryGroup {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for DeclareEntryGroup {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_parenthesis, self.right_parenthesis.after(b")"))
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum DeclareBody {
     // declaration is terminated with `;`
@@ -58,16 +87,30 @@ pub enum DeclareBody {
         expression: Expression, // `*expression*`
         semicolon: Span,        // `;`
     },
-    // declaration is followed by a `:` and terminated with `enddeclare` and `;` after multiple statements.
+    // declaration is followed by a `:` and terminated with `enddeclare` and `;` or `?>` after multiple statements.
     Block {
         colon: Span,                // `:`
         statements: Vec<Statement>, // `*statements*`
-        end: (Span, Span),          // `enddeclare` + `;`
+        enddeclare: Span,           // `enddeclare`
+        ending: Ending,             // `;` or `?>`
     },
 }
 
 impl Node for DeclareBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            DeclareBody::Noop { .. } => vec![],
+            DeclareBody::Braced { statements, .. } => {
+                statements.iter().map(|s| s as &dyn Node).collect()
+            }
+            DeclareBody::Expression { expression, .. } => vec![expression],
+            DeclareBody::Block { statements, .. } => {
+                statements.iter().map(|s| s as &dyn Node).collect()
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             DeclareBody::Noop { .. } => vec![],
             DeclareBody::Braced { statements, .. } => {
@@ -81,8 +124,26 @@ impl Node for DeclareBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for DeclareBody {
+    fn span(&self) -> SpanRange {
+        match self {
+            DeclareBody::Noop { semicolon } => SpanRange::token(*semicolon, b";"),
+            DeclareBody::Braced {
+                left_brace,
+                right_brace,
+                ..
+            } => SpanRange::new(*left_brace, right_brace.after(b"}")),
+            DeclareBody::Expression {
+                expression,
+                semicolon,
+            } => SpanRange::new(expression.span().start, semicolon.after(b";")),
+            DeclareBody::Block { colon, ending, .. } => SpanRange::new(*colon, ending.span().end),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct DeclareStatement {
     pub declare: Span,              // `declare`
     pub entries: DeclareEntryGroup, // `(strict_types = 1)`
@@ -90,7 +151,158 @@ pub struct DeclareStatement {
 }
 
 impl Node for DeclareStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.entries, &self.body]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.entries, &mut self.body]
     }
 }
+
+impl Spanned for DeclareStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.declare, self.body.span().end)
+    }
+}
+
+// Like PHP, only a `declare(strict_types=...);` that is the very first
+// statement of the file counts.
+pub(crate) fn strict_types(statements: &[Statement]) -> Option<bool> {
+    let first = statements.iter().find(|statement| {
+        !matches!(
+            statement,
+            Statement::FullOpeningTag(_) | Statement::ShortOpeningTag(_) | Statement::Comment(_)
+        )
+    })?;
+
+    let declare = match first {
+        Statement::Declare(
+            declare @ DeclareStatement {
+                body: DeclareBody::Noop { .. },
+                ..
+            },
+        ) => declare,
+        _ => return None,
+    };
+
+    declare
+        .entries
+        .entries
+        .iter()
+        .find(|entry| entry.key.value.eq_ignore_ascii_case(b"strict_types"))
+        .and_then(|entry| match &entry.value {
+            // `0`, `0x0`, `0b0`, etc. all disable strict typing, and any
+            // other value is an error.
+            Literal::Integer(integer) => match integer.to_i64() {
+                Some(0) => Some(false),
+                Some(1) => Some(true),
+                _ => None,
+            },
+            _ => None,
+        })
+}
+
+// Whether the value is one PHP accepts for `strict_types`, an integer that is
+// either 0 or 1.
+pub(crate) fn is_strict_types_value(value: &Literal) -> bool {
+    matches!(value, Literal::Integer(integer) if matches!(integer.to_i64(), Some(0 | 1)))
+}
+
+pub(crate) fn declares(statements: &[Statement]) -> impl Iterator<Item = &DeclareEntry> {
+    statements
+        .iter()
+        .flat_map(|statement| match statement {
+            Statement::Namespace(NamespaceStatement::Unbraced(namespace)) => {
+                namespace.statements.iter().collect()
+            }
+            Statement::Namespace(NamespaceStatement::Braced(namespace)) => {
+                namespace.body.statements.iter().collect()
+            }
+            statement => vec![statement],
+        })
+        .filter_map(|statement| match statement {
+            Statement::Declare(declare) => Some(declare.entries.entries.iter()),
+            _ => None,
+        })
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_types() {
+        let strict_types = |code: &str| crate::parse(code).unwrap().strict_types();
+
+        assert_eq!(strict_types("<?php declare(strict_types=1);"), Some(true));
+        assert_eq!(strict_types("<?php declare(strict_types=0);"), Some(false));
+        assert_eq!(
+            strict_types("<?php declare(strict_types=0x0);"),
+            Some(false)
+        );
+        assert_eq!(strict_types("<?php declare(strict_types=0b1);"), Some(true));
+        assert_eq!(
+            strict_types("<?php // header\ndeclare(ticks=1, strict_types=1);"),
+            Some(true)
+        );
+        assert_eq!(strict_types("<?php echo 1;"), None);
+        assert_eq!(strict_types("<?php declare(ticks=1);"), None);
+        assert_eq!(strict_types("<?php echo 1; declare(strict_types=1);"), None);
+        assert_eq!(strict_types("<?php declare(strict_types=1) { }"), None);
+        assert_eq!(
+            strict_types("<?php declare(strict_types=1): enddeclare;"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_edit_updates_strict_types() {
+        let mut program = crate::parse("<?php declare(strict_types=1); echo 1;").unwrap();
+        assert_eq!(program.strict_types(), Some(true));
+
+        program.edit(|statements| {
+            statements.retain(|statement| !matches!(statement, Statement::Declare(_)))
+        });
+        assert_eq!(program.strict_types(), None);
+        assert_eq!(program.declares().count(), 0);
+    }
+
+    #[test]
+    fn test_invalid_strict_types() {
+        for code in [
+            "<?php declare(strict_types=2);",
+            "<?php declare(strict_types=0xB);",
+            "<?php declare(strict_types='1');",
+            "<?php declare(strict_types=1.0);",
+        ] {
+            let Err(crate::Error::Parse(stack)) = crate::parse(code) else {
+                panic!("expected `{}` to fail", code);
+            };
+
+            let ids = stack
+                .errors
+                .iter()
+                .map(|error| error.id.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(ids, vec!["E093"], "{}", code);
+            assert_eq!(stack.partial.strict_types(), None, "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_declares() {
+        let program = crate::parse(
+            "<?php declare(strict_types=1); namespace Foo; declare(ticks=1, encoding='UTF-8');",
+        )
+        .unwrap();
+
+        let keys = program
+            .declares()
+            .map(|entry| entry.key.value.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(keys, vec!["strict_types", "ticks", "encoding"]);
+    }
+}
@@ -1,20 +1,30 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::lexer::token::Span;
 use crate::node::Node;
+use crate::parser::ast::attributes::find_attribute;
+use crate::parser::ast::attributes::Attribute;
 use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::ast::comments::Comment;
+use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::constant::ClassishConstant;
 use crate::parser::ast::functions::ConcreteMethod;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::Expression;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
 use super::traits::TraitUsage;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct UnitEnumCase {
+    pub comments: CommentGroup,          // `/** Foo */`
     pub attributes: Vec<AttributeGroup>, // `#[Foo]`
     pub start: Span,                     // `case`
     pub name: SimpleIdentifier,          // `Bar`
@@ -22,33 +32,93 @@ pub struct UnitEnumCase {
 }
 
 impl Node for UnitEnumCase {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name]
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
+        children
+    }
+}
+
+impl Spanned for UnitEnumCase {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.start, |attribute| attribute.start);
+
+        SpanRange::new(start, self.end.after(b";"))
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl UnitEnumCase {
+    /// The attribute of the case named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum UnitEnumMember {
     Case(UnitEnumCase),         // `case Bar;`
     Method(ConcreteMethod),     // `public function foo(): void { ... }`
     Constant(ClassishConstant), // `public const FOO = 123;`
     TraitUsage(TraitUsage),
+    Comment(Comment), // `// foo` before the closing brace
 }
 
 impl Node for UnitEnumMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            UnitEnumMember::Case(case) => vec![case],
+            UnitEnumMember::Method(method) => vec![method],
+            UnitEnumMember::Constant(constant) => vec![constant],
+            UnitEnumMember::TraitUsage(trait_usage) => vec![trait_usage],
+            UnitEnumMember::Comment(comment) => vec![comment],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             UnitEnumMember::Case(case) => vec![case],
             UnitEnumMember::Method(method) => vec![method],
             UnitEnumMember::Constant(constant) => vec![constant],
             UnitEnumMember::TraitUsage(trait_usage) => vec![trait_usage],
+            UnitEnumMember::Comment(comment) => vec![comment],
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for UnitEnumMember {
+    fn span(&self) -> SpanRange {
+        match self {
+            UnitEnumMember::Case(member) => member.span(),
+            UnitEnumMember::Method(member) => member.span(),
+            UnitEnumMember::Constant(member) => member.span(),
+            UnitEnumMember::TraitUsage(member) => member.span(),
+            UnitEnumMember::Comment(member) => member.span(),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct UnitEnumBody {
     pub left_brace: Span,             // `{`
     pub members: Vec<UnitEnumMember>, // `...`
@@ -56,7 +126,11 @@ pub struct UnitEnumBody {
 }
 
 impl Node for UnitEnumBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.members.iter().map(|m| m as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.members
             .iter_mut()
             .map(|m| m as &mut dyn Node)
@@ -64,9 +138,16 @@ impl Node for UnitEnumBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for UnitEnumBody {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_brace, self.right_brace.after(b"}"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct UnitEnumStatement {
+    pub comments: CommentGroup,            // `/** Foo */`
     pub attributes: Vec<AttributeGroup>,   // `#[Foo]`
     pub r#enum: Span,                      // `enum`
     pub name: SimpleIdentifier,            // `Foo`
@@ -75,8 +156,27 @@ pub struct UnitEnumStatement {
 }
 
 impl Node for UnitEnumStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        for implement in &self.implements {
+            children.push(implement);
+        }
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
         for implement in &mut self.implements {
             children.push(implement);
         }
@@ -85,7 +185,26 @@ impl Node for UnitEnumStatement {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+impl Spanned for UnitEnumStatement {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.r#enum, |attribute| attribute.start);
+
+        SpanRange::new(start, self.body.right_brace.after(b"}"))
+    }
+}
+
+impl UnitEnumStatement {
+    /// The attribute of the enum named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum BackedEnumType {
     String(Span, Span), // `:` + `string`
@@ -96,9 +215,19 @@ impl Node for BackedEnumType {
     //
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for BackedEnumType {
+    fn span(&self) -> SpanRange {
+        match self {
+            BackedEnumType::String(colon, span) => SpanRange::new(*colon, span.after(b"string")),
+            BackedEnumType::Int(colon, span) => SpanRange::new(*colon, span.after(b"int")),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct BackedEnumCase {
+    pub comments: CommentGroup,          // `/** Foo */`
     pub attributes: Vec<AttributeGroup>, // `#[Foo]`
     pub case: Span,                      // `case`
     pub name: SimpleIdentifier,          // `Bar`
@@ -108,33 +237,94 @@ pub struct BackedEnumCase {
 }
 
 impl Node for BackedEnumCase {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name, &mut self.value]
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        children.push(&self.value);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
+        children.push(&mut self.value);
+        children
+    }
+}
+
+impl Spanned for BackedEnumCase {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.case, |attribute| attribute.start);
+
+        SpanRange::new(start, self.semicolon.after(b";"))
+    }
+}
+
+impl BackedEnumCase {
+    /// The attribute of the case named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum BackedEnumMember {
     Case(BackedEnumCase),
     Method(ConcreteMethod),
     Constant(ClassishConstant),
     TraitUsage(TraitUsage),
+    Comment(Comment),
 }
 
 impl Node for BackedEnumMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            BackedEnumMember::Case(case) => vec![case],
+            BackedEnumMember::Method(method) => vec![method],
+            BackedEnumMember::Constant(constant) => vec![constant],
+            BackedEnumMember::TraitUsage(trait_usage) => vec![trait_usage],
+            BackedEnumMember::Comment(comment) => vec![comment],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             BackedEnumMember::Case(case) => vec![case],
             BackedEnumMember::Method(method) => vec![method],
             BackedEnumMember::Constant(constant) => vec![constant],
             BackedEnumMember::TraitUsage(trait_usage) => vec![trait_usage],
+            BackedEnumMember::Comment(comment) => vec![comment],
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for BackedEnumMember {
+    fn span(&self) -> SpanRange {
+        match self {
+            BackedEnumMember::Case(member) => member.span(),
+            BackedEnumMember::Method(member) => member.span(),
+            BackedEnumMember::Constant(member) => member.span(),
+            BackedEnumMember::TraitUsage(member) => member.span(),
+            BackedEnumMember::Comment(member) => member.span(),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct BackedEnumBody {
     pub left_brace: Span,               // `{`
     pub members: Vec<BackedEnumMember>, // `...`
@@ -142,7 +332,11 @@ pub struct BackedEnumBody {
 }
 
 impl Node for BackedEnumBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.members.iter().map(|m| m as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.members
             .iter_mut()
             .map(|m| m as &mut dyn Node)
@@ -150,9 +344,16 @@ impl Node for BackedEnumBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for BackedEnumBody {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_brace, self.right_brace.after(b"}"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct BackedEnumStatement {
+    pub comments: CommentGroup,            // `/** Foo */`
     pub attributes: Vec<AttributeGroup>,   // `#[Foo]`
     pub r#enum: Span,                      // `enum`
     pub name: SimpleIdentifier,            // `Foo`
@@ -162,8 +363,29 @@ pub struct BackedEnumStatement {
 }
 
 impl Node for BackedEnumStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name, &mut self.backed_type];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        children.push(&self.backed_type);
+        for implement in &self.implements {
+            children.push(implement);
+        }
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
+        children.push(&mut self.backed_type);
         for implement in &mut self.implements {
             children.push(implement);
         }
@@ -171,3 +393,21 @@ impl Node for BackedEnumStatement {
         children
     }
 }
+
+impl Spanned for BackedEnumStatement {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.r#enum, |attribute| attribute.start);
+
+        SpanRange::new(start, self.body.right_brace.after(b"}"))
+    }
+}
+
+impl BackedEnumStatement {
+    /// The attribute of the enum named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+}
@@ -1,37 +1,55 @@
-use std::slice::Iter;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::slice::Iter;
 
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::lexer::token::Span;
 use crate::node::Node;
+use crate::parser::ast::attributes::find_attribute;
+use crate::parser::ast::attributes::Attribute;
 use crate::parser::ast::attributes::AttributeGroup;
 use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::data_type::Type;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::modifiers::MethodModifierGroup;
 use crate::parser::ast::modifiers::PromotedPropertyModifierGroup;
+use crate::parser::ast::properties::PropertyHookList;
 use crate::parser::ast::utils::CommaSeparated;
 use crate::parser::ast::variables::SimpleVariable;
 use crate::parser::ast::Expression;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 use crate::parser::ast::Statement;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ReturnType {
     pub colon: Span,
     pub data_type: Type,
 }
 
 impl Node for ReturnType {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.data_type]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.data_type]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ReturnType {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.colon, self.data_type.span().end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct FunctionParameter {
     pub comments: CommentGroup,
     pub name: SimpleVariable,
@@ -43,8 +61,29 @@ pub struct FunctionParameter {
 }
 
 impl Node for FunctionParameter {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        if let Some(data_type) = &self.data_type {
+            children.push(data_type);
+        }
+        if let Some(default) = &self.default {
+            children.push(default);
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
         if let Some(data_type) = &mut self.data_type {
             children.push(data_type);
         }
@@ -55,8 +94,38 @@ impl Node for FunctionParameter {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for FunctionParameter {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or_else(|| {
+                self.data_type
+                    .as_ref()
+                    .map(|data_type| data_type.span().start)
+            })
+            .or(self.ampersand)
+            .or(self.ellipsis)
+            .unwrap_or(self.name.span);
+        let end = self
+            .default
+            .as_ref()
+            .map_or(self.name.span().end, |default| default.span().end);
+
+        SpanRange::new(start, end)
+    }
+}
 
+impl FunctionParameter {
+    /// Whether the parameter is passed by reference, as in `function foo(&$a)`.
+    pub fn is_by_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct FunctionParameterList {
     pub comments: CommentGroup,
     pub left_parenthesis: Span,
@@ -72,7 +141,7 @@ impl FunctionParameterList {
 
 impl IntoIterator for FunctionParameterList {
     type Item = FunctionParameter;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.parameters.into_iter()
@@ -80,13 +149,23 @@ impl IntoIterator for FunctionParameterList {
 }
 
 impl Node for FunctionParameterList {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
         self.parameters.children()
     }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.parameters.children_mut()
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for FunctionParameterList {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_parenthesis, self.right_parenthesis.after(b")"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct FunctionBody {
     pub comments: CommentGroup,
     pub left_brace: Span,
@@ -95,7 +174,11 @@ pub struct FunctionBody {
 }
 
 impl Node for FunctionBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.statements.iter().map(|x| x as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.statements
             .iter_mut()
             .map(|x| x as &mut dyn Node)
@@ -103,8 +186,14 @@ impl Node for FunctionBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for FunctionBody {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_brace, self.right_brace.after(b"}"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct FunctionStatement {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -117,9 +206,30 @@ pub struct FunctionStatement {
 }
 
 impl Node for FunctionStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> =
-            vec![&mut self.name, &mut self.parameters, &mut self.body];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        children.push(&self.parameters);
+        children.push(&self.body);
+        if let Some(return_type) = &self.return_type {
+            children.push(return_type);
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
+        children.push(&mut self.parameters);
+        children.push(&mut self.body);
         if let Some(return_type) = &mut self.return_type {
             children.push(return_type);
         }
@@ -127,8 +237,31 @@ impl Node for FunctionStatement {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for FunctionStatement {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.function, |attribute| attribute.start);
+
+        SpanRange::new(start, self.body.right_brace.after(b"}"))
+    }
+}
 
+impl FunctionStatement {
+    /// The attribute of the function named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+
+    /// Whether the function returns by reference, as in `function &foo()`.
+    pub fn returns_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ClosureUseVariable {
     pub comments: CommentGroup,
     pub ampersand: Option<Span>,
@@ -136,13 +269,33 @@ pub struct ClosureUseVariable {
 }
 
 impl Node for ClosureUseVariable {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.variable]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.variable]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ClosureUseVariable {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(
+            self.ampersand.unwrap_or(self.variable.span),
+            self.variable.span().end,
+        )
+    }
+}
+
+impl ClosureUseVariable {
+    /// Whether the variable is passed by reference, as in `use (&$a)`.
+    pub fn is_by_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ClosureUse {
     pub comments: CommentGroup,
     pub r#use: Span,
@@ -152,13 +305,23 @@ pub struct ClosureUse {
 }
 
 impl Node for ClosureUse {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
         self.variables.children()
     }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.variables.children_mut()
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ClosureUse {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#use, self.right_parenthesis.after(b")"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ClosureExpression {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -172,8 +335,30 @@ pub struct ClosureExpression {
 }
 
 impl Node for ClosureExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.parameters];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.parameters);
+        if let Some(uses) = &self.uses {
+            children.push(uses);
+        }
+        if let Some(return_type) = &self.return_type {
+            children.push(return_type);
+        }
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.parameters);
         if let Some(uses) = &mut self.uses {
             children.push(uses);
         }
@@ -185,8 +370,28 @@ impl Node for ClosureExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ClosureExpression {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or(self.r#static)
+            .unwrap_or(self.function);
+
+        SpanRange::new(start, self.body.right_brace.after(b"}"))
+    }
+}
+
+impl ClosureExpression {
+    /// Whether the closure returns by reference, as in `function &()`.
+    pub fn returns_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ArrowFunctionExpression {
     pub comments: CommentGroup,
     pub r#static: Option<Span>,
@@ -200,8 +405,27 @@ pub struct ArrowFunctionExpression {
 }
 
 impl Node for ArrowFunctionExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.parameters];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.parameters);
+        if let Some(return_type) = &self.return_type {
+            children.push(return_type);
+        }
+        children.push(self.body.as_ref());
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.parameters);
         if let Some(return_type) = &mut self.return_type {
             children.push(return_type);
         }
@@ -210,8 +434,28 @@ impl Node for ArrowFunctionExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ArrowFunctionExpression {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or(self.r#static)
+            .unwrap_or(self.r#fn);
+
+        SpanRange::new(start, self.body.span().end)
+    }
+}
+
+impl ArrowFunctionExpression {
+    /// Whether the arrow function returns by reference, as in `fn &() =>`.
+    pub fn returns_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ConstructorParameter {
     pub attributes: Vec<AttributeGroup>,
     pub comments: CommentGroup,
@@ -222,23 +466,91 @@ pub struct ConstructorParameter {
     pub default: Option<Expression>,
     #[serde(flatten)]
     pub modifiers: PromotedPropertyModifierGroup,
+    /// The hooks of the promoted property, as in
+    /// `public function __construct(public string $name { get => $this->name; })`.
+    pub hooks: Option<PropertyHookList>,
 }
 
 impl Node for ConstructorParameter {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        if let Some(data_type) = &self.data_type {
+            children.push(data_type);
+        }
+        if let Some(default) = &self.default {
+            children.push(default);
+        }
+        if let Some(hooks) = &self.hooks {
+            children.push(hooks);
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
         if let Some(data_type) = &mut self.data_type {
             children.push(data_type);
         }
         if let Some(default) = &mut self.default {
             children.push(default);
         }
+        if let Some(hooks) = &mut self.hooks {
+            children.push(hooks);
+        }
         children
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ConstructorParameter {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or_else(|| {
+                self.modifiers
+                    .modifiers
+                    .first()
+                    .map(|modifier| modifier.span())
+            })
+            .or_else(|| {
+                self.data_type
+                    .as_ref()
+                    .map(|data_type| data_type.span().start)
+            })
+            .or(self.ampersand)
+            .or(self.ellipsis)
+            .unwrap_or(self.name.span);
+        let end = self
+            .hooks
+            .as_ref()
+            .map(|hooks| hooks.right_brace.after(b"}"))
+            .or_else(|| self.default.as_ref().map(|default| default.span().end))
+            .unwrap_or(self.name.span().end);
+
+        SpanRange::new(start, end)
+    }
+}
+
+impl ConstructorParameter {
+    /// Whether the parameter is passed by reference, as in `function __construct(&$a)`.
+    pub fn is_by_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ConstructorParameterList {
     pub comments: CommentGroup,
     pub left_parenthesis: Span,
@@ -247,13 +559,23 @@ pub struct ConstructorParameterList {
 }
 
 impl Node for ConstructorParameterList {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
         self.parameters.children()
     }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.parameters.children_mut()
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ConstructorParameterList {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_parenthesis, self.right_parenthesis.after(b")"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct AbstractConstructor {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -269,13 +591,56 @@ pub struct AbstractConstructor {
 }
 
 impl Node for AbstractConstructor {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name, &mut self.parameters]
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        children.push(&self.parameters);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
+        children.push(&mut self.parameters);
+        children
+    }
+}
+
+impl Spanned for AbstractConstructor {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or_else(|| {
+                self.modifiers
+                    .modifiers
+                    .first()
+                    .map(|modifier| modifier.span())
+            })
+            .unwrap_or(self.function);
+
+        SpanRange::new(start, self.semicolon.after(b";"))
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl AbstractConstructor {
+    /// The attribute of the constructor named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ConcreteConstructor {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -291,12 +656,55 @@ pub struct ConcreteConstructor {
 }
 
 impl Node for ConcreteConstructor {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name, &mut self.parameters, &mut self.body]
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        children.push(&self.parameters);
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
+        children.push(&mut self.parameters);
+        children.push(&mut self.body);
+        children
+    }
+}
+
+impl Spanned for ConcreteConstructor {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or_else(|| {
+                self.modifiers
+                    .modifiers
+                    .first()
+                    .map(|modifier| modifier.span())
+            })
+            .unwrap_or(self.function);
+
+        SpanRange::new(start, self.body.right_brace.after(b"}"))
     }
 }
 
 impl ConcreteConstructor {
+    /// The attribute of the constructor named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+
     pub fn first_span(&self) -> Span {
         self.comments
             .comments
@@ -314,8 +722,8 @@ impl ConcreteConstructor {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct AbstractMethod {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -330,8 +738,28 @@ pub struct AbstractMethod {
 }
 
 impl Node for AbstractMethod {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name, &mut self.parameters];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        children.push(&self.parameters);
+        if let Some(return_type) = &self.return_type {
+            children.push(return_type);
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
+        children.push(&mut self.parameters);
         if let Some(return_type) = &mut self.return_type {
             children.push(return_type);
         }
@@ -339,8 +767,38 @@ impl Node for AbstractMethod {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for AbstractMethod {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or_else(|| {
+                self.modifiers
+                    .modifiers
+                    .first()
+                    .map(|modifier| modifier.span())
+            })
+            .unwrap_or(self.function);
+
+        SpanRange::new(start, self.semicolon.after(b";"))
+    }
+}
+
+impl AbstractMethod {
+    /// The attribute of the method named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+
+    /// Whether the method returns by reference, as in `function &foo();`.
+    pub fn returns_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ConcreteMethod {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
@@ -355,8 +813,29 @@ pub struct ConcreteMethod {
 }
 
 impl Node for ConcreteMethod {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name, &mut self.parameters];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        children.push(&self.parameters);
+        if let Some(return_type) = &self.return_type {
+            children.push(return_type);
+        }
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
+        children.push(&mut self.parameters);
         if let Some(return_type) = &mut self.return_type {
             children.push(return_type);
         }
@@ -365,8 +844,38 @@ impl Node for ConcreteMethod {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ConcreteMethod {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or_else(|| {
+                self.modifiers
+                    .modifiers
+                    .first()
+                    .map(|modifier| modifier.span())
+            })
+            .unwrap_or(self.function);
+
+        SpanRange::new(start, self.body.right_brace.after(b"}"))
+    }
+}
 
+impl ConcreteMethod {
+    /// The attribute of the method named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+
+    /// Whether the method returns by reference, as in `function &foo()`.
+    pub fn returns_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct MethodBody {
     pub comments: CommentGroup,
     pub left_brace: Span, // `{`
@@ -375,10 +884,114 @@ pub struct MethodBody {
 }
 
 impl Node for MethodBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.statements.iter().map(|s| s as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.statements
             .iter_mut()
             .map(|s| s as &mut dyn Node)
             .collect()
     }
 }
+
+impl Spanned for MethodBody {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_brace, self.right_brace.after(b"}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::parser::ast::classes::ClassMember;
+    use crate::parser::ast::Expression;
+    use crate::parser::ast::Statement;
+
+    #[test]
+    fn test_references() {
+        let program = crate::parse(
+            "<?php
+            function &foo(&$a, $b) {}
+            function bar(int &...$rest) {}
+            class A { function &baz() {} function qux() {} }",
+        )
+        .unwrap();
+
+        let Statement::Function(foo) = &program[1] else {
+            panic!("expected a function");
+        };
+
+        assert!(foo.returns_reference());
+        assert_eq!(
+            foo.parameters
+                .iter()
+                .map(|parameter| parameter.is_by_reference())
+                .collect::<Vec<_>>(),
+            [true, false]
+        );
+
+        let Statement::Function(bar) = &program[2] else {
+            panic!("expected a function");
+        };
+
+        assert!(!bar.returns_reference());
+        assert!(bar
+            .parameters
+            .iter()
+            .all(|parameter| parameter.is_by_reference()));
+
+        let Statement::Class(class) = &program[3] else {
+            panic!("expected a class");
+        };
+
+        let returns = class
+            .body
+            .members
+            .iter()
+            .map(|member| match member {
+                ClassMember::ConcreteMethod(method) => method.returns_reference(),
+                _ => panic!("expected a method"),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(returns, [true, false]);
+    }
+
+    #[test]
+    fn test_references_at_statement_start() {
+        let program = crate::parse(
+            "<?php
+            function &() use (&$a, $b) {};
+            fn &($a) => $a;
+            $a & $b;",
+        )
+        .unwrap();
+
+        let closure = match &program[1] {
+            Statement::Expression(statement) => match &statement.expression {
+                Expression::Closure(closure) => closure,
+                _ => panic!("expected a closure"),
+            },
+            _ => panic!("expected an expression"),
+        };
+
+        assert!(closure.returns_reference());
+        let uses = closure.uses.as_ref().unwrap();
+        assert!(uses.variables.inner[0].is_by_reference());
+        assert!(!uses.variables.inner[1].is_by_reference());
+
+        assert!(matches!(
+            &program[2],
+            Statement::Expression(statement)
+                if matches!(&statement.expression, Expression::ArrowFunction(arrow) if arrow.returns_reference())
+        ));
+        assert!(matches!(
+            &program[3],
+            Statement::Expression(statement)
+                if matches!(statement.expression, Expression::BitwiseOperation(_))
+        ));
+    }
+}
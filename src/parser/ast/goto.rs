@@ -1,3 +1,6 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -6,9 +9,11 @@ use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct LabelStatement {
     pub comments: CommentGroup,
     pub label: SimpleIdentifier, // `foo`
@@ -16,13 +21,23 @@ pub struct LabelStatement {
 }
 
 impl Node for LabelStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.label]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.label]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for LabelStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.label.span, self.colon.after(b":"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct GotoStatement {
     pub comments: CommentGroup,
     pub keyword: Span,           // `goto`
@@ -31,7 +46,17 @@ pub struct GotoStatement {
 }
 
 impl Node for GotoStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.label]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.label]
     }
 }
+
+impl Spanned for GotoStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.keyword, self.semicolon.after(b";"))
+    }
+}
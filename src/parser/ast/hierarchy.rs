@@ -0,0 +1,194 @@
+use alloc::vec::Vec;
+
+use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::namespaces::NamespaceStatement;
+use crate::parser::ast::Program;
+use crate::parser::ast::Statement;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DeclaredTypeKind {
+    Class,
+    Interface,
+    Enum,
+}
+
+/// A class, interface or enum, along with the types it extends and
+/// implements, named as they are written in the source.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DeclaredType {
+    pub kind: DeclaredTypeKind,
+    pub namespace: Option<SimpleIdentifier>, // `None` for the global namespace
+    pub name: SimpleIdentifier,
+    pub extends: Vec<SimpleIdentifier>, // at most one for a class, none for an enum
+    pub implements: Vec<SimpleIdentifier>, // none for an interface
+    pub is_abstract: bool,
+    pub is_final: bool,
+}
+
+/// Returns the classes, interfaces and enums declared in the program, in
+/// source order, with their extends and implements edges.
+///
+/// Only the declarations at the top level of the program or of its namespaces
+/// are reported, and names are not resolved against the namespace or its
+/// imports.
+pub fn class_hierarchy(program: &Program) -> Vec<DeclaredType> {
+    let mut types = Vec::new();
+    let mut namespace = None;
+
+    for statement in program {
+        match statement {
+            Statement::Namespace(NamespaceStatement::Unbraced(unbraced)) => {
+                namespace = Some(unbraced.name.clone());
+                declared_types(&unbraced.statements, &namespace, &mut types);
+            }
+            Statement::Namespace(NamespaceStatement::Braced(braced)) => {
+                declared_types(&braced.body.statements, &braced.name, &mut types);
+            }
+            _ => declared_types(core::slice::from_ref(statement), &namespace, &mut types),
+        }
+    }
+
+    types
+}
+
+fn declared_types(
+    statements: &[Statement],
+    namespace: &Option<SimpleIdentifier>,
+    types: &mut Vec<DeclaredType>,
+) {
+    for statement in statements {
+        let declared = match statement {
+            Statement::Class(class) => DeclaredType {
+                kind: DeclaredTypeKind::Class,
+                namespace: namespace.clone(),
+                name: class.name.clone(),
+                extends: class
+                    .extends
+                    .iter()
+                    .map(|extends| extends.parent.clone())
+                    .collect(),
+                implements: class
+                    .implements
+                    .iter()
+                    .flat_map(|implements| implements.iter().cloned())
+                    .collect(),
+                is_abstract: class.modifiers.has_abstract(),
+                is_final: class.modifiers.has_final(),
+            },
+            Statement::Interface(interface) => DeclaredType {
+                kind: DeclaredTypeKind::Interface,
+                namespace: namespace.clone(),
+                name: interface.name.clone(),
+                extends: interface
+                    .extends
+                    .iter()
+                    .flat_map(|extends| extends.parents.iter().cloned())
+                    .collect(),
+                implements: Vec::new(),
+                is_abstract: false,
+                is_final: false,
+            },
+            Statement::UnitEnum(r#enum) => DeclaredType {
+                kind: DeclaredTypeKind::Enum,
+                namespace: namespace.clone(),
+                name: r#enum.name.clone(),
+                extends: Vec::new(),
+                implements: r#enum.implements.clone(),
+                is_abstract: false,
+                is_final: true,
+            },
+            Statement::BackedEnum(r#enum) => DeclaredType {
+                kind: DeclaredTypeKind::Enum,
+                namespace: namespace.clone(),
+                name: r#enum.name.clone(),
+                extends: Vec::new(),
+                implements: r#enum.implements.clone(),
+                is_abstract: false,
+                is_final: true,
+            },
+            _ => continue,
+        };
+
+        types.push(declared);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn names(identifiers: &[SimpleIdentifier]) -> Vec<String> {
+        identifiers
+            .iter()
+            .map(|identifier| identifier.value.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_class_hierarchy() {
+        let program = crate::parse(
+            "<?php
+            namespace App;
+
+            interface Shape extends \\Countable, \\Stringable {}
+            abstract class Base implements Shape {}
+            final class Square extends Base implements Shape, \\JsonSerializable {}
+            enum Kind: string implements Shape { case Square = 'square'; }
+            function helper() {}",
+        )
+        .unwrap();
+
+        let types = class_hierarchy(&program);
+        assert_eq!(types.len(), 4);
+
+        assert!(types
+            .iter()
+            .all(|declared| declared.namespace.as_ref().unwrap().value == b"App"));
+
+        assert_eq!(types[0].kind, DeclaredTypeKind::Interface);
+        assert_eq!(types[0].name.value, b"Shape");
+        assert_eq!(
+            names(&types[0].extends),
+            vec!["\\Countable", "\\Stringable"]
+        );
+        assert!(types[0].implements.is_empty());
+
+        assert_eq!(types[1].kind, DeclaredTypeKind::Class);
+        assert_eq!(types[1].name.value, b"Base");
+        assert!(types[1].extends.is_empty());
+        assert_eq!(names(&types[1].implements), vec!["Shape"]);
+        assert!(types[1].is_abstract);
+        assert!(!types[1].is_final);
+
+        assert_eq!(types[2].name.value, b"Square");
+        assert_eq!(names(&types[2].extends), vec!["Base"]);
+        assert_eq!(
+            names(&types[2].implements),
+            vec!["Shape", "\\JsonSerializable"]
+        );
+        assert!(!types[2].is_abstract);
+        assert!(types[2].is_final);
+
+        assert_eq!(types[3].kind, DeclaredTypeKind::Enum);
+        assert_eq!(types[3].name.value, b"Kind");
+        assert_eq!(names(&types[3].implements), vec!["Shape"]);
+        assert!(types[3].is_final);
+    }
+
+    #[test]
+    fn test_braced_namespaces() {
+        let program = crate::parse(
+            "<?php namespace A { class Foo {} } namespace { class Bar extends A\\Foo {} }",
+        )
+        .unwrap();
+
+        let types = class_hierarchy(&program);
+        assert_eq!(types.len(), 2);
+        assert_eq!(types[0].namespace.as_ref().unwrap().value, b"A");
+        assert_eq!(types[1].namespace, None);
+        assert_eq!(names(&types[1].extends), vec!["A\\Foo"]);
+    }
+}
@@ -1,5 +1,8 @@
-use std::fmt::Display;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::Display;
 
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -8,8 +11,11 @@ use crate::lexer::byte_string::ByteString;
 use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::Expression;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum Identifier {
     SimpleIdentifier(SimpleIdentifier),
@@ -17,16 +23,32 @@ pub enum Identifier {
 }
 
 impl Node for Identifier {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
         match self {
-            Identifier::SimpleIdentifier(identifier) => identifier.children(),
-            Identifier::DynamicIdentifier(identifier) => identifier.children(),
+            Identifier::SimpleIdentifier(identifier) => vec![identifier],
+            Identifier::DynamicIdentifier(identifier) => vec![identifier],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Identifier::SimpleIdentifier(identifier) => vec![identifier],
+            Identifier::DynamicIdentifier(identifier) => vec![identifier],
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Identifier {
+    fn span(&self) -> SpanRange {
+        match self {
+            Identifier::SimpleIdentifier(identifier) => identifier.span(),
+            Identifier::DynamicIdentifier(identifier) => identifier.span(),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct SimpleIdentifier {
     pub span: Span,
     pub value: ByteString,
@@ -36,14 +58,20 @@ impl Node for SimpleIdentifier {
     //
 }
 
+impl Spanned for SimpleIdentifier {
+    fn span(&self) -> SpanRange {
+        SpanRange::token(self.span, &self.value)
+    }
+}
+
 impl Display for SimpleIdentifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.value)
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct DynamicIdentifier {
     pub start: Span,
     pub expr: Box<Expression>,
@@ -51,7 +79,17 @@ pub struct DynamicIdentifier {
 }
 
 impl Node for DynamicIdentifier {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.expr.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.expr.as_mut()]
     }
 }
+
+impl Spanned for DynamicIdentifier {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(b"}"))
+    }
+}
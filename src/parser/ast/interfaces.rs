@@ -1,49 +1,102 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::lexer::token::Span;
 use crate::node::Node;
+use crate::parser::ast::attributes::find_attribute;
+use crate::parser::ast::attributes::Attribute;
 use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::ast::comments::Comment;
+use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::constant::ClassishConstant;
 use crate::parser::ast::functions::AbstractConstructor;
 use crate::parser::ast::functions::AbstractMethod;
 use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::properties::Property;
 use crate::parser::ast::utils::CommaSeparated;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum InterfaceMember {
     Constant(ClassishConstant),       // `public const FOO = 123;`
     Constructor(AbstractConstructor), // `public function __construct(): void;`
     Method(AbstractMethod),           // `public function foo(): void;`
+    Property(Property),               // `public string $foo { get; }`
+    Comment(Comment),                 // `// foo` before the closing brace
 }
 
 impl Node for InterfaceMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
         match self {
             InterfaceMember::Constant(constant) => vec![constant],
             InterfaceMember::Constructor(constructor) => vec![constructor],
             InterfaceMember::Method(method) => vec![method],
+            InterfaceMember::Property(property) => vec![property],
+            InterfaceMember::Comment(comment) => vec![comment],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            InterfaceMember::Constant(constant) => vec![constant],
+            InterfaceMember::Constructor(constructor) => vec![constructor],
+            InterfaceMember::Method(method) => vec![method],
+            InterfaceMember::Property(property) => vec![property],
+            InterfaceMember::Comment(comment) => vec![comment],
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for InterfaceMember {
+    fn span(&self) -> SpanRange {
+        match self {
+            InterfaceMember::Constant(member) => member.span(),
+            InterfaceMember::Constructor(member) => member.span(),
+            InterfaceMember::Method(member) => member.span(),
+            InterfaceMember::Property(member) => member.span(),
+            InterfaceMember::Comment(member) => member.span(),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct InterfaceExtends {
     pub extends: Span,                             // `extends`
     pub parents: CommaSeparated<SimpleIdentifier>, // `Foo`, `Bar`
 }
 
 impl Node for InterfaceExtends {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
         self.parents.children()
     }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.parents.children_mut()
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for InterfaceExtends {
+    fn span(&self) -> SpanRange {
+        let end = self
+            .parents
+            .inner
+            .last()
+            .map_or(self.extends.after(b"extends"), |parent| parent.span().end);
 
+        SpanRange::new(self.extends, end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct InterfaceBody {
     pub left_brace: Span,              // `{`
     pub members: Vec<InterfaceMember>, // `public const FOO = 123;`, `public function foo(): void;`
@@ -51,7 +104,14 @@ pub struct InterfaceBody {
 }
 
 impl Node for InterfaceBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.members
+            .iter()
+            .map(|member| member as &dyn Node)
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.members
             .iter_mut()
             .map(|member| member as &mut dyn Node)
@@ -59,9 +119,16 @@ impl Node for InterfaceBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for InterfaceBody {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_brace, self.right_brace.after(b"}"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct InterfaceStatement {
+    pub comments: CommentGroup,            // `/** Foo */`
     pub attributes: Vec<AttributeGroup>,   // `#[Foo]`
     pub interface: Span,                   // `interface`
     pub name: SimpleIdentifier,            // `Foo`
@@ -70,11 +137,49 @@ pub struct InterfaceStatement {
 }
 
 impl Node for InterfaceStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        if let Some(extends) = &self.extends {
+            children.push(extends);
+        }
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
         if let Some(extends) = &mut self.extends {
             children.push(extends);
         }
+        children.push(&mut self.body);
         children
     }
 }
+
+impl Spanned for InterfaceStatement {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.interface, |attribute| attribute.start);
+
+        SpanRange::new(start, self.body.right_brace.after(b"}"))
+    }
+}
+
+impl InterfaceStatement {
+    /// The attribute of the interface named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+}
@@ -1,3 +1,7 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -5,8 +9,11 @@ use serde::Serialize;
 use crate::lexer::byte_string::ByteString;
 use crate::lexer::token::Span;
 use crate::node::Node;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum Literal {
     String(LiteralString),
@@ -15,7 +22,15 @@ pub enum Literal {
 }
 
 impl Node for Literal {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            Literal::String(literal) => vec![literal],
+            Literal::Integer(literal) => vec![literal],
+            Literal::Float(literal) => vec![literal],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             Literal::String(literal) => vec![literal],
             Literal::Integer(literal) => vec![literal],
@@ -24,31 +39,84 @@ impl Node for Literal {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Literal {
+    fn span(&self) -> SpanRange {
+        match self {
+            Literal::String(literal) => literal.span(),
+            Literal::Integer(literal) => literal.span(),
+            Literal::Float(literal) => literal.span(),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct LiteralString {
     pub value: ByteString,
     pub span: Span,
+    /// Just past the closing quote, which escape sequences in `value` may
+    /// not tell.
+    pub end: Span,
 }
 
 impl Node for LiteralString {
     //
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for LiteralString {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.span, self.end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct LiteralInteger {
+    /// The integer as written, such as `0x1A_FF`, with its prefix and any
+    /// underscores between its digits.
     pub value: ByteString,
     pub span: Span,
 }
 
+impl LiteralInteger {
+    /// Returns the value of the integer, or `None` if it doesn't fit in an
+    /// `i64`, in which case PHP treats it as a float.
+    pub fn to_i64(&self) -> Option<i64> {
+        let digits = String::from_utf8_lossy(&self.value)
+            .replace('_', "")
+            .to_ascii_lowercase();
+
+        let parsed = if let Some(hex) = digits.strip_prefix("0x") {
+            i64::from_str_radix(hex, 16)
+        } else if let Some(binary) = digits.strip_prefix("0b") {
+            i64::from_str_radix(binary, 2)
+        } else if let Some(octal) = digits.strip_prefix("0o") {
+            i64::from_str_radix(octal, 8)
+        } else if let Some(octal) = digits.strip_prefix('0').filter(|octal| !octal.is_empty()) {
+            i64::from_str_radix(octal, 8)
+        } else {
+            digits.parse()
+        };
+
+        parsed.ok()
+    }
+}
+
 impl Node for LiteralInteger {
     //
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for LiteralInteger {
+    fn span(&self) -> SpanRange {
+        SpanRange::token(self.span, &self.value)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct LiteralFloat {
+    /// The float as written, such as `1_000.5E-3`, with any underscores
+    /// between its digits.
     pub value: ByteString,
     pub span: Span,
 }
@@ -56,3 +124,9 @@ pub struct LiteralFloat {
 impl Node for LiteralFloat {
     //
 }
+
+impl Spanned for LiteralFloat {
+    fn span(&self) -> SpanRange {
+        SpanRange::token(self.span, &self.value)
+    }
+}
@@ -1,3 +1,7 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -8,10 +12,12 @@ use crate::parser::ast::literals::LiteralInteger;
 use crate::parser::ast::utils::CommaSeparated;
 use crate::parser::ast::Ending;
 use crate::parser::ast::Expression;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 use crate::parser::ast::Statement;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ForeachStatement {
     pub foreach: Span,                      // `foreach`
     pub left_parenthesis: Span,             // `(`
@@ -21,12 +27,24 @@ pub struct ForeachStatement {
 }
 
 impl Node for ForeachStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.iterator, &self.body]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.iterator, &mut self.body]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ForeachStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.foreach, self.body.span().end)
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum ForeachStatementIterator {
     // `*expression* as &$var`
@@ -48,7 +66,23 @@ pub enum ForeachStatementIterator {
 }
 
 impl Node for ForeachStatementIterator {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            ForeachStatementIterator::Value {
+                expression, value, ..
+            } => {
+                vec![expression, value]
+            }
+            ForeachStatementIterator::KeyAndValue {
+                expression,
+                key,
+                value,
+                ..
+            } => vec![expression, key, value],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             ForeachStatementIterator::Value {
                 expression, value, ..
@@ -65,7 +99,21 @@ impl Node for ForeachStatementIterator {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ForeachStatementIterator {
+    fn span(&self) -> SpanRange {
+        match self {
+            ForeachStatementIterator::Value {
+                expression, value, ..
+            }
+            | ForeachStatementIterator::KeyAndValue {
+                expression, value, ..
+            } => expression.span().to(value.span()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum ForeachStatementBody {
     Statement {
@@ -80,7 +128,16 @@ pub enum ForeachStatementBody {
 }
 
 impl Node for ForeachStatementBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            ForeachStatementBody::Statement { statement } => vec![statement.as_ref()],
+            ForeachStatementBody::Block { statements, .. } => {
+                statements.iter().map(|s| s as &dyn Node).collect()
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             ForeachStatementBody::Statement { statement } => vec![statement.as_mut()],
             ForeachStatementBody::Block { statements, .. } => {
@@ -90,8 +147,19 @@ impl Node for ForeachStatementBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ForeachStatementBody {
+    fn span(&self) -> SpanRange {
+        match self {
+            ForeachStatementBody::Statement { statement } => statement.span(),
+            ForeachStatementBody::Block { colon, ending, .. } => {
+                SpanRange::new(*colon, ending.span().end)
+            }
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ForStatement {
     pub r#for: Span,                    // `for`
     pub left_parenthesis: Span,         // `(`
@@ -101,13 +169,23 @@ pub struct ForStatement {
 }
 
 impl Node for ForStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.iterator, &self.body]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.iterator, &mut self.body]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ForStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#for, self.body.span().end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ForStatementIterator {
     pub initializations: CommaSeparated<Expression>, // `*expression*;`
     pub initializations_semicolon: Span,             // `;`
@@ -117,7 +195,15 @@ pub struct ForStatementIterator {
 }
 
 impl Node for ForStatementIterator {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children = vec![];
+        children.extend(self.initializations.inner.iter().map(|x| x as &dyn Node));
+        children.extend(self.conditions.inner.iter().map(|x| x as &dyn Node));
+        children.extend(self.r#loop.inner.iter().map(|x| x as &dyn Node));
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children = vec![];
         children.extend(
             self.initializations
@@ -131,7 +217,29 @@ impl Node for ForStatementIterator {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ForStatementIterator {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .initializations
+            .inner
+            .first()
+            .map_or(self.initializations_semicolon, |expression| {
+                expression.span().start
+            });
+        let end = self
+            .r#loop
+            .inner
+            .last()
+            .map_or(self.conditions_semicolon.after(b";"), |expression| {
+                expression.span().end
+            });
+
+        SpanRange::new(start, end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum ForStatementBody {
     Statement {
@@ -146,7 +254,16 @@ pub enum ForStatementBody {
 }
 
 impl Node for ForStatementBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            ForStatementBody::Statement { statement } => vec![statement.as_ref()],
+            ForStatementBody::Block { statements, .. } => {
+                statements.iter().map(|x| x as &dyn Node).collect()
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             ForStatementBody::Statement { statement } => vec![statement.as_mut()],
             ForStatementBody::Block { statements, .. } => {
@@ -156,8 +273,19 @@ impl Node for ForStatementBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ForStatementBody {
+    fn span(&self) -> SpanRange {
+        match self {
+            ForStatementBody::Statement { statement } => statement.span(),
+            ForStatementBody::Block { colon, ending, .. } => {
+                SpanRange::new(*colon, ending.span().end)
+            }
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct DoWhileStatement {
     pub r#do: Span,              // `do`
     pub body: Box<Statement>,    // `{ ... }`
@@ -169,13 +297,23 @@ pub struct DoWhileStatement {
 }
 
 impl Node for DoWhileStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.body.as_ref(), &self.condition]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.body.as_mut(), &mut self.condition]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for DoWhileStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#do, self.semicolon.after(b";"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct WhileStatement {
     pub r#while: Span,            // `while`
     pub left_parenthesis: Span,   // `(`
@@ -185,12 +323,23 @@ pub struct WhileStatement {
 }
 
 impl Node for WhileStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.condition, &self.body]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.condition, &mut self.body]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for WhileStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#while, self.body.span().end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum WhileStatementBody {
     Statement {
@@ -205,7 +354,16 @@ pub enum WhileStatementBody {
 }
 
 impl Node for WhileStatementBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            WhileStatementBody::Statement { statement } => vec![statement.as_ref()],
+            WhileStatementBody::Block { statements, .. } => {
+                statements.iter().map(|s| s as &dyn Node).collect()
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             WhileStatementBody::Statement { statement } => vec![statement.as_mut()],
             WhileStatementBody::Block { statements, .. } => {
@@ -215,7 +373,19 @@ impl Node for WhileStatementBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for WhileStatementBody {
+    fn span(&self) -> SpanRange {
+        match self {
+            WhileStatementBody::Statement { statement } => statement.span(),
+            WhileStatementBody::Block { colon, ending, .. } => {
+                SpanRange::new(*colon, ending.span().end)
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum Level {
     Literal(LiteralInteger),
@@ -226,17 +396,55 @@ pub enum Level {
     },
 }
 
+impl Level {
+    /// Returns the number of levels, or `None` if it doesn't fit in an `i64`.
+    pub fn to_i64(&self) -> Option<i64> {
+        match self {
+            Level::Literal(literal) => literal.to_i64(),
+            Level::Parenthesized { level, .. } => level.to_i64(),
+        }
+    }
+
+    /// Returns the integer literal holding the number of levels.
+    pub fn literal(&self) -> &LiteralInteger {
+        match self {
+            Level::Literal(literal) => literal,
+            Level::Parenthesized { level, .. } => level.literal(),
+        }
+    }
+}
+
 impl Node for Level {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
         match self {
             Level::Literal(literal) => vec![literal],
             Level::Parenthesized { level, .. } => level.children(),
         }
     }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Level::Literal(literal) => vec![literal],
+            Level::Parenthesized { level, .. } => level.children_mut(),
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Level {
+    fn span(&self) -> SpanRange {
+        match self {
+            Level::Literal(literal) => literal.span(),
+            Level::Parenthesized {
+                left_parenthesis,
+                right_parenthesis,
+                ..
+            } => SpanRange::new(*left_parenthesis, right_parenthesis.after(b")")),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct BreakStatement {
     pub r#break: Span,        // `break`
     pub level: Option<Level>, // `3`
@@ -244,7 +452,14 @@ pub struct BreakStatement {
 }
 
 impl Node for BreakStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match &self.level {
+            Some(level) => vec![level],
+            None => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match &mut self.level {
             Some(level) => vec![level],
             None => vec![],
@@ -252,8 +467,14 @@ impl Node for BreakStatement {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for BreakStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#break, self.ending.span().end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ContinueStatement {
     pub r#continue: Span,     // `continue`
     pub level: Option<Level>, // `2`
@@ -261,10 +482,23 @@ pub struct ContinueStatement {
 }
 
 impl Node for ContinueStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match &self.level {
+            Some(level) => vec![level],
+            None => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match &mut self.level {
             Some(level) => vec![level],
             None => vec![],
         }
     }
 }
+
+impl Spanned for ContinueStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#continue, self.ending.span().end)
+    }
+}
@@ -1,6 +1,15 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde::Serializer;
 
 use crate::lexer::byte_string::ByteString;
 use crate::lexer::token::Span;
@@ -11,8 +20,10 @@ use crate::parser::ast::arguments::{ArgumentList, SingleArgument};
 use crate::parser::ast::classes::AnonymousClassExpression;
 use crate::parser::ast::classes::ClassStatement;
 use crate::parser::ast::comments::Comment;
+use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::constant::ConstantStatement;
 use crate::parser::ast::control_flow::IfStatement;
+use crate::parser::ast::declares::DeclareEntry;
 use crate::parser::ast::declares::DeclareStatement;
 use crate::parser::ast::enums::BackedEnumStatement;
 use crate::parser::ast::enums::UnitEnumStatement;
@@ -31,6 +42,7 @@ use crate::parser::ast::loops::DoWhileStatement;
 use crate::parser::ast::loops::ForStatement;
 use crate::parser::ast::loops::ForeachStatement;
 use crate::parser::ast::loops::WhileStatement;
+use crate::parser::ast::namespaces::DeclaredNamespace;
 use crate::parser::ast::namespaces::NamespaceStatement;
 use crate::parser::ast::operators::ArithmeticOperationExpression;
 use crate::parser::ast::operators::AssignmentOperationExpression;
@@ -44,15 +56,19 @@ use crate::parser::ast::variables::Variable;
 
 pub mod arguments;
 pub mod attributes;
+pub mod callables;
 pub mod classes;
 pub mod comments;
+pub mod compare;
 pub mod constant;
 pub mod control_flow;
 pub mod data_type;
+pub mod debug_tree;
 pub mod declares;
 pub mod enums;
 pub mod functions;
 pub mod goto;
+pub mod hierarchy;
 pub mod identifiers;
 pub mod interfaces;
 pub mod literals;
@@ -60,23 +76,222 @@ pub mod loops;
 pub mod modifiers;
 pub mod namespaces;
 pub mod operators;
+pub mod parents;
+pub mod paths;
+pub mod php_parser_json;
 pub mod properties;
+pub mod reachability;
+pub mod spans;
+pub mod statistics;
 pub mod traits;
 pub mod try_block;
 pub mod utils;
 pub mod variables;
+pub mod writable;
+
+pub use compare::eq_ignoring_spans;
+pub use debug_tree::debug_tree;
+pub use hierarchy::class_hierarchy;
+pub use parents::parent_map;
+pub use parents::NodeId;
+pub use parents::ParentMap;
+pub use paths::node_at_offset;
+pub use paths::NodePath;
+pub use paths::PathNode;
+pub use php_parser_json::to_php_parser_json;
+pub use spans::NodeKind;
+pub use spans::SpanRange;
+pub use spans::Spanned;
+pub use spans::SpannedNode;
+pub use statistics::Statistics;
+pub use writable::is_writable;
 
 pub type Block = Vec<Statement>;
 
 impl Node for Block {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.iter().map(|s| s as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.iter_mut().map(|s| s as &mut dyn Node).collect()
     }
+
+    fn name(&self) -> &'static str {
+        "Block"
+    }
+}
+
+/// The statements of a file, along with what is known about the file as a
+/// whole, which is worked out once when the program is made from them.
+///
+/// A program dereferences to its statements, and compares and serializes as
+/// them. The statements are only changed through [`Program::edit`], which
+/// keeps what is known about the file up to date.
+#[derive(Clone, Default)]
+pub struct Program {
+    statements: Block,
+    strict_types: Option<bool>,
+    declares: Vec<DeclareEntry>,
+    namespace_names: Vec<String>,
+}
+
+impl Program {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the program is in strict typing mode, or `None` if it
+    /// doesn't declare `strict_types`.
+    ///
+    /// Like PHP, only a `declare(strict_types=...);` that is the very first
+    /// statement of the file counts, and the block forms are ignored since
+    /// strict typing cannot be scoped to a block.
+    pub fn strict_types(&self) -> Option<bool> {
+        self.strict_types
+    }
+
+    /// Returns the entries of every `declare` statement at the top level of
+    /// the program or of its namespaces, in source order.
+    pub fn declares(&self) -> impl Iterator<Item = &DeclareEntry> {
+        self.declares.iter()
+    }
+
+    /// Returns the names of the namespaces declared in the program, in source
+    /// order.
+    pub fn namespace_names(&self) -> &[String] {
+        &self.namespace_names
+    }
+
+    /// Returns every namespace declared in the program, in source order.
+    ///
+    /// A file that declares no namespace at all is made of a single global
+    /// region, while `namespace { ... }` declares the global namespace
+    /// explicitly and is reported as an unnamed braced namespace.
+    pub fn namespaces(&self) -> Vec<DeclaredNamespace> {
+        namespaces::declared_namespaces(&self.statements)
+    }
+
+    /// Returns the classified nodes of the program, sorted by start offset.
+    ///
+    /// Nodes that start at the same offset are yielded in traversal order, so
+    /// an enclosing node comes before the nodes it contains.
+    pub fn spans(&self) -> impl Iterator<Item = SpannedNode> {
+        spans::spans(self)
+    }
+
+    /// Changes the statements of the program, and works out what is known
+    /// about the file again once they are changed.
+    ///
+    /// ```
+    /// let mut program = php_parser_rs::parse("<?php echo 1;").unwrap();
+    ///
+    /// program.edit(|statements| statements.truncate(1));
+    ///
+    /// assert_eq!(program.len(), 1);
+    /// ```
+    pub fn edit<R>(&mut self, edit: impl FnOnce(&mut Block) -> R) -> R {
+        let result = edit(&mut self.statements);
+        *self = Program::from(core::mem::take(&mut self.statements));
+
+        result
+    }
+
+    pub fn into_statements(self) -> Block {
+        self.statements
+    }
+}
+
+impl From<Block> for Program {
+    fn from(statements: Block) -> Self {
+        Self {
+            strict_types: declares::strict_types(&statements),
+            declares: declares::declares(&statements).cloned().collect(),
+            namespace_names: namespaces::namespace_names(&statements),
+            statements,
+        }
+    }
+}
+
+impl Deref for Program {
+    type Target = Block;
+
+    fn deref(&self) -> &Block {
+        &self.statements
+    }
+}
+
+impl<'a> IntoIterator for &'a Program {
+    type Item = &'a Statement;
+    type IntoIter = core::slice::Iter<'a, Statement>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.statements.iter()
+    }
+}
+
+impl IntoIterator for Program {
+    type Item = Statement;
+    type IntoIter = alloc::vec::IntoIter<Statement>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.statements.into_iter()
+    }
+}
+
+// The metadata follows from the statements, so only they are compared.
+impl PartialEq for Program {
+    fn eq(&self, other: &Self) -> bool {
+        self.statements == other.statements
+    }
+}
+
+impl Eq for Program {}
+
+impl fmt::Debug for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.statements.fmt(f)
+    }
+}
+
+impl Serialize for Program {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.statements.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Program {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Block::deserialize(deserializer).map(Program::from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl JsonSchema for Program {
+    fn schema_name() -> String {
+        Block::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        Block::json_schema(generator)
+    }
 }
 
-pub type Program = Block;
+// The statements are not handed out mutably, as that would leave what is
+// known about the file out of date; a visitor that changes them is run on the
+// block given by `Program::edit` instead.
+impl Node for Program {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.statements.children()
+    }
+
+    fn name(&self) -> &'static str {
+        "Program"
+    }
+}
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub enum UseKind {
     Normal,
@@ -84,15 +299,23 @@ pub enum UseKind {
     Const,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct StaticVar {
     pub var: Variable,
     pub default: Option<Expression>,
 }
 
 impl Node for StaticVar {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.var];
+        if let Some(default) = &self.default {
+            children.push(default);
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![&mut self.var];
         if let Some(default) = &mut self.default {
             children.push(default);
@@ -101,34 +324,78 @@ impl Node for StaticVar {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for StaticVar {
+    fn span(&self) -> SpanRange {
+        let end = self
+            .default
+            .as_ref()
+            .map_or(self.var.span(), |default| default.span());
+
+        self.var.span().to(end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum Ending {
     Semicolon(Span),
     CloseTag(Span),
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Ending {
+    fn span(&self) -> SpanRange {
+        match self {
+            Ending::Semicolon(span) => SpanRange::token(*span, b";"),
+            Ending::CloseTag(span) => SpanRange::token(*span, b"?>"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub struct HaltCompilerStatement {
+    pub span: Span, // `__halt_compiler();`
     pub content: Option<ByteString>,
 }
 
 impl Node for HaltCompilerStatement {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for HaltCompilerStatement {
+    fn span(&self) -> SpanRange {
+        // The data after `__halt_compiler();` is not part of the statement.
+        SpanRange::token(self.span, b"__halt_compiler();")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub struct StaticStatement {
-    pub vars: Vec<StaticVar>,
+    pub r#static: Span,                  // `static`
+    pub vars: CommaSeparated<StaticVar>, // `$a = 1, $b`
+    pub semicolon: Span,                 // `;`
 }
 
 impl Node for StaticStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.vars.iter_mut().map(|v| v as &mut dyn Node).collect()
+    fn children(&self) -> Vec<&dyn Node> {
+        self.vars.children()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.vars.children_mut()
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for StaticStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#static, self.semicolon.after(b";"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub struct SwitchStatement {
     pub switch: Span,
@@ -136,17 +403,37 @@ pub struct SwitchStatement {
     pub condition: Expression,
     pub right_parenthesis: Span,
     pub cases: Vec<Case>,
+    pub end: Span,              // `}`, or `endswitch`
+    pub ending: Option<Ending>, // the `;` or `?>` after `endswitch`
 }
 
 impl Node for SwitchStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.condition];
+        children.extend(self.cases.iter().map(|c| c as &dyn Node));
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![&mut self.condition];
         children.extend(self.cases.iter_mut().map(|c| c as &mut dyn Node));
         children
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for SwitchStatement {
+    fn span(&self) -> SpanRange {
+        let end = self
+            .ending
+            .as_ref()
+            .map_or(self.end.after(b"}"), |ending| ending.span().end);
+
+        SpanRange::new(self.switch, end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub struct EchoStatement {
     pub echo: Span,
@@ -155,12 +442,23 @@ pub struct EchoStatement {
 }
 
 impl Node for EchoStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.values.iter().map(|v| v as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.values.iter_mut().map(|v| v as &mut dyn Node).collect()
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for EchoStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.echo, self.ending.span().end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub struct ReturnStatement {
     pub r#return: Span,
@@ -169,7 +467,15 @@ pub struct ReturnStatement {
 }
 
 impl Node for ReturnStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        if let Some(value) = &self.value {
+            vec![value]
+        } else {
+            vec![]
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         if let Some(value) = &mut self.value {
             vec![value]
         } else {
@@ -178,36 +484,74 @@ impl Node for ReturnStatement {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ReturnStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#return, self.ending.span().end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub struct UseStatement {
+    pub r#use: Span,
     pub kind: UseKind,
     pub uses: Vec<Use>,
+    pub semicolon: Span,
 }
 
 impl Node for UseStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.uses.iter().map(|u| u as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.uses.iter_mut().map(|u| u as &mut dyn Node).collect()
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for UseStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#use, self.semicolon.after(b";"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub struct GroupUseStatement {
+    pub r#use: Span,
     pub prefix: SimpleIdentifier,
     pub kind: UseKind,
+    pub left_brace: Span,
     pub uses: Vec<Use>,
+    pub right_brace: Span,
+    pub semicolon: Span,
 }
 
 impl Node for GroupUseStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.prefix];
+        children.extend(self.uses.iter().map(|u| u as &dyn Node));
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![&mut self.prefix];
         children.extend(self.uses.iter_mut().map(|u| u as &mut dyn Node));
         children
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for GroupUseStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#use, self.semicolon.after(b";"))
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum Statement {
     FullOpeningTag(FullOpeningTagStatement),
@@ -248,39 +592,126 @@ pub enum Statement {
     Noop(Span),
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct InlineHtmlStatement {
+    pub span: Span,
     pub html: ByteString,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Node for InlineHtmlStatement {}
+
+impl Spanned for InlineHtmlStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::token(self.span, &self.html)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct FullOpeningTagStatement {
     pub span: Span,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Node for FullOpeningTagStatement {}
+
+impl Spanned for FullOpeningTagStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::token(self.span, b"<?php")
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ShortOpeningTagStatement {
     pub span: Span,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Node for ShortOpeningTagStatement {}
+
+impl Spanned for ShortOpeningTagStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::token(self.span, b"<?")
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct EchoOpeningTagStatement {
     pub span: Span,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Node for EchoOpeningTagStatement {}
 
+impl Spanned for EchoOpeningTagStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::token(self.span, b"<?=")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ClosingTagStatement {
     pub span: Span,
 }
 
+impl Node for ClosingTagStatement {}
+
+impl Spanned for ClosingTagStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::token(self.span, b"?>")
+    }
+}
+
 impl Node for Statement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            Statement::FullOpeningTag(statement) => vec![statement],
+            Statement::ShortOpeningTag(statement) => vec![statement],
+            Statement::EchoOpeningTag(statement) => vec![statement],
+            Statement::ClosingTag(statement) => vec![statement],
+            Statement::InlineHtml(statement) => vec![statement],
+            Statement::Label(statement) => vec![statement],
+            Statement::Goto(statement) => vec![statement],
+            Statement::HaltCompiler(statement) => vec![statement],
+            Statement::Static(statement) => vec![statement],
+            Statement::DoWhile(statement) => vec![statement],
+            Statement::While(statement) => vec![statement],
+            Statement::For(statement) => vec![statement],
+            Statement::Foreach(statement) => vec![statement],
+            Statement::Break(statement) => vec![statement],
+            Statement::Continue(statement) => vec![statement],
+            Statement::Constant(statement) => vec![statement],
+            Statement::Function(statement) => vec![statement],
+            Statement::Class(statement) => vec![statement],
+            Statement::Trait(statement) => vec![statement],
+            Statement::Interface(statement) => vec![statement],
+            Statement::If(statement) => vec![statement],
+            Statement::Switch(statement) => vec![statement],
+            Statement::Echo(statement) => vec![statement],
+            Statement::Expression(statement) => vec![statement],
+            Statement::Return(statement) => vec![statement],
+            Statement::Namespace(statement) => vec![statement],
+            Statement::Use(statement) => vec![statement],
+            Statement::GroupUse(statement) => vec![statement],
+            Statement::Comment(statement) => vec![statement],
+            Statement::Try(statement) => vec![statement],
+            Statement::UnitEnum(statement) => vec![statement],
+            Statement::BackedEnum(statement) => vec![statement],
+            Statement::Block(statement) => vec![statement],
+            Statement::Global(statement) => vec![statement],
+            Statement::Declare(statement) => vec![statement],
+            _ => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
+            Statement::FullOpeningTag(statement) => vec![statement],
+            Statement::ShortOpeningTag(statement) => vec![statement],
+            Statement::EchoOpeningTag(statement) => vec![statement],
+            Statement::ClosingTag(statement) => vec![statement],
+            Statement::InlineHtml(statement) => vec![statement],
             Statement::Label(statement) => vec![statement],
             Statement::Goto(statement) => vec![statement],
             Statement::HaltCompiler(statement) => vec![statement],
@@ -316,7 +747,51 @@ impl Node for Statement {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Statement {
+    fn span(&self) -> SpanRange {
+        match self {
+            Statement::FullOpeningTag(statement) => statement.span(),
+            Statement::ShortOpeningTag(statement) => statement.span(),
+            Statement::EchoOpeningTag(statement) => statement.span(),
+            Statement::ClosingTag(statement) => statement.span(),
+            Statement::InlineHtml(statement) => statement.span(),
+            Statement::Label(statement) => statement.span(),
+            Statement::Goto(statement) => statement.span(),
+            Statement::HaltCompiler(statement) => statement.span(),
+            Statement::Static(statement) => statement.span(),
+            Statement::DoWhile(statement) => statement.span(),
+            Statement::While(statement) => statement.span(),
+            Statement::For(statement) => statement.span(),
+            Statement::Foreach(statement) => statement.span(),
+            Statement::Break(statement) => statement.span(),
+            Statement::Continue(statement) => statement.span(),
+            Statement::Constant(statement) => statement.span(),
+            Statement::Function(statement) => statement.span(),
+            Statement::Class(statement) => statement.span(),
+            Statement::Trait(statement) => statement.span(),
+            Statement::Interface(statement) => statement.span(),
+            Statement::If(statement) => statement.span(),
+            Statement::Switch(statement) => statement.span(),
+            Statement::Echo(statement) => statement.span(),
+            Statement::Expression(statement) => statement.span(),
+            Statement::Return(statement) => statement.span(),
+            Statement::Namespace(statement) => statement.span(),
+            Statement::Use(statement) => statement.span(),
+            Statement::GroupUse(statement) => statement.span(),
+            Statement::Comment(statement) => statement.span(),
+            Statement::Try(statement) => statement.span(),
+            Statement::UnitEnum(statement) => statement.span(),
+            Statement::BackedEnum(statement) => statement.span(),
+            Statement::Block(statement) => statement.span(),
+            Statement::Global(statement) => statement.span(),
+            Statement::Declare(statement) => statement.span(),
+            Statement::Noop(span) => SpanRange::token(*span, b";"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub struct ExpressionStatement {
     pub expression: Expression,
@@ -324,28 +799,48 @@ pub struct ExpressionStatement {
 }
 
 impl Node for ExpressionStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.expression]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.expression]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ExpressionStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.expression.span().start, self.ending.span().end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub struct GlobalStatement {
-    pub global: Span,
-    pub variables: Vec<Variable>,
+    pub global: Span,                        // `global`
+    pub variables: CommaSeparated<Variable>, // `$a, $b`
+    pub semicolon: Span,                     // `;`
 }
 
 impl Node for GlobalStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.variables
-            .iter_mut()
-            .map(|v| v as &mut dyn Node)
-            .collect()
+    fn children(&self) -> Vec<&dyn Node> {
+        self.variables.children()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.variables.children_mut()
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for GlobalStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.global, self.semicolon.after(b";"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub struct BlockStatement {
     pub left_brace: Span,
@@ -354,7 +849,11 @@ pub struct BlockStatement {
 }
 
 impl Node for BlockStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.statements.iter().map(|s| s as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.statements
             .iter_mut()
             .map(|s| s as &mut dyn Node)
@@ -362,8 +861,15 @@ impl Node for BlockStatement {
     }
 }
 
+impl Spanned for BlockStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_brace, self.right_brace.after(b"}"))
+    }
+}
+
 // See https://www.php.net/manual/en/language.types.type-juggling.php#language.types.typecasting for more info.
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub enum CastKind {
     Int,
@@ -375,9 +881,11 @@ pub enum CastKind {
     Unset,
 }
 
-impl From<TokenKind> for CastKind {
-    fn from(kind: TokenKind) -> Self {
-        match kind {
+impl TryFrom<TokenKind> for CastKind {
+    type Error = ();
+
+    fn try_from(kind: TokenKind) -> Result<Self, Self::Error> {
+        Ok(match kind {
             TokenKind::StringCast | TokenKind::BinaryCast => Self::String,
             TokenKind::ObjectCast => Self::Object,
             TokenKind::BoolCast | TokenKind::BooleanCast => Self::Bool,
@@ -385,26 +893,44 @@ impl From<TokenKind> for CastKind {
             TokenKind::FloatCast | TokenKind::DoubleCast | TokenKind::RealCast => Self::Float,
             TokenKind::UnsetCast => Self::Unset,
             TokenKind::ArrayCast => Self::Array,
-            _ => unreachable!(),
-        }
+            _ => return Err(()),
+        })
     }
 }
 
-impl From<&TokenKind> for CastKind {
-    fn from(kind: &TokenKind) -> Self {
-        kind.clone().into()
+impl TryFrom<&TokenKind> for CastKind {
+    type Error = ();
+
+    fn try_from(kind: &TokenKind) -> Result<Self, Self::Error> {
+        (*kind).try_into()
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct Case {
+    pub keyword: Span, // `case` or `default`
     pub condition: Option<Expression>,
+    pub colon: Span, // `:` or `;`
     pub body: Block,
 }
 
 impl Node for Case {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![];
+        if let Some(condition) = &self.condition {
+            children.push(condition);
+        }
+        children.extend(
+            self.body
+                .iter()
+                .map(|statement| statement as &dyn Node)
+                .collect::<Vec<&dyn Node>>(),
+        );
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![];
         if let Some(condition) = &mut self.condition {
             children.push(condition);
@@ -419,8 +945,19 @@ impl Node for Case {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Case {
+    fn span(&self) -> SpanRange {
+        let end = self
+            .body
+            .last()
+            .map_or(self.colon.after(b":"), |statement| statement.span().end);
 
+        SpanRange::new(self.keyword, end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct Use {
     pub name: SimpleIdentifier,
     pub alias: Option<SimpleIdentifier>,
@@ -428,7 +965,15 @@ pub struct Use {
 }
 
 impl Node for Use {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name];
+        if let Some(alias) = &self.alias {
+            children.push(alias);
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
         if let Some(alias) = &mut self.alias {
             children.push(alias);
@@ -437,115 +982,139 @@ impl Node for Use {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Use {
+    fn span(&self) -> SpanRange {
+        // In a group, the `function` or `const` before the name is not kept.
+        let end = self.alias.as_ref().unwrap_or(&self.name).span().end;
+
+        SpanRange::new(self.name.span, end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct EvalExpression {
     pub eval: Span,
     // eval
     pub argument: Box<SingleArgument>, // ("$a = 1")
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct EmptyExpression {
     pub empty: Span,
     // empty
     pub argument: Box<SingleArgument>, // ($a)
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct DieExpression {
     pub die: Span,
     // die
     pub argument: Option<Box<SingleArgument>>, // (1)
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ExitExpression {
     pub exit: Span,
     // exit
     pub argument: Option<Box<SingleArgument>>, // (1)
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct IssetExpression {
     pub isset: Span,
     // isset
     pub arguments: ArgumentList, // `($a, ...)`
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct UnsetExpression {
     pub unset: Span,
     // unset
     pub arguments: ArgumentList, // `($a, ...)`
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct PrintExpression {
     pub print: Span,
     // print
-    pub value: Option<Box<Expression>>,
-    // 1
-    pub argument: Option<Box<SingleArgument>>, // (1)
+    pub value: Box<Expression>, // 1
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ConcatExpression {
     pub left: Box<Expression>,
     pub dot: Span,
     pub right: Box<Expression>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct InstanceofExpression {
     pub left: Box<Expression>,
     pub instanceof: Span,
     pub right: Box<Expression>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ReferenceExpression {
     pub ampersand: Span,
     pub right: Box<Expression>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ParenthesizedExpression {
     pub start: Span,
     pub expr: Box<Expression>,
     pub end: Span,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ErrorSuppressExpression {
     pub at: Span,
     pub expr: Box<Expression>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct IncludeExpression {
     pub include: Span,
     pub path: Box<Expression>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct IncludeOnceExpression {
     pub include_once: Span,
     pub path: Box<Expression>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct RequireExpression {
     pub require: Span,
     pub path: Box<Expression>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct RequireOnceExpression {
     pub require_once: Span,
     pub path: Box<Expression>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct FunctionCallExpression {
     pub target: Box<Expression>,
     // `foo`
@@ -553,12 +1122,23 @@ pub struct FunctionCallExpression {
 }
 
 impl Node for FunctionCallExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.arguments]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.arguments]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for FunctionCallExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.arguments.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct FunctionClosureCreationExpression {
     pub target: Box<Expression>,
     // `foo`
@@ -566,12 +1146,23 @@ pub struct FunctionClosureCreationExpression {
 }
 
 impl Node for FunctionClosureCreationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for FunctionClosureCreationExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.placeholder.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct MethodCallExpression {
     pub target: Box<Expression>,
     // `$foo`
@@ -583,7 +1174,11 @@ pub struct MethodCallExpression {
 }
 
 impl Node for MethodCallExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), self.method.as_ref(), &self.arguments]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![
             self.target.as_mut(),
             self.method.as_mut(),
@@ -592,7 +1187,14 @@ impl Node for MethodCallExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for MethodCallExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.arguments.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct MethodClosureCreationExpression {
     pub target: Box<Expression>,
     // `$foo`
@@ -604,12 +1206,23 @@ pub struct MethodClosureCreationExpression {
 }
 
 impl Node for MethodClosureCreationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), self.method.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), self.method.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for MethodClosureCreationExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.placeholder.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct NullsafeMethodCallExpression {
     pub target: Box<Expression>,
     // `$foo`
@@ -621,7 +1234,11 @@ pub struct NullsafeMethodCallExpression {
 }
 
 impl Node for NullsafeMethodCallExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), self.method.as_ref(), &self.arguments]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![
             self.target.as_mut(),
             self.method.as_mut(),
@@ -630,7 +1247,14 @@ impl Node for NullsafeMethodCallExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for NullsafeMethodCallExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.arguments.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct StaticMethodCallExpression {
     pub target: Box<Expression>,
     // `Foo`
@@ -642,12 +1266,23 @@ pub struct StaticMethodCallExpression {
 }
 
 impl Node for StaticMethodCallExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.method, &self.arguments]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.method, &mut self.arguments]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for StaticMethodCallExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.arguments.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct StaticVariableMethodCallExpression {
     pub target: Box<Expression>,
     // `Foo`
@@ -659,14 +1294,25 @@ pub struct StaticVariableMethodCallExpression {
 }
 
 impl Node for StaticVariableMethodCallExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.method, &self.arguments]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.method, &mut self.arguments]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-pub struct StaticMethodClosureCreationExpression {
-    pub target: Box<Expression>,
+impl Spanned for StaticVariableMethodCallExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.arguments.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+pub struct StaticMethodClosureCreationExpression {
+    pub target: Box<Expression>,
     // `Foo`
     pub double_colon: Span,
     // `::`
@@ -676,12 +1322,23 @@ pub struct StaticMethodClosureCreationExpression {
 }
 
 impl Node for StaticMethodClosureCreationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.method]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.method]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for StaticMethodClosureCreationExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.placeholder.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct StaticVariableMethodClosureCreationExpression {
     pub target: Box<Expression>,
     // `Foo`
@@ -693,12 +1350,23 @@ pub struct StaticVariableMethodClosureCreationExpression {
 }
 
 impl Node for StaticVariableMethodClosureCreationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.method]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.method]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for StaticVariableMethodClosureCreationExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.placeholder.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct PropertyFetchExpression {
     pub target: Box<Expression>,
     // `foo()`
@@ -708,12 +1376,23 @@ pub struct PropertyFetchExpression {
 }
 
 impl Node for PropertyFetchExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), self.property.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), self.property.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for PropertyFetchExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.property.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct NullsafePropertyFetchExpression {
     pub target: Box<Expression>,
     // `foo()`
@@ -723,12 +1402,23 @@ pub struct NullsafePropertyFetchExpression {
 }
 
 impl Node for NullsafePropertyFetchExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), self.property.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), self.property.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for NullsafePropertyFetchExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.property.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct StaticPropertyFetchExpression {
     pub target: Box<Expression>,
     // `foo()`
@@ -738,12 +1428,23 @@ pub struct StaticPropertyFetchExpression {
 }
 
 impl Node for StaticPropertyFetchExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.property]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.property]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for StaticPropertyFetchExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.property.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ConstantFetchExpression {
     pub target: Box<Expression>,
     // `foo()`
@@ -753,12 +1454,65 @@ pub struct ConstantFetchExpression {
 }
 
 impl Node for ConstantFetchExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.constant]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.constant]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ConstantFetchExpression {
+    fn span(&self) -> SpanRange {
+        self.target.span().to(self.constant.span())
+    }
+}
+
+impl ConstantFetchExpression {
+    /// Whether this is `Foo::class`, `static::class` or `$foo::class`, which
+    /// fetches the name of a class rather than one of its constants.
+    pub fn is_class_name(&self) -> bool {
+        matches!(
+            &self.constant,
+            Identifier::SimpleIdentifier(SimpleIdentifier { value, .. })
+                if value.eq_ignore_ascii_case(b"class")
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+pub struct DynamicConstantFetchExpression {
+    pub target: Box<Expression>,
+    // `foo()`
+    pub double_colon: Span,
+    // `::`
+    pub start: Span,
+    // `{`
+    pub expr: Box<Expression>,
+    // `$bar`
+    pub end: Span, // `}`
+}
+
+impl Node for DynamicConstantFetchExpression {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), self.expr.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![self.target.as_mut(), self.expr.as_mut()]
+    }
+}
+
+impl Spanned for DynamicConstantFetchExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.target.span().start, self.end.after(b"}"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ShortArrayExpression {
     pub start: Span,
     // `[`
@@ -768,12 +1522,23 @@ pub struct ShortArrayExpression {
 }
 
 impl Node for ShortArrayExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.items]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.items]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ShortArrayExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(b"]"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ArrayExpression {
     pub array: Span,
     // `array`
@@ -785,12 +1550,23 @@ pub struct ArrayExpression {
 }
 
 impl Node for ArrayExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.items]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.items]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ArrayExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.array, self.end.after(b")"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ListExpression {
     pub list: Span,
     // `list`
@@ -802,12 +1578,23 @@ pub struct ListExpression {
 }
 
 impl Node for ListExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.items.iter().map(|i| i as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.items.iter_mut().map(|i| i as &mut dyn Node).collect()
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ListExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.list, self.end.after(b")"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct NewExpression {
     pub new: Span,
     // `new`
@@ -817,7 +1604,15 @@ pub struct NewExpression {
 }
 
 impl Node for NewExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![self.target.as_ref()];
+        if let Some(arguments) = &self.arguments {
+            children.push(arguments);
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![self.target.as_mut()];
         if let Some(arguments) = &mut self.arguments {
             children.push(arguments);
@@ -826,13 +1621,34 @@ impl Node for NewExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for NewExpression {
+    fn span(&self) -> SpanRange {
+        // The arguments of an anonymous class come before its body.
+        let end = match (&self.arguments, self.target.as_ref()) {
+            (Some(arguments), target) if !matches!(target, Expression::AnonymousClass(_)) => {
+                arguments.right_parenthesis.after(b")")
+            }
+            _ => self.target.span().end,
+        };
+
+        SpanRange::new(self.new, end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct InterpolatedStringExpression {
+    pub start: Span, // `"`
     pub parts: Vec<StringPart>,
+    pub end: Span, // `"`
 }
 
 impl Node for InterpolatedStringExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.parts.iter().map(|part| part as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.parts
             .iter_mut()
             .map(|part| part as &mut dyn Node)
@@ -840,14 +1656,34 @@ impl Node for InterpolatedStringExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for InterpolatedStringExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(b"\""))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct HeredocExpression {
-    pub label: ByteString,
-    pub parts: Vec<StringPart>,
+    pub start: Span,            // `<<<"EOT"`
+    pub opening: ByteString,    // `<<<"EOT"`
+    pub parts: Vec<StringPart>, // `*parts*`
+    pub end: Span,              // `EOT`
+}
+
+impl HeredocExpression {
+    /// The label that opens and closes the heredoc, such as `EOT`.
+    pub fn label(&self) -> &[u8] {
+        doc_string_label(&self.opening)
+    }
 }
 
 impl Node for HeredocExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.parts.iter().map(|part| part as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.parts
             .iter_mut()
             .map(|part| part as &mut dyn Node)
@@ -855,21 +1691,62 @@ impl Node for HeredocExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for HeredocExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(self.label()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct NowdocExpression {
-    pub label: ByteString,
-    pub value: ByteString,
+    pub start: Span,         // `<<<'EOT'`
+    pub opening: ByteString, // `<<<'EOT'`
+    pub value: ByteString,   // `*value*`
+    pub end: Span,           // `EOT`
+}
+
+impl NowdocExpression {
+    /// The label that opens and closes the nowdoc, such as `EOT`.
+    pub fn label(&self) -> &[u8] {
+        doc_string_label(&self.opening)
+    }
 }
 
 impl Node for NowdocExpression {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for NowdocExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(self.label()))
+    }
+}
+
+// The label in the opening of a heredoc or nowdoc, which may be quoted and
+// preceded by whitespace.
+fn doc_string_label(opening: &[u8]) -> &[u8] {
+    let label = opening.strip_prefix(b"<<<").unwrap_or(opening);
+    let label = label.trim_ascii_start();
+
+    match label {
+        [b'"' | b'\'', label @ .., b'"' | b'\''] => label,
+        label => label,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ShellExecExpression {
+    pub start: Span, // the opening backtick
     pub parts: Vec<StringPart>,
+    pub end: Span, // the closing backtick
 }
 
 impl Node for ShellExecExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.parts.iter().map(|part| part as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.parts
             .iter_mut()
             .map(|part| part as &mut dyn Node)
@@ -877,14 +1754,31 @@ impl Node for ShellExecExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ShellExecExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(b"`"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct BoolExpression {
+    pub span: Span,
     pub value: bool,
 }
 
 impl Node for BoolExpression {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for BoolExpression {
+    fn span(&self) -> SpanRange {
+        let text: &[u8] = if self.value { b"true" } else { b"false" };
+
+        SpanRange::token(self.span, text)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ArrayIndexExpression {
     pub array: Box<Expression>,
     pub left_bracket: Span,
@@ -893,8 +1787,16 @@ pub struct ArrayIndexExpression {
 }
 
 impl Node for ArrayIndexExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![self.array.as_ref()];
+        if let Some(index) = &self.index {
+            children.push(index.as_ref());
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![self.array.as_mut()];
         if let Some(index) = &mut self.index {
             children.push(index.as_mut());
         }
@@ -902,7 +1804,14 @@ impl Node for ArrayIndexExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ArrayIndexExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.array.span().start, self.right_bracket.after(b"]"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ShortTernaryExpression {
     pub condition: Box<Expression>,
     // `foo()`
@@ -912,12 +1821,23 @@ pub struct ShortTernaryExpression {
 }
 
 impl Node for ShortTernaryExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.condition.as_ref(), self.r#else.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.condition.as_mut(), self.r#else.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ShortTernaryExpression {
+    fn span(&self) -> SpanRange {
+        self.condition.span().to(self.r#else.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct TernaryExpression {
     pub condition: Box<Expression>,
     // `foo()`
@@ -931,7 +1851,15 @@ pub struct TernaryExpression {
 }
 
 impl Node for TernaryExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![
+            self.condition.as_ref(),
+            self.then.as_ref(),
+            self.r#else.as_ref(),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![
             self.condition.as_mut(),
             self.then.as_mut(),
@@ -940,7 +1868,14 @@ impl Node for TernaryExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for TernaryExpression {
+    fn span(&self) -> SpanRange {
+        self.condition.span().to(self.r#else.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct CoalesceExpression {
     pub lhs: Box<Expression>,
     pub double_question: Span,
@@ -948,23 +1883,47 @@ pub struct CoalesceExpression {
 }
 
 impl Node for CoalesceExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.lhs.as_ref(), self.rhs.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.lhs.as_mut(), self.rhs.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for CoalesceExpression {
+    fn span(&self) -> SpanRange {
+        self.lhs.span().to(self.rhs.span())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct CloneExpression {
-    pub target: Box<Expression>,
+    pub clone: Span,
+    // clone
+    pub target: Box<Expression>, // $foo
 }
 
 impl Node for CloneExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for CloneExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.clone, self.target.span().end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct MatchExpression {
     pub keyword: Span,
     pub left_parenthesis: Span,
@@ -977,7 +1936,21 @@ pub struct MatchExpression {
 }
 
 impl Node for MatchExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![self.condition.as_ref()];
+        if let Some(default) = &self.default {
+            children.push(default.as_ref());
+        }
+        children.extend(
+            self.arms
+                .iter()
+                .map(|arm| arm as &dyn Node)
+                .collect::<Vec<&dyn Node>>(),
+        );
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![self.condition.as_mut()];
         if let Some(default) = &mut self.default {
             children.push(default.as_mut());
@@ -992,25 +1965,56 @@ impl Node for MatchExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for MatchExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.keyword, self.right_brace.after(b"}"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ThrowExpression {
+    pub throw: Span,
     pub value: Box<Expression>,
 }
 
 impl Node for ThrowExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.value.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.value.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ThrowExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.throw, self.value.span().end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct YieldExpression {
+    pub r#yield: Span,
     pub key: Option<Box<Expression>>,
     pub value: Option<Box<Expression>>,
 }
 
 impl Node for YieldExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![];
+        if let Some(key) = &self.key {
+            children.push(key.as_ref());
+        }
+        if let Some(value) = &self.value {
+            children.push(value.as_ref());
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![];
         if let Some(key) = &mut self.key {
             children.push(key.as_mut());
@@ -1022,18 +2026,44 @@ impl Node for YieldExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for YieldExpression {
+    fn span(&self) -> SpanRange {
+        let end = self
+            .value
+            .as_ref()
+            .or(self.key.as_ref())
+            .map_or(self.r#yield.after(b"yield"), |value| value.span().end);
+
+        SpanRange::new(self.r#yield, end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct YieldFromExpression {
+    pub r#yield: Span,
+    pub from: Span,
     pub value: Box<Expression>,
 }
 
 impl Node for YieldFromExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.value.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.value.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for YieldFromExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#yield, self.value.span().end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct CastExpression {
     pub cast: Span,
     pub kind: CastKind,
@@ -1041,12 +2071,23 @@ pub struct CastExpression {
 }
 
 impl Node for CastExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.value.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.value.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for CastExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.cast, self.value.span().end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum Expression {
     // eval("$a = 1")
@@ -1115,14 +2156,16 @@ pub enum Expression {
     NullsafePropertyFetch(NullsafePropertyFetchExpression),
     // `foo()::$bar`
     StaticPropertyFetch(StaticPropertyFetchExpression),
-    // `foo()::bar` or `foo()::{$name}`
+    // `foo()::bar`
     ConstantFetch(ConstantFetchExpression),
+    // `foo()::{$bar}`
+    DynamicConstantFetch(DynamicConstantFetchExpression),
     // `static`
-    Static,
+    Static(Span),
     // `self`
-    Self_,
+    Self_(Span),
     // `parent`
-    Parent,
+    Parent(Span),
     // `[1, 2, 3]`
     ShortArray(ShortArrayExpression),
     // `array(1, 2, 3)`
@@ -1150,7 +2193,7 @@ pub enum Expression {
     // `$foo[0]`
     ArrayIndex(ArrayIndexExpression),
     // `null`
-    Null,
+    Null(Span),
     // `__DIR__`, etc
     MagicConstant(MagicConstantExpression),
     // `foo() ?: bar()`
@@ -1171,24 +2214,52 @@ pub enum Expression {
     YieldFrom(YieldFromExpression),
     // `(int) "1"`, etc
     Cast(CastExpression),
-    // ;
-    Noop,
+    // the missing operand of `foo() ? : bar()`, at the `:`
+    Noop(Span),
 }
 
 impl Node for EvalExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.argument.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.argument.as_mut()]
     }
 }
 
+impl Spanned for EvalExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.eval, self.argument.right_parenthesis.after(b")"))
+    }
+}
+
 impl Node for EmptyExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.argument.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.argument.as_mut()]
     }
 }
 
+impl Spanned for EmptyExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.empty, self.argument.right_parenthesis.after(b")"))
+    }
+}
+
 impl Node for DieExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        if let Some(argument) = &self.argument {
+            vec![argument.as_ref()]
+        } else {
+            vec![]
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         if let Some(argument) = &mut self.argument {
             vec![argument.as_mut()]
         } else {
@@ -1197,8 +2268,29 @@ impl Node for DieExpression {
     }
 }
 
+impl Spanned for DieExpression {
+    fn span(&self) -> SpanRange {
+        let end = self
+            .argument
+            .as_ref()
+            .map_or(self.die.after(b"die"), |argument| {
+                argument.right_parenthesis.after(b")")
+            });
+
+        SpanRange::new(self.die, end)
+    }
+}
+
 impl Node for ExitExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        if let Some(argument) = &self.argument {
+            vec![argument.as_ref()]
+        } else {
+            vec![]
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         if let Some(argument) = &mut self.argument {
             vec![argument.as_mut()]
         } else {
@@ -1207,86 +2299,213 @@ impl Node for ExitExpression {
     }
 }
 
+impl Spanned for ExitExpression {
+    fn span(&self) -> SpanRange {
+        let end = self
+            .argument
+            .as_ref()
+            .map_or(self.exit.after(b"exit"), |argument| {
+                argument.right_parenthesis.after(b")")
+            });
+
+        SpanRange::new(self.exit, end)
+    }
+}
+
 impl Node for IssetExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.arguments]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.arguments]
     }
 }
 
+impl Spanned for IssetExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.isset, self.arguments.right_parenthesis.after(b")"))
+    }
+}
+
 impl Node for UnsetExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.arguments]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.arguments]
     }
 }
 
+impl Spanned for UnsetExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.unset, self.arguments.right_parenthesis.after(b")"))
+    }
+}
+
 impl Node for PrintExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        if let Some(argument) = &mut self.argument {
-            vec![argument.as_mut()]
-        } else if let Some(value) = &mut self.value {
-            vec![value.as_mut()]
-        } else {
-            vec![]
-        }
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.value.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![self.value.as_mut()]
+    }
+}
+
+impl Spanned for PrintExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.print, self.value.span().end)
     }
 }
 
 impl Node for ConcatExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.left.as_ref(), self.right.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.left.as_mut(), self.right.as_mut()]
     }
 }
 
+impl Spanned for ConcatExpression {
+    fn span(&self) -> SpanRange {
+        self.left.span().to(self.right.span())
+    }
+}
+
 impl Node for InstanceofExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.left.as_ref(), self.right.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.left.as_mut(), self.right.as_mut()]
     }
 }
 
+impl Spanned for InstanceofExpression {
+    fn span(&self) -> SpanRange {
+        self.left.span().to(self.right.span())
+    }
+}
+
 impl Node for ReferenceExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.right.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.right.as_mut()]
     }
 }
 
+impl Spanned for ReferenceExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.ampersand, self.right.span().end)
+    }
+}
+
 impl Node for ParenthesizedExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.expr.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.expr.as_mut()]
     }
 }
 
+impl Spanned for ParenthesizedExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(b")"))
+    }
+}
+
 impl Node for ErrorSuppressExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.expr.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.expr.as_mut()]
     }
 }
 
+impl Spanned for ErrorSuppressExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.at, self.expr.span().end)
+    }
+}
+
 impl Node for IncludeExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.path.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.path.as_mut()]
     }
 }
 
+impl Spanned for IncludeExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.include, self.path.span().end)
+    }
+}
+
 impl Node for IncludeOnceExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.path.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.path.as_mut()]
     }
 }
 
+impl Spanned for IncludeOnceExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.include_once, self.path.span().end)
+    }
+}
+
 impl Node for RequireExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.path.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.path.as_mut()]
     }
 }
 
+impl Spanned for RequireExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.require, self.path.span().end)
+    }
+}
+
 impl Node for RequireOnceExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.path.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.path.as_mut()]
     }
 }
 
+impl Spanned for RequireOnceExpression {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.require_once, self.path.span().end)
+    }
+}
+
 impl Node for Expression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
         match self {
             Expression::Eval(expression) => vec![expression],
             Expression::Empty(expression) => vec![expression],
@@ -1325,9 +2544,10 @@ impl Node for Expression {
             Expression::NullsafePropertyFetch(expression) => vec![expression],
             Expression::StaticPropertyFetch(expression) => vec![expression],
             Expression::ConstantFetch(expression) => vec![expression],
-            Expression::Static => vec![],
-            Expression::Self_ => vec![],
-            Expression::Parent => vec![],
+            Expression::DynamicConstantFetch(expression) => vec![expression],
+            Expression::Static(_) => vec![],
+            Expression::Self_(_) => vec![],
+            Expression::Parent(_) => vec![],
             Expression::ShortArray(expression) => vec![expression],
             Expression::Array(expression) => vec![expression],
             Expression::List(expression) => vec![expression],
@@ -1341,7 +2561,7 @@ impl Node for Expression {
             Expression::AnonymousClass(expression) => vec![expression],
             Expression::Bool(_) => vec![],
             Expression::ArrayIndex(expression) => vec![expression],
-            Expression::Null => vec![],
+            Expression::Null(_) => vec![],
             Expression::MagicConstant(constant) => vec![constant],
             Expression::ShortTernary(expression) => vec![expression],
             Expression::Ternary(expression) => vec![expression],
@@ -1352,35 +2572,201 @@ impl Node for Expression {
             Expression::Yield(expression) => vec![expression],
             Expression::YieldFrom(expression) => vec![expression],
             Expression::Cast(expression) => vec![expression],
-            Expression::Noop => vec![],
+            Expression::Noop(_) => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Expression::Eval(expression) => vec![expression],
+            Expression::Empty(expression) => vec![expression],
+            Expression::Die(expression) => vec![expression],
+            Expression::Exit(expression) => vec![expression],
+            Expression::Isset(expression) => vec![expression],
+            Expression::Unset(expression) => vec![expression],
+            Expression::Print(expression) => vec![expression],
+            Expression::Literal(literal) => vec![literal],
+            Expression::ArithmeticOperation(operation) => vec![operation],
+            Expression::AssignmentOperation(operation) => vec![operation],
+            Expression::BitwiseOperation(operation) => vec![operation],
+            Expression::ComparisonOperation(operation) => vec![operation],
+            Expression::LogicalOperation(operation) => vec![operation],
+            Expression::Concat(expression) => vec![expression],
+            Expression::Instanceof(expression) => vec![expression],
+            Expression::Reference(expression) => vec![expression],
+            Expression::Parenthesized(expression) => vec![expression],
+            Expression::ErrorSuppress(expression) => vec![expression],
+            Expression::Identifier(identifier) => vec![identifier],
+            Expression::Variable(variable) => vec![variable],
+            Expression::Include(expression) => vec![expression],
+            Expression::IncludeOnce(expression) => vec![expression],
+            Expression::Require(expression) => vec![expression],
+            Expression::RequireOnce(expression) => vec![expression],
+            Expression::FunctionCall(expression) => vec![expression],
+            Expression::FunctionClosureCreation(expression) => vec![expression],
+            Expression::MethodCall(expression) => vec![expression],
+            Expression::MethodClosureCreation(expression) => vec![expression],
+            Expression::NullsafeMethodCall(expression) => vec![expression],
+            Expression::StaticMethodCall(expression) => vec![expression],
+            Expression::StaticVariableMethodCall(expression) => vec![expression],
+            Expression::StaticMethodClosureCreation(expression) => vec![expression],
+            Expression::StaticVariableMethodClosureCreation(expression) => vec![expression],
+            Expression::PropertyFetch(expression) => vec![expression],
+            Expression::NullsafePropertyFetch(expression) => vec![expression],
+            Expression::StaticPropertyFetch(expression) => vec![expression],
+            Expression::ConstantFetch(expression) => vec![expression],
+            Expression::DynamicConstantFetch(expression) => vec![expression],
+            Expression::Static(_) => vec![],
+            Expression::Self_(_) => vec![],
+            Expression::Parent(_) => vec![],
+            Expression::ShortArray(expression) => vec![expression],
+            Expression::Array(expression) => vec![expression],
+            Expression::List(expression) => vec![expression],
+            Expression::Closure(expression) => vec![expression],
+            Expression::ArrowFunction(expression) => vec![expression],
+            Expression::New(expression) => vec![expression],
+            Expression::InterpolatedString(expression) => vec![expression],
+            Expression::Heredoc(expression) => vec![expression],
+            Expression::Nowdoc(expression) => vec![expression],
+            Expression::ShellExec(expression) => vec![expression],
+            Expression::AnonymousClass(expression) => vec![expression],
+            Expression::Bool(_) => vec![],
+            Expression::ArrayIndex(expression) => vec![expression],
+            Expression::Null(_) => vec![],
+            Expression::MagicConstant(constant) => vec![constant],
+            Expression::ShortTernary(expression) => vec![expression],
+            Expression::Ternary(expression) => vec![expression],
+            Expression::Coalesce(expression) => vec![expression],
+            Expression::Clone(expression) => vec![expression],
+            Expression::Match(expression) => vec![expression],
+            Expression::Throw(expression) => vec![expression],
+            Expression::Yield(expression) => vec![expression],
+            Expression::YieldFrom(expression) => vec![expression],
+            Expression::Cast(expression) => vec![expression],
+            Expression::Noop(_) => vec![],
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Expression {
+    fn span(&self) -> SpanRange {
+        match self {
+            Expression::Eval(expression) => expression.span(),
+            Expression::Empty(expression) => expression.span(),
+            Expression::Die(expression) => expression.span(),
+            Expression::Exit(expression) => expression.span(),
+            Expression::Isset(expression) => expression.span(),
+            Expression::Unset(expression) => expression.span(),
+            Expression::Print(expression) => expression.span(),
+            Expression::Literal(expression) => expression.span(),
+            Expression::ArithmeticOperation(expression) => expression.span(),
+            Expression::AssignmentOperation(expression) => expression.span(),
+            Expression::BitwiseOperation(expression) => expression.span(),
+            Expression::ComparisonOperation(expression) => expression.span(),
+            Expression::LogicalOperation(expression) => expression.span(),
+            Expression::Concat(expression) => expression.span(),
+            Expression::Instanceof(expression) => expression.span(),
+            Expression::Reference(expression) => expression.span(),
+            Expression::Parenthesized(expression) => expression.span(),
+            Expression::ErrorSuppress(expression) => expression.span(),
+            Expression::Identifier(expression) => expression.span(),
+            Expression::Variable(expression) => expression.span(),
+            Expression::Include(expression) => expression.span(),
+            Expression::IncludeOnce(expression) => expression.span(),
+            Expression::Require(expression) => expression.span(),
+            Expression::RequireOnce(expression) => expression.span(),
+            Expression::FunctionCall(expression) => expression.span(),
+            Expression::FunctionClosureCreation(expression) => expression.span(),
+            Expression::MethodCall(expression) => expression.span(),
+            Expression::MethodClosureCreation(expression) => expression.span(),
+            Expression::NullsafeMethodCall(expression) => expression.span(),
+            Expression::StaticMethodCall(expression) => expression.span(),
+            Expression::StaticVariableMethodCall(expression) => expression.span(),
+            Expression::StaticMethodClosureCreation(expression) => expression.span(),
+            Expression::StaticVariableMethodClosureCreation(expression) => expression.span(),
+            Expression::PropertyFetch(expression) => expression.span(),
+            Expression::NullsafePropertyFetch(expression) => expression.span(),
+            Expression::StaticPropertyFetch(expression) => expression.span(),
+            Expression::ConstantFetch(expression) => expression.span(),
+            Expression::DynamicConstantFetch(expression) => expression.span(),
+            Expression::Static(span) => SpanRange::token(*span, b"static"),
+            Expression::Self_(span) => SpanRange::token(*span, b"self"),
+            Expression::Parent(span) => SpanRange::token(*span, b"parent"),
+            Expression::ShortArray(expression) => expression.span(),
+            Expression::Array(expression) => expression.span(),
+            Expression::List(expression) => expression.span(),
+            Expression::Closure(expression) => expression.span(),
+            Expression::ArrowFunction(expression) => expression.span(),
+            Expression::New(expression) => expression.span(),
+            Expression::InterpolatedString(expression) => expression.span(),
+            Expression::Heredoc(expression) => expression.span(),
+            Expression::Nowdoc(expression) => expression.span(),
+            Expression::ShellExec(expression) => expression.span(),
+            Expression::AnonymousClass(expression) => expression.span(),
+            Expression::Bool(expression) => expression.span(),
+            Expression::ArrayIndex(expression) => expression.span(),
+            Expression::Null(span) => SpanRange::token(*span, b"null"),
+            Expression::MagicConstant(expression) => expression.span(),
+            Expression::ShortTernary(expression) => expression.span(),
+            Expression::Ternary(expression) => expression.span(),
+            Expression::Coalesce(expression) => expression.span(),
+            Expression::Clone(expression) => expression.span(),
+            Expression::Match(expression) => expression.span(),
+            Expression::Throw(expression) => expression.span(),
+            Expression::Yield(expression) => expression.span(),
+            Expression::YieldFrom(expression) => expression.span(),
+            Expression::Cast(expression) => expression.span(),
+            Expression::Noop(span) => SpanRange::token(*span, b":"),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct DefaultMatchArm {
+    pub comments: CommentGroup,
     pub keyword: Span,      // `default`
     pub double_arrow: Span, // `=>`
     pub body: Expression,   // `foo()`
 }
 
 impl Node for DefaultMatchArm {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.body]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.body]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for DefaultMatchArm {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.keyword, self.body.span().end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct MatchArm {
+    pub comments: CommentGroup,
     pub conditions: Vec<Expression>,
     pub arrow: Span,
     pub body: Expression,
 }
 
 impl Node for MatchArm {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .conditions
+            .iter()
+            .map(|condition| condition as &dyn Node)
+            .collect();
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = self
             .conditions
             .iter_mut()
@@ -1391,7 +2777,19 @@ impl Node for MatchArm {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for MatchArm {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .conditions
+            .first()
+            .map_or(self.arrow, |condition| condition.span().start);
+
+        SpanRange::new(start, self.body.span().end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum MagicConstantExpression {
     Directory(Span),
@@ -1409,15 +2807,36 @@ impl Node for MagicConstantExpression {
     //
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for MagicConstantExpression {
+    fn span(&self) -> SpanRange {
+        let (span, text): (&Span, &[u8]) = match self {
+            MagicConstantExpression::Directory(span) => (span, b"__DIR__"),
+            MagicConstantExpression::File(span) => (span, b"__FILE__"),
+            MagicConstantExpression::Line(span) => (span, b"__LINE__"),
+            MagicConstantExpression::Class(span) => (span, b"__CLASS__"),
+            MagicConstantExpression::Function(span) => (span, b"__FUNCTION__"),
+            MagicConstantExpression::Method(span) => (span, b"__METHOD__"),
+            MagicConstantExpression::Namespace(span) => (span, b"__NAMESPACE__"),
+            MagicConstantExpression::Trait(span) => (span, b"__TRAIT__"),
+            MagicConstantExpression::CompilerHaltOffset(span) => {
+                (span, b"__COMPILER_HALT_OFFSET__")
+            }
+        };
+
+        SpanRange::token(*span, text)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum StringPart {
     Literal(LiteralStringPart),
     Expression(ExpressionStringPart),
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct LiteralStringPart {
     pub value: ByteString,
 }
@@ -1426,20 +2845,31 @@ impl Node for LiteralStringPart {
     //
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ExpressionStringPart {
     pub expression: Box<Expression>,
 }
 
 impl Node for ExpressionStringPart {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.expression.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.expression.as_mut()]
     }
 }
 
 impl Node for StringPart {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            StringPart::Literal(part) => vec![part],
+            StringPart::Expression(part) => vec![part],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             StringPart::Literal(part) => vec![part],
             StringPart::Expression(part) => vec![part],
@@ -1447,7 +2877,8 @@ impl Node for StringPart {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum ArrayItem {
     Skipped,
@@ -1476,7 +2907,30 @@ pub enum ArrayItem {
 }
 
 impl Node for ArrayItem {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            ArrayItem::Skipped => vec![],
+            ArrayItem::Value { value } => vec![value],
+            ArrayItem::ReferencedValue {
+                ampersand: _,
+                value,
+            } => vec![value],
+            ArrayItem::SpreadValue { ellipsis: _, value } => vec![value],
+            ArrayItem::KeyValue {
+                key,
+                double_arrow: _,
+                value,
+            } => vec![key, value],
+            ArrayItem::ReferencedKeyValue {
+                key,
+                double_arrow: _,
+                ampersand: _,
+                value,
+            } => vec![key, value],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             ArrayItem::Skipped => vec![],
             ArrayItem::Value { value } => vec![value],
@@ -1500,7 +2954,9 @@ impl Node for ArrayItem {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum ListEntry {
     Skipped,
@@ -1515,7 +2971,19 @@ pub enum ListEntry {
 }
 
 impl Node for ListEntry {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            ListEntry::Skipped => vec![],
+            ListEntry::Value { value } => vec![value],
+            ListEntry::KeyValue {
+                key,
+                double_arrow: _,
+                value,
+            } => vec![key, value],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             ListEntry::Skipped => vec![],
             ListEntry::Value { value } => vec![value],
@@ -1,10 +1,15 @@
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
+use alloc::vec::Vec;
+use core::fmt::Display;
+
 use crate::lexer::token::Span;
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub enum Visibility {
     Public,
@@ -12,7 +17,18 @@ pub enum Visibility {
     Private,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Display for Visibility {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Visibility::Public => write!(f, "public"),
+            Visibility::Protected => write!(f, "protected"),
+            Visibility::Private => write!(f, "private"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum VisibilityModifier {
     Public(Span),
@@ -20,12 +36,36 @@ pub enum VisibilityModifier {
     Private(Span),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+impl VisibilityModifier {
+    pub fn span(&self) -> Span {
+        match self {
+            VisibilityModifier::Public(span) => *span,
+            VisibilityModifier::Protected(span) => *span,
+            VisibilityModifier::Private(span) => *span,
+        }
+    }
+}
+
+impl Display for VisibilityModifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VisibilityModifier::Public(_) => write!(f, "public"),
+            VisibilityModifier::Protected(_) => write!(f, "protected"),
+            VisibilityModifier::Private(_) => write!(f, "private"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum PromotedPropertyModifier {
     Public(Span),
     Protected(Span),
     Private(Span),
+    PublicSet(Span),
+    ProtectedSet(Span),
+    PrivateSet(Span),
     Readonly(Span),
 }
 
@@ -35,23 +75,30 @@ impl PromotedPropertyModifier {
             PromotedPropertyModifier::Public(span) => *span,
             PromotedPropertyModifier::Protected(span) => *span,
             PromotedPropertyModifier::Private(span) => *span,
+            PromotedPropertyModifier::PublicSet(span) => *span,
+            PromotedPropertyModifier::ProtectedSet(span) => *span,
+            PromotedPropertyModifier::PrivateSet(span) => *span,
             PromotedPropertyModifier::Readonly(span) => *span,
         }
     }
 }
 
-impl std::fmt::Display for PromotedPropertyModifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Display for PromotedPropertyModifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             PromotedPropertyModifier::Public(_) => write!(f, "public"),
             PromotedPropertyModifier::Protected(_) => write!(f, "protected"),
             PromotedPropertyModifier::Private(_) => write!(f, "private"),
+            PromotedPropertyModifier::PublicSet(_) => write!(f, "public(set)"),
+            PromotedPropertyModifier::ProtectedSet(_) => write!(f, "protected(set)"),
+            PromotedPropertyModifier::PrivateSet(_) => write!(f, "private(set)"),
             PromotedPropertyModifier::Readonly(_) => write!(f, "readonly"),
         }
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[repr(transparent)]
 pub struct PromotedPropertyModifierGroup {
     pub modifiers: Vec<PromotedPropertyModifier>,
@@ -85,14 +132,35 @@ impl PromotedPropertyModifierGroup {
             })
             .unwrap_or(Visibility::Public)
     }
+
+    /// The visibility of writing to the property, as in `private(set)`, when
+    /// it differs from the visibility of reading it.
+    pub fn set_visibility(&self) -> Option<(Visibility, Span)> {
+        self.modifiers.iter().find_map(|modifier| match modifier {
+            PromotedPropertyModifier::PublicSet(span) => Some((Visibility::Public, *span)),
+            PromotedPropertyModifier::ProtectedSet(span) => Some((Visibility::Protected, *span)),
+            PromotedPropertyModifier::PrivateSet(span) => Some((Visibility::Private, *span)),
+            _ => None,
+        })
+    }
+}
+
+impl Display for PromotedPropertyModifierGroup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_modifiers(f, &self.modifiers)
+    }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum PropertyModifier {
     Public(Span),
     Protected(Span),
     Private(Span),
+    PublicSet(Span),
+    ProtectedSet(Span),
+    PrivateSet(Span),
     Static(Span),
     Readonly(Span),
 }
@@ -103,13 +171,32 @@ impl PropertyModifier {
             PropertyModifier::Public(span) => *span,
             PropertyModifier::Protected(span) => *span,
             PropertyModifier::Private(span) => *span,
+            PropertyModifier::PublicSet(span) => *span,
+            PropertyModifier::ProtectedSet(span) => *span,
+            PropertyModifier::PrivateSet(span) => *span,
             PropertyModifier::Static(span) => *span,
             PropertyModifier::Readonly(span) => *span,
         }
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+impl Display for PropertyModifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PropertyModifier::Public(_) => write!(f, "public"),
+            PropertyModifier::Protected(_) => write!(f, "protected"),
+            PropertyModifier::Private(_) => write!(f, "private"),
+            PropertyModifier::PublicSet(_) => write!(f, "public(set)"),
+            PropertyModifier::ProtectedSet(_) => write!(f, "protected(set)"),
+            PropertyModifier::PrivateSet(_) => write!(f, "private(set)"),
+            PropertyModifier::Static(_) => write!(f, "static"),
+            PropertyModifier::Readonly(_) => write!(f, "readonly"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[repr(transparent)]
 pub struct PropertyModifierGroup {
     pub modifiers: Vec<PropertyModifier>,
@@ -155,9 +242,27 @@ impl PropertyModifierGroup {
             })
             .unwrap_or(Visibility::Public)
     }
+
+    /// The visibility of writing to the property, as in `private(set)`, when
+    /// it differs from the visibility of reading it.
+    pub fn set_visibility(&self) -> Option<(Visibility, Span)> {
+        self.modifiers.iter().find_map(|modifier| match modifier {
+            PropertyModifier::PublicSet(span) => Some((Visibility::Public, *span)),
+            PropertyModifier::ProtectedSet(span) => Some((Visibility::Protected, *span)),
+            PropertyModifier::PrivateSet(span) => Some((Visibility::Private, *span)),
+            _ => None,
+        })
+    }
+}
+
+impl Display for PropertyModifierGroup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_modifiers(f, &self.modifiers)
+    }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum MethodModifier {
     Final(Span),
@@ -181,7 +286,21 @@ impl MethodModifier {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+impl Display for MethodModifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MethodModifier::Final(_) => write!(f, "final"),
+            MethodModifier::Static(_) => write!(f, "static"),
+            MethodModifier::Abstract(_) => write!(f, "abstract"),
+            MethodModifier::Public(_) => write!(f, "public"),
+            MethodModifier::Protected(_) => write!(f, "protected"),
+            MethodModifier::Private(_) => write!(f, "private"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[repr(transparent)]
 pub struct MethodModifierGroup {
     pub modifiers: Vec<MethodModifier>,
@@ -229,7 +348,14 @@ impl MethodModifierGroup {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+impl Display for MethodModifierGroup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_modifiers(f, &self.modifiers)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum ClassModifier {
     Final(Span),
@@ -237,7 +363,28 @@ pub enum ClassModifier {
     Readonly(Span),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+impl ClassModifier {
+    pub fn span(&self) -> Span {
+        match self {
+            ClassModifier::Final(span) => *span,
+            ClassModifier::Abstract(span) => *span,
+            ClassModifier::Readonly(span) => *span,
+        }
+    }
+}
+
+impl Display for ClassModifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ClassModifier::Final(_) => write!(f, "final"),
+            ClassModifier::Abstract(_) => write!(f, "abstract"),
+            ClassModifier::Readonly(_) => write!(f, "readonly"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[repr(transparent)]
 pub struct ClassModifierGroup {
     pub modifiers: Vec<ClassModifier>,
@@ -267,7 +414,14 @@ impl ClassModifierGroup {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+impl Display for ClassModifierGroup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_modifiers(f, &self.modifiers)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum ConstantModifier {
     Final(Span),
@@ -276,7 +430,30 @@ pub enum ConstantModifier {
     Private(Span),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+impl ConstantModifier {
+    pub fn span(&self) -> Span {
+        match self {
+            ConstantModifier::Final(span) => *span,
+            ConstantModifier::Public(span) => *span,
+            ConstantModifier::Protected(span) => *span,
+            ConstantModifier::Private(span) => *span,
+        }
+    }
+}
+
+impl Display for ConstantModifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConstantModifier::Final(_) => write!(f, "final"),
+            ConstantModifier::Public(_) => write!(f, "public"),
+            ConstantModifier::Protected(_) => write!(f, "protected"),
+            ConstantModifier::Private(_) => write!(f, "private"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[repr(transparent)]
 pub struct ConstantModifierGroup {
     pub modifiers: Vec<ConstantModifier>,
@@ -305,3 +482,57 @@ impl ConstantModifierGroup {
             .unwrap_or(Visibility::Public)
     }
 }
+
+impl Display for ConstantModifierGroup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_modifiers(f, &self.modifiers)
+    }
+}
+
+// Modifiers are separated by a single space, as in `final public static`.
+fn write_modifiers<T: Display>(
+    f: &mut core::fmt::Formatter<'_>,
+    modifiers: &[T],
+) -> core::fmt::Result {
+    for (index, modifier) in modifiers.iter().enumerate() {
+        if index > 0 {
+            write!(f, " ")?;
+        }
+
+        write!(f, "{}", modifier)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::ast::classes::ClassMember;
+    use crate::parser::ast::Statement;
+
+    #[test]
+    fn test_display() {
+        let program = crate::parse(
+            "<?php final class Foo { final public static function a() {} protected readonly int $b; }",
+        )
+        .unwrap();
+
+        let Some(Statement::Class(class)) = program.get(1) else {
+            panic!("expected a class, found {:?}", program.get(1));
+        };
+
+        assert_eq!(class.modifiers.to_string(), "final");
+
+        let modifiers = class
+            .body
+            .iter()
+            .map(|member| match member {
+                ClassMember::ConcreteMethod(method) => method.modifiers.to_string(),
+                ClassMember::Property(property) => property.modifiers.to_string(),
+                member => panic!("unexpected member {:?}", member),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(modifiers, ["final public static", "protected readonly"]);
+    }
+}
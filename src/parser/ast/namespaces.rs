@@ -1,3 +1,8 @@
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -5,10 +10,12 @@ use serde::Serialize;
 use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 use crate::parser::ast::Statement;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct UnbracedNamespace {
     pub start: Span,                // `namespace`
     pub name: SimpleIdentifier,     // `Foo`
@@ -17,7 +24,18 @@ pub struct UnbracedNamespace {
 }
 
 impl Node for UnbracedNamespace {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children = vec![&self.name as &dyn Node];
+        children.extend(
+            self.statements
+                .iter()
+                .map(|s| s as &dyn Node)
+                .collect::<Vec<&dyn Node>>(),
+        );
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children = vec![&mut self.name as &mut dyn Node];
         children.extend(
             self.statements
@@ -29,8 +47,19 @@ impl Node for UnbracedNamespace {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for UnbracedNamespace {
+    fn span(&self) -> SpanRange {
+        let end = self
+            .statements
+            .last()
+            .map_or(self.end.after(b";"), |statement| statement.span().end);
 
+        SpanRange::new(self.start, end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct BracedNamespace {
     pub namespace: Span,                // `namespace`
     pub name: Option<SimpleIdentifier>, // `Foo`
@@ -38,7 +67,16 @@ pub struct BracedNamespace {
 }
 
 impl Node for BracedNamespace {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![];
+        if let Some(name) = &self.name {
+            children.push(name);
+        }
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![];
         if let Some(name) = &mut self.name {
             children.push(name);
@@ -48,8 +86,14 @@ impl Node for BracedNamespace {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for BracedNamespace {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.namespace, self.body.end.after(b"}"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct BracedNamespaceBody {
     pub start: Span,                // `{`
     pub end: Span,                  // `}`
@@ -57,7 +101,11 @@ pub struct BracedNamespaceBody {
 }
 
 impl Node for BracedNamespaceBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.statements.iter().map(|s| s as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.statements
             .iter_mut()
             .map(|s| s as &mut dyn Node)
@@ -65,7 +113,14 @@ impl Node for BracedNamespaceBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for BracedNamespaceBody {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(b"}"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum NamespaceStatement {
     Unbraced(UnbracedNamespace), // `namespace Foo; *statements*`
@@ -73,10 +128,133 @@ pub enum NamespaceStatement {
 }
 
 impl Node for NamespaceStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            NamespaceStatement::Unbraced(namespace) => vec![namespace],
+            NamespaceStatement::Braced(namespace) => vec![namespace],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             NamespaceStatement::Unbraced(namespace) => vec![namespace],
             NamespaceStatement::Braced(namespace) => vec![namespace],
         }
     }
 }
+
+impl Spanned for NamespaceStatement {
+    fn span(&self) -> SpanRange {
+        match self {
+            NamespaceStatement::Unbraced(namespace) => namespace.span(),
+            NamespaceStatement::Braced(namespace) => namespace.span(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NamespaceForm {
+    Unbraced, // `namespace Foo;`
+    Braced,   // `namespace Foo { ... }`
+    Global,   // code outside of any namespace declaration
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DeclaredNamespace {
+    pub name: Option<SimpleIdentifier>, // `None` for the global namespace
+    pub form: NamespaceForm,
+    pub span: Option<SpanRange>, // the whole statement, `None` for the global region
+}
+
+pub(crate) fn declared_namespaces(statements: &[Statement]) -> Vec<DeclaredNamespace> {
+    let namespaces: Vec<DeclaredNamespace> = statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::Namespace(NamespaceStatement::Unbraced(namespace)) => {
+                Some(DeclaredNamespace {
+                    name: Some(namespace.name.clone()),
+                    form: NamespaceForm::Unbraced,
+                    span: Some(statement.span()),
+                })
+            }
+            Statement::Namespace(NamespaceStatement::Braced(namespace)) => {
+                Some(DeclaredNamespace {
+                    name: namespace.name.clone(),
+                    form: NamespaceForm::Braced,
+                    span: Some(statement.span()),
+                })
+            }
+            _ => None,
+        })
+        .collect();
+
+    if !namespaces.is_empty() {
+        return namespaces;
+    }
+
+    vec![DeclaredNamespace {
+        name: None,
+        form: NamespaceForm::Global,
+        span: None,
+    }]
+}
+
+pub(crate) fn namespace_names(statements: &[Statement]) -> Vec<String> {
+    declared_namespaces(statements)
+        .into_iter()
+        .filter_map(|namespace| namespace.name)
+        .map(|name| name.value.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_braced_namespaces() {
+        let program = crate::parse(
+            "<?php namespace Foo { function a() {} } namespace Bar\\Baz { function b() {} }",
+        )
+        .unwrap();
+
+        let namespaces = program.namespaces();
+        assert_eq!(namespaces.len(), 2);
+
+        assert_eq!(namespaces[0].name.as_ref().unwrap().value, b"Foo");
+        assert_eq!(namespaces[0].form, NamespaceForm::Braced);
+        assert_eq!(namespaces[0].span.unwrap().start.position, 6);
+        assert_eq!(namespaces[0].span.unwrap().end.position, 39);
+
+        assert_eq!(namespaces[1].name.as_ref().unwrap().value, b"Bar\\Baz");
+        assert_eq!(namespaces[1].form, NamespaceForm::Braced);
+        assert_eq!(namespaces[1].span.unwrap().start.position, 40);
+        assert_eq!(namespaces[1].span.unwrap().end.position, 77);
+
+        assert_eq!(program.namespace_names(), ["Foo", "Bar\\Baz"]);
+    }
+
+    #[test]
+    fn test_unbraced_and_global_namespaces() {
+        let program = crate::parse("<?php namespace Foo; function a() {}").unwrap();
+        let namespaces = program.namespaces();
+        assert_eq!(namespaces.len(), 1);
+        assert_eq!(namespaces[0].form, NamespaceForm::Unbraced);
+
+        let program = crate::parse("<?php namespace { function a() {} }").unwrap();
+        let namespaces = program.namespaces();
+        assert_eq!(namespaces.len(), 1);
+        assert_eq!(namespaces[0].name, None);
+        assert_eq!(namespaces[0].form, NamespaceForm::Braced);
+
+        let program = crate::parse("<?php function a() {}").unwrap();
+        assert_eq!(
+            program.namespaces(),
+            vec![DeclaredNamespace {
+                name: None,
+                form: NamespaceForm::Global,
+                span: None,
+            }]
+        );
+    }
+}
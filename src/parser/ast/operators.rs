@@ -1,3 +1,7 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -5,8 +9,11 @@ use serde::Serialize;
 use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::Expression;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum ArithmeticOperationExpression {
     Addition {
@@ -65,8 +72,57 @@ pub enum ArithmeticOperationExpression {
     },
 }
 
+impl ArithmeticOperationExpression {
+    /// Returns the operator as it is written in PHP, such as `+`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ArithmeticOperationExpression::Addition { .. } => "+",
+            ArithmeticOperationExpression::Subtraction { .. } => "-",
+            ArithmeticOperationExpression::Multiplication { .. } => "*",
+            ArithmeticOperationExpression::Division { .. } => "/",
+            ArithmeticOperationExpression::Modulo { .. } => "%",
+            ArithmeticOperationExpression::Exponentiation { .. } => "**",
+            ArithmeticOperationExpression::Negative { .. } => "-",
+            ArithmeticOperationExpression::Positive { .. } => "+",
+            ArithmeticOperationExpression::PreIncrement { .. } => "++",
+            ArithmeticOperationExpression::PostIncrement { .. } => "++",
+            ArithmeticOperationExpression::PreDecrement { .. } => "--",
+            ArithmeticOperationExpression::PostDecrement { .. } => "--",
+        }
+    }
+}
+
 impl Node for ArithmeticOperationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            ArithmeticOperationExpression::Addition { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ArithmeticOperationExpression::Subtraction { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ArithmeticOperationExpression::Multiplication { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ArithmeticOperationExpression::Division { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ArithmeticOperationExpression::Modulo { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ArithmeticOperationExpression::Exponentiation { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ArithmeticOperationExpression::Negative { right, .. } => vec![right.as_ref()],
+            ArithmeticOperationExpression::Positive { right, .. } => vec![right.as_ref()],
+            ArithmeticOperationExpression::PreIncrement { right, .. } => vec![right.as_ref()],
+            ArithmeticOperationExpression::PostIncrement { left, .. } => vec![left.as_ref()],
+            ArithmeticOperationExpression::PreDecrement { right, .. } => vec![right.as_ref()],
+            ArithmeticOperationExpression::PostDecrement { left, .. } => vec![left.as_ref()],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             ArithmeticOperationExpression::Addition { left, right, .. } => {
                 vec![left.as_mut(), right.as_mut()]
@@ -96,7 +152,41 @@ impl Node for ArithmeticOperationExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ArithmeticOperationExpression {
+    fn span(&self) -> SpanRange {
+        match self {
+            ArithmeticOperationExpression::Addition { left, right, .. }
+            | ArithmeticOperationExpression::Subtraction { left, right, .. }
+            | ArithmeticOperationExpression::Multiplication { left, right, .. }
+            | ArithmeticOperationExpression::Division { left, right, .. }
+            | ArithmeticOperationExpression::Modulo { left, right, .. }
+            | ArithmeticOperationExpression::Exponentiation { left, right, .. } => {
+                left.span().to(right.span())
+            }
+            ArithmeticOperationExpression::Negative { minus, right } => {
+                SpanRange::new(*minus, right.span().end)
+            }
+            ArithmeticOperationExpression::Positive { plus, right } => {
+                SpanRange::new(*plus, right.span().end)
+            }
+            ArithmeticOperationExpression::PreIncrement { increment, right } => {
+                SpanRange::new(*increment, right.span().end)
+            }
+            ArithmeticOperationExpression::PreDecrement { decrement, right } => {
+                SpanRange::new(*decrement, right.span().end)
+            }
+            ArithmeticOperationExpression::PostIncrement { left, increment } => {
+                SpanRange::new(left.span().start, increment.after(b"++"))
+            }
+            ArithmeticOperationExpression::PostDecrement { left, decrement } => {
+                SpanRange::new(left.span().start, decrement.after(b"--"))
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum AssignmentOperationExpression {
     Assign {
@@ -172,6 +262,26 @@ pub enum AssignmentOperationExpression {
 }
 
 impl AssignmentOperationExpression {
+    /// Returns the operator as it is written in PHP, such as `=`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            AssignmentOperationExpression::Assign { .. } => "=",
+            AssignmentOperationExpression::Addition { .. } => "+=",
+            AssignmentOperationExpression::Subtraction { .. } => "-=",
+            AssignmentOperationExpression::Multiplication { .. } => "*=",
+            AssignmentOperationExpression::Division { .. } => "/=",
+            AssignmentOperationExpression::Modulo { .. } => "%=",
+            AssignmentOperationExpression::Exponentiation { .. } => "**=",
+            AssignmentOperationExpression::Concat { .. } => ".=",
+            AssignmentOperationExpression::BitwiseAnd { .. } => "&=",
+            AssignmentOperationExpression::BitwiseOr { .. } => "|=",
+            AssignmentOperationExpression::BitwiseXor { .. } => "^=",
+            AssignmentOperationExpression::LeftShift { .. } => "<<=",
+            AssignmentOperationExpression::RightShift { .. } => ">>=",
+            AssignmentOperationExpression::Coalesce { .. } => "??=",
+        }
+    }
+
     pub fn left(&self) -> &Expression {
         match self {
             AssignmentOperationExpression::Assign { left, .. } => left.as_ref(),
@@ -241,7 +351,54 @@ impl AssignmentOperationExpression {
 }
 
 impl Node for AssignmentOperationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            AssignmentOperationExpression::Assign { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::Addition { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::Subtraction { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::Multiplication { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::Division { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::Modulo { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::Exponentiation { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::Concat { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::BitwiseAnd { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::BitwiseOr { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::BitwiseXor { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::LeftShift { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::RightShift { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            AssignmentOperationExpression::Coalesce { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             AssignmentOperationExpression::Assign { left, right, .. } => {
                 vec![left.as_mut(), right.as_mut()]
@@ -289,7 +446,31 @@ impl Node for AssignmentOperationExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for AssignmentOperationExpression {
+    fn span(&self) -> SpanRange {
+        match self {
+            AssignmentOperationExpression::Assign { left, right, .. }
+            | AssignmentOperationExpression::Addition { left, right, .. }
+            | AssignmentOperationExpression::Subtraction { left, right, .. }
+            | AssignmentOperationExpression::Multiplication { left, right, .. }
+            | AssignmentOperationExpression::Division { left, right, .. }
+            | AssignmentOperationExpression::Modulo { left, right, .. }
+            | AssignmentOperationExpression::Exponentiation { left, right, .. }
+            | AssignmentOperationExpression::Concat { left, right, .. }
+            | AssignmentOperationExpression::BitwiseAnd { left, right, .. }
+            | AssignmentOperationExpression::BitwiseOr { left, right, .. }
+            | AssignmentOperationExpression::BitwiseXor { left, right, .. }
+            | AssignmentOperationExpression::LeftShift { left, right, .. }
+            | AssignmentOperationExpression::RightShift { left, right, .. }
+            | AssignmentOperationExpression::Coalesce { left, right, .. } => {
+                left.span().to(right.span())
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum BitwiseOperationExpression {
     And {
@@ -323,8 +504,43 @@ pub enum BitwiseOperationExpression {
     },
 }
 
+impl BitwiseOperationExpression {
+    /// Returns the operator as it is written in PHP, such as `&`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BitwiseOperationExpression::And { .. } => "&",
+            BitwiseOperationExpression::Or { .. } => "|",
+            BitwiseOperationExpression::Xor { .. } => "^",
+            BitwiseOperationExpression::LeftShift { .. } => "<<",
+            BitwiseOperationExpression::RightShift { .. } => ">>",
+            BitwiseOperationExpression::Not { .. } => "~",
+        }
+    }
+}
+
 impl Node for BitwiseOperationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            BitwiseOperationExpression::And { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            BitwiseOperationExpression::Or { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            BitwiseOperationExpression::Xor { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            BitwiseOperationExpression::LeftShift { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            BitwiseOperationExpression::RightShift { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            BitwiseOperationExpression::Not { right, .. } => vec![right.as_ref()],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             BitwiseOperationExpression::And { left, right, .. } => {
                 vec![left.as_mut(), right.as_mut()]
@@ -346,7 +562,25 @@ impl Node for BitwiseOperationExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for BitwiseOperationExpression {
+    fn span(&self) -> SpanRange {
+        match self {
+            BitwiseOperationExpression::And { left, right, .. }
+            | BitwiseOperationExpression::Or { left, right, .. }
+            | BitwiseOperationExpression::Xor { left, right, .. }
+            | BitwiseOperationExpression::LeftShift { left, right, .. }
+            | BitwiseOperationExpression::RightShift { left, right, .. } => {
+                left.span().to(right.span())
+            }
+            BitwiseOperationExpression::Not { not, right } => {
+                SpanRange::new(*not, right.span().end)
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum ComparisonOperationExpression {
     Equal {
@@ -401,8 +635,61 @@ pub enum ComparisonOperationExpression {
     },
 }
 
+impl ComparisonOperationExpression {
+    /// Returns the operator as it is written in PHP, such as `==`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ComparisonOperationExpression::Equal { .. } => "==",
+            ComparisonOperationExpression::Identical { .. } => "===",
+            ComparisonOperationExpression::NotEqual { .. } => "!=",
+            ComparisonOperationExpression::AngledNotEqual { .. } => "<>",
+            ComparisonOperationExpression::NotIdentical { .. } => "!==",
+            ComparisonOperationExpression::LessThan { .. } => "<",
+            ComparisonOperationExpression::GreaterThan { .. } => ">",
+            ComparisonOperationExpression::LessThanOrEqual { .. } => "<=",
+            ComparisonOperationExpression::GreaterThanOrEqual { .. } => ">=",
+            ComparisonOperationExpression::Spaceship { .. } => "<=>",
+        }
+    }
+}
+
 impl Node for ComparisonOperationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            ComparisonOperationExpression::Equal { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ComparisonOperationExpression::Identical { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ComparisonOperationExpression::NotEqual { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ComparisonOperationExpression::AngledNotEqual { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ComparisonOperationExpression::NotIdentical { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ComparisonOperationExpression::LessThan { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ComparisonOperationExpression::GreaterThan { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ComparisonOperationExpression::LessThanOrEqual { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ComparisonOperationExpression::GreaterThanOrEqual { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            ComparisonOperationExpression::Spaceship { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             ComparisonOperationExpression::Equal { left, right, .. } => {
                 vec![left.as_mut(), right.as_mut()]
@@ -438,7 +725,27 @@ impl Node for ComparisonOperationExpression {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for ComparisonOperationExpression {
+    fn span(&self) -> SpanRange {
+        match self {
+            ComparisonOperationExpression::Equal { left, right, .. }
+            | ComparisonOperationExpression::Identical { left, right, .. }
+            | ComparisonOperationExpression::NotEqual { left, right, .. }
+            | ComparisonOperationExpression::AngledNotEqual { left, right, .. }
+            | ComparisonOperationExpression::NotIdentical { left, right, .. }
+            | ComparisonOperationExpression::LessThan { left, right, .. }
+            | ComparisonOperationExpression::GreaterThan { left, right, .. }
+            | ComparisonOperationExpression::LessThanOrEqual { left, right, .. }
+            | ComparisonOperationExpression::GreaterThanOrEqual { left, right, .. }
+            | ComparisonOperationExpression::Spaceship { left, right, .. } => {
+                left.span().to(right.span())
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum LogicalOperationExpression {
     And {
@@ -472,8 +779,43 @@ pub enum LogicalOperationExpression {
     },
 }
 
+impl LogicalOperationExpression {
+    /// Returns the operator as it is written in PHP, such as `&&`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            LogicalOperationExpression::And { .. } => "&&",
+            LogicalOperationExpression::Or { .. } => "||",
+            LogicalOperationExpression::Not { .. } => "!",
+            LogicalOperationExpression::LogicalAnd { .. } => "and",
+            LogicalOperationExpression::LogicalOr { .. } => "or",
+            LogicalOperationExpression::LogicalXor { .. } => "xor",
+        }
+    }
+}
+
 impl Node for LogicalOperationExpression {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            LogicalOperationExpression::And { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            LogicalOperationExpression::Or { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            LogicalOperationExpression::Not { right, .. } => vec![right.as_ref()],
+            LogicalOperationExpression::LogicalAnd { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            LogicalOperationExpression::LogicalOr { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+            LogicalOperationExpression::LogicalXor { left, right, .. } => {
+                vec![left.as_ref(), right.as_ref()]
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             LogicalOperationExpression::And { left, right, .. } => {
                 vec![left.as_mut(), right.as_mut()]
@@ -494,3 +836,20 @@ impl Node for LogicalOperationExpression {
         }
     }
 }
+
+impl Spanned for LogicalOperationExpression {
+    fn span(&self) -> SpanRange {
+        match self {
+            LogicalOperationExpression::And { left, right, .. }
+            | LogicalOperationExpression::Or { left, right, .. }
+            | LogicalOperationExpression::LogicalAnd { left, right, .. }
+            | LogicalOperationExpression::LogicalOr { left, right, .. }
+            | LogicalOperationExpression::LogicalXor { left, right, .. } => {
+                left.span().to(right.span())
+            }
+            LogicalOperationExpression::Not { bang, right } => {
+                SpanRange::new(*bang, right.span().end)
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::any::TypeId;
+
+use crate::node::Node;
+use crate::parser::ast::classes::AnonymousClassExpression;
+use crate::parser::ast::classes::ClassStatement;
+use crate::parser::ast::enums::BackedEnumStatement;
+use crate::parser::ast::enums::UnitEnumStatement;
+use crate::parser::ast::functions::AbstractConstructor;
+use crate::parser::ast::functions::AbstractMethod;
+use crate::parser::ast::functions::ArrowFunctionExpression;
+use crate::parser::ast::functions::ClosureExpression;
+use crate::parser::ast::functions::ConcreteConstructor;
+use crate::parser::ast::functions::ConcreteMethod;
+use crate::parser::ast::functions::FunctionStatement;
+use crate::parser::ast::interfaces::InterfaceStatement;
+use crate::parser::ast::traits::TraitStatement;
+use crate::parser::ast::Program;
+
+/// Identifies a node by its position in a pre-order traversal of the program,
+/// the program itself being `0`.
+pub type NodeId = usize;
+
+/// The parent of every node in a program, computed by traversing the AST
+/// rather than stored on the nodes themselves.
+///
+/// The map refers to nodes by [`NodeId`]; use [`ParentMap::id_of`] to find the
+/// ID of a node and [`ParentMap::get`] to go back from an ID to the node. Both
+/// are only meaningful as long as the program is not modified or moved.
+#[derive(Debug, Clone, Default)]
+pub struct ParentMap {
+    parents: Vec<Option<NodeId>>,
+    kinds: Vec<TypeId>,
+    ids: BTreeMap<(usize, TypeId), NodeId>,
+}
+
+pub fn parent_map(program: &Program) -> ParentMap {
+    let mut builder = ParentMapBuilder {
+        map: ParentMap::default(),
+        stack: vec![],
+    };
+
+    builder.visit(program);
+
+    builder.map
+}
+
+impl ParentMap {
+    /// Returns the number of nodes in the map.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the ID of the given node, if it belongs to the mapped program.
+    pub fn id_of(&self, node: &dyn Node) -> Option<NodeId> {
+        self.ids.get(&key(node)).copied()
+    }
+
+    /// Returns the ID of the parent of the given node, or `None` for the root.
+    pub fn parent_of(&self, id: NodeId) -> Option<NodeId> {
+        self.parents.get(id).copied().flatten()
+    }
+
+    /// Returns the IDs of the ancestors of the given node, closest first.
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        core::iter::successors(self.parent_of(id), |id| self.parent_of(*id))
+    }
+
+    /// Returns `true` if the node with the given ID is a `T`.
+    pub fn is<T: Node>(&self, id: NodeId) -> bool {
+        self.kinds.get(id) == Some(&TypeId::of::<T>())
+    }
+
+    /// Returns the closest function, closure, arrow function, method or
+    /// constructor containing the given node.
+    pub fn enclosing_function(&self, id: NodeId) -> Option<NodeId> {
+        self.ancestors(id).find(|id| {
+            self.is::<FunctionStatement>(*id)
+                || self.is::<ClosureExpression>(*id)
+                || self.is::<ArrowFunctionExpression>(*id)
+                || self.is::<ConcreteMethod>(*id)
+                || self.is::<AbstractMethod>(*id)
+                || self.is::<ConcreteConstructor>(*id)
+                || self.is::<AbstractConstructor>(*id)
+        })
+    }
+
+    /// Returns the closest class, anonymous class, interface, trait or enum
+    /// containing the given node.
+    pub fn enclosing_classish(&self, id: NodeId) -> Option<NodeId> {
+        self.ancestors(id).find(|id| {
+            self.is::<ClassStatement>(*id)
+                || self.is::<AnonymousClassExpression>(*id)
+                || self.is::<InterfaceStatement>(*id)
+                || self.is::<TraitStatement>(*id)
+                || self.is::<UnitEnumStatement>(*id)
+                || self.is::<BackedEnumStatement>(*id)
+        })
+    }
+
+    /// Returns the node with the given ID in the mapped program.
+    pub fn get<'a>(&self, program: &'a Program, id: NodeId) -> Option<&'a dyn Node> {
+        fn find<'a>(node: &'a dyn Node, id: NodeId, next: &mut NodeId) -> Option<&'a dyn Node> {
+            if *next == id {
+                return Some(node);
+            }
+
+            *next += 1;
+            for child in node.children() {
+                if let Some(found) = find(child, id, next) {
+                    return Some(found);
+                }
+            }
+
+            None
+        }
+
+        find(program, id, &mut 0)
+    }
+}
+
+// A wrapper enum and the node it holds may live at the same address, so
+// nodes are told apart by their type as well.
+fn key(node: &dyn Node) -> (usize, TypeId) {
+    (
+        node as *const dyn Node as *const () as usize,
+        (*node).type_id(),
+    )
+}
+
+struct ParentMapBuilder {
+    map: ParentMap,
+    stack: Vec<NodeId>,
+}
+
+impl ParentMapBuilder {
+    fn visit(&mut self, node: &dyn Node) {
+        let id = self.map.parents.len();
+
+        self.map.parents.push(self.stack.last().copied());
+        self.map.kinds.push((*node).type_id());
+        self.map.ids.entry(key(node)).or_insert(id);
+
+        self.stack.push(id);
+        for child in node.children() {
+            self.visit(child);
+        }
+        self.stack.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::downcast::downcast;
+    use crate::parser::ast::ReturnStatement;
+
+    #[test]
+    fn test_return_inside_anonymous_class_method() {
+        let program = crate::parse(
+            r#"<?php
+
+            function outer() {
+                return new class {
+                    public function inner() {
+                        return 1;
+                    }
+                };
+            }
+            "#,
+        )
+        .unwrap();
+
+        let map = parent_map(&program);
+        let returns = (0..map.len())
+            .filter(|id| map.is::<ReturnStatement>(*id))
+            .collect::<Vec<_>>();
+        assert_eq!(returns.len(), 2);
+
+        let function = map.enclosing_function(returns[1]).unwrap();
+        assert!(map.is::<ConcreteMethod>(function));
+        let method = downcast::<ConcreteMethod>(map.get(&program, function).unwrap()).unwrap();
+        assert_eq!(method.name.value, b"inner");
+
+        let class = map.enclosing_classish(returns[1]).unwrap();
+        assert!(map.is::<AnonymousClassExpression>(class));
+        assert!(map.ancestors(function).any(|id| id == class));
+
+        let function = map.enclosing_function(class).unwrap();
+        assert!(map.is::<FunctionStatement>(function));
+        assert_eq!(map.enclosing_function(returns[0]), Some(function));
+        assert_eq!(map.enclosing_classish(returns[0]), None);
+
+        assert_eq!(map.ancestors(returns[1]).last(), Some(0));
+        assert_eq!(map.parent_of(0), None);
+
+        let statement = map.get(&program, returns[1]).unwrap();
+        assert_eq!(map.id_of(statement), Some(returns[1]));
+    }
+}
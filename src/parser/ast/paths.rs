@@ -0,0 +1,382 @@
+use alloc::vec::Vec;
+
+use serde::Serialize;
+
+use crate::downcast::downcast;
+use crate::node::Node;
+use crate::parser::ast;
+use crate::parser::ast::arguments;
+use crate::parser::ast::attributes;
+use crate::parser::ast::classes;
+use crate::parser::ast::comments;
+use crate::parser::ast::constant;
+use crate::parser::ast::control_flow;
+use crate::parser::ast::data_type;
+use crate::parser::ast::declares;
+use crate::parser::ast::enums;
+use crate::parser::ast::functions;
+use crate::parser::ast::goto;
+use crate::parser::ast::identifiers;
+use crate::parser::ast::interfaces;
+use crate::parser::ast::literals;
+use crate::parser::ast::loops;
+use crate::parser::ast::namespaces;
+use crate::parser::ast::operators;
+use crate::parser::ast::parents::NodeId;
+use crate::parser::ast::properties;
+use crate::parser::ast::traits;
+use crate::parser::ast::try_block;
+use crate::parser::ast::variables;
+use crate::parser::ast::Program;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
+
+/// A node on a [`NodePath`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PathNode {
+    /// The ID of the node, as given by a [`ParentMap`](crate::parser::ast::ParentMap)
+    /// of the same program.
+    pub id: NodeId,
+    /// The name of the node's type, as given by [`Node::name`], or `Program`
+    /// for the root.
+    pub name: &'static str,
+    pub span: SpanRange,
+}
+
+/// The nodes that contain an offset, from the program down to the innermost
+/// one.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NodePath {
+    pub nodes: Vec<PathNode>,
+}
+
+impl NodePath {
+    /// The innermost node of the path.
+    pub fn innermost(&self) -> Option<&PathNode> {
+        self.nodes.last()
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, PathNode> {
+        self.nodes.iter()
+    }
+}
+
+/// Returns the nodes that contain the byte at `offset`, from the program down
+/// to the innermost one.
+///
+/// A node contains the offsets from its start up to, but not including, its
+/// end, so an offset on the boundary between two nodes is in the second. An
+/// offset in the whitespace or comments between two statements is in the
+/// block that holds them, or in the program for top-level statements. Inline
+/// HTML is a statement of its own. Offsets before the first statement or
+/// after the last have no path.
+///
+/// Nodes without a range of their own, such as a
+/// [`Block`](crate::parser::ast::Block) or an array item, span their children.
+///
+/// ```
+/// use php_parser_rs::parser::ast::node_at_offset;
+///
+/// let program = php_parser_rs::parse("<?php echo $a + 1;").unwrap();
+///
+/// let path = node_at_offset(&program, 11).unwrap();
+/// let names = path.iter().map(|node| node.name).collect::<Vec<_>>();
+///
+/// assert_eq!(names.first(), Some(&"Program"));
+/// assert_eq!(path.innermost().unwrap().name, "SimpleVariable");
+/// ```
+pub fn node_at_offset(program: &Program, offset: usize) -> Option<NodePath> {
+    let mut finder = PathFinder {
+        offset,
+        next: 1,
+        nodes: vec![],
+    };
+
+    let mut span: Option<SpanRange> = None;
+    for statement in program {
+        let collect = finder.nodes.is_empty();
+        let range = finder.visit(statement, collect);
+        span = union(span, range);
+    }
+
+    let span = span.filter(|span| span.contains(offset))?;
+    let mut nodes = vec![PathNode {
+        id: 0,
+        name: "Program",
+        span,
+    }];
+    nodes.append(&mut finder.nodes);
+
+    Some(NodePath { nodes })
+}
+
+struct PathFinder {
+    offset: usize,
+    // The ID of the next node, counted as a `ParentMap` does.
+    next: NodeId,
+    nodes: Vec<PathNode>,
+}
+
+impl PathFinder {
+    // Returns the range of the node, and keeps it and its children on the
+    // path if it contains the offset. Once one child is on the path, its
+    // siblings are only counted.
+    fn visit(&mut self, node: &dyn Node, collect: bool) -> Option<SpanRange> {
+        let id = self.next;
+        self.next += 1;
+
+        let depth = self.nodes.len();
+        let mut children: Option<SpanRange> = None;
+        let mut found = false;
+        for child in node.children() {
+            let range = self.visit(child, collect && !found);
+            found = found || self.nodes.len() > depth;
+            children = union(children, range);
+        }
+
+        let range = range(node).or(children);
+        match range {
+            Some(span) if collect && span.contains(self.offset) => {
+                self.nodes.insert(
+                    depth,
+                    PathNode {
+                        id,
+                        name: node.name(),
+                        span,
+                    },
+                );
+            }
+            _ => self.nodes.truncate(depth),
+        }
+
+        range
+    }
+}
+
+pub(crate) fn union(first: Option<SpanRange>, second: Option<SpanRange>) -> Option<SpanRange> {
+    match (first, second) {
+        (Some(first), Some(second)) => Some(SpanRange::new(
+            core::cmp::min_by_key(first.start, second.start, |span| span.position),
+            core::cmp::max_by_key(first.end, second.end, |span| span.position),
+        )),
+        (first, second) => first.or(second),
+    }
+}
+
+// The range of a node that implements `Spanned`.
+pub(crate) fn range(node: &dyn Node) -> Option<SpanRange> {
+    struct Range(Option<SpanRange>);
+
+    impl WithSpanned for Range {
+        fn with<T: Spanned + Serialize>(&mut self, node: &T) {
+            self.0 = Some(node.span());
+        }
+    }
+
+    let mut range = Range(None);
+    with_spanned(node, &mut range);
+
+    range.0
+}
+
+// Something to do with a node as its concrete type.
+pub(crate) trait WithSpanned {
+    fn with<T: Spanned + Serialize>(&mut self, node: &T);
+}
+
+// Hands the node to `with` as its concrete type, if that type implements
+// `Spanned`.
+pub(crate) fn with_spanned(node: &dyn Node, with: &mut impl WithSpanned) {
+    macro_rules! spanned {
+        ($($node:ty),* $(,)?) => {
+            $(
+                if let Some(node) = downcast::<$node>(node) {
+                    return with.with(node);
+                }
+            )*
+        };
+    }
+
+    spanned!(
+        arguments::PositionalArgument,
+        arguments::NamedArgument,
+        arguments::Argument,
+        arguments::ArgumentList,
+        arguments::SingleArgument,
+        attributes::Attribute,
+        attributes::AttributeGroup,
+        classes::ClassBody,
+        classes::ClassStatement,
+        classes::AnonymousClassBody,
+        classes::AnonymousClassExpression,
+        classes::ClassExtends,
+        classes::ClassImplements,
+        classes::ClassMember,
+        classes::AnonymousClassMember,
+        comments::Comment,
+        constant::ConstantEntry,
+        constant::ConstantStatement,
+        constant::ClassishConstant,
+        control_flow::IfStatement,
+        control_flow::IfStatementBody,
+        control_flow::IfStatementElseIf,
+        control_flow::IfStatementElse,
+        control_flow::IfStatementElseIfBlock,
+        control_flow::IfStatementElseBlock,
+        data_type::Type,
+        declares::DeclareEntry,
+        declares::DeclareEntryGroup,
+        declares::DeclareBody,
+        declares::DeclareStatement,
+        enums::UnitEnumCase,
+        enums::UnitEnumMember,
+        enums::UnitEnumBody,
+        enums::UnitEnumStatement,
+        enums::BackedEnumType,
+        enums::BackedEnumCase,
+        enums::BackedEnumMember,
+        enums::BackedEnumBody,
+        enums::BackedEnumStatement,
+        functions::ReturnType,
+        functions::FunctionParameter,
+        functions::FunctionParameterList,
+        functions::FunctionBody,
+        functions::FunctionStatement,
+        functions::ClosureUseVariable,
+        functions::ClosureUse,
+        functions::ClosureExpression,
+        functions::ArrowFunctionExpression,
+        functions::ConstructorParameter,
+        functions::ConstructorParameterList,
+        functions::AbstractConstructor,
+        functions::ConcreteConstructor,
+        functions::AbstractMethod,
+        functions::ConcreteMethod,
+        functions::MethodBody,
+        goto::LabelStatement,
+        goto::GotoStatement,
+        identifiers::Identifier,
+        identifiers::SimpleIdentifier,
+        identifiers::DynamicIdentifier,
+        interfaces::InterfaceMember,
+        interfaces::InterfaceExtends,
+        interfaces::InterfaceBody,
+        interfaces::InterfaceStatement,
+        literals::Literal,
+        literals::LiteralString,
+        literals::LiteralInteger,
+        literals::LiteralFloat,
+        loops::ForeachStatement,
+        loops::ForeachStatementIterator,
+        loops::ForeachStatementBody,
+        loops::ForStatement,
+        loops::ForStatementIterator,
+        loops::ForStatementBody,
+        loops::DoWhileStatement,
+        loops::WhileStatement,
+        loops::WhileStatementBody,
+        loops::Level,
+        loops::BreakStatement,
+        loops::ContinueStatement,
+        ast::StaticVar,
+        ast::HaltCompilerStatement,
+        ast::StaticStatement,
+        ast::SwitchStatement,
+        ast::EchoStatement,
+        ast::ReturnStatement,
+        ast::UseStatement,
+        ast::GroupUseStatement,
+        ast::InlineHtmlStatement,
+        ast::FullOpeningTagStatement,
+        ast::ShortOpeningTagStatement,
+        ast::EchoOpeningTagStatement,
+        ast::ClosingTagStatement,
+        ast::Statement,
+        ast::ExpressionStatement,
+        ast::GlobalStatement,
+        ast::BlockStatement,
+        ast::Case,
+        ast::Use,
+        ast::FunctionCallExpression,
+        ast::FunctionClosureCreationExpression,
+        ast::MethodCallExpression,
+        ast::MethodClosureCreationExpression,
+        ast::NullsafeMethodCallExpression,
+        ast::StaticMethodCallExpression,
+        ast::StaticVariableMethodCallExpression,
+        ast::StaticMethodClosureCreationExpression,
+        ast::StaticVariableMethodClosureCreationExpression,
+        ast::PropertyFetchExpression,
+        ast::NullsafePropertyFetchExpression,
+        ast::StaticPropertyFetchExpression,
+        ast::ConstantFetchExpression,
+        ast::DynamicConstantFetchExpression,
+        ast::ShortArrayExpression,
+        ast::ArrayExpression,
+        ast::ListExpression,
+        ast::NewExpression,
+        ast::InterpolatedStringExpression,
+        ast::HeredocExpression,
+        ast::NowdocExpression,
+        ast::ShellExecExpression,
+        ast::BoolExpression,
+        ast::ArrayIndexExpression,
+        ast::ShortTernaryExpression,
+        ast::TernaryExpression,
+        ast::CoalesceExpression,
+        ast::CloneExpression,
+        ast::MatchExpression,
+        ast::ThrowExpression,
+        ast::YieldExpression,
+        ast::YieldFromExpression,
+        ast::CastExpression,
+        ast::EvalExpression,
+        ast::EmptyExpression,
+        ast::DieExpression,
+        ast::ExitExpression,
+        ast::IssetExpression,
+        ast::UnsetExpression,
+        ast::PrintExpression,
+        ast::ConcatExpression,
+        ast::InstanceofExpression,
+        ast::ReferenceExpression,
+        ast::ParenthesizedExpression,
+        ast::ErrorSuppressExpression,
+        ast::IncludeExpression,
+        ast::IncludeOnceExpression,
+        ast::RequireExpression,
+        ast::RequireOnceExpression,
+        ast::Expression,
+        ast::DefaultMatchArm,
+        ast::MatchArm,
+        ast::MagicConstantExpression,
+        namespaces::UnbracedNamespace,
+        namespaces::BracedNamespace,
+        namespaces::BracedNamespaceBody,
+        namespaces::NamespaceStatement,
+        operators::ArithmeticOperationExpression,
+        operators::AssignmentOperationExpression,
+        operators::BitwiseOperationExpression,
+        operators::ComparisonOperationExpression,
+        operators::LogicalOperationExpression,
+        properties::Property,
+        properties::VariableProperty,
+        properties::PropertyEntry,
+        properties::PropertyHookList,
+        properties::PropertyHook,
+        properties::PropertyHookBody,
+        traits::TraitMember,
+        traits::TraitBody,
+        traits::TraitStatement,
+        traits::TraitUsage,
+        traits::TraitUsageAdaptation,
+        try_block::CatchType,
+        try_block::TryStatement,
+        try_block::CatchBlock,
+        try_block::FinallyBlock,
+        variables::Variable,
+        variables::SimpleVariable,
+        variables::VariableVariable,
+        variables::BracedVariableVariable,
+    );
+}
@@ -0,0 +1,871 @@
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::parser::ast::arguments::Argument;
+use crate::parser::ast::arguments::ArgumentList;
+use crate::parser::ast::classes::ClassMember;
+use crate::parser::ast::classes::ClassStatement;
+use crate::parser::ast::control_flow::IfStatement;
+use crate::parser::ast::control_flow::IfStatementBody;
+use crate::parser::ast::data_type::Type;
+use crate::parser::ast::functions::ConcreteMethod;
+use crate::parser::ast::functions::FunctionParameterList;
+use crate::parser::ast::functions::FunctionStatement;
+use crate::parser::ast::functions::ReturnType;
+use crate::parser::ast::identifiers::Identifier;
+use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::literals::Literal;
+use crate::parser::ast::literals::LiteralInteger;
+use crate::parser::ast::operators::ArithmeticOperationExpression;
+use crate::parser::ast::operators::AssignmentOperationExpression;
+use crate::parser::ast::operators::BitwiseOperationExpression;
+use crate::parser::ast::operators::ComparisonOperationExpression;
+use crate::parser::ast::operators::LogicalOperationExpression;
+use crate::parser::ast::properties::PropertyEntry;
+use crate::parser::ast::properties::PropertyHookBody;
+use crate::parser::ast::properties::PropertyHookList;
+use crate::parser::ast::variables::Variable;
+use crate::parser::ast::ArrayItem;
+use crate::parser::ast::Expression;
+use crate::parser::ast::Program;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
+use crate::parser::ast::Statement;
+
+/// Converts a program to the JSON dump of [nikic/php-parser] 5.x, for tools
+/// that were written against it.
+///
+/// Functions, classes and their members, `if`, `echo`, `return`, calls,
+/// operators, arrays, variables and literals are mapped onto the node of the
+/// same meaning. Every other node becomes an `X_Unknown` node, whose `kind` is
+/// the name of the statement, expression or class member variant in this
+/// crate, such as `{"nodeType": "X_Unknown", "kind": "Foreach"}`.
+///
+/// Every node has the position attributes of php-parser: `startLine`,
+/// `startFilePos`, `endLine` and `endFilePos`, the offset of its last byte.
+/// Its other attributes, such as its comments, are left out, as are comments
+/// that are statements of their own, and opening and closing tags, which
+/// php-parser does not represent.
+///
+/// [nikic/php-parser]: https://github.com/nikic/PHP-Parser
+pub fn to_php_parser_json(program: &Program) -> Value {
+    Value::Array(statements(program))
+}
+
+fn statements(statements: &[Statement]) -> Vec<Value> {
+    statements
+        .iter()
+        .filter(|statement| {
+            !matches!(
+                statement,
+                Statement::FullOpeningTag(_)
+                    | Statement::ShortOpeningTag(_)
+                    | Statement::EchoOpeningTag(_)
+                    | Statement::ClosingTag(_)
+                    | Statement::Comment(_)
+            )
+        })
+        .map(statement)
+        .collect()
+}
+
+fn statement(statement: &Statement) -> Value {
+    let node = match statement {
+        Statement::InlineHtml(html) => json!({
+            "nodeType": "Stmt_InlineHTML",
+            "value": string(&html.html),
+        }),
+        Statement::Expression(statement) => json!({
+            "nodeType": "Stmt_Expression",
+            "expr": expression(&statement.expression),
+        }),
+        Statement::Echo(echo) => json!({
+            "nodeType": "Stmt_Echo",
+            "exprs": echo.values.iter().map(expression).collect::<Vec<_>>(),
+        }),
+        Statement::Return(statement) => json!({
+            "nodeType": "Stmt_Return",
+            "expr": statement.value.as_ref().map(expression),
+        }),
+        Statement::Function(function) => function_statement(function),
+        Statement::Class(class) => class_statement(class),
+        Statement::If(statement) => if_statement(statement),
+        Statement::Block(block) => json!({
+            "nodeType": "Stmt_Block",
+            "stmts": statements(&block.statements),
+        }),
+        Statement::Noop(_) => json!({ "nodeType": "Stmt_Nop" }),
+        statement => unknown(statement),
+    };
+
+    with_attributes(node, statement.span())
+}
+
+fn function_statement(function: &FunctionStatement) -> Value {
+    json!({
+        "nodeType": "Stmt_Function",
+        "attrGroups": [],
+        "byRef": function.ampersand.is_some(),
+        "name": identifier(&function.name),
+        "params": parameters(&function.parameters),
+        "returnType": return_type(&function.return_type),
+        "stmts": statements(&function.body.statements),
+        "namespacedName": null,
+    })
+}
+
+fn class_statement(class: &ClassStatement) -> Value {
+    let members = class
+        .body
+        .iter()
+        .filter(|member| !matches!(member, ClassMember::Comment(_)))
+        .map(class_member)
+        .collect::<Vec<_>>();
+
+    json!({
+        "nodeType": "Stmt_Class",
+        "attrGroups": [],
+        "flags": flags(&class.modifiers.modifiers),
+        "name": identifier(&class.name),
+        "extends": class.extends.as_ref().map(|extends| simple_name(&extends.parent)),
+        "implements": class
+            .implements
+            .iter()
+            .flat_map(|implements| implements.interfaces.iter())
+            .map(simple_name)
+            .collect::<Vec<_>>(),
+        "stmts": members,
+        "namespacedName": null,
+    })
+}
+
+fn class_member(member: &ClassMember) -> Value {
+    let node = match member {
+        ClassMember::Constant(constant) => json!({
+            "nodeType": "Stmt_ClassConst",
+            "attrGroups": [],
+            "flags": flags(&constant.modifiers.modifiers),
+            "type": constant.r#type.as_ref().map(data_type),
+            "consts": constant
+                .entries
+                .iter()
+                .map(|entry| with_attributes(
+                    json!({
+                        "nodeType": "Const",
+                        "name": identifier(&entry.name),
+                        "value": expression(&entry.value),
+                        "namespacedName": null,
+                    }),
+                    entry.span(),
+                ))
+                .collect::<Vec<_>>(),
+        }),
+        ClassMember::Property(property) => json!({
+            "nodeType": "Stmt_Property",
+            "attrGroups": [],
+            "flags": flags(&property.modifiers.modifiers),
+            "type": property.r#type.as_ref().map(data_type),
+            "props": property
+                .entries
+                .iter()
+                .map(|entry| {
+                    let (variable, default) = match entry {
+                        PropertyEntry::Uninitialized { variable } => (variable, None),
+                        PropertyEntry::Initialized {
+                            variable, value, ..
+                        } => (variable, Some(expression(value))),
+                    };
+
+                    let name = json!({
+                        "nodeType": "VarLikeIdentifier",
+                        "name": variable_name(&variable.name),
+                    });
+
+                    with_attributes(
+                        json!({
+                            "nodeType": "PropertyItem",
+                            "name": with_attributes(name, variable.span()),
+                            "default": default,
+                        }),
+                        entry.span(),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            "hooks": property_hooks(&property.hooks),
+        }),
+        ClassMember::ConcreteMethod(method) => concrete_method(method),
+        ClassMember::AbstractMethod(method) => json!({
+            "nodeType": "Stmt_ClassMethod",
+            "attrGroups": [],
+            "flags": flags(&method.modifiers.modifiers),
+            "byRef": method.ampersand.is_some(),
+            "name": identifier(&method.name),
+            "params": parameters(&method.parameters),
+            "returnType": return_type(&method.return_type),
+            "stmts": null,
+        }),
+        member => unknown(member),
+    };
+
+    with_attributes(node, member.span())
+}
+
+fn concrete_method(method: &ConcreteMethod) -> Value {
+    json!({
+        "nodeType": "Stmt_ClassMethod",
+        "attrGroups": [],
+        "flags": flags(&method.modifiers.modifiers),
+        "byRef": method.ampersand.is_some(),
+        "name": identifier(&method.name),
+        "params": parameters(&method.parameters),
+        "returnType": return_type(&method.return_type),
+        "stmts": statements(&method.body.statements),
+    })
+}
+
+fn if_statement(statement: &IfStatement) -> Value {
+    let (body, elseifs, r#else) = match &statement.body {
+        IfStatementBody::Statement {
+            statement,
+            elseifs,
+            r#else,
+        } => (
+            body(statement),
+            elseifs
+                .iter()
+                .map(|elseif| {
+                    let node = json!({
+                        "nodeType": "Stmt_ElseIf",
+                        "cond": expression(&elseif.condition),
+                        "stmts": body(&elseif.statement),
+                    });
+
+                    with_attributes(node, elseif.span())
+                })
+                .collect::<Vec<_>>(),
+            r#else.as_ref().map(|r#else| {
+                let node = json!({
+                    "nodeType": "Stmt_Else",
+                    "stmts": body(&r#else.statement),
+                });
+
+                with_attributes(node, r#else.span())
+            }),
+        ),
+        IfStatementBody::Block {
+            statements: body,
+            elseifs,
+            r#else,
+            ..
+        } => (
+            statements(body),
+            elseifs
+                .iter()
+                .map(|elseif| {
+                    let node = json!({
+                        "nodeType": "Stmt_ElseIf",
+                        "cond": expression(&elseif.condition),
+                        "stmts": statements(&elseif.statements),
+                    });
+
+                    with_attributes(node, elseif.span())
+                })
+                .collect::<Vec<_>>(),
+            r#else.as_ref().map(|r#else| {
+                let node = json!({
+                    "nodeType": "Stmt_Else",
+                    "stmts": statements(&r#else.statements),
+                });
+
+                with_attributes(node, r#else.span())
+            }),
+        ),
+    };
+
+    json!({
+        "nodeType": "Stmt_If",
+        "cond": expression(&statement.condition),
+        "stmts": body,
+        "elseifs": elseifs,
+        "else": r#else,
+    })
+}
+
+// The braces of a control structure's body are not a block of their own in
+// php-parser.
+fn body(statement: &Statement) -> Vec<Value> {
+    match statement {
+        Statement::Block(block) => statements(&block.statements),
+        statement => statements(core::slice::from_ref(statement)),
+    }
+}
+
+fn parameters(parameters: &FunctionParameterList) -> Vec<Value> {
+    parameters
+        .iter()
+        .map(|parameter| {
+            let var = json!({
+                "nodeType": "Expr_Variable",
+                "name": variable_name(&parameter.name.name),
+            });
+            let node = json!({
+                "nodeType": "Param",
+                "attrGroups": [],
+                "flags": 0,
+                "type": parameter.data_type.as_ref().map(data_type),
+                "byRef": parameter.ampersand.is_some(),
+                "variadic": parameter.ellipsis.is_some(),
+                "var": with_attributes(var, parameter.name.span()),
+                "default": parameter.default.as_ref().map(expression),
+                "hooks": [],
+            });
+
+            with_attributes(node, parameter.span())
+        })
+        .collect()
+}
+
+fn property_hooks(hooks: &Option<PropertyHookList>) -> Vec<Value> {
+    hooks
+        .iter()
+        .flat_map(|hooks| &hooks.hooks)
+        .map(|hook| {
+            let node = json!({
+                "nodeType": "PropertyHook",
+                "attrGroups": [],
+                "flags": if hook.r#final.is_some() { 32 } else { 0 },
+                "byRef": hook.ampersand.is_some(),
+                "name": identifier(&hook.name),
+                "params": hook.parameters.as_ref().map(parameters).unwrap_or_default(),
+                "body": match &hook.body {
+                    PropertyHookBody::Abstract { .. } => Value::Null,
+                    PropertyHookBody::Expression { expression: value, .. } => expression(value),
+                    PropertyHookBody::Block(body) => Value::Array(statements(&body.statements)),
+                },
+            });
+
+            with_attributes(node, hook.span())
+        })
+        .collect()
+}
+
+fn return_type(return_type: &Option<ReturnType>) -> Value {
+    match return_type {
+        Some(return_type) => data_type(&return_type.data_type),
+        None => Value::Null,
+    }
+}
+
+fn data_type(ty: &Type) -> Value {
+    let node = match ty {
+        Type::Named(_, value) => name(value),
+        Type::Nullable(_, ty) => json!({
+            "nodeType": "NullableType",
+            "type": data_type(ty),
+        }),
+        Type::Union(types) => json!({
+            "nodeType": "UnionType",
+            "types": types.iter().map(data_type).collect::<Vec<_>>(),
+        }),
+        Type::Intersection(types) => json!({
+            "nodeType": "IntersectionType",
+            "types": types.iter().map(data_type).collect::<Vec<_>>(),
+        }),
+        Type::StaticReference(_) | Type::SelfReference(_) | Type::ParentReference(_) => {
+            name(ty.to_string().as_bytes())
+        }
+        ty => json!({
+            "nodeType": "Identifier",
+            "name": ty.to_string(),
+        }),
+    };
+
+    with_attributes(node, ty.span())
+}
+
+fn expression(expression: &Expression) -> Value {
+    let node = match expression {
+        // The range of an expression in parentheses leaves them out.
+        Expression::Parenthesized(parenthesized) => return self::expression(&parenthesized.expr),
+        Expression::Literal(Literal::String(literal)) => json!({
+            "nodeType": "Scalar_String",
+            "value": string(unquote(&literal.value)),
+        }),
+        Expression::Literal(Literal::Integer(literal)) => integer(literal),
+        Expression::Literal(Literal::Float(literal)) => json!({
+            "nodeType": "Scalar_Float",
+            "value": float(&literal.value),
+        }),
+        Expression::Bool(bool) => {
+            constant(if bool.value { "true" } else { "false" }, expression.span())
+        }
+        Expression::Null(_) => constant("null", expression.span()),
+        Expression::Identifier(Identifier::SimpleIdentifier(identifier)) => json!({
+            "nodeType": "Expr_ConstFetch",
+            "name": simple_name(identifier),
+        }),
+        Expression::Variable(variable) => self::variable(variable),
+        Expression::FunctionCall(call) => json!({
+            "nodeType": "Expr_FuncCall",
+            "name": class_or_function_name(&call.target),
+            "args": arguments(&call.arguments),
+        }),
+        Expression::MethodCall(call) => json!({
+            "nodeType": "Expr_MethodCall",
+            "var": self::expression(&call.target),
+            "name": member_name(&call.method),
+            "args": arguments(&call.arguments),
+        }),
+        Expression::NullsafeMethodCall(call) => json!({
+            "nodeType": "Expr_NullsafeMethodCall",
+            "var": self::expression(&call.target),
+            "name": member_name(&call.method),
+            "args": arguments(&call.arguments),
+        }),
+        Expression::StaticMethodCall(call) => json!({
+            "nodeType": "Expr_StaticCall",
+            "class": class_or_function_name(&call.target),
+            "name": dynamic_identifier(&call.method),
+            "args": arguments(&call.arguments),
+        }),
+        Expression::PropertyFetch(fetch) => json!({
+            "nodeType": "Expr_PropertyFetch",
+            "var": self::expression(&fetch.target),
+            "name": member_name(&fetch.property),
+        }),
+        Expression::NullsafePropertyFetch(fetch) => json!({
+            "nodeType": "Expr_NullsafePropertyFetch",
+            "var": self::expression(&fetch.target),
+            "name": member_name(&fetch.property),
+        }),
+        Expression::ConstantFetch(fetch) => json!({
+            "nodeType": "Expr_ClassConstFetch",
+            "class": class_or_function_name(&fetch.target),
+            "name": dynamic_identifier(&fetch.constant),
+        }),
+        Expression::DynamicConstantFetch(fetch) => json!({
+            "nodeType": "Expr_ClassConstFetch",
+            "class": class_or_function_name(&fetch.target),
+            "name": self::expression(&fetch.expr),
+        }),
+        Expression::ArrayIndex(index) => json!({
+            "nodeType": "Expr_ArrayDimFetch",
+            "var": self::expression(&index.array),
+            "dim": index.index.as_deref().map(self::expression),
+        }),
+        Expression::New(new) => json!({
+            "nodeType": "Expr_New",
+            "class": class_or_function_name(&new.target),
+            "args": new.arguments.as_ref().map(arguments).unwrap_or_default(),
+        }),
+        Expression::ShortArray(array) => self::array(&array.items.inner),
+        Expression::Array(array) => self::array(&array.items.inner),
+        Expression::Ternary(ternary) => json!({
+            "nodeType": "Expr_Ternary",
+            "cond": self::expression(&ternary.condition),
+            "if": self::expression(&ternary.then),
+            "else": self::expression(&ternary.r#else),
+        }),
+        Expression::ShortTernary(ternary) => json!({
+            "nodeType": "Expr_Ternary",
+            "cond": self::expression(&ternary.condition),
+            "if": null,
+            "else": self::expression(&ternary.r#else),
+        }),
+        Expression::Coalesce(coalesce) => binary("Coalesce", &coalesce.lhs, &coalesce.rhs),
+        Expression::Concat(concat) => binary("Concat", &concat.left, &concat.right),
+        Expression::Instanceof(instanceof) => json!({
+            "nodeType": "Expr_Instanceof",
+            "expr": self::expression(&instanceof.left),
+            "class": class_or_function_name(&instanceof.right),
+        }),
+        Expression::Print(print) => unary("Expr_Print", "expr", &print.value),
+        Expression::Clone(clone) => unary("Expr_Clone", "expr", &clone.target),
+        Expression::ErrorSuppress(suppress) => unary("Expr_ErrorSuppress", "expr", &suppress.expr),
+        Expression::Throw(throw) => unary("Expr_Throw", "expr", &throw.value),
+        Expression::ArithmeticOperation(operation) => arithmetic(operation),
+        Expression::AssignmentOperation(operation) => assignment(operation),
+        Expression::BitwiseOperation(operation) => bitwise(operation),
+        Expression::ComparisonOperation(operation) => comparison(operation),
+        Expression::LogicalOperation(operation) => logical(operation),
+        expression => unknown(expression),
+    };
+
+    with_attributes(node, expression.span())
+}
+
+fn arithmetic(operation: &ArithmeticOperationExpression) -> Value {
+    use ArithmeticOperationExpression::*;
+
+    match operation {
+        Addition { left, right, .. } => binary("Plus", left, right),
+        Subtraction { left, right, .. } => binary("Minus", left, right),
+        Multiplication { left, right, .. } => binary("Mul", left, right),
+        Division { left, right, .. } => binary("Div", left, right),
+        Modulo { left, right, .. } => binary("Mod", left, right),
+        Exponentiation { left, right, .. } => binary("Pow", left, right),
+        Negative { right, .. } => unary("Expr_UnaryMinus", "expr", right),
+        Positive { right, .. } => unary("Expr_UnaryPlus", "expr", right),
+        PreIncrement { right, .. } => unary("Expr_PreInc", "var", right),
+        PostIncrement { left, .. } => unary("Expr_PostInc", "var", left),
+        PreDecrement { right, .. } => unary("Expr_PreDec", "var", right),
+        PostDecrement { left, .. } => unary("Expr_PostDec", "var", left),
+    }
+}
+
+fn assignment(operation: &AssignmentOperationExpression) -> Value {
+    use AssignmentOperationExpression::*;
+
+    let (node_type, left, right) = match operation {
+        Assign { left, right, .. } => ("Expr_Assign", left, right),
+        Addition { left, right, .. } => ("Expr_AssignOp_Plus", left, right),
+        Subtraction { left, right, .. } => ("Expr_AssignOp_Minus", left, right),
+        Multiplication { left, right, .. } => ("Expr_AssignOp_Mul", left, right),
+        Division { left, right, .. } => ("Expr_AssignOp_Div", left, right),
+        Modulo { left, right, .. } => ("Expr_AssignOp_Mod", left, right),
+        Exponentiation { left, right, .. } => ("Expr_AssignOp_Pow", left, right),
+        Concat { left, right, .. } => ("Expr_AssignOp_Concat", left, right),
+        BitwiseAnd { left, right, .. } => ("Expr_AssignOp_BitwiseAnd", left, right),
+        BitwiseOr { left, right, .. } => ("Expr_AssignOp_BitwiseOr", left, right),
+        BitwiseXor { left, right, .. } => ("Expr_AssignOp_BitwiseXor", left, right),
+        LeftShift { left, right, .. } => ("Expr_AssignOp_ShiftLeft", left, right),
+        RightShift { left, right, .. } => ("Expr_AssignOp_ShiftRight", left, right),
+        Coalesce { left, right, .. } => ("Expr_AssignOp_Coalesce", left, right),
+    };
+
+    json!({
+        "nodeType": node_type,
+        "var": expression(left),
+        "expr": expression(right),
+    })
+}
+
+fn bitwise(operation: &BitwiseOperationExpression) -> Value {
+    use BitwiseOperationExpression::*;
+
+    match operation {
+        And { left, right, .. } => binary("BitwiseAnd", left, right),
+        Or { left, right, .. } => binary("BitwiseOr", left, right),
+        Xor { left, right, .. } => binary("BitwiseXor", left, right),
+        LeftShift { left, right, .. } => binary("ShiftLeft", left, right),
+        RightShift { left, right, .. } => binary("ShiftRight", left, right),
+        Not { right, .. } => unary("Expr_BitwiseNot", "expr", right),
+    }
+}
+
+fn comparison(operation: &ComparisonOperationExpression) -> Value {
+    use ComparisonOperationExpression::*;
+
+    match operation {
+        Equal { left, right, .. } => binary("Equal", left, right),
+        Identical { left, right, .. } => binary("Identical", left, right),
+        NotEqual { left, right, .. } | AngledNotEqual { left, right, .. } => {
+            binary("NotEqual", left, right)
+        }
+        NotIdentical { left, right, .. } => binary("NotIdentical", left, right),
+        LessThan { left, right, .. } => binary("Smaller", left, right),
+        GreaterThan { left, right, .. } => binary("Greater", left, right),
+        LessThanOrEqual { left, right, .. } => binary("SmallerOrEqual", left, right),
+        GreaterThanOrEqual { left, right, .. } => binary("GreaterOrEqual", left, right),
+        Spaceship { left, right, .. } => binary("Spaceship", left, right),
+    }
+}
+
+fn logical(operation: &LogicalOperationExpression) -> Value {
+    use LogicalOperationExpression::*;
+
+    match operation {
+        And { left, right, .. } => binary("BooleanAnd", left, right),
+        Or { left, right, .. } => binary("BooleanOr", left, right),
+        Not { right, .. } => unary("Expr_BooleanNot", "expr", right),
+        LogicalAnd { left, right, .. } => binary("LogicalAnd", left, right),
+        LogicalOr { left, right, .. } => binary("LogicalOr", left, right),
+        LogicalXor { left, right, .. } => binary("LogicalXor", left, right),
+    }
+}
+
+fn binary(operator: &str, left: &Expression, right: &Expression) -> Value {
+    json!({
+        "nodeType": format!("Expr_BinaryOp_{}", operator),
+        "left": expression(left),
+        "right": expression(right),
+    })
+}
+
+fn unary(node_type: &str, field: &str, operand: &Expression) -> Value {
+    json!({
+        "nodeType": node_type,
+        field: expression(operand),
+    })
+}
+
+fn array(items: &[ArrayItem]) -> Value {
+    let items = items
+        .iter()
+        .map(|item| {
+            let (key, value, by_ref, unpack) = match item {
+                ArrayItem::Skipped => return Value::Null,
+                ArrayItem::Value { value } => (None, value, None, None),
+                ArrayItem::ReferencedValue { ampersand, value } => {
+                    (None, value, Some(*ampersand), None)
+                }
+                ArrayItem::SpreadValue { ellipsis, value } => (None, value, None, Some(*ellipsis)),
+                ArrayItem::KeyValue { key, value, .. } => (Some(key), value, None, None),
+                ArrayItem::ReferencedKeyValue {
+                    key,
+                    ampersand,
+                    value,
+                    ..
+                } => (Some(key), value, Some(*ampersand), None),
+            };
+
+            let start = key
+                .map(|key| key.span().start)
+                .or(by_ref)
+                .or(unpack)
+                .unwrap_or(value.span().start);
+            let node = json!({
+                "nodeType": "ArrayItem",
+                "key": key.map(expression),
+                "value": expression(value),
+                "byRef": by_ref.is_some(),
+                "unpack": unpack.is_some(),
+            });
+
+            with_attributes(node, SpanRange::new(start, value.span().end))
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "nodeType": "Expr_Array",
+        "items": items,
+    })
+}
+
+fn arguments(arguments: &ArgumentList) -> Vec<Value> {
+    arguments
+        .iter()
+        .map(|argument| {
+            let (name, ellipsis, value) = match argument {
+                Argument::Positional(argument) => (None, argument.ellipsis, &argument.value),
+                Argument::Named(argument) => {
+                    (Some(&argument.name), argument.ellipsis, &argument.value)
+                }
+            };
+
+            let node = json!({
+                "nodeType": "Arg",
+                "name": name.map(identifier),
+                "value": expression(value),
+                "byRef": false,
+                "unpack": ellipsis.is_some(),
+            });
+
+            with_attributes(node, argument.span())
+        })
+        .collect()
+}
+
+fn variable(variable: &Variable) -> Value {
+    let name = match variable {
+        Variable::SimpleVariable(variable) => json!(variable_name(&variable.name)),
+        Variable::VariableVariable(variable) => self::variable(&variable.variable),
+        Variable::BracedVariableVariable(variable) => expression(&variable.variable),
+    };
+
+    let node = json!({
+        "nodeType": "Expr_Variable",
+        "name": name,
+    });
+
+    with_attributes(node, variable.span())
+}
+
+// The name of a called function, or of the class in `new`, `instanceof` and
+// static accesses, which php-parser represents as a name rather than a
+// constant fetch.
+fn class_or_function_name(target: &Expression) -> Value {
+    match target {
+        Expression::Identifier(Identifier::SimpleIdentifier(identifier)) => simple_name(identifier),
+        Expression::Static(_) => with_attributes(name(b"static"), target.span()),
+        Expression::Self_(_) => with_attributes(name(b"self"), target.span()),
+        Expression::Parent(_) => with_attributes(name(b"parent"), target.span()),
+        target => expression(target),
+    }
+}
+
+fn member_name(name: &Expression) -> Value {
+    match name {
+        Expression::Identifier(identifier) => dynamic_identifier(identifier),
+        name => expression(name),
+    }
+}
+
+fn dynamic_identifier(name: &Identifier) -> Value {
+    match name {
+        Identifier::SimpleIdentifier(identifier) => self::identifier(identifier),
+        Identifier::DynamicIdentifier(identifier) => expression(&identifier.expr),
+    }
+}
+
+fn identifier(identifier: &SimpleIdentifier) -> Value {
+    let node = json!({
+        "nodeType": "Identifier",
+        "name": string(&identifier.value),
+    });
+
+    with_attributes(node, identifier.span())
+}
+
+fn simple_name(identifier: &SimpleIdentifier) -> Value {
+    with_attributes(name(&identifier.value), identifier.span())
+}
+
+fn name(value: &[u8]) -> Value {
+    let (node_type, name) = if let Some(name) = value.strip_prefix(b"\\") {
+        ("Name_FullyQualified", name)
+    } else if value.len() > 10
+        && value
+            .get(..10)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(b"namespace\\"))
+    {
+        ("Name_Relative", value.get(10..).unwrap_or_default())
+    } else {
+        ("Name", value)
+    };
+
+    json!({
+        "nodeType": node_type,
+        "name": string(name),
+    })
+}
+
+// `true`, `false` and `null`, whose name has the range of the fetch.
+fn constant(name: &str, range: SpanRange) -> Value {
+    json!({
+        "nodeType": "Expr_ConstFetch",
+        "name": with_attributes(self::name(name.as_bytes()), range),
+    })
+}
+
+// Integers that overflow are floats in PHP, and so in php-parser.
+fn integer(literal: &LiteralInteger) -> Value {
+    match literal.to_i64() {
+        Some(value) => json!({
+            "nodeType": "Scalar_Int",
+            "value": value,
+        }),
+        None => json!({
+            "nodeType": "Scalar_Float",
+            "value": float(&literal.value),
+        }),
+    }
+}
+
+fn float(value: &[u8]) -> Value {
+    String::from_utf8_lossy(value)
+        .replace('_', "")
+        .parse::<f64>()
+        .ok()
+        .map_or(Value::Null, |value| json!(value))
+}
+
+// String literals keep their quotes, and their `b` prefix if any, in the AST.
+fn unquote(value: &[u8]) -> &[u8] {
+    let value = value
+        .strip_prefix(b"b")
+        .or_else(|| value.strip_prefix(b"B"))
+        .unwrap_or(value);
+
+    value
+        .get(1..value.len().saturating_sub(1))
+        .unwrap_or_default()
+}
+
+fn variable_name(name: &[u8]) -> String {
+    string(name.strip_prefix(b"$").unwrap_or(name))
+}
+
+fn string(value: &[u8]) -> String {
+    String::from_utf8_lossy(value).into_owned()
+}
+
+// Sums the php-parser modifier flags, keyed by the modifier's keyword.
+fn flags<T: Display>(modifiers: &[T]) -> u32 {
+    modifiers
+        .iter()
+        .map(|modifier| match modifier.to_string().as_str() {
+            "public" => 1,
+            "protected" => 2,
+            "private" => 4,
+            "static" => 8,
+            "abstract" => 16,
+            "final" => 32,
+            "readonly" => 64,
+            "public(set)" => 128,
+            "protected(set)" => 256,
+            "private(set)" => 512,
+            _ => 0,
+        })
+        .sum()
+}
+
+// Adds the position attributes to a node, after its other fields as in the
+// dump of php-parser.
+fn with_attributes(mut node: Value, range: SpanRange) -> Value {
+    if let Value::Object(fields) = &mut node {
+        fields.insert(
+            "attributes".to_string(),
+            json!({
+                "startLine": range.start.line,
+                "startFilePos": range.start.position,
+                "endLine": range.end.line,
+                "endFilePos": range.end.position.saturating_sub(1),
+            }),
+        );
+    }
+
+    node
+}
+
+fn unknown<T: Serialize>(node: &T) -> Value {
+    let kind = serde_json::to_value(node)
+        .ok()
+        .and_then(|node| node.get("type").cloned())
+        .unwrap_or(Value::Null);
+
+    json!({
+        "nodeType": "X_Unknown",
+        "kind": kind,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown() {
+        let program = crate::parse("<?php\nforeach ($a as $b) {}").unwrap();
+
+        assert_eq!(
+            to_php_parser_json(&program),
+            json!([{
+                "nodeType": "X_Unknown",
+                "kind": "Foreach",
+                "attributes": {
+                    "startLine": 2,
+                    "startFilePos": 6,
+                    "endLine": 2,
+                    "endFilePos": 26,
+                },
+            }])
+        );
+    }
+}
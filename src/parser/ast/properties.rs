@@ -1,3 +1,6 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -5,25 +8,61 @@ use serde::Serialize;
 use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::data_type::Type;
+use crate::parser::ast::functions::FunctionParameterList;
+use crate::parser::ast::functions::MethodBody;
+use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::modifiers::PropertyModifierGroup;
 use crate::parser::ast::variables::SimpleVariable;
 use crate::parser::ast::Expression;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct Property {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
     #[serde(flatten)]
     pub modifiers: PropertyModifierGroup,
     pub r#type: Option<Type>,
     pub entries: Vec<PropertyEntry>,
+    /// The hooks of a property declared on its own, as in
+    /// `public string $name { get => $this->name; }`.
+    pub hooks: Option<PropertyHookList>,
+    /// The semicolon, or the closing brace of the hooks.
     pub end: Span,
 }
 
 impl Node for Property {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        if let Some(r#type) = &self.r#type {
+            children.push(r#type);
+        }
+        children.extend(
+            self.entries
+                .iter()
+                .map(|e| e as &dyn Node)
+                .collect::<Vec<&dyn Node>>(),
+        );
+        if let Some(hooks) = &self.hooks {
+            children.push(hooks);
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
         if let Some(r#type) = &mut self.r#type {
             children.push(r#type);
         }
@@ -33,22 +72,69 @@ impl Node for Property {
                 .map(|e| e as &mut dyn Node)
                 .collect::<Vec<&mut dyn Node>>(),
         );
+        if let Some(hooks) = &mut self.hooks {
+            children.push(hooks);
+        }
         children
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Property {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or_else(|| {
+                self.modifiers
+                    .modifiers
+                    .first()
+                    .map(|modifier| modifier.span())
+            })
+            .or_else(|| self.r#type.as_ref().map(|r#type| r#type.span().start))
+            .or_else(|| self.entries.first().map(|entry| entry.span().start))
+            .unwrap_or(self.end);
 
+        SpanRange::new(start, self.end.after(b";"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct VariableProperty {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
+    pub var: Span,
     pub r#type: Option<Type>,
     pub entries: Vec<PropertyEntry>,
     pub end: Span,
 }
 
 impl Node for VariableProperty {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        let mut children: Vec<&mut dyn Node> = vec![];
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        if let Some(r#type) = &self.r#type {
+            children.push(r#type);
+        }
+        children.extend(
+            self.entries
+                .iter()
+                .map(|e| e as &dyn Node)
+                .collect::<Vec<&dyn Node>>(),
+        );
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
         if let Some(r#type) = &mut self.r#type {
             children.push(r#type);
         }
@@ -62,7 +148,20 @@ impl Node for VariableProperty {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for VariableProperty {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.var, |attribute| attribute.start);
+
+        SpanRange::new(start, self.end.after(b";"))
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum PropertyEntry {
     Uninitialized {
@@ -76,7 +175,16 @@ pub enum PropertyEntry {
 }
 
 impl Node for PropertyEntry {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            PropertyEntry::Uninitialized { variable } => vec![variable],
+            PropertyEntry::Initialized {
+                variable, value, ..
+            } => vec![variable, value],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             PropertyEntry::Uninitialized { variable } => vec![variable],
             PropertyEntry::Initialized {
@@ -86,6 +194,17 @@ impl Node for PropertyEntry {
     }
 }
 
+impl Spanned for PropertyEntry {
+    fn span(&self) -> SpanRange {
+        match self {
+            PropertyEntry::Uninitialized { variable } => variable.span(),
+            PropertyEntry::Initialized {
+                variable, value, ..
+            } => variable.span().to(value.span()),
+        }
+    }
+}
+
 impl PropertyEntry {
     pub fn variable(&self) -> &SimpleVariable {
         match self {
@@ -94,3 +213,142 @@ impl PropertyEntry {
         }
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+pub struct PropertyHookList {
+    pub left_brace: Span,         // `{`
+    pub hooks: Vec<PropertyHook>, // `get => $this->name;`, `set;`
+    pub right_brace: Span,        // `}`
+}
+
+impl Node for PropertyHookList {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.hooks.iter().map(|hook| hook as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.hooks
+            .iter_mut()
+            .map(|hook| hook as &mut dyn Node)
+            .collect()
+    }
+}
+
+impl Spanned for PropertyHookList {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_brace, self.right_brace.after(b"}"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+pub struct PropertyHook {
+    pub comments: CommentGroup,
+    pub attributes: Vec<AttributeGroup>,
+    pub r#final: Option<Span>,
+    pub ampersand: Option<Span>,
+    pub name: SimpleIdentifier,
+    pub parameters: Option<FunctionParameterList>,
+    pub body: PropertyHookBody,
+}
+
+impl Node for PropertyHook {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        if let Some(parameters) = &self.parameters {
+            children.push(parameters);
+        }
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
+        if let Some(parameters) = &mut self.parameters {
+            children.push(parameters);
+        }
+        children.push(&mut self.body);
+        children
+    }
+}
+
+impl Spanned for PropertyHook {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map(|attribute| attribute.start)
+            .or(self.r#final)
+            .or(self.ampersand)
+            .unwrap_or(self.name.span);
+
+        SpanRange::new(start, self.body.span().end)
+    }
+}
+
+impl PropertyHook {
+    /// Whether the hook returns the value by reference, as in `&get`.
+    pub fn is_by_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(tag = "type", content = "value")]
+pub enum PropertyHookBody {
+    /// `get;`, in an interface or for an abstract property.
+    Abstract { semicolon: Span },
+    /// `get => $this->name;`
+    Expression {
+        double_arrow: Span,
+        expression: Expression,
+        semicolon: Span,
+    },
+    /// `get { return $this->name; }`
+    Block(MethodBody),
+}
+
+impl Node for PropertyHookBody {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            PropertyHookBody::Abstract { .. } => vec![],
+            PropertyHookBody::Expression { expression, .. } => vec![expression],
+            PropertyHookBody::Block(body) => vec![body],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            PropertyHookBody::Abstract { .. } => vec![],
+            PropertyHookBody::Expression { expression, .. } => vec![expression],
+            PropertyHookBody::Block(body) => vec![body],
+        }
+    }
+}
+
+impl Spanned for PropertyHookBody {
+    fn span(&self) -> SpanRange {
+        match self {
+            PropertyHookBody::Abstract { semicolon } => SpanRange::token(*semicolon, b";"),
+            PropertyHookBody::Expression {
+                double_arrow,
+                semicolon,
+                ..
+            } => SpanRange::new(*double_arrow, semicolon.after(b";")),
+            PropertyHookBody::Block(body) => body.span(),
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use alloc::vec::Vec;
+
+use crate::parser::ast::control_flow::IfStatement;
+use crate::parser::ast::control_flow::IfStatementBody;
+use crate::parser::ast::try_block::TryStatement;
+use crate::parser::ast::Expression;
+use crate::parser::ast::Statement;
+
+/// Whether control never moves on from the statement to the one after it.
+///
+/// That is the case of `return`, `break`, `continue`, `goto` and
+/// `__halt_compiler()`, and of expression statements that always throw or
+/// exit, such as `exit;`, `die(1);`, `throw $e;` or a `match` whose every arm
+/// does. Blocks, `if` statements that have an `else` and `try` statements
+/// terminate when every way through them does.
+///
+/// Loops and `switch` statements are never terminators, as telling whether
+/// they end needs more than their syntax.
+pub fn is_terminator(statement: &Statement) -> bool {
+    match statement {
+        Statement::Return(_)
+        | Statement::Break(_)
+        | Statement::Continue(_)
+        | Statement::Goto(_)
+        | Statement::HaltCompiler(_) => true,
+        Statement::Expression(statement) => always_exits(&statement.expression),
+        Statement::Block(block) => terminates(&block.statements),
+        Statement::If(statement) => if_terminates(statement),
+        Statement::Try(statement) => try_terminates(statement),
+        _ => false,
+    }
+}
+
+/// The statements of a list that can never run, as they follow a terminator
+/// with no label in between.
+///
+/// Only the list itself is looked at, not the statements nested in it.
+/// Comments, tags and inline HTML are left out, as they never run anyway, and
+/// so are declarations of functions, classes, interfaces, traits and enums,
+/// which PHP hoists so that they are declared before any statement runs.
+///
+/// # Example
+///
+/// ```
+/// use php_parser_rs::parser::ast::reachability::unreachable;
+///
+/// let program = php_parser_rs::parse("<?php exit; echo 1;").unwrap();
+///
+/// assert_eq!(unreachable(&program).len(), 1);
+/// ```
+pub fn unreachable(statements: &[Statement]) -> Vec<&Statement> {
+    let mut unreachable = Vec::new();
+    let mut terminated = false;
+
+    for statement in statements {
+        match statement {
+            Statement::Label(_) => terminated = false,
+            Statement::Comment(_)
+            | Statement::FullOpeningTag(_)
+            | Statement::ShortOpeningTag(_)
+            | Statement::EchoOpeningTag(_)
+            | Statement::ClosingTag(_)
+            | Statement::InlineHtml(_)
+            | Statement::Function(_)
+            | Statement::Class(_)
+            | Statement::Interface(_)
+            | Statement::Trait(_)
+            | Statement::UnitEnum(_)
+            | Statement::BackedEnum(_) => continue,
+            _ if terminated => unreachable.push(statement),
+            _ => {}
+        }
+
+        if is_terminator(statement) {
+            terminated = true;
+        }
+    }
+
+    unreachable
+}
+
+// A list of statements terminates when control cannot reach its end, which a
+// label after the last terminator makes possible again.
+fn terminates(statements: &[Statement]) -> bool {
+    let mut terminated = false;
+
+    for statement in statements {
+        if let Statement::Label(_) = statement {
+            terminated = false;
+        }
+
+        if is_terminator(statement) {
+            terminated = true;
+        }
+    }
+
+    terminated
+}
+
+fn if_terminates(statement: &IfStatement) -> bool {
+    match &statement.body {
+        IfStatementBody::Statement {
+            statement,
+            elseifs,
+            r#else,
+        } => {
+            r#else
+                .as_ref()
+                .is_some_and(|r#else| is_terminator(&r#else.statement))
+                && is_terminator(statement)
+                && elseifs
+                    .iter()
+                    .all(|elseif| is_terminator(&elseif.statement))
+        }
+        IfStatementBody::Block {
+            statements,
+            elseifs,
+            r#else,
+            ..
+        } => {
+            r#else
+                .as_ref()
+                .is_some_and(|r#else| terminates(&r#else.statements))
+                && terminates(statements)
+                && elseifs.iter().all(|elseif| terminates(&elseif.statements))
+        }
+    }
+}
+
+fn try_terminates(statement: &TryStatement) -> bool {
+    if let Some(finally) = &statement.finally {
+        if terminates(&finally.body) {
+            return true;
+        }
+    }
+
+    terminates(&statement.body)
+        && statement
+            .catches
+            .iter()
+            .all(|catch| terminates(&catch.body))
+}
+
+// A `match` without a matching arm throws, so one whose arms all exit always
+// does, whether or not it has a `default` arm.
+fn always_exits(expression: &Expression) -> bool {
+    match expression {
+        Expression::Exit(_) | Expression::Die(_) | Expression::Throw(_) => true,
+        Expression::Parenthesized(expression) => always_exits(&expression.expr),
+        Expression::Match(expression) => {
+            expression
+                .default
+                .as_ref()
+                .is_none_or(|default| always_exits(&default.body))
+                && expression.arms.iter().all(|arm| always_exits(&arm.body))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unreachable;
+    use crate::parser::ast::Statement;
+
+    fn unreachable_echoes(code: &str) -> usize {
+        let program = crate::parse(code).unwrap();
+
+        unreachable(&program)
+            .into_iter()
+            .filter(|statement| matches!(statement, Statement::Echo(_)))
+            .count()
+    }
+
+    #[test]
+    fn test_exit() {
+        assert_eq!(unreachable_echoes("<?php exit; echo 1;"), 1);
+        assert_eq!(unreachable_echoes("<?php die(1); echo 1; echo 2;"), 2);
+        assert_eq!(unreachable_echoes("<?php exit(); // done\n echo 1;"), 1);
+        assert_eq!(unreachable_echoes("<?php echo 1; exit;"), 0);
+    }
+
+    #[test]
+    fn test_if() {
+        assert_eq!(
+            unreachable_echoes("<?php if ($a) { exit; } else { exit; } echo 1;"),
+            1
+        );
+        assert_eq!(
+            unreachable_echoes(
+                "<?php if ($a): exit; elseif ($b): die; else: throw $e; endif; echo 1;"
+            ),
+            1
+        );
+        assert_eq!(unreachable_echoes("<?php if ($a) exit; echo 1;"), 0);
+        assert_eq!(
+            unreachable_echoes(
+                "<?php if ($a) { exit; } elseif ($b) { echo 2; } else { exit; } echo 1;"
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn test_match() {
+        assert_eq!(
+            unreachable_echoes("<?php match($x) { default => exit }; echo 1;"),
+            1
+        );
+        assert_eq!(
+            unreachable_echoes("<?php match($x) { 1 => exit, 2 => throw $e }; echo 1;"),
+            1
+        );
+        assert_eq!(
+            unreachable_echoes("<?php match($x) { 1 => exit, default => 2 }; echo 1;"),
+            0
+        );
+    }
+
+    #[test]
+    fn test_try() {
+        assert_eq!(
+            unreachable_echoes("<?php try { $a; } finally { exit; } echo 1;"),
+            1
+        );
+        assert_eq!(
+            unreachable_echoes("<?php try { exit; } catch (E $e) { return; } echo 1;"),
+            1
+        );
+        assert_eq!(
+            unreachable_echoes("<?php try { exit; } catch (E $e) { } echo 1;"),
+            0
+        );
+    }
+
+    #[test]
+    fn test_inline_html() {
+        let program = crate::parse("<?php exit; ?>\n<p>Done</p>\n<?php echo 1; ?>\n").unwrap();
+
+        assert_eq!(unreachable(&program).len(), 1);
+        assert!(matches!(unreachable(&program)[0], Statement::Echo(_)));
+
+        let program = crate::parse("<?php exit ?><p><?= $a ?></p>").unwrap();
+
+        assert_eq!(unreachable(&program).len(), 1);
+        assert!(matches!(unreachable(&program)[0], Statement::Echo(_)));
+    }
+
+    #[test]
+    fn test_declarations() {
+        let code = "<?php return; function a() {} class B {} interface C {} trait D {} enum E {} enum F: int {} echo 1;";
+        let program = crate::parse(code).unwrap();
+
+        assert_eq!(unreachable(&program).len(), 1);
+        assert!(matches!(unreachable(&program)[0], Statement::Echo(_)));
+    }
+
+    #[test]
+    fn test_labels() {
+        assert_eq!(unreachable_echoes("<?php goto a; echo 1; a: echo 2;"), 1);
+        assert_eq!(unreachable_echoes("<?php { exit; a: } echo 1;"), 0);
+    }
+}
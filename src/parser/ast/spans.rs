@@ -0,0 +1,432 @@
+use alloc::vec::Vec;
+
+use crate::downcast::downcast;
+use crate::lexer::token::Span;
+use crate::node::Node;
+use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::literals::LiteralFloat;
+use crate::parser::ast::literals::LiteralInteger;
+use crate::parser::ast::literals::LiteralString;
+use crate::parser::ast::namespaces::NamespaceStatement;
+use crate::parser::ast::operators::ArithmeticOperationExpression;
+use crate::parser::ast::operators::AssignmentOperationExpression;
+use crate::parser::ast::operators::BitwiseOperationExpression;
+use crate::parser::ast::operators::ComparisonOperationExpression;
+use crate::parser::ast::operators::LogicalOperationExpression;
+use crate::parser::ast::variables::BracedVariableVariable;
+use crate::parser::ast::variables::SimpleVariable;
+use crate::parser::ast::variables::VariableVariable;
+use crate::parser::ast::CoalesceExpression;
+use crate::parser::ast::ConcatExpression;
+use crate::parser::ast::Expression;
+use crate::parser::ast::ExpressionStatement;
+use crate::parser::ast::InstanceofExpression;
+use crate::parser::ast::Program;
+use crate::parser::ast::Statement;
+
+/// The source that a node spans, from the first byte of its first token at
+/// `start` up to `end`, the position just past its last token. Comments
+/// before the node are not part of it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SpanRange {
+    pub start: Span,
+    pub end: Span,
+}
+
+impl SpanRange {
+    pub const fn new(start: Span, end: Span) -> Self {
+        Self { start, end }
+    }
+
+    /// The range of a single token written as `text` at `span`.
+    pub fn token(span: Span, text: &[u8]) -> Self {
+        Self::new(span, span.after(text))
+    }
+
+    /// The range from the start of this one to the end of `other`.
+    pub const fn to(self, other: SpanRange) -> Self {
+        Self::new(self.start, other.end)
+    }
+
+    /// Whether the byte at `offset` is part of the range.
+    pub const fn contains(&self, offset: usize) -> bool {
+        self.start.offset() <= offset && offset < self.end.offset()
+    }
+}
+
+/// A node of the AST that knows which tokens it spans.
+///
+/// Nodes that may be empty, such as a [`Block`](crate::parser::ast::Block) or
+/// a group of modifiers, have no range of their own.
+pub trait Spanned {
+    fn span(&self) -> SpanRange;
+}
+
+/// A rough classification of the nodes yielded by [`Program::spans`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum NodeKind {
+    Identifier,
+    Variable,
+    /// A statement introduced by a keyword, such as `if` or `return`; the
+    /// span points at the keyword.
+    Keyword,
+    Literal,
+    /// An operation; the span points at the operator.
+    Operator,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SpannedNode {
+    pub span: Span,
+    pub kind: NodeKind,
+    /// The depth of the node in the AST, the statements of the program being
+    /// at depth `1`. When a node contains another, the outer one is shallower.
+    pub depth: usize,
+}
+
+// The classified nodes of the program, sorted by start offset.
+pub(crate) fn spans(program: &Program) -> alloc::vec::IntoIter<SpannedNode> {
+    let mut collector = SpanCollector {
+        nodes: vec![],
+        depth: 0,
+    };
+
+    collector.collect(program);
+
+    let mut nodes = collector.nodes;
+    nodes.sort_by_key(|node| node.span.position);
+
+    nodes.into_iter()
+}
+
+struct SpanCollector {
+    nodes: Vec<SpannedNode>,
+    depth: usize,
+}
+
+impl SpanCollector {
+    fn collect(&mut self, node: &dyn Node) {
+        if let Some((span, kind)) = classify(node) {
+            self.nodes.push(SpannedNode {
+                span,
+                kind,
+                depth: self.depth,
+            });
+        }
+
+        self.depth += 1;
+        for child in node.children() {
+            self.collect(child);
+        }
+        self.depth -= 1;
+    }
+}
+
+fn classify(node: &dyn Node) -> Option<(Span, NodeKind)> {
+    if let Some(identifier) = downcast::<SimpleIdentifier>(node) {
+        return Some((identifier.span, NodeKind::Identifier));
+    }
+
+    if let Some(variable) = downcast::<SimpleVariable>(node) {
+        return Some((variable.span, NodeKind::Variable));
+    }
+
+    if let Some(variable) = downcast::<VariableVariable>(node) {
+        return Some((variable.span, NodeKind::Variable));
+    }
+
+    if let Some(variable) = downcast::<BracedVariableVariable>(node) {
+        return Some((variable.start, NodeKind::Variable));
+    }
+
+    if let Some(literal) = downcast::<LiteralString>(node) {
+        return Some((literal.span, NodeKind::Literal));
+    }
+
+    if let Some(literal) = downcast::<LiteralInteger>(node) {
+        return Some((literal.span, NodeKind::Literal));
+    }
+
+    if let Some(literal) = downcast::<LiteralFloat>(node) {
+        return Some((literal.span, NodeKind::Literal));
+    }
+
+    if let Some(statement) = downcast::<Statement>(node) {
+        return keyword(statement).map(|span| (span, NodeKind::Keyword));
+    }
+
+    operator(node).map(|span| (span, NodeKind::Operator))
+}
+
+fn keyword(statement: &Statement) -> Option<Span> {
+    let span = match statement {
+        Statement::Goto(statement) => statement.keyword,
+        Statement::HaltCompiler(statement) => statement.span,
+        Statement::Static(statement) => statement.r#static,
+        Statement::DoWhile(statement) => statement.r#do,
+        Statement::While(statement) => statement.r#while,
+        Statement::For(statement) => statement.r#for,
+        Statement::Foreach(statement) => statement.foreach,
+        Statement::Break(statement) => statement.r#break,
+        Statement::Continue(statement) => statement.r#continue,
+        Statement::Constant(statement) => statement.r#const,
+        Statement::Function(statement) => statement.function,
+        Statement::Class(statement) => statement.class,
+        Statement::Trait(statement) => statement.r#trait,
+        Statement::Interface(statement) => statement.interface,
+        Statement::If(statement) => statement.r#if,
+        Statement::Switch(statement) => statement.switch,
+        Statement::Echo(statement) => statement.echo,
+        Statement::Expression(ExpressionStatement { expression, .. }) => match expression {
+            Expression::Unset(expression) => expression.unset,
+            Expression::Throw(expression) => expression.throw,
+            _ => return None,
+        },
+        Statement::Return(statement) => statement.r#return,
+        Statement::Namespace(NamespaceStatement::Unbraced(statement)) => statement.start,
+        Statement::Namespace(NamespaceStatement::Braced(statement)) => statement.namespace,
+        Statement::Use(statement) => statement.r#use,
+        Statement::GroupUse(statement) => statement.r#use,
+        Statement::Try(statement) => statement.start,
+        Statement::UnitEnum(statement) => statement.r#enum,
+        Statement::BackedEnum(statement) => statement.r#enum,
+        Statement::Global(statement) => statement.global,
+        Statement::Declare(statement) => statement.declare,
+        _ => return None,
+    };
+
+    Some(span)
+}
+
+fn operator(node: &dyn Node) -> Option<Span> {
+    if let Some(operation) = downcast::<ArithmeticOperationExpression>(node) {
+        return Some(match operation {
+            ArithmeticOperationExpression::Addition { plus: span, .. }
+            | ArithmeticOperationExpression::Subtraction { minus: span, .. }
+            | ArithmeticOperationExpression::Multiplication { asterisk: span, .. }
+            | ArithmeticOperationExpression::Division { slash: span, .. }
+            | ArithmeticOperationExpression::Modulo { percent: span, .. }
+            | ArithmeticOperationExpression::Exponentiation { pow: span, .. }
+            | ArithmeticOperationExpression::Negative { minus: span, .. }
+            | ArithmeticOperationExpression::Positive { plus: span, .. }
+            | ArithmeticOperationExpression::PreIncrement {
+                increment: span, ..
+            }
+            | ArithmeticOperationExpression::PostIncrement {
+                increment: span, ..
+            }
+            | ArithmeticOperationExpression::PreDecrement {
+                decrement: span, ..
+            }
+            | ArithmeticOperationExpression::PostDecrement {
+                decrement: span, ..
+            } => *span,
+        });
+    }
+
+    if let Some(operation) = downcast::<AssignmentOperationExpression>(node) {
+        return Some(match operation {
+            AssignmentOperationExpression::Assign { equals: span, .. }
+            | AssignmentOperationExpression::Addition {
+                plus_equals: span, ..
+            }
+            | AssignmentOperationExpression::Subtraction {
+                minus_equals: span, ..
+            }
+            | AssignmentOperationExpression::Multiplication {
+                asterisk_equals: span,
+                ..
+            }
+            | AssignmentOperationExpression::Division {
+                slash_equals: span, ..
+            }
+            | AssignmentOperationExpression::Modulo {
+                percent_equals: span,
+                ..
+            }
+            | AssignmentOperationExpression::Exponentiation {
+                pow_equals: span, ..
+            }
+            | AssignmentOperationExpression::Concat {
+                dot_equals: span, ..
+            }
+            | AssignmentOperationExpression::BitwiseAnd {
+                ampersand_equals: span,
+                ..
+            }
+            | AssignmentOperationExpression::BitwiseOr {
+                pipe_equals: span, ..
+            }
+            | AssignmentOperationExpression::BitwiseXor {
+                caret_equals: span, ..
+            }
+            | AssignmentOperationExpression::LeftShift {
+                left_shift_equals: span,
+                ..
+            }
+            | AssignmentOperationExpression::RightShift {
+                right_shift_equals: span,
+                ..
+            }
+            | AssignmentOperationExpression::Coalesce {
+                coalesce_equals: span,
+                ..
+            } => *span,
+        });
+    }
+
+    if let Some(operation) = downcast::<BitwiseOperationExpression>(node) {
+        return Some(match operation {
+            BitwiseOperationExpression::And { and: span, .. }
+            | BitwiseOperationExpression::Or { or: span, .. }
+            | BitwiseOperationExpression::Xor { xor: span, .. }
+            | BitwiseOperationExpression::LeftShift {
+                left_shift: span, ..
+            }
+            | BitwiseOperationExpression::RightShift {
+                right_shift: span, ..
+            }
+            | BitwiseOperationExpression::Not { not: span, .. } => *span,
+        });
+    }
+
+    if let Some(operation) = downcast::<ComparisonOperationExpression>(node) {
+        return Some(match operation {
+            ComparisonOperationExpression::Equal {
+                double_equals: span,
+                ..
+            }
+            | ComparisonOperationExpression::Identical {
+                triple_equals: span,
+                ..
+            }
+            | ComparisonOperationExpression::NotEqual {
+                bang_equals: span, ..
+            }
+            | ComparisonOperationExpression::AngledNotEqual {
+                angled_left_right: span,
+                ..
+            }
+            | ComparisonOperationExpression::NotIdentical {
+                bang_double_equals: span,
+                ..
+            }
+            | ComparisonOperationExpression::LessThan {
+                less_than: span, ..
+            }
+            | ComparisonOperationExpression::GreaterThan {
+                greater_than: span, ..
+            }
+            | ComparisonOperationExpression::LessThanOrEqual {
+                less_than_equals: span,
+                ..
+            }
+            | ComparisonOperationExpression::GreaterThanOrEqual {
+                greater_than_equals: span,
+                ..
+            }
+            | ComparisonOperationExpression::Spaceship {
+                spaceship: span, ..
+            } => *span,
+        });
+    }
+
+    if let Some(operation) = downcast::<LogicalOperationExpression>(node) {
+        return Some(match operation {
+            LogicalOperationExpression::And {
+                double_ampersand: span,
+                ..
+            }
+            | LogicalOperationExpression::Or {
+                double_pipe: span, ..
+            }
+            | LogicalOperationExpression::Not { bang: span, .. }
+            | LogicalOperationExpression::LogicalAnd { and: span, .. }
+            | LogicalOperationExpression::LogicalOr { or: span, .. }
+            | LogicalOperationExpression::LogicalXor { xor: span, .. } => *span,
+        });
+    }
+
+    if let Some(expression) = downcast::<ConcatExpression>(node) {
+        return Some(expression.dot);
+    }
+
+    if let Some(expression) = downcast::<InstanceofExpression>(node) {
+        return Some(expression.instanceof);
+    }
+
+    downcast::<CoalesceExpression>(node).map(|expression| expression.double_question)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_are_sorted_and_cover_literals_and_variables() {
+        let program = crate::parse(
+            r#"<?php
+
+            function foo($a) {
+                if ($a > 1) {
+                    return $a . "b" ?? 2.5;
+                }
+
+                $$b = [$c => 3];
+                bar();
+            }
+            "#,
+        )
+        .unwrap();
+
+        let nodes = program.spans().collect::<Vec<_>>();
+
+        assert!(nodes
+            .windows(2)
+            .all(|pair| pair[0].span.position <= pair[1].span.position));
+
+        let of_kind = |kind| {
+            nodes
+                .iter()
+                .filter(|node| node.kind == kind)
+                .map(|node| (node.span.line, node.span.column))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            of_kind(NodeKind::Literal),
+            vec![(4, 26), (5, 33), (5, 40), (8, 30)]
+        );
+        assert_eq!(
+            of_kind(NodeKind::Variable),
+            vec![(3, 26), (4, 21), (5, 28), (8, 17), (8, 18), (8, 24)]
+        );
+        assert_eq!(of_kind(NodeKind::Keyword), vec![(3, 13), (4, 17), (5, 21)]);
+        assert_eq!(of_kind(NodeKind::Identifier), vec![(3, 22), (9, 17)]);
+        assert_eq!(of_kind(NodeKind::Operator).len(), 4);
+
+        // `$$b` contains `$b`, so both are yielded with the outer one first.
+        let outer = nodes
+            .iter()
+            .position(|node| node.span.column == 17 && node.span.line == 8);
+        let outer = outer.unwrap();
+        assert_eq!(nodes[outer + 1].span.column, 18);
+        assert!(nodes[outer].depth < nodes[outer + 1].depth);
+    }
+
+    #[test]
+    fn test_keyword_statements() {
+        let program = crate::parse(
+            "<?php use A; use B\\{C, D}; function f() { static $a; global $b; unset($a); throw $e; } __halt_compiler();",
+        )
+        .unwrap();
+
+        let keywords = program
+            .spans()
+            .filter(|node| node.kind == NodeKind::Keyword)
+            .map(|node| node.span.column)
+            .collect::<Vec<_>>();
+
+        assert_eq!(keywords, vec![7, 14, 28, 43, 54, 65, 76, 88]);
+    }
+}
@@ -0,0 +1,103 @@
+use alloc::collections::BTreeMap;
+
+use crate::downcast::downcast;
+use crate::node::Node;
+use crate::parser::ast::Expression;
+use crate::parser::ast::Program;
+use crate::parser::ast::Statement;
+
+/// Structural metrics of a program, counted over every node of its AST.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Statistics {
+    /// The number of nodes, not counting the program itself.
+    pub nodes: usize,
+    pub statements: usize,
+    pub expressions: usize,
+    /// The depth of the deepest node, the statements of the program being at
+    /// depth `1`.
+    pub max_depth: usize,
+    /// The number of nodes of each kind, keyed by [`Node::name`].
+    pub kinds: BTreeMap<&'static str, usize>,
+}
+
+impl Statistics {
+    pub fn collect(program: &Program) -> Self {
+        let mut collector = StatisticsCollector {
+            statistics: Statistics::default(),
+            depth: 0,
+        };
+
+        for statement in program {
+            collector.collect(statement);
+        }
+
+        collector.statistics
+    }
+
+    /// Returns the number of nodes of the given kind, such as `"IfStatement"`.
+    pub fn count(&self, kind: &str) -> usize {
+        self.kinds.get(kind).copied().unwrap_or(0)
+    }
+}
+
+struct StatisticsCollector {
+    statistics: Statistics,
+    depth: usize,
+}
+
+impl StatisticsCollector {
+    fn collect(&mut self, node: &dyn Node) {
+        self.depth += 1;
+
+        let statistics = &mut self.statistics;
+
+        statistics.nodes += 1;
+        statistics.max_depth = statistics.max_depth.max(self.depth);
+        *statistics.kinds.entry(node.name()).or_default() += 1;
+
+        if downcast::<Statement>(node).is_some() {
+            statistics.statements += 1;
+        } else if downcast::<Expression>(node).is_some() {
+            statistics.expressions += 1;
+        }
+
+        for child in node.children() {
+            self.collect(child);
+        }
+
+        self.depth -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        let program = crate::parse(include_str!("../../../tests/fixtures/0001/code.php")).unwrap();
+
+        let statistics = Statistics::collect(&program);
+
+        assert_eq!(statistics.nodes, 63);
+        assert_eq!(statistics.statements, 5);
+        assert_eq!(statistics.expressions, 5);
+        // statement > function > body > statement > expression statement > expression >
+        // exit > argument list > argument > positional argument > expression > literal >
+        // integer
+        assert_eq!(statistics.max_depth, 13);
+        assert_eq!(statistics.kinds.values().sum::<usize>(), statistics.nodes);
+
+        assert_eq!(statistics.count("FullOpeningTagStatement"), 1);
+        assert_eq!(statistics.count("FunctionStatement"), 2);
+        assert_eq!(statistics.count("FunctionParameter"), 8);
+        assert_eq!(statistics.count("SimpleVariable"), 8);
+        assert_eq!(statistics.count("Type"), 14);
+        assert_eq!(statistics.count("ReturnStatement"), 1);
+        assert_eq!(statistics.count("ExitExpression"), 1);
+        assert_eq!(statistics.count("LiteralInteger"), 1);
+        assert_eq!(statistics.count("LiteralString"), 1);
+        assert_eq!(statistics.count("ShortArrayExpression"), 1);
+        assert_eq!(statistics.count("IfStatement"), 0);
+    }
+}
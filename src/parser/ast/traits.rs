@@ -1,10 +1,18 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::lexer::token::Span;
 use crate::node::Node;
+use crate::parser::ast::attributes::find_attribute;
+use crate::parser::ast::attributes::Attribute;
 use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::ast::comments::Comment;
+use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::constant::ClassishConstant;
 use crate::parser::ast::functions::AbstractConstructor;
 use crate::parser::ast::functions::AbstractMethod;
@@ -14,8 +22,11 @@ use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::modifiers::VisibilityModifier;
 use crate::parser::ast::properties::Property;
 use crate::parser::ast::properties::VariableProperty;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum TraitMember {
     Constant(ClassishConstant),
@@ -26,10 +37,25 @@ pub enum TraitMember {
     AbstractConstructor(AbstractConstructor),
     ConcreteMethod(ConcreteMethod),
     ConcreteConstructor(ConcreteConstructor),
+    Comment(Comment),
 }
 
 impl Node for TraitMember {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            TraitMember::Constant(constant) => vec![constant],
+            TraitMember::TraitUsage(usage) => vec![usage],
+            TraitMember::Property(property) => vec![property],
+            TraitMember::VariableProperty(property) => vec![property],
+            TraitMember::AbstractMethod(method) => vec![method],
+            TraitMember::AbstractConstructor(constructor) => vec![constructor],
+            TraitMember::ConcreteMethod(method) => vec![method],
+            TraitMember::ConcreteConstructor(constructor) => vec![constructor],
+            TraitMember::Comment(comment) => vec![comment],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             TraitMember::Constant(constant) => vec![constant],
             TraitMember::TraitUsage(usage) => vec![usage],
@@ -39,12 +65,29 @@ impl Node for TraitMember {
             TraitMember::AbstractConstructor(constructor) => vec![constructor],
             TraitMember::ConcreteMethod(method) => vec![method],
             TraitMember::ConcreteConstructor(constructor) => vec![constructor],
+            TraitMember::Comment(comment) => vec![comment],
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for TraitMember {
+    fn span(&self) -> SpanRange {
+        match self {
+            TraitMember::Constant(member) => member.span(),
+            TraitMember::TraitUsage(member) => member.span(),
+            TraitMember::Property(member) => member.span(),
+            TraitMember::VariableProperty(member) => member.span(),
+            TraitMember::AbstractMethod(member) => member.span(),
+            TraitMember::AbstractConstructor(member) => member.span(),
+            TraitMember::ConcreteMethod(member) => member.span(),
+            TraitMember::ConcreteConstructor(member) => member.span(),
+            TraitMember::Comment(member) => member.span(),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct TraitBody {
     pub left_brace: Span,
     pub members: Vec<TraitMember>,
@@ -52,7 +95,14 @@ pub struct TraitBody {
 }
 
 impl Node for TraitBody {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.members
+            .iter()
+            .map(|member| member as &dyn Node)
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.members
             .iter_mut()
             .map(|member| member as &mut dyn Node)
@@ -60,9 +110,16 @@ impl Node for TraitBody {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for TraitBody {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.left_brace, self.right_brace.after(b"}"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct TraitStatement {
+    pub comments: CommentGroup,
     pub r#trait: Span,
     pub name: SimpleIdentifier,
     pub attributes: Vec<AttributeGroup>,
@@ -70,26 +127,88 @@ pub struct TraitStatement {
 }
 
 impl Node for TraitStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        vec![&mut self.name, &mut self.body]
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .attributes
+            .iter()
+            .map(|group| group as &dyn Node)
+            .collect();
+        children.push(&self.name);
+        children.push(&self.body);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = self
+            .attributes
+            .iter_mut()
+            .map(|group| group as &mut dyn Node)
+            .collect();
+        children.push(&mut self.name);
+        children.push(&mut self.body);
+        children
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for TraitStatement {
+    fn span(&self) -> SpanRange {
+        let start = self
+            .attributes
+            .first()
+            .map_or(self.r#trait, |attribute| attribute.start);
 
+        SpanRange::new(start, self.body.right_brace.after(b"}"))
+    }
+}
+
+impl TraitStatement {
+    /// The attribute of the trait named `name`, see [`Attribute::is_named`].
+    pub fn find_attribute(&self, name: &str) -> Option<&Attribute> {
+        find_attribute(&self.attributes, name)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct TraitUsage {
+    pub comments: CommentGroup,
     pub r#use: Span,
     pub traits: Vec<SimpleIdentifier>,
     pub adaptations: Vec<TraitUsageAdaptation>,
+    pub end: Span, // `;`, or the `}` closing the adaptations
 }
 
 impl Node for TraitUsage {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.traits.iter_mut().map(|t| t as &mut dyn Node).collect()
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self.traits.iter().map(|t| t as &dyn Node).collect();
+        children.extend(
+            self.adaptations
+                .iter()
+                .map(|adaptation| adaptation as &dyn Node),
+        );
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> =
+            self.traits.iter_mut().map(|t| t as &mut dyn Node).collect();
+        children.extend(
+            self.adaptations
+                .iter_mut()
+                .map(|adaptation| adaptation as &mut dyn Node),
+        );
+        children
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for TraitUsage {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.r#use, self.end.after(b";"))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum TraitUsageAdaptation {
     Alias {
@@ -109,3 +228,121 @@ pub enum TraitUsageAdaptation {
         insteadof: Vec<SimpleIdentifier>,
     },
 }
+
+impl Node for TraitUsageAdaptation {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            TraitUsageAdaptation::Alias {
+                r#trait,
+                method,
+                alias,
+                ..
+            } => {
+                let mut children: Vec<&dyn Node> = vec![];
+                if let Some(r#trait) = r#trait {
+                    children.push(r#trait);
+                }
+                children.push(method);
+                children.push(alias);
+                children
+            }
+            TraitUsageAdaptation::Visibility {
+                r#trait, method, ..
+            } => {
+                let mut children: Vec<&dyn Node> = vec![];
+                if let Some(r#trait) = r#trait {
+                    children.push(r#trait);
+                }
+                children.push(method);
+                children
+            }
+            TraitUsageAdaptation::Precedence {
+                r#trait,
+                method,
+                insteadof,
+            } => {
+                let mut children: Vec<&dyn Node> = vec![];
+                if let Some(r#trait) = r#trait {
+                    children.push(r#trait);
+                }
+                children.push(method);
+                children.extend(insteadof.iter().map(|i| i as &dyn Node));
+                children
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            TraitUsageAdaptation::Alias {
+                r#trait,
+                method,
+                alias,
+                ..
+            } => {
+                let mut children: Vec<&mut dyn Node> = vec![];
+                if let Some(r#trait) = r#trait {
+                    children.push(r#trait);
+                }
+                children.push(method);
+                children.push(alias);
+                children
+            }
+            TraitUsageAdaptation::Visibility {
+                r#trait, method, ..
+            } => {
+                let mut children: Vec<&mut dyn Node> = vec![];
+                if let Some(r#trait) = r#trait {
+                    children.push(r#trait);
+                }
+                children.push(method);
+                children
+            }
+            TraitUsageAdaptation::Precedence {
+                r#trait,
+                method,
+                insteadof,
+            } => {
+                let mut children: Vec<&mut dyn Node> = vec![];
+                if let Some(r#trait) = r#trait {
+                    children.push(r#trait);
+                }
+                children.push(method);
+                children.extend(insteadof.iter_mut().map(|i| i as &mut dyn Node));
+                children
+            }
+        }
+    }
+}
+
+impl Spanned for TraitUsageAdaptation {
+    fn span(&self) -> SpanRange {
+        // The `;` after the adaptation is not kept, so the range ends before it.
+        match self {
+            TraitUsageAdaptation::Alias {
+                r#trait,
+                method,
+                alias,
+                ..
+            } => SpanRange::new(r#trait.as_ref().unwrap_or(method).span, alias.span().end),
+            TraitUsageAdaptation::Visibility {
+                r#trait,
+                method,
+                visibility,
+            } => {
+                let end = visibility.span().after(visibility.to_string().as_bytes());
+
+                SpanRange::new(r#trait.as_ref().unwrap_or(method).span, end)
+            }
+            TraitUsageAdaptation::Precedence {
+                r#trait,
+                method,
+                insteadof,
+            } => {
+                let end = insteadof.last().unwrap_or(method).span().end;
+
+                SpanRange::new(r#trait.as_ref().unwrap_or(method).span, end)
+            }
+        }
+    }
+}
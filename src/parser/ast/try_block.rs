@@ -1,3 +1,6 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -6,10 +9,13 @@ use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::Block;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
 use super::variables::SimpleVariable;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum CatchType {
     Identifier { identifier: SimpleIdentifier },
@@ -17,7 +23,16 @@ pub enum CatchType {
 }
 
 impl Node for CatchType {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        match self {
+            CatchType::Identifier { identifier } => vec![identifier],
+            CatchType::Union { identifiers } => {
+                identifiers.iter().map(|i| i as &dyn Node).collect()
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         match self {
             CatchType::Identifier { identifier } => vec![identifier],
             CatchType::Union { identifiers } => {
@@ -27,18 +42,42 @@ impl Node for CatchType {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for CatchType {
+    // The parser never produces an empty union of types.
+    #[allow(clippy::indexing_slicing)]
+    fn span(&self) -> SpanRange {
+        match self {
+            CatchType::Identifier { identifier } => identifier.span(),
+            CatchType::Union { identifiers } => identifiers[0]
+                .span()
+                .to(identifiers[identifiers.len() - 1].span()),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct TryStatement {
-    pub start: Span,
-    pub end: Span,
+    pub start: Span, // `try`
+    pub end: Span,   // the `}` of the last block
     pub body: Block,
     pub catches: Vec<CatchBlock>,
     pub finally: Option<FinallyBlock>,
 }
 
 impl Node for TryStatement {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.body];
+        for catch in &self.catches {
+            children.push(catch);
+        }
+        if let Some(finally) = &self.finally {
+            children.push(finally);
+        }
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![&mut self.body];
         for catch in &mut self.catches {
             children.push(catch);
@@ -50,18 +89,33 @@ impl Node for TryStatement {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for TryStatement {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(b"}"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct CatchBlock {
-    pub start: Span,
-    pub end: Span,
+    pub start: Span, // `catch`
+    pub end: Span,   // `}`
     pub types: CatchType,
     pub var: Option<SimpleVariable>,
     pub body: Block,
 }
 
 impl Node for CatchBlock {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        let mut children = vec![&self.types as &dyn Node];
+        if let Some(var) = &self.var {
+            children.push(var as &dyn Node);
+        }
+        children.push(&self.body as &dyn Node);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         let mut children = vec![&mut self.types as &mut dyn Node];
         if let Some(var) = &mut self.var {
             children.push(var as &mut dyn Node);
@@ -71,16 +125,32 @@ impl Node for CatchBlock {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for CatchBlock {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(b"}"))
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct FinallyBlock {
-    pub start: Span,
-    pub end: Span,
+    pub start: Span, // `finally`
+    pub end: Span,   // `}`
     pub body: Block,
 }
 
 impl Node for FinallyBlock {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![&self.body as &dyn Node]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.body as &mut dyn Node]
     }
 }
+
+impl Spanned for FinallyBlock {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(b"}"))
+    }
+}
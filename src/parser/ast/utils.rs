@@ -1,6 +1,8 @@
-use std::slice::Iter;
-use std::slice::IterMut;
+use alloc::vec::Vec;
+use core::slice::Iter;
+use core::slice::IterMut;
 
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -8,8 +10,8 @@ use serde::Serialize;
 use crate::lexer::token::Span;
 use crate::node::Node;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
-
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct CommaSeparated<T> {
     pub inner: Vec<T>,
     pub commas: Vec<Span>, // `,`
@@ -26,14 +28,18 @@ impl<T> CommaSeparated<T> {
 }
 
 impl<T: Node> Node for CommaSeparated<T> {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        self.inner.iter().map(|x| x as &dyn Node).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.inner.iter_mut().map(|x| x as &mut dyn Node).collect()
     }
 }
 
 impl<T> IntoIterator for CommaSeparated<T> {
     type Item = T;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.inner.into_iter()
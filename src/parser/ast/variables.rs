@@ -1,15 +1,21 @@
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
-use std::fmt::Display;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::Display;
 
 use crate::lexer::byte_string::ByteString;
 use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::Expression;
+use crate::parser::ast::SpanRange;
+use crate::parser::ast::Spanned;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum Variable {
     SimpleVariable(SimpleVariable),
@@ -18,17 +24,35 @@ pub enum Variable {
 }
 
 impl Node for Variable {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
         match self {
-            Variable::SimpleVariable(variable) => variable.children(),
-            Variable::VariableVariable(variable) => variable.children(),
-            Variable::BracedVariableVariable(variable) => variable.children(),
+            Variable::SimpleVariable(variable) => vec![variable],
+            Variable::VariableVariable(variable) => vec![variable],
+            Variable::BracedVariableVariable(variable) => vec![variable],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Variable::SimpleVariable(variable) => vec![variable],
+            Variable::VariableVariable(variable) => vec![variable],
+            Variable::BracedVariableVariable(variable) => vec![variable],
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for Variable {
+    fn span(&self) -> SpanRange {
+        match self {
+            Variable::SimpleVariable(variable) => variable.span(),
+            Variable::VariableVariable(variable) => variable.span(),
+            Variable::BracedVariableVariable(variable) => variable.span(),
+        }
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct SimpleVariable {
     pub span: Span,
     pub name: ByteString,
@@ -38,21 +62,37 @@ impl Node for SimpleVariable {
     //
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for SimpleVariable {
+    fn span(&self) -> SpanRange {
+        SpanRange::token(self.span, &self.name)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct VariableVariable {
     pub span: Span,
     pub variable: Box<Variable>,
 }
 
 impl Node for VariableVariable {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.variable.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.variable.as_mut()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+impl Spanned for VariableVariable {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.span, self.variable.span().end)
+    }
+}
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct BracedVariableVariable {
     pub start: Span,
     pub variable: Box<Expression>,
@@ -60,13 +100,23 @@ pub struct BracedVariableVariable {
 }
 
 impl Node for BracedVariableVariable {
-    fn children(&mut self) -> Vec<&mut dyn Node> {
+    fn children(&self) -> Vec<&dyn Node> {
+        vec![self.variable.as_ref()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![self.variable.as_mut()]
     }
 }
 
+impl Spanned for BracedVariableVariable {
+    fn span(&self) -> SpanRange {
+        SpanRange::new(self.start, self.end.after(b"}"))
+    }
+}
+
 impl Display for SimpleVariable {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.name)
     }
 }
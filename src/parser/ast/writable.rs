@@ -0,0 +1,94 @@
+use crate::parser::ast::Expression;
+
+/// Whether a value can be written to the expression, by an assignment, an
+/// increment or a decrement, or taken by reference.
+///
+/// Variables, including `$$a` and `${'a'}`, are writable, as are array
+/// offsets, `$a[] = 1` included, and property and static property fetches,
+/// whatever they are fetched from: `Foo::$a[0]->b` is writable just like
+/// `$a->b`.
+///
+/// Calls, constants, `::class`, nullsafe fetches and parenthesized
+/// expressions are not. Neither are `list()` and `[]`, which can be
+/// destructured by a plain `=`, but are not written to as a whole.
+///
+/// # Example
+///
+/// ```
+/// use php_parser_rs::parser::ast::{is_writable, Statement};
+///
+/// let program = php_parser_rs::parse("<?php static::$cache[$k]; foo()[0]->a; foo();").unwrap();
+///
+/// let writable = program
+///     .iter()
+///     .filter_map(|statement| match statement {
+///         Statement::Expression(statement) => Some(is_writable(&statement.expression)),
+///         _ => None,
+///     })
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(writable, [true, true, false]);
+/// ```
+pub fn is_writable(expression: &Expression) -> bool {
+    matches!(
+        expression,
+        Expression::Variable(_)
+            | Expression::ArrayIndex(_)
+            | Expression::PropertyFetch(_)
+            | Expression::StaticPropertyFetch(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_writable;
+    use crate::parser::ast::Statement;
+
+    fn writable(code: &str) -> bool {
+        let program = crate::parse(&alloc::format!("<?php {};", code)).unwrap();
+
+        match &program[1] {
+            Statement::Expression(statement) => is_writable(&statement.expression),
+            _ => panic!("expected an expression"),
+        }
+    }
+
+    #[test]
+    fn test_writable() {
+        for code in [
+            "$a",
+            "$$a",
+            "${'a'}",
+            "$a[0]",
+            "$a->b",
+            "$a->b[0]->c",
+            "Foo::$a",
+            "static::$a[0]",
+            "$cls::$a->b",
+            "Foo::$a::$b",
+            "foo()[0]",
+            "foo()->a",
+        ] {
+            assert!(writable(code), "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_not_writable() {
+        for code in [
+            "foo()",
+            "$a->b()",
+            "Foo::b()",
+            "$a?->b",
+            "Foo::class",
+            "Foo::BAR",
+            "FOO",
+            "($a)",
+            "list($a)",
+            "[$a]",
+            "1",
+        ] {
+            assert!(!writable(code), "{}", code);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! What the parser expected where it gave up, for completion.
+//!
+//! When the code before a cursor is parsed on its own, the parser fails at the
+//! cursor, and the error says what could have come next: a member of the
+//! receiver after `$a->`, a type after `function foo(): `, and so on.
+
+use alloc::boxed::Box;
+
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::Expression;
+use crate::parser::ast::UseKind;
+
+/// The grammatical context of a parse error: what the parser was looking for
+/// when it found something else.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(tag = "type")]
+pub enum ExpectedAt {
+    /// A property or method name, after `$receiver->` or `$receiver?->`.
+    MemberName { receiver: Box<Expression> },
+    /// A constant, static property or static method name, after `Class::`.
+    StaticMemberName { class: Box<Expression> },
+    /// A type, as in `function foo(): ` or `?`.
+    TypeName,
+    /// A name to import, after `use`, `use function`, `use const`, or inside
+    /// the braces of a group use, where the names are relative to `prefix`.
+    UsePath {
+        kind: UseKind,
+        prefix: Option<SimpleIdentifier>,
+    },
+    /// The name of an attribute, after `#[` or a comma between attributes.
+    AttributeName,
+    /// An argument, either a value or the name of a named argument. The
+    /// callee is known for function calls and `new`.
+    Argument { callee: Option<Box<Expression>> },
+    /// A variable, as in a parameter after its type, or a `catch` after the
+    /// caught types.
+    Variable,
+    /// Any expression.
+    Expression,
+}
+
+/// What the parser expected at `offset` in `input`, found by parsing the input
+/// up to the offset.
+///
+/// This is `None` when the code before the offset parses, or fails before
+/// reaching the offset. To complete a word that is partly typed, pass the
+/// offset at which the word starts.
+///
+/// # Example
+///
+/// ```
+/// use php_parser_rs::parser::completion::{expected_at, ExpectedAt};
+///
+/// let code = "<?php $user->";
+///
+/// assert!(matches!(
+///     expected_at(code, code.len()),
+///     Some(ExpectedAt::MemberName { .. })
+/// ));
+/// ```
+pub fn expected_at<B: ?Sized + AsRef<[u8]>>(input: &B, offset: usize) -> Option<ExpectedAt> {
+    let input = input.as_ref().get(..offset)?;
+    let Err(Error::Parse(stack)) = crate::parser::parse(input) else {
+        return None;
+    };
+
+    // The error that stopped the parse is the last one.
+    let error = stack.errors.into_iter().last()?;
+    if error.span.offset() != offset {
+        return None;
+    }
+
+    error.expected.map(|expected| *expected)
+}
@@ -0,0 +1,168 @@
+//! Syntax that still parses but is deprecated in the version of PHP the code
+//! targets, which the engine warns about when compiling it.
+
+use core::fmt::{Display, Formatter};
+
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lexer::token::Span;
+use crate::parser::options::PhpVersion;
+
+/// A use of deprecated syntax, from the first byte of `start` to the last
+/// byte of the token at `end`.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+pub struct Deprecation {
+    pub kind: DeprecationKind,
+    pub start: Span,
+    pub end: Span,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+pub enum DeprecationKind {
+    /// `"${name}"`, which is short for `"{$name}"`.
+    DollarBraceVariable,
+    /// `"${expr}"`, which is short for `"{${expr}}"`.
+    DollarBraceExpression,
+    /// `continue` targeting a `switch` statement, where it acts like `break`.
+    ContinueTargetingSwitch,
+}
+
+impl DeprecationKind {
+    /// The version of PHP that deprecated the syntax.
+    pub fn since(&self) -> PhpVersion {
+        match self {
+            Self::DollarBraceVariable | Self::DollarBraceExpression => PhpVersion::Php82,
+            // PHP 7.3 started warning about it, which is before the oldest
+            // version the parser supports.
+            Self::ContinueTargetingSwitch => PhpVersion::Php74,
+        }
+    }
+}
+
+impl Deprecation {
+    /// The warning the engine gives for the syntax.
+    pub fn message(&self) -> &'static str {
+        match self.kind {
+            DeprecationKind::DollarBraceVariable => {
+                "Using ${var} in strings is deprecated, use {$var} instead"
+            }
+            DeprecationKind::DollarBraceExpression => {
+                "Using ${expr} (variable variables) in strings is deprecated, use {${expr}} instead"
+            }
+            DeprecationKind::ContinueTargetingSwitch => {
+                "\"continue\" targeting switch is equivalent to \"break\""
+            }
+        }
+    }
+}
+
+impl Display for Deprecation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Deprecated: {} on line {} column {}",
+            self.message(),
+            self.start.line,
+            self.start.column
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::parser::parse_with_deprecations;
+    use crate::parser::ParserOptions;
+
+    // The kind of every deprecation along with the source it spans.
+    fn deprecations(code: &str, version: PhpVersion) -> Vec<(DeprecationKind, &str)> {
+        let options = ParserOptions {
+            version,
+            ..ParserOptions::default()
+        };
+
+        let (program, deprecations) = parse_with_deprecations(code, &options);
+        assert!(program.is_ok(), "{}", code);
+
+        deprecations
+            .iter()
+            .map(|deprecation| {
+                let end = deprecation.end.offset() + 1;
+
+                (deprecation.kind, &code[deprecation.start.offset()..end])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_double_quoted_string() {
+        let code = r#"<?php echo "{$a} ${b} {$c[0]} ${d[1]} ${$e} ${f . 'g'}";"#;
+
+        assert_eq!(
+            deprecations(code, PhpVersion::Php84),
+            [
+                (DeprecationKind::DollarBraceVariable, "${b}"),
+                (DeprecationKind::DollarBraceVariable, "${d[1]}"),
+                (DeprecationKind::DollarBraceExpression, "${$e}"),
+                (DeprecationKind::DollarBraceExpression, "${f . 'g'}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_heredoc() {
+        let code = "<?php echo <<<EOT\n    {$a} ${b}\n    ${$c} {$d}\n    EOT;\n";
+
+        assert_eq!(
+            deprecations(code, PhpVersion::Php84),
+            [
+                (DeprecationKind::DollarBraceVariable, "${b}"),
+                (DeprecationKind::DollarBraceExpression, "${$c}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_continue_targeting_switch() {
+        let code = "<?php switch ($a) { case 1: continue; } while ($b) { switch ($c) { default: continue 2; continue (1); } }";
+
+        let (program, deprecations) = parse_with_deprecations(code, &ParserOptions::default());
+        assert!(program.is_ok());
+
+        // Only the `continue` that targets the inner `switch` is deprecated,
+        // from the keyword up to the end of its level.
+        let spans = deprecations
+            .iter()
+            .map(|deprecation| {
+                (
+                    deprecation.kind,
+                    deprecation.start.offset(),
+                    deprecation.end.offset(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            spans,
+            [
+                (DeprecationKind::ContinueTargetingSwitch, 28, 28),
+                (DeprecationKind::ContinueTargetingSwitch, 88, 99),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_before_deprecation() {
+        let code = r#"<?php echo "${a} ${$b}";"#;
+
+        assert_eq!(deprecations(code, PhpVersion::Php81), []);
+        assert_eq!(deprecations(code, PhpVersion::Php82).len(), 2);
+    }
+}
@@ -1,6 +1,12 @@
-use std::fmt::{Display, Formatter};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
 
+#[cfg(feature = "std")]
 use ariadne::{CharSet, Color, Config, Label, Report, ReportKind, Source};
+#[cfg(feature = "std")]
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -9,8 +15,15 @@ use crate::lexer::error::SyntaxError;
 use crate::lexer::token::{Span, Token, TokenKind};
 use crate::parser::ast::attributes::AttributeGroup;
 use crate::parser::ast::data_type::Type;
+use crate::parser::ast::literals::Literal;
+use crate::parser::ast::literals::LiteralInteger;
 use crate::parser::ast::modifiers::PromotedPropertyModifier;
+use crate::parser::ast::modifiers::Visibility;
+use crate::parser::ast::Expression;
 use crate::parser::ast::Program;
+use crate::parser::ast::Spanned;
+use crate::parser::completion::ExpectedAt;
+use crate::parser::options::PhpVersion;
 
 use super::ast::identifiers::SimpleIdentifier;
 use super::ast::variables::SimpleVariable;
@@ -18,37 +31,45 @@ use super::state::State;
 
 pub type ParseResult<T> = Result<T, ParseError>;
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(tag = "type")]
 pub enum ParseErrorAnnotationType {
     Hint,
     Error,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ParseErrorAnnotation {
     pub r#type: ParseErrorAnnotationType,
     pub message: String,
-    pub position: usize,
-    pub length: usize,
+    pub position: u32,
+    pub length: u32,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ParseError {
     pub id: String,
     pub message: String,
     pub span: Span,
     pub annotations: Vec<ParseErrorAnnotation>,
     pub note: Option<String>,
+    /// What the parser expected instead of what it found, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<Box<ExpectedAt>>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct ParseErrorStack {
     pub partial: Program,
     pub errors: Vec<ParseError>,
 }
 
 impl ParseErrorStack {
+    #[cfg(feature = "std")]
     pub fn report<'a>(
         &self,
         source: &'a str,
@@ -74,37 +95,119 @@ impl ParseError {
             span,
             annotations: Vec::new(),
             note: None,
+            expected: None,
         }
     }
 
-    pub fn highlight(mut self, position: usize, length: usize) -> Self {
+    /// Records what the parser expected, unless a more specific context was
+    /// recorded where the error was raised.
+    pub fn expecting(mut self, context: ExpectedAt) -> Self {
+        self.expected.get_or_insert_with(|| Box::new(context));
+
+        self
+    }
+
+    /// Records the callee of the argument the parser expected, as in `foo(`.
+    pub fn called_on(mut self, target: &Expression) -> Self {
+        if let Some(ExpectedAt::Argument {
+            callee: callee @ None,
+        }) = self.expected.as_deref_mut()
+        {
+            *callee = Some(Box::new(target.clone()));
+        }
+
+        self
+    }
+
+    pub fn highlight(self, position: u32, length: usize) -> Self {
+        self.hint("", position, length)
+    }
+
+    pub fn hint<T: ToString>(mut self, message: T, position: u32, length: usize) -> Self {
         self.annotations.push(ParseErrorAnnotation {
             r#type: ParseErrorAnnotationType::Hint,
-            message: "".to_owned(),
+            message: message.to_string(),
             position,
-            length,
+            length: annotation_length(length),
         });
 
         self
     }
 
-    pub fn error<T: ToString>(mut self, message: T, position: usize, length: usize) -> Self {
+    pub fn error<T: ToString>(mut self, message: T, position: u32, length: usize) -> Self {
         self.annotations.push(ParseErrorAnnotation {
             r#type: ParseErrorAnnotationType::Error,
             message: message.to_string(),
             position,
-            length,
+            length: annotation_length(length),
         });
 
         self
     }
 
+    // Code pasted from word processors and chat applications often contains
+    // characters that look like ASCII punctuation or whitespace, but that PHP
+    // treats as part of an identifier.
+    fn confusable(self, value: &[u8], span: Span) -> Self {
+        const CONFUSABLES: &[(char, &str, &str)] = &[
+            (
+                '\u{00A0}',
+                "a non-breaking space (U+00A0)",
+                "a regular space",
+            ),
+            ('\u{200B}', "a zero width space (U+200B)", "a regular space"),
+            ('\u{2013}', "an en dash (U+2013)", "`-`"),
+            ('\u{2014}', "an em dash (U+2014)", "`-`"),
+            ('\u{2212}', "a minus sign (U+2212)", "`-`"),
+            ('\u{2018}', "a left single quotation mark (U+2018)", "`'`"),
+            ('\u{2019}', "a right single quotation mark (U+2019)", "`'`"),
+            ('\u{201C}', "a left double quotation mark (U+201C)", "`\"`"),
+            ('\u{201D}', "a right double quotation mark (U+201D)", "`\"`"),
+        ];
+
+        // The value is searched byte by byte, as it may not be valid UTF-8,
+        // so that the offset of the character is its offset in the source.
+        let confusable = (0..value.len()).find_map(|index| {
+            let rest = value.get(index..)?;
+
+            CONFUSABLES
+                .iter()
+                .find(|(character, _, _)| {
+                    rest.starts_with(character.encode_utf8(&mut [0; 4]).as_bytes())
+                })
+                .map(|confusable| (index, confusable))
+        });
+
+        match confusable {
+            Some((index, (character, name, replacement))) => self.hint(
+                format!("this is {}, not {}", name, replacement),
+                span.position + index as u32,
+                character.len_utf8(),
+            ),
+            None => self,
+        }
+    }
+
+    /// Hints at a look-alike character in the token before the one that an
+    /// unexpected token error is about. A character pasted in place of a space
+    /// or an operator joins the word before it, as in `echo\u{a0}$a;`, where
+    /// the parser only notices something is wrong at `$a`.
+    pub(crate) fn confusable_before(self, previous: Option<&Token>) -> Self {
+        match previous {
+            Some(previous) if matches!(self.id.as_str(), "E003" | "E005" | "E006") => {
+                self.confusable(&previous.value, previous.span)
+            }
+            _ => self,
+        }
+    }
+
     pub fn note<T: ToString>(mut self, note: T) -> Self {
         self.note = Some(note.to_string());
 
         self
     }
 
+    #[cfg(feature = "std")]
     pub fn report<'a>(
         &self,
         source: &'a str,
@@ -114,7 +217,13 @@ impl ParseError {
     ) -> std::io::Result<String> {
         let origin = origin.unwrap_or("input");
 
-        let mut report = Report::build(ReportKind::Error, origin, self.span.position)
+        // Spans are byte offsets, while reports are built from character offsets.
+        let offset = |position: usize| match source.get(..position) {
+            Some(prefix) => prefix.chars().count(),
+            None => position,
+        };
+
+        let mut report = Report::build(ReportKind::Error, origin, offset(self.span.offset()))
             .with_code(&self.id)
             .with_message(&self.message)
             .with_config(
@@ -130,9 +239,10 @@ impl ParseError {
         for (order, annotation) in self.annotations.iter().enumerate() {
             let mut label = Label::new((
                 origin,
-                annotation.position..annotation.position + annotation.length,
+                offset(annotation.position as usize)
+                    ..offset((annotation.position + annotation.length) as usize),
             ))
-            .with_order(order.try_into().unwrap());
+            .with_order(i32::try_from(order).unwrap_or(i32::MAX));
 
             if !annotation.message.is_empty() {
                 label = label.with_message(&annotation.message);
@@ -167,7 +277,17 @@ impl ParseError {
     }
 }
 
+// Annotations cover code in a file, which cannot be longer than a span can
+// point into.
+fn annotation_length(length: usize) -> u32 {
+    u32::try_from(length).unwrap_or(u32::MAX)
+}
+
 pub fn unexpected_token(expected: Vec<String>, found: &Token) -> ParseError {
+    if let TokenKind::OpenTag(_) | TokenKind::CloseTag = found.kind {
+        return unexpected_tag(found);
+    }
+
     let (found_name, eof) = match &found.kind {
         TokenKind::Eof => ("end of file".to_string(), true),
         kind => match kind {
@@ -186,11 +306,13 @@ pub fn unexpected_token(expected: Vec<String>, found: &Token) -> ParseError {
         return if eof {
             ParseError::new("E002", format!("unexpected {}", found_name), found.span)
         } else {
-            ParseError::new("E003", format!("unexpected {}", found_name), found.span).error(
-                "try removing this".to_string(),
-                found.span.position,
-                found.value.len(),
-            )
+            ParseError::new("E003", format!("unexpected {}", found_name), found.span)
+                .error(
+                    "try removing this".to_string(),
+                    found.span.position,
+                    found.value.len(),
+                )
+                .confusable(&found.value, found.span)
         };
     }
 
@@ -205,13 +327,9 @@ pub fn unexpected_token(expected: Vec<String>, found: &Token) -> ParseError {
         })
         .collect();
 
-    let length = expected.len();
-    let expected = if length > 2 {
-        let (left, right) = expected.split_at(length - 1);
-
-        format!("{}, or {}", left.join(", "), right[0])
-    } else {
-        expected.join(", or ")
+    let expected = match expected.split_last() {
+        Some((last, rest)) if rest.len() > 1 => format!("{}, or {}", rest.join(", "), last),
+        _ => expected.join(", or "),
     };
 
     ParseError::new(
@@ -224,16 +342,15 @@ pub fn unexpected_token(expected: Vec<String>, found: &Token) -> ParseError {
         found.span.position,
         found.value.len(),
     )
+    .confusable(&found.value, found.span)
 }
 
 pub fn unexpected_identifier(expected: Vec<String>, found: String, span: Span) -> ParseError {
-    let length = expected.len();
-    let expected = if length >= 2 {
-        let (left, right) = expected.split_at(length - 1);
-
-        format!("{}`, or `{}", left.join("`, `"), right[0])
-    } else {
-        expected.join("")
+    let expected = match expected.split_last() {
+        Some((last, rest)) if !rest.is_empty() => {
+            format!("{}`, or `{}", rest.join("`, `"), last)
+        }
+        _ => expected.join(""),
     };
 
     ParseError::new(
@@ -249,6 +366,7 @@ pub fn unexpected_identifier(expected: Vec<String>, found: String, span: Span) -
         span.position,
         found.len(),
     )
+    .confusable(found.as_bytes(), span)
 }
 
 pub fn multiple_modifiers(modifier: String, first: Span, second: Span) -> ParseError {
@@ -335,7 +453,7 @@ pub fn try_without_catch_or_finally(try_span: Span, last_right_brace: Span) -> P
     )
     .highlight(
         try_span.position,
-        last_right_brace.position - try_span.position + 1,
+        last_right_brace.offset() - try_span.offset() + 1,
     )
 }
 
@@ -482,7 +600,7 @@ pub fn missing_case_value_for_backed_enum(
             case,
             r#enum
         ),
-        semicolon_span,
+        case.span,
     )
     .error("try adding a value", semicolon_span.position, 1)
     .highlight(case.span.position, case.value.len())
@@ -503,7 +621,7 @@ pub fn case_value_for_unit_enum(
             case,
             r#enum
         ),
-        equals_span,
+        case.span,
     )
     .error("try replacing this with `;`", equals_span.position, 1)
     .highlight(case.span.position, case.value.len())
@@ -833,13 +951,13 @@ pub fn missing_item_definition_after_attributes(
             r#type: ParseErrorAnnotationType::Error,
             message: "reached end of file before an item definition".to_string(),
             position: current.span.position,
-            length: current.value.len(),
+            length: annotation_length(current.value.len()),
         },
         _ => ParseErrorAnnotation {
             r#type: ParseErrorAnnotationType::Error,
             message: format!("expected an item definition, found `{}`", current.value),
             position: current.span.position,
-            length: current.value.len(),
+            length: annotation_length(current.value.len()),
         },
     });
 
@@ -849,6 +967,7 @@ pub fn missing_item_definition_after_attributes(
         span: current.span,
         annotations,
         note: None,
+        expected: None,
     }
 }
 
@@ -861,6 +980,368 @@ pub fn nested_disjunctive_normal_form_types(span: Span) -> ParseError {
     .error("try removing this", span.position, 1)
 }
 
+pub fn union_type_in_intersection_type(span: Span) -> ParseError {
+    ParseError::new(
+        "E077".to_string(),
+        "cannot use a union type in an intersection type",
+        span,
+    )
+    .error("try removing this", span.position, 1)
+    .note("disjunctive normal form types are unions of intersections, such as `(A&B)|C`")
+}
+
+pub fn nullable_disjunctive_normal_form_type(span: Span) -> ParseError {
+    ParseError::new(
+        "E078".to_string(),
+        "cannot use `?` with a disjunctive normal form type",
+        span,
+    )
+    .error("try removing this", span.position, 1)
+    .note("add `null` to the union instead, as in `(A&B)|null`")
+}
+
+pub fn redundant_type_in_union(union: &Type, redundant: &Type, other: &Type) -> ParseError {
+    let span = redundant.first_span();
+
+    ParseError::new(
+        "E079".to_string(),
+        format!(
+            "type `{}` contains both `{}` and `{}`, which is redundant",
+            union, other, redundant
+        ),
+        span,
+    )
+    .error(
+        "try removing this",
+        span.position,
+        redundant.to_string().len(),
+    )
+    .highlight(other.first_span().position, other.to_string().len())
+}
+
+pub fn global_non_simple_variable(current: &Token) -> ParseError {
+    ParseError::new(
+        "E080".to_string(),
+        "global statements can only declare variables",
+        current.span,
+    )
+    .error(
+        "try removing this",
+        current.span.position,
+        current.value.len(),
+    )
+    .note("only variables such as `$a`, `$$a`, or `${'a'}` can be made global")
+}
+
+pub fn class_constant_in_interpolation(double_colon: Span) -> ParseError {
+    ParseError::new(
+        "E081".to_string(),
+        "cannot interpolate a class constant",
+        double_colon,
+    )
+    .error("try removing this", double_colon.position, 2)
+    .note("only variables, along with their elements, properties, and method calls, can be interpolated")
+}
+
+pub fn cannot_use_expression_in_construct(
+    construct: &str,
+    span: Span,
+    current_span: Span,
+) -> ParseError {
+    ParseError::new(
+        "E082".to_string(),
+        format!("cannot use `{}` on the result of an expression", construct),
+        span,
+    )
+    .error(
+        "try using a variable",
+        span.position,
+        current_span.offset() - span.offset(),
+    )
+    .note(format!(
+        "only variables, array elements, and properties can be passed to `{}`",
+        construct
+    ))
+}
+
+pub fn unparenthesized_instanceof_class(current: &Token) -> ParseError {
+    ParseError::new(
+        "E083".to_string(),
+        "the class of `instanceof` must be a name, a variable, or in parentheses",
+        current.span,
+    )
+    .error(
+        "try wrapping the class in parentheses",
+        current.span.position,
+        current.value.len(),
+    )
+    .note("`$a instanceof (foo())` checks against the class named by the result of `foo()`")
+}
+
+pub fn invalid_doc_body_indentation(
+    whitespace: &[u8],
+    line: Span,
+    indentation: &[u8],
+    closing: Span,
+) -> ParseError {
+    let matching = whitespace
+        .iter()
+        .zip(indentation)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let message = if matching == whitespace.len() {
+        "this line of the doc string is indented less than its closing label"
+    } else {
+        "this line of the doc string is indented with different whitespace than its closing label"
+    };
+
+    ParseError::new("E084".to_string(), message, line)
+        .error(
+            format!("this line starts with {}", describe_whitespace(whitespace)),
+            line.position,
+            whitespace.len().max(1),
+        )
+        .hint(
+            format!(
+                "the closing label is indented with {}",
+                describe_whitespace(indentation)
+            ),
+            closing.position,
+            indentation.len(),
+        )
+        .note("each line of a heredoc or nowdoc has to start with the indentation of its closing label, byte for byte")
+}
+
+pub fn unknown_property_hook(name: &SimpleIdentifier) -> ParseError {
+    ParseError::new(
+        "E085".to_string(),
+        format!("unknown property hook `{}`", name),
+        name.span,
+    )
+    .error("try `get` or `set`", name.span.position, name.value.len())
+    .note("a property can only have a `get` and a `set` hook")
+}
+
+pub fn static_property_with_hooks(
+    state: &mut State,
+    class: Option<&SimpleIdentifier>,
+    property: &SimpleVariable,
+    static_span: Span,
+    left_brace_span: Span,
+) -> ParseError {
+    let error = ParseError::new(
+        "E086",
+        format!(
+            "cannot declare hooks for static property `{}::{}`",
+            class
+                .map(|c| state.named(c))
+                .unwrap_or_else(|| "anonymous@class".to_string()),
+            property.name,
+        ),
+        static_span,
+    )
+    .highlight(property.span.position, property.name.len())
+    .highlight(left_brace_span.position, 1)
+    .error("try removing this", static_span.position, "static".len());
+
+    // If the class is anonymous, we don't have a span to highlight
+    if let Some(class) = class {
+        error.highlight(class.span.position, class.value.len())
+    } else {
+        error
+    }
+}
+
+pub fn interface_property_without_hooks(
+    state: &mut State,
+    interface: &SimpleIdentifier,
+    property: &SimpleVariable,
+) -> ParseError {
+    ParseError::new(
+        "E087",
+        format!(
+            "interface property `{}::{}` must have hooks",
+            state.named(interface),
+            property.name,
+        ),
+        property.span,
+    )
+    .error(
+        "try adding hooks after this, such as `{ get; }`",
+        property.span.position,
+        property.name.len(),
+    )
+    .highlight(interface.span.position, interface.value.len())
+    .note("an interface can only declare the hooks a property has, not the property itself")
+}
+
+pub fn modifier_cannot_be_used_for_interface_property(
+    modifier: String,
+    modifier_span: Span,
+) -> ParseError {
+    ParseError::new(
+        "E088",
+        format!(
+            "cannot use '{}' as an interface property modifier",
+            modifier
+        ),
+        modifier_span,
+    )
+    .error("try removing this", modifier_span.position, modifier.len())
+    .note("only the `public` modifier can be used on interface properties")
+}
+
+pub fn set_visibility_wider_than_visibility(
+    (visibility, visibility_span): (Visibility, Option<Span>),
+    (set_visibility, set_span): (Visibility, Span),
+) -> ParseError {
+    let set_modifier = format!("{}(set)", set_visibility);
+    let error = ParseError::new(
+        "E089",
+        format!(
+            "cannot declare a {} property as `{}`",
+            visibility, set_modifier
+        ),
+        set_span,
+    )
+    .error(
+        format!("try `{}(set)` or removing this", visibility),
+        set_span.position,
+        set_modifier.len(),
+    )
+    .note("a property cannot be easier to write than to read");
+
+    match visibility_span {
+        Some(span) => error.highlight(span.position, visibility.to_string().len()),
+        None => error,
+    }
+}
+
+pub fn set_visibility_with_incompatible_modifier(
+    set_visibility: Visibility,
+    set_span: Span,
+    modifier: String,
+    modifier_span: Span,
+) -> ParseError {
+    let set_modifier = format!("{}(set)", set_visibility);
+
+    ParseError::new(
+        "E090",
+        format!(
+            "cannot declare a `{}` property as `{}`",
+            modifier, set_modifier
+        ),
+        set_span,
+    )
+    .error("try removing this", set_span.position, set_modifier.len())
+    .highlight(modifier_span.position, modifier.len())
+    .note(if modifier == "static" {
+        "a static property is written with the same visibility it is read with"
+    } else {
+        "a readonly property can only be written from within its class"
+    })
+}
+
+pub fn missing_type_for_asymmetric_property(
+    state: &mut State,
+    class: Option<&SimpleIdentifier>,
+    property: &SimpleVariable,
+    set_visibility: Visibility,
+    set_span: Span,
+) -> ParseError {
+    let error = ParseError::new(
+        "E091",
+        format!(
+            "missing type for property `{}::{}` with a `{}(set)` visibility",
+            class
+                .map(|c| state.named(c))
+                .unwrap_or_else(|| "anonymous@class".to_string()),
+            property.name,
+            set_visibility,
+        ),
+        property.span,
+    )
+    .error(
+        format!("try adding a type before `{}`", property.name),
+        property.span.position,
+        property.name.len(),
+    )
+    .highlight(set_span.position, set_visibility.to_string().len() + 5);
+
+    if let Some(class) = class {
+        error.highlight(class.span.position, class.value.len())
+    } else {
+        error
+    }
+}
+
+pub fn dereferenced_new_without_arguments(new: Span, current: &Token) -> ParseError {
+    ParseError::new(
+        "E092",
+        format!(
+            "cannot use `{}` on `new` without an argument list",
+            current.value
+        ),
+        current.span,
+    )
+    .error(
+        "try adding `()` before this",
+        current.span.position,
+        current.value.len(),
+    )
+    .highlight(new.position, 3)
+    .note("`new Foo()->bar()` calls `bar()` on a new instance of `Foo`, as does `(new Foo)->bar()`")
+}
+
+pub fn invalid_strict_types_value(value: &Literal) -> ParseError {
+    let span = value.span();
+
+    ParseError::new(
+        "E093",
+        "`strict_types` declaration must have 0 or 1 as its value",
+        span.start,
+    )
+    .error(
+        "try using 0 or 1 here",
+        span.start.position,
+        (span.end.position - span.start.position) as usize,
+    )
+}
+
+// Spells out leading whitespace, as tabs and spaces look alike: `\t  ` is
+// "1 tab, then 2 spaces".
+fn describe_whitespace(whitespace: &[u8]) -> String {
+    if whitespace.is_empty() {
+        return "no indentation".to_string();
+    }
+
+    let mut runs: Vec<(u8, usize)> = Vec::new();
+    for b in whitespace {
+        match runs.last_mut() {
+            Some((last, count)) if last == b => *count += 1,
+            _ => runs.push((*b, 1)),
+        }
+    }
+
+    let symbols = whitespace
+        .iter()
+        .map(|b| if *b == b'\t' { "\\t" } else { " " })
+        .collect::<String>();
+    let counts = runs
+        .iter()
+        .map(|(b, count)| {
+            let name = if *b == b'\t' { "tab" } else { "space" };
+            let plural = if *count == 1 { "" } else { "s" };
+
+            format!("{} {}{}", count, name, plural)
+        })
+        .collect::<Vec<String>>()
+        .join(", then ");
+
+    format!("`{}` ({})", symbols, counts)
+}
+
 pub fn illegal_spread_operator_usage(span: Span) -> ParseError {
     ParseError::new("E041".to_string(), "illegal spread operator usage", span).error(
         "try removing this",
@@ -899,7 +1380,7 @@ pub fn cannot_use_positional_argument_after_named_argument(
     .error(
         "try adding a name for this argument",
         span.position,
-        current_span.position - span.position,
+        current_span.offset() - span.offset(),
     )
 }
 
@@ -951,7 +1432,7 @@ pub fn only_positional_arguments_are_accepted(span: Span, current_span: Span) ->
     .error(
         "try changing this to a positional argument",
         span.position,
-        current_span.position - span.position,
+        current_span.offset() - span.offset(),
     )
 }
 
@@ -959,7 +1440,7 @@ pub fn only_one_argument_is_accepted(span: Span, current_span: Span) -> ParseErr
     ParseError::new("E050".to_string(), "only one argument are accepted", span).error(
         "try removing this argument",
         span.position,
-        current_span.position - span.position,
+        current_span.offset() - span.offset(),
     )
 }
 
@@ -967,24 +1448,331 @@ pub fn argument_is_required(span: Span, current_span: Span) -> ParseError {
     ParseError::new("E051".to_string(), "argument is required", span).error(
         "try passing an argument",
         span.position,
-        current_span.position - span.position,
+        current_span.offset() - span.offset(),
+    )
+}
+
+pub fn yield_from_cannot_have_a_key(span: Span) -> ParseError {
+    ParseError::new(
+        "E052".to_string(),
+        "cannot use a key with `yield from`",
+        span,
+    )
+    .error("try removing this", span.position, 2)
+    .note("`yield from` delegates to another generator and yields its keys and values")
+}
+
+pub fn cannot_use_this_as_lexical_variable(span: Span) -> ParseError {
+    ParseError::new(
+        "E053".to_string(),
+        "cannot use `$this` as lexical variable",
+        span,
     )
+    .error("try removing this", span.position, "$this".len())
+    .note("`$this` is automatically available inside non-static closures")
+}
+
+// Duplicates that PHP allows are never reported. Of those it rejects at
+// compile time, constants, properties and methods declared more than once in
+// a class-like body are deliberately not checked, and are left to tools that
+// look at the whole declaration:
+//
+// | duplicate                               | verdict                  |
+// |-----------------------------------------|--------------------------|
+// | parameter names                         | E054                     |
+// | named arguments in a call               | E055                     |
+// | enum case names                         | E056                     |
+// | constant names in one `const` group     | E057                     |
+// | constant names in separate `const`s     | not checked              |
+// | property names                          | not checked              |
+// | method names                            | not checked              |
+// | literal array keys                      | allowed, the last wins   |
+// | `match` arm conditions                  | allowed, the first wins  |
+
+pub fn duplicate_parameter(first: &SimpleVariable, second: &SimpleVariable) -> ParseError {
+    ParseError::new(
+        "E054".to_string(),
+        format!("redefinition of parameter `{}`", second.name),
+        second.span,
+    )
+    .error(
+        "try renaming this parameter",
+        second.span.position,
+        second.name.len(),
+    )
+    .hint("first declared here", first.span.position, first.name.len())
+}
+
+pub fn duplicate_named_argument(first: &SimpleIdentifier, second: &SimpleIdentifier) -> ParseError {
+    ParseError::new(
+        "E055".to_string(),
+        format!("duplicate named argument `{}`", second.value),
+        second.span,
+    )
+    .error(
+        "try removing this argument",
+        second.span.position,
+        second.value.len(),
+    )
+    .hint("first passed here", first.span.position, first.value.len())
+}
+
+pub fn duplicate_enum_case(
+    state: &mut State,
+    r#enum: &SimpleIdentifier,
+    first: &SimpleIdentifier,
+    second: &SimpleIdentifier,
+) -> ParseError {
+    ParseError::new(
+        "E056".to_string(),
+        format!(
+            "cannot redefine case `{}::{}`",
+            state.named(&r#enum),
+            second.value
+        ),
+        second.span,
+    )
+    .error(
+        "try renaming this case",
+        second.span.position,
+        second.value.len(),
+    )
+    .hint(
+        "first declared here",
+        first.span.position,
+        first.value.len(),
+    )
+}
+
+pub fn duplicate_constant(first: &SimpleIdentifier, second: &SimpleIdentifier) -> ParseError {
+    ParseError::new(
+        "E057".to_string(),
+        format!("cannot redefine constant `{}`", second.value),
+        second.span,
+    )
+    .error(
+        "try renaming this constant",
+        second.span.position,
+        second.value.len(),
+    )
+    .hint(
+        "first declared here",
+        first.span.position,
+        first.value.len(),
+    )
+}
+
+pub fn cannot_assign_to_non_variable(operator: &Token) -> ParseError {
+    ParseError::new(
+        "E058".to_string(),
+        format!(
+            "cannot use `{}` on an expression that is not a variable",
+            operator.value
+        ),
+        operator.span,
+    )
+    .error(
+        "cannot assign to the expression on the left",
+        operator.span.position,
+        operator.value.len(),
+    )
+    .note("only variables, array offsets, and properties can be assigned to")
+}
+
+/// Returned instead of panicking when the parser reaches a state that should
+/// be impossible, so that a bug in the parser doesn't bring down its host.
+///
+/// The error only unwinds the parse, which then fails with
+/// [`Error::Internal`](crate::Error::Internal) rather than this error.
+pub fn internal_error<T: ToString>(state: &mut State, detail: T, span: Span) -> ParseError {
+    let detail = detail.to_string();
+    let error = internal(&detail, span);
+
+    state.internal = Some(detail);
+
+    error
+}
+
+/// The report for an internal error found at `span`.
+pub fn internal(detail: &str, span: Span) -> ParseError {
+    ParseError::new(
+        "E059".to_string(),
+        format!("internal error: {}", detail),
+        span,
+    )
+    .note("this is a bug in the parser, please report it")
+}
+
+pub fn unparenthesized_nested_ternary(
+    first: Span,
+    first_length: usize,
+    second: &Token,
+) -> ParseError {
+    ParseError::new(
+        "E060".to_string(),
+        "cannot nest ternary expressions without parentheses",
+        second.span,
+    )
+    .error(
+        "try wrapping one of the ternary expressions in parentheses",
+        second.span.position,
+        second.value.len(),
+    )
+    .hint(
+        "nested in this ternary expression",
+        first.position,
+        first_length,
+    )
+    .note("use either `(a ? b : c) ? d : e` or `a ? b : (c ? d : e)`")
+}
+
+// Statements that PHP only accepts in some positions, and how the parser
+// treats them:
+//
+// | construct                               | verdict                       |
+// |-----------------------------------------|-------------------------------|
+// | `break` / `continue` outside a loop     | E061                          |
+// | `break 3` with fewer enclosing loops    | E062                          |
+// | `break 0`                               | E063                          |
+// | `continue` targeting a `switch`         | ContinueTargetingSwitch       |
+// |                                         | deprecation, PHP only warns   |
+// | `yield` and `return` in `finally`       | allowed                       |
+// | `goto` into a loop, `switch` or         | not checked, PHP rejects it   |
+// | `finally`, or out of a `finally`        | once labels are resolved      |
+
+pub fn loop_control_outside_of_loop(keyword: &str, span: Span) -> ParseError {
+    ParseError::new(
+        "E061".to_string(),
+        format!(
+            "cannot use `{}` outside of a loop or `switch` statement",
+            keyword
+        ),
+        span,
+    )
+    .error("try removing this", span.position, keyword.len())
+}
+
+pub fn loop_control_level_too_deep(
+    keyword: &str,
+    span: Span,
+    level: &LiteralInteger,
+    depth: usize,
+) -> ParseError {
+    ParseError::new(
+        "E062".to_string(),
+        format!(
+            "cannot `{}` {} levels, as there {} only {} enclosing loop{} or `switch` statement{}",
+            keyword,
+            level.value,
+            if depth == 1 { "is" } else { "are" },
+            depth,
+            if depth == 1 { "" } else { "s" },
+            if depth == 1 { "" } else { "s" },
+        ),
+        span,
+    )
+    .error(
+        format!("try using a level of at most {}", depth),
+        level.span.position,
+        level.value.len(),
+    )
+    .highlight(span.position, keyword.len())
+}
+
+pub fn loop_control_level_not_positive(
+    keyword: &str,
+    span: Span,
+    level: &LiteralInteger,
+) -> ParseError {
+    ParseError::new(
+        "E063".to_string(),
+        format!("`{}` only accepts positive levels", keyword),
+        span,
+    )
+    .error(
+        "try using a level of 1 or more",
+        level.span.position,
+        level.value.len(),
+    )
+    .highlight(span.position, keyword.len())
+}
+
+// Rules of argument lists, and how the parser treats them:
+//
+// | construct                               | verdict                       |
+// |-----------------------------------------|-------------------------------|
+// | `foo(a: 1, $b)`                         | E044                          |
+// | `foo(a: 1, a: 2)`                       | E055                          |
+// | `foo(a: ...$b)`                         | E064                          |
+// | `#[Foo(...$a)]`                         | E065                          |
+// | `foo(...$a, ...f(), ...[1, 2])`         | allowed                       |
+// | `foo(...['a' => 1])`                    | allowed, keys are only known  |
+// |                                         | at runtime                    |
+// | `foo(...$a, b: 1)`                      | allowed                       |
+// | `foo(...$a, 1)`                         | not checked, PHP rejects it   |
+// |                                         | at compile time               |
+
+pub fn cannot_unpack_named_argument(name: &SimpleIdentifier, ellipsis: Span) -> ParseError {
+    ParseError::new(
+        "E064".to_string(),
+        format!("cannot unpack named argument `{}`", name.value),
+        ellipsis,
+    )
+    .error("try removing this", ellipsis.position, 3)
+    .highlight(name.span.position, name.value.len())
+    .note("only positional arguments can be unpacked, as in `foo(...$arguments)`")
+}
+
+pub fn cannot_unpack_attribute_arguments(ellipsis: Span) -> ParseError {
+    ParseError::new(
+        "E065".to_string(),
+        "cannot use argument unpacking in attribute arguments",
+        ellipsis,
+    )
+    .error("try removing this", ellipsis.position, 3)
+    .note("attribute arguments must be constant expressions")
+}
+
+pub fn forbidden_type_used_in_constant(constant: &SimpleIdentifier, ty: &Type) -> ParseError {
+    let type_string = ty.to_string();
+    let type_span = ty.first_span();
+
+    ParseError::new(
+        "E066".to_string(),
+        format!(
+            "constant `{}` cannot have type `{}`",
+            constant.value, type_string
+        ),
+        type_span,
+    )
+    .highlight(constant.span.position, constant.value.len())
+    .error(
+        "try using a different type",
+        type_span.position,
+        type_string.len(),
+    )
+    .note("`void`, `never`, `callable`, and `static` types are not allowed in constants")
 }
 
 impl From<SyntaxError> for ParseError {
     fn from(e: SyntaxError) -> Self {
+        if let SyntaxError::InvalidDocBodyIndentation(whitespace, line, indentation, closing) = &e {
+            return invalid_doc_body_indentation(whitespace, *line, indentation, *closing);
+        }
+
         Self {
             id: "E001".to_string(),
             message: format!("syntax error, {}", e),
             annotations: vec![],
             span: e.span(),
             note: None,
+            expected: None,
         }
     }
 }
 
 impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "[{}] Error: {} on line {} column {}",
@@ -1000,7 +1788,7 @@ impl Display for ParseError {
 }
 
 impl Display for ParseErrorStack {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         for error in &self.errors {
             writeln!(f, "{}", error)?;
         }
@@ -1008,3 +1796,290 @@ impl Display for ParseErrorStack {
         Ok(())
     }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseErrorStack {}
+
+pub fn variadic_parameter_not_last(name: &SimpleVariable, ellipsis: Span) -> ParseError {
+    ParseError::new(
+        "E067".to_string(),
+        format!(
+            "variadic parameter `{}` must be the last parameter",
+            name.name
+        ),
+        ellipsis,
+    )
+    .error("try removing this", ellipsis.position, 3)
+    .highlight(name.span.position, name.name.len())
+    .note("a variadic parameter collects all of the remaining arguments")
+}
+
+pub fn variadic_parameter_with_default(name: &SimpleVariable, equals: Span) -> ParseError {
+    ParseError::new(
+        "E068".to_string(),
+        format!(
+            "variadic parameter `{}` cannot have a default value",
+            name.name
+        ),
+        equals,
+    )
+    .error("try removing the default value", equals.position, 1)
+    .highlight(name.span.position, name.name.len())
+    .note("a variadic parameter is an empty array when no argument is left for it")
+}
+
+pub fn unexpected_tag(tag: &Token) -> ParseError {
+    let kind = match tag.kind {
+        TokenKind::CloseTag => "closing",
+        _ => "opening",
+    };
+
+    ParseError::new(
+        "E069".to_string(),
+        format!("unexpected {} tag `{}`", kind, tag.value),
+        tag.span,
+    )
+    .error("try removing this", tag.span.position, tag.value.len())
+    .note("tags can only appear between statements, where a closing tag ends the statement before it like a semicolon")
+}
+
+pub fn feature_not_available(
+    feature: &str,
+    required: PhpVersion,
+    current: PhpVersion,
+    span: Span,
+    length: usize,
+) -> ParseError {
+    ParseError::new(
+        "E070".to_string(),
+        format!("{} are not available in PHP {}", feature, current),
+        span,
+    )
+    .error(
+        format!("{} require PHP {} or later", feature, required),
+        span.position,
+        length,
+    )
+}
+
+// Unwinds a parse that was cancelled, which then fails with
+// `Error::Cancelled` rather than this error.
+pub fn cancelled(span: Span) -> ParseError {
+    ParseError::new("E071".to_string(), "the parse was cancelled", span)
+}
+
+pub fn static_property_in_readonly_class(
+    state: &mut State,
+    class: Option<&SimpleIdentifier>,
+    property: &SimpleVariable,
+    static_span: Span,
+    readonly_span: Span,
+) -> ParseError {
+    let class_name = class
+        .map(|c| state.named(c))
+        .unwrap_or_else(|| "anonymous@class".to_string());
+
+    let error = ParseError::new(
+        "E072".to_string(),
+        format!(
+            "readonly class `{}` cannot declare static property `{}::{}`",
+            class_name, class_name, property.name,
+        ),
+        static_span,
+    )
+    .error("try removing this", static_span.position, "static".len())
+    .highlight(property.span.position, property.name.len())
+    .highlight(readonly_span.position, "readonly".len())
+    .note(
+        "every property of a readonly class is readonly, and readonly properties cannot be static",
+    );
+
+    // If the class is anonymous, we don't have a span to highlight
+    if let Some(class) = class {
+        error.highlight(class.span.position, class.value.len())
+    } else {
+        error
+    }
+}
+
+pub fn untyped_property_in_readonly_class(
+    state: &mut State,
+    class: Option<&SimpleIdentifier>,
+    property: &SimpleVariable,
+    readonly_span: Span,
+) -> ParseError {
+    let class_name = class
+        .map(|c| state.named(c))
+        .unwrap_or_else(|| "anonymous@class".to_string());
+
+    let error = ParseError::new(
+        "E073".to_string(),
+        format!(
+            "readonly class `{}` cannot declare untyped property `{}::{}`",
+            class_name, class_name, property.name,
+        ),
+        property.span,
+    )
+    .error(
+        format!("try adding a type before `{}`", property.name),
+        property.span.position,
+        property.name.len(),
+    )
+    .highlight(readonly_span.position, "readonly".len())
+    .note(
+        "every property of a readonly class is readonly, and readonly properties must have a type",
+    );
+
+    // If the class is anonymous, we don't have a span to highlight
+    if let Some(class) = class {
+        error.highlight(class.span.position, class.value.len())
+    } else {
+        error
+    }
+}
+
+pub fn property_in_enum(
+    state: &mut State,
+    r#enum: &SimpleIdentifier,
+    property: &SimpleVariable,
+) -> ParseError {
+    ParseError::new(
+        "E074",
+        format!(
+            "enum `{}` cannot declare property `{}::{}`",
+            state.named(&r#enum),
+            state.named(&r#enum),
+            property.name,
+        ),
+        property.span,
+    )
+    .error(
+        "try removing this property",
+        property.span.position,
+        property.name.len(),
+    )
+    .highlight(r#enum.span.position, r#enum.value.len())
+    .note("enums cannot have state, try using a constant or a method instead")
+}
+
+pub fn case_outside_enum(
+    state: &mut State,
+    kind: &str,
+    container: Option<&SimpleIdentifier>,
+    case: Span,
+    name: &SimpleIdentifier,
+) -> ParseError {
+    let container_name = container
+        .map(|c| state.named(c))
+        .unwrap_or_else(|| "anonymous@class".to_string());
+
+    let error = ParseError::new("E075", "case declarations are only allowed in enums", case)
+        .error(
+            "try declaring a constant instead",
+            case.position,
+            "case".len(),
+        )
+        .highlight(name.span.position, name.value.len())
+        .note(format!("`{}` is {}, not an enum", container_name, kind));
+
+    // If the class is anonymous, we don't have a span to highlight
+    if let Some(container) = container {
+        error.highlight(container.span.position, container.value.len())
+    } else {
+        error
+    }
+}
+
+pub fn enum_instantiation(name: &SimpleIdentifier, r#enum: &SimpleIdentifier) -> ParseError {
+    ParseError::new(
+        "E076",
+        format!("cannot instantiate enum `{}`", name),
+        name.span,
+    )
+    .error("this is an enum", name.span.position, name.value.len())
+    .highlight(r#enum.span.position, r#enum.value.len())
+    .note("enums cannot be instantiated, try using one of their cases instead")
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::ParseErrorAnnotationType;
+    use crate::Error;
+
+    const CONFUSABLES: &[(char, &str)] = &[
+        ('\u{00A0}', "a non-breaking space (U+00A0)"),
+        ('\u{200B}', "a zero width space (U+200B)"),
+        ('\u{2013}', "an en dash (U+2013)"),
+        ('\u{2014}', "an em dash (U+2014)"),
+        ('\u{2212}', "a minus sign (U+2212)"),
+        ('\u{2018}', "a left single quotation mark (U+2018)"),
+        ('\u{2019}', "a right single quotation mark (U+2019)"),
+        ('\u{201C}', "a left double quotation mark (U+201C)"),
+        ('\u{201D}', "a right double quotation mark (U+201D)"),
+    ];
+
+    // The positions of the hints of the error the code fails with, and
+    // whether they all name the character.
+    fn hints<B: ?Sized + AsRef<[u8]>>(code: &B, name: &str) -> (Vec<usize>, bool) {
+        let Err(Error::Parse(stack)) = crate::parse(code) else {
+            panic!("expected a parse error");
+        };
+        let hints = stack
+            .errors
+            .last()
+            .unwrap()
+            .annotations
+            .iter()
+            .filter(|annotation| annotation.r#type == ParseErrorAnnotationType::Hint)
+            .collect::<Vec<_>>();
+
+        let mut positions = hints
+            .iter()
+            .map(|hint| hint.position as usize)
+            .collect::<Vec<_>>();
+        positions.sort();
+
+        (
+            positions,
+            hints
+                .iter()
+                .all(|hint| hint.message.starts_with(&format!("this is {},", name))),
+        )
+    }
+
+    #[test]
+    fn test_confusable_positions() {
+        for (character, name) in CONFUSABLES {
+            let length = character.len_utf8();
+
+            // In place of a space after a keyword, which joins the keyword.
+            let code = format!("<?php\n\necho{}$a;\n", character);
+            assert_eq!(hints(&code, name), (vec![11], true), "{}", code);
+
+            // In place of the quotes around a string, which join its words.
+            let code = format!("<?php\n\n$a = {}hello world{};\n", character, character);
+            assert_eq!(
+                hints(&code, name),
+                (vec![12, 23 + length], true),
+                "{}",
+                code
+            );
+
+            // In place of an operator.
+            let code = format!("<?php\n\n$a = $b {} $c;\n", character);
+            assert_eq!(hints(&code, name), (vec![15], true), "{}", code);
+
+            // After a byte that is not valid UTF-8, in the same word.
+            let mut code = b"<?php\n\necho\xFF".to_vec();
+            code.extend(format!("{}$a;\n", character).bytes());
+            assert_eq!(hints(&code, name), (vec![12], true), "{}", character);
+        }
+    }
+}
@@ -1,7 +1,14 @@
+use alloc::boxed::Box;
+
+use crate::downcast::downcast;
+use crate::expected_token;
 use crate::expected_token_err;
 use crate::lexer::token::DocStringKind;
+use crate::lexer::token::Span;
 use crate::lexer::token::TokenKind;
+use crate::node::Node;
 use crate::parser::ast::arguments::ArgumentPlaceholder;
+use crate::parser::ast::arguments::{Argument, PositionalArgument, SingleArgument};
 use crate::parser::ast::identifiers::DynamicIdentifier;
 use crate::parser::ast::identifiers::Identifier;
 use crate::parser::ast::identifiers::SimpleIdentifier;
@@ -14,16 +21,18 @@ use crate::parser::ast::operators::AssignmentOperationExpression;
 use crate::parser::ast::operators::BitwiseOperationExpression;
 use crate::parser::ast::operators::ComparisonOperationExpression;
 use crate::parser::ast::operators::LogicalOperationExpression;
+use crate::parser::ast::writable::is_writable;
 use crate::parser::ast::{
-    ArrayIndexExpression, CoalesceExpression, ConcatExpression, ConstantFetchExpression,
-    Expression, FunctionCallExpression, FunctionClosureCreationExpression, InstanceofExpression,
-    MagicConstantExpression, MethodCallExpression, MethodClosureCreationExpression,
-    NullsafeMethodCallExpression, NullsafePropertyFetchExpression, PropertyFetchExpression,
-    ReferenceExpression, ShortTernaryExpression, StaticMethodCallExpression,
-    StaticMethodClosureCreationExpression, StaticPropertyFetchExpression,
-    StaticVariableMethodCallExpression, StaticVariableMethodClosureCreationExpression,
-    TernaryExpression,
+    ArrayIndexExpression, CastKind, CoalesceExpression, ConcatExpression, ConstantFetchExpression,
+    DynamicConstantFetchExpression, Expression, FunctionCallExpression,
+    FunctionClosureCreationExpression, InstanceofExpression, MagicConstantExpression,
+    MethodCallExpression, MethodClosureCreationExpression, NullsafeMethodCallExpression,
+    NullsafePropertyFetchExpression, PropertyFetchExpression, ReferenceExpression,
+    ShortTernaryExpression, StaticMethodCallExpression, StaticMethodClosureCreationExpression,
+    StaticPropertyFetchExpression, StaticVariableMethodCallExpression,
+    StaticVariableMethodClosureCreationExpression, TernaryExpression,
 };
+use crate::parser::completion::ExpectedAt;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::arrays;
@@ -39,6 +48,7 @@ use crate::parser::internal::strings;
 use crate::parser::internal::utils;
 use crate::parser::internal::variables;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 
 use super::ast::BoolExpression;
 use super::ast::CastExpression;
@@ -61,10 +71,34 @@ use super::ast::UnsetExpression;
 use super::ast::YieldExpression;
 use super::ast::YieldFromExpression;
 
+// The number of expressions that are parsed between two checks of the
+// cancellation token.
+const EXPRESSION_CANCELLATION_INTERVAL: usize = 64;
+
 pub fn create(state: &mut State) -> ParseResult<Expression> {
     for_precedence(state, Precedence::Lowest)
 }
 
+/// Records an error when the initializer of a parameter, a static variable or
+/// a constant creates an object with `new`, which PHP only allows from 8.1.
+pub fn require_new_in_initializer(state: &mut State, initializer: &Expression) {
+    fn find(node: &dyn Node) -> Option<Span> {
+        match downcast::<NewExpression>(node) {
+            Some(new) => Some(new.new),
+            None => node.children().into_iter().find_map(find),
+        }
+    }
+
+    if let Some(new) = find(initializer) {
+        state.require(
+            "`new` expressions in initializers",
+            PhpVersion::Php81,
+            new,
+            3,
+        );
+    }
+}
+
 fn null_coalesce_precedence(state: &mut State) -> ParseResult<Expression> {
     for_precedence(state, Precedence::NullCoalesce)
 }
@@ -74,6 +108,8 @@ fn clone_or_new_precedence(state: &mut State) -> ParseResult<Expression> {
 }
 
 fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expression> {
+    state.check_cancellation(EXPRESSION_CANCELLATION_INTERVAL)?;
+
     let mut left = left(state, &precedence)?;
 
     loop {
@@ -85,9 +121,7 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
             break;
         }
 
-        if is_postfix(kind) {
-            let lpred = Precedence::postfix(kind);
-
+        if let Some(lpred) = Precedence::postfix(kind) {
             if lpred < precedence {
                 break;
             }
@@ -96,10 +130,12 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
             continue;
         }
 
-        if is_infix(kind) {
-            let rpred = Precedence::infix(kind);
-
-            if rpred < precedence {
+        if let Some(rpred) = Precedence::infix(kind) {
+            // An assignment always binds to the variable directly on its left, whatever
+            // operator precedes it: `!$a = foo()` is `!($a = foo())`.
+            if rpred < precedence
+                && !(rpred == Precedence::Assignment && is_assignable(&left, kind))
+            {
                 break;
             }
 
@@ -111,6 +147,26 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                 return Err(error::unexpected_token(vec![], current));
             }
 
+            // PHP 8 rejects `a ? b : c ? d : e`, as it used to be left-associative unlike in
+            // most other languages; only chains of short ternaries, `a ?: b ?: c`, are allowed.
+            match (&left, kind) {
+                (Expression::Ternary(ternary), TokenKind::Question | TokenKind::QuestionColon) => {
+                    state.record(error::unparenthesized_nested_ternary(
+                        ternary.question,
+                        1,
+                        current,
+                    ));
+                }
+                (Expression::ShortTernary(ternary), TokenKind::Question) => {
+                    state.record(error::unparenthesized_nested_ternary(
+                        ternary.question_colon,
+                        2,
+                        current,
+                    ));
+                }
+                _ => {}
+            }
+
             state.stream.next();
 
             let op = state.stream.current();
@@ -123,19 +179,19 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                     if op.kind == TokenKind::Colon {
                         state.stream.next();
 
-                        let r#else = create(state)?;
+                        let r#else = for_precedence(state, Precedence::Ternary)?;
 
                         Expression::Ternary(TernaryExpression {
                             condition: Box::new(left),
                             question: span,
-                            then: Box::new(Expression::Noop),
+                            then: Box::new(Expression::Noop(op.span)),
                             colon: op.span,
                             r#else: Box::new(r#else),
                         })
                     } else {
                         let then = create(state)?;
                         let colon = utils::skip_colon(state)?;
-                        let r#else = create(state)?;
+                        let r#else = for_precedence(state, Precedence::Ternary)?;
 
                         Expression::Ternary(TernaryExpression {
                             condition: Box::new(left),
@@ -147,7 +203,7 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                     }
                 }
                 TokenKind::QuestionColon => {
-                    let r#else = create(state)?;
+                    let r#else = for_precedence(state, Precedence::Ternary)?;
                     Expression::ShortTernary(ShortTernaryExpression {
                         condition: Box::new(left),
                         question_colon: span,
@@ -155,6 +211,10 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                     })
                 }
                 TokenKind::Equals if op.kind == TokenKind::Ampersand => {
+                    if !is_assignable(&left, &TokenKind::Ampersand) {
+                        state.record(error::cannot_assign_to_non_variable(current));
+                    }
+
                     state.stream.next();
 
                     // FIXME: You should only be allowed to assign a referencable variable,
@@ -170,64 +230,26 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                         })),
                     })
                 }
-                TokenKind::Instanceof if op.kind == TokenKind::Self_ => {
-                    state.stream.next();
+                TokenKind::Instanceof => {
+                    let right = class_name_reference(state)?;
 
-                    Expression::Instanceof(InstanceofExpression {
-                        left: Box::new(left),
-                        instanceof: span,
-                        right: Box::new(Expression::Self_),
-                    })
-                }
-                TokenKind::Instanceof if op.kind == TokenKind::Parent => {
-                    state.stream.next();
-
-                    Expression::Instanceof(InstanceofExpression {
-                        left: Box::new(left),
-                        instanceof: span,
-                        right: Box::new(Expression::Parent),
-                    })
-                }
-                TokenKind::Instanceof if op.kind == TokenKind::Static => {
-                    state.stream.next();
-
-                    Expression::Instanceof(InstanceofExpression {
-                        left: Box::new(left),
-                        instanceof: span,
-                        right: Box::new(Expression::Static),
-                    })
-                }
-                TokenKind::Instanceof if op.kind == TokenKind::Enum => {
-                    let enum_span = op.span;
-                    state.stream.next();
-
-                    Expression::Instanceof(InstanceofExpression {
-                        left: Box::new(left),
-                        instanceof: span,
-                        right: Box::new(Expression::Identifier(Identifier::SimpleIdentifier(
-                            SimpleIdentifier {
-                                span: enum_span,
-                                value: "enum".into(),
-                            },
-                        ))),
-                    })
-                }
-                TokenKind::Instanceof if op.kind == TokenKind::From => {
-                    let from_span = op.span;
-                    state.stream.next();
+                    // `$a instanceof foo()` and `$a instanceof Foo::BAR`.
+                    let current = state.stream.current();
+                    if matches!(current.kind, TokenKind::LeftParen | TokenKind::DoubleColon) {
+                        return Err(error::unparenthesized_instanceof_class(current));
+                    }
 
                     Expression::Instanceof(InstanceofExpression {
                         left: Box::new(left),
                         instanceof: span,
-                        right: Box::new(Expression::Identifier(Identifier::SimpleIdentifier(
-                            SimpleIdentifier {
-                                span: from_span,
-                                value: "from".into(),
-                            },
-                        ))),
+                        right: Box::new(right),
                     })
                 }
                 _ => {
+                    if rpred == Precedence::Assignment && !is_assignable(&left, kind) {
+                        state.record(error::cannot_assign_to_non_variable(current));
+                    }
+
                     let left = Box::new(left);
                     let right = Box::new(for_precedence(state, rpred)?);
 
@@ -517,12 +539,13 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                             dot: span,
                             right,
                         }),
-                        TokenKind::Instanceof => Expression::Instanceof(InstanceofExpression {
-                            left,
-                            instanceof: span,
-                            right,
-                        }),
-                        _ => todo!(),
+                        _ => {
+                            return Err(error::internal_error(
+                                state,
+                                format!("unhandled infix operator `{}`", kind),
+                                span,
+                            ))
+                        }
                     }
                 }
             };
@@ -538,7 +561,8 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
 
 fn left(state: &mut State, precedence: &Precedence) -> ParseResult<Expression> {
     if state.stream.is_eof() {
-        return Err(error::unexpected_token(vec![], state.stream.current()));
+        return Err(error::unexpected_token(vec![], state.stream.current())
+            .expecting(ExpectedAt::Expression));
     }
 
     attributes(state, precedence)
@@ -622,7 +646,8 @@ expressions! {
         let eval = state.stream.current().span;
         state.stream.next();
 
-        let argument = Box::new(parameters::single_argument(state, true, true).unwrap()?);
+        let argument = Box::new(parameters::single_argument(state, true)?);
+        forbid_unpacking(&argument)?;
 
         Ok(Expression::Eval(EvalExpression { eval, argument }))
     })
@@ -632,33 +657,36 @@ expressions! {
         let empty = state.stream.current().span;
         state.stream.next();
 
-        let argument = Box::new(parameters::single_argument(state, true, true).unwrap()?);
+        let argument = Box::new(parameters::single_argument(state, true)?);
+        forbid_unpacking(&argument)?;
 
         Ok(Expression::Empty(EmptyExpression { empty, argument }))
     })
 
     #[before(exit), current(TokenKind::Die)]
     die({
+        if let Some(closure) = exit_closure_creation(state)? {
+            return Ok(closure);
+        }
+
         let die = state.stream.current().span;
         state.stream.next();
 
-        let argument = match parameters::single_argument(state, false, true) {
-            Some(arg) => Some(Box::new(arg?)),
-            None => None,
-        };
+        let argument = exit_argument(state)?;
 
         Ok(Expression::Die(DieExpression { die, argument }))
     })
 
     #[before(isset), current(TokenKind::Exit)]
     exit({
+        if let Some(closure) = exit_closure_creation(state)? {
+            return Ok(closure);
+        }
+
         let exit = state.stream.current().span;
         state.stream.next();
 
-        let argument = match parameters::single_argument(state, false, true) {
-            Some(arg) => Some(Box::new(arg?)),
-            None => None,
-        };
+        let argument = exit_argument(state)?;
 
         Ok(Expression::Exit(ExitExpression { exit, argument }))
     })
@@ -667,7 +695,7 @@ expressions! {
     isset({
         let isset = state.stream.current().span;
         state.stream.next();
-        let arguments = parameters::argument_list(state)?;
+        let arguments = parameters::variable_argument_list(state, "isset", is_issettable)?;
 
         Ok(Expression::Isset(IssetExpression { isset, arguments}))
     })
@@ -676,7 +704,7 @@ expressions! {
     unset({
         let unset = state.stream.current().span;
         state.stream.next();
-        let arguments = parameters::argument_list(state)?;
+        let arguments = parameters::variable_argument_list(state, "unset", is_unsettable)?;
 
         Ok(Expression::Unset(UnsetExpression { unset, arguments}))
     })
@@ -686,16 +714,14 @@ expressions! {
         let print = state.stream.current().span;
         state.stream.next();
 
-        let mut value = None;
-        let mut argument = None;
-
-        if let Some(arg) = parameters::single_argument(state, false, true) {
-            argument = Some(Box::new(arg?));
-        } else {
-            value = Some(Box::new(create(state)?));
-        }
+        // `print` is not a function: in `print("a") . "b"`, the parentheses only
+        // group `"a"`, and the whole concatenation is printed.
+        let value = for_precedence(state, Precedence::Print)?;
 
-        Ok(Expression::Print(PrintExpression { print, value, argument }))
+        Ok(Expression::Print(PrintExpression {
+            print,
+            value: Box::new(value),
+        }))
     })
 
     #[before(reserved_identifier_static_call), precedence(Precedence::CallDim), current(
@@ -723,93 +749,118 @@ expressions! {
         arrays::list_expression(state)
     })
 
-    #[before(throw), current(TokenKind::New), peek(TokenKind::Class | TokenKind::Attribute)]
+    #[before(throw), current(TokenKind::New), peek(TokenKind::Class | TokenKind::Attribute | TokenKind::Readonly)]
     anonymous_class({
-        classes::parse_anonymous(state, None)
+        let class = classes::parse_anonymous(state, None)?;
+        dereferenced_new(state, &class)?;
+
+        Ok(class)
     })
 
     #[before(r#yield), current(TokenKind::Throw)]
     throw({
+        let throw = state.stream.current().span;
         state.stream.next();
 
+        state.require("throw expressions", PhpVersion::Php80, throw, 5);
+
         Ok(Expression::Throw(ThrowExpression {
+            throw,
             value: Box::new(for_precedence(state, Precedence::Lowest)?)
         }))
     })
 
     #[before(clone), current(TokenKind::Yield)]
     r#yield({
+        let r#yield = state.stream.current().span;
         state.stream.next();
-        if state.stream.current().kind == TokenKind::SemiColon || state.stream.current().kind == TokenKind::RightParen {
+
+        // A bare `yield` ends where an expression can end.
+        if matches!(
+            state.stream.current().kind,
+            TokenKind::SemiColon
+                | TokenKind::RightParen
+                | TokenKind::RightBracket
+                | TokenKind::Comma
+                | TokenKind::Colon
+                | TokenKind::CloseTag
+        ) {
             Ok(Expression::Yield(YieldExpression {
+                r#yield,
                 key: None,
                 value: None,
             }))
-        } else {
-            let mut from = false;
+        } else if state.stream.current().kind == TokenKind::From {
+            let from = state.stream.current().span;
+            state.stream.next();
 
-            if state.stream.current().kind == TokenKind::From {
-                state.stream.next();
-                from = true;
+            let value = Box::new(for_precedence(state, Precedence::YieldFrom)?);
+
+            // `yield from` delegates to another generator, so it can never
+            // carry a key. A `=>` after it may still belong to an array item
+            // or a match arm around it, so it is only reported once nothing
+            // takes it.
+            let current = state.stream.current();
+            if current.kind == TokenKind::DoubleArrow {
+                state.yield_from_arrow = Some(current.span);
             }
 
+            Ok(Expression::YieldFrom(YieldFromExpression { r#yield, from, value }))
+        } else {
             let mut key = None;
-            let mut value = Box::new(for_precedence(
-                state,
-                if from {
-                    Precedence::YieldFrom
-                } else {
-                    Precedence::Yield
-                },
-            )?);
+            let mut value = Box::new(for_precedence(state, Precedence::Yield)?);
 
-            if state.stream.current().kind == TokenKind::DoubleArrow && !from {
+            if state.stream.current().kind == TokenKind::DoubleArrow {
                 state.stream.next();
-                key = Some(value.clone());
+                key = Some(value);
                 value = Box::new(for_precedence(state, Precedence::Yield)?);
             }
 
-            if from {
-                Ok(Expression::YieldFrom(YieldFromExpression { value }))
-            } else {
-                Ok(Expression::Yield(YieldExpression {
-                    key,
-                    value: Some(value),
-                }))
-            }
+            Ok(Expression::Yield(YieldExpression {
+                r#yield,
+                key,
+                value: Some(value),
+            }))
         }
     })
 
     #[before(r#true), current(TokenKind::Clone)]
     clone({
+        let clone = state.stream.current().span;
         state.stream.next();
 
-        let target = for_precedence(state, Precedence::CloneOrNew)?;
+        // `clone $a->b()` clones the result of the call, so the operand takes
+        // in property fetches, calls and array accesses.
+        let target = for_precedence(state, Precedence::CallDim)?;
 
         Ok(Expression::Clone(CloneExpression {
+            clone,
             target: Box::new(target),
         }))
     })
 
     #[before(r#false), current(TokenKind::True)]
     r#true({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        Ok(Expression::Bool(BoolExpression { value: true }))
+        Ok(Expression::Bool(BoolExpression { span, value: true }))
     })
 
     #[before(null), current(TokenKind::False)]
     r#false({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        Ok(Expression::Bool(BoolExpression { value: false }))
+        Ok(Expression::Bool(BoolExpression { span, value: false }))
     })
 
     #[before(literal_integer), current(TokenKind::Null)]
     null({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        Ok(Expression::Null)
+        Ok(Expression::Null(span))
     })
 
     #[before(literal_float), current(TokenKind::LiteralInteger)]
@@ -819,6 +870,10 @@ expressions! {
         if let TokenKind::LiteralInteger = &current.kind {
             state.stream.next();
 
+            if matches!(current.value.get(..2), Some(b"0o" | b"0O")) {
+                state.require("explicit octal integer literals", PhpVersion::Php81, current.span, 2);
+            }
+
             Ok(Expression::Literal(Literal::Integer(
                 LiteralInteger {
                     span: current.span,
@@ -826,7 +881,7 @@ expressions! {
                 }
             )))
         } else {
-            unreachable!("{}:{}", file!(), line!());
+            Err(error::internal_error(state, "expected a literal", current.span))
         }
     })
 
@@ -844,7 +899,7 @@ expressions! {
                 })
             ))
         } else {
-            unreachable!("{}:{}", file!(), line!());
+            Err(error::internal_error(state, "expected a literal", current.span))
         }
     })
 
@@ -858,11 +913,12 @@ expressions! {
             Ok(Expression::Literal(
                 Literal::String(LiteralString {
                     span: current.span,
+                    end: current.end,
                     value: current.value.clone()
                 })
             ))
         } else {
-            unreachable!("{}:{}", file!(), line!());
+            Err(error::internal_error(state, "expected a literal", current.span))
         }
     })
 
@@ -893,23 +949,26 @@ expressions! {
 
     #[before(self_identifier), current(TokenKind::Static)]
     static_postfix({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        postfix(state, Expression::Static, &TokenKind::DoubleColon)
+        postfix(state, Expression::Static(span), &TokenKind::DoubleColon)
     })
 
     #[before(parent_identifier), current(TokenKind::Self_)]
     self_identifier({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        Ok(Expression::Self_)
+        Ok(Expression::Self_(span))
     })
 
     #[before(left_parenthesis), current(TokenKind::Parent)]
     parent_identifier({
+        let span = state.stream.current().span;
         state.stream.next();
 
-        Ok(Expression::Parent)
+        Ok(Expression::Parent(span))
     })
 
     #[before(r#match), current(TokenKind::LeftParen)]
@@ -945,54 +1004,35 @@ expressions! {
 
         state.stream.next();
 
-        if state.stream.current().kind == TokenKind::Class || state.stream.current().kind == TokenKind::Attribute {
-            return classes::parse_anonymous(state, Some(new));
-        };
-
-        let target = match state.stream.current().kind {
-            TokenKind::Self_ => {
-                state.stream.next();
+        if matches!(state.stream.current().kind, TokenKind::Class | TokenKind::Attribute | TokenKind::Readonly) {
+            let class = classes::parse_anonymous(state, Some(new))?;
+            dereferenced_new(state, &class)?;
 
-                Expression::Self_
-            }
-            TokenKind::Static => {
-                state.stream.next();
-
-                Expression::Static
-            }
-            TokenKind::Parent => {
-                state.stream.next();
-
-                Expression::Parent
-            }
-            TokenKind::Enum => {
-                let span = state.stream.current().span;
-
-                state.stream.next();
+            return Ok(class);
+        };
 
-                Expression::Identifier(Identifier::SimpleIdentifier(SimpleIdentifier { span, value: "enum".into() }))
-            }
-            TokenKind::From => {
-                let span = state.stream.current().span;
+        let target = class_name_reference(state)?;
 
-                state.stream.next();
+        if let Expression::Identifier(Identifier::SimpleIdentifier(name)) = &target {
+            let qualified = state.qualified(name);
 
-                Expression::Identifier(Identifier::SimpleIdentifier(SimpleIdentifier { span, value: "from".into() }))
-            }
-            _ => clone_or_new_precedence(state)?,
-        };
+            state.instantiations.push((qualified, name.clone()));
+        }
 
         let arguments = if state.stream.current().kind == TokenKind::LeftParen {
-            Some(parameters::argument_list(state)?)
+            Some(parameters::argument_list(state).map_err(|error| error.called_on(&target))?)
         } else {
             None
         };
 
-        Ok(Expression::New(NewExpression {
+        let expression = Expression::New(NewExpression {
             target: Box::new(target),
             new,
             arguments,
-        }))
+        });
+        dereferenced_new(state, &expression)?;
+
+        Ok(expression)
     })
 
     #[before(file_magic_constant), current(TokenKind::DirConstant)]
@@ -1081,7 +1121,7 @@ expressions! {
             TokenKind::IncludeOnce => Expression::IncludeOnce(IncludeOnceExpression { include_once: span, path }),
             TokenKind::Require => Expression::Require(RequireExpression { require: span, path }),
             TokenKind::RequireOnce => Expression::RequireOnce(RequireOnceExpression { require_once: span, path }),
-            _ => unreachable!()
+            _ => return Err(error::internal_error(state, "expected `include` or `require`", span)),
         })
     })
 
@@ -1095,7 +1135,9 @@ expressions! {
         let current = state.stream.current();
 
         let span = current.span;
-        let kind = current.kind.clone().into();
+        let Ok(kind) = CastKind::try_from(&current.kind) else {
+            return Err(error::internal_error(state, "expected a cast", span));
+        };
 
         state.stream.next();
 
@@ -1113,7 +1155,7 @@ expressions! {
         let current = state.stream.current();
 
         let span = current.span;
-        let op = current.kind.clone();
+        let op = current.kind;
 
         state.stream.next();
 
@@ -1123,7 +1165,7 @@ expressions! {
             TokenKind::Plus => Expression::ArithmeticOperation(ArithmeticOperationExpression::Positive { plus: span, right }),
             TokenKind::Decrement => Expression::ArithmeticOperation(ArithmeticOperationExpression::PreDecrement { decrement: span, right }),
             TokenKind::Increment => Expression::ArithmeticOperation(ArithmeticOperationExpression::PreIncrement { increment: span, right }),
-            _ => unreachable!(),
+            _ => return Err(error::internal_error(state, "expected a numeric prefix operator", span)),
         };
 
         Ok(expr)
@@ -1177,7 +1219,7 @@ expressions! {
 fn unexpected_token(state: &mut State, _: &Precedence) -> ParseResult<Expression> {
     let current = state.stream.current();
 
-    Err(error::unexpected_token(vec![], current))
+    Err(error::unexpected_token(vec![], current).expecting(ExpectedAt::Expression))
 }
 
 fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Expression> {
@@ -1202,6 +1244,8 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                 let start = utils::skip(state, TokenKind::LeftParen)?;
                 let ellipsis = utils::skip(state, TokenKind::Ellipsis)?;
                 let end = utils::skip(state, TokenKind::RightParen)?;
+                let length = end.offset() + 1 - start.offset();
+                state.require("first-class callables", PhpVersion::Php81, start, length);
 
                 let placeholder = ArgumentPlaceholder {
                     comments: state.stream.comments(),
@@ -1215,7 +1259,8 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                     placeholder,
                 })
             } else {
-                let arguments = parameters::argument_list(state)?;
+                let arguments =
+                    parameters::argument_list(state).map_err(|error| error.called_on(&lhs))?;
 
                 Expression::FunctionCall(FunctionCallExpression {
                     target: Box::new(lhs),
@@ -1265,7 +1310,13 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                     }))
                 }
                 _ => {
-                    return expected_token_err!(["`{`", "`$`", "an identifier"], state);
+                    return Err(
+                        expected_token!(["`{`", "`$`", "an identifier"], state).expecting(
+                            ExpectedAt::StaticMemberName {
+                                class: Box::new(lhs),
+                            },
+                        ),
+                    );
                 }
             };
 
@@ -1278,6 +1329,8 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                     let start = utils::skip(state, TokenKind::LeftParen)?;
                     let ellipsis = utils::skip(state, TokenKind::Ellipsis)?;
                     let end = utils::skip(state, TokenKind::RightParen)?;
+                    let length = end.offset() + 1 - start.offset();
+                    state.require("first-class callables", PhpVersion::Php81, start, length);
 
                     let placeholder = ArgumentPlaceholder {
                         comments: state.stream.comments(),
@@ -1307,7 +1360,13 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                                 },
                             )
                         }
-                        _ => unreachable!(),
+                        _ => {
+                            return Err(error::internal_error(
+                                state,
+                                "unexpected static member",
+                                span,
+                            ))
+                        }
                     }
                 } else {
                     let arguments = parameters::argument_list(state)?;
@@ -1329,11 +1388,38 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                                 arguments,
                             },
                         ),
-                        _ => unreachable!(),
+                        _ => {
+                            return Err(error::internal_error(
+                                state,
+                                "unexpected static member",
+                                span,
+                            ))
+                        }
                     }
                 }
             } else {
                 match property {
+                    Expression::Identifier(Identifier::DynamicIdentifier(DynamicIdentifier {
+                        start,
+                        expr,
+                        end,
+                    })) => {
+                        let length = end.offset() + 1 - start.offset();
+                        state.require(
+                            "dynamic class constant fetches",
+                            PhpVersion::Php83,
+                            start,
+                            length,
+                        );
+
+                        Expression::DynamicConstantFetch(DynamicConstantFetchExpression {
+                            target: lhs,
+                            double_colon: span,
+                            start,
+                            expr,
+                            end,
+                        })
+                    }
                     Expression::Identifier(identifier) => {
                         Expression::ConstantFetch(ConstantFetchExpression {
                             target: lhs,
@@ -1348,7 +1434,13 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                             property: variable,
                         })
                     }
-                    _ => unreachable!(),
+                    _ => {
+                        return Err(error::internal_error(
+                            state,
+                            "unexpected static member",
+                            span,
+                        ))
+                    }
                 }
             }
         }
@@ -1356,33 +1448,15 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
             let span = state.stream.current().span;
             state.stream.next();
 
-            let property = match state.stream.current().kind {
-                TokenKind::Variable | TokenKind::Dollar | TokenKind::DollarLeftBrace => {
-                    Expression::Variable(variables::dynamic_variable(state)?)
-                }
-                _ if identifiers::is_identifier_maybe_reserved(&state.stream.current().kind) => {
-                    Expression::Identifier(Identifier::SimpleIdentifier(
-                        identifiers::identifier_maybe_reserved(state)?,
-                    ))
-                }
-                TokenKind::LeftBrace => {
-                    let start = state.stream.current().span;
-                    state.stream.next();
-
-                    let name = create(state)?;
-
-                    let end = utils::skip_right_brace(state)?;
+            if op == &TokenKind::QuestionArrow {
+                state.require("nullsafe operators", PhpVersion::Php80, span, 3);
+            }
 
-                    Expression::Identifier(Identifier::DynamicIdentifier(DynamicIdentifier {
-                        start,
-                        expr: Box::new(name),
-                        end,
-                    }))
-                }
-                _ => {
-                    return expected_token_err!(["`{`", "`$`", "an identifier"], state);
-                }
-            };
+            let property = property_name(state).map_err(|error| {
+                error.expecting(ExpectedAt::MemberName {
+                    receiver: Box::new(lhs.clone()),
+                })
+            })?;
 
             if state.stream.current().kind == TokenKind::LeftParen {
                 if op == &TokenKind::QuestionArrow {
@@ -1402,6 +1476,8 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                         let start = utils::skip(state, TokenKind::LeftParen)?;
                         let ellipsis = utils::skip(state, TokenKind::Ellipsis)?;
                         let end = utils::skip(state, TokenKind::RightParen)?;
+                        let length = end.offset() + 1 - start.offset();
+                        state.require("first-class callables", PhpVersion::Php81, start, length);
 
                         let placeholder = ArgumentPlaceholder {
                             comments: state.stream.comments(),
@@ -1459,71 +1535,293 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                 decrement: span,
             })
         }
-        _ => todo!("postfix: {:?}", op),
+        _ => {
+            return Err(error::internal_error(
+                state,
+                format!("unhandled postfix operator `{}`", op),
+                state.stream.current().span,
+            ))
+        }
     })
 }
 
-fn is_infix(t: &TokenKind) -> bool {
-    matches!(
-        t,
-        TokenKind::Pow
-            | TokenKind::RightShiftEquals
-            | TokenKind::LeftShiftEquals
-            | TokenKind::CaretEquals
-            | TokenKind::AmpersandEquals
-            | TokenKind::PipeEquals
-            | TokenKind::PercentEquals
-            | TokenKind::PowEquals
-            | TokenKind::LogicalAnd
-            | TokenKind::LogicalOr
-            | TokenKind::LogicalXor
-            | TokenKind::Spaceship
-            | TokenKind::LeftShift
-            | TokenKind::RightShift
-            | TokenKind::Ampersand
-            | TokenKind::Pipe
-            | TokenKind::Caret
-            | TokenKind::Percent
-            | TokenKind::Instanceof
-            | TokenKind::Asterisk
-            | TokenKind::Slash
-            | TokenKind::Plus
-            | TokenKind::Minus
-            | TokenKind::Dot
-            | TokenKind::LessThan
-            | TokenKind::GreaterThan
-            | TokenKind::LessThanEquals
-            | TokenKind::GreaterThanEquals
-            | TokenKind::DoubleEquals
-            | TokenKind::TripleEquals
-            | TokenKind::BangEquals
-            | TokenKind::BangDoubleEquals
-            | TokenKind::AngledLeftRight
-            | TokenKind::Question
-            | TokenKind::QuestionColon
-            | TokenKind::BooleanAnd
-            | TokenKind::BooleanOr
-            | TokenKind::Equals
-            | TokenKind::PlusEquals
-            | TokenKind::MinusEquals
-            | TokenKind::DotEquals
-            | TokenKind::DoubleQuestionEquals
-            | TokenKind::AsteriskEquals
-            | TokenKind::SlashEquals
-    )
+// The name following `->` or `?->`.
+fn property_name(state: &mut State) -> ParseResult<Expression> {
+    Ok(match state.stream.current().kind {
+        TokenKind::Variable | TokenKind::Dollar | TokenKind::DollarLeftBrace => {
+            Expression::Variable(variables::dynamic_variable(state)?)
+        }
+        _ if identifiers::is_identifier_maybe_reserved(&state.stream.current().kind) => {
+            Expression::Identifier(Identifier::SimpleIdentifier(
+                identifiers::identifier_maybe_reserved(state)?,
+            ))
+        }
+        TokenKind::LeftBrace => {
+            let start = state.stream.current().span;
+            state.stream.next();
+
+            let name = create(state)?;
+
+            let end = utils::skip_right_brace(state)?;
+
+            Expression::Identifier(Identifier::DynamicIdentifier(DynamicIdentifier {
+                start,
+                expr: Box::new(name),
+                end,
+            }))
+        }
+        _ => {
+            return expected_token_err!(["`{`", "`$`", "an identifier"], state);
+        }
+    })
 }
 
-#[inline(always)]
-fn is_postfix(t: &TokenKind) -> bool {
-    matches!(
-        t,
-        TokenKind::Increment
-            | TokenKind::Decrement
-            | TokenKind::LeftParen
-            | TokenKind::LeftBracket
-            | TokenKind::Arrow
+// The class after `new` or `instanceof`: a name, a variable, or a
+// parenthesized expression, as in `new ($factory->class())`.
+fn class_name_reference(state: &mut State) -> ParseResult<Expression> {
+    let target = match state.stream.current().kind {
+        TokenKind::Self_ => {
+            let span = state.stream.current().span;
+            state.stream.next();
+
+            Expression::Self_(span)
+        }
+        TokenKind::Static => {
+            let span = state.stream.current().span;
+            state.stream.next();
+
+            Expression::Static(span)
+        }
+        TokenKind::Parent => {
+            let span = state.stream.current().span;
+            state.stream.next();
+
+            Expression::Parent(span)
+        }
+        TokenKind::Enum => {
+            let span = state.stream.current().span;
+
+            state.stream.next();
+
+            Expression::Identifier(Identifier::SimpleIdentifier(SimpleIdentifier {
+                span,
+                value: "enum".into(),
+            }))
+        }
+        TokenKind::From => {
+            let span = state.stream.current().span;
+
+            state.stream.next();
+
+            Expression::Identifier(Identifier::SimpleIdentifier(SimpleIdentifier {
+                span,
+                value: "from".into(),
+            }))
+        }
+        _ => clone_or_new_precedence(state)?,
+    };
+
+    new_target(state, target)
+}
+
+// In `new $a->b($c)`, the arguments belong to `new`: the class name may only
+// be followed by property fetches and array accesses, never by a call. A
+// name, as in `new Foo->bar`, may only be followed by a static property.
+fn new_target(state: &mut State, mut target: Expression) -> ParseResult<Expression> {
+    loop {
+        let current = state.stream.current();
+        let is_name = matches!(
+            target,
+            Expression::Identifier(_)
+                | Expression::Self_(_)
+                | Expression::Static(_)
+                | Expression::Parent(_)
+                | Expression::Parenthesized(_)
+        );
+
+        target = match current.kind {
+            TokenKind::LeftBracket | TokenKind::Arrow | TokenKind::QuestionArrow if is_name => {
+                return Ok(target)
+            }
+            TokenKind::LeftBracket => postfix(state, target, &TokenKind::LeftBracket)?,
+            TokenKind::Arrow => {
+                state.stream.next();
+
+                Expression::PropertyFetch(PropertyFetchExpression {
+                    target: Box::new(target),
+                    arrow: current.span,
+                    property: Box::new(property_name(state)?),
+                })
+            }
+            TokenKind::QuestionArrow => {
+                state.stream.next();
+                state.require("nullsafe operators", PhpVersion::Php80, current.span, 3);
+
+                Expression::NullsafePropertyFetch(NullsafePropertyFetchExpression {
+                    target: Box::new(target),
+                    question_arrow: current.span,
+                    property: Box::new(property_name(state)?),
+                })
+            }
+            TokenKind::DoubleColon
+                if matches!(
+                    state.stream.peek().kind,
+                    TokenKind::Variable | TokenKind::Dollar | TokenKind::DollarLeftBrace
+                ) =>
+            {
+                state.stream.next();
+
+                Expression::StaticPropertyFetch(StaticPropertyFetchExpression {
+                    target: Box::new(target),
+                    double_colon: current.span,
+                    property: variables::dynamic_variable(state)?,
+                })
+            }
+            _ => return Ok(target),
+        };
+    }
+}
+
+// Since PHP 8.4, `new` with an argument list, or of an anonymous class, can
+// be followed by a member access, an array access or a call without being
+// wrapped in parentheses, as in `new Foo()->bar()`, which is
+// `(new Foo())->bar()`.
+fn dereferenced_new(state: &mut State, expression: &Expression) -> ParseResult<()> {
+    let current = state.stream.current();
+    if !matches!(
+        current.kind,
+        TokenKind::Arrow
             | TokenKind::QuestionArrow
             | TokenKind::DoubleColon
-            | TokenKind::DoubleQuestion
-    )
+            | TokenKind::LeftBracket
+            | TokenKind::LeftParen
+    ) {
+        return Ok(());
+    }
+
+    let new = match expression {
+        Expression::New(NewExpression {
+            new,
+            target,
+            arguments,
+        }) if arguments.is_some() || matches!(**target, Expression::AnonymousClass(_)) => *new,
+        Expression::New(NewExpression { new, .. }) => {
+            return Err(error::dereferenced_new_without_arguments(*new, current))
+        }
+        _ => return Ok(()),
+    };
+
+    state.require(
+        "dereferenced `new` expressions without parentheses",
+        PhpVersion::Php84,
+        new,
+        current.span.offset() + current.value.len() - new.offset(),
+    );
+
+    Ok(())
+}
+
+// Besides what can be written to, `list()` and `[]` can be destructured by a
+// plain `=`.
+fn is_assignable(expression: &Expression, operator: &TokenKind) -> bool {
+    match expression {
+        Expression::List(_) | Expression::ShortArray(_) => operator == &TokenKind::Equals,
+        expression => is_writable(expression),
+    }
+}
+
+// `isset($a?->b)` is allowed, but `isset($a[])` is not.
+fn is_issettable(expression: &Expression) -> bool {
+    match expression {
+        Expression::NullsafePropertyFetch(_) => true,
+        Expression::ArrayIndex(ArrayIndexExpression { index: None, .. }) => false,
+        expression => is_writable(expression),
+    }
+}
+
+// `unset($a[])` is not allowed.
+fn is_unsettable(expression: &Expression) -> bool {
+    match expression {
+        Expression::ArrayIndex(ArrayIndexExpression { index: None, .. }) => false,
+        expression => is_writable(expression),
+    }
+}
+
+// Since PHP 8.4, `exit` and `die` are functions, which `exit(...)` and
+// `die(...)` create a closure of, as they would of any other function.
+fn exit_closure_creation(state: &mut State) -> ParseResult<Option<Expression>> {
+    if state.stream.lookahead(0).kind != TokenKind::LeftParen
+        || state.stream.lookahead(1).kind != TokenKind::Ellipsis
+        || state.stream.lookahead(2).kind != TokenKind::RightParen
+    {
+        return Ok(None);
+    }
+
+    let keyword = state.stream.current();
+    let name = SimpleIdentifier {
+        span: keyword.span,
+        value: keyword.value.clone(),
+    };
+    state.stream.next();
+
+    let left_parenthesis = utils::skip(state, TokenKind::LeftParen)?;
+    let ellipsis = utils::skip(state, TokenKind::Ellipsis)?;
+    let right_parenthesis = utils::skip(state, TokenKind::RightParen)?;
+    let length = right_parenthesis.offset() + 1 - name.span.offset();
+    state.require(
+        "first-class callables of `exit` and `die`",
+        PhpVersion::Php84,
+        name.span,
+        length,
+    );
+
+    Ok(Some(Expression::FunctionClosureCreation(
+        FunctionClosureCreationExpression {
+            target: Box::new(Expression::Identifier(Identifier::SimpleIdentifier(name))),
+            placeholder: ArgumentPlaceholder {
+                comments: state.stream.comments(),
+                left_parenthesis,
+                ellipsis,
+                right_parenthesis,
+            },
+        },
+    )))
+}
+
+// The status of `exit` and `die`, which may be passed by name or unpacked
+// since they are functions, in PHP 8.4.
+fn exit_argument(state: &mut State) -> ParseResult<Option<Box<SingleArgument>>> {
+    let argument = parameters::optional_single_argument(state, false)?;
+
+    match argument.as_ref().map(|argument| &argument.argument) {
+        Some(Argument::Named(argument)) => state.require(
+            "named arguments to `exit` and `die`",
+            PhpVersion::Php84,
+            argument.name.span,
+            argument.name.value.len(),
+        ),
+        Some(Argument::Positional(PositionalArgument {
+            ellipsis: Some(ellipsis),
+            ..
+        })) => state.require(
+            "unpacked arguments to `exit` and `die`",
+            PhpVersion::Php84,
+            *ellipsis,
+            3,
+        ),
+        _ => {}
+    }
+
+    Ok(argument.map(Box::new))
+}
+
+// `empty()` and `eval()` take a single expression, which cannot be unpacked.
+fn forbid_unpacking(argument: &SingleArgument) -> ParseResult<()> {
+    match &argument.argument {
+        Argument::Positional(PositionalArgument {
+            ellipsis: Some(ellipsis),
+            ..
+        }) => Err(error::illegal_spread_operator_usage(*ellipsis)),
+        _ => Ok(()),
+    }
 }
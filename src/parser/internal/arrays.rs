@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::Expression;
 use crate::parser::ast::ListEntry;
@@ -71,7 +73,7 @@ pub fn list_expression(state: &mut State) -> ParseResult<Expression> {
                     let mut key = expressions::create(state)?;
                     current = state.stream.current();
 
-                    std::mem::swap(&mut key, &mut value);
+                    core::mem::swap(&mut key, &mut value);
 
                     items.push(ListEntry::KeyValue {
                         key,
@@ -193,7 +195,7 @@ fn array_pair(state: &mut State) -> ParseResult<ArrayItem> {
 
         let mut key = expressions::create(state)?;
 
-        std::mem::swap(&mut key, &mut value);
+        core::mem::swap(&mut key, &mut value);
 
         return match ampersand {
             Some(ampersand) => Ok(ArrayItem::ReferencedKeyValue {
@@ -1,11 +1,16 @@
 use crate::lexer::token::TokenKind;
+use crate::parser::ast::arguments::Argument;
+use crate::parser::ast::arguments::PositionalArgument;
 use crate::parser::ast::attributes::Attribute;
 use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::completion::ExpectedAt;
+use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::identifiers;
 use crate::parser::internal::parameters;
 use crate::parser::internal::utils;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 
 pub fn gather_attributes(state: &mut State) -> ParseResult<bool> {
     if state.stream.current().kind != TokenKind::Attribute {
@@ -15,17 +20,33 @@ pub fn gather_attributes(state: &mut State) -> ParseResult<bool> {
     let start = state.stream.current().span;
     let mut members = vec![];
 
+    state.require("attributes", PhpVersion::Php80, start, 2);
+
     state.stream.next();
 
     loop {
         let start = state.stream.current().span;
-        let name = identifiers::full_type_name_including_self(state)?;
+        let name = identifiers::full_type_name_including_self(state)
+            .map_err(|error| error.expecting(ExpectedAt::AttributeName))?;
         let arguments = if state.stream.current().kind == TokenKind::LeftParen {
-            Some(parameters::argument_list(state)?)
+            let arguments = parameters::argument_list(state)?;
+            for argument in &arguments.arguments {
+                if let Argument::Positional(PositionalArgument {
+                    ellipsis: Some(ellipsis),
+                    ..
+                }) = argument
+                {
+                    state.record(error::cannot_unpack_attribute_arguments(*ellipsis));
+                }
+            }
+
+            Some(arguments)
         } else {
             None
         };
-        let end = state.stream.current().span;
+        let end = arguments
+            .as_ref()
+            .map_or(name.span, |arguments| arguments.right_parenthesis);
 
         members.push(Attribute {
             start,
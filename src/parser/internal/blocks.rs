@@ -1,9 +1,12 @@
-use crate::lexer::token::OpenTagKind;
+use alloc::vec::Vec;
+
 use crate::lexer::token::TokenKind;
 use crate::parser;
 use crate::parser::ast::BlockStatement;
+use crate::parser::ast::Spanned;
 use crate::parser::ast::Statement;
 use crate::parser::error::ParseResult;
+use crate::parser::internal::identifiers;
 use crate::parser::internal::utils;
 use crate::parser::state::State;
 
@@ -27,17 +30,13 @@ pub fn multiple_statements_until(
 
     let mut current = state.stream.current();
     while &current.kind != until {
-        if let TokenKind::OpenTag(OpenTagKind::Full) = current.kind {
-            state.stream.next();
-
-            current = state.stream.current();
-            continue;
-        }
-
+        comments(state, &mut statements);
         statements.push(parser::statement(state)?);
         current = state.stream.current();
     }
 
+    remaining_comments(state, &mut statements);
+
     Ok(statements)
 }
 
@@ -49,16 +48,60 @@ pub fn multiple_statements_until_any(
 
     let mut current = state.stream.current();
     while !until.contains(&current.kind) {
-        if let TokenKind::OpenTag(OpenTagKind::Full) = current.kind {
-            state.stream.next();
-
-            current = state.stream.current();
-            continue;
-        }
-
+        comments(state, &mut statements);
         statements.push(parser::statement(state)?);
         current = state.stream.current();
     }
 
+    remaining_comments(state, &mut statements);
+
     Ok(statements)
 }
+
+// The comments before a statement become statements of their own, unless the
+// statement keeps them, as declarations do.
+pub fn comments(state: &mut State, statements: &mut Vec<Statement>) {
+    skip_inner_comments(state, statements);
+
+    let peek = state.stream.peek();
+    let keeps_comments = match &state.stream.current().kind {
+        TokenKind::Attribute
+        | TokenKind::Abstract
+        | TokenKind::Final
+        | TokenKind::Class
+        | TokenKind::Interface
+        | TokenKind::Trait
+        | TokenKind::Const
+        | TokenKind::Function
+        | TokenKind::Fn
+        | TokenKind::Goto => true,
+        TokenKind::Readonly => peek.kind != TokenKind::LeftParen,
+        TokenKind::Enum => !matches!(
+            peek.kind,
+            TokenKind::LeftParen | TokenKind::DoubleColon | TokenKind::Colon
+        ),
+        kind => identifiers::is_identifier_maybe_reserved(kind) && peek.kind == TokenKind::Colon,
+    };
+
+    if !keeps_comments {
+        remaining_comments(state, statements);
+    }
+}
+
+// Comments that no statement follows, such as those before the closing brace
+// of a block or at the end of the file.
+pub fn remaining_comments(state: &mut State, statements: &mut Vec<Statement>) {
+    skip_inner_comments(state, statements);
+    statements.extend(state.stream.comments().into_iter().map(Statement::Comment));
+}
+
+// Comments inside the previous statement that none of its nodes kept, such as
+// one in an empty argument list, are left out of the tree rather than placed
+// after the statement.
+fn skip_inner_comments(state: &mut State, statements: &[Statement]) {
+    if let Some(previous) = statements.last() {
+        state
+            .stream
+            .skip_comments_before(previous.span().end.offset());
+    }
+}
@@ -1,3 +1,6 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
 use crate::lexer::token::Span;
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::classes::AnonymousClassBody;
@@ -8,13 +11,21 @@ use crate::parser::ast::classes::ClassExtends;
 use crate::parser::ast::classes::ClassImplements;
 use crate::parser::ast::classes::ClassMember;
 use crate::parser::ast::classes::ClassStatement;
+use crate::parser::ast::functions::AbstractConstructor;
+use crate::parser::ast::functions::AbstractMethod;
+use crate::parser::ast::functions::ConcreteConstructor;
 use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::modifiers::ClassModifier;
+use crate::parser::ast::properties::Property;
+use crate::parser::ast::properties::VariableProperty;
+use crate::parser::ast::variables::SimpleVariable;
 use crate::parser::ast::Statement;
 use crate::parser::ast::{Expression, NewExpression};
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::attributes;
 use crate::parser::internal::constants::classish;
+use crate::parser::internal::enums;
 use crate::parser::internal::functions::method;
 use crate::parser::internal::functions::Method;
 use crate::parser::internal::functions::MethodType;
@@ -25,11 +36,19 @@ use crate::parser::internal::properties;
 use crate::parser::internal::traits;
 use crate::parser::internal::utils;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let attributes = state.get_attributes();
 
     let modifiers = modifiers::class_group(modifiers::collect(state)?)?;
+    for modifier in &modifiers.modifiers {
+        if let ClassModifier::Readonly(span) = modifier {
+            state.require("readonly classes", PhpVersion::Php82, *span, 8);
+        }
+    }
+
     let class = utils::skip(state, TokenKind::Class)?;
     let name = identifiers::type_identifier(state)?;
     let current = state.stream.current();
@@ -72,15 +91,48 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         members: {
             let mut members = Vec::new();
             while state.stream.current().kind != TokenKind::RightBrace {
+                if state.stream.current().kind == TokenKind::Case {
+                    enums::misplaced_case(state, "a class", Some(&name))?;
+                    continue;
+                }
+
                 members.push(member(state, has_abstract, &name)?);
             }
+            // Comments after the last member stay in the body.
+            members.extend(
+                state
+                    .stream
+                    .comments()
+                    .into_iter()
+                    .map(ClassMember::Comment),
+            );
 
             members
         },
         right_brace: utils::skip_right_brace(state)?,
     };
 
+    let readonly = modifiers
+        .modifiers
+        .iter()
+        .find_map(|modifier| match modifier {
+            ClassModifier::Readonly(span) => Some(*span),
+            _ => None,
+        });
+
+    if let Some(readonly) = readonly {
+        let properties = body.members.iter().flat_map(|member| match member {
+            ClassMember::Property(property) => property_names(property),
+            ClassMember::VariableProperty(property) => variable_property_names(property),
+            ClassMember::ConcreteConstructor(constructor) => promoted_property_names(constructor),
+            _ => Vec::new(),
+        });
+
+        readonly_class_properties(state, Some(&name), readonly, properties);
+    }
+
     Ok(Statement::Class(ClassStatement {
+        comments,
         class,
         name,
         modifiers,
@@ -101,6 +153,15 @@ pub fn parse_anonymous(state: &mut State, span: Option<Span>) -> ParseResult<Exp
 
     let attributes = state.get_attributes();
 
+    let readonly = if state.stream.current().kind == TokenKind::Readonly {
+        let readonly = utils::skip(state, TokenKind::Readonly)?;
+        state.require("readonly anonymous classes", PhpVersion::Php83, readonly, 8);
+
+        Some(readonly)
+    } else {
+        None
+    };
+
     let class = utils::skip(state, TokenKind::Class)?;
 
     let arguments = if state.stream.current().kind == TokenKind::LeftParen {
@@ -144,15 +205,41 @@ pub fn parse_anonymous(state: &mut State, span: Option<Span>) -> ParseResult<Exp
         members: {
             let mut members = Vec::new();
             while state.stream.current().kind != TokenKind::RightBrace {
+                if state.stream.current().kind == TokenKind::Case {
+                    enums::misplaced_case(state, "a class", None)?;
+                    continue;
+                }
+
                 members.push(anonymous_member(state)?);
             }
+            members.extend(
+                state
+                    .stream
+                    .comments()
+                    .into_iter()
+                    .map(AnonymousClassMember::Comment),
+            );
             members
         },
         right_brace: utils::skip_right_brace(state)?,
     };
 
+    if let Some(readonly) = readonly {
+        let properties = body.members.iter().flat_map(|member| match member {
+            AnonymousClassMember::Property(property) => property_names(property),
+            AnonymousClassMember::VariableProperty(property) => variable_property_names(property),
+            AnonymousClassMember::ConcreteConstructor(constructor) => {
+                promoted_property_names(constructor)
+            }
+            _ => Vec::new(),
+        });
+
+        readonly_class_properties(state, None, readonly, properties);
+    }
+
     Ok(Expression::New(NewExpression {
         target: Box::new(Expression::AnonymousClass(AnonymousClassExpression {
+            readonly,
             class,
             extends,
             implements,
@@ -164,6 +251,64 @@ pub fn parse_anonymous(state: &mut State, span: Option<Span>) -> ParseResult<Exp
     }))
 }
 
+// A property of a readonly class, with the span of its `static` modifier and
+// whether it has a type.
+type ReadonlyClassProperty<'a> = (&'a SimpleVariable, Option<Span>, bool);
+
+// Every property of a readonly class is readonly, so it needs a type, and it
+// cannot be static.
+fn readonly_class_properties<'a>(
+    state: &mut State,
+    class: Option<&SimpleIdentifier>,
+    readonly: Span,
+    properties: impl Iterator<Item = ReadonlyClassProperty<'a>>,
+) {
+    for (property, r#static, typed) in properties {
+        let error = match r#static {
+            Some(r#static) => {
+                error::static_property_in_readonly_class(state, class, property, r#static, readonly)
+            }
+            None if !typed => {
+                error::untyped_property_in_readonly_class(state, class, property, readonly)
+            }
+            None => continue,
+        };
+
+        state.record(error);
+    }
+}
+
+fn property_names(property: &Property) -> Vec<ReadonlyClassProperty<'_>> {
+    let r#static = property
+        .modifiers
+        .get_static()
+        .map(|modifier| modifier.span());
+
+    property
+        .entries
+        .iter()
+        .map(|entry| (entry.variable(), r#static, property.r#type.is_some()))
+        .collect()
+}
+
+fn variable_property_names(property: &VariableProperty) -> Vec<ReadonlyClassProperty<'_>> {
+    property
+        .entries
+        .iter()
+        .map(|entry| (entry.variable(), None, property.r#type.is_some()))
+        .collect()
+}
+
+fn promoted_property_names(constructor: &ConcreteConstructor) -> Vec<ReadonlyClassProperty<'_>> {
+    constructor
+        .parameters
+        .parameters
+        .iter()
+        .filter(|parameter| !parameter.modifiers.is_empty())
+        .map(|parameter| (&parameter.name, None, parameter.data_type.is_some()))
+        .collect()
+}
+
 fn member(
     state: &mut State,
     has_abstract: bool,
@@ -194,33 +339,31 @@ fn member(
         )?;
 
         return match method {
-            Method::Abstract(method) => {
-                if has_abstract {
-                    Ok(ClassMember::AbstractMethod(method))
-                } else {
+            Method::Abstract(method) => match method.modifiers.get_abstract() {
+                Some(modifier) if !has_abstract => {
                     Err(error::abstract_method_on_a_non_abstract_class(
                         state,
                         name,
                         &method.name,
-                        method.modifiers.get_abstract().unwrap().span(),
+                        modifier.span(),
                         method.semicolon,
                     ))
                 }
-            }
+                _ => Ok(ClassMember::AbstractMethod(method)),
+            },
             Method::Concrete(method) => Ok(ClassMember::ConcreteMethod(method)),
-            Method::AbstractConstructor(ctor) => {
-                if has_abstract {
-                    Ok(ClassMember::AbstractConstructor(ctor))
-                } else {
+            Method::AbstractConstructor(ctor) => match ctor.modifiers.get_abstract() {
+                Some(modifier) if !has_abstract => {
                     Err(error::abstract_method_on_a_non_abstract_class(
                         state,
                         name,
                         &ctor.name,
-                        ctor.modifiers.get_abstract().unwrap().span(),
+                        modifier.span(),
                         ctor.semicolon,
                     ))
                 }
-            }
+                _ => Ok(ClassMember::AbstractConstructor(ctor)),
+            },
             Method::ConcreteConstructor(ctor) => Ok(ClassMember::ConcreteConstructor(ctor)),
         };
     }
@@ -264,7 +407,14 @@ fn anonymous_member(state: &mut State) -> ParseResult<AnonymousClassMember> {
             Method::ConcreteConstructor(ctor) => {
                 return Ok(AnonymousClassMember::ConcreteConstructor(ctor));
             }
-            Method::Abstract(_) | Method::AbstractConstructor(_) => unreachable!(),
+            Method::Abstract(AbstractMethod { function, .. })
+            | Method::AbstractConstructor(AbstractConstructor { function, .. }) => {
+                return Err(error::internal_error(
+                    state,
+                    "abstract method in an anonymous class",
+                    function,
+                ));
+            }
         }
     }
 
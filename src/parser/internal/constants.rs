@@ -1,13 +1,18 @@
+use alloc::string::ToString;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::constant::ClassishConstant;
 use crate::parser::ast::constant::ConstantEntry;
 use crate::parser::ast::constant::ConstantStatement;
 use crate::parser::ast::modifiers::ConstantModifierGroup;
+use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
+use crate::parser::internal::data_type;
 use crate::parser::internal::identifiers;
 use crate::parser::internal::utils;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 
 pub fn parse(state: &mut State) -> ParseResult<ConstantStatement> {
     let comments = state.stream.comments();
@@ -19,6 +24,7 @@ pub fn parse(state: &mut State) -> ParseResult<ConstantStatement> {
         let name = identifiers::constant_identifier(state)?;
         let span = utils::skip(state, TokenKind::Equals)?;
         let value = expressions::create(state)?;
+        expressions::require_new_in_initializer(state, &value);
 
         entries.push(ConstantEntry {
             name,
@@ -52,6 +58,22 @@ pub fn classish(
     let comments = state.stream.comments();
     let start = utils::skip(state, TokenKind::Const)?;
 
+    // `const int FOO = 1;`, where the name, unlike the type, is followed by `=`.
+    let r#type = if matches!(
+        state.stream.peek().kind,
+        TokenKind::Equals | TokenKind::Comma | TokenKind::SemiColon
+    ) {
+        None
+    } else {
+        let r#type = data_type::data_type(state)?;
+        let span = r#type.first_span();
+        let length = r#type.to_string().len();
+
+        state.require("typed class constants", PhpVersion::Php83, span, length);
+
+        Some(r#type)
+    };
+
     let mut entries = vec![];
 
     loop {
@@ -72,6 +94,22 @@ pub fn classish(
         }
     }
 
+    for (index, entry) in entries.iter().enumerate() {
+        if let Some(first) = entries
+            .iter()
+            .take(index)
+            .find(|first| first.name.value == entry.name.value)
+        {
+            state.record(error::duplicate_constant(&first.name, &entry.name));
+        }
+    }
+
+    if let (Some(r#type), Some(entry)) = (&r#type, entries.first()) {
+        if r#type.includes_callable() || r#type.includes_static() || r#type.is_bottom() {
+            state.record(error::forbidden_type_used_in_constant(&entry.name, r#type));
+        }
+    }
+
     let end = utils::skip_semicolon(state)?;
 
     Ok(ClassishConstant {
@@ -79,6 +117,7 @@ pub fn classish(
         attributes,
         modifiers,
         r#const: start,
+        r#type,
         entries,
         semicolon: end,
     })
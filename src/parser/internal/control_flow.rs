@@ -1,5 +1,9 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
 use crate::expected_token_err;
 
+use crate::lexer::token::OpenTagKind;
 use crate::lexer::token::TokenKind;
 use crate::parser;
 use crate::parser::ast::control_flow::IfStatement;
@@ -20,10 +24,13 @@ use crate::parser::error::ParseResult;
 use crate::parser::expressions;
 use crate::parser::internal::blocks;
 use crate::parser::internal::utils;
+use crate::parser::state::Scope;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 
 pub fn match_expression(state: &mut State) -> ParseResult<Expression> {
     let keyword = utils::skip(state, TokenKind::Match)?;
+    state.require("match expressions", PhpVersion::Php80, keyword, 5);
 
     let (left_parenthesis, condition, right_parenthesis) =
         utils::parenthesized(state, &|state: &mut State| {
@@ -35,6 +42,7 @@ pub fn match_expression(state: &mut State) -> ParseResult<Expression> {
     let mut default: Option<Box<DefaultMatchArm>> = None;
     let mut arms = Vec::new();
     while state.stream.current().kind != TokenKind::RightBrace {
+        let comments = state.stream.comments();
         let current = state.stream.current();
         if current.kind == TokenKind::Default {
             if let Some(default_arm) = default {
@@ -56,6 +64,7 @@ pub fn match_expression(state: &mut State) -> ParseResult<Expression> {
             let body = expressions::create(state)?;
 
             default = Some(Box::new(DefaultMatchArm {
+                comments,
                 keyword: current.span,
                 double_arrow: arrow,
                 body,
@@ -81,6 +90,7 @@ pub fn match_expression(state: &mut State) -> ParseResult<Expression> {
             let body = expressions::create(state)?;
 
             arms.push(MatchArm {
+                comments,
                 conditions,
                 arrow,
                 body,
@@ -122,15 +132,20 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
         TokenKind::RightBrace
     };
 
+    state.enter(Scope::Switch);
+
+    skip_case_list_separator(state);
+
     let mut cases = Vec::new();
     while state.stream.current().kind != end_token {
         match state.stream.current().kind {
             TokenKind::Case => {
+                let keyword = state.stream.current().span;
                 state.stream.next();
 
                 let condition = expressions::create(state)?;
 
-                utils::skip_any_of(state, &[TokenKind::Colon, TokenKind::SemiColon])?;
+                let colon = utils::skip_any_of(state, &[TokenKind::Colon, TokenKind::SemiColon])?;
 
                 let mut body = Block::new();
 
@@ -139,18 +154,22 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
                     && state.stream.current().kind != TokenKind::RightBrace
                     && state.stream.current().kind != end_token
                 {
+                    blocks::comments(state, &mut body);
                     body.push(parser::statement(state)?);
                 }
 
                 cases.push(Case {
+                    keyword,
                     condition: Some(condition),
+                    colon,
                     body,
                 });
             }
             TokenKind::Default => {
+                let keyword = state.stream.current().span;
                 state.stream.next();
 
-                utils::skip_any_of(state, &[TokenKind::Colon, TokenKind::SemiColon])?;
+                let colon = utils::skip_any_of(state, &[TokenKind::Colon, TokenKind::SemiColon])?;
 
                 let mut body = Block::new();
 
@@ -158,11 +177,14 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
                     && state.stream.current().kind != TokenKind::Default
                     && state.stream.current().kind != end_token
                 {
+                    blocks::comments(state, &mut body);
                     body.push(parser::statement(state)?);
                 }
 
                 cases.push(Case {
+                    keyword,
                     condition: None,
+                    colon,
                     body,
                 });
             }
@@ -172,12 +194,15 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
         }
     }
 
-    if end_token == TokenKind::EndSwitch {
-        utils::skip(state, TokenKind::EndSwitch)?;
-        utils::skip_ending(state)?;
+    state.exit();
+
+    let (end, ending) = if end_token == TokenKind::EndSwitch {
+        let end = utils::skip(state, TokenKind::EndSwitch)?;
+
+        (end, Some(utils::skip_ending(state)?))
     } else {
-        utils::skip_right_brace(state)?;
-    }
+        (utils::skip_right_brace(state)?, None)
+    };
 
     Ok(Statement::Switch(SwitchStatement {
         switch,
@@ -185,9 +210,37 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
         condition,
         right_parenthesis,
         cases,
+        end,
+        ending,
     }))
 }
 
+// The list of cases may start with a semicolon, or with a closing tag, which
+// stands for one, followed by an opening tag. As in `switch ($a): ?>`, on its
+// own line before `<?php case 1: ?>`, the line break right after a closing tag
+// is not output, so it may come in between.
+fn skip_case_list_separator(state: &mut State) {
+    match state.stream.current().kind {
+        TokenKind::SemiColon => state.stream.next(),
+        TokenKind::CloseTag => {
+            let peek = state.stream.peek();
+            let line_break =
+                peek.kind == TokenKind::InlineHtml && matches!(&peek.value[..], b"\n" | b"\r\n");
+            let tokens = if line_break { 2 } else { 1 };
+
+            if matches!(
+                state.stream.lookahead(tokens - 1).kind,
+                TokenKind::OpenTag(OpenTagKind::Full | OpenTagKind::Short)
+            ) {
+                for _ in 0..=tokens {
+                    state.stream.next();
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
 pub fn if_statement(state: &mut State) -> ParseResult<Statement> {
     let r#if = utils::skip(state, TokenKind::If)?;
 
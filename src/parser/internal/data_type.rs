@@ -1,15 +1,34 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
 use crate::expected_token;
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::data_type::Type;
+use crate::parser::completion::ExpectedAt;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::utils;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 use crate::peek_token;
 
 pub fn data_type(state: &mut State) -> ParseResult<Type> {
     if state.stream.current().kind == TokenKind::Question {
-        return nullable(state);
+        let ty = nullable(state)?;
+
+        // `?A|B` and `?A&B` are reported as a standalone type used in a union
+        // or an intersection.
+        if state.stream.current().kind == TokenKind::Pipe {
+            return union(state, ty, false);
+        }
+
+        if is_intersection_ampersand(state) {
+            return intersection(state, ty, false);
+        }
+
+        return Ok(ty);
     }
 
     // (A|B|..)&C.. or (A&B&..)|C..
@@ -23,57 +42,65 @@ pub fn data_type(state: &mut State) -> ParseResult<Type> {
         return union(state, ty, false);
     }
 
-    if state.stream.current().kind == TokenKind::Ampersand
-        && !matches!(
-            state.stream.peek().kind,
-            TokenKind::Variable | TokenKind::Ellipsis | TokenKind::Ampersand
-        )
-    {
+    if is_intersection_ampersand(state) {
         return intersection(state, ty, false);
     }
 
+    require_standalone(state, &ty);
+
     Ok(ty)
 }
 
 pub fn optional_data_type(state: &mut State) -> ParseResult<Option<Type>> {
-    if state.stream.current().kind == TokenKind::Question {
-        return nullable(state).map(Some);
-    }
-
-    // (A|B|..)&C.. or (A&B&..)|C..
-    if state.stream.current().kind == TokenKind::LeftParen {
-        return dnf(state).map(Some);
+    if matches!(
+        state.stream.current().kind,
+        TokenKind::Question | TokenKind::LeftParen
+    ) {
+        return data_type(state).map(Some);
     }
 
     let ty = optional_simple_data_type(state)?;
 
     match ty {
         Some(ty) => {
+            require_simple(state, &ty);
+
             if state.stream.current().kind == TokenKind::Pipe {
                 return union(state, ty, false).map(Some);
             }
 
-            if state.stream.current().kind == TokenKind::Ampersand
-                && !matches!(
-                    state.stream.peek().kind,
-                    TokenKind::Variable | TokenKind::Ellipsis | TokenKind::Ampersand
-                )
-            {
+            if is_intersection_ampersand(state) {
                 return intersection(state, ty, false).map(Some);
             }
 
+            require_standalone(state, &ty);
+
             Ok(Some(ty))
         }
         None => Ok(None),
     }
 }
 
+// `A&B $x` is an intersection type, while `A &$x` and `A &...$x` are by-reference
+// parameters of type `A`: an ampersand followed by a variable or an ellipsis
+// (ignoring whitespace and comments) is never part of a type.
+fn is_intersection_ampersand(state: &State) -> bool {
+    state.stream.current().kind == TokenKind::Ampersand
+        && !matches!(
+            state.stream.peek().kind,
+            TokenKind::Variable | TokenKind::Ellipsis
+        )
+}
+
 fn dnf(state: &mut State) -> ParseResult<Type> {
     // (A|B|..)&C.. or (A&B&..)|C..
+    let left_parenthesis = state.stream.current().span;
     state.stream.next();
     let ty = simple_data_type(state)?;
     peek_token!([
         TokenKind::Pipe => {
+            state.record(error::union_type_in_intersection_type(left_parenthesis));
+
             let union = union(state, ty, true)?;
 
             utils::skip_right_parenthesis(state)?;
@@ -81,6 +108,8 @@ fn dnf(state: &mut State) -> ParseResult<Type> {
             intersection(state, union, false)
         },
         TokenKind::Ampersand => {
+            state.require("disjunctive normal form types", PhpVersion::Php82, left_parenthesis, 1);
+
             let intersection = intersection(state, ty, true)?;
 
             utils::skip_right_parenthesis(state)?;
@@ -188,7 +217,37 @@ fn optional_simple_data_type(state: &mut State) -> ParseResult<Option<Type>> {
 
 fn simple_data_type(state: &mut State) -> ParseResult<Type> {
     // TODO(azjezz): add a better error message here.
-    optional_simple_data_type(state)?.ok_or_else(|| expected_token!(["a type"], state))
+    let ty = optional_simple_data_type(state)?
+        .ok_or_else(|| expected_token!(["a type"], state).expecting(ExpectedAt::TypeName))?;
+
+    require_simple(state, &ty);
+
+    Ok(ty)
+}
+
+// Records an error when the type was added in a later version of PHP than the
+// one the code targets.
+fn require_simple(state: &mut State, ty: &Type) {
+    let (feature, version, span) = match ty {
+        Type::Mixed(span) => ("`mixed` types", PhpVersion::Php80, span),
+        Type::StaticReference(span) => ("`static` return types", PhpVersion::Php80, span),
+        Type::Never(span) => ("`never` types", PhpVersion::Php81, span),
+        Type::True(span) => ("`true` types", PhpVersion::Php82, span),
+        _ => return,
+    };
+
+    state.require(feature, version, *span, ty.to_string().len());
+}
+
+// `null` and `false` could only be part of a union before PHP 8.2.
+fn require_standalone(state: &mut State, ty: &Type) {
+    let (feature, span) = match ty {
+        Type::Null(span) => ("standalone `null` types", span),
+        Type::False(span) => ("standalone `false` types", span),
+        _ => return,
+    };
+
+    state.require(feature, PhpVersion::Php82, *span, ty.to_string().len());
 }
 
 fn nullable(state: &mut State) -> ParseResult<Type> {
@@ -196,6 +255,13 @@ fn nullable(state: &mut State) -> ParseResult<Type> {
 
     state.stream.next();
 
+    // ?(A&B)|C
+    if state.stream.current().kind == TokenKind::LeftParen {
+        state.record(error::nullable_disjunctive_normal_form_type(current.span));
+
+        return Ok(Type::Nullable(current.span, Box::new(dnf(state)?)));
+    }
+
     let ty = simple_data_type(state)?;
 
     if ty.standalone() {
@@ -217,6 +283,11 @@ fn union(state: &mut State, other: Type, within_dnf: bool) -> ParseResult<Type>
 
     let mut last_pipe = utils::skip(state, TokenKind::Pipe)?;
 
+    // Unions within a disjunctive normal form type are reported as such.
+    if !within_dnf {
+        state.require("union types", PhpVersion::Php80, last_pipe, 1);
+    }
+
     loop {
         let current = state.stream.current();
         let ty = if current.kind == TokenKind::LeftParen {
@@ -237,6 +308,12 @@ fn union(state: &mut State, other: Type, within_dnf: bool) -> ParseResult<Type>
                 state.record(error::nested_disjunctive_normal_form_types(current.span));
             }
 
+            state.require(
+                "disjunctive normal form types",
+                PhpVersion::Php82,
+                current.span,
+                1,
+            );
             state.stream.next();
 
             let other = simple_data_type(state)?;
@@ -263,7 +340,57 @@ fn union(state: &mut State, other: Type, within_dnf: bool) -> ParseResult<Type>
         }
     }
 
-    Ok(Type::Union(types))
+    let union = Type::Union(types);
+    redundant_types(state, &union);
+
+    Ok(union)
+}
+
+// A member of a disjunctive normal form type is redundant when another member
+// is made of some of its types, as in `A|(A&B)`, where `A&B` is already an `A`.
+fn redundant_types(state: &mut State, union: &Type) {
+    let Type::Union(types) = union else {
+        return;
+    };
+
+    if !types.iter().any(|ty| matches!(ty, Type::Intersection(_))) {
+        return;
+    }
+
+    let names = |ty: &Type| -> Vec<String> {
+        match ty {
+            Type::Intersection(types) => types.iter().map(type_name).collect(),
+            _ => vec![type_name(ty)],
+        }
+    };
+
+    for (index, redundant) in types.iter().enumerate() {
+        let redundant_names = names(redundant);
+
+        // Members with the same types are only reported the second time.
+        let other = types.iter().enumerate().find(|(other_index, other)| {
+            let other_names = names(other);
+
+            *other_index != index
+                && other_names
+                    .iter()
+                    .all(|name| redundant_names.contains(name))
+                && (other_names.len() < redundant_names.len() || *other_index < index)
+        });
+
+        if let Some((_, other)) = other {
+            state.record(error::redundant_type_in_union(union, redundant, other));
+        }
+    }
+}
+
+fn type_name(ty: &Type) -> String {
+    let name = ty.to_string().to_ascii_lowercase();
+
+    match name.strip_prefix('\\') {
+        Some(name) => name.to_string(),
+        None => name,
+    }
 }
 
 fn intersection(state: &mut State, other: Type, within_dnf: bool) -> ParseResult<Type> {
@@ -278,6 +405,10 @@ fn intersection(state: &mut State, other: Type, within_dnf: bool) -> ParseResult
 
     let mut last_ampersand = utils::skip(state, TokenKind::Ampersand)?;
 
+    if !within_dnf {
+        state.require("intersection types", PhpVersion::Php81, last_ampersand, 1);
+    }
+
     loop {
         let current = state.stream.current();
         let ty = if current.kind == TokenKind::LeftParen {
@@ -296,6 +427,9 @@ fn intersection(state: &mut State, other: Type, within_dnf: bool) -> ParseResult
                 //        v-- error
                 // F|(A&B&(D|S))
                 state.record(error::nested_disjunctive_normal_form_types(current.span));
+            } else {
+                // F&(A|B)
+                state.record(error::union_type_in_intersection_type(current.span));
             }
 
             state.stream.next();
@@ -320,12 +454,7 @@ fn intersection(state: &mut State, other: Type, within_dnf: bool) -> ParseResult
 
         types.push(ty);
 
-        if state.stream.current().kind == TokenKind::Ampersand
-            && !matches!(
-                state.stream.peek().kind,
-                TokenKind::Variable | TokenKind::Ellipsis | TokenKind::Ampersand
-            )
-        {
+        if is_intersection_ampersand(state) {
             last_ampersand = utils::skip(state, TokenKind::Ampersand)?;
         } else {
             break;
@@ -334,3 +463,55 @@ fn intersection(state: &mut State, other: Type, within_dnf: bool) -> ParseResult
 
     Ok(Type::Intersection(types))
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use crate::Error;
+
+    fn errors(ty: &str) -> Vec<String> {
+        let code = alloc::format!("<?php function foo({} $a): {} {{}}", ty, ty);
+
+        match crate::parse(&code) {
+            Ok(_) => Vec::new(),
+            Err(Error::Parse(stack)) => stack.errors.into_iter().map(|error| error.id).collect(),
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    #[test]
+    fn test_disjunctive_normal_form_types() {
+        for ty in [
+            "(A&B)|null",
+            "(Countable&ArrayAccess)|array",
+            "A|(B&C)|null",
+            "(A&B)|(C&D)",
+            "(A&B)|(A&C)",
+            "\\A|(B&\\C)",
+        ] {
+            assert_eq!(errors(ty), Vec::<String>::new(), "{}", ty);
+        }
+    }
+
+    #[test]
+    fn test_invalid_disjunctive_normal_form_types() {
+        for (ty, error) in [
+            ("(A|B)&C", "E077"),
+            ("A&(B|C)", "E077"),
+            ("A|(B&(C|D))", "E040"),
+            ("(A&(B|C))|D", "E040"),
+            ("?(A&B)|null", "E078"),
+            ("A|(A&B)", "E079"),
+            ("(A&B)|a", "E079"),
+            ("(A&B)|(B&A)", "E079"),
+            ("(A&B)|(A&B&C)", "E079"),
+            ("\\A|(A&B)", "E079"),
+            ("?A|B", "E010"),
+            ("?A&B", "E011"),
+        ] {
+            assert_eq!(errors(ty), [error, error], "{}", ty);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::lexer::token::Span;
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::enums::BackedEnumBody;
@@ -9,6 +11,8 @@ use crate::parser::ast::enums::UnitEnumBody;
 use crate::parser::ast::enums::UnitEnumCase;
 use crate::parser::ast::enums::UnitEnumMember;
 use crate::parser::ast::enums::UnitEnumStatement;
+use crate::parser::ast::functions::AbstractConstructor;
+use crate::parser::ast::functions::AbstractMethod;
 use crate::parser::ast::functions::ConcreteMethod;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::Statement;
@@ -21,15 +25,21 @@ use crate::parser::internal::functions;
 use crate::parser::internal::functions::Method;
 use crate::parser::internal::identifiers;
 use crate::parser::internal::modifiers;
+use crate::parser::internal::properties;
 use crate::parser::internal::utils;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 
 use super::traits;
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let span = utils::skip(state, TokenKind::Enum)?;
+    state.require("enums", PhpVersion::Php81, span, 4);
 
     let name = identifiers::type_identifier(state)?;
+    let qualified = state.qualified(&name);
+    state.enums.push((qualified, name.clone()));
 
     let backed_type: Option<BackedEnumType> = if state.stream.current().kind == TokenKind::Colon {
         let span = utils::skip_colon(state)?;
@@ -38,7 +48,13 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         Some(match &identifier.value[..] {
             b"string" => BackedEnumType::String(span, identifier.span),
             b"int" => BackedEnumType::Int(span, identifier.span),
-            _ => unreachable!(),
+            _ => {
+                return Err(error::internal_error(
+                    state,
+                    "unexpected backed enum type",
+                    identifier.span,
+                ))
+            }
         })
     } else {
         None
@@ -70,6 +86,22 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
                         members.push(member);
                     }
                 }
+                members.extend(
+                    state
+                        .stream
+                        .comments()
+                        .into_iter()
+                        .map(BackedEnumMember::Comment),
+                );
+
+                let cases = members
+                    .iter()
+                    .filter_map(|member| match member {
+                        BackedEnumMember::Case(case) => Some(&case.name),
+                        _ => None,
+                    })
+                    .collect();
+                duplicate_cases(state, &name, cases);
 
                 members
             },
@@ -77,6 +109,7 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         };
 
         Ok(Statement::BackedEnum(BackedEnumStatement {
+            comments,
             r#enum: span,
             name,
             backed_type,
@@ -94,12 +127,30 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
                         members.push(member);
                     }
                 }
+                members.extend(
+                    state
+                        .stream
+                        .comments()
+                        .into_iter()
+                        .map(UnitEnumMember::Comment),
+                );
+
+                let cases = members
+                    .iter()
+                    .filter_map(|member| match member {
+                        UnitEnumMember::Case(case) => Some(&case.name),
+                        _ => None,
+                    })
+                    .collect();
+                duplicate_cases(state, &name, cases);
+
                 members
             },
             right_brace: utils::skip_right_brace(state)?,
         };
 
         Ok(Statement::UnitEnum(UnitEnumStatement {
+            comments,
             r#enum: span,
             name,
             attributes,
@@ -109,6 +160,96 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
     }
 }
 
+/// Records an error for every `new` of an enum declared in the program.
+pub fn instantiations(state: &mut State) {
+    let errors: Vec<_> = state
+        .instantiations
+        .iter()
+        .filter_map(|(qualified, name)| {
+            state
+                .enums
+                .iter()
+                .find(|(r#enum, _)| r#enum == qualified)
+                .map(|(_, r#enum)| error::enum_instantiation(name, r#enum))
+        })
+        .collect();
+
+    for error in errors {
+        state.record(error);
+    }
+}
+
+/// Parses a `case` declared outside of an enum, recording an error and
+/// leaving it out so that the rest of the body can be parsed.
+pub fn misplaced_case(
+    state: &mut State,
+    kind: &str,
+    container: Option<&SimpleIdentifier>,
+) -> ParseResult<()> {
+    let case = utils::skip(state, TokenKind::Case)?;
+    let name = identifiers::identifier_maybe_reserved(state)?;
+
+    if state.stream.current().kind == TokenKind::Equals {
+        utils::skip(state, TokenKind::Equals)?;
+        expressions::create(state)?;
+    }
+
+    utils::skip_semicolon(state)?;
+
+    let error = error::case_outside_enum(state, kind, container, case, &name);
+    state.record(error);
+
+    Ok(())
+}
+
+// Enums cannot have properties, but they are parsed anyway so that the rest
+// of the enum can be.
+fn property(
+    state: &mut State,
+    modifiers: Vec<(Span, TokenKind)>,
+    enum_name: &SimpleIdentifier,
+) -> ParseResult<()> {
+    let variables: Vec<_> = if state.stream.current().kind == TokenKind::Var {
+        properties::parse_var(state, Some(enum_name))?
+            .entries
+            .iter()
+            .map(|entry| entry.variable().clone())
+            .collect()
+    } else {
+        properties::parse(
+            state,
+            Some(enum_name),
+            modifiers::property_group(modifiers)?,
+        )?
+        .entries
+        .iter()
+        .map(|entry| entry.variable().clone())
+        .collect()
+    };
+
+    for variable in variables {
+        let error = error::property_in_enum(state, enum_name, &variable);
+
+        state.record(error);
+    }
+
+    Ok(())
+}
+
+fn duplicate_cases(state: &mut State, enum_name: &SimpleIdentifier, cases: Vec<&SimpleIdentifier>) {
+    for (index, case) in cases.iter().enumerate() {
+        if let Some(first) = cases
+            .iter()
+            .take(index)
+            .find(|first| first.value == case.value)
+        {
+            let error = error::duplicate_enum_case(state, enum_name, first, case);
+
+            state.record(error);
+        }
+    }
+}
+
 fn unit_member(
     state: &mut State,
     enum_name: &SimpleIdentifier,
@@ -123,6 +264,7 @@ fn unit_member(
 
     let current = state.stream.current();
     if current.kind == TokenKind::Case {
+        let comments = state.stream.comments();
         let attributes = state.get_attributes();
 
         let start = current.span;
@@ -147,6 +289,7 @@ fn unit_member(
         let end = utils::skip_semicolon(state)?;
 
         return Ok(Some(UnitEnumMember::Case(UnitEnumCase {
+            comments,
             start,
             end,
             name,
@@ -162,6 +305,10 @@ fn unit_member(
             .map(Some);
     }
 
+    if state.stream.current().kind != TokenKind::Function {
+        return property(state, modifiers, enum_name).map(|_| None);
+    }
+
     method(state, modifiers, enum_name).map(|method| method.map(UnitEnumMember::Method))
 }
 
@@ -179,6 +326,7 @@ fn backed_member(
 
     let current = state.stream.current();
     if current.kind == TokenKind::Case {
+        let comments = state.stream.comments();
         let attributes = state.get_attributes();
 
         let case = current.span;
@@ -206,6 +354,7 @@ fn backed_member(
         let semicolon = utils::skip_semicolon(state)?;
 
         return Ok(Some(BackedEnumMember::Case(BackedEnumCase {
+            comments,
             attributes,
             case,
             name,
@@ -223,6 +372,10 @@ fn backed_member(
             .map(Some);
     }
 
+    if state.stream.current().kind != TokenKind::Function {
+        return property(state, modifiers, enum_name).map(|_| None);
+    }
+
     method(state, modifiers, enum_name).map(|method| method.map(BackedEnumMember::Method))
 }
 
@@ -260,6 +413,116 @@ fn method(
 
             Ok(Some(method))
         }
-        Method::Abstract(_) | Method::AbstractConstructor(_) => unreachable!(),
+        Method::Abstract(AbstractMethod { function, .. })
+        | Method::AbstractConstructor(AbstractConstructor { function, .. }) => Err(
+            error::internal_error(state, "abstract method in an enum", function),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use crate::parser::ast::Statement;
+    use crate::Error;
+
+    // The ids of the errors in the code, along with the number of members
+    // that the declaration after the opening tag kept.
+    fn misplaced(code: &str) -> (Vec<String>, usize) {
+        let Err(Error::Parse(stack)) = crate::parse(code) else {
+            panic!("expected a parse error");
+        };
+        let errors = stack.errors.into_iter().map(|error| error.id).collect();
+
+        let members = match &stack.partial[1] {
+            Statement::Class(class) => class.body.members.len(),
+            Statement::Interface(interface) => interface.body.members.len(),
+            Statement::Trait(r#trait) => r#trait.body.members.len(),
+            Statement::UnitEnum(r#enum) => r#enum.body.members.len(),
+            Statement::BackedEnum(r#enum) => r#enum.body.members.len(),
+            _ => panic!("expected a declaration"),
+        };
+
+        (errors, members)
+    }
+
+    #[test]
+    fn test_case_outside_enum() {
+        for code in [
+            "<?php class A { case B; const C = 1; function d() {} }",
+            "<?php interface A { const C = 1; case B = 1; function d(); }",
+            "<?php trait A { const C = 1; function d() {} case B; }",
+        ] {
+            assert_eq!(misplaced(code), (vec!["E075".into()], 2), "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_case_outside_enum_in_anonymous_class() {
+        let Err(Error::Parse(stack)) = crate::parse("<?php new class { case A; }; echo 1;") else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(stack.errors[0].id, "E075");
+        assert_eq!(stack.partial.len(), 3);
+    }
+
+    #[test]
+    fn test_property_in_enum() {
+        assert_eq!(
+            misplaced("<?php enum A { public $b, $c; case D; var int $e; function f() {} }"),
+            (vec!["E074".into(), "E074".into(), "E074".into()], 2)
+        );
+        assert_eq!(
+            misplaced("<?php enum A: int { case B = 1; public static ?int $c = null; }"),
+            (vec!["E074".into()], 1)
+        );
+    }
+
+    // Enums cannot declare a constructor, promoted properties or not.
+    #[test]
+    fn test_constructor_in_enum() {
+        for code in [
+            "<?php enum A { case B; public function __construct() {} }",
+            "<?php enum A: string { case B = 'b'; function __construct(public int $c) {} }",
+        ] {
+            assert_eq!(misplaced(code), (vec!["E016".into()], 1), "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_enum_instantiation() {
+        let errors = |code: &str| -> Vec<String> {
+            match crate::parse(code) {
+                Ok(_) => Vec::new(),
+                Err(Error::Parse(stack)) => stack
+                    .errors
+                    .into_iter()
+                    .map(|error| error.message)
+                    .collect(),
+                Err(error) => panic!("{}", error),
+            }
+        };
+
+        assert_eq!(
+            errors("<?php new Suit(); new SUIT; new \\Suit; enum Suit { case A; }"),
+            [
+                "cannot instantiate enum `Suit`",
+                "cannot instantiate enum `SUIT`",
+                "cannot instantiate enum `\\Suit`"
+            ]
+        );
+        assert_eq!(
+            errors(
+                "<?php namespace A; enum Suit {} new Suit; new \\A\\Suit; new \\Suit; new B\\Suit;"
+            ),
+            [
+                "cannot instantiate enum `Suit`",
+                "cannot instantiate enum `\\A\\Suit`"
+            ]
+        );
+        assert!(errors("<?php enum Suit {} new Suits; new $suit; new class {};").is_empty());
     }
 }
@@ -1,3 +1,7 @@
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::functions::AbstractConstructor;
 use crate::parser::ast::functions::AbstractMethod;
@@ -15,6 +19,7 @@ use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::modifiers::MethodModifierGroup;
 use crate::parser::ast::Expression;
 use crate::parser::ast::Statement;
+use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
 use crate::parser::internal::blocks;
@@ -23,6 +28,7 @@ use crate::parser::internal::identifiers;
 use crate::parser::internal::parameters;
 use crate::parser::internal::utils;
 use crate::parser::internal::variables;
+use crate::parser::state::Scope;
 use crate::parser::state::State;
 
 pub enum MethodType {
@@ -86,6 +92,10 @@ pub fn anonymous_function(state: &mut State) -> ParseResult<Expression> {
 
                     let var = variables::simple_variable(state)?;
 
+                    if var.name == b"$this" {
+                        state.record(error::cannot_use_this_as_lexical_variable(var.span));
+                    }
+
                     Ok(ClosureUseVariable {
                         comments: use_comments,
                         variable: var,
@@ -112,7 +122,7 @@ pub fn anonymous_function(state: &mut State) -> ParseResult<Expression> {
     let body = FunctionBody {
         comments: state.stream.comments(),
         left_brace: utils::skip_left_brace(state)?,
-        statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
+        statements: body_statements(state)?,
         right_brace: utils::skip_right_brace(state)?,
     };
 
@@ -212,7 +222,7 @@ pub fn function(state: &mut State) -> ParseResult<Statement> {
     let body = FunctionBody {
         comments: state.stream.comments(),
         left_brace: utils::skip_left_brace(state)?,
-        statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
+        statements: body_statements(state)?,
         right_brace: utils::skip_right_brace(state)?,
     };
 
@@ -260,7 +270,7 @@ pub fn method(
             let body = MethodBody {
                 comments: state.stream.comments(),
                 left_brace: utils::skip_left_brace(state)?,
-                statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
+                statements: body_statements(state)?,
                 right_brace: utils::skip_right_brace(state)?,
             };
 
@@ -314,7 +324,7 @@ pub fn method(
             body: MethodBody {
                 comments: state.stream.comments(),
                 left_brace: utils::skip_left_brace(state)?,
-                statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
+                statements: body_statements(state)?,
                 right_brace: utils::skip_right_brace(state)?,
             },
         }))
@@ -332,3 +342,12 @@ pub fn method(
         }))
     }
 }
+
+// `break` and `continue` cannot target a loop outside of the function.
+pub fn body_statements(state: &mut State) -> ParseResult<Vec<Statement>> {
+    state.enter(Scope::Function);
+    let statements = blocks::multiple_statements_until(state, &TokenKind::RightBrace)?;
+    state.exit();
+
+    Ok(statements)
+}
@@ -1,3 +1,6 @@
+use alloc::borrow::ToOwned;
+use alloc::string::ToString;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::error;
@@ -1,22 +1,30 @@
+use alloc::vec::Vec;
+
 use crate::lexer::token::TokenKind;
+use crate::parser::ast::functions::ConcreteConstructor;
+use crate::parser::ast::functions::ConcreteMethod;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::interfaces::InterfaceBody;
 use crate::parser::ast::interfaces::InterfaceExtends;
 use crate::parser::ast::interfaces::InterfaceMember;
 use crate::parser::ast::interfaces::InterfaceStatement;
 use crate::parser::ast::Statement;
+use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::attributes;
 use crate::parser::internal::constants;
+use crate::parser::internal::enums;
 use crate::parser::internal::functions::method;
 use crate::parser::internal::functions::Method;
 use crate::parser::internal::functions::MethodType;
 use crate::parser::internal::identifiers;
 use crate::parser::internal::modifiers;
+use crate::parser::internal::properties;
 use crate::parser::internal::utils;
 use crate::parser::state::State;
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let span = utils::skip(state, TokenKind::Interface)?;
 
     let name = identifiers::type_identifier(state)?;
@@ -47,8 +55,20 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         members: {
             let mut members = Vec::new();
             while state.stream.current().kind != TokenKind::RightBrace {
+                if state.stream.current().kind == TokenKind::Case {
+                    enums::misplaced_case(state, "an interface", Some(&name))?;
+                    continue;
+                }
+
                 members.push(member(state, &name)?);
             }
+            members.extend(
+                state
+                    .stream
+                    .comments()
+                    .into_iter()
+                    .map(InterfaceMember::Comment),
+            );
 
             members
         },
@@ -56,6 +76,7 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
     };
 
     Ok(Statement::Interface(InterfaceStatement {
+        comments,
         interface: span,
         name,
         attributes,
@@ -72,6 +93,26 @@ fn member(state: &mut State, interface_name: &SimpleIdentifier) -> ParseResult<I
     if state.stream.current().kind == TokenKind::Const {
         constants::classish(state, modifiers::interface_constant_group(modifiers)?)
             .map(InterfaceMember::Constant)
+    } else if state.stream.current().kind != TokenKind::Function {
+        let property = properties::parse(
+            state,
+            Some(interface_name),
+            modifiers::interface_property_group(modifiers)?,
+        )?;
+
+        if property.hooks.is_none() {
+            if let Some(entry) = property.entries.first() {
+                let error = error::interface_property_without_hooks(
+                    state,
+                    interface_name,
+                    entry.variable(),
+                );
+
+                state.record(error);
+            }
+        }
+
+        Ok(InterfaceMember::Property(property))
     } else {
         let method = method(
             state,
@@ -83,7 +124,12 @@ fn member(state: &mut State, interface_name: &SimpleIdentifier) -> ParseResult<I
         match method {
             Method::Abstract(method) => Ok(InterfaceMember::Method(method)),
             Method::AbstractConstructor(ctor) => Ok(InterfaceMember::Constructor(ctor)),
-            Method::ConcreteConstructor(_) | Method::Concrete(_) => unreachable!(),
+            Method::ConcreteConstructor(ConcreteConstructor { function, .. })
+            | Method::Concrete(ConcreteMethod { function, .. }) => Err(error::internal_error(
+                state,
+                "concrete method in an interface",
+                function,
+            )),
         }
     }
 }
@@ -1,3 +1,6 @@
+use alloc::boxed::Box;
+
+use crate::lexer::token::Span;
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
 use crate::parser;
@@ -15,10 +18,13 @@ use crate::parser::ast::loops::Level;
 use crate::parser::ast::loops::WhileStatement;
 use crate::parser::ast::loops::WhileStatementBody;
 use crate::parser::ast::Statement;
+use crate::parser::deprecations::DeprecationKind;
+use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
 use crate::parser::internal::blocks;
 use crate::parser::internal::utils;
+use crate::parser::state::Scope;
 use crate::parser::state::State;
 
 pub fn foreach_statement(state: &mut State) -> ParseResult<Statement> {
@@ -55,7 +61,7 @@ pub fn foreach_statement(state: &mut State) -> ParseResult<Statement> {
 
                 let mut key = expressions::create(state)?;
 
-                std::mem::swap(&mut value, &mut key);
+                core::mem::swap(&mut value, &mut key);
 
                 Ok(ForeachStatementIterator::KeyAndValue {
                     expression,
@@ -75,6 +81,7 @@ pub fn foreach_statement(state: &mut State) -> ParseResult<Statement> {
             }
         })?;
 
+    state.enter(Scope::Loop);
     let body = if state.stream.current().kind == TokenKind::Colon {
         ForeachStatementBody::Block {
             colon: utils::skip_colon(state)?,
@@ -87,6 +94,7 @@ pub fn foreach_statement(state: &mut State) -> ParseResult<Statement> {
             statement: parser::statement(state).map(Box::new)?,
         }
     };
+    state.exit();
 
     Ok(Statement::Foreach(ForeachStatement {
         foreach,
@@ -127,6 +135,7 @@ pub fn for_statement(state: &mut State) -> ParseResult<Statement> {
         })
     })?;
 
+    state.enter(Scope::Loop);
     let body = if state.stream.current().kind == TokenKind::Colon {
         ForStatementBody::Block {
             colon: utils::skip_colon(state)?,
@@ -139,6 +148,7 @@ pub fn for_statement(state: &mut State) -> ParseResult<Statement> {
             statement: parser::statement(state).map(Box::new)?,
         }
     };
+    state.exit();
 
     Ok(Statement::For(ForStatement {
         r#for,
@@ -152,7 +162,9 @@ pub fn for_statement(state: &mut State) -> ParseResult<Statement> {
 pub fn do_while_statement(state: &mut State) -> ParseResult<Statement> {
     let r#do = utils::skip(state, TokenKind::Do)?;
 
+    state.enter(Scope::Loop);
     let body = parser::statement(state).map(Box::new)?;
+    state.exit();
 
     let r#while = utils::skip(state, TokenKind::While)?;
 
@@ -178,6 +190,7 @@ pub fn while_statement(state: &mut State) -> ParseResult<Statement> {
     let (left_parenthesis, condition, right_parenthesis) =
         utils::parenthesized(state, &expressions::create)?;
 
+    state.enter(Scope::Loop);
     let body = if state.stream.current().kind == TokenKind::Colon {
         WhileStatementBody::Block {
             colon: utils::skip_colon(state)?,
@@ -190,6 +203,7 @@ pub fn while_statement(state: &mut State) -> ParseResult<Statement> {
             statement: parser::statement(state).map(Box::new)?,
         }
     };
+    state.exit();
 
     Ok(Statement::While(WhileStatement {
         r#while,
@@ -201,21 +215,93 @@ pub fn while_statement(state: &mut State) -> ParseResult<Statement> {
 }
 
 pub fn continue_statement(state: &mut State) -> ParseResult<Statement> {
+    let r#continue = utils::skip(state, TokenKind::Continue)?;
+    let level = maybe_loop_level(state)?;
+
+    if check_loop_level(state, "continue", r#continue, level.as_ref()) {
+        // PHP warns when `continue` targets a `switch` statement, where it
+        // acts like `break`.
+        let target = level
+            .as_ref()
+            .and_then(Level::to_i64)
+            .map_or(1, |level| level as usize);
+
+        if state.breakable().nth(target - 1) == Some(&Scope::Switch) {
+            let end = match &level {
+                None => r#continue,
+                Some(Level::Literal(literal)) => literal.span,
+                Some(Level::Parenthesized {
+                    right_parenthesis, ..
+                }) => *right_parenthesis,
+            };
+
+            state.deprecate(DeprecationKind::ContinueTargetingSwitch, r#continue, end);
+        }
+    }
+
     Ok(Statement::Continue(ContinueStatement {
-        r#continue: utils::skip(state, TokenKind::Continue)?,
-        level: maybe_loop_level(state)?,
+        r#continue,
+        level,
         ending: utils::skip_ending(state)?,
     }))
 }
 
 pub fn break_statement(state: &mut State) -> ParseResult<Statement> {
+    let r#break = utils::skip(state, TokenKind::Break)?;
+    let level = maybe_loop_level(state)?;
+
+    check_loop_level(state, "break", r#break, level.as_ref());
+
     Ok(Statement::Break(BreakStatement {
-        r#break: utils::skip(state, TokenKind::Break)?,
-        level: maybe_loop_level(state)?,
+        r#break,
+        level,
         ending: utils::skip_ending(state)?,
     }))
 }
 
+// The checks are made in the same order as PHP's compiler, so that the same
+// error is reported first. Returns whether the level is valid.
+fn check_loop_level(state: &mut State, keyword: &str, span: Span, level: Option<&Level>) -> bool {
+    if let Some(level) = level {
+        if level.to_i64().is_some_and(|level| level < 1) {
+            state.record(error::loop_control_level_not_positive(
+                keyword,
+                span,
+                level.literal(),
+            ));
+
+            return false;
+        }
+    }
+
+    let depth = state.breakable().count();
+    if depth == 0 {
+        state.record(error::loop_control_outside_of_loop(keyword, span));
+
+        return false;
+    }
+
+    if let Some(level) = level {
+        let too_deep = match level.to_i64() {
+            Some(level) => level as u64 > depth as u64,
+            None => true,
+        };
+
+        if too_deep {
+            state.record(error::loop_control_level_too_deep(
+                keyword,
+                span,
+                level.literal(),
+                depth,
+            ));
+
+            return false;
+        }
+    }
+
+    true
+}
+
 fn maybe_loop_level(state: &mut State) -> ParseResult<Option<Level>> {
     let current = &state.stream.current().kind;
 
@@ -233,6 +319,7 @@ fn loop_level(state: &mut State) -> ParseResult<Level> {
         kind: TokenKind::LiteralInteger,
         span,
         value,
+        ..
     } = state.stream.current()
     {
         state.stream.next();
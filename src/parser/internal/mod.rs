@@ -17,6 +17,7 @@ pub(in crate::parser) mod parameters;
 pub(in crate::parser) mod precedences;
 pub(in crate::parser) mod properties;
 pub(in crate::parser) mod strings;
+pub(in crate::parser) mod tags;
 pub(in crate::parser) mod traits;
 pub(in crate::parser) mod try_block;
 pub(in crate::parser) mod uses;
@@ -1,3 +1,7 @@
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
 use crate::lexer::token::Span;
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::modifiers::ClassModifier;
@@ -10,9 +14,11 @@ use crate::parser::ast::modifiers::PromotedPropertyModifier;
 use crate::parser::ast::modifiers::PromotedPropertyModifierGroup;
 use crate::parser::ast::modifiers::PropertyModifier;
 use crate::parser::ast::modifiers::PropertyModifierGroup;
+use crate::parser::ast::modifiers::Visibility;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 
 #[inline(always)]
 pub fn class_group(input: Vec<(Span, TokenKind)>) -> ParseResult<ClassModifierGroup> {
@@ -148,6 +154,9 @@ pub fn property_group(input: Vec<(Span, TokenKind)>) -> ParseResult<PropertyModi
             TokenKind::Public => Ok(PropertyModifier::Public(*span)),
             TokenKind::Protected => Ok(PropertyModifier::Protected(*span)),
             TokenKind::Private => Ok(PropertyModifier::Private(*span)),
+            TokenKind::PublicSet => Ok(PropertyModifier::PublicSet(*span)),
+            TokenKind::ProtectedSet => Ok(PropertyModifier::ProtectedSet(*span)),
+            TokenKind::PrivateSet => Ok(PropertyModifier::PrivateSet(*span)),
             _ => Err(error::modifier_cannot_be_used_for_property(
                 token.to_string(),
                 *span,
@@ -155,6 +164,50 @@ pub fn property_group(input: Vec<(Span, TokenKind)>) -> ParseResult<PropertyModi
         })
         .collect::<ParseResult<Vec<PropertyModifier>>>()?;
 
+    let group = PropertyModifierGroup { modifiers };
+    if let Some(set_visibility) = group.set_visibility() {
+        let visibility = group.modifiers.iter().find(|modifier| {
+            matches!(
+                modifier,
+                PropertyModifier::Public(_)
+                    | PropertyModifier::Protected(_)
+                    | PropertyModifier::Private(_)
+            )
+        });
+        let incompatible = group.modifiers.iter().find(|modifier| match modifier {
+            PropertyModifier::Static(_) => true,
+            PropertyModifier::Readonly(_) => set_visibility.0 == Visibility::Public,
+            _ => false,
+        });
+
+        check_set_visibility(
+            (
+                group.visibility(),
+                visibility.map(|modifier| modifier.span()),
+            ),
+            set_visibility,
+            incompatible.map(|modifier| (modifier.to_string(), modifier.span())),
+        )?;
+    }
+
+    Ok(group)
+}
+
+#[inline(always)]
+pub fn interface_property_group(
+    input: Vec<(Span, TokenKind)>,
+) -> ParseResult<PropertyModifierGroup> {
+    let modifiers = input
+        .iter()
+        .map(|(span, token)| match token {
+            TokenKind::Public => Ok(PropertyModifier::Public(*span)),
+            _ => Err(error::modifier_cannot_be_used_for_interface_property(
+                token.to_string(),
+                *span,
+            )),
+        })
+        .collect::<ParseResult<Vec<PropertyModifier>>>()?;
+
     Ok(PropertyModifierGroup { modifiers })
 }
 
@@ -169,6 +222,9 @@ pub fn promoted_property_group(
             TokenKind::Private => Ok(PromotedPropertyModifier::Private(*span)),
             TokenKind::Protected => Ok(PromotedPropertyModifier::Protected(*span)),
             TokenKind::Public => Ok(PromotedPropertyModifier::Public(*span)),
+            TokenKind::PublicSet => Ok(PromotedPropertyModifier::PublicSet(*span)),
+            TokenKind::ProtectedSet => Ok(PromotedPropertyModifier::ProtectedSet(*span)),
+            TokenKind::PrivateSet => Ok(PromotedPropertyModifier::PrivateSet(*span)),
             _ => Err(error::modifier_cannot_be_used_for_promoted_property(
                 token.to_string(),
                 *span,
@@ -176,7 +232,32 @@ pub fn promoted_property_group(
         })
         .collect::<ParseResult<Vec<PromotedPropertyModifier>>>()?;
 
-    Ok(PromotedPropertyModifierGroup { modifiers })
+    let group = PromotedPropertyModifierGroup { modifiers };
+    if let Some(set_visibility) = group.set_visibility() {
+        let visibility = group.modifiers.iter().find(|modifier| {
+            matches!(
+                modifier,
+                PromotedPropertyModifier::Public(_)
+                    | PromotedPropertyModifier::Protected(_)
+                    | PromotedPropertyModifier::Private(_)
+            )
+        });
+        let incompatible = group.modifiers.iter().find(|modifier| match modifier {
+            PromotedPropertyModifier::Readonly(_) => set_visibility.0 == Visibility::Public,
+            _ => false,
+        });
+
+        check_set_visibility(
+            (
+                group.visibility(),
+                visibility.map(|modifier| modifier.span()),
+            ),
+            set_visibility,
+            incompatible.map(|modifier| (modifier.to_string(), modifier.span())),
+        )?;
+    }
+
+    Ok(group)
 }
 
 pub fn constant_group(input: Vec<(Span, TokenKind)>) -> ParseResult<ConstantModifierGroup> {
@@ -195,7 +276,7 @@ pub fn constant_group(input: Vec<(Span, TokenKind)>) -> ParseResult<ConstantModi
                         final_span, *span,
                     ))
                 } else {
-                    Ok(ConstantModifier::Final(*span))
+                    Ok(ConstantModifier::Private(*span))
                 }
             }
             TokenKind::Final => {
@@ -240,7 +321,7 @@ pub fn interface_constant_group(
 pub fn collect(state: &mut State) -> ParseResult<Vec<(Span, TokenKind)>> {
     let mut collected: Vec<(Span, TokenKind)> = vec![];
 
-    let collectable_tokens = vec![
+    let collectable_tokens = [
         TokenKind::Private,
         TokenKind::Protected,
         TokenKind::Public,
@@ -250,11 +331,27 @@ pub fn collect(state: &mut State) -> ParseResult<Vec<(Span, TokenKind)>> {
         TokenKind::Readonly,
     ];
 
-    let mut current = state.stream.current().clone();
+    let mut current = state.stream.current();
     let mut current_kind = current.kind;
     let mut current_span = current.span;
 
     while collectable_tokens.contains(&current_kind) {
+        if let Some(kind) = set_visibility(state) {
+            let length = kind.to_string().len();
+            state.require(
+                "asymmetric visibility modifiers",
+                PhpVersion::Php84,
+                current_span,
+                length,
+            );
+
+            // The visibility token is skipped along with the others below.
+            state.stream.next();
+            state.stream.next();
+            state.stream.next();
+            current_kind = kind;
+        }
+
         if let Some((span, _)) = collected.iter().find(|(_, kind)| kind == &current_kind) {
             return Err(error::multiple_modifiers(
                 current_kind.to_string(),
@@ -281,14 +378,99 @@ pub fn collect(state: &mut State) -> ParseResult<Vec<(Span, TokenKind)>> {
             }
         }
 
+        if matches!(
+            current_kind,
+            TokenKind::PublicSet | TokenKind::ProtectedSet | TokenKind::PrivateSet
+        ) {
+            if let Some((span, visibility)) = collected.iter().find(|(_, kind)| {
+                matches!(
+                    kind,
+                    TokenKind::PublicSet | TokenKind::ProtectedSet | TokenKind::PrivateSet
+                )
+            }) {
+                state.record(error::multiple_visibility_modifiers(
+                    (visibility.to_string(), *span),
+                    (current_kind.to_string(), current_span),
+                ));
+            }
+        }
+
         collected.push((current_span, current_kind));
 
         state.stream.next();
 
-        current = state.stream.current().clone();
+        current = state.stream.current();
         current_kind = current.kind;
         current_span = current.span;
     }
 
     Ok(collected)
 }
+
+// Checks that a property is no harder to read than to write, as with `private
+// public(set)`, and that its visibility of writing goes with its modifiers.
+fn check_set_visibility(
+    (visibility, visibility_span): (Visibility, Option<Span>),
+    (set_visibility, set_span): (Visibility, Span),
+    incompatible: Option<(String, Span)>,
+) -> ParseResult<()> {
+    let rank = |visibility: &Visibility| match visibility {
+        Visibility::Public => 0,
+        Visibility::Protected => 1,
+        Visibility::Private => 2,
+    };
+
+    if rank(&set_visibility) < rank(&visibility) {
+        return Err(error::set_visibility_wider_than_visibility(
+            (visibility, visibility_span),
+            (set_visibility, set_span),
+        ));
+    }
+
+    if let Some((modifier, span)) = incompatible {
+        return Err(error::set_visibility_with_incompatible_modifier(
+            set_visibility,
+            set_span,
+            modifier,
+            span,
+        ));
+    }
+
+    Ok(())
+}
+
+// Whether the visibility at the current token is followed by `(set)`, which
+// makes it the visibility of writing to a property. There can be no space in
+// between, as in `private(set)`.
+fn set_visibility(state: &State) -> Option<TokenKind> {
+    let visibility = state.stream.current();
+    let kind = match visibility.kind {
+        TokenKind::Public => TokenKind::PublicSet,
+        TokenKind::Protected => TokenKind::ProtectedSet,
+        TokenKind::Private => TokenKind::PrivateSet,
+        _ => return None,
+    };
+
+    let mut position = visibility.span.offset() + visibility.value.len();
+    for (n, expected) in [
+        TokenKind::LeftParen,
+        TokenKind::Identifier,
+        TokenKind::RightParen,
+    ]
+    .iter()
+    .enumerate()
+    {
+        let token = state.stream.lookahead(n);
+        if token.kind != *expected || token.span.offset() != position {
+            return None;
+        }
+
+        if token.kind == TokenKind::Identifier && !token.value.eq_ignore_ascii_case(b"set") {
+            return None;
+        }
+
+        position += token.value.len();
+    }
+
+    Some(kind)
+}
@@ -10,6 +10,7 @@ use crate::parser::ast::Block;
 use crate::parser::ast::Statement;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
+use crate::parser::internal::blocks;
 use crate::parser::internal::identifiers;
 use crate::parser::internal::utils;
 use crate::parser::state::NamespaceType;
@@ -59,9 +60,12 @@ fn unbraced_namespace(
         // `namespace` token as a top level statement, this namespace scope ends.
         // otherwise we will end up with nested namespace statements.
         while state.stream.current().kind != TokenKind::Namespace && !state.stream.is_eof() {
+            blocks::comments(state, &mut statements);
             statements.push(parser::top_level_statement(state)?);
         }
 
+        blocks::remaining_comments(state, &mut statements);
+
         statements
     });
 
@@ -85,9 +89,12 @@ fn braced_namespace(
 
         let mut statements = Block::new();
         while state.stream.current().kind != TokenKind::RightBrace && !state.stream.is_eof() {
+            blocks::comments(state, &mut statements);
             statements.push(parser::top_level_statement(state)?);
         }
 
+        blocks::remaining_comments(state, &mut statements);
+
         let end = utils::skip_right_brace(state)?;
 
         BracedNamespaceBody {
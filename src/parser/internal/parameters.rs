@@ -1,3 +1,7 @@
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::arguments::{Argument, SingleArgument};
 use crate::parser::ast::arguments::{ArgumentList, NamedArgument, PositionalArgument};
@@ -6,6 +10,8 @@ use crate::parser::ast::functions::ConstructorParameterList;
 use crate::parser::ast::functions::FunctionParameter;
 use crate::parser::ast::functions::FunctionParameterList;
 use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::Expression;
+use crate::parser::completion::ExpectedAt;
 use crate::parser::error;
 use crate::parser::error::ParseError;
 use crate::parser::error::ParseResult;
@@ -14,9 +20,11 @@ use crate::parser::internal::attributes;
 use crate::parser::internal::data_type;
 use crate::parser::internal::identifiers;
 use crate::parser::internal::modifiers;
+use crate::parser::internal::properties;
 use crate::parser::internal::utils;
 use crate::parser::internal::variables;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 
 pub fn function_parameter_list(state: &mut State) -> Result<FunctionParameterList, ParseError> {
     let comments = state.stream.comments();
@@ -31,8 +39,9 @@ pub fn function_parameter_list(state: &mut State) -> Result<FunctionParameterLis
             let mut current = state.stream.current();
             let ampersand = if current.kind == TokenKind::Ampersand {
                 state.stream.next();
+                let span = current.span;
                 current = state.stream.current();
-                Some(current.span)
+                Some(span)
             } else {
                 None
             };
@@ -50,8 +59,15 @@ pub fn function_parameter_list(state: &mut State) -> Result<FunctionParameterLis
 
             let mut default = None;
             if state.stream.current().kind == TokenKind::Equals {
+                let equals = state.stream.current().span;
                 state.stream.next();
-                default = Some(expressions::create(state)?);
+                let value = expressions::create(state)?;
+                expressions::require_new_in_initializer(state, &value);
+                default = Some(value);
+
+                if ellipsis.is_some() {
+                    state.record(error::variadic_parameter_with_default(&var, equals));
+                }
             }
 
             Ok(FunctionParameter {
@@ -67,6 +83,26 @@ pub fn function_parameter_list(state: &mut State) -> Result<FunctionParameterLis
         TokenKind::RightParen,
     )?;
 
+    for (index, parameter) in parameters.inner.iter().enumerate() {
+        if let Some(first) = parameters
+            .inner
+            .iter()
+            .take(index)
+            .find(|first| first.name.name == parameter.name.name)
+        {
+            state.record(error::duplicate_parameter(&first.name, &parameter.name));
+        }
+
+        if let Some(ellipsis) = parameter.ellipsis {
+            if index + 1 < parameters.inner.len() {
+                state.record(error::variadic_parameter_not_last(
+                    &parameter.name,
+                    ellipsis,
+                ));
+            }
+        }
+    }
+
     let right_parenthesis = utils::skip_right_parenthesis(state)?;
 
     Ok(FunctionParameterList {
@@ -88,18 +124,32 @@ pub fn constructor_parameter_list(
         state,
         &|state| {
             attributes::gather_attributes(state)?;
+            // Taken before the hooks, which have attributes of their own.
+            let attributes = state.get_attributes();
 
             let modifiers = modifiers::promoted_property_group(modifiers::collect(state)?)?;
+            if let Some(modifier) = modifiers.modifiers.first() {
+                let span = modifier.span();
+                let length = modifier.to_string().len();
+
+                state.require("promoted properties", PhpVersion::Php80, span, length);
+            }
+            if let Some(readonly) = modifiers.get_readonly() {
+                let span = readonly.span();
+
+                state.require("readonly properties", PhpVersion::Php81, span, 8);
+            }
 
             let ty = data_type::optional_data_type(state)?;
 
             let mut current = state.stream.current();
             let ampersand = if matches!(current.kind, TokenKind::Ampersand) {
                 state.stream.next();
+                let span = current.span;
 
                 current = state.stream.current();
 
-                Some(current.span)
+                Some(span)
             } else {
                 None
             };
@@ -107,13 +157,13 @@ pub fn constructor_parameter_list(
             let (ellipsis, var) = if matches!(current.kind, TokenKind::Ellipsis) {
                 state.stream.next();
                 let var = variables::simple_variable(state)?;
-                if !modifiers.is_empty() {
+                if let Some(modifier) = modifiers.modifiers.first() {
                     return Err(error::variadic_promoted_property(
                         state,
                         class,
                         &var,
                         current.span,
-                        modifiers.modifiers.first().unwrap(),
+                        modifier,
                     ));
                 }
 
@@ -145,30 +195,76 @@ pub fn constructor_parameter_list(
                                 modifier.span(),
                             ));
                         }
+
+                        if let Some((set_visibility, span)) = modifiers.set_visibility() {
+                            return Err(error::missing_type_for_asymmetric_property(
+                                state,
+                                class,
+                                &var,
+                                set_visibility,
+                                span,
+                            ));
+                        }
                     }
                 }
             }
 
             let mut default = None;
             if state.stream.current().kind == TokenKind::Equals {
+                let equals = state.stream.current().span;
                 state.stream.next();
-                default = Some(expressions::create(state)?);
+                let value = expressions::create(state)?;
+                expressions::require_new_in_initializer(state, &value);
+                default = Some(value);
+
+                if ellipsis.is_some() {
+                    state.record(error::variadic_parameter_with_default(&var, equals));
+                }
             }
 
+            // Only a promoted property can have hooks.
+            let hooks =
+                if state.stream.current().kind == TokenKind::LeftBrace && !modifiers.is_empty() {
+                    Some(properties::hook_list(state)?)
+                } else {
+                    None
+                };
+
             Ok(ConstructorParameter {
                 comments: state.stream.comments(),
                 name: var,
-                attributes: state.get_attributes(),
+                attributes,
                 data_type: ty,
                 ellipsis,
                 default,
                 modifiers,
                 ampersand,
+                hooks,
             })
         },
         TokenKind::RightParen,
     )?;
 
+    for (index, parameter) in parameters.inner.iter().enumerate() {
+        if let Some(first) = parameters
+            .inner
+            .iter()
+            .take(index)
+            .find(|first| first.name.name == parameter.name.name)
+        {
+            state.record(error::duplicate_parameter(&first.name, &parameter.name));
+        }
+
+        if let Some(ellipsis) = parameter.ellipsis {
+            if index + 1 < parameters.inner.len() {
+                state.record(error::variadic_parameter_not_last(
+                    &parameter.name,
+                    ellipsis,
+                ));
+            }
+        }
+    }
+
     let right_parenthesis = utils::skip_right_parenthesis(state)?;
 
     Ok(ConstructorParameterList {
@@ -185,10 +281,21 @@ pub fn argument_list(state: &mut State) -> ParseResult<ArgumentList> {
 
     let mut arguments = Vec::new();
     let mut has_used_named_arguments = false;
+    // Whether an argument could start at the current token, as it follows
+    // the opening parenthesis or a comma.
+    let mut expects_argument = true;
 
     while !state.stream.is_eof() && state.stream.current().kind != TokenKind::RightParen {
         let span = state.stream.current().span;
-        let (named, argument) = argument(state)?;
+        let (named, argument) = argument(state).map_err(|mut error| {
+            // Nothing of the argument was found, not even the name of a
+            // named argument.
+            if error.span.position == span.position {
+                error.expected = Some(Box::new(ExpectedAt::Argument { callee: None }));
+            }
+
+            error
+        })?;
         if named {
             has_used_named_arguments = true;
         } else if has_used_named_arguments {
@@ -198,16 +305,35 @@ pub fn argument_list(state: &mut State) -> ParseResult<ArgumentList> {
             ));
         }
 
+        if let Argument::Named(argument) = &argument {
+            let first = arguments.iter().find_map(|first| match first {
+                Argument::Named(first) if first.name.value == argument.name.value => Some(first),
+                _ => None,
+            });
+
+            if let Some(first) = first {
+                state.record(error::duplicate_named_argument(&first.name, &argument.name));
+            }
+        }
+
         arguments.push(argument);
+        expects_argument = false;
 
         if state.stream.current().kind == TokenKind::Comma {
             state.stream.next();
+            expects_argument = true;
         } else {
             break;
         }
     }
 
-    let end = utils::skip_right_parenthesis(state)?;
+    let end = utils::skip_right_parenthesis(state).map_err(|error| {
+        if expects_argument {
+            error.expecting(ExpectedAt::Argument { callee: None })
+        } else {
+            error
+        }
+    })?;
 
     Ok(ArgumentList {
         comments,
@@ -217,34 +343,70 @@ pub fn argument_list(state: &mut State) -> ParseResult<ArgumentList> {
     })
 }
 
-pub fn single_argument(
+/// Parses a parenthesized argument that must be present, as in `empty($a)`.
+pub fn single_argument(state: &mut State, only_positional: bool) -> ParseResult<SingleArgument> {
+    let comments = state.stream.comments();
+    let left_parenthesis = utils::skip_left_parenthesis(state)?;
+
+    let Some(argument) = argument_until_right_parenthesis(state, only_positional)? else {
+        return Err(error::argument_is_required(
+            state.stream.current().span,
+            state.stream.current().span,
+        ));
+    };
+
+    let right_parenthesis = utils::skip_right_parenthesis(state)?;
+
+    Ok(SingleArgument {
+        comments,
+        left_parenthesis,
+        right_parenthesis,
+        argument,
+    })
+}
+
+/// Parses the arguments of `isset` and `unset`: one or more positional
+/// arguments, each of which `valid` is expected to accept.
+///
+/// An argument that is not accepted is recorded as an error, and kept.
+pub fn variable_argument_list(
     state: &mut State,
-    required: bool,
-    only_positional: bool,
-) -> Option<ParseResult<SingleArgument>> {
+    construct: &str,
+    valid: fn(&Expression) -> bool,
+) -> ParseResult<ArgumentList> {
     let comments = state.stream.comments();
-    let start = utils::skip_left_parenthesis(state).ok()?;
+    let left_parenthesis = utils::skip_left_parenthesis(state)?;
 
-    let mut first_argument = None;
+    let mut arguments = Vec::new();
 
     while !state.stream.is_eof() && state.stream.current().kind != TokenKind::RightParen {
         let span = state.stream.current().span;
-        let (named, argument) = argument(state).ok()?;
-        if only_positional && named {
-            return Some(Err(error::only_positional_arguments_are_accepted(
+        let (named, argument) = argument(state)?;
+        if named {
+            return Err(error::only_positional_arguments_are_accepted(
                 span,
                 state.stream.current().span,
-            )));
+            ));
         }
 
-        if first_argument.is_some() {
-            return Some(Err(error::only_one_argument_is_accepted(
-                span,
-                state.stream.current().span,
-            )));
+        if let Argument::Positional(PositionalArgument {
+            ellipsis, value, ..
+        }) = &argument
+        {
+            if let Some(ellipsis) = ellipsis {
+                return Err(error::illegal_spread_operator_usage(*ellipsis));
+            }
+
+            if !valid(value) {
+                state.record(error::cannot_use_expression_in_construct(
+                    construct,
+                    span,
+                    state.stream.current().span,
+                ));
+            }
         }
 
-        first_argument = Some(argument);
+        arguments.push(argument);
 
         if state.stream.current().kind == TokenKind::Comma {
             state.stream.next();
@@ -253,33 +415,98 @@ pub fn single_argument(
         }
     }
 
-    if required && first_argument.is_none() {
-        return Some(Err(error::argument_is_required(
+    if arguments.is_empty() {
+        return Err(error::argument_is_required(
             state.stream.current().span,
             state.stream.current().span,
-        )));
+        ));
     }
 
-    let end = utils::skip_right_parenthesis(state).ok()?;
+    let right_parenthesis = utils::skip_right_parenthesis(state)?;
 
-    first_argument.as_ref()?;
+    Ok(ArgumentList {
+        comments,
+        left_parenthesis,
+        arguments,
+        right_parenthesis,
+    })
+}
+
+/// Parses a parenthesized argument that may be left out, as in `exit` or
+/// `exit()`.
+pub fn optional_single_argument(
+    state: &mut State,
+    only_positional: bool,
+) -> ParseResult<Option<SingleArgument>> {
+    if state.stream.current().kind != TokenKind::LeftParen {
+        return Ok(None);
+    }
 
-    Some(Ok(SingleArgument {
+    let comments = state.stream.comments();
+    let left_parenthesis = utils::skip_left_parenthesis(state)?;
+    let argument = argument_until_right_parenthesis(state, only_positional)?;
+    let right_parenthesis = utils::skip_right_parenthesis(state)?;
+
+    Ok(argument.map(|argument| SingleArgument {
         comments,
-        left_parenthesis: start,
-        right_parenthesis: end,
-        argument: first_argument.unwrap(),
+        left_parenthesis,
+        right_parenthesis,
+        argument,
     }))
 }
 
+fn argument_until_right_parenthesis(
+    state: &mut State,
+    only_positional: bool,
+) -> ParseResult<Option<Argument>> {
+    let mut first_argument = None;
+
+    while !state.stream.is_eof() && state.stream.current().kind != TokenKind::RightParen {
+        let span = state.stream.current().span;
+        let (named, argument) = argument(state)?;
+        if only_positional && named {
+            return Err(error::only_positional_arguments_are_accepted(
+                span,
+                state.stream.current().span,
+            ));
+        }
+
+        if first_argument.is_some() {
+            return Err(error::only_one_argument_is_accepted(
+                span,
+                state.stream.current().span,
+            ));
+        }
+
+        first_argument = Some(argument);
+
+        if state.stream.current().kind == TokenKind::Comma {
+            state.stream.next();
+        } else {
+            break;
+        }
+    }
+
+    Ok(first_argument)
+}
+
 fn argument(state: &mut State) -> ParseResult<(bool, Argument)> {
     if identifiers::is_identifier_maybe_reserved(&state.stream.current().kind)
         && state.stream.peek().kind == TokenKind::Colon
     {
         let name = identifiers::identifier_maybe_reserved(state)?;
         let colon = utils::skip(state, TokenKind::Colon)?;
+        state.require(
+            "named arguments",
+            PhpVersion::Php80,
+            name.span,
+            colon.offset() + 1 - name.span.offset(),
+        );
         let ellipsis = if state.stream.current().kind == TokenKind::Ellipsis {
-            Some(utils::skip(state, TokenKind::Ellipsis)?)
+            let ellipsis = utils::skip(state, TokenKind::Ellipsis)?;
+            state.record(error::cannot_unpack_named_argument(&name, ellipsis));
+
+            Some(ellipsis)
         } else {
             None
         };
@@ -6,17 +6,18 @@ pub enum Associativity {
     Right,
 }
 
+// Ordered from the loosest to the tightest binding, following
+// https://www.php.net/manual/en/language.operators.precedence.php
 #[allow(dead_code)]
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub enum Precedence {
     Lowest,
-    Print,
-    Yield,
-    YieldFrom,
-    IncDec,
     KeyOr,
     KeyXor,
     KeyAnd,
+    Print,
+    Yield,
+    YieldFrom,
     Assignment,
     Ternary,
     NullCoalesce,
@@ -35,16 +36,19 @@ pub enum Precedence {
     Instanceof,
     Prefix,
     Pow,
+    IncDec,
     CallDim,
     ObjectAccess,
     CloneOrNew,
 }
 
 impl Precedence {
-    pub fn infix(kind: &TokenKind) -> Self {
+    /// Returns the precedence of the given infix operator, or `None` if the
+    /// token isn't one.
+    pub fn infix(kind: &TokenKind) -> Option<Self> {
         use TokenKind::*;
 
-        match kind {
+        Some(match kind {
             Pow => Self::Pow,
             Instanceof => Self::Instanceof,
             Asterisk | Slash | Percent => Self::MulDivMod,
@@ -62,26 +66,27 @@ impl Precedence {
             DoubleQuestion => Self::NullCoalesce,
             Question | QuestionColon => Self::Ternary,
             Equals | PlusEquals | MinusEquals | AsteriskEquals | PowEquals | SlashEquals
-            | DotEquals | AndEquals | DoubleQuestionEquals | PercentEquals | AmpersandEquals
-            | PipeEquals | CaretEquals | LeftShiftEquals | RightShiftEquals => Self::Assignment,
-            Yield => Self::Yield,
+            | DotEquals | DoubleQuestionEquals | PercentEquals | AmpersandEquals | PipeEquals
+            | CaretEquals | LeftShiftEquals | RightShiftEquals => Self::Assignment,
             LogicalAnd => Self::KeyAnd,
             LogicalOr => Self::KeyOr,
             LogicalXor => Self::KeyXor,
-            _ => unimplemented!("precedence for op {:?}", kind),
-        }
+            _ => return None,
+        })
     }
 
-    pub fn postfix(kind: &TokenKind) -> Self {
+    /// Returns the precedence of the given postfix operator, or `None` if the
+    /// token isn't one.
+    pub fn postfix(kind: &TokenKind) -> Option<Self> {
         use TokenKind::*;
 
-        match kind {
+        Some(match kind {
             DoubleQuestion => Self::NullCoalesce,
             Increment | Decrement => Self::IncDec,
             LeftParen | LeftBracket => Self::CallDim,
             Arrow | QuestionArrow | DoubleColon => Self::ObjectAccess,
-            _ => unimplemented!("postfix precedence for op {:?}", kind),
-        }
+            _ => return None,
+        })
     }
 
     pub fn associativity(&self) -> Option<Associativity> {
@@ -98,9 +103,10 @@ impl Precedence {
             | Self::Or
             | Self::KeyAnd
             | Self::KeyOr
-            | Self::KeyXor => Associativity::Left,
+            | Self::KeyXor
+            | Self::Ternary => Associativity::Left,
             Self::Pow | Self::NullCoalesce | Self::Assignment => Associativity::Right,
-            Self::Ternary | Self::Equality | Self::LtGt => Associativity::Non,
+            Self::Equality | Self::LtGt => Associativity::Non,
             _ => return None,
         })
     }
@@ -1,38 +1,57 @@
 use crate::lexer::token::TokenKind;
+use crate::parser::ast::functions::MethodBody;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::modifiers::PropertyModifierGroup;
 use crate::parser::ast::properties::Property;
 use crate::parser::ast::properties::PropertyEntry;
+use crate::parser::ast::properties::PropertyHook;
+use crate::parser::ast::properties::PropertyHookBody;
+use crate::parser::ast::properties::PropertyHookList;
 use crate::parser::ast::properties::VariableProperty;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
+use crate::parser::internal::attributes;
 use crate::parser::internal::data_type;
+use crate::parser::internal::functions;
+use crate::parser::internal::identifiers;
+use crate::parser::internal::parameters;
 use crate::parser::internal::utils;
 use crate::parser::internal::variables;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 
 pub fn parse(
     state: &mut State,
     class_name: Option<&SimpleIdentifier>,
     modifiers: PropertyModifierGroup,
 ) -> ParseResult<Property> {
+    let comments = state.stream.comments();
+    // Taken before the hooks, which have attributes of their own.
+    let attributes = state.get_attributes();
+    if let Some(readonly) = modifiers.get_readonly() {
+        state.require("readonly properties", PhpVersion::Php81, readonly.span(), 8);
+    }
+
     let ty = data_type::optional_data_type(state)?;
 
     let mut entries = vec![];
+    let mut hooks = None;
     let mut type_checked = false;
     loop {
         let variable = variables::simple_variable(state)?;
 
         if !type_checked {
             type_checked = true;
-            if modifiers.has_readonly() && modifiers.has_static() {
+            if let (Some(r#static), Some(readonly)) =
+                (modifiers.get_static(), modifiers.get_readonly())
+            {
                 let error = error::static_property_cannot_be_readonly(
                     state,
                     class_name,
                     &variable,
-                    modifiers.get_static().unwrap().span(),
-                    modifiers.get_readonly().unwrap().span(),
+                    r#static.span(),
+                    readonly.span(),
                 );
 
                 state.record(error);
@@ -62,6 +81,18 @@ pub fn parse(
 
                         state.record(error);
                     }
+
+                    if let Some((set_visibility, span)) = modifiers.set_visibility() {
+                        let error = error::missing_type_for_asymmetric_property(
+                            state,
+                            class_name,
+                            &variable,
+                            set_visibility,
+                            span,
+                        );
+
+                        state.record(error);
+                    }
                 }
             }
         }
@@ -92,6 +123,25 @@ pub fn parse(
             entries.push(PropertyEntry::Uninitialized { variable });
         }
 
+        // Only a property declared on its own can have hooks.
+        let current = state.stream.current();
+        if let (TokenKind::LeftBrace, [entry]) = (&current.kind, &entries[..]) {
+            if let Some(r#static) = modifiers.get_static() {
+                let error = error::static_property_with_hooks(
+                    state,
+                    class_name,
+                    entry.variable(),
+                    r#static.span(),
+                    current.span,
+                );
+
+                state.record(error);
+            }
+
+            hooks = Some(hook_list(state)?);
+            break;
+        }
+
         if state.stream.current().kind == TokenKind::Comma {
             state.stream.next();
         } else {
@@ -99,22 +149,114 @@ pub fn parse(
         }
     }
 
-    let end = utils::skip_semicolon(state)?;
+    let end = match &hooks {
+        Some(hooks) => hooks.right_brace,
+        None => utils::skip_semicolon(state)?,
+    };
 
     Ok(Property {
+        comments,
         r#type: ty,
         modifiers,
-        attributes: state.get_attributes(),
+        attributes,
         entries,
+        hooks,
         end,
     })
 }
 
+pub fn hook_list(state: &mut State) -> ParseResult<PropertyHookList> {
+    let left_brace = utils::skip_left_brace(state)?;
+    state.require("property hooks", PhpVersion::Php84, left_brace, 1);
+
+    let mut hooks = vec![];
+    while state.stream.current().kind != TokenKind::RightBrace {
+        hooks.push(hook(state)?);
+    }
+
+    Ok(PropertyHookList {
+        left_brace,
+        hooks,
+        right_brace: utils::skip_right_brace(state)?,
+    })
+}
+
+fn hook(state: &mut State) -> ParseResult<PropertyHook> {
+    attributes::gather_attributes(state)?;
+    let comments = state.stream.comments();
+
+    let current = state.stream.current();
+    let r#final = if current.kind == TokenKind::Final {
+        state.stream.next();
+
+        Some(current.span)
+    } else {
+        None
+    };
+
+    let current = state.stream.current();
+    let ampersand = if current.kind == TokenKind::Ampersand {
+        state.stream.next();
+
+        Some(current.span)
+    } else {
+        None
+    };
+
+    let name = identifiers::identifier(state)?;
+    if !name.value.eq_ignore_ascii_case(b"get") && !name.value.eq_ignore_ascii_case(b"set") {
+        state.record(error::unknown_property_hook(&name));
+    }
+
+    let parameters = if state.stream.current().kind == TokenKind::LeftParen {
+        Some(parameters::function_parameter_list(state)?)
+    } else {
+        None
+    };
+
+    let current = state.stream.current();
+    let body = match current.kind {
+        TokenKind::SemiColon => {
+            state.stream.next();
+
+            PropertyHookBody::Abstract {
+                semicolon: current.span,
+            }
+        }
+        TokenKind::DoubleArrow => {
+            state.stream.next();
+
+            PropertyHookBody::Expression {
+                double_arrow: current.span,
+                expression: expressions::create(state)?,
+                semicolon: utils::skip_semicolon(state)?,
+            }
+        }
+        _ => PropertyHookBody::Block(MethodBody {
+            comments: state.stream.comments(),
+            left_brace: utils::skip_left_brace(state)?,
+            statements: functions::body_statements(state)?,
+            right_brace: utils::skip_right_brace(state)?,
+        }),
+    };
+
+    Ok(PropertyHook {
+        comments,
+        attributes: state.get_attributes(),
+        r#final,
+        ampersand,
+        name,
+        parameters,
+        body,
+    })
+}
+
 pub fn parse_var(
     state: &mut State,
     class_name: Option<&SimpleIdentifier>,
 ) -> ParseResult<VariableProperty> {
-    utils::skip(state, TokenKind::Var)?;
+    let comments = state.stream.comments();
+    let var = utils::skip(state, TokenKind::Var)?;
 
     let ty = data_type::optional_data_type(state)?;
 
@@ -165,8 +307,10 @@ pub fn parse_var(
     let end = utils::skip_semicolon(state)?;
 
     Ok(VariableProperty {
+        comments,
         r#type: ty,
         attributes: state.get_attributes(),
+        var,
         entries,
         end,
     })
@@ -1,22 +1,28 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
 use crate::expect_token;
 use crate::expected_token_err;
-use crate::lexer::error::SyntaxError;
-use crate::lexer::token::DocStringIndentationKind;
+use crate::lexer::byte_string::ByteString;
+use crate::lexer::token::Span;
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::identifiers::Identifier;
 use crate::parser::ast::literals::Literal;
 use crate::parser::ast::literals::LiteralInteger;
 use crate::parser::ast::literals::LiteralString;
 use crate::parser::ast::operators::ArithmeticOperationExpression;
+use crate::parser::ast::variables::BracedVariableVariable;
 use crate::parser::ast::variables::Variable;
 use crate::parser::ast::ExpressionStringPart;
 use crate::parser::ast::LiteralStringPart;
 use crate::parser::ast::StringPart;
 use crate::parser::ast::{
-    ArrayIndexExpression, Expression, HeredocExpression, InterpolatedStringExpression,
-    NowdocExpression, NullsafePropertyFetchExpression, PropertyFetchExpression,
-    ShellExecExpression,
+    ArrayIndexExpression, ConstantFetchExpression, DynamicConstantFetchExpression, Expression,
+    HeredocExpression, InterpolatedStringExpression, NowdocExpression,
+    NullsafePropertyFetchExpression, PropertyFetchExpression, ShellExecExpression,
 };
+use crate::parser::deprecations::DeprecationKind;
+use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions::create;
 use crate::parser::internal::identifiers;
@@ -26,6 +32,8 @@ use crate::parser::state::State;
 
 #[inline(always)]
 pub fn interpolated(state: &mut State) -> ParseResult<Expression> {
+    // The opening quote is part of the first literal part.
+    let start = state.stream.current().span;
     let mut parts = Vec::new();
 
     while state.stream.current().kind != TokenKind::DoubleQuote {
@@ -34,15 +42,17 @@ pub fn interpolated(state: &mut State) -> ParseResult<Expression> {
         }
     }
 
+    let end = state.stream.current().span;
     state.stream.next();
 
     Ok(Expression::InterpolatedString(
-        InterpolatedStringExpression { parts },
+        InterpolatedStringExpression { start, parts, end },
     ))
 }
 
 #[inline(always)]
 pub fn shell_exec(state: &mut State) -> ParseResult<Expression> {
+    let start = state.stream.current().span;
     state.stream.next();
 
     let mut parts = Vec::new();
@@ -53,15 +63,20 @@ pub fn shell_exec(state: &mut State) -> ParseResult<Expression> {
         }
     }
 
+    let end = state.stream.current().span;
     state.stream.next();
 
-    Ok(Expression::ShellExec(ShellExecExpression { parts }))
+    Ok(Expression::ShellExec(ShellExecExpression {
+        start,
+        parts,
+        end,
+    }))
 }
 
 #[inline(always)]
 pub fn heredoc(state: &mut State) -> ParseResult<Expression> {
     let span = state.stream.current().span;
-    let label = state.stream.current().value.clone();
+    let opening = state.stream.current().value.clone();
     state.stream.next();
 
     let mut parts = Vec::new();
@@ -72,165 +87,49 @@ pub fn heredoc(state: &mut State) -> ParseResult<Expression> {
         }
     }
 
-    let (indentation_type, indentation_amount) = match &state.stream.current().kind {
-        TokenKind::EndDocString(indentation_type, indentation_amount) => {
-            (indentation_type.clone(), *indentation_amount)
-        }
-        _ => unreachable!(),
-    };
-
-    state.stream.next();
-
-    let mut new_line = true;
-    if indentation_type != DocStringIndentationKind::None {
-        let indentation_char: u8 = indentation_type.into();
-
-        for part in parts.iter_mut() {
-            // We only need to strip and validate indentation
-            // for individual lines, so we can skip checks if
-            // we know we're not on a new line.
-            if !new_line {
-                continue;
-            }
-
-            match part {
-                StringPart::Literal(LiteralStringPart { value: bytes }) => {
-                    // 1. If this line doesn't start with any whitespace,
-                    //    we can return an error early because we know
-                    //    the label was indented.
-                    if !bytes.starts_with(&[b' ']) && !bytes.starts_with(&[b'\t']) {
-                        return Err(SyntaxError::InvalidDocBodyIndentationLevel(
-                            indentation_amount,
-                            span,
-                        )
-                        .into());
-                    }
-
-                    // 2. If this line doesn't start with the correct
-                    //    type of whitespace, we can also return an error.
-                    if !bytes.starts_with(&[indentation_char]) {
-                        return Err(SyntaxError::InvalidDocIndentation(span).into());
-                    }
-
-                    // 3. We now know that the whitespace at the start of
-                    //    this line is correct, so we need to check that the
-                    //    amount of whitespace is correct too. In this case,
-                    //    the amount of whitespace just needs to be at least
-                    //    the same, so we can create a vector containing the
-                    //    minimum and check using `starts_with()`.
-                    let expected_whitespace_buffer = vec![indentation_char; indentation_amount];
-                    if !bytes.starts_with(&expected_whitespace_buffer) {
-                        return Err(SyntaxError::InvalidDocBodyIndentationLevel(
-                            indentation_amount,
-                            span,
-                        )
-                        .into());
-                    }
-
-                    // 4. All of the above checks have passed, so we know
-                    //    there are no more possible errors. Let's now
-                    //    strip the leading whitespace accordingly.
-                    *bytes = bytes
-                        .strip_prefix(&expected_whitespace_buffer[..])
-                        .unwrap()
-                        .into();
-                    new_line = bytes.ends_with(&[b'\n']);
-                }
-                _ => continue,
-            }
-        }
-    }
-
-    Ok(Expression::Heredoc(HeredocExpression { label, parts }))
+    // The lexer has already removed the indentation of the closing label
+    // from each line.
+    let end = state.stream.current().span;
+    expect_token!([TokenKind::EndDocString(_, _) => ()], state, "label");
+
+    Ok(Expression::Heredoc(HeredocExpression {
+        start: span,
+        opening,
+        parts,
+        end,
+    }))
 }
 
 #[inline(always)]
 pub fn nowdoc(state: &mut State) -> ParseResult<Expression> {
     let span = state.stream.current().span;
-    let label = state.stream.current().value.clone();
-
-    state.stream.next();
-
-    let mut string_part = state.stream.current().value.clone();
-    expect_token!([TokenKind::StringPart => ()], state, "constant string");
-
-    let (indentation_type, indentation_amount) = match &state.stream.current().kind {
-        TokenKind::EndDocString(indentation_type, indentation_amount) => {
-            (indentation_type.clone(), *indentation_amount)
-        }
-        _ => unreachable!(),
-    };
+    let opening = state.stream.current().value.clone();
 
     state.stream.next();
 
-    if indentation_type != DocStringIndentationKind::None {
-        let indentation_char: u8 = indentation_type.into();
-
-        let mut lines = string_part
-            .split(|b| *b == b'\n')
-            .map(|s| s.to_vec())
-            .collect::<Vec<Vec<u8>>>();
-
-        for line in lines.iter_mut() {
-            if line.is_empty() {
-                continue;
-            }
-
-            // 1. If this line doesn't start with any whitespace,
-            //    we can return an error early because we know
-            //    the label was indented.
-            if !line.starts_with(&[b' ']) && !line.starts_with(&[b'\t']) {
-                return Err(
-                    SyntaxError::InvalidDocBodyIndentationLevel(indentation_amount, span).into(),
-                );
-            }
-
-            // 2. If this line doesn't start with the correct
-            //    type of whitespace, we can also return an error.
-            if !line.starts_with(&[indentation_char]) {
-                return Err(SyntaxError::InvalidDocIndentation(span).into());
-            }
-
-            // 3. We now know that the whitespace at the start of
-            //    this line is correct, so we need to check that the
-            //    amount of whitespace is correct too. In this case,
-            //    the amount of whitespace just needs to be at least
-            //    the same, so we can create a vector containing the
-            //    minimum and check using `starts_with()`.
-            let expected_whitespace_buffer = vec![indentation_char; indentation_amount];
-            if !line.starts_with(&expected_whitespace_buffer) {
-                return Err(
-                    SyntaxError::InvalidDocBodyIndentationLevel(indentation_amount, span).into(),
-                );
-            }
-
-            // 4. All of the above checks have passed, so we know
-            //    there are no more possible errors. Let's now
-            //    strip the leading whitespace accordingly.
-            *line = line
-                .strip_prefix(&expected_whitespace_buffer[..])
-                .unwrap()
-                .into();
-        }
-
-        let mut bytes = Vec::new();
-        for (i, line) in lines.iter().enumerate() {
-            bytes.extend(line);
-            if i < lines.len() - 1 {
-                bytes.push(b'\n');
-            }
-        }
-        string_part = bytes.into();
+    // A nowdoc with nothing between its opening and closing has no body.
+    let mut string_part = ByteString::default();
+    if state.stream.current().kind == TokenKind::StringPart {
+        string_part = state.stream.current().value.clone();
+        state.stream.next();
     }
 
-    Ok(Expression::Nowdoc(NowdocExpression { label, value: string_part }))
+    let end = state.stream.current().span;
+    expect_token!([TokenKind::EndDocString(_, _) => ()], state, "label");
+
+    Ok(Expression::Nowdoc(NowdocExpression {
+        start: span,
+        opening,
+        value: string_part,
+        end,
+    }))
 }
 
 fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
     Ok(match &state.stream.current().kind {
         TokenKind::StringPart => {
             let s = state.stream.current().value.clone();
-            let part = if s.len() > 0 {
+            let part = if !s.is_empty() {
                 Some(StringPart::Literal(LiteralStringPart { value: s }))
             } else {
                 None
@@ -242,6 +141,15 @@ fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
         TokenKind::DollarLeftBrace => {
             let variable = variables::dynamic_variable(state)?;
 
+            if let Variable::BracedVariableVariable(BracedVariableVariable {
+                start,
+                variable: expression,
+                end,
+            }) = &variable
+            {
+                deprecate_dollar_brace(state, expression, *start, *end);
+            }
+
             Some(StringPart::Expression(ExpressionStringPart {
                 expression: Box::new(Expression::Variable(variable)),
             }))
@@ -250,6 +158,17 @@ fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
             // "{$expr}"
             state.stream.next();
             let e = create(state)?;
+
+            // "{$foo::BAR}" and "{$foo::{$bar}}" are not variables.
+            if let Expression::ConstantFetch(ConstantFetchExpression { double_colon, .. })
+            | Expression::DynamicConstantFetch(DynamicConstantFetchExpression {
+                double_colon,
+                ..
+            }) = &e
+            {
+                return Err(error::class_constant_in_interpolation(*double_colon));
+            }
+
             utils::skip_right_brace(state)?;
             Some(StringPart::Expression(ExpressionStringPart {
                 expression: Box::new(e),
@@ -302,6 +221,7 @@ fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
 
                             Expression::Literal(Literal::String(LiteralString {
                                 span: current.span,
+                                end: current.end,
                                 value: current.value.clone(),
                             }))
                         }
@@ -358,3 +278,21 @@ fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
         }
     })
 }
+
+// "${name}" and "${name[expr]}" name a variable, while "${expr}" is a variable
+// variable, which the engine warns about differently.
+fn deprecate_dollar_brace(state: &mut State, expression: &Expression, start: Span, end: Span) {
+    let name = match expression {
+        Expression::ArrayIndex(ArrayIndexExpression { array, .. }) => array,
+        expression => expression,
+    };
+
+    let kind = match name {
+        Expression::Identifier(Identifier::SimpleIdentifier(_)) => {
+            DeprecationKind::DollarBraceVariable
+        }
+        _ => DeprecationKind::DollarBraceExpression,
+    };
+
+    state.deprecate(kind, start, end);
+}
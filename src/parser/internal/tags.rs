@@ -0,0 +1,139 @@
+//! Opening and closing tags.
+//!
+//! Tags are only allowed between statements, where each of them becomes a
+//! statement of its own, wherever that statement is: at the top level, in a
+//! block, or in the body of a `case`. The printer needs them to tell PHP
+//! apart from the inline HTML around it.
+//!
+//! A closing tag also ends the statement before it, as a semicolon would, in
+//! which case it is that statement's ending rather than a statement. `<?=` is
+//! short for `<?php echo`, so the statement after it is the echo that it
+//! starts.
+//!
+//! Anywhere else, such as in the middle of an expression or between the
+//! members of a class, a tag is an error.
+
+use crate::lexer::token::OpenTagKind;
+use crate::lexer::token::TokenKind;
+use crate::parser::ast::ClosingTagStatement;
+use crate::parser::ast::EchoOpeningTagStatement;
+use crate::parser::ast::FullOpeningTagStatement;
+use crate::parser::ast::ShortOpeningTagStatement;
+use crate::parser::ast::Statement;
+use crate::parser::error;
+use crate::parser::error::ParseResult;
+use crate::parser::state::State;
+
+pub fn tag_statement(state: &mut State) -> ParseResult<Statement> {
+    let current = state.stream.current();
+    let span = current.span;
+
+    let statement = match current.kind {
+        TokenKind::OpenTag(OpenTagKind::Full) => {
+            Statement::FullOpeningTag(FullOpeningTagStatement { span })
+        }
+        TokenKind::OpenTag(OpenTagKind::Short) => {
+            Statement::ShortOpeningTag(ShortOpeningTagStatement { span })
+        }
+        TokenKind::OpenTag(OpenTagKind::Echo) => {
+            state.echo_tag = Some(span);
+
+            Statement::EchoOpeningTag(EchoOpeningTagStatement { span })
+        }
+        TokenKind::CloseTag => Statement::ClosingTag(ClosingTagStatement { span }),
+        _ => return Err(error::unexpected_token(vec![], current)),
+    };
+
+    state.stream.next();
+
+    Ok(statement)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use crate::parser::ast::BlockStatement;
+    use crate::parser::ast::Statement;
+    use crate::Error;
+
+    fn kinds(statements: &[Statement]) -> Vec<&'static str> {
+        statements
+            .iter()
+            .map(|statement| match statement {
+                Statement::FullOpeningTag(_) => "<?php",
+                Statement::ShortOpeningTag(_) => "<?",
+                Statement::EchoOpeningTag(_) => "<?=",
+                Statement::ClosingTag(_) => "?>",
+                Statement::InlineHtml(_) => "html",
+                Statement::Echo(_) => "echo",
+                _ => "statement",
+            })
+            .collect()
+    }
+
+    // The statements of the block that follows the opening tag.
+    fn block(code: &str) -> Vec<&'static str> {
+        let program = crate::parse(code).unwrap();
+
+        match &program[1] {
+            Statement::Block(BlockStatement { statements, .. }) => kinds(statements),
+            _ => panic!("expected a block"),
+        }
+    }
+
+    fn error(code: &str) -> String {
+        let Err(Error::Parse(stack)) = crate::parse(code) else {
+            panic!("expected a parse error");
+        };
+
+        stack.errors.first().unwrap().id.clone()
+    }
+
+    #[test]
+    fn test_tags_between_statements() {
+        assert_eq!(
+            kinds(&crate::parse("<?php echo 1; ?>a<?php echo 2;").unwrap()),
+            ["<?php", "echo", "?>", "html", "<?php", "echo"]
+        );
+        assert_eq!(
+            block("<?php { echo 1; ?>a<?php echo 2; }"),
+            ["echo", "?>", "html", "<?php", "echo"]
+        );
+        assert_eq!(
+            block("<?php { echo 1; ?>a<? echo 2; }"),
+            ["echo", "?>", "html", "<?", "echo"]
+        );
+        assert_eq!(block("<?php { ?><?php }"), ["?>", "<?php"]);
+    }
+
+    #[test]
+    fn test_closing_tag_ends_statement() {
+        assert_eq!(
+            kinds(&crate::parse("<?php echo 1 ?>a<?php echo 2 ?>").unwrap()),
+            ["<?php", "echo", "html", "<?php", "echo"]
+        );
+        assert_eq!(block("<?php { echo 1 ?><?php }"), ["echo", "<?php"]);
+    }
+
+    #[test]
+    fn test_echo_tag() {
+        assert_eq!(
+            block("<?php { ?><?= 1, 2 ?><?php }"),
+            ["?>", "<?=", "echo", "<?php"]
+        );
+    }
+
+    #[test]
+    fn test_tags_elsewhere() {
+        assert_eq!(error("<?php $a = ?><?php 1;"), "E069");
+        assert_eq!(error("<?php foo(1, ?><?php 2);"), "E069");
+        assert_eq!(error("<?php $a = [1, ?><?php 2];"), "E069");
+        assert_eq!(error("<?php match ($a) { ?><?php };"), "E069");
+        assert_eq!(error("<?php class A { ?><?php }"), "E069");
+        assert_eq!(error("<?php class A { public $a; ?><?php }"), "E069");
+        assert_eq!(error("<?php interface A { ?><?php }"), "E069");
+        assert_eq!(error("<?php enum A { ?><?php }"), "E069");
+    }
+}
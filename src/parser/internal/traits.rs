@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::expect_token;
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
@@ -12,6 +14,7 @@ use crate::parser::ast::Statement;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::attributes;
 use crate::parser::internal::constants;
+use crate::parser::internal::enums;
 use crate::parser::internal::functions::method;
 use crate::parser::internal::functions::Method;
 use crate::parser::internal::functions::MethodType;
@@ -20,9 +23,11 @@ use crate::parser::internal::modifiers;
 use crate::parser::internal::properties;
 use crate::parser::internal::utils;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 use crate::peek_token;
 
 pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
+    let comments = state.stream.comments();
     let span = utils::skip(state, TokenKind::Use)?;
 
     let mut traits = Vec::new();
@@ -51,7 +56,7 @@ pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
     }
 
     let mut adaptations = Vec::new();
-    if state.stream.current().kind == TokenKind::LeftBrace {
+    let end = if state.stream.current().kind == TokenKind::LeftBrace {
         utils::skip_left_brace(state)?;
 
         while state.stream.current().kind != TokenKind::RightBrace {
@@ -147,19 +152,22 @@ pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
             utils::skip_semicolon(state)?;
         }
 
-        utils::skip_right_brace(state)?;
+        utils::skip_right_brace(state)?
     } else {
-        utils::skip_semicolon(state)?;
-    }
+        utils::skip_semicolon(state)?
+    };
 
     Ok(TraitUsage {
+        comments,
         r#use: span,
         traits,
         adaptations,
+        end,
     })
 }
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let span = utils::skip(state, TokenKind::Trait)?;
     let name = identifiers::type_identifier(state)?;
     let attributes = state.get_attributes();
@@ -169,14 +177,27 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         members: {
             let mut members = Vec::new();
             while state.stream.current().kind != TokenKind::RightBrace && !state.stream.is_eof() {
+                if state.stream.current().kind == TokenKind::Case {
+                    enums::misplaced_case(state, "a trait", Some(&name))?;
+                    continue;
+                }
+
                 members.push(member(state, &name)?);
             }
+            members.extend(
+                state
+                    .stream
+                    .comments()
+                    .into_iter()
+                    .map(TraitMember::Comment),
+            );
             members
         },
         right_brace: utils::skip_right_brace(state)?,
     };
 
     Ok(Statement::Trait(TraitStatement {
+        comments,
         r#trait: span,
         name,
         attributes,
@@ -198,6 +219,9 @@ fn member(state: &mut State, class_name: &SimpleIdentifier) -> ParseResult<Trait
     let modifiers = modifiers::collect(state)?;
 
     if state.stream.current().kind == TokenKind::Const {
+        let keyword = state.stream.current().span;
+        state.require("constants in traits", PhpVersion::Php82, keyword, 5);
+
         return constants::classish(state, modifiers::constant_group(modifiers)?)
             .map(TraitMember::Constant);
     }
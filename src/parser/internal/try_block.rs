@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::try_block::CatchBlock;
 use crate::parser::ast::try_block::CatchType;
@@ -10,6 +12,7 @@ use crate::parser::internal::blocks;
 use crate::parser::internal::identifiers;
 use crate::parser::internal::utils;
 use crate::parser::state::State;
+use crate::parser::PhpVersion;
 
 use super::variables;
 
@@ -21,7 +24,8 @@ pub fn try_block(state: &mut State) -> ParseResult<Statement> {
 
     let body = blocks::multiple_statements_until(state, &TokenKind::RightBrace)?;
 
-    let last_right_brace = utils::skip_right_brace(state)?;
+    let mut end = utils::skip_right_brace(state)?;
+    let last_right_brace = end;
 
     let mut catches = Vec::new();
     loop {
@@ -36,6 +40,13 @@ pub fn try_block(state: &mut State) -> ParseResult<Statement> {
 
         let types = catch_type(state)?;
         let var = if state.stream.current().kind == TokenKind::RightParen {
+            state.require(
+                "catch blocks without a variable",
+                PhpVersion::Php80,
+                catch_start,
+                5,
+            );
+
             None
         } else {
             Some(variables::simple_variable(state)?)
@@ -46,9 +57,8 @@ pub fn try_block(state: &mut State) -> ParseResult<Statement> {
 
         let catch_body = blocks::multiple_statements_until(state, &TokenKind::RightBrace)?;
 
-        utils::skip_right_brace(state)?;
-
-        let catch_end = state.stream.current().span;
+        let catch_end = utils::skip_right_brace(state)?;
+        end = catch_end;
 
         catches.push(CatchBlock {
             start: catch_start,
@@ -67,8 +77,8 @@ pub fn try_block(state: &mut State) -> ParseResult<Statement> {
 
         let finally_body = blocks::multiple_statements_until(state, &TokenKind::RightBrace)?;
 
-        utils::skip_right_brace(state)?;
-        let finally_end = state.stream.current().span;
+        let finally_end = utils::skip_right_brace(state)?;
+        end = finally_end;
 
         finally = Some(FinallyBlock {
             start: finally_start,
@@ -81,8 +91,6 @@ pub fn try_block(state: &mut State) -> ParseResult<Statement> {
         return Err(error::try_without_catch_or_finally(start, last_right_brace));
     }
 
-    let end = state.stream.current().span;
-
     Ok(Statement::Try(TryStatement {
         start,
         end,
@@ -94,7 +102,7 @@ pub fn try_block(state: &mut State) -> ParseResult<Statement> {
 
 #[inline(always)]
 fn catch_type(state: &mut State) -> ParseResult<CatchType> {
-    let id = identifiers::full_name(state)?;
+    let id = identifiers::full_type_name(state)?;
 
     if state.stream.current().kind == TokenKind::Pipe {
         state.stream.next();
@@ -102,7 +110,7 @@ fn catch_type(state: &mut State) -> ParseResult<CatchType> {
         let mut types = vec![id];
 
         while !state.stream.is_eof() {
-            let id = identifiers::full_name(state)?;
+            let id = identifiers::full_type_name(state)?;
             types.push(id);
 
             if state.stream.current().kind != TokenKind::Pipe {
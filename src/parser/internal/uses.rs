@@ -1,9 +1,13 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::GroupUseStatement;
 use crate::parser::ast::Statement;
 use crate::parser::ast::Use;
 use crate::parser::ast::UseKind;
 use crate::parser::ast::UseStatement;
+use crate::parser::completion::ExpectedAt;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::identifiers;
@@ -11,7 +15,7 @@ use crate::parser::internal::utils;
 use crate::parser::state::State;
 
 pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
-    state.stream.next();
+    let r#use = utils::skip(state, TokenKind::Use)?;
 
     let kind = match state.stream.current().kind {
         TokenKind::Function => {
@@ -27,7 +31,16 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
 
     if state.stream.peek().kind == TokenKind::LeftBrace {
         let prefix = identifiers::full_name(state)?;
-        state.stream.next();
+        let left_brace = utils::skip_left_brace(state)?;
+
+        // A group holds at least one name, and may end with a comma.
+        if state.stream.current().kind == TokenKind::RightBrace {
+            return Err(error::unexpected_token(
+                vec!["an identifier".to_string()],
+                state.stream.current(),
+            ));
+        }
+
         let mut uses = Vec::new();
         while state.stream.current().kind != TokenKind::RightBrace {
             let use_kind = match state.stream.current().kind {
@@ -56,7 +69,12 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
                 _ => None,
             };
 
-            let name = identifiers::full_type_name(state)?;
+            let name = identifiers::full_type_name(state).map_err(|error| {
+                error.expecting(ExpectedAt::UsePath {
+                    kind: use_kind.clone().unwrap_or_else(|| kind.clone()),
+                    prefix: Some(prefix.clone()),
+                })
+            })?;
             let mut alias = None;
             if state.stream.current().kind == TokenKind::As {
                 state.stream.next();
@@ -71,22 +89,32 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
 
             if state.stream.current().kind == TokenKind::Comma {
                 state.stream.next();
-                continue;
+            } else {
+                break;
             }
         }
 
-        utils::skip_right_brace(state)?;
-        utils::skip_semicolon(state)?;
+        let right_brace = utils::skip_right_brace(state)?;
+        let semicolon = utils::skip_semicolon(state)?;
 
         Ok(Statement::GroupUse(GroupUseStatement {
+            r#use,
             prefix,
             kind,
+            left_brace,
             uses,
+            right_brace,
+            semicolon,
         }))
     } else {
         let mut uses = Vec::new();
-        while !state.stream.is_eof() {
-            let name = identifiers::full_type_name(state)?;
+        let semicolon = loop {
+            let name = identifiers::full_type_name(state).map_err(|error| {
+                error.expecting(ExpectedAt::UsePath {
+                    kind: kind.clone(),
+                    prefix: None,
+                })
+            })?;
             let mut alias = None;
             if state.stream.current().kind == TokenKind::As {
                 state.stream.next();
@@ -104,10 +132,14 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
                 continue;
             }
 
-            utils::skip_semicolon(state)?;
-            break;
-        }
+            break utils::skip_semicolon(state)?;
+        };
 
-        Ok(Statement::Use(UseStatement { uses, kind }))
+        Ok(Statement::Use(UseStatement {
+            r#use,
+            kind,
+            uses,
+            semicolon,
+        }))
     }
 }
@@ -1,3 +1,6 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
 use crate::lexer::token::Span;
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::utils::CommaSeparated;
@@ -107,7 +110,7 @@ pub fn skip_any_of(state: &mut State, kinds: &[TokenKind]) -> ParseResult<Span>
 /// and then skip the right parenthesis.
 pub fn parenthesized<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
 ) -> ParseResult<(Span, T, Span)> {
     let left_parenthesis = skip_left_parenthesis(state)?;
     let inner = func(state)?;
@@ -122,7 +125,7 @@ pub fn parenthesized<T>(
 /// and then skip the right brace.
 pub fn braced<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
 ) -> ParseResult<(Span, T, Span)> {
     let left_brace = skip_left_brace(state)?;
     let inner = func(state)?;
@@ -133,7 +136,7 @@ pub fn braced<T>(
 
 pub fn semicolon_terminated<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
 ) -> ParseResult<(Span, T)> {
     let inner = func(state)?;
     let semicolon = skip_semicolon(state)?;
@@ -143,7 +146,7 @@ pub fn semicolon_terminated<T>(
 /// Parse a comma-separated list of items, allowing a trailing comma.
 pub fn comma_separated<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
     until: TokenKind,
 ) -> ParseResult<CommaSeparated<T>> {
     let mut inner: Vec<T> = vec![];
@@ -171,7 +174,7 @@ pub fn comma_separated<T>(
 /// Parse a comma-separated list of items, not allowing trailing commas.
 pub fn comma_separated_no_trailing<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
     until: TokenKind,
 ) -> ParseResult<CommaSeparated<T>> {
     let mut inner: Vec<T> = vec![];
@@ -205,7 +208,7 @@ pub fn comma_separated_no_trailing<T>(
 /// Parse a comma-separated list of items, requiring at least one item, and not allowing trailing commas.
 pub fn at_least_one_comma_separated_no_trailing<T>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
 ) -> ParseResult<CommaSeparated<T>> {
     let mut inner: Vec<T> = vec![];
     let mut commas: Vec<Span> = vec![];
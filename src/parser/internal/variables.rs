@@ -1,9 +1,13 @@
+use alloc::boxed::Box;
+
+use crate::expected_token;
 use crate::expected_token_err;
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::variables::BracedVariableVariable;
 use crate::parser::ast::variables::SimpleVariable;
 use crate::parser::ast::variables::Variable;
 use crate::parser::ast::variables::VariableVariable;
+use crate::parser::completion::ExpectedAt;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
 use crate::parser::internal::utils;
@@ -19,7 +23,7 @@ pub fn simple_variable(state: &mut State) -> ParseResult<SimpleVariable> {
         return Ok(SimpleVariable { span, name });
     }
 
-    expected_token_err!("a variable", state)
+    Err(expected_token!("a variable", state).expecting(ExpectedAt::Variable))
 }
 
 pub fn dynamic_variable(state: &mut State) -> ParseResult<Variable> {
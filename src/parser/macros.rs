@@ -79,6 +79,7 @@ macro_rules! expect_literal {
                 $crate::parser::ast::literals::Literal::String(
                     $crate::parser::ast::literals::LiteralString {
                         span: current.span,
+                        end: current.end,
                         value: current.value.clone(),
                     },
                 )
@@ -1,14 +1,36 @@
+// `parse` and `construct` never panic, whatever the input: invariants that
+// turn out to be broken are reported as `Error::Internal` instead. Any
+// exception to these lints must be allowed where it occurs, with the reason
+// it cannot panic.
+#![deny(
+    clippy::indexing_slicing,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::todo,
+    clippy::unimplemented,
+    clippy::unreachable
+)]
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::error::Error;
 use crate::expect_literal;
-use crate::lexer::token::OpenTagKind;
+use crate::lexer::token::Span;
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
+use crate::lexer::trivia::ConcreteToken;
+use crate::lexer::trivia::Trivia;
 use crate::lexer::Lexer;
+use crate::parser::ast::declares;
 use crate::parser::ast::declares::DeclareBody;
 use crate::parser::ast::declares::DeclareEntry;
 use crate::parser::ast::declares::DeclareEntryGroup;
 use crate::parser::ast::declares::DeclareStatement;
 use crate::parser::ast::variables::Variable;
-use crate::parser::ast::{Program, Statement, StaticVar};
+use crate::parser::ast::{Block, Program, Statement, StaticVar};
+use crate::parser::deprecations::Deprecation;
 use crate::parser::error::ParseErrorStack;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::attributes;
@@ -23,6 +45,7 @@ use crate::parser::internal::identifiers;
 use crate::parser::internal::interfaces;
 use crate::parser::internal::loops;
 use crate::parser::internal::namespaces;
+use crate::parser::internal::tags;
 use crate::parser::internal::traits;
 use crate::parser::internal::try_block;
 use crate::parser::internal::uses;
@@ -32,58 +55,204 @@ use crate::parser::state::State;
 
 pub use crate::lexer::stream::TokenStream;
 
-use self::ast::ClosingTagStatement;
-use self::ast::EchoOpeningTagStatement;
 use self::ast::EchoStatement;
+use self::ast::Expression;
 use self::ast::ExpressionStatement;
-use self::ast::FullOpeningTagStatement;
 use self::ast::GlobalStatement;
 use self::ast::HaltCompilerStatement;
 use self::ast::InlineHtmlStatement;
 use self::ast::ReturnStatement;
-use self::ast::ShortOpeningTagStatement;
 use self::ast::StaticStatement;
+use self::ast::ThrowExpression;
 use self::internal::precedences::Precedence;
 
 pub mod ast;
+pub mod completion;
+pub mod deprecations;
 pub mod error;
 
 mod expressions;
 mod internal;
 mod macros;
+mod options;
 mod state;
 
-pub fn parse<B: ?Sized + AsRef<[u8]>>(input: &B) -> Result<Program, ParseErrorStack> {
-    let lexer = Lexer::new();
+pub use self::options::ParserOptions;
+pub use self::options::PhpVersion;
+
+pub fn parse<B: ?Sized + AsRef<[u8]>>(input: &B) -> Result<Program, Error> {
+    let tokens = Lexer::new().tokenize(input)?;
+
+    construct(&tokens)
+}
+
+/// Parses the source for the given options, such as the version of PHP it
+/// targets, telling errors found while splitting it into tokens apart from
+/// those found while parsing them.
+pub fn parse_with_options<B: ?Sized + AsRef<[u8]>>(
+    input: &B,
+    options: &ParserOptions,
+) -> Result<Program, Error> {
+    parse_with_deprecations(input, options).0
+}
+
+/// Parses the source like [`parse_with_options`], also returning the syntax
+/// in it that is deprecated in the version of PHP the options target, such as
+/// `"${name}"` from PHP 8.2 on.
+///
+/// The deprecations found before the parser gave up are returned along with
+/// its errors.
+///
+/// # Example
+///
+/// ```
+/// use php_parser_rs::parser::deprecations::DeprecationKind;
+/// use php_parser_rs::parser::{parse_with_deprecations, ParserOptions};
+///
+/// let (program, deprecations) =
+///     parse_with_deprecations("<?php echo \"${name}\";", &ParserOptions::default());
+///
+/// assert!(program.is_ok());
+/// assert_eq!(deprecations[0].kind, DeprecationKind::DollarBraceVariable);
+/// ```
+pub fn parse_with_deprecations<B: ?Sized + AsRef<[u8]>>(
+    input: &B,
+    options: &ParserOptions,
+) -> (Result<Program, Error>, Vec<Deprecation>) {
+    let lexer = match &options.cancellation {
+        Some(cancellation) => Lexer::with_cancellation(cancellation.clone()),
+        None => Lexer::new(),
+    };
+
     let tokens = match lexer.tokenize(input) {
         Ok(tokens) => tokens,
-        Err(error) => {
-            return Err(ParseErrorStack {
-                errors: vec![error.into()],
-                partial: Vec::new(),
-            })
-        }
+        Err(error) => return (Err(error.into()), Vec::new()),
     };
 
-    construct(&tokens)
+    let mut stream = TokenStream::new(&tokens);
+    let mut state = State::new(&mut stream, options.clone());
+
+    let program = program(&mut state);
+    let deprecations = core::mem::take(&mut state.deprecations);
+
+    (finish(state, program), deprecations)
+}
+
+/// Parses the source, just like [`parse`].
+pub fn parse_str(source: &str) -> Result<Program, Error> {
+    parse(source)
+}
+
+/// A program along with the tokens it was parsed from, trivia included, so
+/// that it can be printed back exactly as it was written.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LosslessProgram {
+    pub program: Program,
+    pub tokens: Vec<ConcreteToken>,
 }
 
-pub fn construct(tokens: &[Token]) -> Result<Program, ParseErrorStack> {
+impl LosslessProgram {
+    /// The token that starts at `span`, such as the first token of a node.
+    pub fn token(&self, span: Span) -> Option<&ConcreteToken> {
+        self.tokens
+            .binary_search_by_key(&span.position, |token| token.token.span.position)
+            .ok()
+            .and_then(|index| self.tokens.get(index))
+    }
+}
+
+/// Parses the source, keeping the whitespace and comments around every token.
+///
+/// The program is the same as the one that [`parse`] returns.
+///
+/// # Example
+///
+/// ```
+/// use php_parser_rs::parser::parse_lossless;
+/// use php_parser_rs::printer::print_exact;
+///
+/// let code = "<?php\n\nif($a){echo   1;} // done\n";
+/// let program = parse_lossless(code).unwrap();
+///
+/// assert_eq!(print_exact(&program), code.as_bytes());
+/// ```
+pub fn parse_lossless<B: ?Sized + AsRef<[u8]>>(input: &B) -> Result<LosslessProgram, Error> {
+    let tokens = Lexer::new().tokenize_lossless(input)?;
+
+    let mut stream = Vec::with_capacity(tokens.len());
+    for token in &tokens {
+        stream.extend(token.leading.iter().filter_map(Trivia::token));
+        stream.push(token.token.clone());
+        stream.extend(token.trailing.iter().filter_map(Trivia::token));
+    }
+
+    Ok(LosslessProgram {
+        program: construct(&stream)?,
+        tokens,
+    })
+}
+
+/// Reads and parses the file at `path`.
+#[cfg(feature = "std")]
+pub fn parse_file<P: AsRef<std::path::Path>>(path: P) -> Result<Program, Error> {
+    let contents = std::fs::read(path)?;
+
+    parse(&contents)
+}
+
+pub fn construct(tokens: &[Token]) -> Result<Program, Error> {
+    construct_with_options(tokens, &ParserOptions::default())
+}
+
+/// Parses the tokens for the given options. A parse that is cancelled fails
+/// with [`Error::Cancelled`], leaving out whatever it found so far.
+pub fn construct_with_options(tokens: &[Token], options: &ParserOptions) -> Result<Program, Error> {
     let mut stream = TokenStream::new(tokens);
-    let mut state = State::new(&mut stream);
+    let mut state = State::new(&mut stream, options.clone());
 
-    let mut program = Program::new();
+    let program = program(&mut state);
+
+    finish(state, program)
+}
+
+// A parse that was cancelled or that found the parser broken gave up early,
+// so its errors are left out.
+fn finish(state: State, program: Result<Program, ParseErrorStack>) -> Result<Program, Error> {
+    if state.cancelled {
+        return Err(Error::Cancelled);
+    }
+
+    if let Some(detail) = state.internal {
+        return Err(Error::Internal { detail });
+    }
+
+    Ok(program?)
+}
+
+fn program(state: &mut State) -> Result<Program, ParseErrorStack> {
+    let mut program = Block::new();
 
     while !state.stream.is_eof() {
-        let statement = match top_level_statement(&mut state) {
+        blocks::comments(state, &mut program);
+
+        let statement = match top_level_statement(state) {
             Ok(statement) => statement,
             Err(error) => {
-                let mut previous = state.errors;
+                let before = state.stream.before(error.span);
+
+                // A `=>` that nothing around a `yield from` took is its key.
+                let error = if state.yield_from_arrow == Some(error.span) {
+                    error::yield_from_cannot_have_a_key(error.span)
+                } else {
+                    error.confusable_before(before)
+                };
+
+                let mut previous = core::mem::take(&mut state.errors);
                 previous.push(error);
 
                 return Err(ParseErrorStack {
                     errors: previous,
-                    partial: program,
+                    partial: program.into(),
                 });
             }
         };
@@ -91,15 +260,18 @@ pub fn construct(tokens: &[Token]) -> Result<Program, ParseErrorStack> {
         program.push(statement);
     }
 
-    let errors = state.errors;
+    blocks::remaining_comments(state, &mut program);
+    enums::instantiations(state);
+
+    let errors = core::mem::take(&mut state.errors);
     if !errors.is_empty() {
         return Err(ParseErrorStack {
             errors,
-            partial: program,
+            partial: program.into(),
         });
     }
 
-    Ok(program.to_vec())
+    Ok(program.into())
 }
 
 fn top_level_statement(state: &mut State) -> ParseResult<Statement> {
@@ -108,9 +280,10 @@ fn top_level_statement(state: &mut State) -> ParseResult<Statement> {
         TokenKind::Use => uses::use_statement(state)?,
         TokenKind::Const => Statement::Constant(constants::parse(state)?),
         TokenKind::HaltCompiler => {
+            let span = state.stream.current().span;
             state.stream.next();
 
-            let content = if let TokenKind::InlineHtml = state.stream.current().kind.clone() {
+            let content = if let TokenKind::InlineHtml = state.stream.current().kind {
                 let content = state.stream.current().value.clone();
                 state.stream.next();
                 Some(content)
@@ -118,7 +291,7 @@ fn top_level_statement(state: &mut State) -> ParseResult<Statement> {
                 None
             };
 
-            Statement::HaltCompiler(HaltCompilerStatement { content })
+            Statement::HaltCompiler(HaltCompilerStatement { span, content })
         }
         _ => statement(state)?,
     };
@@ -127,6 +300,17 @@ fn top_level_statement(state: &mut State) -> ParseResult<Statement> {
 }
 
 fn statement(state: &mut State) -> ParseResult<Statement> {
+    state.check_cancellation(1)?;
+
+    // `<?= $a, $b ?>` is short for `<?php echo $a, $b ?>`.
+    if let Some(echo) = state.echo_tag.take() {
+        return Ok(Statement::Echo(EchoStatement {
+            echo,
+            values: echo_values(state)?,
+            ending: utils::skip_ending(state)?,
+        }));
+    }
+
     let has_attributes = attributes::gather_attributes(state)?;
 
     let current = state.stream.current();
@@ -173,30 +357,7 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
         }
     } else {
         match &current.kind {
-            TokenKind::OpenTag(OpenTagKind::Echo) => {
-                let span = current.span;
-                state.stream.next();
-
-                Statement::EchoOpeningTag(EchoOpeningTagStatement { span })
-            }
-            TokenKind::OpenTag(OpenTagKind::Full) => {
-                let span = current.span;
-                state.stream.next();
-
-                Statement::FullOpeningTag(FullOpeningTagStatement { span })
-            }
-            TokenKind::OpenTag(OpenTagKind::Short) => {
-                let span = current.span;
-                state.stream.next();
-
-                Statement::ShortOpeningTag(ShortOpeningTagStatement { span })
-            }
-            TokenKind::CloseTag => {
-                let span = current.span;
-                state.stream.next();
-
-                Statement::ClosingTag(ClosingTagStatement { span })
-            }
+            TokenKind::OpenTag(_) | TokenKind::CloseTag => tags::tag_statement(state)?,
             TokenKind::Abstract => classes::parse(state)?,
             TokenKind::Readonly if peek.kind != TokenKind::LeftParen => classes::parse(state)?,
             TokenKind::Final => classes::parse(state)?,
@@ -248,6 +409,12 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                         let span = utils::skip(state, TokenKind::Equals)?;
                         let value = expect_literal!(state);
 
+                        if key.value.eq_ignore_ascii_case(b"strict_types")
+                            && !declares::is_strict_types_value(&value)
+                        {
+                            state.record(error::invalid_strict_types_value(&value));
+                        }
+
                         entries.push(DeclareEntry {
                             key,
                             equals: span,
@@ -269,7 +436,7 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                     }
                 };
 
-                let body = match state.stream.current().kind.clone() {
+                let body = match state.stream.current().kind {
                     TokenKind::SemiColon => {
                         let span = utils::skip_semicolon(state)?;
 
@@ -291,15 +458,12 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                         let start = utils::skip_colon(state)?;
                         let statements =
                             blocks::multiple_statements_until(state, &TokenKind::EndDeclare)?;
-                        let end = (
-                            utils::skip(state, TokenKind::EndDeclare)?,
-                            utils::skip_semicolon(state)?,
-                        );
 
                         DeclareBody::Block {
                             colon: start,
                             statements,
-                            end,
+                            enddeclare: utils::skip(state, TokenKind::EndDeclare)?,
+                            ending: utils::skip_ending(state)?,
                         }
                     }
                     _ => {
@@ -323,61 +487,69 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                 let span = current.span;
                 state.stream.next();
 
-                let mut variables = vec![];
-                // `loop` instead of `while` as we don't allow for extra commas.
-                loop {
-                    variables.push(variables::dynamic_variable(state)?);
-
-                    if state.stream.current().kind == TokenKind::Comma {
-                        state.stream.next();
-                    } else {
-                        break;
+                let variables = utils::at_least_one_comma_separated_no_trailing(state, &|state| {
+                    let variable = variables::dynamic_variable(state)?;
+
+                    // `global $a->b;`, `global $a[0];` and `global Foo::$a;`
+                    let current = state.stream.current();
+                    if matches!(
+                        current.kind,
+                        TokenKind::Arrow
+                            | TokenKind::QuestionArrow
+                            | TokenKind::LeftBracket
+                            | TokenKind::DoubleColon
+                    ) {
+                        return Err(error::global_non_simple_variable(current));
                     }
-                }
 
-                utils::skip_semicolon(state)?;
+                    Ok(variable)
+                })?;
+
+                let semicolon = utils::skip_semicolon(state)?;
                 Statement::Global(GlobalStatement {
                     global: span,
                     variables,
+                    semicolon,
                 })
             }
             TokenKind::Static if matches!(peek.kind, TokenKind::Variable) => {
+                let r#static = current.span;
                 state.stream.next();
 
-                let mut vars = vec![];
-
-                // `loop` instead of `while` as we don't allow for extra commas.
-                loop {
+                // The default of a static variable can be any expression, as
+                // it is evaluated when the function is first called.
+                let vars = utils::at_least_one_comma_separated_no_trailing(state, &|state| {
                     let var = variables::simple_variable(state)?;
                     let mut default = None;
 
                     if state.stream.current().kind == TokenKind::Equals {
                         state.stream.next();
 
-                        default = Some(expressions::create(state)?);
+                        let value = expressions::create(state)?;
+                        expressions::require_new_in_initializer(state, &value);
+                        default = Some(value);
                     }
 
-                    vars.push(StaticVar {
+                    Ok(StaticVar {
                         var: Variable::SimpleVariable(var),
                         default,
-                    });
-
-                    if state.stream.current().kind == TokenKind::Comma {
-                        state.stream.next();
-                    } else {
-                        break;
-                    }
-                }
+                    })
+                })?;
 
-                utils::skip_semicolon(state)?;
+                let semicolon = utils::skip_semicolon(state)?;
 
-                Statement::Static(StaticStatement { vars })
+                Statement::Static(StaticStatement {
+                    r#static,
+                    vars,
+                    semicolon,
+                })
             }
             TokenKind::InlineHtml => {
+                let span = state.stream.current().span;
                 let html = state.stream.current().value.clone();
                 state.stream.next();
 
-                Statement::InlineHtml(InlineHtmlStatement { html })
+                Statement::InlineHtml(InlineHtmlStatement { span, html })
             }
             TokenKind::Do => loops::do_while_statement(state)?,
             TokenKind::While => loops::while_statement(state)?,
@@ -399,20 +571,9 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
             TokenKind::Echo => {
                 state.stream.next();
 
-                let mut values = Vec::new();
-                loop {
-                    values.push(expressions::create(state)?);
-
-                    if state.stream.current().kind == TokenKind::Comma {
-                        state.stream.next();
-                    } else {
-                        break;
-                    }
-                }
-
                 Statement::Echo(EchoStatement {
                     echo: current.span,
-                    values,
+                    values: echo_values(state)?,
                     ending: utils::skip_ending(state)?,
                 })
             }
@@ -434,6 +595,19 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                     ending: utils::skip_ending(state)?,
                 })
             }
+            // `throw` was a statement before it became an expression in PHP
+            // 8.0, so it is available to every version here.
+            TokenKind::Throw => {
+                state.stream.next();
+
+                Statement::Expression(ExpressionStatement {
+                    expression: Expression::Throw(ThrowExpression {
+                        throw: current.span,
+                        value: Box::new(expressions::create(state)?),
+                    }),
+                    ending: utils::skip_ending(state)?,
+                })
+            }
             _ => Statement::Expression(ExpressionStatement {
                 expression: expressions::create(state)?,
                 ending: utils::skip_ending(state)?,
@@ -443,3 +617,162 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
 
     Ok(statement)
 }
+
+fn echo_values(state: &mut State) -> ParseResult<Vec<Expression>> {
+    let mut values = Vec::new();
+    loop {
+        values.push(expressions::create(state)?);
+
+        if state.stream.current().kind == TokenKind::Comma {
+            state.stream.next();
+        } else {
+            break;
+        }
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::token::TokenKind;
+
+    #[test]
+    fn test_construct_without_tokens() {
+        assert_eq!(construct(&[]).unwrap(), Program::new());
+    }
+
+    #[test]
+    fn test_construct_without_eof_token() {
+        let tokens = Lexer::new().tokenize("<?php echo 1;").unwrap();
+        let tokens = tokens
+            .into_iter()
+            .filter(|token| token.kind != TokenKind::Eof)
+            .collect::<Vec<_>>();
+
+        assert!(construct(&tokens).is_ok());
+    }
+
+    #[test]
+    fn test_parse_source() {
+        let program = parse("<?php function foo() { return 1; }").unwrap();
+
+        assert!(matches!(
+            program.as_slice(),
+            [Statement::FullOpeningTag(_), Statement::Function(_)]
+        ));
+    }
+
+    #[test]
+    fn test_parse_source_with_syntax_error() {
+        assert!(matches!(parse("<?php echo 'foo"), Err(Error::Lex(_))));
+
+        let Err(Error::Parse(stack)) = parse("<?php echo 'foo';;)") else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(stack.errors.len(), 1);
+        assert_eq!(stack.errors[0].id, "E003");
+    }
+
+    #[test]
+    fn test_internal_error() {
+        let tokens = Lexer::new().tokenize("<?php echo 1;").unwrap();
+        let mut stream = TokenStream::new(&tokens);
+        let mut state = State::new(&mut stream, ParserOptions::default());
+
+        let error = error::internal_error(&mut state, "broken", Span::new(1, 1, 0));
+        let program = Err(ParseErrorStack {
+            partial: Program::new(),
+            errors: vec![error],
+        });
+
+        assert!(matches!(
+            finish(state, program),
+            Err(Error::Internal { detail }) if detail == "broken"
+        ));
+    }
+
+    #[test]
+    fn test_parse_file() {
+        let program = parse_file("tests/fixtures/0001/code.php").unwrap();
+
+        assert_eq!(
+            program,
+            parse(include_str!("../../tests/fixtures/0001/code.php")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_file() {
+        assert!(matches!(
+            parse_file("tests/fixtures/missing.php"),
+            Err(Error::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_remaining_tokens() {
+        let tokens = Lexer::new()
+            .tokenize("<?php echo 1; $a = 2; foo();")
+            .unwrap();
+        let mut stream = TokenStream::new(&tokens);
+        let mut state = State::new(&mut stream, ParserOptions::default());
+
+        // The opening tag, `echo 1;` and `$a = 2;`.
+        for _ in 0..3 {
+            top_level_statement(&mut state).unwrap();
+        }
+
+        assert_eq!(state.stream.peek().kind, TokenKind::LeftParen);
+
+        let remaining = state
+            .stream
+            .remaining()
+            .iter()
+            .map(|token| token.kind)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            remaining,
+            [
+                TokenKind::Identifier,
+                TokenKind::LeftParen,
+                TokenKind::RightParen,
+                TokenKind::SemiColon,
+                TokenKind::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_doc_string_labels() {
+        let program = parse("<?php <<<\"SQL2\"\n  SELECT 1\n  SQL2;\n<<<'EOT'\nEOT;").unwrap();
+
+        let Statement::Expression(ExpressionStatement {
+            expression: Expression::Heredoc(heredoc),
+            ..
+        }) = &program[1]
+        else {
+            panic!("expected a heredoc");
+        };
+
+        assert_eq!(heredoc.opening, b"<<<\"SQL2\"");
+        assert_eq!(heredoc.label(), b"SQL2");
+        assert_eq!((heredoc.start.line, heredoc.start.column), (1, 7));
+        assert_eq!((heredoc.end.line, heredoc.end.column), (3, 3));
+
+        let Statement::Expression(ExpressionStatement {
+            expression: Expression::Nowdoc(nowdoc),
+            ..
+        }) = &program[2]
+        else {
+            panic!("expected a nowdoc");
+        };
+
+        assert_eq!(nowdoc.label(), b"EOT");
+        assert!(nowdoc.value.is_empty());
+        assert_eq!((nowdoc.end.line, nowdoc.end.column), (5, 1));
+    }
+}
@@ -0,0 +1,340 @@
+use core::fmt::{Display, Formatter};
+
+use serde::Serialize;
+
+use crate::cancellation::CancellationToken;
+
+/// A version of PHP that code can be parsed for.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize)]
+pub enum PhpVersion {
+    #[serde(rename = "7.4")]
+    Php74,
+    #[serde(rename = "8.0")]
+    Php80,
+    #[serde(rename = "8.1")]
+    Php81,
+    #[serde(rename = "8.2")]
+    Php82,
+    #[serde(rename = "8.3")]
+    Php83,
+    #[default]
+    #[serde(rename = "8.4")]
+    Php84,
+}
+
+impl Display for PhpVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let version = match self {
+            Self::Php74 => "7.4",
+            Self::Php80 => "8.0",
+            Self::Php81 => "8.1",
+            Self::Php82 => "8.2",
+            Self::Php83 => "8.3",
+            Self::Php84 => "8.4",
+        };
+
+        write!(f, "{}", version)
+    }
+}
+
+/// How code is parsed.
+///
+/// # Example
+///
+/// ```
+/// use php_parser_rs::parser::{parse_with_options, ParserOptions, PhpVersion};
+///
+/// let options = ParserOptions {
+///     version: PhpVersion::Php80,
+///     ..ParserOptions::default()
+/// };
+///
+/// assert!(parse_with_options("<?php enum Suit {}", &options).is_err());
+/// assert!(parse_with_options("<?php match ($a) { default => 1 };", &options).is_ok());
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct ParserOptions {
+    /// The version of PHP the code targets. Syntax added in a later version
+    /// is reported as an error, as the engine of that version would.
+    pub version: PhpVersion,
+    /// Once cancelled, the source is given up on, and the parse fails with
+    /// [`Error::Cancelled`](crate::Error::Cancelled) soon after.
+    pub cancellation: Option<CancellationToken>,
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::parser::parse_with_options;
+    use crate::Error;
+
+    fn errors(code: &str, version: PhpVersion) -> Vec<String> {
+        let options = ParserOptions {
+            version,
+            ..ParserOptions::default()
+        };
+
+        match parse_with_options(code, &options) {
+            Ok(_) => Vec::new(),
+            Err(Error::Parse(stack)) => stack
+                .errors
+                .into_iter()
+                .map(|error| error.message)
+                .collect(),
+            Err(error) => panic!("unexpected error: {}", error),
+        }
+    }
+
+    #[test]
+    fn test_features_by_version() {
+        let features = [
+            ("<?php #[Pure] function foo() {}", "attributes"),
+            ("<?php match ($a) { default => 1 };", "match expressions"),
+            ("<?php $a?->b; $a?->c();", "nullsafe operators"),
+            ("<?php foo(bar: 1);", "named arguments"),
+            (
+                "<?php class A { function __construct(private $a) {} }",
+                "promoted properties",
+            ),
+            ("<?php enum Suit {}", "enums"),
+            (
+                "<?php class A { public readonly int $a; }",
+                "readonly properties",
+            ),
+            ("<?php strlen(...);", "first-class callables"),
+            ("<?php function foo(int|string $a) {}", "union types"),
+            ("<?php function foo(): mixed {}", "`mixed` types"),
+            ("<?php $x = throw new E();", "throw expressions"),
+            (
+                "<?php class A { function foo(): static {} }",
+                "`static` return types",
+            ),
+            (
+                "<?php try {} catch (E) {}",
+                "catch blocks without a variable",
+            ),
+            ("<?php function foo(A&B $a) {}", "intersection types"),
+            ("<?php function foo(): never {}", "`never` types"),
+            (
+                "<?php function foo($a = new Foo()) {}",
+                "`new` expressions in initializers",
+            ),
+            ("<?php $a = 0o17;", "explicit octal integer literals"),
+        ];
+
+        for (code, feature) in features {
+            let found = errors(code, PhpVersion::Php74);
+
+            assert!(!found.is_empty(), "{}", code);
+            assert!(
+                found
+                    .iter()
+                    .all(|error| *error == format!("{} are not available in PHP 7.4", feature)),
+                "{}: {:?}",
+                code,
+                found
+            );
+
+            assert_eq!(errors(code, PhpVersion::Php81), Vec::<String>::new());
+        }
+    }
+
+    #[test]
+    fn test_php81_features() {
+        let code = "<?php function foo(A&B $a = new Foo()): never { static $b = new Bar(); } const C = 0o17;";
+
+        assert_eq!(
+            errors(code, PhpVersion::Php80),
+            [
+                "intersection types are not available in PHP 8.0",
+                "`new` expressions in initializers are not available in PHP 8.0",
+                "`never` types are not available in PHP 8.0",
+                "`new` expressions in initializers are not available in PHP 8.0",
+                "explicit octal integer literals are not available in PHP 8.0"
+            ]
+        );
+        assert_eq!(errors(code, PhpVersion::Php81), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_later_features() {
+        let code = "<?php function foo(): null {} function bar(): false {} function baz(): true {}";
+
+        assert_eq!(
+            errors(code, PhpVersion::Php81),
+            [
+                "standalone `null` types are not available in PHP 8.1",
+                "standalone `false` types are not available in PHP 8.1",
+                "`true` types are not available in PHP 8.1"
+            ]
+        );
+        assert_eq!(errors(code, PhpVersion::Php82), Vec::<String>::new());
+
+        // `null` and `false` could be part of a union before.
+        let code = "<?php function foo(): int|null|false {}";
+
+        assert_eq!(errors(code, PhpVersion::Php80), Vec::<String>::new());
+
+        // `throw` was a statement before it was an expression.
+        let code = "<?php if ($a) throw new E(); throw $e;";
+
+        assert_eq!(errors(code, PhpVersion::Php74), Vec::<String>::new());
+
+        let code = "<?php readonly class A { const int B = 1; }";
+
+        assert_eq!(
+            errors(code, PhpVersion::Php81),
+            [
+                "readonly classes are not available in PHP 8.1",
+                "typed class constants are not available in PHP 8.1"
+            ]
+        );
+        assert_eq!(
+            errors(code, PhpVersion::Php82),
+            ["typed class constants are not available in PHP 8.2"]
+        );
+        assert!(crate::parse(code).is_ok());
+
+        let code = "<?php function foo((A&B)|null $a): A|(B&C) {}";
+
+        assert_eq!(
+            errors(code, PhpVersion::Php81),
+            [
+                "disjunctive normal form types are not available in PHP 8.1",
+                "disjunctive normal form types are not available in PHP 8.1"
+            ]
+        );
+        assert_eq!(errors(code, PhpVersion::Php82), Vec::<String>::new());
+
+        let code = "<?php trait T { const A = 1; final protected const B = 2; }";
+
+        assert_eq!(
+            errors(code, PhpVersion::Php81),
+            [
+                "constants in traits are not available in PHP 8.1",
+                "constants in traits are not available in PHP 8.1"
+            ]
+        );
+        assert_eq!(errors(code, PhpVersion::Php82), Vec::<String>::new());
+
+        let code = "<?php new readonly class {};";
+
+        assert_eq!(
+            errors(code, PhpVersion::Php82),
+            ["readonly anonymous classes are not available in PHP 8.2"]
+        );
+        assert_eq!(errors(code, PhpVersion::Php83), Vec::<String>::new());
+
+        let code = "<?php Foo::{$a}::{$b}; Foo::{'bar'}();";
+
+        assert_eq!(
+            errors(code, PhpVersion::Php82),
+            [
+                "dynamic class constant fetches are not available in PHP 8.2",
+                "dynamic class constant fetches are not available in PHP 8.2"
+            ]
+        );
+        assert_eq!(errors(code, PhpVersion::Php83), Vec::<String>::new());
+
+        let code =
+            "<?php class A { public $a { get => 1; } function __construct(public $b { set; }) {} }";
+
+        assert_eq!(
+            errors(code, PhpVersion::Php83),
+            [
+                "property hooks are not available in PHP 8.3",
+                "property hooks are not available in PHP 8.3"
+            ]
+        );
+        assert_eq!(errors(code, PhpVersion::Php84), Vec::<String>::new());
+
+        let code = "<?php class A { public private(set) int $a; function __construct(protected(set) int $b) {} }";
+
+        assert_eq!(
+            errors(code, PhpVersion::Php83),
+            [
+                "asymmetric visibility modifiers are not available in PHP 8.3",
+                "asymmetric visibility modifiers are not available in PHP 8.3"
+            ]
+        );
+        assert_eq!(errors(code, PhpVersion::Php84), Vec::<String>::new());
+
+        let code = "<?php $a = exit(...); die(status: 1); exit(...$b);";
+
+        assert_eq!(
+            errors(code, PhpVersion::Php83),
+            [
+                "first-class callables of `exit` and `die` are not available in PHP 8.3",
+                "named arguments to `exit` and `die` are not available in PHP 8.3",
+                "unpacked arguments to `exit` and `die` are not available in PHP 8.3"
+            ]
+        );
+        assert_eq!(errors(code, PhpVersion::Php84), Vec::<String>::new());
+
+        let code = "<?php new Foo()->bar(); new class {}::$a; (new Foo)->bar();";
+
+        assert_eq!(
+            errors(code, PhpVersion::Php83),
+            [
+                "dereferenced `new` expressions without parentheses are not available in PHP 8.3",
+                "dereferenced `new` expressions without parentheses are not available in PHP 8.3"
+            ]
+        );
+        assert_eq!(errors(code, PhpVersion::Php84), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_cancellation() {
+        use crate::lexer::stream::TokenStream;
+        use crate::lexer::Lexer;
+        use crate::parser::construct_with_options;
+        use crate::parser::finish;
+        use crate::parser::program;
+        use crate::parser::state::State;
+
+        let code = alloc::format!("<?php\n{}", "echo $a + 1;\n".repeat(100));
+        let tokens = Lexer::new().tokenize(&code).unwrap();
+
+        // Cancelled before the source is split into tokens, and before the
+        // tokens are parsed.
+        let token = CancellationToken::new();
+        token.cancel();
+        let options = ParserOptions {
+            cancellation: Some(token),
+            ..ParserOptions::default()
+        };
+
+        assert!(matches!(
+            parse_with_options(&code, &options),
+            Err(Error::Cancelled)
+        ));
+        assert!(matches!(
+            construct_with_options(&tokens, &options),
+            Err(Error::Cancelled)
+        ));
+
+        // Cancelled while the tokens are parsed, once the first 10 statements,
+        // one to a line, are parsed.
+        let options = ParserOptions {
+            cancellation: Some(CancellationToken::new()),
+            ..ParserOptions::default()
+        };
+
+        let mut stream = TokenStream::new(&tokens);
+        let mut state = State::new(&mut stream, options);
+        state.on_check = Some(|state| {
+            if state.stream.current().span.line > 11 {
+                if let Some(cancellation) = &state.options.cancellation {
+                    cancellation.cancel();
+                }
+            }
+        });
+
+        let result = program(&mut state);
+        assert_eq!(state.stream.current().span.line, 12);
+        assert!(matches!(finish(state, result), Err(Error::Cancelled)));
+    }
+}
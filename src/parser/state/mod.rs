@@ -1,10 +1,20 @@
-use std::collections::VecDeque;
-use std::fmt::Display;
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::Display;
 
 use crate::lexer::stream::TokenStream;
+use crate::lexer::token::Span;
 use crate::parser::ast::attributes::AttributeGroup;
 use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::deprecations::Deprecation;
+use crate::parser::deprecations::DeprecationKind;
+use crate::parser::error;
 use crate::parser::error::ParseError;
+use crate::parser::error::ParseResult;
+use crate::parser::options::ParserOptions;
+use crate::parser::options::PhpVersion;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum NamespaceType {
@@ -16,6 +26,11 @@ pub enum NamespaceType {
 pub enum Scope {
     Namespace(SimpleIdentifier),
     BracedNamespace(Option<SimpleIdentifier>),
+    /// The body of a function, closure or method, which `break` and `continue`
+    /// cannot leave.
+    Function,
+    Loop,
+    Switch,
 }
 
 #[derive(Debug)]
@@ -25,16 +40,49 @@ pub struct State<'a> {
     pub attributes: Vec<AttributeGroup>,
     pub namespace_type: Option<NamespaceType>,
     pub errors: Vec<ParseError>,
+    /// The span of an `<?=` tag whose values are yet to be parsed.
+    pub echo_tag: Option<Span>,
+    pub options: ParserOptions,
+    /// Whether the parse gave up as its cancellation token was cancelled.
+    pub cancelled: bool,
+    /// What went wrong, if the parse gave up as it found one of its own
+    /// invariants broken.
+    pub internal: Option<String>,
+    /// The number of cancellation checks so far.
+    pub checks: usize,
+    /// The enums declared so far, by their qualified name.
+    pub enums: Vec<(String, SimpleIdentifier)>,
+    /// The classes instantiated by name so far, by their qualified name.
+    pub instantiations: Vec<(String, SimpleIdentifier)>,
+    /// The deprecated syntax found so far.
+    pub deprecations: Vec<Deprecation>,
+    /// The last `=>` found right after a `yield from`.
+    pub yield_from_arrow: Option<Span>,
+    /// Called on every cancellation check, so that a test can cancel the
+    /// parse at a known point in the source.
+    #[cfg(test)]
+    pub on_check: Option<fn(&State)>,
 }
 
 impl<'a> State<'a> {
-    pub fn new(tokens: &'a mut TokenStream<'a>) -> Self {
+    pub fn new(tokens: &'a mut TokenStream<'a>, options: ParserOptions) -> Self {
         Self {
             stack: VecDeque::with_capacity(32),
             stream: tokens,
             namespace_type: None,
             attributes: vec![],
             errors: vec![],
+            echo_tag: None,
+            options,
+            cancelled: false,
+            internal: None,
+            checks: 0,
+            enums: vec![],
+            instantiations: vec![],
+            deprecations: vec![],
+            yield_from_arrow: None,
+            #[cfg(test)]
+            on_check: None,
         }
     }
 
@@ -45,7 +93,7 @@ impl<'a> State<'a> {
     pub fn get_attributes(&mut self) -> Vec<AttributeGroup> {
         let mut attributes = vec![];
 
-        std::mem::swap(&mut self.attributes, &mut attributes);
+        core::mem::swap(&mut self.attributes, &mut attributes);
 
         attributes
     }
@@ -54,6 +102,48 @@ impl<'a> State<'a> {
         self.errors.push(error);
     }
 
+    /// Fails once the cancellation token of the parse is cancelled, looking
+    /// at the token only once every `interval` checks.
+    pub fn check_cancellation(&mut self, interval: usize) -> ParseResult<()> {
+        #[cfg(test)]
+        if let Some(on_check) = self.on_check {
+            on_check(self);
+        }
+
+        let Some(cancellation) = &self.options.cancellation else {
+            return Ok(());
+        };
+
+        self.checks = self.checks.wrapping_add(1);
+        if self.checks.is_multiple_of(interval) && cancellation.is_cancelled() {
+            self.cancelled = true;
+
+            return Err(error::cancelled(self.stream.current().span));
+        }
+
+        Ok(())
+    }
+
+    /// Records an error when the syntax of `length` bytes at `span` was added
+    /// in a later version of PHP than the one the code targets.
+    pub fn require(&mut self, feature: &str, required: PhpVersion, span: Span, length: usize) {
+        let current = self.options.version;
+
+        if current < required {
+            self.record(error::feature_not_available(
+                feature, required, current, span, length,
+            ));
+        }
+    }
+
+    /// Records the deprecated syntax from `start` to `end`, unless the code
+    /// targets a version of PHP from before it was deprecated.
+    pub fn deprecate(&mut self, kind: DeprecationKind, start: Span, end: Span) {
+        if self.options.version >= kind.since() {
+            self.deprecations.push(Deprecation { kind, start, end });
+        }
+    }
+
     /// Return the namespace type used in the current state
     ///
     /// The namespace type is retrieve from the last entered
@@ -66,7 +156,20 @@ impl<'a> State<'a> {
     }
 
     pub fn namespace(&self) -> Option<&Scope> {
-        self.stack.iter().next()
+        self.stack
+            .iter()
+            .next()
+            .filter(|scope| matches!(scope, Scope::Namespace(_) | Scope::BracedNamespace(_)))
+    }
+
+    /// Returns the loops and `switch` statements that a `break` or `continue`
+    /// at the current position can target, innermost first.
+    pub fn breakable(&self) -> impl Iterator<Item = &Scope> {
+        self.stack
+            .iter()
+            .rev()
+            .take_while(|scope| **scope != Scope::Function)
+            .filter(|scope| matches!(scope, Scope::Loop | Scope::Switch))
     }
 
     pub fn named<T: Display + ?Sized>(&self, name: &T) -> String {
@@ -78,6 +181,19 @@ impl<'a> State<'a> {
         }
     }
 
+    /// The fully qualified name that a class name refers to, in lowercase
+    /// as class names are case-insensitive.
+    ///
+    /// Names are not resolved against the imports of the namespace.
+    pub fn qualified(&self, name: &SimpleIdentifier) -> String {
+        let name = match name.value.strip_prefix(b"\\") {
+            Some(name) => String::from_utf8_lossy(name).into_owned(),
+            None => self.named(name),
+        };
+
+        name.to_ascii_lowercase()
+    }
+
     pub fn enter(&mut self, scope: Scope) {
         match &scope {
             Scope::Namespace(_) => {
@@ -86,6 +202,7 @@ impl<'a> State<'a> {
             Scope::BracedNamespace(_) => {
                 self.namespace_type = Some(NamespaceType::Braced);
             }
+            Scope::Function | Scope::Loop | Scope::Switch => {}
         }
 
         self.stack.push_back(scope);
@@ -0,0 +1,766 @@
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::parser::ast::arguments::Argument;
+use crate::parser::ast::arguments::ArgumentList;
+use crate::parser::ast::arguments::ArgumentPlaceholder;
+use crate::parser::ast::arguments::SingleArgument;
+use crate::parser::ast::classes::AnonymousClassExpression;
+use crate::parser::ast::classes::AnonymousClassMember;
+use crate::parser::ast::functions::ClosureUseVariable;
+use crate::parser::ast::identifiers::Identifier;
+use crate::parser::ast::literals::Literal;
+use crate::parser::ast::operators::ArithmeticOperationExpression;
+use crate::parser::ast::operators::BitwiseOperationExpression;
+use crate::parser::ast::operators::ComparisonOperationExpression;
+use crate::parser::ast::operators::LogicalOperationExpression;
+use crate::parser::ast::utils::CommaSeparated;
+use crate::parser::ast::variables::Variable;
+use crate::parser::ast::ArrayIndexExpression;
+use crate::parser::ast::ArrayItem;
+use crate::parser::ast::CastKind;
+use crate::parser::ast::Expression;
+use crate::parser::ast::ListEntry;
+use crate::parser::ast::MagicConstantExpression;
+use crate::parser::ast::StringPart;
+use crate::printer::Printer;
+
+impl Printer {
+    pub(super) fn expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Eval(eval) => {
+                self.write("eval");
+                self.single_argument(&eval.argument);
+            }
+            Expression::Empty(empty) => {
+                self.write("empty");
+                self.single_argument(&empty.argument);
+            }
+            Expression::Die(die) => {
+                self.write("die");
+                if let Some(argument) = &die.argument {
+                    self.single_argument(argument);
+                }
+            }
+            Expression::Exit(exit) => {
+                self.write("exit");
+                if let Some(argument) = &exit.argument {
+                    self.single_argument(argument);
+                }
+            }
+            Expression::Isset(isset) => {
+                self.write("isset");
+                self.arguments(&isset.arguments);
+            }
+            Expression::Unset(unset) => {
+                self.write("unset");
+                self.arguments(&unset.arguments);
+            }
+            Expression::Print(print) => {
+                self.write("print ");
+                self.expression(&print.value);
+            }
+            Expression::Literal(literal) => self.literal(literal),
+            Expression::ArithmeticOperation(operation) => {
+                let symbol = operation.symbol();
+                match operation {
+                    ArithmeticOperationExpression::Addition { left, right, .. }
+                    | ArithmeticOperationExpression::Subtraction { left, right, .. }
+                    | ArithmeticOperationExpression::Multiplication { left, right, .. }
+                    | ArithmeticOperationExpression::Division { left, right, .. }
+                    | ArithmeticOperationExpression::Modulo { left, right, .. }
+                    | ArithmeticOperationExpression::Exponentiation { left, right, .. } => {
+                        self.binary(left, symbol, right)
+                    }
+                    ArithmeticOperationExpression::Negative { right, .. }
+                    | ArithmeticOperationExpression::Positive { right, .. }
+                    | ArithmeticOperationExpression::PreIncrement { right, .. }
+                    | ArithmeticOperationExpression::PreDecrement { right, .. } => {
+                        self.prefix(symbol, right)
+                    }
+                    ArithmeticOperationExpression::PostIncrement { left, .. }
+                    | ArithmeticOperationExpression::PostDecrement { left, .. } => {
+                        self.expression(left);
+                        self.write(symbol);
+                    }
+                }
+            }
+            Expression::AssignmentOperation(operation) => {
+                self.binary(operation.left(), operation.symbol(), operation.right())
+            }
+            Expression::BitwiseOperation(operation) => {
+                let symbol = operation.symbol();
+                match operation {
+                    BitwiseOperationExpression::And { left, right, .. }
+                    | BitwiseOperationExpression::Or { left, right, .. }
+                    | BitwiseOperationExpression::Xor { left, right, .. }
+                    | BitwiseOperationExpression::LeftShift { left, right, .. }
+                    | BitwiseOperationExpression::RightShift { left, right, .. } => {
+                        self.binary(left, symbol, right)
+                    }
+                    BitwiseOperationExpression::Not { right, .. } => self.prefix(symbol, right),
+                }
+            }
+            Expression::ComparisonOperation(operation) => {
+                let symbol = operation.symbol();
+                match operation {
+                    ComparisonOperationExpression::Equal { left, right, .. }
+                    | ComparisonOperationExpression::Identical { left, right, .. }
+                    | ComparisonOperationExpression::NotEqual { left, right, .. }
+                    | ComparisonOperationExpression::AngledNotEqual { left, right, .. }
+                    | ComparisonOperationExpression::NotIdentical { left, right, .. }
+                    | ComparisonOperationExpression::LessThan { left, right, .. }
+                    | ComparisonOperationExpression::GreaterThan { left, right, .. }
+                    | ComparisonOperationExpression::LessThanOrEqual { left, right, .. }
+                    | ComparisonOperationExpression::GreaterThanOrEqual { left, right, .. }
+                    | ComparisonOperationExpression::Spaceship { left, right, .. } => {
+                        self.binary(left, symbol, right)
+                    }
+                }
+            }
+            Expression::LogicalOperation(operation) => {
+                let symbol = operation.symbol();
+                match operation {
+                    LogicalOperationExpression::And { left, right, .. }
+                    | LogicalOperationExpression::Or { left, right, .. }
+                    | LogicalOperationExpression::LogicalAnd { left, right, .. }
+                    | LogicalOperationExpression::LogicalOr { left, right, .. }
+                    | LogicalOperationExpression::LogicalXor { left, right, .. } => {
+                        self.binary(left, symbol, right)
+                    }
+                    LogicalOperationExpression::Not { right, .. } => self.prefix(symbol, right),
+                }
+            }
+            Expression::Concat(concat) => self.binary(&concat.left, ".", &concat.right),
+            Expression::Instanceof(instanceof) => {
+                self.binary(&instanceof.left, "instanceof", &instanceof.right)
+            }
+            Expression::Reference(reference) => {
+                self.write("&");
+                self.expression(&reference.right);
+            }
+            Expression::Parenthesized(parenthesized) => {
+                self.write("(");
+                self.expression(&parenthesized.expr);
+                self.write(")");
+            }
+            Expression::ErrorSuppress(suppress) => {
+                self.write("@");
+                self.expression(&suppress.expr);
+            }
+            Expression::Identifier(identifier) => self.identifier(identifier),
+            Expression::Variable(variable) => self.variable(variable),
+            Expression::Include(include) => {
+                self.write("include ");
+                self.expression(&include.path);
+            }
+            Expression::IncludeOnce(include) => {
+                self.write("include_once ");
+                self.expression(&include.path);
+            }
+            Expression::Require(require) => {
+                self.write("require ");
+                self.expression(&require.path);
+            }
+            Expression::RequireOnce(require) => {
+                self.write("require_once ");
+                self.expression(&require.path);
+            }
+            Expression::FunctionCall(call) => {
+                self.expression(&call.target);
+                self.arguments(&call.arguments);
+            }
+            Expression::FunctionClosureCreation(creation) => {
+                self.expression(&creation.target);
+                self.placeholder(&creation.placeholder);
+            }
+            Expression::MethodCall(call) => {
+                self.expression(&call.target);
+                self.write("->");
+                self.expression(&call.method);
+                self.arguments(&call.arguments);
+            }
+            Expression::MethodClosureCreation(creation) => {
+                self.expression(&creation.target);
+                self.write("->");
+                self.expression(&creation.method);
+                self.placeholder(&creation.placeholder);
+            }
+            Expression::NullsafeMethodCall(call) => {
+                self.expression(&call.target);
+                self.write("?->");
+                self.expression(&call.method);
+                self.arguments(&call.arguments);
+            }
+            Expression::StaticMethodCall(call) => {
+                self.expression(&call.target);
+                self.write("::");
+                self.identifier(&call.method);
+                self.arguments(&call.arguments);
+            }
+            Expression::StaticVariableMethodCall(call) => {
+                self.expression(&call.target);
+                self.write("::");
+                self.variable(&call.method);
+                self.arguments(&call.arguments);
+            }
+            Expression::StaticMethodClosureCreation(creation) => {
+                self.expression(&creation.target);
+                self.write("::");
+                self.identifier(&creation.method);
+                self.placeholder(&creation.placeholder);
+            }
+            Expression::StaticVariableMethodClosureCreation(creation) => {
+                self.expression(&creation.target);
+                self.write("::");
+                self.variable(&creation.method);
+                self.placeholder(&creation.placeholder);
+            }
+            Expression::PropertyFetch(fetch) => {
+                self.expression(&fetch.target);
+                self.write("->");
+                self.expression(&fetch.property);
+            }
+            Expression::NullsafePropertyFetch(fetch) => {
+                self.expression(&fetch.target);
+                self.write("?->");
+                self.expression(&fetch.property);
+            }
+            Expression::StaticPropertyFetch(fetch) => {
+                self.expression(&fetch.target);
+                self.write("::");
+                self.variable(&fetch.property);
+            }
+            Expression::ConstantFetch(fetch) => {
+                self.expression(&fetch.target);
+                self.write("::");
+                self.identifier(&fetch.constant);
+            }
+            Expression::DynamicConstantFetch(fetch) => {
+                self.expression(&fetch.target);
+                self.write("::{");
+                self.expression(&fetch.expr);
+                self.write("}");
+            }
+            Expression::Static(_) => self.write("static"),
+            Expression::Self_(_) => self.write("self"),
+            Expression::Parent(_) => self.write("parent"),
+            Expression::ShortArray(array) => {
+                self.write("[");
+                self.comma_separated(&array.items, Printer::array_item);
+                self.write("]");
+            }
+            Expression::Array(array) => {
+                self.write("array(");
+                self.comma_separated(&array.items, Printer::array_item);
+                self.write(")");
+            }
+            Expression::List(list) => {
+                self.write("list(");
+                self.separated(&list.items, ", ", Printer::list_entry);
+                self.write(")");
+            }
+            Expression::Closure(closure) => {
+                self.attributes_and_comments(&closure.attributes, &closure.comments, true);
+                if closure.r#static.is_some() {
+                    self.write("static ");
+                }
+                self.write("function ");
+                if closure.ampersand.is_some() {
+                    self.write("&");
+                }
+                self.parameters(&closure.parameters);
+                if let Some(uses) = &closure.uses {
+                    self.write(" use ");
+                    self.comments(&uses.comments);
+                    self.write("(");
+                    self.comma_separated(&uses.variables, Printer::closure_use_variable);
+                    self.write(")");
+                }
+                self.return_type(&closure.return_type);
+                self.write(" ");
+                self.comments(&closure.body.comments);
+                self.block(&closure.body.statements);
+            }
+            Expression::ArrowFunction(function) => {
+                self.attributes_and_comments(&function.attributes, &function.comments, true);
+                if function.r#static.is_some() {
+                    self.write("static ");
+                }
+                self.write("fn ");
+                if function.ampersand.is_some() {
+                    self.write("&");
+                }
+                self.parameters(&function.parameters);
+                self.return_type(&function.return_type);
+                self.write(" => ");
+                self.expression(&function.body);
+            }
+            Expression::New(new) => {
+                self.write("new ");
+                match new.target.as_ref() {
+                    // The arguments of an anonymous class go between `class`
+                    // and the rest of its declaration.
+                    Expression::AnonymousClass(class) => {
+                        self.anonymous_class(class, new.arguments.as_ref())
+                    }
+                    target => {
+                        self.expression(target);
+                        if let Some(arguments) = &new.arguments {
+                            self.arguments(arguments);
+                        }
+                    }
+                }
+            }
+            Expression::InterpolatedString(string) => {
+                self.string_parts(&string.parts, Some('"'));
+                self.write("\"");
+            }
+            Expression::Heredoc(heredoc) => {
+                self.write_bytes(&heredoc.opening);
+                self.newline_at_start();
+                if !heredoc.parts.is_empty() {
+                    self.string_parts(&heredoc.parts, None);
+                    self.newline_at_start();
+                }
+                self.write_bytes(heredoc.label());
+            }
+            Expression::Nowdoc(nowdoc) => {
+                self.write_bytes(&nowdoc.opening);
+                self.newline_at_start();
+                if !nowdoc.value.is_empty() {
+                    self.write_bytes(&nowdoc.value);
+                    self.newline_at_start();
+                }
+                self.write_bytes(nowdoc.label());
+            }
+            Expression::ShellExec(shell) => {
+                self.write("`");
+                self.string_parts(&shell.parts, Some('`'));
+                self.write("`");
+            }
+            Expression::AnonymousClass(class) => self.anonymous_class(class, None),
+            Expression::Bool(bool) => self.write(if bool.value { "true" } else { "false" }),
+            Expression::ArrayIndex(index) => {
+                self.expression(&index.array);
+                self.write("[");
+                if let Some(index) = &index.index {
+                    self.expression(index);
+                }
+                self.write("]");
+            }
+            Expression::Null(_) => self.write("null"),
+            Expression::MagicConstant(constant) => self.write(match constant {
+                MagicConstantExpression::Directory(_) => "__DIR__",
+                MagicConstantExpression::File(_) => "__FILE__",
+                MagicConstantExpression::Line(_) => "__LINE__",
+                MagicConstantExpression::Class(_) => "__CLASS__",
+                MagicConstantExpression::Function(_) => "__FUNCTION__",
+                MagicConstantExpression::Method(_) => "__METHOD__",
+                MagicConstantExpression::Namespace(_) => "__NAMESPACE__",
+                MagicConstantExpression::Trait(_) => "__TRAIT__",
+                MagicConstantExpression::CompilerHaltOffset(_) => "__COMPILER_HALT_OFFSET__",
+            }),
+            Expression::ShortTernary(ternary) => {
+                self.binary(&ternary.condition, "?:", &ternary.r#else)
+            }
+            Expression::Ternary(ternary) => {
+                self.expression(&ternary.condition);
+                self.write(" ? ");
+                self.expression(&ternary.then);
+                self.write(" : ");
+                self.expression(&ternary.r#else);
+            }
+            Expression::Coalesce(coalesce) => self.binary(&coalesce.lhs, "??", &coalesce.rhs),
+            Expression::Clone(clone) => {
+                self.write("clone ");
+                self.expression(&clone.target);
+            }
+            Expression::Match(r#match) => {
+                self.write("match (");
+                self.expression(&r#match.condition);
+                self.write(") {");
+                self.indent += 1;
+                for arm in &r#match.arms {
+                    self.newline();
+                    self.comments(&arm.comments);
+                    self.separated(&arm.conditions, ", ", Printer::expression);
+                    self.write(" => ");
+                    self.expression(&arm.body);
+                    self.write(",");
+                }
+                if let Some(default) = &r#match.default {
+                    self.newline();
+                    self.comments(&default.comments);
+                    self.write("default => ");
+                    self.expression(&default.body);
+                    self.write(",");
+                }
+                self.indent -= 1;
+                self.newline();
+                self.write("}");
+            }
+            Expression::Throw(throw) => {
+                self.write("throw ");
+                self.expression(&throw.value);
+            }
+            Expression::Yield(r#yield) => {
+                self.write("yield");
+                if let Some(key) = &r#yield.key {
+                    self.write(" ");
+                    self.expression(key);
+                    self.write(" =>");
+                }
+                if let Some(value) = &r#yield.value {
+                    self.write(" ");
+                    self.expression(value);
+                }
+            }
+            Expression::YieldFrom(r#yield) => {
+                self.write("yield from ");
+                self.expression(&r#yield.value);
+            }
+            Expression::Cast(cast) => {
+                self.write(match cast.kind {
+                    CastKind::Int => "(int) ",
+                    CastKind::Bool => "(bool) ",
+                    CastKind::Float => "(float) ",
+                    CastKind::String => "(string) ",
+                    CastKind::Array => "(array) ",
+                    CastKind::Object => "(object) ",
+                    CastKind::Unset => "(unset) ",
+                });
+                self.expression(&cast.value);
+            }
+            Expression::Noop(_) => {}
+        }
+    }
+
+    fn binary(&mut self, left: &Expression, operator: &str, right: &Expression) {
+        self.expression(left);
+        self.write(" ");
+        self.write(operator);
+        self.write(" ");
+        self.expression(right);
+    }
+
+    // A space keeps `- -$a` and `+ ++$a` from being read as `--` and `++`.
+    fn prefix(&mut self, operator: &str, right: &Expression) {
+        self.write(operator);
+
+        if let Expression::ArithmeticOperation(
+            ArithmeticOperationExpression::Negative { .. }
+            | ArithmeticOperationExpression::Positive { .. }
+            | ArithmeticOperationExpression::PreIncrement { .. }
+            | ArithmeticOperationExpression::PreDecrement { .. },
+        ) = right
+        {
+            self.write(" ");
+        }
+
+        self.expression(right);
+    }
+
+    pub(super) fn literal(&mut self, literal: &Literal) {
+        match literal {
+            Literal::String(string) => self.string(&string.value),
+            Literal::Integer(integer) => self.write_bytes(&integer.value),
+            Literal::Float(float) => self.write_bytes(&float.value),
+        }
+    }
+
+    // The value of a string literal is decoded, but keeps its quotes and any
+    // `b` prefix, which tell how to escape it again. A value without quotes is
+    // the key of an array access inside of an interpolated string, as in
+    // `"$a[key]"`, and is printed as it is.
+    fn string(&mut self, value: &[u8]) {
+        let (prefix, rest) = match value.first() {
+            Some(b'b' | b'B') => value.split_at(1),
+            _ => value.split_at(0),
+        };
+
+        match (rest.first(), rest.len()) {
+            (Some(&quote @ (b'\'' | b'"')), 2..) => {
+                let inner = rest.get(1..rest.len() - 1).unwrap_or_default();
+
+                self.write_bytes(prefix);
+                self.write_bytes(&[quote]);
+                if quote == b'\'' {
+                    // A backslash only needs escaping before another one, a
+                    // quote, or the closing quote.
+                    let mut escaped = Vec::with_capacity(inner.len());
+                    for (index, &byte) in inner.iter().enumerate() {
+                        let next = inner.get(index + 1);
+                        if byte == b'\''
+                            || (byte == b'\\' && matches!(next, None | Some(b'\\' | b'\'')))
+                        {
+                            escaped.push(b'\\');
+                        }
+                        escaped.push(byte);
+                    }
+                    self.write_bytes(&escaped);
+                } else {
+                    self.escaped(inner, Some('"'));
+                }
+                self.write_bytes(&[quote]);
+            }
+            _ => self.write_bytes(value),
+        }
+    }
+
+    // Escapes the literal text of a double-quoted string, heredoc or shell
+    // command, whose closing `quote`, if any, needs escaping too. Bytes that are
+    // not valid UTF-8 are written as `\x` escapes, and so are control
+    // characters outside of heredocs, which span lines anyway.
+    fn escaped(&mut self, bytes: &[u8], quote: Option<char>) {
+        for chunk in bytes.utf8_chunks() {
+            let mut characters = chunk.valid().chars().peekable();
+            let mut previous = None;
+            while let Some(character) = characters.next() {
+                // A `$` only needs escaping where it would start a variable or
+                // an expression.
+                let starts_variable = character == '$'
+                    && (previous == Some('{')
+                        || characters.peek().is_none_or(|next| {
+                            *next == '{'
+                                || *next == '_'
+                                || !next.is_ascii()
+                                || next.is_ascii_alphabetic()
+                        }));
+
+                match character {
+                    '\\' => self.write("\\\\"),
+                    '$' if starts_variable => self.write("\\$"),
+                    character if Some(character) == quote => {
+                        self.output.push('\\');
+                        self.output.push(character);
+                    }
+                    character if quote.is_some() && character.is_ascii_control() => match character
+                    {
+                        '\n' => self.write("\\n"),
+                        '\t' => self.write("\\t"),
+                        '\r' => self.write("\\r"),
+                        '\x0b' => self.write("\\v"),
+                        '\x0c' => self.write("\\f"),
+                        '\x1b' => self.write("\\e"),
+                        character => {
+                            let _ = write!(self.output, "\\x{:02x}", character as u32);
+                        }
+                    },
+                    character => self.output.push(character),
+                }
+
+                previous = Some(character);
+            }
+
+            for byte in chunk.invalid() {
+                let _ = write!(self.output, "\\x{:02x}", byte);
+            }
+        }
+    }
+
+    fn string_parts(&mut self, parts: &[StringPart], quote: Option<char>) {
+        for (index, part) in parts.iter().enumerate() {
+            match part {
+                StringPart::Literal(literal) => {
+                    let mut value = literal.value.as_slice();
+
+                    // The opening quote, and any `b` prefix, are part of the
+                    // first literal of an interpolated string.
+                    if index == 0 && quote == Some('"') {
+                        if let Some(position) = value.iter().position(|byte| *byte == b'"') {
+                            let (prefix, rest) = value.split_at(position + 1);
+                            self.write_bytes(prefix);
+                            value = rest;
+                        }
+                    }
+
+                    self.escaped(value, quote);
+                }
+                StringPart::Expression(part) => match part.expression.as_ref() {
+                    Expression::ArrayIndex(index) if is_simple_array_index(index) => {
+                        self.expression(&part.expression)
+                    }
+                    Expression::Variable(Variable::BracedVariableVariable(_)) => {
+                        self.expression(&part.expression)
+                    }
+                    expression => {
+                        self.write("{");
+                        self.expression(expression);
+                        self.write("}");
+                    }
+                },
+            }
+        }
+    }
+
+    // Heredocs and nowdocs have to start their lines at the first column, as
+    // their closing label sets the indentation to remove from each line.
+    fn newline_at_start(&mut self) {
+        self.output.push('\n');
+    }
+
+    fn anonymous_class(
+        &mut self,
+        class: &AnonymousClassExpression,
+        arguments: Option<&ArgumentList>,
+    ) {
+        self.attributes(&class.attributes, true);
+        if class.readonly.is_some() {
+            self.write("readonly ");
+        }
+        self.write("class");
+        if let Some(arguments) = arguments {
+            self.arguments(arguments);
+        }
+        self.extends_and_implements(&class.extends, &class.implements);
+        self.write(" ");
+        self.members(
+            &class.body.members,
+            Printer::anonymous_class_member,
+            |member| {
+                matches!(
+                    member,
+                    AnonymousClassMember::ConcreteMethod(_)
+                        | AnonymousClassMember::ConcreteConstructor(_)
+                )
+            },
+        );
+    }
+
+    pub(super) fn identifier(&mut self, identifier: &Identifier) {
+        match identifier {
+            Identifier::SimpleIdentifier(identifier) => self.write_bytes(&identifier.value),
+            Identifier::DynamicIdentifier(identifier) => {
+                self.write("{");
+                self.expression(&identifier.expr);
+                self.write("}");
+            }
+        }
+    }
+
+    pub(super) fn variable(&mut self, variable: &Variable) {
+        match variable {
+            Variable::SimpleVariable(variable) => self.write_bytes(&variable.name),
+            Variable::VariableVariable(variable) => {
+                self.write("$");
+                self.variable(&variable.variable);
+            }
+            Variable::BracedVariableVariable(variable) => {
+                self.write("${");
+                self.expression(&variable.variable);
+                self.write("}");
+            }
+        }
+    }
+
+    pub(super) fn arguments(&mut self, arguments: &ArgumentList) {
+        self.comments(&arguments.comments);
+        self.write("(");
+        self.separated(&arguments.arguments, ", ", Printer::argument);
+        self.write(")");
+    }
+
+    fn single_argument(&mut self, argument: &SingleArgument) {
+        self.comments(&argument.comments);
+        self.write("(");
+        self.argument(&argument.argument);
+        self.write(")");
+    }
+
+    fn placeholder(&mut self, placeholder: &ArgumentPlaceholder) {
+        self.write("(...)");
+        self.trailing_comments(&placeholder.comments);
+    }
+
+    fn argument(&mut self, argument: &Argument) {
+        let (comments, ellipsis, value) = match argument {
+            Argument::Positional(argument) => {
+                (&argument.comments, argument.ellipsis, &argument.value)
+            }
+            Argument::Named(argument) => {
+                self.write_bytes(&argument.name.value);
+                self.write(": ");
+
+                (&argument.comments, argument.ellipsis, &argument.value)
+            }
+        };
+
+        if ellipsis.is_some() {
+            self.write("...");
+        }
+        self.expression(value);
+
+        self.trailing_comments(comments);
+    }
+
+    fn closure_use_variable(&mut self, variable: &ClosureUseVariable) {
+        self.comments(&variable.comments);
+        if variable.ampersand.is_some() {
+            self.write("&");
+        }
+        self.write_bytes(&variable.variable.name);
+    }
+
+    fn array_item(&mut self, item: &ArrayItem) {
+        match item {
+            ArrayItem::Skipped => {}
+            ArrayItem::Value { value } => self.expression(value),
+            ArrayItem::ReferencedValue { value, .. } => {
+                self.write("&");
+                self.expression(value);
+            }
+            ArrayItem::SpreadValue { value, .. } => {
+                self.write("...");
+                self.expression(value);
+            }
+            ArrayItem::KeyValue { key, value, .. } => {
+                self.expression(key);
+                self.write(" => ");
+                self.expression(value);
+            }
+            ArrayItem::ReferencedKeyValue { key, value, .. } => {
+                self.expression(key);
+                self.write(" => &");
+                self.expression(value);
+            }
+        }
+    }
+
+    fn list_entry(&mut self, entry: &ListEntry) {
+        match entry {
+            ListEntry::Skipped => {}
+            ListEntry::Value { value } => self.expression(value),
+            ListEntry::KeyValue { key, value, .. } => {
+                self.expression(key);
+                self.write(" => ");
+                self.expression(value);
+            }
+        }
+    }
+
+    // A trailing comma is kept, as the parser keeps track of it.
+    pub(super) fn comma_separated<T>(
+        &mut self,
+        items: &CommaSeparated<T>,
+        print: impl Fn(&mut Self, &T),
+    ) {
+        self.separated(&items.inner, ", ", print);
+
+        if !items.inner.is_empty() && items.commas.len() >= items.inner.len() {
+            self.write(",");
+        }
+    }
+}
+
+// `"$a[key]"` is the simple syntax for `"{$a['key']}"`, whose key is printed
+// without quotes, so it has to keep that syntax.
+fn is_simple_array_index(index: &ArrayIndexExpression) -> bool {
+    matches!(
+        (index.array.as_ref(), index.index.as_deref()),
+        (
+            Expression::Variable(Variable::SimpleVariable(_)),
+            Some(Expression::Literal(Literal::String(key))),
+        ) if !matches!(key.value.first(), Some(b'\'' | b'"'))
+    )
+}
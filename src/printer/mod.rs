@@ -0,0 +1,217 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::fmt::Write;
+
+use crate::parser::ast::comments::CommentFormat;
+use crate::parser::ast::comments::CommentGroup;
+use crate::parser::ast::Program;
+use crate::parser::LosslessProgram;
+
+mod expressions;
+mod statements;
+pub mod tokens;
+
+pub use tokens::print_tokens;
+
+/// Prints the program as PHP source.
+///
+/// The output is laid out consistently, whatever the layout of the code that
+/// was parsed: four spaces of indentation, one statement per line and blank
+/// lines around declarations. Parsing the output gives back the same AST,
+/// spans aside, with two exceptions:
+///
+/// - the alternative syntax of control structures, as in `if (..): .. endif;`,
+///   is printed with braces;
+/// - single-quoted strings and inline HTML that are not valid UTF-8 have the
+///   invalid bytes replaced, as the output is a `String`. Double-quoted
+///   strings write them as `\x` escapes instead.
+///
+/// Inline HTML is printed verbatim, and comments are kept wherever the AST
+/// holds them.
+///
+/// # Example
+///
+/// ```
+/// use php_parser_rs::printer::print;
+///
+/// let program = php_parser_rs::parse("<?php if($a){echo   1;}").unwrap();
+///
+/// assert_eq!(print(&program), "<?php\nif ($a) {\n    echo 1;\n}\n");
+/// ```
+pub fn print(program: &Program) -> String {
+    let mut printer = Printer::default();
+    printer.statements(program);
+
+    if !printer.html {
+        printer.output.push('\n');
+    }
+
+    printer.output
+}
+
+/// Prints the program exactly as it was written, from the tokens and trivia
+/// that [`parse_lossless`] keeps.
+///
+/// [`parse_lossless`]: crate::parser::parse_lossless
+pub fn print_exact(program: &LosslessProgram) -> Vec<u8> {
+    let mut output = Vec::new();
+    for token in &program.tokens {
+        token.write(&mut output);
+    }
+
+    output
+}
+
+struct Printer {
+    output: String,
+    indent: usize,
+    /// Whether the output is outside of PHP tags, where anything other than
+    /// inline HTML needs an opening tag first.
+    html: bool,
+    /// Whether the last thing printed is a `<?=` tag, after which the echo
+    /// statement that it opens is printed without its keyword.
+    echo_tag: bool,
+}
+
+impl Default for Printer {
+    fn default() -> Self {
+        Self {
+            output: String::new(),
+            indent: 0,
+            html: true,
+            echo_tag: false,
+        }
+    }
+}
+
+impl Printer {
+    fn write(&mut self, text: &str) {
+        self.output.push_str(text);
+    }
+
+    fn display(&mut self, value: &impl Display) {
+        let _ = write!(self.output, "{}", value);
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.output.push_str(&String::from_utf8_lossy(bytes));
+    }
+
+    fn newline(&mut self) {
+        self.output.push('\n');
+        for _ in 0..self.indent {
+            self.output.push_str("    ");
+        }
+    }
+
+    fn blank_line(&mut self) {
+        self.output.push('\n');
+    }
+
+    // Comments are printed where the parser collects them, so that they end up
+    // in the same node once the output is parsed again.
+    fn comments(&mut self, comments: &CommentGroup) {
+        for comment in comments.iter() {
+            self.write_bytes(&comment.content);
+
+            match comment.format {
+                CommentFormat::SingleLine | CommentFormat::HashMark | CommentFormat::Document => {
+                    self.newline()
+                }
+                CommentFormat::MultiLine => self.write(" "),
+            }
+        }
+    }
+
+    // Comments that the parser collects after a node, such as those after the
+    // default value of a parameter, follow it on the same line.
+    fn trailing_comments(&mut self, comments: &CommentGroup) {
+        for comment in comments.iter() {
+            self.write(" ");
+            self.write_bytes(&comment.content);
+
+            match comment.format {
+                CommentFormat::SingleLine | CommentFormat::HashMark => self.newline(),
+                CommentFormat::MultiLine | CommentFormat::Document => {}
+            }
+        }
+    }
+
+    fn separated<T>(&mut self, items: &[T], separator: &str, print: impl Fn(&mut Self, &T)) {
+        for (index, item) in items.iter().enumerate() {
+            if index > 0 {
+                self.write(separator);
+            }
+
+            print(self, item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use super::print;
+    use crate::assert_ast_eq;
+    use crate::parse;
+
+    fn reprint(code: &str) -> String {
+        print(&parse(code).unwrap())
+    }
+
+    #[test]
+    fn test_inline_html_is_kept() {
+        let code = "<ul>\n<?php foreach ($items as $item) { ?>\n  <li><?= $item ?></li>\n<?php } ?>\n</ul>\n";
+
+        assert_eq!(
+            reprint(code),
+            "<ul>\n<?php\nforeach ($items as $item) {\n    ?>\n  <li><?= $item ?></li>\n<?php\n}\n?>\n</ul>\n"
+        );
+        assert_ast_eq!(parse(code).unwrap(), parse(&reprint(code)).unwrap());
+    }
+
+    #[test]
+    fn test_alternative_syntax_is_printed_with_braces() {
+        assert_eq!(
+            reprint("<?php if ($a): foo(); elseif ($b): else: bar(); endif;"),
+            "<?php\nif ($a) {\n    foo();\n} elseif ($b) {} else {\n    bar();\n}\n"
+        );
+        assert_eq!(
+            reprint("<?php while ($a): endwhile; foreach ($a as $b): endforeach;"),
+            "<?php\nwhile ($a) {}\nforeach ($a as $b) {}\n"
+        );
+    }
+
+    #[test]
+    fn test_strings_are_escaped() {
+        let code = r#"<?php
+'it\'s a \ and a \\';
+"a \"quote\", a \$variable, {$a}, $a[key] and a price of $5\n";
+b"\x80";
+`ls $dir`;
+"#;
+
+        assert_eq!(
+            reprint(code),
+            r#"<?php
+'it\'s a \ and a \\';
+"a \"quote\", a \$variable, {$a}, $a[key] and a price of $5\n";
+b"\x80";
+`ls {$dir}`;
+"#
+        );
+        assert_ast_eq!(parse(code).unwrap(), parse(&reprint(code)).unwrap());
+    }
+
+    #[test]
+    fn test_comments_are_kept() {
+        let code = "<?php\n/** Docs. */\n#[Pure]\nfunction foo($a = 1 /* one */) {}";
+
+        assert_eq!(
+            reprint(code),
+            "<?php\n\n/** Docs. */\n#[Pure]\nfunction foo($a = 1 /* one */) {}\n"
+        );
+    }
+}
@@ -0,0 +1,1155 @@
+use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::ast::classes::AnonymousClassMember;
+use crate::parser::ast::classes::ClassExtends;
+use crate::parser::ast::classes::ClassImplements;
+use crate::parser::ast::classes::ClassMember;
+use crate::parser::ast::comments::CommentFormat;
+use crate::parser::ast::comments::CommentGroup;
+use crate::parser::ast::constant::ClassishConstant;
+use crate::parser::ast::constant::ConstantEntry;
+use crate::parser::ast::control_flow::IfStatementBody;
+use crate::parser::ast::data_type::Type;
+use crate::parser::ast::declares::DeclareBody;
+use crate::parser::ast::enums::BackedEnumMember;
+use crate::parser::ast::enums::BackedEnumType;
+use crate::parser::ast::enums::UnitEnumMember;
+use crate::parser::ast::functions::AbstractConstructor;
+use crate::parser::ast::functions::AbstractMethod;
+use crate::parser::ast::functions::ConcreteConstructor;
+use crate::parser::ast::functions::ConcreteMethod;
+use crate::parser::ast::functions::ConstructorParameterList;
+use crate::parser::ast::functions::FunctionParameterList;
+use crate::parser::ast::functions::ReturnType;
+use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::interfaces::InterfaceMember;
+use crate::parser::ast::loops::ForStatementBody;
+use crate::parser::ast::loops::ForeachStatementBody;
+use crate::parser::ast::loops::ForeachStatementIterator;
+use crate::parser::ast::loops::Level;
+use crate::parser::ast::loops::WhileStatementBody;
+use crate::parser::ast::modifiers::MethodModifierGroup;
+use crate::parser::ast::modifiers::VisibilityModifier;
+use crate::parser::ast::namespaces::NamespaceStatement;
+use crate::parser::ast::properties::Property;
+use crate::parser::ast::properties::PropertyEntry;
+use crate::parser::ast::properties::PropertyHookBody;
+use crate::parser::ast::properties::PropertyHookList;
+use crate::parser::ast::properties::VariableProperty;
+use crate::parser::ast::traits::TraitMember;
+use crate::parser::ast::traits::TraitUsage;
+use crate::parser::ast::traits::TraitUsageAdaptation;
+use crate::parser::ast::try_block::CatchType;
+use crate::parser::ast::Ending;
+use crate::parser::ast::Statement;
+use crate::parser::ast::StaticVar;
+use crate::parser::ast::Use;
+use crate::parser::ast::UseKind;
+use crate::printer::Printer;
+
+impl Printer {
+    pub(super) fn statements(&mut self, statements: &[Statement]) {
+        for (index, statement) in statements.iter().enumerate() {
+            let previous = index.checked_sub(1).and_then(|index| statements.get(index));
+            if previous
+                .is_some_and(|previous| is_declaration(previous) || is_declaration(statement))
+                && !self.html
+            {
+                self.blank_line();
+            }
+
+            if self.echo_tag {
+                self.write(" ");
+            } else {
+                self.line();
+            }
+
+            self.statement(statement);
+        }
+    }
+
+    // Starts a new line, unless the output is in inline HTML, which is printed
+    // verbatim, or at its very start.
+    fn line(&mut self) {
+        if !self.html && !self.output.is_empty() {
+            self.newline();
+        }
+    }
+
+    // Prints a `{ .. }` block of statements, leaving the output in PHP mode.
+    pub(super) fn block(&mut self, statements: &[Statement]) {
+        if statements.is_empty() {
+            self.write("{}");
+
+            return;
+        }
+
+        self.write("{");
+        self.indent += 1;
+        self.statements(statements);
+        self.indent -= 1;
+        self.close_block();
+    }
+
+    fn close_block(&mut self) {
+        if self.html {
+            self.write("<?php ");
+            self.html = false;
+        } else {
+            self.newline();
+        }
+
+        self.write("}");
+    }
+
+    // Prints the body of a control structure. Blocks and `if` statements go on
+    // the same line, other statements are indented on the next one.
+    fn body(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Block(_) | Statement::If(_) => {
+                self.write(" ");
+                self.statement(statement);
+            }
+            statement => {
+                self.indent += 1;
+                self.newline();
+                self.statement(statement);
+                self.indent -= 1;
+            }
+        }
+    }
+
+    // Continues a control structure, such as with `else`, after its body.
+    fn after_body(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Block(_) => self.write(" "),
+            _ => self.newline(),
+        }
+    }
+
+    fn ending(&mut self, ending: &Ending) {
+        match ending {
+            Ending::Semicolon(_) => self.write(";"),
+            Ending::CloseTag(_) => {
+                self.write(" ?>");
+                self.html = true;
+            }
+        }
+    }
+
+    pub(super) fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::InlineHtml(html) => {
+                if !self.html {
+                    self.write("?>");
+                    self.html = true;
+                }
+
+                self.write_bytes(&html.html);
+
+                return;
+            }
+            Statement::FullOpeningTag(_) => {
+                self.open_tag("<?php");
+
+                return;
+            }
+            Statement::ShortOpeningTag(_) => {
+                self.open_tag("<?");
+
+                return;
+            }
+            Statement::EchoOpeningTag(_) => {
+                self.open_tag("<?=");
+                self.echo_tag = true;
+
+                return;
+            }
+            _ => {}
+        }
+
+        if self.html {
+            self.write("<?php");
+            self.html = false;
+            self.newline();
+        }
+
+        match statement {
+            Statement::InlineHtml(_)
+            | Statement::FullOpeningTag(_)
+            | Statement::ShortOpeningTag(_)
+            | Statement::EchoOpeningTag(_) => {}
+            Statement::ClosingTag(_) => {
+                self.write("?>");
+                self.html = true;
+            }
+            Statement::Label(label) => {
+                self.comments(&label.comments);
+                self.write_bytes(&label.label.value);
+                self.write(":");
+            }
+            Statement::Goto(goto) => {
+                self.comments(&goto.comments);
+                self.write("goto ");
+                self.write_bytes(&goto.label.value);
+                self.write(";");
+            }
+            Statement::HaltCompiler(halt) => {
+                self.write("__halt_compiler();");
+                if let Some(content) = &halt.content {
+                    self.write_bytes(content);
+                }
+
+                // Everything after the call is data, not PHP.
+                self.html = true;
+            }
+            Statement::Static(statement) => {
+                self.write("static ");
+                self.comma_separated(&statement.vars, Printer::static_var);
+                self.write(";");
+            }
+            Statement::DoWhile(statement) => {
+                self.write("do");
+                self.body(&statement.body);
+                self.after_body(&statement.body);
+                self.write("while (");
+                self.expression(&statement.condition);
+                self.write(");");
+            }
+            Statement::While(statement) => {
+                self.write("while (");
+                self.expression(&statement.condition);
+                self.write(")");
+
+                match &statement.body {
+                    WhileStatementBody::Statement { statement } => self.body(statement),
+                    WhileStatementBody::Block { statements, .. } => {
+                        self.write(" ");
+                        self.block(statements);
+                    }
+                }
+            }
+            Statement::For(statement) => {
+                let iterator = &statement.iterator;
+
+                self.write("for (");
+                self.comma_separated(&iterator.initializations, Printer::expression);
+                self.write(";");
+                if !iterator.conditions.inner.is_empty() {
+                    self.write(" ");
+                }
+                self.comma_separated(&iterator.conditions, Printer::expression);
+                self.write(";");
+                if !iterator.r#loop.inner.is_empty() {
+                    self.write(" ");
+                }
+                self.comma_separated(&iterator.r#loop, Printer::expression);
+                self.write(")");
+
+                match &statement.body {
+                    ForStatementBody::Statement { statement } => self.body(statement),
+                    ForStatementBody::Block { statements, .. } => {
+                        self.write(" ");
+                        self.block(statements);
+                    }
+                }
+            }
+            Statement::Foreach(statement) => {
+                self.write("foreach (");
+                match &statement.iterator {
+                    ForeachStatementIterator::Value {
+                        expression,
+                        ampersand,
+                        value,
+                        ..
+                    } => {
+                        self.expression(expression);
+                        self.write(" as ");
+                        if ampersand.is_some() {
+                            self.write("&");
+                        }
+                        self.expression(value);
+                    }
+                    ForeachStatementIterator::KeyAndValue {
+                        expression,
+                        ampersand,
+                        key,
+                        value,
+                        ..
+                    } => {
+                        self.expression(expression);
+                        self.write(" as ");
+                        self.expression(key);
+                        self.write(" => ");
+                        if ampersand.is_some() {
+                            self.write("&");
+                        }
+                        self.expression(value);
+                    }
+                }
+                self.write(")");
+
+                match &statement.body {
+                    ForeachStatementBody::Statement { statement } => self.body(statement),
+                    ForeachStatementBody::Block { statements, .. } => {
+                        self.write(" ");
+                        self.block(statements);
+                    }
+                }
+            }
+            Statement::Break(statement) => {
+                self.write("break");
+                if let Some(level) = &statement.level {
+                    self.write(" ");
+                    self.level(level);
+                }
+                self.ending(&statement.ending);
+            }
+            Statement::Continue(statement) => {
+                self.write("continue");
+                if let Some(level) = &statement.level {
+                    self.write(" ");
+                    self.level(level);
+                }
+                self.ending(&statement.ending);
+            }
+            Statement::Constant(constant) => {
+                self.comments(&constant.comments);
+                self.write("const ");
+                self.separated(&constant.entries, ", ", Printer::constant_entry);
+                self.write(";");
+            }
+            Statement::Function(function) => {
+                self.attributes_and_comments(&function.attributes, &function.comments, false);
+                self.write("function ");
+                if function.ampersand.is_some() {
+                    self.write("&");
+                }
+                self.write_bytes(&function.name.value);
+                self.parameters(&function.parameters);
+                self.return_type(&function.return_type);
+                self.write(" ");
+                self.comments(&function.body.comments);
+                self.block(&function.body.statements);
+            }
+            Statement::Class(class) => {
+                self.attributes_and_comments(&class.attributes, &class.comments, false);
+                if !class.modifiers.is_empty() {
+                    self.display(&class.modifiers);
+                    self.write(" ");
+                }
+                self.write("class ");
+                self.write_bytes(&class.name.value);
+                self.extends_and_implements(&class.extends, &class.implements);
+                self.write(" ");
+                self.members(&class.body.members, Printer::class_member, |member| {
+                    matches!(
+                        member,
+                        ClassMember::AbstractMethod(_)
+                            | ClassMember::AbstractConstructor(_)
+                            | ClassMember::ConcreteMethod(_)
+                            | ClassMember::ConcreteConstructor(_)
+                    )
+                });
+            }
+            Statement::Trait(r#trait) => {
+                self.attributes_and_comments(&r#trait.attributes, &r#trait.comments, false);
+                self.write("trait ");
+                self.write_bytes(&r#trait.name.value);
+                self.write(" ");
+                self.members(&r#trait.body.members, Printer::trait_member, |member| {
+                    matches!(
+                        member,
+                        TraitMember::AbstractMethod(_)
+                            | TraitMember::AbstractConstructor(_)
+                            | TraitMember::ConcreteMethod(_)
+                            | TraitMember::ConcreteConstructor(_)
+                    )
+                });
+            }
+            Statement::Interface(interface) => {
+                self.attributes_and_comments(&interface.attributes, &interface.comments, false);
+                self.write("interface ");
+                self.write_bytes(&interface.name.value);
+                if let Some(extends) = &interface.extends {
+                    self.write(" extends ");
+                    self.comma_separated(&extends.parents, |printer, parent| {
+                        printer.write_bytes(&parent.value)
+                    });
+                }
+                self.write(" ");
+                self.members(
+                    &interface.body.members,
+                    Printer::interface_member,
+                    |member| !matches!(member, InterfaceMember::Constant(_)),
+                );
+            }
+            Statement::If(statement) => {
+                self.write("if (");
+                self.expression(&statement.condition);
+                self.write(")");
+
+                match &statement.body {
+                    IfStatementBody::Statement {
+                        statement,
+                        elseifs,
+                        r#else,
+                    } => {
+                        self.body(statement);
+
+                        let mut last = statement;
+                        for elseif in elseifs {
+                            self.after_body(last);
+                            self.write("elseif (");
+                            self.expression(&elseif.condition);
+                            self.write(")");
+                            self.body(&elseif.statement);
+
+                            last = &elseif.statement;
+                        }
+
+                        if let Some(r#else) = r#else {
+                            self.after_body(last);
+                            self.write("else");
+                            self.body(&r#else.statement);
+                        }
+                    }
+                    IfStatementBody::Block {
+                        statements,
+                        elseifs,
+                        r#else,
+                        ..
+                    } => {
+                        self.write(" ");
+                        self.block(statements);
+
+                        for elseif in elseifs {
+                            self.write(" elseif (");
+                            self.expression(&elseif.condition);
+                            self.write(") ");
+                            self.block(&elseif.statements);
+                        }
+
+                        if let Some(r#else) = r#else {
+                            self.write(" else ");
+                            self.block(&r#else.statements);
+                        }
+                    }
+                }
+            }
+            Statement::Switch(switch) => {
+                self.write("switch (");
+                self.expression(&switch.condition);
+                self.write(") {");
+                self.indent += 1;
+                for case in &switch.cases {
+                    self.line();
+                    match &case.condition {
+                        Some(condition) => {
+                            self.write("case ");
+                            self.expression(condition);
+                            self.write(":");
+                        }
+                        None => self.write("default:"),
+                    }
+
+                    self.indent += 1;
+                    self.statements(&case.body);
+                    self.indent -= 1;
+                }
+                self.indent -= 1;
+                self.close_block();
+            }
+            Statement::Echo(echo) => {
+                if self.echo_tag {
+                    self.echo_tag = false;
+                } else {
+                    self.write("echo ");
+                }
+
+                self.separated(&echo.values, ", ", Printer::expression);
+                self.ending(&echo.ending);
+            }
+            Statement::Expression(statement) => {
+                self.expression(&statement.expression);
+                self.ending(&statement.ending);
+            }
+            Statement::Return(statement) => {
+                self.write("return");
+                if let Some(value) = &statement.value {
+                    self.write(" ");
+                    self.expression(value);
+                }
+                self.ending(&statement.ending);
+            }
+            Statement::Namespace(NamespaceStatement::Unbraced(namespace)) => {
+                self.write("namespace ");
+                self.write_bytes(&namespace.name.value);
+                self.write(";");
+                if !namespace.statements.is_empty() {
+                    self.blank_line();
+                }
+                self.statements(&namespace.statements);
+            }
+            Statement::Namespace(NamespaceStatement::Braced(namespace)) => {
+                self.write("namespace ");
+                if let Some(name) = &namespace.name {
+                    self.write_bytes(&name.value);
+                    self.write(" ");
+                }
+                self.block(&namespace.body.statements);
+            }
+            Statement::Use(statement) => {
+                self.write("use ");
+                self.use_kind(&statement.kind);
+                self.separated(&statement.uses, ", ", Printer::r#use);
+                self.write(";");
+            }
+            Statement::GroupUse(statement) => {
+                self.write("use ");
+                self.use_kind(&statement.kind);
+                self.write_bytes(&statement.prefix.value);
+                self.write("{");
+                self.separated(&statement.uses, ", ", Printer::r#use);
+                self.write("};");
+            }
+            Statement::Comment(comment) => {
+                self.write_bytes(&comment.content);
+
+                // Statements in a `<?=` tag are kept on one line, which a line
+                // comment would otherwise swallow.
+                if self.echo_tag
+                    && matches!(
+                        comment.format,
+                        CommentFormat::SingleLine | CommentFormat::HashMark
+                    )
+                {
+                    self.blank_line();
+                }
+            }
+            Statement::Try(statement) => {
+                self.write("try ");
+                self.block(&statement.body);
+
+                for catch in &statement.catches {
+                    self.write(" catch (");
+                    match &catch.types {
+                        CatchType::Identifier { identifier } => self.write_bytes(&identifier.value),
+                        CatchType::Union { identifiers } => {
+                            self.separated(identifiers, " | ", |printer, identifier| {
+                                printer.write_bytes(&identifier.value)
+                            })
+                        }
+                    }
+                    if let Some(var) = &catch.var {
+                        self.write(" ");
+                        self.write_bytes(&var.name);
+                    }
+                    self.write(") ");
+                    self.block(&catch.body);
+                }
+
+                if let Some(finally) = &statement.finally {
+                    self.write(" finally ");
+                    self.block(&finally.body);
+                }
+            }
+            Statement::UnitEnum(r#enum) => {
+                self.attributes_and_comments(&r#enum.attributes, &r#enum.comments, false);
+                self.write("enum ");
+                self.write_bytes(&r#enum.name.value);
+                self.enum_implements(&r#enum.implements);
+                self.write(" ");
+                self.members(&r#enum.body.members, Printer::unit_enum_member, |member| {
+                    matches!(member, UnitEnumMember::Method(_))
+                });
+            }
+            Statement::BackedEnum(r#enum) => {
+                self.attributes_and_comments(&r#enum.attributes, &r#enum.comments, false);
+                self.write("enum ");
+                self.write_bytes(&r#enum.name.value);
+                match r#enum.backed_type {
+                    BackedEnumType::String(..) => self.write(": string"),
+                    BackedEnumType::Int(..) => self.write(": int"),
+                }
+                self.enum_implements(&r#enum.implements);
+                self.write(" ");
+                self.members(
+                    &r#enum.body.members,
+                    Printer::backed_enum_member,
+                    |member| matches!(member, BackedEnumMember::Method(_)),
+                );
+            }
+            Statement::Block(block) => self.block(&block.statements),
+            Statement::Global(global) => {
+                self.write("global ");
+                self.comma_separated(&global.variables, Printer::variable);
+                self.write(";");
+            }
+            Statement::Declare(declare) => {
+                self.write("declare(");
+                self.separated(&declare.entries.entries, ", ", |printer, entry| {
+                    printer.write_bytes(&entry.key.value);
+                    printer.write("=");
+                    printer.literal(&entry.value);
+                });
+                self.write(")");
+
+                match &declare.body {
+                    DeclareBody::Noop { .. } => self.write(";"),
+                    DeclareBody::Braced { statements, .. }
+                    | DeclareBody::Block { statements, .. } => {
+                        self.write(" ");
+                        self.block(statements);
+                    }
+                    DeclareBody::Expression { expression, .. } => {
+                        self.write(" ");
+                        self.expression(expression);
+                        self.write(";");
+                    }
+                }
+            }
+            Statement::Noop(_) => self.write(";"),
+        }
+    }
+
+    fn open_tag(&mut self, tag: &str) {
+        if !self.html {
+            self.write("?>");
+        }
+
+        self.write(tag);
+        self.html = false;
+    }
+
+    fn level(&mut self, level: &Level) {
+        match level {
+            Level::Literal(literal) => self.write_bytes(&literal.value),
+            Level::Parenthesized { level, .. } => {
+                self.write("(");
+                self.level(level);
+                self.write(")");
+            }
+        }
+    }
+
+    fn static_var(&mut self, var: &StaticVar) {
+        self.variable(&var.var);
+        if let Some(default) = &var.default {
+            self.write(" = ");
+            self.expression(default);
+        }
+    }
+
+    fn use_kind(&mut self, kind: &UseKind) {
+        match kind {
+            UseKind::Normal => {}
+            UseKind::Function => self.write("function "),
+            UseKind::Const => self.write("const "),
+        }
+    }
+
+    fn r#use(&mut self, r#use: &Use) {
+        if let Some(kind) = &r#use.kind {
+            self.use_kind(kind);
+        }
+
+        self.write_bytes(&r#use.name.value);
+        if let Some(alias) = &r#use.alias {
+            self.write(" as ");
+            self.write_bytes(&alias.value);
+        }
+    }
+
+    fn constant_entry(&mut self, entry: &ConstantEntry) {
+        self.write_bytes(&entry.name.value);
+        self.write(" = ");
+        self.expression(&entry.value);
+    }
+
+    // Attributes of declarations go on lines of their own, those of
+    // parameters and closures on the same line.
+    pub(super) fn attributes(&mut self, attributes: &[AttributeGroup], inline: bool) {
+        for group in attributes {
+            self.write("#[");
+            self.separated(&group.members, ", ", |printer, attribute| {
+                printer.write_bytes(&attribute.name.value);
+                if let Some(arguments) = &attribute.arguments {
+                    printer.arguments(arguments);
+                }
+            });
+            self.write("]");
+
+            if inline {
+                self.write(" ");
+            } else {
+                self.newline();
+            }
+        }
+    }
+
+    // Comments go before the attributes, unless one of them has arguments,
+    // whose list would take the comments when the output is parsed.
+    pub(super) fn attributes_and_comments(
+        &mut self,
+        attributes: &[AttributeGroup],
+        comments: &CommentGroup,
+        inline: bool,
+    ) {
+        let has_arguments = attributes
+            .iter()
+            .flat_map(|group| &group.members)
+            .any(|attribute| attribute.arguments.is_some());
+
+        if has_arguments {
+            self.attributes(attributes, inline);
+            self.comments(comments);
+        } else {
+            self.comments(comments);
+            self.attributes(attributes, inline);
+        }
+    }
+
+    pub(super) fn parameters(&mut self, parameters: &FunctionParameterList) {
+        self.comments(&parameters.comments);
+        self.write("(");
+        self.comma_separated(&parameters.parameters, |printer, parameter| {
+            printer.attributes(&parameter.attributes, true);
+            if let Some(data_type) = &parameter.data_type {
+                printer.display(data_type);
+                printer.write(" ");
+            }
+            if parameter.ampersand.is_some() {
+                printer.write("&");
+            }
+            if parameter.ellipsis.is_some() {
+                printer.write("...");
+            }
+            printer.write_bytes(&parameter.name.name);
+            if let Some(default) = &parameter.default {
+                printer.write(" = ");
+                printer.expression(default);
+            }
+            printer.trailing_comments(&parameter.comments);
+        });
+        self.write(")");
+    }
+
+    fn constructor_parameters(&mut self, parameters: &ConstructorParameterList) {
+        self.comments(&parameters.comments);
+        self.write("(");
+        self.comma_separated(&parameters.parameters, |printer, parameter| {
+            printer.attributes(&parameter.attributes, true);
+            if !parameter.modifiers.is_empty() {
+                printer.display(&parameter.modifiers);
+                printer.write(" ");
+            }
+            if let Some(data_type) = &parameter.data_type {
+                printer.display(data_type);
+                printer.write(" ");
+            }
+            if parameter.ampersand.is_some() {
+                printer.write("&");
+            }
+            if parameter.ellipsis.is_some() {
+                printer.write("...");
+            }
+            printer.write_bytes(&parameter.name.name);
+            if let Some(default) = &parameter.default {
+                printer.write(" = ");
+                printer.expression(default);
+            }
+            if let Some(hooks) = &parameter.hooks {
+                printer.write(" ");
+                printer.property_hooks(hooks);
+            }
+            printer.trailing_comments(&parameter.comments);
+        });
+        self.write(")");
+    }
+
+    pub(super) fn return_type(&mut self, return_type: &Option<ReturnType>) {
+        if let Some(return_type) = return_type {
+            self.write(": ");
+            self.display(&return_type.data_type);
+        }
+    }
+
+    pub(super) fn extends_and_implements(
+        &mut self,
+        extends: &Option<ClassExtends>,
+        implements: &Option<ClassImplements>,
+    ) {
+        if let Some(extends) = extends {
+            self.write(" extends ");
+            self.write_bytes(&extends.parent.value);
+        }
+
+        if let Some(implements) = implements {
+            self.write(" implements ");
+            self.comma_separated(&implements.interfaces, |printer, interface| {
+                printer.write_bytes(&interface.value)
+            });
+        }
+    }
+
+    fn enum_implements(&mut self, implements: &[SimpleIdentifier]) {
+        if !implements.is_empty() {
+            self.write(" implements ");
+            self.separated(implements, ", ", |printer, interface| {
+                printer.write_bytes(&interface.value)
+            });
+        }
+    }
+
+    // Prints the `{ .. }` body of a class-like, with a blank line around the
+    // members that `spaced` picks out, which are methods.
+    pub(super) fn members<T>(
+        &mut self,
+        members: &[T],
+        print: impl Fn(&mut Self, &T),
+        spaced: impl Fn(&T) -> bool,
+    ) {
+        if members.is_empty() {
+            self.write("{}");
+
+            return;
+        }
+
+        self.write("{");
+        self.indent += 1;
+        for (index, member) in members.iter().enumerate() {
+            let previous = index.checked_sub(1).and_then(|index| members.get(index));
+            if previous.is_some_and(|previous| spaced(previous) || spaced(member)) {
+                self.blank_line();
+            }
+
+            self.newline();
+            print(self, member);
+        }
+        self.indent -= 1;
+        self.newline();
+        self.write("}");
+    }
+
+    fn class_member(&mut self, member: &ClassMember) {
+        match member {
+            ClassMember::Constant(constant) => self.classish_constant(constant),
+            ClassMember::TraitUsage(usage) => self.trait_usage(usage),
+            ClassMember::Property(property) => self.property(property),
+            ClassMember::VariableProperty(property) => self.variable_property(property),
+            ClassMember::AbstractMethod(method) => self.abstract_method(method),
+            ClassMember::AbstractConstructor(constructor) => self.abstract_constructor(constructor),
+            ClassMember::ConcreteMethod(method) => self.concrete_method(method),
+            ClassMember::ConcreteConstructor(constructor) => self.concrete_constructor(constructor),
+            ClassMember::Comment(comment) => self.write_bytes(&comment.content),
+        }
+    }
+
+    pub(super) fn anonymous_class_member(&mut self, member: &AnonymousClassMember) {
+        match member {
+            AnonymousClassMember::Constant(constant) => self.classish_constant(constant),
+            AnonymousClassMember::TraitUsage(usage) => self.trait_usage(usage),
+            AnonymousClassMember::Property(property) => self.property(property),
+            AnonymousClassMember::VariableProperty(property) => self.variable_property(property),
+            AnonymousClassMember::ConcreteMethod(method) => self.concrete_method(method),
+            AnonymousClassMember::ConcreteConstructor(constructor) => {
+                self.concrete_constructor(constructor)
+            }
+            AnonymousClassMember::Comment(comment) => self.write_bytes(&comment.content),
+        }
+    }
+
+    fn trait_member(&mut self, member: &TraitMember) {
+        match member {
+            TraitMember::Constant(constant) => self.classish_constant(constant),
+            TraitMember::TraitUsage(usage) => self.trait_usage(usage),
+            TraitMember::Property(property) => self.property(property),
+            TraitMember::VariableProperty(property) => self.variable_property(property),
+            TraitMember::AbstractMethod(method) => self.abstract_method(method),
+            TraitMember::AbstractConstructor(constructor) => self.abstract_constructor(constructor),
+            TraitMember::ConcreteMethod(method) => self.concrete_method(method),
+            TraitMember::ConcreteConstructor(constructor) => self.concrete_constructor(constructor),
+            TraitMember::Comment(comment) => self.write_bytes(&comment.content),
+        }
+    }
+
+    fn interface_member(&mut self, member: &InterfaceMember) {
+        match member {
+            InterfaceMember::Constant(constant) => self.classish_constant(constant),
+            InterfaceMember::Constructor(constructor) => self.abstract_constructor(constructor),
+            InterfaceMember::Method(method) => self.abstract_method(method),
+            InterfaceMember::Property(property) => self.property(property),
+            InterfaceMember::Comment(comment) => self.write_bytes(&comment.content),
+        }
+    }
+
+    fn unit_enum_member(&mut self, member: &UnitEnumMember) {
+        match member {
+            UnitEnumMember::Case(case) => {
+                self.attributes_and_comments(&case.attributes, &case.comments, false);
+                self.write("case ");
+                self.write_bytes(&case.name.value);
+                self.write(";");
+            }
+            UnitEnumMember::Method(method) => self.concrete_method(method),
+            UnitEnumMember::Constant(constant) => self.classish_constant(constant),
+            UnitEnumMember::TraitUsage(usage) => self.trait_usage(usage),
+            UnitEnumMember::Comment(comment) => self.write_bytes(&comment.content),
+        }
+    }
+
+    fn backed_enum_member(&mut self, member: &BackedEnumMember) {
+        match member {
+            BackedEnumMember::Case(case) => {
+                self.attributes_and_comments(&case.attributes, &case.comments, false);
+                self.write("case ");
+                self.write_bytes(&case.name.value);
+                self.write(" = ");
+                self.expression(&case.value);
+                self.write(";");
+            }
+            BackedEnumMember::Method(method) => self.concrete_method(method),
+            BackedEnumMember::Constant(constant) => self.classish_constant(constant),
+            BackedEnumMember::TraitUsage(usage) => self.trait_usage(usage),
+            BackedEnumMember::Comment(comment) => self.write_bytes(&comment.content),
+        }
+    }
+
+    fn classish_constant(&mut self, constant: &ClassishConstant) {
+        self.attributes_and_comments(&constant.attributes, &constant.comments, false);
+        if !constant.modifiers.is_empty() {
+            self.display(&constant.modifiers);
+            self.write(" ");
+        }
+        self.write("const ");
+        if let Some(r#type) = &constant.r#type {
+            self.display(r#type);
+            self.write(" ");
+        }
+        self.separated(&constant.entries, ", ", Printer::constant_entry);
+        self.write(";");
+    }
+
+    fn trait_usage(&mut self, usage: &TraitUsage) {
+        self.comments(&usage.comments);
+        self.write("use ");
+        self.separated(&usage.traits, ", ", |printer, r#trait| {
+            printer.write_bytes(&r#trait.value)
+        });
+
+        if usage.adaptations.is_empty() {
+            self.write(";");
+
+            return;
+        }
+
+        self.write(" {");
+        self.indent += 1;
+        for adaptation in &usage.adaptations {
+            self.newline();
+
+            let (r#trait, method) = match adaptation {
+                TraitUsageAdaptation::Alias {
+                    r#trait, method, ..
+                }
+                | TraitUsageAdaptation::Visibility {
+                    r#trait, method, ..
+                }
+                | TraitUsageAdaptation::Precedence {
+                    r#trait, method, ..
+                } => (r#trait, method),
+            };
+
+            if let Some(r#trait) = r#trait {
+                self.write_bytes(&r#trait.value);
+                self.write("::");
+            }
+            self.write_bytes(&method.value);
+
+            match adaptation {
+                TraitUsageAdaptation::Alias {
+                    alias, visibility, ..
+                } => {
+                    self.write(" as ");
+                    if let Some(visibility) = visibility {
+                        self.visibility(visibility);
+                        self.write(" ");
+                    }
+                    self.write_bytes(&alias.value);
+                }
+                TraitUsageAdaptation::Visibility { visibility, .. } => {
+                    self.write(" as ");
+                    self.visibility(visibility);
+                }
+                TraitUsageAdaptation::Precedence { insteadof, .. } => {
+                    self.write(" insteadof ");
+                    self.separated(insteadof, ", ", |printer, r#trait| {
+                        printer.write_bytes(&r#trait.value)
+                    });
+                }
+            }
+            self.write(";");
+        }
+        self.indent -= 1;
+        self.newline();
+        self.write("}");
+    }
+
+    fn visibility(&mut self, visibility: &VisibilityModifier) {
+        self.display(visibility);
+    }
+
+    fn property(&mut self, property: &Property) {
+        self.attributes_and_comments(&property.attributes, &property.comments, false);
+        if !property.modifiers.is_empty() {
+            self.display(&property.modifiers);
+            self.write(" ");
+        }
+        self.property_entries(&property.r#type, &property.entries);
+        match &property.hooks {
+            Some(hooks) => {
+                self.write(" ");
+                self.property_hooks(hooks);
+            }
+            None => self.write(";"),
+        }
+    }
+
+    fn variable_property(&mut self, property: &VariableProperty) {
+        self.attributes_and_comments(&property.attributes, &property.comments, false);
+        self.write("var ");
+        self.property_entries(&property.r#type, &property.entries);
+        self.write(";");
+    }
+
+    fn property_entries(&mut self, r#type: &Option<Type>, entries: &[PropertyEntry]) {
+        if let Some(r#type) = r#type {
+            self.display(r#type);
+            self.write(" ");
+        }
+
+        self.separated(entries, ", ", |printer, entry| match entry {
+            PropertyEntry::Uninitialized { variable } => printer.write_bytes(&variable.name),
+            PropertyEntry::Initialized {
+                variable, value, ..
+            } => {
+                printer.write_bytes(&variable.name);
+                printer.write(" = ");
+                printer.expression(value);
+            }
+        });
+    }
+
+    fn property_hooks(&mut self, hooks: &PropertyHookList) {
+        if hooks.hooks.is_empty() {
+            self.write("{}");
+
+            return;
+        }
+
+        self.write("{");
+        self.indent += 1;
+        for hook in &hooks.hooks {
+            self.newline();
+            self.attributes_and_comments(&hook.attributes, &hook.comments, false);
+            if hook.r#final.is_some() {
+                self.write("final ");
+            }
+            if hook.ampersand.is_some() {
+                self.write("&");
+            }
+            self.write_bytes(&hook.name.value);
+            if let Some(parameters) = &hook.parameters {
+                self.parameters(parameters);
+            }
+            match &hook.body {
+                PropertyHookBody::Abstract { .. } => self.write(";"),
+                PropertyHookBody::Expression { expression, .. } => {
+                    self.write(" => ");
+                    self.expression(expression);
+                    self.write(";");
+                }
+                PropertyHookBody::Block(body) => {
+                    self.write(" ");
+                    self.comments(&body.comments);
+                    self.block(&body.statements);
+                }
+            }
+        }
+        self.indent -= 1;
+        self.newline();
+        self.write("}");
+    }
+
+    fn abstract_method(&mut self, method: &AbstractMethod) {
+        self.attributes_and_comments(&method.attributes, &method.comments, false);
+        self.method_head(&method.modifiers, method.ampersand.is_some(), &method.name);
+        self.parameters(&method.parameters);
+        self.return_type(&method.return_type);
+        self.write(";");
+    }
+
+    fn abstract_constructor(&mut self, constructor: &AbstractConstructor) {
+        self.attributes_and_comments(&constructor.attributes, &constructor.comments, false);
+        self.method_head(
+            &constructor.modifiers,
+            constructor.ampersand.is_some(),
+            &constructor.name,
+        );
+        self.parameters(&constructor.parameters);
+        self.write(";");
+    }
+
+    fn concrete_method(&mut self, method: &ConcreteMethod) {
+        self.attributes_and_comments(&method.attributes, &method.comments, false);
+        self.method_head(&method.modifiers, method.ampersand.is_some(), &method.name);
+        self.parameters(&method.parameters);
+        self.return_type(&method.return_type);
+        self.write(" ");
+        self.comments(&method.body.comments);
+        self.block(&method.body.statements);
+    }
+
+    fn concrete_constructor(&mut self, constructor: &ConcreteConstructor) {
+        self.attributes_and_comments(&constructor.attributes, &constructor.comments, false);
+        self.method_head(
+            &constructor.modifiers,
+            constructor.ampersand.is_some(),
+            &constructor.name,
+        );
+        self.constructor_parameters(&constructor.parameters);
+        self.write(" ");
+        self.comments(&constructor.body.comments);
+        self.block(&constructor.body.statements);
+    }
+
+    fn method_head(
+        &mut self,
+        modifiers: &MethodModifierGroup,
+        ampersand: bool,
+        name: &SimpleIdentifier,
+    ) {
+        if !modifiers.is_empty() {
+            self.display(modifiers);
+            self.write(" ");
+        }
+        self.write("function ");
+        if ampersand {
+            self.write("&");
+        }
+        self.write_bytes(&name.value);
+    }
+}
+
+fn is_declaration(statement: &Statement) -> bool {
+    matches!(
+        statement,
+        Statement::Function(_)
+            | Statement::Class(_)
+            | Statement::Trait(_)
+            | Statement::Interface(_)
+            | Statement::UnitEnum(_)
+            | Statement::BackedEnum(_)
+            | Statement::Namespace(_)
+    )
+}
@@ -1,5 +1,9 @@
-use std::cmp;
-use std::collections::HashMap;
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::cmp;
 
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
@@ -11,7 +15,7 @@ use crate::lexer::token::TokenKind;
 /// ```
 /// use pretty_assertions::assert_str_eq;
 /// use php_parser_rs::lexer::Lexer;
-/// use php_parser_rs::printer::print;
+/// use php_parser_rs::printer::print_tokens;
 ///
 /// let code = r#"
 /// <?php
@@ -25,10 +29,10 @@ use crate::lexer::token::TokenKind;
 ///
 /// let tokens = Lexer::new().tokenize(code.as_bytes()).unwrap();
 ///
-/// assert_str_eq!(print(&tokens), code);
+/// assert_str_eq!(print_tokens(&tokens), code);
 /// ```
-pub fn print(tokens: &[Token]) -> String {
-    let mut lines: HashMap<usize, Vec<&Token>> = HashMap::new();
+pub fn print_tokens(tokens: &[Token]) -> String {
+    let mut lines: BTreeMap<u32, Vec<&Token>> = BTreeMap::new();
     let mut max_line = 0;
 
     for token in tokens {
@@ -54,7 +58,7 @@ pub fn print(tokens: &[Token]) -> String {
                         break;
                     }
 
-                    let repeat = token.span.column - representation.len() - 1;
+                    let repeat = token.span.column as usize - representation.len() - 1;
 
                     representation.push_str(&" ".repeat(repeat));
                     representation.push_str(&token.value.to_string());
@@ -62,7 +66,7 @@ pub fn print(tokens: &[Token]) -> String {
 
                 let mut result = vec![];
                 let lines = representation.lines();
-                last += lines.clone().count();
+                last += lines.clone().count() as u32;
                 for line in lines {
                     result.push(line);
                 }
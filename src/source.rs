@@ -0,0 +1,245 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::OnceCell;
+
+use crate::lexer::token::Span;
+use crate::lexer::trivia::ConcreteToken;
+use crate::lexer::Lexer;
+use crate::parser::ast::spans::SpanRange;
+use crate::parser::ast::spans::SpannedNode;
+use crate::parser::ast::Program;
+use crate::parser::completion::expected_at;
+use crate::parser::completion::ExpectedAt;
+use crate::parser::deprecations::Deprecation;
+use crate::parser::error;
+use crate::parser::error::ParseError;
+use crate::parser::parse_with_deprecations;
+use crate::parser::ParserOptions;
+use crate::Error;
+
+/// A PHP file, holding its source along with what is computed from it: the
+/// program, the errors and deprecations found while parsing it, and the
+/// offsets of its lines.
+///
+/// The file is only parsed, and its lines only indexed, when something needs
+/// them, and only once.
+///
+/// # Example
+///
+/// ```
+/// use php_parser_rs::source::SourceFile;
+///
+/// let file = SourceFile::from_bytes("example.php", "<?php\n\necho $a;\n");
+///
+/// assert_eq!(file.program().len(), 2);
+/// assert!(file.diagnostics().is_empty());
+///
+/// let position = file.position(12).unwrap();
+/// assert_eq!((position.line, position.column), (3, 6));
+/// assert_eq!(file.snippet(position), b"echo $a;");
+/// ```
+#[derive(Debug)]
+pub struct SourceFile {
+    name: String,
+    bytes: Vec<u8>,
+    parsed: OnceCell<(Program, Vec<ParseError>, Vec<Deprecation>)>,
+    lines: OnceCell<Vec<usize>>,
+    tokens: OnceCell<Vec<ConcreteToken>>,
+    nodes: OnceCell<Vec<SpannedNode>>,
+}
+
+impl SourceFile {
+    /// A file with the given contents, named `name` in reports.
+    pub fn from_bytes<N: Into<String>, B: Into<Vec<u8>>>(name: N, bytes: B) -> Self {
+        Self {
+            name: name.into(),
+            bytes: bytes.into(),
+            parsed: OnceCell::new(),
+            lines: OnceCell::new(),
+            tokens: OnceCell::new(),
+            nodes: OnceCell::new(),
+        }
+    }
+
+    /// Reads the file at `path`, which is also its name.
+    ///
+    /// The file is read as it is, line breaks included, so that it parses to
+    /// the same program on every platform. Only its name, used in reports,
+    /// depends on how the platform displays paths.
+    #[cfg(feature = "std")]
+    pub fn from_path<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        let path = path.as_ref();
+
+        Ok(Self::from_bytes(
+            path.display().to_string(),
+            std::fs::read(path)?,
+        ))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The program in the file. When the file has errors, this is the part
+    /// of the program that was parsed before the parser gave up.
+    pub fn program(&self) -> &Program {
+        &self.parsed().0
+    }
+
+    /// The errors found while parsing the file.
+    pub fn diagnostics(&self) -> &[ParseError] {
+        &self.parsed().1
+    }
+
+    /// The deprecated syntax found while parsing the file, for the latest
+    /// version of PHP.
+    pub fn deprecations(&self) -> &[Deprecation] {
+        &self.parsed().2
+    }
+
+    /// The line and column of the byte at `offset`, or `None` past the end of
+    /// the file. Columns count bytes, as in the spans of the AST.
+    pub fn position(&self, offset: usize) -> Option<Span> {
+        if offset > self.bytes.len() {
+            return None;
+        }
+
+        let lines = self.lines();
+        let line = lines.partition_point(|start| *start <= offset);
+        let start = lines.get(line.checked_sub(1)?)?;
+
+        Some(Span::new(
+            u32::try_from(line).ok()?,
+            u32::try_from(offset - start + 1).ok()?,
+            u32::try_from(offset).ok()?,
+        ))
+    }
+
+    /// The line that the span starts on, without its line break.
+    pub fn snippet(&self, span: Span) -> &[u8] {
+        let lines = self.lines();
+        let line = lines.partition_point(|start| *start <= span.offset());
+
+        let start = line
+            .checked_sub(1)
+            .and_then(|line| lines.get(line))
+            .copied()
+            .unwrap_or(self.bytes.len());
+        let end = lines.get(line).copied().unwrap_or(self.bytes.len());
+
+        let snippet = self.bytes.get(start..end).unwrap_or_default();
+        let snippet = snippet.strip_suffix(b"\n").unwrap_or(snippet);
+
+        snippet.strip_suffix(b"\r").unwrap_or(snippet)
+    }
+
+    /// The source in `range`, as given by
+    /// [`Spanned::span`](crate::parser::ast::Spanned::span).
+    pub fn text(&self, range: SpanRange) -> &[u8] {
+        self.bytes
+            .get(range.start.offset()..range.end.offset())
+            .unwrap_or_default()
+    }
+
+    /// The innermost node that starts at the token under `offset`, as
+    /// classified by [`Program::spans`].
+    ///
+    /// Offsets in whitespace or comments, or in tokens that no node starts
+    /// at, such as punctuation, have no node. For every node that contains
+    /// an offset, see [`node_at_offset`](crate::parser::ast::node_at_offset).
+    pub fn node_at(&self, offset: usize) -> Option<SpannedNode> {
+        let tokens = self.tokens();
+        let index = tokens.partition_point(|token| token.token.span.offset() <= offset);
+        let token = tokens.get(index.checked_sub(1)?)?;
+
+        let start = token.token.span.offset();
+        if offset >= start + token.text.len() {
+            return None;
+        }
+
+        let nodes = self.nodes();
+        let first = nodes.partition_point(|node| node.span.offset() < start);
+
+        nodes
+            .get(first..)?
+            .iter()
+            .take_while(|node| node.span.offset() == start)
+            .max_by_key(|node| node.depth)
+            .copied()
+    }
+
+    /// What the parser expected at `offset`, as found by
+    /// [`expected_at`](crate::parser::completion::expected_at).
+    pub fn expected_at(&self, offset: usize) -> Option<ExpectedAt> {
+        expected_at(&self.bytes, offset)
+    }
+
+    /// Renders the errors of the file as reports pointing into its source.
+    #[cfg(feature = "std")]
+    pub fn report(&self, colored: bool, ascii: bool) -> std::io::Result<String> {
+        let source = String::from_utf8_lossy(&self.bytes);
+        let mut reports = Vec::new();
+
+        for error in self.diagnostics() {
+            reports.push(error.report(&source, Some(&self.name), colored, ascii)?);
+        }
+
+        Ok(reports.join("\n"))
+    }
+
+    fn parsed(&self) -> &(Program, Vec<ParseError>, Vec<Deprecation>) {
+        self.parsed.get_or_init(|| {
+            let (program, deprecations) =
+                parse_with_deprecations(&self.bytes, &ParserOptions::default());
+
+            match program {
+                Ok(program) => (program, Vec::new(), deprecations),
+                Err(Error::Parse(stack)) => (stack.partial, stack.errors, deprecations),
+                Err(Error::Lex(error)) => (Program::new(), vec![error.into()], deprecations),
+                // Without a cancellation token, the parse is never cancelled,
+                // and the source has already been read.
+                Err(Error::Cancelled) => (Program::new(), Vec::new(), deprecations),
+                #[cfg(feature = "std")]
+                Err(Error::Io(_)) => (Program::new(), Vec::new(), deprecations),
+                // The parser doesn't say where it found itself broken, so the
+                // error is reported at the start of the file.
+                Err(Error::Internal { detail }) => (
+                    Program::new(),
+                    vec![error::internal(&detail, Span::new(1, 1, 0))],
+                    deprecations,
+                ),
+            }
+        })
+    }
+
+    // The offset at which every line starts.
+    fn lines(&self) -> &[usize] {
+        self.lines.get_or_init(|| {
+            let breaks = self
+                .bytes
+                .iter()
+                .enumerate()
+                .filter(|(_, byte)| **byte == b'\n')
+                .map(|(offset, _)| offset + 1);
+
+            core::iter::once(0).chain(breaks).collect()
+        })
+    }
+
+    // A file that cannot be split into tokens has none.
+    fn tokens(&self) -> &[ConcreteToken] {
+        self.tokens.get_or_init(|| {
+            Lexer::new()
+                .tokenize_lossless(&self.bytes)
+                .unwrap_or_default()
+        })
+    }
+
+    fn nodes(&self) -> &[SpannedNode] {
+        self.nodes.get_or_init(|| self.program().spans().collect())
+    }
+}
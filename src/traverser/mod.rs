@@ -1,17 +1,119 @@
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 use crate::node::Node;
 
+/// Tells the traversal how to proceed after a node has been visited.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Flow {
+    /// Go on and visit the children of the node.
+    Continue,
+    /// Don't visit anything beneath the node, but carry on with its siblings.
+    SkipChildren,
+    /// Stop the whole traversal immediately.
+    Break,
+}
+
 pub trait Visitor<E: Debug> {
-    fn visit_node(&mut self, node: &mut dyn Node) -> Result<(), E> {
-        self.visit(node)?;
+    /// Visits `node` and its descendants, returning `Flow::Break` if the traversal
+    /// was stopped.
+    ///
+    /// A [`Program`](crate::parser::ast::Program) is visited through
+    /// [`Program::edit`](crate::parser::ast::Program::edit).
+    fn visit_node(&mut self, node: &mut dyn Node) -> Result<Flow, E> {
+        match self.visit(node)? {
+            Flow::Continue => {}
+            Flow::SkipChildren => return Ok(Flow::Continue),
+            Flow::Break => return Ok(Flow::Break),
+        }
 
-        for child in node.children() {
-            self.visit_node(child)?;
+        for child in node.children_mut() {
+            if self.visit_node(child)? == Flow::Break {
+                return Ok(Flow::Break);
+            }
         }
 
-        Ok(())
+        Ok(Flow::Continue)
     }
 
-    fn visit(&mut self, node: &mut dyn Node) -> Result<(), E>;
+    fn visit(&mut self, node: &mut dyn Node) -> Result<Flow, E>;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use super::*;
+    use crate::downcast::downcast;
+    use crate::parser::ast::classes::ClassStatement;
+    use crate::parser::ast::functions::FunctionStatement;
+    use crate::parser::ast::ReturnStatement;
+
+    #[derive(Default)]
+    struct Collector {
+        classes: Vec<String>,
+        returns: usize,
+    }
+
+    #[test]
+    fn test_skip_children() {
+        struct SkipFunctions(Collector);
+
+        impl Visitor<Infallible> for SkipFunctions {
+            fn visit(&mut self, node: &mut dyn Node) -> Result<Flow, Infallible> {
+                if downcast::<ReturnStatement>(node).is_some() {
+                    self.0.returns += 1;
+                }
+
+                if downcast::<FunctionStatement>(node).is_some() {
+                    return Ok(Flow::SkipChildren);
+                }
+
+                Ok(Flow::Continue)
+            }
+        }
+
+        let mut program = crate::parse(
+            "<?php function foo() { return 1; } class Foo { function bar() { return 2; } }",
+        )
+        .unwrap();
+
+        let mut visitor = SkipFunctions(Collector::default());
+        assert_eq!(
+            program.edit(|statements| visitor.visit_node(statements)),
+            Ok(Flow::Continue)
+        );
+        assert_eq!(visitor.0.returns, 1);
+    }
+
+    #[test]
+    fn test_break() {
+        struct FirstClass(Collector);
+
+        impl Visitor<Infallible> for FirstClass {
+            fn visit(&mut self, node: &mut dyn Node) -> Result<Flow, Infallible> {
+                if downcast::<ReturnStatement>(node).is_some() {
+                    self.0.returns += 1;
+                }
+
+                if let Some(class) = downcast::<ClassStatement>(node) {
+                    self.0.classes.push(class.name.value.to_string());
+
+                    return Ok(Flow::Break);
+                }
+
+                Ok(Flow::Continue)
+            }
+        }
+
+        let mut program =
+            crate::parse("<?php class Foo {} class Bar {} function baz() { return 1; }").unwrap();
+
+        let mut visitor = FirstClass(Collector::default());
+        assert_eq!(
+            program.edit(|statements| visitor.visit_node(statements)),
+            Ok(Flow::Break)
+        );
+        assert_eq!(visitor.0.classes, vec!["Foo".to_string()]);
+        assert_eq!(visitor.0.returns, 0);
+    }
 }
@@ -0,0 +1,97 @@
+#![cfg(feature = "cli")]
+
+use assert_cmd::Command;
+
+fn php_parser() -> Command {
+    Command::cargo_bin("php-parser").unwrap()
+}
+
+#[test]
+fn test_debug_tree() {
+    let output = php_parser()
+        .args(["ast", "tests/fixtures/0001/code.php"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let output = String::from_utf8(output).unwrap();
+    let mut lines = output.lines();
+
+    assert_eq!(lines.next(), Some("FullOpeningTag @1:1..1:6"));
+    assert_eq!(lines.next(), Some("Function @3:1..5:2"));
+    assert_eq!(lines.next(), Some("  name: value=\"foo\" @3:10..3:13"));
+}
+
+#[test]
+fn test_json_from_stdin() {
+    let output = php_parser()
+        .args(["ast", "-", "--format=json"])
+        .write_stdin("<?php echo 1;")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(json[1]["type"], "Echo");
+    assert_eq!(
+        json[1]["value"]["values"][0]["value"]["value"]["value"],
+        "1"
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    let output = php_parser()
+        .args([
+            "ast",
+            "tests/fixtures/0375-unparenthesized-nested-ternary/code.php",
+        ])
+        .assert()
+        .failure()
+        .stdout("")
+        .get_output()
+        .stderr
+        .clone();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "[E060] Error: cannot nest ternary expressions without parentheses on line 3 column 14, Note: use either `(a ? b : c) ? d : e` or `a ? b : (c ? d : e)`\n"
+    );
+}
+
+#[test]
+fn test_missing_file() {
+    php_parser()
+        .args(["ast", "tests/fixtures/missing.php"])
+        .assert()
+        .failure()
+        .stdout("");
+}
+
+#[test]
+fn test_features() {
+    php_parser()
+        .arg("features")
+        .assert()
+        .success()
+        .stdout(std::fs::read_to_string("FEATURES.md").unwrap());
+
+    let output = php_parser()
+        .args(["features", "--format=json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(json[0]["id"], "ArrowFunctions");
+    assert_eq!(json[0]["since"], "7.4");
+    assert_eq!(json[0]["status"]["type"], "Supported");
+}
@@ -0,0 +1,182 @@
+use php_parser_rs::parser::ast::identifiers::Identifier;
+use php_parser_rs::parser::ast::variables::Variable;
+use php_parser_rs::parser::ast::{Expression, UseKind};
+use php_parser_rs::parser::completion::{expected_at, ExpectedAt};
+use php_parser_rs::source::SourceFile;
+
+const CURSOR: &str = "<cursor>";
+
+fn expected(code: &str) -> Option<ExpectedAt> {
+    let offset = code.find(CURSOR).unwrap();
+    let code = code.replace(CURSOR, "");
+
+    expected_at(&code, offset)
+}
+
+fn variable(expression: &Expression) -> &[u8] {
+    match expression {
+        Expression::Variable(Variable::SimpleVariable(variable)) => &variable.name,
+        expression => panic!("expected a variable, found {:?}", expression),
+    }
+}
+
+fn identifier(expression: &Expression) -> &[u8] {
+    match expression {
+        Expression::Identifier(Identifier::SimpleIdentifier(identifier)) => &identifier.value,
+        expression => panic!("expected an identifier, found {:?}", expression),
+    }
+}
+
+#[test]
+fn test_member_name() {
+    for code in [
+        "<?php $user-><cursor>",
+        "<?php $user?-><cursor>",
+        "<?php\n\nif ($user-> <cursor>",
+    ] {
+        match expected(code) {
+            Some(ExpectedAt::MemberName { receiver }) => assert_eq!(variable(&receiver), b"$user"),
+            other => panic!("{}: {:?}", code, other),
+        }
+    }
+
+    match expected("<?php $this->repository->users()-><cursor>") {
+        Some(ExpectedAt::MemberName { receiver }) => {
+            assert!(matches!(*receiver, Expression::MethodCall(_)))
+        }
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn test_static_member_name() {
+    match expected("<?php echo Config::<cursor>") {
+        Some(ExpectedAt::StaticMemberName { class }) => assert_eq!(identifier(&class), b"Config"),
+        other => panic!("{:?}", other),
+    }
+
+    match expected("<?php static::<cursor>") {
+        Some(ExpectedAt::StaticMemberName { class }) => {
+            assert!(matches!(*class, Expression::Static(_)))
+        }
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn test_type_name() {
+    for code in [
+        "<?php function foo(): <cursor>",
+        "<?php function foo(?<cursor>",
+        "<?php class Foo { public int|<cursor>",
+    ] {
+        assert_eq!(expected(code), Some(ExpectedAt::TypeName), "{}", code);
+    }
+}
+
+#[test]
+fn test_use_path() {
+    assert_eq!(
+        expected("<?php use <cursor>"),
+        Some(ExpectedAt::UsePath {
+            kind: UseKind::Normal,
+            prefix: None
+        })
+    );
+    assert_eq!(
+        expected("<?php use function <cursor>"),
+        Some(ExpectedAt::UsePath {
+            kind: UseKind::Function,
+            prefix: None
+        })
+    );
+
+    match expected("<?php use App\\Models\\{User, <cursor>") {
+        Some(ExpectedAt::UsePath {
+            kind: UseKind::Normal,
+            prefix: Some(prefix),
+        }) => assert_eq!(prefix.value, b"App\\Models\\"),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn test_attribute_name() {
+    for code in [
+        "<?php #[<cursor>",
+        "<?php #[Pure, <cursor>",
+        "<?php class Foo { #[<cursor>",
+    ] {
+        assert_eq!(expected(code), Some(ExpectedAt::AttributeName), "{}", code);
+    }
+}
+
+#[test]
+fn test_argument() {
+    match expected("<?php str_pad($name, <cursor>") {
+        Some(ExpectedAt::Argument {
+            callee: Some(callee),
+        }) => assert_eq!(identifier(&callee), b"str_pad"),
+        other => panic!("{:?}", other),
+    }
+
+    match expected("<?php new DateTime(<cursor>") {
+        Some(ExpectedAt::Argument {
+            callee: Some(callee),
+        }) => assert_eq!(identifier(&callee), b"DateTime"),
+        other => panic!("{:?}", other),
+    }
+
+    assert_eq!(
+        expected("<?php $user->rename(<cursor>"),
+        Some(ExpectedAt::Argument { callee: None })
+    );
+
+    // The value of a named argument is any expression.
+    assert_eq!(
+        expected("<?php foo(name: <cursor>"),
+        Some(ExpectedAt::Expression)
+    );
+}
+
+#[test]
+fn test_variable() {
+    for code in [
+        "<?php function foo(int <cursor>",
+        "<?php try {} catch (Exception <cursor>",
+        "<?php function () use (<cursor>",
+    ] {
+        assert_eq!(expected(code), Some(ExpectedAt::Variable), "{}", code);
+    }
+}
+
+#[test]
+fn test_expression() {
+    for code in [
+        "<?php $a = <cursor>",
+        "<?php return $a + <cursor>",
+        "<?php echo [1, <cursor>",
+    ] {
+        assert_eq!(expected(code), Some(ExpectedAt::Expression), "{}", code);
+    }
+}
+
+#[test]
+fn test_nothing_expected() {
+    // The code before the cursor parses.
+    assert_eq!(expected("<?php $a = 1;<cursor>"), None);
+    // The parse fails before reaching the cursor.
+    assert_eq!(expected("<?php $a = );\n\n$b-><cursor>"), None);
+    // The cursor is past the end of the code.
+    assert_eq!(expected_at("<?php $a->", 100), None);
+}
+
+#[test]
+fn test_source_file_expected_at() {
+    let file = SourceFile::from_bytes("input.php", "<?php\n\n$user->\n\necho 1;\n");
+
+    match file.expected_at("<?php\n\n$user->".len()) {
+        Some(ExpectedAt::MemberName { receiver }) => assert_eq!(variable(&receiver), b"$user"),
+        other => panic!("{:?}", other),
+    }
+}
@@ -1,5 +1,4 @@
 <?php
 
-// while PHP allows this, we shouldn't, it's a syntax error that most parsers for other languages,
-// including Rust, don't allow.
+// PHP wraps octal escapes above `\377` around, with a warning, so this is `\266`.
 $a = "\666";
@@ -5,6 +5,6 @@ interface B {}
 interface C {}
 interface D {}
 
-function foo(A|(B&C&D) $a): A&(B|C|D) {
+function foo(A|(B&C&D) $a): (B&C&D)|A {
     exit(0);
 }
@@ -32,14 +32,14 @@ static function (Foo|(Bar&Baz) &...$s): Bar { return Bar::from($s); };
 static function (Foo|(Bar&Baz) ...$s): Bar { return Bar::from($s); };
 static function (Foo|(Bar&Baz) &$s): Bar { return Bar::from($s); };
 static function (Foo|(Bar&Baz) $s): Bar { return Bar::from($s); };
-static fn (Foo&(Bar|Baz) &...$s): Bar => Bar::from($s);
-static fn (Foo&(Bar|Baz) ...$s): Bar => Bar::from($s);
-static fn (Foo&(Bar|Baz) &$s): Bar => Bar::from($s);
-static fn (Foo&(Bar|Baz) $s): Bar => Bar::from($s);
-static function (Foo&(Bar|Baz) &...$s): Bar { return Bar::from($s); };
-static function (Foo&(Bar|Baz) ...$s): Bar { return Bar::from($s); };
-static function (Foo&(Bar|Baz) &$s): Bar { return Bar::from($s); };
-static function (Foo&(Bar|Baz) $s): Bar { return Bar::from($s); };
+static fn ((Foo&Bar)|Baz &...$s): Bar => Bar::from($s);
+static fn ((Foo&Bar)|Baz ...$s): Bar => Bar::from($s);
+static fn ((Foo&Bar)|Baz &$s): Bar => Bar::from($s);
+static fn ((Foo&Bar)|Baz $s): Bar => Bar::from($s);
+static function ((Foo&Bar)|Baz &...$s): Bar { return Bar::from($s); };
+static function ((Foo&Bar)|Baz ...$s): Bar { return Bar::from($s); };
+static function ((Foo&Bar)|Baz &$s): Bar { return Bar::from($s); };
+static function ((Foo&Bar)|Baz $s): Bar { return Bar::from($s); };
 
 fn (Foo &...$s): Bar => Bar::from($s);
 fn (Foo ...$s): Bar => Bar::from($s);
@@ -73,14 +73,14 @@ function (Foo|(Bar&Baz) &...$s): Bar { return Bar::from($s); };
 function (Foo|(Bar&Baz) ...$s): Bar { return Bar::from($s); };
 function (Foo|(Bar&Baz) &$s): Bar { return Bar::from($s); };
 function (Foo|(Bar&Baz) $s): Bar { return Bar::from($s); };
-fn (Foo&(Bar|Baz) &...$s): Bar => Bar::from($s);
-fn (Foo&(Bar|Baz) ...$s): Bar => Bar::from($s);
-fn (Foo&(Bar|Baz) &$s): Bar => Bar::from($s);
-fn (Foo&(Bar|Baz) $s): Bar => Bar::from($s);
-function (Foo&(Bar|Baz) &...$s): Bar { return Bar::from($s); };
-function (Foo&(Bar|Baz) ...$s): Bar { return Bar::from($s); };
-function (Foo&(Bar|Baz) &$s): Bar { return Bar::from($s); };
-function (Foo&(Bar|Baz) $s): Bar { return Bar::from($s); };
+fn ((Foo&Bar)|Baz &...$s): Bar => Bar::from($s);
+fn ((Foo&Bar)|Baz ...$s): Bar => Bar::from($s);
+fn ((Foo&Bar)|Baz &$s): Bar => Bar::from($s);
+fn ((Foo&Bar)|Baz $s): Bar => Bar::from($s);
+function ((Foo&Bar)|Baz &...$s): Bar { return Bar::from($s); };
+function ((Foo&Bar)|Baz ...$s): Bar { return Bar::from($s); };
+function ((Foo&Bar)|Baz &$s): Bar { return Bar::from($s); };
+function ((Foo&Bar)|Baz $s): Bar { return Bar::from($s); };
 
 function foo(Foo &...$s): Bar { return Bar::from($s); }
 function foo(Foo ...$s): Bar { return Bar::from($s); }
@@ -98,7 +98,7 @@ function foo(Foo|(Bar&Baz) &...$s): Bar { return Bar::from($s); }
 function foo(Foo|(Bar&Baz) ...$s): Bar { return Bar::from($s); }
 function foo(Foo|(Bar&Baz) &$s): Bar { return Bar::from($s); }
 function foo(Foo|(Bar&Baz) $s): Bar { return Bar::from($s); }
-function foo(Foo&(Bar|Baz) &...$s): Bar { return Bar::from($s); }
-function foo(Foo&(Bar|Baz) ...$s): Bar { return Bar::from($s); }
-function foo(Foo&(Bar|Baz) &$s): Bar { return Bar::from($s); }
-function foo(Foo&(Bar|Baz) $s): Bar { return Bar::from($s); }
+function foo((Foo&Bar)|Baz &...$s): Bar { return Bar::from($s); }
+function foo((Foo&Bar)|Baz ...$s): Bar { return Bar::from($s); }
+function foo((Foo&Bar)|Baz &$s): Bar { return Bar::from($s); }
+function foo((Foo&Bar)|Baz $s): Bar { return Bar::from($s); }
@@ -0,0 +1,12 @@
+<?php
+
+function foo(): iterable {
+    yield;
+    yield $a;
+    yield $k => $v;
+    yield from $a;
+    yield from bar();
+
+    $x = yield;
+    $y = (yield $k => $v);
+}
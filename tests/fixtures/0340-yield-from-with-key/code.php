@@ -0,0 +1,5 @@
+<?php
+
+function foo(): iterable {
+    yield from $k => $v;
+}
@@ -0,0 +1,13 @@
+<?php
+
+$a = function () use ($a, &$b) {
+    $b = $a;
+};
+
+$b = static function (int $x) use (&$total): int {
+    return $total += $x;
+};
+
+$c = function &() use ($a,) {
+    return $a;
+};
@@ -0,0 +1,5 @@
+<?php
+
+$a = function () use (&$this) {
+    return $this;
+};
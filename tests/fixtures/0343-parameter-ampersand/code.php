@@ -0,0 +1,18 @@
+<?php
+
+function a(A&B $x) {}
+function b(A & B $x) {}
+function c(A &$x) {}
+function d(A & ...$x) {}
+function e(A &...$x) {}
+function f(callable&$x) {}
+function g((A&B)|C &$x) {}
+function h(A&B &$x) {}
+function i(A&B&C ...$x) {}
+function j(A & /* by-ref */ $x) {}
+function k(?A &$x = null) {}
+function l(&$x, &...$y) {}
+
+class Foo {
+    public function __construct(public A&B $x, private A &$y) {}
+}
@@ -0,0 +1,9 @@
+<?php
+
+throw new Exception();
+
+$x = $cond ? throw new Exception() : 1;
+$y = $value ?? throw new Exception('missing');
+$z = $value ?: throw $exception;
+
+$fn = fn() => throw new Exception();
@@ -0,0 +1,7 @@
+<?php
+
+function foo($x, int $y, string $x) {}
+
+class Foo {
+    public function __construct(private $a, $a) {}
+}
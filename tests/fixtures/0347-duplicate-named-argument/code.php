@@ -0,0 +1,3 @@
+<?php
+
+foo(a: 1, b: 2, a: 3);
@@ -0,0 +1,12 @@
+<?php
+
+enum Foo {
+    case Bar;
+    case Baz;
+    case Bar;
+}
+
+enum Qux: int {
+    case Bar = 1;
+    case Bar = 2;
+}
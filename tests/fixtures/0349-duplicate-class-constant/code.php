@@ -0,0 +1,5 @@
+<?php
+
+class Foo {
+    const A = 1, B = 2, A = 3;
+}
@@ -0,0 +1,9 @@
+<?php
+
+$a = ['a' => 1, 'a' => 2];
+
+$b = match ($c) {
+    1, 1 => 'one',
+    1 => 'uno',
+    default => 'other',
+};
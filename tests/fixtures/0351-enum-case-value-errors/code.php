@@ -0,0 +1,13 @@
+<?php
+
+enum Suit: string {
+    case Hearts = 'H';
+    case Diamonds;
+    case Clubs = 'C';
+    case Spades;
+}
+
+enum Status {
+    case Active;
+    case Inactive = 0;
+}
@@ -0,0 +1,7 @@
+<?php
+
+$a ??= $b ??= $c;
+
+$foo->bar ??= [];
+Foo::$bar ??= [];
+$foo['bar'] ??= 1;
@@ -0,0 +1,5 @@
+<?php
+
+foo() ??= 1;
+$foo?->bar ??= 1;
+[$a, $b] ??= [1, 2];
@@ -0,0 +1,7 @@
+<?php
+
+if (!$a = foo()) {}
+
+@$b = bar();
+
+$c && $d = 1;
@@ -0,0 +1,17 @@
+<?php
+
+$a += 1;
+$a -= 1;
+$a *= 1;
+$a /= 1;
+$a %= 1;
+$a **= 1;
+$a .= 1;
+$a &= 1;
+$a |= 1;
+$a ^= 1;
+$a <<= 1;
+$a >>= 1;
+$a ??= 1;
+
+$a += $b -= $c .= $d;
@@ -0,0 +1,5 @@
+<?php
+
+list($a, $b) += 1;
+new Foo() .= 'bar';
+FOO **= 2;
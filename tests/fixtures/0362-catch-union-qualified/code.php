@@ -0,0 +1,6 @@
+<?php
+
+try {
+} catch (\Foo | Bar\Baz $e) {
+} catch (Enum | \Qux\From) {
+}
@@ -0,0 +1,5 @@
+<?php
+
+try {
+} catch (\Foo | Bar\Baz | $e) {
+}
@@ -0,0 +1,8 @@
+<?php
+
+$a | $b & $c;
+$a ^ $b | $c & $d;
+$a == $b & $c;
+$a < $b == $c;
+$a << 1 + 2;
+$a . $b << 2;
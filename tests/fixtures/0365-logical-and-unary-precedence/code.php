@@ -0,0 +1,13 @@
+<?php
+
+$a || $b && $c;
+$a and $b or $c xor $d;
+$a = $b and $c;
+!$a instanceof B;
+-$a ** 2;
+$a . $b++;
+print $a and $b;
+
+function foo() {
+    yield $a or $b;
+}
@@ -0,0 +1,11 @@
+<?php
+
+class Foo {
+    public readonly int|string $id;
+    readonly protected ?Bar $bar;
+    private readonly Baz&Qux $baz;
+
+    public function __construct(
+        public readonly int|float $amount,
+    ) {}
+}
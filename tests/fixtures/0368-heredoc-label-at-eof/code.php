@@ -0,0 +1,2 @@
+<?php
+<<<E
\ No newline at end of file
@@ -0,0 +1 @@
+<?php 
\ No newline at end of file
@@ -0,0 +1,2 @@
+<?php
+\Class
\ No newline at end of file
@@ -0,0 +1,2 @@
+<?php
+eval(
\ No newline at end of file
@@ -0,0 +1,2 @@
+<?php
+empty(
\ No newline at end of file
@@ -0,0 +1,6 @@
+<?php
+
+$a ? $b : ($c ? $d : $e);
+($a ? $b : $c) ? $d : $e;
+$a ?: ($b ? $c : $d);
+$a ? $b : $c = $d;
@@ -0,0 +1,5 @@
+<table>
+<?php foreach ($rows as $row): ?>
+<tr><td><?= $row->format('%s') ?></td><td><?= $row?->name, ' ', $row::LABEL ?></td></tr>
+<?php endforeach; ?>
+</table>
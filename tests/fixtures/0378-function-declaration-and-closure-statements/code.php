@@ -0,0 +1,11 @@
+<?php
+
+function foo(): void {}
+function &bar(): never { exit; }
+function () {};
+function &() use ($a): void {};
+
+if ($a) {
+    function baz(): void {}
+    function (): never { exit; };
+}
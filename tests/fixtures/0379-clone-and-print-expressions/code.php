@@ -0,0 +1,9 @@
+<?php
+
+$a = clone $b;
+$a = clone $b->c();
+$a = clone $b[0] ?? $c;
+$a = print "hi";
+print("a") . "b";
+print $a ? 1 : 2;
+$a && print $b;
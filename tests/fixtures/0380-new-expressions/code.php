@@ -0,0 +1,11 @@
+<?php
+
+new static;
+new static();
+new $var;
+new $var($a);
+new $a->b($c);
+new $a['b']->c();
+new Foo::$bar;
+new static::$cls($a);
+new (trim(' Foo '));
@@ -0,0 +1,10 @@
+<?php
+
+$foo = new class ($x, ...$rest) extends Base implements Countable, Stringable {
+    public function __construct(private int $x) {}
+
+    public function count(): int
+    {
+        return $this->x;
+    }
+};
@@ -0,0 +1,7 @@
+<?php
+
+$a = "\xff\x80";
+$b = "caf\u{e9}";
+$c = <<<EOT
+\xc3\x28 $a
+EOT;
@@ -0,0 +1,5 @@
+<?php
+
+$a = ($x === 1 ? match ($y) { 1 => 'a', default => 'b' } : $z);
+$b = match ($x) { 1 => true, default => false } ? match ($y) { default => 1 } : match ($z) { default => 2 };
+$c = $x ?: match ($y) { default => null };
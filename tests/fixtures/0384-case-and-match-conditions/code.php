@@ -0,0 +1,20 @@
+<?php
+
+switch ($input) {
+    case strtolower($input):
+        break;
+    case $a ? 1 : 2:
+        break;
+    case $a ?: $b;
+        break;
+    case match ($a) { 1, 2 => 'x', default => 'y' }:
+        break;
+    default:
+}
+
+$result = match (true) {
+    $x > 5 && $y < 2 => 'a',
+    str_contains($s, 'x'), in_array($s, [1, 2], true) => 'b',
+    $a ? $b : $c => 'c',
+    default => 'd',
+};
@@ -0,0 +1,8 @@
+<?php
+
+function add(int $a, ?float $b = 1.5, string ...$rest): int|float
+{
+    echo "sum: ", $a + $b, "\n";
+
+    return strlen(implode(', ', $rest)) * -$a;
+}
@@ -0,0 +1,16 @@
+<?php
+
+final class Foo extends Bar implements \Countable
+{
+    const A = 0x1F;
+
+    protected static ?array $items = [1, 'b' => true];
+
+    public function count(): int
+    {
+        return self::A + parent::count() + $this->items[0];
+    }
+}
+
+$foo = new Foo();
+$foo->count(named: null);
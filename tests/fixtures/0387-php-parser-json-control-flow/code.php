@@ -0,0 +1,9 @@
+<?php
+
+if ($a === 1) {
+    $b .= 'one';
+} elseif ($a < 10 && !$c) {
+    $b = $a ? 'yes' : 'no';
+} else {
+    $b = $a ?? null;
+}
@@ -0,0 +1,10 @@
+<?php
+
+abstract class Base
+{
+    abstract public function a(): void;
+
+    public function b()
+    {
+    }
+}
@@ -0,0 +1,16 @@
+<?php
+
+foreach ($items as $item) {
+    switch ($item) {
+        case 1:
+            continue 2;
+        case 2:
+            break 2;
+    }
+}
+
+for (;;) {
+    do {
+        break (2);
+    } while (false);
+}
@@ -0,0 +1,7 @@
+<?php
+
+foreach ($items as $item) {
+    while (true) {
+        break 3;
+    }
+}
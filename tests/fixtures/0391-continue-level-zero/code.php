@@ -0,0 +1,5 @@
+<?php
+
+while (true) {
+    continue 0;
+}
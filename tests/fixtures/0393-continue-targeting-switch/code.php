@@ -0,0 +1,6 @@
+<?php
+
+switch ($a) {
+    case 1:
+        continue;
+}
@@ -0,0 +1,11 @@
+<?php
+
+function foo() {
+    try {
+        yield 1;
+    } finally {
+        yield 2;
+
+        return 3;
+    }
+}
@@ -0,0 +1,10 @@
+<?php
+
+const TYPE = Foo::class;
+
+class Bar
+{
+    const TYPE = \App\Foo::class;
+
+    public const PARENT = parent::class;
+}
@@ -0,0 +1,7 @@
+<?php
+
+$outer = function () use ($a, &$b) {
+    return function ($c) use ($a) {
+        return $a + $c;
+    };
+};
@@ -0,0 +1,5 @@
+<?php
+
+foo(...$args, ...getMore(), ...[1, 2]);
+foo(...['a' => 1, 'b' => 2]);
+foo(...$args, b: 1);
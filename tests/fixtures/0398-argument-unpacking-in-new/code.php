@@ -0,0 +1,3 @@
+<?php
+
+new Foo(...$args, ...getMore(), ...[1, 2]);
@@ -0,0 +1,4 @@
+<?php
+
+#[Foo(...$args)]
+function foo() {}
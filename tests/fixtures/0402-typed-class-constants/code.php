@@ -0,0 +1,13 @@
+<?php
+
+interface HasVersion
+{
+    const string VERSION = '1.0';
+}
+
+final class Version implements HasVersion
+{
+    public const ?int PATCH = null;
+    final protected const int|string MAJOR = 1, MINOR = '0';
+    private const LIST = [self::MAJOR, self::MINOR];
+}
@@ -0,0 +1,6 @@
+<?php
+
+class Foo
+{
+    const void BAR = null;
+}
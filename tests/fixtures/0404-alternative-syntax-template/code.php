@@ -0,0 +1,23 @@
+<ul>
+<?php foreach ($items as $key => $item): ?>
+    <?php if ($item->visible): ?>
+        <?php if ($key === 0): ?>
+            <li class="first"><?= $item->name ?></li>
+        <?php elseif ($key === $last): ?>
+            <li class="last"><?= $item->name ?></li>
+        <?php else: ?>
+            <li><?= $item->name ?></li>
+        <?php endif; ?>
+    <?php endif; ?>
+<?php endforeach; ?>
+</ul>
+<?php switch ($status): ?>
+<?php case 'open': ?>
+    <p>Open</p>
+    <?php break; ?>
+<?php default: ?>
+    <p>Closed</p>
+<?php endswitch; ?>
+<?php while ($row = next($rows)): for ($i = 0; $i < 2; $i++): ?>
+    <td><?= $row[$i] ?></td>
+<?php endfor; endwhile; ?>
@@ -0,0 +1,10 @@
+<?php
+
+while ($x = next($values)) {
+    switch ($x) {
+        case 1:
+            break 2;
+        default:
+            break;
+    }
+}
@@ -0,0 +1,31 @@
+<?php
+
+/**
+ * @param int $x
+ */
+function double(int $x): int
+{
+    // Twice as much.
+    return $x * 2;
+}
+
+/** A point. */
+final class Point
+{
+    use Comparable;
+
+    /** @var int */
+    public $x = 0;
+
+    /** The origin. */
+    var $origin;
+}
+
+/** Suits. */
+enum Suit: string
+{
+    /** Red. */
+    case Hearts = 'H';
+}
+
+// The end.
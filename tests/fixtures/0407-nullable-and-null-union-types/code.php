@@ -0,0 +1,14 @@
+<?php
+
+class Foo
+{
+    public const ?int A = null;
+    public const int|null B = null;
+
+    public ?Foo $a = null;
+    public Foo|null $b = null;
+
+    public function bar(?Foo $a, Foo|null $b): ?Foo {}
+
+    public function baz(): Foo|null {}
+}
@@ -0,0 +1,8 @@
+<?php
+
+use App\{
+    Foo,
+    Bar as B,
+    function baz,
+    const QUX as Q,
+};
@@ -0,0 +1,13 @@
+<?php
+
+// a single-line comment
+# a hash mark comment
+/* a multi-line comment */
+/** a document comment */
+/**
+ * A document comment over several lines.
+ */
+/**/
+/***************************/
+#[Attribute]
+function foo() {}
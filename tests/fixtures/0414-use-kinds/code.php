@@ -0,0 +1,8 @@
+<?php
+
+use Foo\Bar;
+use Foo\Baz as Qux;
+use function strlen;
+use function strlen as len, Foo\bar as baz;
+use const PHP_EOL;
+use const Foo\BAR as BAZ, Foo\QUX;
@@ -0,0 +1,10 @@
+<?php
+
+function f(int $flags = Options::DEFAULT, int $mode = Options::A | Options::B) {}
+
+class Options
+{
+    const DEFAULT = 0;
+
+    public function g(int $flags = self::DEFAULT, $parent = parent::MODE, $static = static::class) {}
+}
@@ -0,0 +1,10 @@
+<?php
+
+static::class;
+Foo::class;
+$class::class;
+$class::method();
+self::$instance;
+static::create();
+parent::VERSION;
+Foo::{$method}();
@@ -0,0 +1,19 @@
+<?php
+
+$a = <<<EOT
+Hello, $name!
+EOT;
+
+$query = <<<"SQL"
+SELECT * FROM users WHERE id = {$id}
+SQL;
+
+$html = <<<'HTML5'
+<p>HTML5</p>
+HTML5;
+
+$empty = <<<EOT
+EOT;
+
+$nothing = <<<'EOT'
+EOT;
@@ -0,0 +1,7 @@
+<?php
+
+$a = <<<EOT
+EOTS are not the end,
+    EOT_ neither,
+EOT2 nor this.
+EOT;
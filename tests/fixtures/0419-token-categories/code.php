@@ -0,0 +1,6 @@
+<h1><?= $title ?></h1>
+<?php
+// Greets.
+function greet(string $name = "World"): string {
+    return 'Hello, ' . $name . 1.5;
+}
@@ -0,0 +1,7 @@
+<?php
+
+function f(int ...$numbers) {}
+
+function g(string &...$references) {}
+
+$h = fn(Foo|Bar ...$values) => $values;
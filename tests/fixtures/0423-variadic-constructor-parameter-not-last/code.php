@@ -0,0 +1,6 @@
+<?php
+
+class Foo
+{
+    public function __construct(...$values, public $b) {}
+}
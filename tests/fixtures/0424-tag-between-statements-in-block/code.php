@@ -0,0 +1,9 @@
+<?php
+
+if ($a) {
+    echo 1;
+    ?>
+    <p>Hello</p>
+    <?php
+    echo 2;
+}
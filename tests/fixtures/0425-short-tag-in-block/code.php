@@ -0,0 +1,6 @@
+<?php
+
+while ($a) { ?>
+    <li>item</li>
+<? next($a);
+}
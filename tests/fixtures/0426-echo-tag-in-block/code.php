@@ -0,0 +1,3 @@
+<?php foreach ($items as $item) { ?>
+    <li><?= $item ?></li>
+<?php }
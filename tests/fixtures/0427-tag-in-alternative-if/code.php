@@ -0,0 +1,5 @@
+<?php if ($a): ?>
+    <p>yes</p>
+<?php else: ?>
+    <p>no</p>
+<?php endif;
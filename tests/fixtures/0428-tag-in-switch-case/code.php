@@ -0,0 +1,7 @@
+<?php switch ($a): ?>
+<?php case 1: ?>
+    <p>one</p>
+<?php break; ?>
+<?php default: ?>
+    <p>other</p>
+<?php endswitch;
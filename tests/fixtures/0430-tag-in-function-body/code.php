@@ -0,0 +1,7 @@
+<?php
+
+function render($title) {
+    ?>
+    <h1><?= $title ?></h1>
+    <?php
+}
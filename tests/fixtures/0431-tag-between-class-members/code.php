@@ -0,0 +1,9 @@
+<?php
+
+class Foo {
+    public $a;
+    ?>
+    <p>Hello</p>
+    <?php
+    public $b;
+}
@@ -0,0 +1,4 @@
+<?php
+
+$a = 1 + ?>
+<?php 2;
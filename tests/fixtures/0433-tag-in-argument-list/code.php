@@ -0,0 +1,3 @@
+<?php
+
+foo(1, ?> <?php 2);
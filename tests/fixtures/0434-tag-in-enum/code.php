@@ -0,0 +1,7 @@
+<?php
+
+enum Suit {
+    case Hearts;
+    ?><?php
+    case Spades;
+}
@@ -0,0 +1,8 @@
+<?php
+
+$a = [
+    1,
+    ?>
+    <?php
+    2,
+];
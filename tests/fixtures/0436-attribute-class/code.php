@@ -0,0 +1,12 @@
+<?php
+
+#[Attribute(Attribute::TARGET_METHOD | Attribute::TARGET_CLASS)]
+final class Route
+{
+    public function __construct(public string $path)
+    {
+    }
+}
+
+#[Attribute]
+class Marker {}
@@ -0,0 +1,9 @@
+<?php
+
+($obj)->method();
+($a = $b)->method();
+(new Foo)->bar()->baz;
+($a)['x'] = 1;
+($f)(1);
+(clone $a)::$b;
+(string) $a;
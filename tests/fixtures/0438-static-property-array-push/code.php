@@ -0,0 +1,3 @@
+<?php
+
+Foo::$instances[] = $x;
@@ -0,0 +1,3 @@
+<?php
+
+parent::$prop = 1;
@@ -0,0 +1,18 @@
+<?php
+
+Foo::$a += 1;
+Foo::$a -= 1;
+Foo::$a *= 1;
+Foo::$a /= 1;
+Foo::$a .= 1;
+Foo::$a %= 1;
+Foo::$a **= 1;
+Foo::$a &= 1;
+Foo::$a |= 1;
+Foo::$a ^= 1;
+Foo::$a <<= 1;
+Foo::$a >>= 1;
+Foo::$a = &$b;
+Foo::$a++;
+--Foo::$a;
+Foo::$a::$b[0]->c = 1;
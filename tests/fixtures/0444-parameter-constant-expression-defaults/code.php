@@ -0,0 +1,19 @@
+<?php
+
+function f(
+    $x = 1 + 2,
+    $y = Foo::BAR,
+    array $z = [1, 'a' => [Foo::BAZ, self::QUX]],
+    int $w = PHP_INT_MAX << 1,
+    ?Foo $v = null,
+    $u = new Foo(1),
+    $required,
+) {}
+
+class Bar
+{
+    public function __construct(
+        private array $options = ['mode' => self::MODE],
+        $required,
+    ) {}
+}
@@ -0,0 +1,11 @@
+<?php
+
+$a = match ($x) {
+    1 => 'a',
+    default => 'b',
+};
+
+$b = match ($x) {
+    default => 'b',
+    1, 2, => 'a',
+};
@@ -0,0 +1,10 @@
+<?php
+
+readonly class Point {
+    public int $x;
+    protected ?Point $next;
+
+    public function __construct(public int $y, private array $z = []) {}
+}
+
+final readonly class Origin {}
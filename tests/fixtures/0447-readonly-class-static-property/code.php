@@ -0,0 +1,5 @@
+<?php
+
+readonly class Foo {
+    public static int $bar;
+}
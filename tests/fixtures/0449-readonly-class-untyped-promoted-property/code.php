@@ -0,0 +1,5 @@
+<?php
+
+readonly class Foo {
+    public function __construct(private $bar) {}
+}
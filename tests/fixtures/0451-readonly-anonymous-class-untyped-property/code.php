@@ -0,0 +1,5 @@
+<?php
+
+$a = new readonly class {
+    var $a;
+};
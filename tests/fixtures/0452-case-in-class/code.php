@@ -0,0 +1,7 @@
+<?php
+
+class Suit {
+    case Hearts;
+
+    public function color(): string {}
+}
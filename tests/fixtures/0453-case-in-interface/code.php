@@ -0,0 +1,5 @@
+<?php
+
+interface HasColor {
+    case Red = 1;
+}
@@ -0,0 +1,9 @@
+<?php
+
+class Collection {
+    private (Countable&ArrayAccess)|array|null $items;
+
+    public function __construct((Countable&ArrayAccess)|array $items) {}
+
+    public function first(): A|(B&C)|null {}
+}
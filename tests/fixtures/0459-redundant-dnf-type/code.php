@@ -0,0 +1,3 @@
+<?php
+
+function foo(A|(A&B) $a) {}
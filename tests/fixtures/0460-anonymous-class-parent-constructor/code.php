@@ -0,0 +1,7 @@
+<?php
+
+$service = new class($logger, ...$rest) extends Base implements Loggable {
+    public function __construct(private Logger $logger, mixed ...$rest) {
+        parent::__construct(...$rest);
+    }
+};
@@ -0,0 +1,15 @@
+<?php
+
+function generator() {
+    yield;
+    yield $value;
+    yield $key => $value;
+    yield from $iterator;
+
+    $received = (yield $value);
+    $received = yield;
+    $items = [yield, yield $key => $value];
+    process(yield, yield from generator());
+
+    yield $a or $b;
+}
@@ -0,0 +1,6 @@
+<?php
+
+foreach ($data as $id => [$name, $email]) {}
+foreach ($data as $id => list($name, $email)) {}
+foreach ($data as $id => ["name" => $name, "tags" => [$first]]) {}
+foreach ($data as $id => [, $email]) {}
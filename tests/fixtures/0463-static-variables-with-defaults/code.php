@@ -0,0 +1,7 @@
+<?php
+
+function counter() {
+    static $count = 0, $step = 1 + 1, $names = ['a', 'b'], $limit = PHP_INT_MAX, $last;
+
+    return $count += $step;
+}
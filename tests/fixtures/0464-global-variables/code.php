@@ -0,0 +1,5 @@
+<?php
+
+function configure() {
+    global $config, $$name, ${'options'};
+}
@@ -0,0 +1,8 @@
+<?php
+
+Foo::{$name};
+$object::{'FOO'};
+static::{$name};
+self::{strtoupper($name)};
+(get_class($object))::{$prefix . 'BAR'};
+Foo::{'bar'}();
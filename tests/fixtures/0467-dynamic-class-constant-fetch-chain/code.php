@@ -0,0 +1,4 @@
+<?php
+
+Foo::{$x}::{$y};
+Foo::{$x}::BAR;
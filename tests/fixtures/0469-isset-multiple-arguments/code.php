@@ -0,0 +1,5 @@
+<?php
+
+if (isset($a, $b['key'], $c->d, $e?->f, Foo::$g, $$h,)) {
+    unset($a, $b['key'], $c->d, Foo::$g[0]);
+}
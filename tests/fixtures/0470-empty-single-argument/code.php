@@ -0,0 +1,5 @@
+<?php
+
+if (empty($a) || empty(foo()) || empty($b['key'] ?? null)) {
+    eval('return 1;');
+}
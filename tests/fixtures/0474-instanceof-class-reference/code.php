@@ -0,0 +1,9 @@
+<?php
+
+$x instanceof Foo;
+$x instanceof $cls;
+$x instanceof $this->class;
+$x instanceof static::$classes['foo'];
+$x instanceof (getClassName());
+$x instanceof ($factory->getClass());
+!$x instanceof self && $x instanceof $y instanceof $z;
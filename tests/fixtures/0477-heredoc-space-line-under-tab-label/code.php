@@ -0,0 +1,6 @@
+<?php
+
+$a = <<<'EOT'
+		foo
+        bar
+		EOT;
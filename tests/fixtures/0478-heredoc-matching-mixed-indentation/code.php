@@ -0,0 +1,8 @@
+<?php
+
+$a = <<<EOT
+	  foo
+	    {$bar}
+
+	   baz
+	  EOT;
@@ -0,0 +1,19 @@
+<?php
+
+class User
+{
+    private string $_name;
+
+    public string $name {
+        get => ucfirst($this->_name);
+        set ($value) {
+            $this->_name = $value;
+        }
+    }
+
+    public string $fullName {
+        get {
+            return $this->first . ' ' . $this->last;
+        }
+    }
+}
@@ -0,0 +1,10 @@
+<?php
+
+class Collection
+{
+    public array $items = [] {
+        final &get => $this->items;
+        #[Deprecated]
+        final set(array $items) => array_values($items);
+    }
+}
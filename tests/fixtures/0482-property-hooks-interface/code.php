@@ -0,0 +1,10 @@
+<?php
+
+interface Named
+{
+    public string $name { get; }
+
+    public string $email { get; set; }
+
+    public function rename(string $name): void;
+}
@@ -0,0 +1,12 @@
+<?php
+
+class Point
+{
+    public function __construct(
+        public int $x = 0 {
+            set => max(0, $value);
+        },
+        public int $y = 0,
+    ) {
+    }
+}
@@ -0,0 +1,8 @@
+<?php
+
+class User
+{
+    public string $name {
+        fetch => $this->name;
+    }
+}
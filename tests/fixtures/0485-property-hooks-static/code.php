@@ -0,0 +1,8 @@
+<?php
+
+class User
+{
+    public static string $name {
+        get => 'user';
+    }
+}
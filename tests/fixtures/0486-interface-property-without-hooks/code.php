@@ -0,0 +1,6 @@
+<?php
+
+interface Named
+{
+    public string $name;
+}
@@ -0,0 +1,18 @@
+<?php
+
+enum Status: string
+{
+    case Active = 'active';
+    case Inactive = 'inactive';
+
+    const DEFAULT = self::Active;
+
+    final public const FALLBACK = [self::Inactive, Status::Active];
+}
+
+enum Suit
+{
+    case Hearts;
+
+    const Wild = self::Hearts;
+}
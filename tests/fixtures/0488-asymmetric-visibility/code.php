@@ -0,0 +1,18 @@
+<?php
+
+class Book
+{
+    public private(set) string $title;
+
+    protected(set) int $pages = 0;
+
+    public protected(set) ?string $isbn {
+        set => strtoupper($value);
+    }
+
+    public function __construct(
+        public private(set) string $author,
+        protected protected(set) readonly array $tags = [],
+    ) {
+    }
+}
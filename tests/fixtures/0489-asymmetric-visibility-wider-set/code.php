@@ -0,0 +1,6 @@
+<?php
+
+class Book
+{
+    private public(set) string $title;
+}
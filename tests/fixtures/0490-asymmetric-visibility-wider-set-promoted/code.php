@@ -0,0 +1,8 @@
+<?php
+
+class Book
+{
+    public function __construct(protected public(set) string $title)
+    {
+    }
+}
@@ -0,0 +1,6 @@
+<?php
+
+class Book
+{
+    public public(set) readonly string $title;
+}
@@ -0,0 +1,6 @@
+<?php
+
+class Book
+{
+    public private(set) protected(set) string $title;
+}
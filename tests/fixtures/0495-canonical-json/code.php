@@ -0,0 +1,23 @@
+<?php
+
+namespace App;
+
+/** Größe in €. */
+final class Size
+{
+    public const MAX = 0x7FFF_FFFF;
+
+    public function __construct(private readonly float $value = 1.5e3) {}
+
+    public function label(): string
+    {
+        return match (true) {
+            $this->value > 9_007_199_254_740_993 => 'huge',
+            default => <<<EOT
+              {$this->value} élan
+              EOT,
+        };
+    }
+}
+
+$sizes = array_map(fn ($v) => new Size($v), [1, 2.0, -3]);
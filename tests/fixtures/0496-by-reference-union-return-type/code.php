@@ -0,0 +1,13 @@
+<?php
+
+function &first(array &$items): int|false {
+    return $items[0];
+}
+
+abstract class A {
+    public function &get(): ?array { return $this->a; }
+    abstract public static function &find(int $id): static|null;
+}
+
+$f = function &(): A|B {};
+$g = fn &(): int|string => 1;
@@ -0,0 +1,11 @@
+<?php
+
+class A extends B {
+    function f($obj, $other) {
+        return $obj instanceof $other
+            || $obj instanceof parent
+            || $obj instanceof static
+            || $obj instanceof \Ns\Foo === false
+            || -$obj instanceof self != true;
+    }
+}
@@ -0,0 +1,11 @@
+<?php
+
+$exit = exit(...);
+$die = DIE(...);
+array_map(exit(...), $codes);
+
+exit(status: 1);
+die(...$arguments);
+exit;
+exit();
+die("bye");
@@ -0,0 +1,23 @@
+<?php
+
+(int) $a;
+(integer) $a;
+(float) $a;
+(double) $a;
+(string) $a;
+(binary) $a;
+(bool) $a;
+(boolean) $a;
+(array) $a;
+(object) $a;
+(unset) $a;
+(int)(float)$x;
+(INT) ( string ) -$x ** 2;
+(int) + 1;
+(int) ($x);
+(int) $x ** 2;
+(int) $a + 1;
+(integer_value);
+(int_max) + 1;
+(arrays);
+(object_id) - $b;
@@ -0,0 +1,5 @@
+<?php
+
+5 = $x;
+foo() = 1;
+$a->b() = 1;
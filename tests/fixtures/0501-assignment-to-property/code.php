@@ -0,0 +1,8 @@
+<?php
+
+$a->b = 1;
+$a->b->c = 1;
+$a->{$b} = 1;
+A::$b = 1;
+$a->b[] = 1;
+[$a->b, $c[0]] = $d;
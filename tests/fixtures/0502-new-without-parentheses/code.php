@@ -0,0 +1,14 @@
+<?php
+new Foo()->bar();
+new Foo()?->bar;
+new Foo()::CONST;
+new Foo()::$a;
+new Foo()["x"];
+new Foo()();
+new class {}->foo();
+new class(1) {}::$x;
+new $a->b()->c;
+new static()->a->b()[0];
+new Foo()->bar = 1;
+$x = new Foo();
+new (Foo::class)()->a;
@@ -0,0 +1,16 @@
+<?php
+
+declare(ticks=1):
+    $a = 1;
+
+    if ($a) {
+        echo $a;
+    }
+
+    function foo() {}
+enddeclare;
+
+declare(ticks=1):
+    echo 2;
+enddeclare ?>
+<p>done</p>
@@ -0,0 +1,17 @@
+<?php
+
+1_000_000;
+0xFF;
+0X1a_2B;
+0o17;
+0O1_7;
+017;
+0_17;
+0b1010;
+0B10_10;
+1.5e10;
+1.5E-10;
+1_000.000_5e1_0;
+.5;
+1.;
+9223372036854775808;
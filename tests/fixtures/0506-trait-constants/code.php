@@ -0,0 +1,10 @@
+<?php
+
+trait Loggable
+{
+    const LEVEL = 'debug';
+    final public const int RETRIES = 3;
+    protected const string|null CHANNEL = null;
+    #[Deprecated]
+    private const array TAGS = [];
+}
@@ -0,0 +1,6 @@
+<?php
+
+trait Loggable
+{
+    abstract const LEVEL = 'debug';
+}
@@ -0,0 +1,6 @@
+<?php
+
+trait Loggable
+{
+    static const LEVEL = 'debug';
+}
@@ -0,0 +1,30 @@
+<?php
+
+class A {
+    public $a; // end of members
+}
+
+enum E {
+    case A;
+    /* last */
+}
+
+enum F: string {
+    case A = 'a';
+    # last
+}
+
+trait T {
+    public function foo() {}
+
+    /** todo */
+}
+
+interface I {
+    // nothing yet
+}
+
+$a = new class {
+    public $a;
+    // end of members
+};
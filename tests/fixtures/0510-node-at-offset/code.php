@@ -0,0 +1,12 @@
+<html>
+<?php
+
+function foo($a, ...$b) {
+    $c = [1 => $a];
+
+    return $a + $b;
+}
+
+echo "x{$a}y";
+?>
+<p><?= $a ?></p>
@@ -0,0 +1,9 @@
+<?php
+
+function &foo(int &$a, string ...$b) {
+    return $a;
+}
+
+$c = fn (&$d, ...$e) => $d;
+
+foo(...$f);
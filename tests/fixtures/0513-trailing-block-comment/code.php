@@ -0,0 +1,6 @@
+<?php
+
+echo 1; /* a
+b */
+echo 2; /* c
+ * d */ echo 3;
@@ -0,0 +1,4 @@
+<p><?= // c
+$a ?></p>
+<p><?= # d
+$b, /* e */ $c ?></p>
@@ -0,0 +1,10 @@
+<?php
+
+function foo(): iterable {
+    $a = [yield from $x => 1];
+    $b = array(yield from $y => 2);
+
+    return match ($c) {
+        yield from $g => 3,
+    };
+}
@@ -0,0 +1,45 @@
+<?php
+
+// Dumps the `php-parser.json` of a fixture with nikic/php-parser, keeping only
+// the position attributes that `to_php_parser_json` emits.
+//
+// Usage: php tests/php-parser/dump.php tests/fixtures/<fixture>
+
+declare(strict_types=1);
+
+require __DIR__ . '/vendor/autoload.php';
+
+use PhpParser\Node;
+use PhpParser\NodeTraverser;
+use PhpParser\NodeVisitorAbstract;
+use PhpParser\ParserFactory;
+
+$fixture = $argv[1] ?? null;
+if ($fixture === null) {
+    fwrite(STDERR, "usage: php dump.php <fixture>\n");
+    exit(1);
+}
+
+$parser = (new ParserFactory())->createForNewestSupportedVersion();
+$statements = $parser->parse(file_get_contents($fixture . '/code.php'));
+
+$traverser = new NodeTraverser();
+$traverser->addVisitor(new class extends NodeVisitorAbstract {
+    private const POSITIONS = [
+        'startLine' => true,
+        'startFilePos' => true,
+        'endLine' => true,
+        'endFilePos' => true,
+    ];
+
+    public function enterNode(Node $node)
+    {
+        $node->setAttributes(array_intersect_key($node->getAttributes(), self::POSITIONS));
+
+        return null;
+    }
+});
+$statements = $traverser->traverse($statements);
+
+$json = json_encode($statements, JSON_PRETTY_PRINT | JSON_UNESCAPED_SLASHES | JSON_UNESCAPED_UNICODE | JSON_THROW_ON_ERROR);
+file_put_contents($fixture . '/php-parser.json', $json . "\n");
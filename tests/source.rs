@@ -0,0 +1,139 @@
+use php_parser_rs::parser::ast::classes::ClassMember;
+use php_parser_rs::parser::ast::spans::NodeKind;
+use php_parser_rs::parser::ast::Spanned;
+use php_parser_rs::parser::ast::Statement;
+use php_parser_rs::source::SourceFile;
+
+fn offset_of(file: &SourceFile, needle: &str) -> usize {
+    file.bytes()
+        .windows(needle.len())
+        .position(|window| window == needle.as_bytes())
+        .unwrap()
+}
+
+#[test]
+fn test_source_file() {
+    let path = "tests/fixtures/0446-readonly-class/code.php";
+    let file = SourceFile::from_path(path).unwrap();
+
+    assert_eq!(file.name(), path);
+    assert_eq!(file.bytes(), std::fs::read(path).unwrap());
+    assert!(file.diagnostics().is_empty());
+    assert_eq!(file.report(false, true).unwrap(), "");
+    assert_eq!(file.program(), &php_parser_rs::parse(file.bytes()).unwrap());
+
+    let point = offset_of(&file, "Point {");
+    let position = file.position(point).unwrap();
+    assert_eq!((position.line, position.column), (3, 16));
+    assert_eq!(file.snippet(position), b"readonly class Point {");
+
+    let end = file.bytes().len();
+    assert_eq!(file.position(end).unwrap().column, 1);
+    assert_eq!(file.position(end + 1), None);
+    assert_eq!(file.snippet(file.position(end).unwrap()), b"");
+
+    let node = file.node_at(point + 2).unwrap();
+    assert_eq!(
+        (node.kind, node.span.offset()),
+        (NodeKind::Identifier, point)
+    );
+
+    let x = offset_of(&file, "$x");
+    let node = file.node_at(x + 1).unwrap();
+    assert_eq!((node.kind, node.span.offset()), (NodeKind::Variable, x));
+
+    // Whitespace, and a brace that no node starts at.
+    assert_eq!(file.node_at(point - 1), None);
+    assert_eq!(file.node_at(point + "Point ".len()), None);
+}
+
+#[test]
+fn test_source_file_with_errors() {
+    let path = "tests/fixtures/0452-case-in-class/code.php";
+    let file = SourceFile::from_path(path).unwrap();
+
+    let ids: Vec<&str> = file
+        .diagnostics()
+        .iter()
+        .map(|error| error.id.as_str())
+        .collect();
+    assert_eq!(ids, ["E075"]);
+
+    let report = file.report(false, true).unwrap();
+    assert!(report.contains("[E075] Error: case declarations are only allowed in enums"));
+    assert!(report.contains(path));
+
+    // The misplaced case is left out of the class, which is kept.
+    match &file.program()[1] {
+        Statement::Class(class) => {
+            assert!(matches!(
+                class.body.members[..],
+                [ClassMember::ConcreteMethod(_)]
+            ));
+        }
+        statement => panic!("expected a class, found {:?}", statement),
+    }
+
+    let case = offset_of(&file, "case");
+    let span = file.diagnostics()[0].span;
+    assert_eq!(span, file.position(case).unwrap());
+    assert_eq!(file.snippet(span), b"    case Hearts;");
+}
+
+#[test]
+fn test_source_file_from_bytes() {
+    let file = SourceFile::from_bytes("input.php", "<?php\r\nfoo(\r\n");
+
+    assert_eq!(file.name(), "input.php");
+    assert_eq!(file.program().len(), 1);
+    assert_eq!(file.diagnostics().len(), 1);
+    assert_eq!(file.snippet(file.position(7).unwrap()), b"foo(");
+
+    // A file that cannot be split into tokens has no nodes.
+    let file = SourceFile::from_bytes("input.php", "<?php \"unterminated");
+
+    assert_eq!(file.program().len(), 0);
+    assert_eq!(file.node_at(0), None);
+}
+
+#[test]
+fn test_source_file_deprecations() {
+    let file = SourceFile::from_bytes("input.php", "<?php\n\necho \"${a} {$b}\";\nfoo(\n");
+
+    assert_eq!(file.diagnostics().len(), 1);
+    assert_eq!(file.deprecations().len(), 1);
+
+    let deprecation = &file.deprecations()[0];
+    assert_eq!(deprecation.start, file.position(13).unwrap());
+    assert_eq!(deprecation.end, file.position(16).unwrap());
+}
+
+#[test]
+fn test_source_file_text() {
+    let code = "<?php\n\n// Checks $a.\nif ($a) {\n    echo 1;\n} elseif ($b) {\n    echo \"b\\n\";\n} else {\n    echo <<<EOT\n    c\n    EOT;\n}\n\nif ($c):\n    echo 4;\nendif ?>\n";
+    let file = SourceFile::from_bytes("input.php", code);
+
+    let statements = file
+        .program()
+        .iter()
+        .filter(|statement| matches!(statement, Statement::If(_)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        file.text(statements[0].span()),
+        b"if ($a) {\n    echo 1;\n} elseif ($b) {\n    echo \"b\\n\";\n} else {\n    echo <<<EOT\n    c\n    EOT;\n}"
+    );
+    assert_eq!(
+        file.text(statements[1].span()),
+        b"if ($c):\n    echo 4;\nendif ?>"
+    );
+
+    let Statement::If(statement) = statements[0] else {
+        unreachable!()
+    };
+    assert_eq!(file.text(statement.condition.span()), b"$a");
+    assert_eq!(
+        file.text(statement.body.span()),
+        &file.text(statements[0].span())[8..]
+    );
+}
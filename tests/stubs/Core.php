@@ -0,0 +1,2050 @@
+<?php
+
+// Start of Core v.8.2
+
+use JetBrains\PhpStorm\ArrayShape;
+use JetBrains\PhpStorm\Deprecated;
+use JetBrains\PhpStorm\ExpectedValues;
+use JetBrains\PhpStorm\Internal\LanguageLevelTypeAware;
+use JetBrains\PhpStorm\Internal\PhpStormStubsElementAvailable;
+use JetBrains\PhpStorm\Internal\TentativeType;
+use JetBrains\PhpStorm\Pure;
+
+/**
+ * Gets the version of the current Zend engine
+ * @link https://php.net/manual/en/function.zend-version.php
+ * @return string the Zend Engine version number, as a string.
+ */
+#[Pure]
+function zend_version(): string {}
+
+/**
+ * Returns the number of arguments passed to the function
+ * @link https://php.net/manual/en/function.func-num-args.php
+ * @return int the number of arguments passed into the current user-defined
+ * function.
+ */
+#[Pure]
+function func_num_args(): int {}
+
+/**
+ * Return an item from the argument list
+ * @link https://php.net/manual/en/function.func-get-arg.php
+ * @param int $position <p>
+ * The argument offset. Function arguments are counted starting from
+ * zero.
+ * </p>
+ * @return mixed|false the specified argument, or false on error.
+ */
+#[Pure]
+function func_get_arg(int $position): mixed {}
+
+/**
+ * Returns an array comprising a function's argument list
+ * @link https://php.net/manual/en/function.func-get-args.php
+ * @return array an array in which each element is a copy of the corresponding
+ * member of the current user-defined function's argument list.
+ */
+#[Pure]
+function func_get_args(): array {}
+
+/**
+ * Get string length
+ * @link https://php.net/manual/en/function.strlen.php
+ * @param string $string <p>
+ * The string being measured for length.
+ * </p>
+ * @return int<0, max> The length of the <i>string</i> on success,
+ * and 0 if the <i>string</i> is empty.
+ */
+#[Pure]
+function strlen(string $string): int {}
+
+/**
+ * Binary safe string comparison
+ * @link https://php.net/manual/en/function.strcmp.php
+ * @param string $string1 <p>
+ * The first string.
+ * </p>
+ * @param string $string2 <p>
+ * The second string.
+ * </p>
+ * @return int less 0 if <i>string1</i> is less than
+ * <i>string2</i>; &gt; 0 if <i>string1</i>
+ * is greater than <i>string2</i>, and 0 if they are
+ * equal.
+ */
+#[Pure]
+function strcmp(string $string1, string $string2): int {}
+
+/**
+ * Binary safe string comparison of the first n characters
+ * @link https://php.net/manual/en/function.strncmp.php
+ * @param string $string1 <p>
+ * The first string.
+ * </p>
+ * @param string $string2 <p>
+ * The second string.
+ * </p>
+ * @param int $length <p>
+ * Number of characters to use in the comparison.
+ * </p>
+ * @return int less than 0 if <i>string1</i> is less than
+ * <i>string2</i>; &gt; 0 if <i>string1</i>
+ * is greater than <i>string2</i>, and 0 if they are
+ * equal.
+ */
+#[Pure]
+function strncmp(string $string1, string $string2, int $length): int {}
+
+/**
+ * Binary safe case-insensitive string comparison
+ * @link https://php.net/manual/en/function.strcasecmp.php
+ * @param string $string1 <p>
+ * The first string
+ * </p>
+ * @param string $string2 <p>
+ * The second string
+ * </p>
+ * @return int less than 0 if <i>string1</i> is less than
+ * <i>string2</i>; &gt; 0 if <i>string1</i>
+ * is greater than <i>string2</i>, and 0 if they are
+ * equal.
+ */
+#[Pure]
+function strcasecmp(string $string1, string $string2): int {}
+
+/**
+ * Binary safe case-insensitive string comparison of the first n characters
+ * @link https://php.net/manual/en/function.strncasecmp.php
+ * @param string $string1 <p>
+ * The first string.
+ * </p>
+ * @param string $string2 <p>
+ * The second string.
+ * </p>
+ * @param int $length <p>
+ * The length of strings to be used in the comparison.
+ * </p>
+ * @return int less than 0 if <i>string1</i> is less than
+ * <i>string2</i>; &gt; 0 if <i>string1</i> is
+ * greater than <i>string2</i>, and 0 if they are equal.
+ */
+#[Pure]
+function strncasecmp(string $string1, string $string2, int $length): int {}
+
+/**
+ * Return the current key and value pair from an array and advance the array cursor
+ * @link https://php.net/manual/en/function.each.php
+ * @param array|ArrayObject &$array <p>
+ * The input array.
+ * </p>
+ * @return array the current key and value pair from the array
+ * <i>array</i>. This pair is returned in a four-element
+ * array, with the keys 0, 1,
+ * key, and value. Elements
+ * 0 and key contain the key name of
+ * the array element, and 1 and value
+ * contain the data.
+ * </p>
+ * <p>
+ * If the internal pointer for the array points past the end of the
+ * array contents, <b>each</b> returns
+ * false.
+ * @removed 8.0
+ */
+#[Deprecated(since: '7.2')]
+function each(&$array) {}
+
+/**
+ * Sets which PHP errors are reported
+ * @link https://php.net/manual/en/function.error-reporting.php
+ * @param int|null $error_level [optional] <p>
+ * The new error_reporting
+ * level. It takes on either a bitmask, or named constants. Using named
+ * constants is strongly encouraged to ensure compatibility for future
+ * versions. As error levels are added, the range of integers increases,
+ * so older integer-based error levels will not always behave as expected.
+ * </p>
+ * @return int the old error_reporting
+ * level or the current level if no <i>level</i> parameter is
+ * given.
+ */
+function error_reporting(?int $error_level): int {}
+
+/**
+ * Defines a named constant
+ * @link https://php.net/manual/en/function.define.php
+ * @param string $constant_name <p>
+ * The name of the constant.
+ * </p>
+ * @param null|array|bool|int|float|string $value <p>
+ * The value of the constant.
+ * In PHP 5, value must be a scalar value (integer, float, string, boolean, or null).
+ * In PHP 7, array values are also accepted.
+ * It is possible to define resource constants,
+ * however it is not recommended and may cause unpredictable behavior.
+ * </p>
+ * @param bool $case_insensitive [optional] <p>
+ * If set to true, the constant will be defined case-insensitive.
+ * The default behavior is case-sensitive; i.e.
+ * CONSTANT and Constant represent
+ * different values.
+ * Defining case-insensitive constants is deprecated as of PHP 7.3.0.
+ * </p>
+ * @return bool true on success or false on failure.
+ */
+function define(
+    string $constant_name,
+    #[LanguageLevelTypeAware(['8.1' => 'mixed'], default: 'null|array|bool|int|float|string')] $value,
+    #[Deprecated(since: "7.3")] bool $case_insensitive = false
+): bool {}
+
+/**
+ * Checks whether a given named constant exists
+ * @link https://php.net/manual/en/function.defined.php
+ * @param string $constant_name <p>
+ * The constant name.
+ * </p>
+ * @return bool true if the named constant given by <i>name</i>
+ * has been defined, false otherwise.
+ */
+#[Pure(true)]
+function defined(string $constant_name): bool {}
+
+/**
+ * Returns the name of the class of an object
+ * @link https://php.net/manual/en/function.get-class.php
+ * @param object $object [optional] <p>
+ * The tested object. This parameter may be omitted when inside a class.
+ * </p>
+ * @return string <p>The name of the class of which <i>object</i> is an
+ * instance.</p>
+ * <p>
+ * If <i>object</i> is omitted when inside a class, the
+ * name of that class is returned.
+ * </p>
+ * @psalm-return class-string
+ */
+#[Pure]
+function get_class(object $object): string {}
+
+/**
+ * the "Late Static Binding" class name
+ * @link https://php.net/manual/en/function.get-called-class.php
+ * @return string
+ * @psalm-return class-string
+ */
+#[Pure]
+function get_called_class(): string {}
+
+/**
+ * Retrieves the parent class name for object or class
+ * @link https://php.net/manual/en/function.get-parent-class.php
+ * @param object|string $object_or_class [optional] <p>
+ * The tested object or class name
+ * </p>
+ * @return string|false <p>The name of the parent class of the class of which
+ * <i>object</i> is an instance or the name.
+ * </p>
+ * @psalm-return class-string|false
+ */
+#[Pure]
+function get_parent_class(object|string $object_or_class): string|false {}
+
+/**
+ * Checks if the class method exists
+ * @link https://php.net/manual/en/function.method-exists.php
+ * @param object|string $object_or_class <p>
+ * An object instance or a class name
+ * </p>
+ * @param string $method <p>
+ * The method name
+ * </p>
+ * @return bool true if the method given by <i>method_name</i>
+ * has been defined for the given <i>object</i>, false
+ * otherwise.
+ */
+#[Pure]
+function method_exists($object_or_class, string $method): bool {}
+
+/**
+ * Checks if the object or class has a property
+ * @link https://php.net/manual/en/function.property-exists.php
+ * @param object|string $object_or_class <p>
+ * The class name or an object of the class to test for
+ * </p>
+ * @param string $property <p>
+ * The name of the property
+ * </p>
+ * @return bool true if the property exists, false if it doesn't exist or
+ * null in case of an error.
+ */
+#[Pure]
+function property_exists($object_or_class, string $property): bool {}
+
+/**
+ * Checks if the trait exists
+ * @param string $trait Name of the trait to check
+ * @param bool $autoload [optional] Whether to autoload if not already loaded.
+ * @return bool Returns TRUE if trait exists, FALSE if not, NULL in case of an error.
+ * @link https://secure.php.net/manual/en/function.trait-exists.php
+ * @since 5.4
+ */
+function trait_exists(string $trait, bool $autoload = true): bool {}
+
+/**
+ * Checks if the class has been defined
+ * @link https://php.net/manual/en/function.class-exists.php
+ * @param string $class <p>
+ * The class name. The name is matched in a case-insensitive manner.
+ * </p>
+ * @param bool $autoload [optional] <p>
+ * Whether or not to call autoload by default.
+ * </p>
+ * @return bool true if <i>class_name</i> is a defined class,
+ * false otherwise.
+ */
+function class_exists(string $class, bool $autoload = true): bool {}
+
+/**
+ * Checks if the interface has been defined
+ * @link https://php.net/manual/en/function.interface-exists.php
+ * @param string $interface <p>
+ * The interface name
+ * </p>
+ * @param bool $autoload [optional] <p>
+ * Whether to call autoload or not by default.
+ * </p>
+ * @return bool true if the interface given by
+ * <i>interface_name</i> has been defined, false otherwise.
+ * @since 5.0.2
+ */
+function interface_exists(string $interface, bool $autoload = true): bool {}
+
+/**
+ * Checks if the enum has been defined
+ * @link https://php.net/manual/en/function.enum-exists.php
+ * @param string $enum <p>
+ * The enum name. The name is matched in a case-insensitive manner.
+ * </p>
+ * @param bool $autoload [optional] <p>
+ * Whether or not to call autoload by default.
+ * </p>
+ * @return bool true if <i>enum</i> is a defined enum,
+ * false otherwise.
+ * @since 8.1
+ */
+function enum_exists(string $enum, bool $autoload = true): bool {}
+
+/**
+ * Return true if the given function has been defined
+ * @link https://php.net/manual/en/function.function-exists.php
+ * @param string $function <p>
+ * The function name, as a string.
+ * </p>
+ * @return bool true if <i>function_name</i> exists and is a
+ * function, false otherwise.
+ * </p>
+ * <p>
+ * This function will return false for constructs, such as
+ * include_once and echo.
+ * @psalm-param callable-string $function
+ */
+#[Pure(true)]
+function function_exists(string $function): bool {}
+
+/**
+ * Creates an alias for a class
+ * @link https://php.net/manual/en/function.class-alias.php
+ * @param string $class The original class.
+ * @param string $alias The alias name for the class.
+ * @param bool $autoload [optional] Whether to autoload if the original class is not found.
+ * @return bool true on success or false on failure.
+ */
+function class_alias(string $class, string $alias, bool $autoload = true): bool {}
+
+/**
+ * Returns an array with the names of included or required files
+ * @link https://php.net/manual/en/function.get-included-files.php
+ * @return string[] an array of the names of all files.
+ * </p>
+ * <p>
+ * The script originally called is considered an "included file," so it will
+ * be listed together with the files referenced by
+ * include and family.
+ * </p>
+ * <p>
+ * Files that are included or required multiple times only show up once in
+ * the returned array.
+ */
+#[Pure(true)]
+function get_included_files(): array {}
+
+/**
+ * Checks if the object has this class as one of its parents or implements it.
+ * @link https://php.net/manual/en/function.is-subclass-of.php
+ * @param object|string $object_or_class <p>
+ * A class name or an object instance
+ * </p>
+ * @param string $class <p>
+ * The class name
+ * </p>
+ * @param bool $allow_string [optional] <p>
+ * If this parameter set to false, string class name as object is not allowed.
+ * This also prevents from calling autoloader if the class doesn't exist.
+ * </p>
+ * @return bool This function returns true if the object <i>object</i>,
+ * belongs to a class which is a subclass of
+ * <i>class_name</i>, false otherwise.
+ */
+#[Pure]
+function is_subclass_of(mixed $object_or_class, string $class, bool $allow_string = true): bool {}
+
+/**
+ * Checks if the object is of this class or has this class as one of its parents
+ * @link https://php.net/manual/en/function.is-a.php
+ * @param object|string $object_or_class <p>
+ * The tested object
+ * </p>
+ * @param string $class <p>
+ * The class name
+ * </p>
+ * @param bool $allow_string [optional] <p>
+ * If this parameter set to FALSE, string class name as object
+ * is not allowed. This also prevents from calling autoloader if the class doesn't exist.
+ * </p>
+ * @return bool <b>TRUE</b> if the object is of this class or has this class as one of
+ * its parents, <b>FALSE</b> otherwise.
+ */
+#[Pure]
+function is_a(mixed $object_or_class, string $class, bool $allow_string = false): bool {}
+
+/**
+ * Get the default properties of the given class
+ * @link https://php.net/manual/en/function.get-class-vars.php
+ * @param string $class <p>
+ * The class name
+ * </p>
+ * @return array an associative array of declared properties visible from the
+ * current scope, with their default value.
+ * The resulting array elements are in the form of
+ * varname => value.
+ */
+#[Pure]
+function get_class_vars(string $class): array {}
+
+/**
+ * Gets the properties of the given object
+ * @link https://php.net/manual/en/function.get-object-vars.php
+ * @param object $object <p>
+ * An object instance.
+ * </p>
+ * @return array an associative array of defined object accessible non-static properties
+ * for the specified <i>object</i> in scope.
+ */
+#[Pure]
+function get_object_vars(object $object): array {}
+
+/**
+ * Gets the class methods' names
+ * @link https://php.net/manual/en/function.get-class-methods.php
+ * @param object|class-string $object_or_class <p>
+ * The class name or an object instance
+ * </p>
+ * @return string[] an array of method names defined for the class specified by
+ * <i>class_name</i>. In case of an error, it returns null.
+ */
+#[Pure]
+function get_class_methods(object|string $object_or_class): array {}
+
+/**
+ * Generates a user-level error/warning/notice message
+ * @link https://php.net/manual/en/function.trigger-error.php
+ * @param string $message <p>
+ * The designated error message for this error. It's limited to 1024
+ * characters in length. Any additional characters beyond 1024 will be
+ * truncated.
+ * </p>
+ * @param int $error_level [optional] <p>
+ * The designated error type for this error. It only works with the E_USER
+ * family of constants, and will default to <b>E_USER_NOTICE</b>.
+ * </p>
+ * @return bool This function returns false if wrong <i>error_type</i> is
+ * specified, true otherwise.
+ */
+#[LanguageLevelTypeAware(['8.4' => 'true'], default: 'bool')]
+function trigger_error(string $message, int $error_level = E_USER_NOTICE) {}
+
+/**
+ * Alias of <b>trigger_error</b>
+ * @link https://php.net/manual/en/function.user-error.php
+ * @param string $message
+ * @param int $error_level [optional]
+ * @return bool This function returns false if wrong <i>error_type</i> is
+ * specified, true otherwise.
+ */
+function user_error(string $message, int $error_level = E_USER_NOTICE): bool {}
+
+/**
+ * Sets a user-defined error handler function
+ * @link https://php.net/manual/en/function.set-error-handler.php
+ * @param callable|null $callback <p>
+ * The user function needs to accept two parameters: the error code, and a
+ * string describing the error.
+ * </p>
+ * @param int $error_levels [optional] <p>
+ * Can be used to mask the triggering of the
+ * <i>error_handler</i> function just like the error_reporting ini setting
+ * controls which errors are shown.
+ * </p>
+ * @return callable|null a string containing the previously defined error handler (if any). If
+ * the built-in error handler is used null is returned.
+ */
+function set_error_handler(?callable $callback, int $error_levels = E_ALL) {}
+
+/**
+ * Restores the previous error handler function
+ * @link https://php.net/manual/en/function.restore-error-handler.php
+ * @return bool This function always returns true.
+ */
+#[LanguageLevelTypeAware(['8.2' => 'true'], default: 'bool')]
+function restore_error_handler(): bool {}
+
+/**
+ * Sets a user-defined exception handler function
+ * @link https://php.net/manual/en/function.set-exception-handler.php
+ * @param callable|null $callback <p>
+ * Name of the function to be called when an uncaught exception occurs.
+ * This function must be defined before calling
+ * <b>set_exception_handler</b>. This handler function
+ * needs to accept one parameter, which will be the exception object that
+ * was thrown.
+ * NULL may be passed instead, to reset this handler to its default state.
+ * </p>
+ * @return callable|null the name of the previously defined exception handler, or null on error. If
+ * no previous handler was defined, null is also returned.
+ */
+function set_exception_handler(?callable $callback) {}
+
+/**
+ * Returns an array with the name of the defined classes
+ * @link https://php.net/manual/en/function.get-declared-classes.php
+ * @return string[] an array of the names of the declared classes in the current
+ * script.
+ */
+#[Pure(true)]
+function get_declared_classes(): array {}
+
+/**
+ * Returns an array of all defined functions
+ * @link https://php.net/manual/en/function.get-defined-functions.php
+ * @param bool $exclude_disabled [optional] Whether disabled functions should be excluded from the return value.
+ * @return array an multidimensional array containing a list of all defined
+ * functions, both built-in (internal) and user-defined.
+ */
+#[Pure(true)]
+#[ArrayShape(["internal" => "string[]", "user" => "string[]"])]
+function get_defined_functions(#[PhpStormStubsElementAvailable(from: '7.1')] bool $exclude_disabled = true): array {}
+
+/**
+ * Returns an array of all defined variables
+ * @link https://php.net/manual/en/function.get-defined-vars.php
+ * @return array A multidimensional array with all the variables.
+ */
+#[Pure(true)]
+function get_defined_vars(): array {}
+
+/**
+ * Returns the resource type
+ * @link https://php.net/manual/en/function.get-resource-type.php
+ * @param resource $resource <p>
+ * The evaluated resource handle.
+ * </p>
+ * @return string If the given <i>handle</i> is a resource, this function
+ * will return a string representing its type.
+ */
+function get_resource_type($resource): string {}
+
+/**
+ * Returns an integer identifier for the given resource
+ *
+ * @link https://www.php.net/manual/en/function.get-resource-id.php
+ * @param resource $resource
+ * @return int
+ * @since 8.0
+ */
+function get_resource_id($resource): int {}
+
+/**
+ * Returns an array with the names of all modules compiled and loaded
+ * @link https://php.net/manual/en/function.get-loaded-extensions.php
+ * @param bool $zend_extensions [optional] <p>
+ * Only return Zend extensions, if not then regular extensions, like
+ * mysqli are listed. Defaults to false (return regular extensions).
+ * </p>
+ * @return string[] an indexed array of all the modules names.
+ */
+#[Pure]
+function get_loaded_extensions(bool $zend_extensions = false): array {}
+
+/**
+ * Returns an associative array with the names of all the constants and their values
+ * @link https://php.net/manual/en/function.get-defined-constants.php
+ * @param bool $categorize [optional] <p>
+ * Causing this function to return a multi-dimensional
+ * array with categories in the keys of the first dimension and constants
+ * and their values in the second dimension.
+ * </p>
+ * @return array
+ */
+#[Pure(true)]
+function get_defined_constants(bool $categorize = false): array {}
+
+/**
+ * Generates a backtrace
+ * @link https://php.net/manual/en/function.debug-backtrace.php
+ * @param int $options [optional] <p>
+ * As of 5.3.6, this parameter is a bitmask for the following options:
+ * <b>debug_backtrace</b> options
+ * </p>
+ * @param int $limit [optional] <p>
+ * As of 5.4.0, this parameter can be used to limit the number of stack frames returned.
+ * By default (<i>limit</i>=0) it returns all stack frames.
+ * </p>
+ * @return array an array of associative arrays.
+ */
+#[ArrayShape([
+    "function" => "string",
+    "line" => "int",
+    "file" => "string",
+    "class" => "string",
+    "type" => "string",
+    "args" => "array",
+    "object" => "object",
+])]
+function debug_backtrace(int $options = DEBUG_BACKTRACE_PROVIDE_OBJECT, int $limit = 0): array {}
+
+/**
+ * Prints a backtrace
+ * @link https://php.net/manual/en/function.debug-print-backtrace.php
+ * @param int $options [optional] <p>
+ * As of 5.3.6, this parameter is a bitmask for the following options:
+ * <b>debug_print_backtrace</b> options
+ * </p>
+ * @param int $limit [optional] <p>
+ * As of 5.4.0, this parameter can be used to limit the number of stack frames printed.
+ * By default (<i>limit</i>=0) it prints all stack frames.
+ * </p>
+ * @return void
+ */
+function debug_print_backtrace(
+    int $options = 0,
+    #[PhpStormStubsElementAvailable(from: '5.4')] int $limit = 0
+): void {}
+
+/**
+ * Forces collection of any existing garbage cycles
+ * @link https://php.net/manual/en/function.gc-collect-cycles.php
+ * @return int number of collected cycles.
+ */
+function gc_collect_cycles(): int {}
+
+/**
+ * Returns status of the circular reference collector
+ * @link https://php.net/manual/en/function.gc-enabled.php
+ * @return bool true if the garbage collector is enabled, false otherwise.
+ */
+#[Pure(true)]
+function gc_enabled(): bool {}
+
+/**
+ * Gets information about the garbage collector
+ * @link https://php.net/manual/en/function.gc-status.php
+ * @return int[] associative array with the following elements:
+ * <ul>
+ * <li>"runs"</li>
+ * <li>"collected"</li>
+ * <li>"threshold"</li>
+ * <li>"roots"</li>
+ * </ul>
+ * @since 7.3
+ */
+#[ArrayShape(["runs" => "int", "collected" => "int", "threshold" => "int", "roots" => "int"])]
+#[Pure(true)]
+function gc_status(): array {}
+
+/**
+ * Checks if assertion is false
+ * @link https://php.net/manual/en/function.assert.php
+ * @param mixed $assertion <p>
+ * The assertion.
+ * </p>
+ * @param Throwable|string|null $description
+ * @return bool false if the assertion is false, true otherwise.
+ */
+function assert(mixed $assertion, Throwable|string|null $description = null): bool {}
+
+/**
+ * Returns the type of a variable
+ * @link https://php.net/manual/en/function.gettype.php
+ * @param mixed $value <p>
+ * The variable being type checked.
+ * </p>
+ * @return string Possibles values for the returned string are:
+ * "boolean", "integer", "double", "string", "array", "object",
+ * "resource", "NULL" and "unknown type".
+ */
+#[Pure]
+#[ExpectedValues(["boolean", "integer", "double", "string", "array", "object", "resource", "NULL", "unknown type", "resource (closed)"])]
+function gettype(mixed $value): string {}
+
+/**
+ * Gets the type name of a variable in a way that is suitable for debugging
+ * @link https://php.net/manual/en/function.get-debug-type.php
+ * @param mixed $value
+ * @return string
+ * @since 8.0
+ */
+#[Pure]
+function get_debug_type(mixed $value): string {}
+
+/**
+ * Created by typecasting to object.
+ */
+class stdClass {}
+
+/**
+ * Interface to detect if a class is traversable using foreach.
+ * Abstract base interface that cannot be implemented alone.
+ * Instead, it must be implemented by either {@see IteratorAggregate} or {@see Iterator}.
+ *
+ * @link https://php.net/manual/en/class.traversable.php
+ * @template TKey
+ * @template-covariant TValue
+ */
+interface Traversable {}
+
+/**
+ * Interface to create an external Iterator.
+ * @link https://php.net/manual/en/class.iteratoraggregate.php
+ * @template TKey
+ * @template-covariant TValue
+ * @template-implements Traversable<TKey, TValue>
+ */
+interface IteratorAggregate extends Traversable
+{
+    /**
+     * Retrieve an external iterator
+     * @link https://php.net/manual/en/iteratoraggregate.getiterator.php
+     * @return Traversable<TKey, TValue>|TValue[] An instance of an object implementing <b>Iterator</b> or
+     * <b>Traversable</b>
+     * @throws Exception on failure.
+     */
+    #[TentativeType]
+    public function getIterator(): Iterator;
+}
+
+/**
+ * Interface for external iterators or objects that can be iterated
+ * themselves internally.
+ * @link https://php.net/manual/en/class.iterator.php
+ * @template TKey
+ * @template-covariant TValue
+ * @template-implements Traversable<TKey, TValue>
+ */
+interface Iterator extends Traversable
+{
+    /**
+     * Return the current element
+     * @link https://php.net/manual/en/iterator.current.php
+     * @return TValue Can return any type.
+     */
+    #[TentativeType]
+    public function current(): mixed;
+
+    /**
+     * Move forward to next element
+     * @link https://php.net/manual/en/iterator.next.php
+     * @return void Any returned value is ignored.
+     */
+    #[TentativeType]
+    public function next(): void;
+
+    /**
+     * Return the key of the current element
+     * @link https://php.net/manual/en/iterator.key.php
+     * @return TKey|null TKey on success, or null on failure.
+     */
+    #[TentativeType]
+    public function key(): mixed;
+
+    /**
+     * Checks if current position is valid
+     * @link https://php.net/manual/en/iterator.valid.php
+     * @return bool The return value will be casted to boolean and then evaluated.
+     * Returns true on success or false on failure.
+     */
+    #[TentativeType]
+    public function valid(): bool;
+
+    /**
+     * Rewind the Iterator to the first element
+     * @link https://php.net/manual/en/iterator.rewind.php
+     * @return void Any returned value is ignored.
+     */
+    #[TentativeType]
+    public function rewind(): void;
+}
+
+/**
+ * Interface to provide accessing objects as arrays.
+ * @link https://php.net/manual/en/class.arrayaccess.php
+ * @template TKey
+ * @template TValue
+ */
+interface ArrayAccess
+{
+    /**
+     * Whether a offset exists
+     * @link https://php.net/manual/en/arrayaccess.offsetexists.php
+     * @param mixed $offset <p>
+     * An offset to check for.
+     * </p>
+     * @return bool true on success or false on failure.
+     * </p>
+     * <p>
+     * The return value will be casted to boolean if non-boolean was returned.
+     */
+    #[TentativeType]
+    public function offsetExists(#[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $offset): bool;
+
+    /**
+     * Offset to retrieve
+     * @link https://php.net/manual/en/arrayaccess.offsetget.php
+     * @param mixed $offset <p>
+     * The offset to retrieve.
+     * </p>
+     * @return TValue Can return all value types.
+     */
+    #[TentativeType]
+    public function offsetGet(#[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $offset): mixed;
+
+    /**
+     * Offset to set
+     * @link https://php.net/manual/en/arrayaccess.offsetset.php
+     * @param TKey $offset <p>
+     * The offset to assign the value to.
+     * </p>
+     * @param TValue $value <p>
+     * The value to set.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function offsetSet(
+        #[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $offset,
+        #[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $value
+    ): void;
+
+    /**
+     * Offset to unset
+     * @link https://php.net/manual/en/arrayaccess.offsetunset.php
+     * @param TKey $offset <p>
+     * The offset to unset.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function offsetUnset(#[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $offset): void;
+}
+
+/**
+ * Interface for customized serializing.<br>
+ * As of PHP 8.1.0, a class which implements Serializable without also implementing `__serialize()` and `__unserialize()`
+ * will generate a deprecation warning.
+ * @link https://php.net/manual/en/class.serializable.php
+ */
+interface Serializable
+{
+    /**
+     * String representation of object.
+     * @link https://php.net/manual/en/serializable.serialize.php
+     * @return string|null The string representation of the object or null
+     * @throws Exception Returning other type than string or null
+     */
+    public function serialize();
+
+    /**
+     * Constructs the object.
+     * @link https://php.net/manual/en/serializable.unserialize.php
+     * @param string $data The string representation of the object.
+     * @return void
+     */
+    public function unserialize(string $data);
+}
+
+/**
+ * Throwable is the base interface for any object that can be thrown via a throw statement in PHP 7,
+ * including Error and Exception.
+ * @link https://php.net/manual/en/class.throwable.php
+ * @since 7.0
+ */
+interface Throwable extends Stringable
+{
+    /**
+     * Gets the message
+     * @link https://php.net/manual/en/throwable.getmessage.php
+     * @return string
+     * @since 7.0
+     */
+    public function getMessage(): string;
+
+    /**
+     * Gets the exception code
+     * @link https://php.net/manual/en/throwable.getcode.php
+     * @return int <p>
+     * Returns the exception code as integer in
+     * {@see Exception} but possibly as other type in
+     * {@see Exception} descendants (for example as
+     * string in {@see PDOException}).
+     * </p>
+     * @since 7.0
+     */
+    public function getCode();
+
+    /**
+     * Gets the file in which the exception occurred
+     * @link https://php.net/manual/en/throwable.getfile.php
+     * @return string Returns the name of the file from which the object was thrown.
+     * @since 7.0
+     */
+    public function getFile(): string;
+
+    /**
+     * Gets the line on which the object was instantiated
+     * @link https://php.net/manual/en/throwable.getline.php
+     * @return int Returns the line number where the thrown object was instantiated.
+     * @since 7.0
+     */
+    public function getLine(): int;
+
+    /**
+     * Gets the stack trace
+     * @link https://php.net/manual/en/throwable.gettrace.php
+     * @return array <p>
+     * Returns the stack trace as an array in the same format as
+     * {@see debug_backtrace()}.
+     * </p>
+     * @since 7.0
+     */
+    public function getTrace(): array;
+
+    /**
+     * Gets the stack trace as a string
+     * @link https://php.net/manual/en/throwable.gettraceasstring.php
+     * @return string Returns the stack trace as a string.
+     * @since 7.0
+     */
+    public function getTraceAsString(): string;
+
+    /**
+     * Returns the previous Throwable
+     * @link https://php.net/manual/en/throwable.getprevious.php
+     * @return Throwable|null Returns the previous {@see Throwable} if available, or <b>NULL</b> otherwise.
+     * @since 7.0
+     */
+    #[LanguageLevelTypeAware(['8.0' => 'Throwable|null'], default: '')]
+    public function getPrevious();
+
+    /**
+     * Gets a string representation of the thrown object
+     * @link https://php.net/manual/en/throwable.tostring.php
+     * @return string <p>Returns the string representation of the thrown object.</p>
+     * @since 7.0
+     */
+    public function __toString();
+}
+
+/**
+ * Exception is the base class for
+ * all Exceptions.
+ * @link https://php.net/manual/en/class.exception.php
+ */
+class Exception implements Throwable
+{
+    /** The error message */
+    protected $message = '';
+
+    /** The error code */
+    protected $code = 0;
+
+    /**
+     * The filename where the error happened
+     * @var string
+     */
+    #[LanguageLevelTypeAware(['8.1' => 'string'], default: '')]
+    protected $file = '';
+
+    /**
+     * The line where the error happened
+     * @var int
+     */
+    #[LanguageLevelTypeAware(['8.1' => 'int'], default: '')]
+    protected $line;
+
+    /**
+     * The previous throwable
+     * @var null|Throwable
+     */
+    #[LanguageLevelTypeAware(['8.1' => '?Throwable'], default: '')]
+    private $previous = null;
+
+    /**
+     * Clone the exception
+     * Tries to clone the Exception, which results in Fatal error.
+     * @link https://php.net/manual/en/exception.clone.php
+     * @return void
+     */
+    #[PhpStormStubsElementAvailable(from: "5.4", to: "8.0")]
+    final private function __clone(): void {}
+
+    /**
+     * Construct the exception. Note: The message is NOT binary safe.
+     * @link https://php.net/manual/en/exception.construct.php
+     * @param string $message [optional] The Exception message to throw.
+     * @param int $code [optional] The Exception code.
+     * @param null|Throwable $previous [optional] The previous throwable used for the exception chaining.
+     */
+    #[Pure]
+    public function __construct(
+        #[LanguageLevelTypeAware(['8.0' => 'string'], default: '')] $message = "",
+        #[LanguageLevelTypeAware(['8.0' => 'int'], default: '')] $code = 0,
+        #[LanguageLevelTypeAware(['8.0' => 'Throwable|null'], default: 'Throwable')] $previous = null
+    ) {}
+
+    /**
+     * Gets the Exception message
+     * @link https://php.net/manual/en/exception.getmessage.php
+     * @return string the Exception message as a string.
+     */
+    #[Pure]
+    final public function getMessage(): string {}
+
+    /**
+     * Gets the Exception code
+     * @link https://php.net/manual/en/exception.getcode.php
+     * @return mixed|int the exception code as integer in
+     * <b>Exception</b> but possibly as other type in
+     * <b>Exception</b> descendants (for example as
+     * string in <b>PDOException</b>).
+     */
+    #[Pure]
+    final public function getCode() {}
+
+    /**
+     * Gets the file in which the exception occurred
+     * @link https://php.net/manual/en/exception.getfile.php
+     * @return string the filename in which the exception was created.
+     */
+    #[Pure]
+    final public function getFile(): string {}
+
+    /**
+     * Gets the line in which the exception occurred
+     * @link https://php.net/manual/en/exception.getline.php
+     * @return int the line number where the exception was created.
+     */
+    #[Pure]
+    final public function getLine(): int {}
+
+    /**
+     * Gets the stack trace
+     * @link https://php.net/manual/en/exception.gettrace.php
+     * @return array the Exception stack trace as an array.
+     */
+    #[Pure]
+    final public function getTrace(): array {}
+
+    /**
+     * Returns previous Exception
+     * @link https://php.net/manual/en/exception.getprevious.php
+     * @return null|Throwable Returns the previous {@see Throwable} if available, or <b>NULL</b> otherwise.
+     * or null otherwise.
+     */
+    #[Pure]
+    final public function getPrevious(): ?Throwable {}
+
+    /**
+     * Gets the stack trace as a string
+     * @link https://php.net/manual/en/exception.gettraceasstring.php
+     * @return string the Exception stack trace as a string.
+     */
+    #[Pure]
+    final public function getTraceAsString(): string {}
+
+    /**
+     * String representation of the exception
+     * @link https://php.net/manual/en/exception.tostring.php
+     * @return string the string representation of the exception.
+     */
+    public function __toString(): string {}
+
+    #[TentativeType]
+    public function __wakeup(): void {}
+}
+
+/**
+ * Error is the base class for all internal PHP error exceptions.
+ * @link https://php.net/manual/en/class.error.php
+ * @since 7.0
+ */
+class Error implements Throwable
+{
+    /** The error message */
+    protected $message = '';
+
+    /** The error code */
+    protected $code = 0;
+
+    /** The filename where the error happened */
+    #[LanguageLevelTypeAware(['8.1' => 'string'], default: '')]
+    protected $file = '';
+
+    /** The line where the error happened */
+    #[LanguageLevelTypeAware(['8.1' => 'int'], default: '')]
+    protected $line;
+
+    /**
+     * Construct the error object.
+     * @link https://php.net/manual/en/error.construct.php
+     * @param string $message [optional] The Error message to throw.
+     * @param int $code [optional] The Error code.
+     * @param null|Throwable $previous [optional] The previous throwable used for the exception chaining.
+     */
+    public function __construct(
+        #[LanguageLevelTypeAware(['8.0' => 'string'], default: '')] $message = "",
+        #[LanguageLevelTypeAware(['8.0' => 'int'], default: '')] $code = 0,
+        #[LanguageLevelTypeAware(['8.0' => 'Throwable|null'], default: 'Throwable')] $previous = null
+    ) {}
+
+    /***
+     * Gets the message
+     * @link https://php.net/manual/en/error.getmessage.php
+     * @return string
+     * @since 7.0
+     */
+    final public function getMessage(): string {}
+
+    /**
+     * Gets the exception code
+     * @link https://php.net/manual/en/error.getcode.php
+     * @return int <p>
+     * Returns the exception code as integer in
+     * {@see Exception} but possibly as other type in
+     * {@see Exception} descendants (for example as
+     * string in {@see PDOException}).
+     * </p>
+     * @since 7.0
+     */
+    final public function getCode() {}
+
+    /**
+     * Gets the file in which the exception occurred
+     * @link https://php.net/manual/en/error.getfile.php
+     * @return string Returns the name of the file from which the object was thrown.
+     * @since 7.0
+     */
+    final public function getFile(): string {}
+
+    /**
+     * Gets the line on which the object was instantiated
+     * @link https://php.net/manual/en/error.getline.php
+     * @return int Returns the line number where the thrown object was instantiated.
+     * @since 7.0
+     */
+    final public function getLine(): int {}
+
+    /**
+     * Gets the stack trace
+     * @link https://php.net/manual/en/error.gettrace.php
+     * @return array <p>
+     * Returns the stack trace as an array in the same format as
+     * {@see debug_backtrace()}.
+     * </p>
+     * @since 7.0
+     */
+    final public function getTrace(): array {}
+
+    /**
+     * Gets the stack trace as a string
+     * @link https://php.net/manual/en/error.gettraceasstring.php
+     * @return string Returns the stack trace as a string.
+     * @since 7.0
+     */
+    final public function getTraceAsString(): string {}
+
+    /**
+     * Returns the previous Throwable
+     * @link https://php.net/manual/en/error.getprevious.php
+     * @return Throwable|null Returns the previous {@see Throwable} if available, or <b>NULL</b> otherwise.
+     * @since 7.0
+     */
+    final public function getPrevious(): ?Throwable {}
+
+    /**
+     * Gets a string representation of the thrown object
+     * @link https://php.net/manual/en/error.tostring.php
+     * @return string <p>Returns the string representation of the thrown object.</p>
+     * @since 7.0
+     */
+    public function __toString(): string {}
+
+    /**
+     * Clone the error
+     * Error can not be clone, so this method results in fatal error.
+     * @return void
+     * @link https://php.net/manual/en/error.clone.php
+     */
+    #[PhpStormStubsElementAvailable(from: "7.0", to: "8.0")]
+    final private function __clone(): void {}
+
+    #[TentativeType]
+    public function __wakeup(): void {}
+}
+
+class ErrorException extends Exception
+{
+    #[LanguageLevelTypeAware(["8.1" => "int"], default: "")]
+    protected $severity = E_ERROR;
+
+    /**
+     * Constructs the exception
+     * @link https://php.net/manual/en/errorexception.construct.php
+     * @param string $message [optional] The Exception message to throw.
+     * @param int $code [optional] The Exception code.
+     * @param int $severity [optional] The severity level of the exception.
+     * @param string|null $filename [optional] The filename where the exception is thrown.
+     * @param int|null $line [optional] The line number where the exception is thrown.
+     * @param Throwable|null $previous [optional] The previous exception used for the exception chaining.
+     */
+    #[Pure]
+    public function __construct(
+        #[LanguageLevelTypeAware(['8.0' => 'string'], default: '')] $message = "",
+        #[LanguageLevelTypeAware(['8.0' => 'int'], default: '')] $code = 0,
+        #[LanguageLevelTypeAware(['8.0' => 'int'], default: '')] $severity = 1,
+        #[LanguageLevelTypeAware(['8.0' => 'string|null'], default: '')] $filename = null,
+        #[LanguageLevelTypeAware(['8.0' => 'int|null'], default: '')] $line = null,
+        #[LanguageLevelTypeAware(['8.0' => 'Throwable|null'], default: 'Throwable')] $previous = null
+    ) {}
+
+    /**
+     * Gets the exception severity
+     * @link https://php.net/manual/en/errorexception.getseverity.php
+     * @return int the severity level of the exception.
+     */
+    final public function getSeverity(): int {}
+}
+
+class TypeError extends Error {}
+
+class ValueError extends Error {}
+
+class ArithmeticError extends Error {}
+
+class DivisionByZeroError extends ArithmeticError {}
+
+class CompileError extends Error {}
+
+class ParseError extends CompileError {}
+
+class ArgumentCountError extends TypeError {}
+
+class UnhandledMatchError extends Error {}
+
+/**
+ * Class used to represent anonymous functions.
+ * <p>Anonymous functions, implemented in PHP 5.3, yield objects of this type.
+ * This fact used to be considered an implementation detail, but it can now be relied upon.
+ * Starting with PHP 5.4, this class has methods that allow further control of the anonymous function after it has been created.
+ * <p>Besides the methods listed here, this class also has an __invoke method.
+ * This is for consistency with other classes that implement calling magic, as this method is not used for calling the function.
+ * @link https://secure.php.net/manual/en/class.closure.php
+ */
+final class Closure
+{
+    /**
+     * This method exists only to disallow instantiation of the Closure class.
+     * Objects of this class are created in the fashion described on the anonymous functions page.
+     * @link https://secure.php.net/manual/en/closure.construct.php
+     */
+    private function __construct() {}
+
+    /**
+     * This is for consistency with other classes that implement calling magic,
+     * as this method is not used for calling the function.
+     * @param mixed ...$_ [optional]
+     * @return mixed
+     * @link https://secure.php.net/manual/en/class.closure.php
+     */
+    public function __invoke(...$_) {}
+
+    /**
+     * Duplicates the closure with a new bound object and class scope
+     * @link https://secure.php.net/manual/en/closure.bindto.php
+     * @param object|null $newThis The object to which the given anonymous function should be bound, or NULL for the closure to be unbound.
+     * @param object|class-string|null $newScope The class scope to which associate the closure is to be associated, or 'static' to keep the current one.
+     * If an object is given, the type of the object will be used instead.
+     * This determines the visibility of protected and private methods of the bound object.
+     * @return Closure|null Returns the newly created Closure object or null on failure
+     */
+    #[Pure]
+    public function bindTo(?object $newThis, object|string|null $newScope = 'static'): ?Closure {}
+
+    /**
+     * This method is a static version of Closure::bindTo().
+     * See the documentation of that method for more information.
+     * @link https://secure.php.net/manual/en/closure.bind.php
+     * @param Closure $closure The anonymous functions to bind.
+     * @param object|null $newThis The object to which the given anonymous function should be bound, or NULL for the closure to be unbound.
+     * @param object|class-string|null $newScope The class scope to which associate the closure is to be associated, or 'static' to keep the current one.
+     * If an object is given, the type of the object will be used instead.
+     * This determines the visibility of protected and private methods of the bound object.
+     * @return Closure|null Returns the newly created Closure object or null on failure
+     */
+    #[Pure]
+    public static function bind(Closure $closure, ?object $newThis, object|string|null $newScope = 'static'): ?Closure {}
+
+    /**
+     * Temporarily binds the closure to newthis, and calls it with any given parameters.
+     * @link https://php.net/manual/en/closure.call.php
+     * @param object $newThis The object to bind the closure to for the duration of the call.
+     * @param mixed $args [optional] Zero or more parameters, which will be given as parameters to the closure.
+     * @return mixed
+     * @since 7.0
+     */
+    public function call(object $newThis, mixed ...$args): mixed {}
+
+    /**
+     * @param callable $callback
+     * @return Closure
+     * @since 7.1
+     */
+    public static function fromCallable(callable $callback): Closure {}
+}
+
+/**
+ * Classes implementing <b>Countable</b> can be used with the
+ * <b>count</b> function.
+ * @link https://php.net/manual/en/class.countable.php
+ */
+interface Countable
+{
+    /**
+     * Count elements of an object
+     * @link https://php.net/manual/en/countable.count.php
+     * @return int<0,max> The custom count as an integer.
+     * </p>
+     * <p>
+     * The return value is cast to an integer.
+     */
+    #[TentativeType]
+    public function count(): int;
+}
+
+/**
+ * Weak references allow the programmer to retain a reference to an
+ * object which does not prevent the object from being destroyed.
+ * They are useful for implementing cache like structures.
+ * @template T of object
+ * @link https://www.php.net/manual/en/class.weakreference.php
+ * @since 7.4
+ */
+final class WeakReference
+{
+    /**
+     * This method exists only to disallow instantiation of the WeakReference
+     * class. Weak references are to be instantiated with the factory method
+     * WeakReference::create().
+     */
+    public function __construct() {}
+
+    /**
+     * Create a new weak reference.
+     * @link https://www.php.net/manual/en/weakreference.create.php
+     * @template TIn of object
+     * @param TIn $object Any object.
+     * @return WeakReference<TIn> The freshly instantiated object.
+     * @since 7.4
+     */
+    public static function create(object $object): WeakReference {}
+
+    /**
+     * Gets a weakly referenced object. If the object has already been
+     * destroyed, NULL is returned.
+     * @link https://www.php.net/manual/en/weakreference.get.php
+     * @return T|null
+     * @since 7.4
+     */
+    public function get(): ?object {}
+}
+
+/**
+ * Weak maps allow creating a map from objects to arbitrary values
+ * (similar to SplObjectStorage) without preventing the objects that are used
+ * as keys from being garbage collected. If an object key is garbage collected,
+ * it will simply be removed from the map.
+ *
+ * @since 8.0
+ *
+ * @template TKey of object
+ * @template TValue
+ * @template-implements IteratorAggregate<TKey, TValue>
+ */
+final class WeakMap implements ArrayAccess, Countable, IteratorAggregate
+{
+    /**
+     * Returns {@see true} if the value for the object is contained in
+     * the {@see WeakMap} and {@see false} instead.
+     *
+     * @param TKey $object Any object
+     * @return bool
+     */
+    public function offsetExists($object): bool {}
+
+    /**
+     * Returns the existsing value by an object.
+     *
+     * @param TKey $object Any object
+     * @return TValue Value associated with the key object
+     */
+    public function offsetGet($object): mixed {}
+
+    /**
+     * Sets a new value for an object.
+     *
+     * @param TKey $object Any object
+     * @param TValue $value Any value
+     * @return void
+     */
+    public function offsetSet($object, mixed $value): void {}
+
+    /**
+     * Force removes an object value from the {@see WeakMap} instance.
+     *
+     * @param TKey $object Any object
+     * @return void
+     */
+    public function offsetUnset($object): void {}
+
+    /**
+     * Returns an iterator in the "[object => mixed]" format.
+     *
+     * @return Iterator<TKey, TValue>
+     */
+    public function getIterator(): Iterator {}
+
+    /**
+     * Returns the number of items in the {@see WeakMap} instance.
+     *
+     * @return int<0,max>
+     */
+    public function count(): int {}
+}
+
+/**
+ * Stringable interface denotes a class as having a __toString() method.
+ *
+ * @since 8.0
+ */
+interface Stringable
+{
+    /**
+     * Magic method {@see https://www.php.net/manual/en/language.oop5.magic.php#object.tostring}
+     * allows a class to decide how it will react when it is treated like a string.
+     *
+     * @return string Returns string representation of the object that
+     * implements this interface (and/or "__toString" magic method).
+     */
+    public function __toString(): string;
+}
+
+/**
+ * @since 8.0
+ */
+#[Attribute(Attribute::TARGET_CLASS)]
+final class Attribute
+{
+    public int $flags;
+
+    /**
+     * Marks that attribute declaration is allowed only in classes.
+     */
+    public const TARGET_CLASS = 1;
+
+    /**
+     * Marks that attribute declaration is allowed only in functions.
+     */
+    public const TARGET_FUNCTION = 2;
+
+    /**
+     * Marks that attribute declaration is allowed only in class methods.
+     */
+    public const TARGET_METHOD = 4;
+
+    /**
+     * Marks that attribute declaration is allowed only in class properties.
+     */
+    public const TARGET_PROPERTY = 8;
+
+    /**
+     * Marks that attribute declaration is allowed only in class constants.
+     */
+    public const TARGET_CLASS_CONSTANT = 16;
+
+    /**
+     * Marks that attribute declaration is allowed only in function or method parameters.
+     */
+    public const TARGET_PARAMETER = 32;
+
+    /**
+     * Marks that attribute declaration is allowed anywhere.
+     */
+    public const TARGET_ALL = 63;
+
+    /**
+     * Notes that an attribute declaration in the same place is
+     * allowed multiple times.
+     */
+    public const IS_REPEATABLE = 64;
+
+    /**
+     * @param int $flags A value in the form of a bitmask indicating the places
+     * where attributes can be defined.
+     */
+    public function __construct(#[ExpectedValues(flagsFromClass: Attribute::class)] int $flags = Attribute::TARGET_ALL) {}
+}
+
+/**
+ * @since 8.1
+ */
+#[Attribute(Attribute::TARGET_METHOD)]
+final class ReturnTypeWillChange
+{
+    public function __construct() {}
+}
+
+/**
+ * @since 8.2
+ */
+#[Attribute(Attribute::TARGET_CLASS)]
+final class AllowDynamicProperties
+{
+    public function __construct() {}
+}
+
+/**
+ * @since 8.2
+ */
+#[Attribute(Attribute::TARGET_PARAMETER)]
+final class SensitiveParameter
+{
+    public function __construct() {}
+}
+
+/**
+ * @since 8.2
+ */
+final class SensitiveParameterValue
+{
+    private readonly mixed $value;
+
+    public function __construct(mixed $value) {}
+
+    public function getValue(): mixed {}
+
+    public function __debugInfo(): array {}
+}
+
+/**
+ * @since 8.1
+ */
+interface UnitEnum
+{
+    /**
+     * @return static[]
+     */
+    #[Pure]
+    public static function cases(): array;
+}
+
+/**
+ * @since 8.1
+ */
+interface BackedEnum extends UnitEnum
+{
+    /**
+     * Translates a string or int into the corresponding <code>Enum</code>
+     * case, if any. If there is no matching case defined, it will throw a
+     * <code>ValueError</code>.
+     * @param int|string $value
+     * @throws ValueError if there is no matching case defined
+     * @return static
+     */
+    #[Pure]
+    public static function from(int|string $value): static;
+
+    /**
+     * Translates a string or int into the corresponding <code>Enum</code>
+     * case, if any. If there is no matching case defined, it will return
+     * null.
+     * @param int|string $value
+     * @return static|null
+     */
+    #[Pure]
+    public static function tryFrom(int|string $value): ?static;
+}
+
+/**
+ * @since 8.1
+ * @template TStart
+ * @template TResume
+ * @template TReturn
+ * @template TSuspend
+ */
+final class Fiber
+{
+    /**
+     * @param callable $callback Function to invoke when starting the fiber.
+     */
+    public function __construct(callable $callback) {}
+
+    /**
+     * Starts execution of the fiber. Returns when the fiber suspends or terminates.
+     *
+     * @param TStart ...$args Arguments passed to fiber function.
+     *
+     * @return TSuspend|null Value from the first suspension point or NULL if the fiber returns.
+     *
+     * @throws FiberError If the fiber has already been started.
+     * @throws Throwable If the fiber callable throws an uncaught exception.
+     */
+    public function start(mixed ...$args): mixed {}
+
+    /**
+     * Resumes the fiber, returning the given value from {@see Fiber::suspend()}.
+     * Returns when the fiber suspends or terminates.
+     *
+     * @param TResume $value
+     *
+     * @return TSuspend|null Value from the next suspension point or NULL if the fiber returns.
+     *
+     * @throws FiberError If the fiber has not started, is running, or has terminated.
+     * @throws Throwable If the fiber callable throws an uncaught exception.
+     */
+    public function resume(mixed $value = null): mixed {}
+
+    /**
+     * Throws the given exception into the fiber from {@see Fiber::suspend()}.
+     * Returns when the fiber suspends or terminates.
+     *
+     * @param Throwable $exception
+     *
+     * @return TSuspend|null Value from the next suspension point or NULL if the fiber returns.
+     *
+     * @throws FiberError If the fiber has not started, is running, or has terminated.
+     * @throws Throwable If the fiber callable throws an uncaught exception.
+     */
+    public function throw(Throwable $exception): mixed {}
+
+    /**
+     * @return TReturn Return value of the fiber callback.
+     *
+     * @throws FiberError If the fiber has not terminated or the fiber threw an exception.
+     */
+    public function getReturn(): mixed {}
+
+    /**
+     * @return bool True if the fiber has been started.
+     */
+    public function isStarted(): bool {}
+
+    /**
+     * @return bool True if the fiber is suspended.
+     */
+    public function isSuspended(): bool {}
+
+    /**
+     * @return bool True if the fiber is currently running.
+     */
+    public function isRunning(): bool {}
+
+    /**
+     * @return bool True if the fiber has completed execution (returned or threw).
+     */
+    public function isTerminated(): bool {}
+
+    /**
+     * @return Fiber|null Returns the currently executing fiber instance or NULL if in {main}.
+     */
+    public static function getCurrent(): ?Fiber {}
+
+    /**
+     * Suspend execution of the fiber. The fiber may be resumed with {@see Fiber::resume()} or {@see Fiber::throw()}.
+     *
+     * Cannot be called from {main}.
+     *
+     * @param TSuspend $value Value to return from {@see Fiber::resume()} or {@see Fiber::throw()}.
+     *
+     * @return TResume Value provided to {@see Fiber::resume()}.
+     *
+     * @throws FiberError Thrown if not within a fiber (i.e., if called from {main}).
+     * @throws Throwable Exception provided to {@see Fiber::throw()}.
+     */
+    public static function suspend(mixed $value = null): mixed {}
+}
+
+/**
+ * @since 8.1
+ */
+final class FiberError extends Error
+{
+    public function __construct() {}
+}
+
+/**
+ * Generator objects are returned from generators, cannot be instantiated via new.
+ * @link https://secure.php.net/manual/en/class.generator.php
+ * @link https://wiki.php.net/rfc/generators
+ *
+ * @template-covariant TKey
+ * @template-covariant TYield
+ * @template TSend
+ * @template-covariant TReturn
+ *
+ * @template-implements Iterator<TKey, TYield>
+ */
+final class Generator implements Iterator
+{
+    /**
+     * Throws an exception if the generator is currently after the first yield expression.
+     * @return void
+     */
+    public function rewind(): void {}
+
+    /**
+     * Returns false if the generator has been closed, true otherwise.
+     * @return bool
+     */
+    public function valid(): bool {}
+
+    /**
+     * Returns whatever was passed to yield or null if nothing was passed or the generator is already closed.
+     * @return TYield|null
+     */
+    public function current(): mixed {}
+
+    /**
+     * Returns the yielded key or, if none was specified, an auto-incrementing key or null if the generator is already closed.
+     * @return TKey|null
+     */
+    public function key(): mixed {}
+
+    /**
+     * Resumes the generator (unless the generator is already closed).
+     * @return void
+     */
+    public function next(): void {}
+
+    /**
+     * Sets the return value of the yield expression and resumes the generator (unless the generator is already closed).
+     * @param TSend $value
+     * @return TYield|null
+     */
+    public function send(mixed $value): mixed {}
+
+    /**
+     * Throws an exception at the current suspension point in the generator.
+     * @param Throwable $exception
+     * @return TYield|null
+     */
+    public function throw(Throwable $exception): mixed {}
+
+    /**
+     * Returns whatever was passed to return or null if nothing.
+     * Throws an exception if the generator is still valid.
+     * @link https://wiki.php.net/rfc/generator-return-expressions
+     * @return TReturn|null
+     * @since 7.0
+     */
+    public function getReturn(): mixed {}
+
+    /**
+     * Serialize callback
+     * Throws an exception as generators can't be serialized.
+     * @link https://php.net/manual/en/generator.wakeup.php
+     * @return void
+     */
+    public function __wakeup() {}
+}
+
+class ClosedGeneratorException extends Exception {}
+
+/**
+ * Fatal run-time errors. These indicate errors that can not be
+ * recovered from, such as a memory allocation problem.
+ * Execution of the script is halted.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_ERROR', 1);
+
+/**
+ * Run-time warnings (non-fatal errors). Execution of the script is not
+ * halted.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_WARNING', 2);
+
+/**
+ * Compile-time parse errors. Parse errors should only be generated by
+ * the parser.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_PARSE', 4);
+
+/**
+ * Run-time notices. Indicate that the script encountered something that
+ * could indicate an error, but could also happen in the normal course of
+ * running a script.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_NOTICE', 8);
+
+/**
+ * Fatal errors that occur during PHP's initial startup. This is like an
+ * E_ERROR, except it is generated by the core of PHP.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_CORE_ERROR', 16);
+
+/**
+ * Warnings (non-fatal errors) that occur during PHP's initial startup.
+ * This is like an E_WARNING, except it is generated by the core of PHP.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_CORE_WARNING', 32);
+
+/**
+ * Fatal compile-time errors. This is like an E_ERROR, except it is
+ * generated by the Zend Scripting Engine.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_COMPILE_ERROR', 64);
+
+/**
+ * Compile-time warnings (non-fatal errors). This is like an E_WARNING,
+ * except it is generated by the Zend Scripting Engine.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_COMPILE_WARNING', 128);
+
+/**
+ * User-generated error message. This is like an E_ERROR, except it is
+ * generated in PHP code by using the PHP function trigger_error.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_USER_ERROR', 256);
+
+/**
+ * User-generated warning message. This is like an E_WARNING, except it
+ * is generated in PHP code by using the PHP function trigger_error.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_USER_WARNING', 512);
+
+/**
+ * User-generated notice message. This is like an E_NOTICE, except it is
+ * generated in PHP code by using the PHP function trigger_error.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_USER_NOTICE', 1024);
+
+/**
+ * Enable to have PHP suggest changes to your code which will ensure the
+ * best interoperability and forward compatibility of your code.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_STRICT', 2048);
+
+/**
+ * Catchable fatal error. It indicates that a probably dangerous error
+ * occurred, but did not leave the Engine in an unstable state. If the error
+ * is not caught by a user defined handle (see also
+ * set_error_handler), the application aborts as it
+ * was an E_ERROR.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_RECOVERABLE_ERROR', 4096);
+
+/**
+ * Run-time notices. Enable this to receive warnings about code
+ * that will not work in future versions.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_DEPRECATED', 8192);
+
+/**
+ * User-generated warning message. This is like an E_DEPRECATED, except it is
+ * generated in PHP code by using the PHP function trigger_error.
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_USER_DEPRECATED', 16384);
+
+/**
+ * All errors and warnings, as supported, except of level
+ * E_STRICT prior to PHP 5.4.0.
+ * Value of E_ALL is 32767 since PHP 5.4.x,
+ * 30719 in PHP 5.3.x, 6143 in PHP 5.2.x, 2047 previously
+ * @link https://php.net/manual/en/errorfunc.constants.php
+ */
+define('E_ALL', 32767);
+
+define('DEBUG_BACKTRACE_PROVIDE_OBJECT', 1);
+define('DEBUG_BACKTRACE_IGNORE_ARGS', 2);
+define('S_MEMORY', 1);
+define('S_VARS', 4);
+define('S_FILES', 8);
+define('S_INCLUDE', 16);
+define('S_SQL', 32);
+define('S_EXECUTOR', 64);
+define('S_MAIL', 128);
+define('S_SESSION', 256);
+define('S_MISC', 2);
+define('S_INTERNAL', 536870912);
+define('S_ALL', 511);
+
+define('true', (bool)1, true);
+define('false', (bool)0, true);
+define('null', null, true);
+define('ZEND_THREAD_SAFE', false);
+define('ZEND_DEBUG_BUILD', false);
+define('PHP_WINDOWS_VERSION_BUILD', 0);
+define('PHP_WINDOWS_VERSION_MAJOR', 0);
+define('PHP_WINDOWS_VERSION_MINOR', 0);
+define('PHP_WINDOWS_VERSION_PLATFORM', 0);
+define('PHP_WINDOWS_VERSION_PRODUCTTYPE', 0);
+define('PHP_WINDOWS_VERSION_SP_MAJOR', 0);
+define('PHP_WINDOWS_VERSION_SP_MINOR', 0);
+define('PHP_WINDOWS_VERSION_SUITEMASK', 0);
+define('PHP_WINDOWS_NT_DOMAIN_CONTROLLER', 2);
+define('PHP_WINDOWS_NT_SERVER', 3);
+define('PHP_WINDOWS_NT_WORKSTATION', 1);
+/**
+ * @since 7.4
+ */
+define('PHP_WINDOWS_EVENT_CTRL_C', 0);
+/**
+ * @since 7.4
+ */
+define('PHP_WINDOWS_EVENT_CTRL_BREAK', 1);
+define('PHP_VERSION', "5.3.6-13ubuntu3.2");
+define('PHP_MAJOR_VERSION', 5);
+define('PHP_MINOR_VERSION', 3);
+define('PHP_RELEASE_VERSION', 6);
+define('PHP_EXTRA_VERSION', "-13ubuntu3.2");
+define('PHP_VERSION_ID', 50306);
+define('PHP_ZTS', 0);
+define('PHP_DEBUG', 0);
+define('PHP_OS', "Linux");
+/**
+ * The operating system family PHP was built for. Either of 'Windows', 'BSD', 'Darwin', 'Solaris', 'Linux' or 'Unknown'. Available as of PHP 7.2.0.
+ * @link https://php.net/manual/en/reserved.constants.php
+ */
+define('PHP_OS_FAMILY', "Linux");
+define('PHP_SAPI', "cli");
+/**
+ * @since 7.4
+ */
+define('PHP_CLI_PROCESS_TITLE', 1);
+define('DEFAULT_INCLUDE_PATH', ".:/usr/share/php:/usr/share/pear");
+define('PEAR_INSTALL_DIR', "/usr/share/php");
+define('PEAR_EXTENSION_DIR', "/usr/lib/php5/20090626");
+define('PHP_EXTENSION_DIR', "/usr/lib/php5/20090626");
+/**
+ * Specifies where the binaries were installed into.
+ * @link https://php.net/manual/en/reserved.constants.php
+ */
+define('PHP_BINARY', '/usr/local/php/bin/php');
+define('PHP_PREFIX', "/usr");
+define('PHP_BINDIR', "/usr/bin");
+define('PHP_LIBDIR', "/usr/lib/php5");
+define('PHP_DATADIR', "/usr/share");
+define('PHP_SYSCONFDIR', "/etc");
+define('PHP_LOCALSTATEDIR', "/var");
+define('PHP_CONFIG_FILE_PATH', "/etc/php5/cli");
+define('PHP_CONFIG_FILE_SCAN_DIR', "/etc/php5/cli/conf.d");
+define('PHP_SHLIB_SUFFIX', "so");
+define('PHP_EOL', "\n");
+define('SUHOSIN_PATCH', 1);
+define('SUHOSIN_PATCH_VERSION', "0.9.10");
+define('PHP_MAXPATHLEN', 4096);
+define('PHP_INT_MAX', 9223372036854775807);
+define('PHP_INT_MIN', -9223372036854775808);
+define('PHP_INT_SIZE', 8);
+/**
+ * Number of decimal digits that can be rounded into a float and back without precision loss. Available as of PHP 7.2.0.
+ * @link https://php.net/manual/en/reserved.constants.php
+ */
+define('PHP_FLOAT_DIG', 15);
+/**
+ * Smallest representable positive number x, so that x + 1.0 != 1.0. Available as of PHP 7.2.0.
+ * @link https://php.net/manual/en/reserved.constants.php
+ */
+define('PHP_FLOAT_EPSILON', 2.220446049250313E-16);
+
+/**
+ * Largest representable floating point number. Available as of PHP 7.2.0.
+ * @link https://php.net/manual/en/reserved.constants.php
+ */
+define('PHP_FLOAT_MAX', 1.7976931348623157E+308);
+
+/**
+ * Smallest representable floating point number. Available as of PHP 7.2.0.
+ * @link https://php.net/manual/en/reserved.constants.php
+ */
+define('PHP_FLOAT_MIN', 2.2250738585072E-308);
+define('PHP_FD_SETSIZE', 1024);
+define('PHP_OUTPUT_HANDLER_START', 1);
+define('PHP_OUTPUT_HANDLER_CONT', 2);
+define('PHP_OUTPUT_HANDLER_WRITE', 0);
+define('PHP_OUTPUT_HANDLER_FLUSH', 4);
+define('PHP_OUTPUT_HANDLER_CLEAN', 2);
+define('PHP_OUTPUT_HANDLER_FINAL', 8);
+define('PHP_OUTPUT_HANDLER_END', 8);
+define('PHP_OUTPUT_HANDLER_CLEANABLE', 16);
+define('PHP_OUTPUT_HANDLER_FLUSHABLE', 32);
+define('PHP_OUTPUT_HANDLER_REMOVABLE', 64);
+define('PHP_OUTPUT_HANDLER_STDFLAGS', 112);
+define('PHP_OUTPUT_HANDLER_STARTED', 4096);
+define('PHP_OUTPUT_HANDLER_DISABLED', 8192);
+define('PHP_OUTPUT_HANDLER_PROCESSED', 16384);
+define('UPLOAD_ERR_OK', 0);
+define('UPLOAD_ERR_INI_SIZE', 1);
+define('UPLOAD_ERR_FORM_SIZE', 2);
+define('UPLOAD_ERR_PARTIAL', 3);
+define('UPLOAD_ERR_NO_FILE', 4);
+define('UPLOAD_ERR_NO_TMP_DIR', 6);
+define('UPLOAD_ERR_CANT_WRITE', 7);
+define('UPLOAD_ERR_EXTENSION', 8);
+define('STDIN', fopen('php://stdin', 'r'));
+define('STDOUT', fopen('php://stdout', 'w'));
+define('STDERR', fopen('php://stderr', 'w'));
+
+define('PHP_FLOAT_EPSILON_HALF', PHP_FLOAT_EPSILON / 2);
+
+/**
+ * @since 8.0
+ */
+define('E_ALL_EXCEPT_DEPRECATED', E_ALL & ~E_DEPRECATED & ~E_USER_DEPRECATED);
+
+// End of Core v.8.2
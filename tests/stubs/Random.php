@@ -0,0 +1,243 @@
+<?php
+
+// Start of random v.8.3
+
+namespace Random\Engine
+{
+    use JetBrains\PhpStorm\Internal\LanguageLevelTypeAware;
+
+    /**
+     * @since 8.2
+     */
+    final class Mt19937 implements \Random\Engine
+    {
+        public function __construct(int|null $seed = null, int $mode = MT_RAND_MT19937) {}
+
+        public function generate(): string {}
+
+        public function __serialize(): array {}
+
+        public function __unserialize(array $data): void {}
+
+        public function __debugInfo(): array {}
+    }
+
+    /**
+     * @since 8.2
+     */
+    final class PcgOneseq128XslRr64 implements \Random\Engine
+    {
+        public function __construct(string|int|null $seed = null) {}
+
+        public function generate(): string {}
+
+        public function jump(int $advance): void {}
+
+        public function __serialize(): array {}
+
+        public function __unserialize(array $data): void {}
+
+        public function __debugInfo(): array {}
+    }
+
+    /**
+     * @since 8.2
+     */
+    final class Xoshiro256StarStar implements \Random\Engine
+    {
+        public function __construct(string|int|null $seed = null) {}
+
+        public function generate(): string {}
+
+        public function jump(): void {}
+
+        public function jumpLong(): void {}
+
+        public function __serialize(): array {}
+
+        public function __unserialize(array $data): void {}
+
+        public function __debugInfo(): array {}
+    }
+
+    /**
+     * @since 8.2
+     */
+    final class Secure implements \Random\CryptoSafeEngine
+    {
+        public function generate(): string {}
+    }
+}
+
+namespace Random
+{
+    use Error;
+    use Exception;
+    use JetBrains\PhpStorm\Internal\PhpStormStubsElementAvailable;
+    use JetBrains\PhpStorm\Pure;
+
+    /**
+     * @since 8.2
+     */
+    interface Engine
+    {
+        public function generate(): string;
+    }
+
+    /**
+     * @since 8.2
+     */
+    interface CryptoSafeEngine extends Engine {}
+
+    /**
+     * @since 8.2
+     */
+    final class Randomizer
+    {
+        public readonly Engine $engine;
+
+        public function __construct(?Engine $engine = null) {}
+
+        public function nextInt(): int {}
+
+        #[PhpStormStubsElementAvailable(from: '8.3')]
+        public function nextFloat(): float {}
+
+        #[PhpStormStubsElementAvailable(from: '8.3')]
+        public function getFloat(float $min, float $max, IntervalBoundary $boundary = IntervalBoundary::ClosedOpen): float {}
+
+        public function getInt(int $min, int $max): int {}
+
+        /**
+         * @param positive-int $length
+         */
+        public function getBytes(int $length): string {}
+
+        #[PhpStormStubsElementAvailable(from: '8.3')]
+        public function getBytesFromString(string $string, int $length): string {}
+
+        /**
+         * @template TValue
+         * @param array<TValue> $array
+         * @return list<TValue>
+         */
+        public function shuffleArray(array $array): array {}
+
+        public function shuffleBytes(string $bytes): string {}
+
+        public function pickArrayKeys(array $array, int $num): array {}
+
+        public function __serialize(): array {}
+
+        public function __unserialize(array $data): void {}
+    }
+
+    /**
+     * @since 8.3
+     */
+    enum IntervalBoundary
+    {
+        case ClosedOpen;
+        case ClosedClosed;
+        case OpenClosed;
+        case OpenOpen;
+    }
+
+    /**
+     * @since 8.2
+     */
+    class RandomError extends Error {}
+
+    /**
+     * @since 8.2
+     */
+    class BrokenRandomEngineError extends RandomError {}
+
+    /**
+     * @since 8.2
+     */
+    class RandomException extends Exception {}
+}
+
+namespace
+{
+    use JetBrains\PhpStorm\Internal\LanguageLevelTypeAware;
+    use JetBrains\PhpStorm\Pure;
+
+    /**
+     * Seed the Mersenne Twister Random Number Generator
+     * @link https://php.net/manual/en/function.mt-srand.php
+     * @param int $seed [optional] <p>
+     * An optional seed value
+     * </p>
+     * @param int $mode [optional] <p>
+     * Use MT_RAND_PHP to use the incorrect implementation of
+     * the Mersenne Twister algorithm used before PHP 7.1.
+     * </p>
+     * @return void
+     */
+    function mt_srand(int $seed = 0, int $mode = MT_RAND_MT19937): void {}
+
+    /**
+     * Show largest possible random value
+     * @link https://php.net/manual/en/function.mt-getrandmax.php
+     * @return int the maximum random value returned by mt_rand
+     */
+    #[Pure]
+    function mt_getrandmax(): int {}
+
+    /**
+     * Generates cryptographically secure pseudo-random bytes
+     * @link https://php.net/manual/en/function.random-bytes.php
+     * @param int<1, max> $length The length of the random string that should be returned in bytes.
+     * @return string Returns a string containing the requested number of cryptographically secure random bytes.
+     * @since 7.0
+     * @throws \Random\RandomException if an appropriate source of randomness cannot be found.
+     */
+    function random_bytes(int $length): string {}
+
+    /**
+     * Calculates the intersection of two or more iterables; typed with an
+     * intersection of interfaces, as the newer stubs do.
+     * @param Countable&Traversable $values
+     * @return (Countable&Traversable)|null
+     */
+    function iterable_first_of(Countable&Traversable $values): (Countable&Traversable)|null {}
+
+    /**
+     * @since 8.3
+     */
+    final class SensitiveParameterDescription
+    {
+        public const string NAME = 'SensitiveParameter';
+        public const int|string VERSION = 1;
+        final public const ?array DEFAULTS = null;
+
+        public function __construct(public readonly string $name = self::NAME) {}
+    }
+
+    /**
+     * @since 8.2
+     */
+    readonly class ReadonlyPoint
+    {
+        public function __construct(
+            public int $x = 0,
+            public int $y = 0,
+        ) {}
+
+        public function withX(int $x): static {}
+    }
+
+    /**
+     * @since 8.2
+     */
+    function null_or_false(null $value): false {}
+
+    /**
+     * @since 8.2
+     */
+    function always_true(): true {}
+}
+
+// End of random v.8.3
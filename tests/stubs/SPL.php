@@ -0,0 +1,1713 @@
+<?php
+
+// Start of SPL v.8.2
+
+use JetBrains\PhpStorm\Deprecated;
+use JetBrains\PhpStorm\Internal\LanguageLevelTypeAware;
+use JetBrains\PhpStorm\Internal\PhpStormStubsElementAvailable;
+use JetBrains\PhpStorm\Internal\TentativeType;
+use JetBrains\PhpStorm\Pure;
+
+/**
+ * Exception that represents error in the program logic. This kind of
+ * exception should lead directly to a fix in your code.
+ * @link https://php.net/manual/en/class.logicexception.php
+ */
+class LogicException extends Exception {}
+
+/**
+ * Exception thrown if a callback refers to an undefined function or if some
+ * arguments are missing.
+ * @link https://php.net/manual/en/class.badfunctioncallexception.php
+ */
+class BadFunctionCallException extends LogicException {}
+
+/**
+ * Exception thrown if a callback refers to an undefined method or if some
+ * arguments are missing.
+ * @link https://php.net/manual/en/class.badmethodcallexception.php
+ */
+class BadMethodCallException extends BadFunctionCallException {}
+
+/**
+ * Exception thrown if a value does not adhere to a defined valid data domain.
+ * @link https://php.net/manual/en/class.domainexception.php
+ */
+class DomainException extends LogicException {}
+
+/**
+ * Exception thrown if an argument does not match with the expected value.
+ * @link https://php.net/manual/en/class.invalidargumentexception.php
+ */
+class InvalidArgumentException extends LogicException {}
+
+/**
+ * Exception thrown if a length is invalid.
+ * @link https://php.net/manual/en/class.lengthexception.php
+ */
+class LengthException extends LogicException {}
+
+/**
+ * Exception thrown when an illegal index was requested. This represents
+ * errors that should be detected at compile time.
+ * @link https://php.net/manual/en/class.outofrangeexception.php
+ */
+class OutOfRangeException extends LogicException {}
+
+/**
+ * Exception thrown if an error which can only be found on runtime occurs.
+ * @link https://php.net/manual/en/class.runtimeexception.php
+ */
+class RuntimeException extends Exception {}
+
+/**
+ * Exception thrown if a value is not a valid key. This represents errors
+ * that cannot be detected at compile time.
+ * @link https://php.net/manual/en/class.outofboundsexception.php
+ */
+class OutOfBoundsException extends RuntimeException {}
+
+/**
+ * Exception thrown when adding an element to a full container.
+ * @link https://php.net/manual/en/class.overflowexception.php
+ */
+class OverflowException extends RuntimeException {}
+
+/**
+ * Exception thrown to indicate range errors during program execution.
+ * Normally this means there was an arithmetic error other than
+ * under/overflow. This is the runtime version of
+ * <b>DomainException</b>.
+ * @link https://php.net/manual/en/class.rangeexception.php
+ */
+class RangeException extends RuntimeException {}
+
+/**
+ * Exception thrown when performing an invalid operation on an empty container, such as removing an element.
+ * @link https://php.net/manual/en/class.underflowexception.php
+ */
+class UnderflowException extends RuntimeException {}
+
+/**
+ * Exception thrown if a value does not match with a set of values. Typically
+ * this happens when a function calls another function and expects the return
+ * value to be of a certain type or value not including arithmetic or buffer
+ * related errors.
+ * @link https://php.net/manual/en/class.unexpectedvalueexception.php
+ */
+class UnexpectedValueException extends RuntimeException {}
+
+/**
+ * The EmptyIterator class for an empty iterator.
+ * @link https://secure.php.net/manual/en/class.emptyiterator.php
+ */
+class EmptyIterator implements Iterator
+{
+    /**
+     * Return the current element
+     * @link https://php.net/manual/en/iterator.current.php
+     * @return mixed Can return any type.
+     */
+    #[TentativeType]
+    public function current(): never {}
+
+    /**
+     * Move forward to next element
+     * @link https://php.net/manual/en/iterator.next.php
+     * @return void Any returned value is ignored.
+     */
+    #[TentativeType]
+    public function next(): void {}
+
+    /**
+     * Return the key of the current element
+     * @link https://php.net/manual/en/iterator.key.php
+     * @return string|int|null scalar on success, or null on failure.
+     */
+    #[TentativeType]
+    public function key(): never {}
+
+    /**
+     * Checks if current position is valid
+     * @link https://php.net/manual/en/iterator.valid.php
+     * @return bool The return value will be casted to boolean and then evaluated.
+     * Returns true on success or false on failure.
+     */
+    #[TentativeType]
+    public function valid(): false {}
+
+    /**
+     * Rewind the Iterator to the first element
+     * @link https://php.net/manual/en/iterator.rewind.php
+     * @return void Any returned value is ignored.
+     */
+    #[TentativeType]
+    public function rewind(): void {}
+}
+
+/**
+ * Classes implementing <b>OuterIterator</b> can be used to iterate
+ * over iterators.
+ * @link https://php.net/manual/en/class.outeriterator.php
+ */
+interface OuterIterator extends Iterator
+{
+    /**
+     * Returns the inner iterator for the current entry.
+     * @link https://php.net/manual/en/outeriterator.getinneriterator.php
+     * @return Iterator|null The inner iterator for the current entry.
+     */
+    #[TentativeType]
+    public function getInnerIterator(): ?Iterator;
+}
+
+/**
+ * Classes implementing <b>RecursiveIterator</b> can be used to iterate
+ * over iterators recursively.
+ * @link https://php.net/manual/en/class.recursiveiterator.php
+ */
+interface RecursiveIterator extends Iterator
+{
+    /**
+     * Returns if an iterator can be created for the current entry.
+     * @link https://php.net/manual/en/recursiveiterator.haschildren.php
+     * @return bool true if the current entry can be iterated over, otherwise returns false.
+     */
+    #[TentativeType]
+    public function hasChildren(): bool;
+
+    /**
+     * Returns an iterator for the current entry.
+     * @link https://php.net/manual/en/recursiveiterator.getchildren.php
+     * @return RecursiveIterator|null An iterator for the current entry.
+     */
+    #[TentativeType]
+    public function getChildren(): ?RecursiveIterator;
+}
+
+/**
+ * The Seekable iterator.
+ * @link https://php.net/manual/en/class.seekableiterator.php
+ */
+interface SeekableIterator extends Iterator
+{
+    /**
+     * Seeks to a position
+     * @link https://php.net/manual/en/seekableiterator.seek.php
+     * @param int $offset <p>
+     * The position to seek to.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function seek(int $offset): void;
+}
+
+/**
+ * This iterator wrapper allows the conversion of anything that is
+ * Traversable into an Iterator.
+ * It is important to understand that most classes that do not implement
+ * Iterators have reasons as most likely they do not allow the full
+ * Iterator feature set.
+ * @link https://php.net/manual/en/class.iteratoriterator.php
+ */
+class IteratorIterator implements OuterIterator
+{
+    /**
+     * Create an iterator from anything that is traversable
+     * @link https://php.net/manual/en/iteratoriterator.construct.php
+     * @param Traversable $iterator
+     * @param string|null $class [optional]
+     */
+    public function __construct(Traversable $iterator, ?string $class = '') {}
+
+    /**
+     * Get the inner iterator
+     * @link https://php.net/manual/en/iteratoriterator.getinneriterator.php
+     * @return Iterator|null The inner iterator as passed to IteratorIterator::__construct.
+     */
+    #[TentativeType]
+    public function getInnerIterator(): ?Iterator {}
+
+    /**
+     * Rewind to the first element
+     * @link https://php.net/manual/en/iteratoriterator.rewind.php
+     * @return void
+     */
+    #[TentativeType]
+    public function rewind(): void {}
+
+    /**
+     * Checks if the iterator is valid
+     * @link https://php.net/manual/en/iteratoriterator.valid.php
+     * @return bool true if the iterator is valid, otherwise false
+     */
+    #[TentativeType]
+    public function valid(): bool {}
+
+    /**
+     * Get the key of the current element
+     * @link https://php.net/manual/en/iteratoriterator.key.php
+     * @return mixed The key of the current element.
+     */
+    #[TentativeType]
+    public function key(): mixed {}
+
+    /**
+     * Get the current value
+     * @link https://php.net/manual/en/iteratoriterator.current.php
+     * @return mixed The value of the current element.
+     */
+    #[TentativeType]
+    public function current(): mixed {}
+
+    /**
+     * Forward to the next element
+     * @link https://php.net/manual/en/iteratoriterator.next.php
+     * @return void
+     */
+    #[TentativeType]
+    public function next(): void {}
+}
+
+/**
+ * This abstract iterator filters out unwanted values. This class should be extended to
+ * implement custom iterator filters. The <b>FilterIterator::accept</b>
+ * must be implemented in the subclass.
+ * @link https://php.net/manual/en/class.filteriterator.php
+ */
+abstract class FilterIterator extends IteratorIterator
+{
+    /**
+     * Check whether the current element of the iterator is acceptable
+     * @link https://php.net/manual/en/filteriterator.accept.php
+     * @return bool true if the current element is acceptable, otherwise false.
+     */
+    #[TentativeType]
+    abstract public function accept(): bool;
+
+    /**
+     * Construct a filterIterator
+     * @link https://php.net/manual/en/filteriterator.construct.php
+     * @param Iterator $iterator
+     */
+    public function __construct(Iterator $iterator) {}
+
+    /**
+     * Rewind the iterator
+     * @link https://php.net/manual/en/filteriterator.rewind.php
+     * @return void
+     */
+    #[TentativeType]
+    public function rewind(): void {}
+
+    /**
+     * Move the iterator forward
+     * @link https://php.net/manual/en/filteriterator.next.php
+     * @return void
+     */
+    #[TentativeType]
+    public function next(): void {}
+}
+
+/**
+ * @since 5.4
+ */
+class CallbackFilterIterator extends FilterIterator
+{
+    /**
+     * Creates a filtered iterator using the callback to determine which items are accepted or rejected.
+     * @link https://secure.php.net/manual/en/callbackfilteriterator.construct.php
+     * @param Iterator $iterator The iterator to be filtered.
+     * @param callable $callback The callback, which should return TRUE to accept the current item or FALSE otherwise.
+     * May be any valid callable value.
+     * The callback should accept up to three arguments: the current item, the current key and the iterator, respectively.
+     * <code> function my_callback($current, $key, $iterator) </code>
+     * @since 5.4
+     */
+    public function __construct(Iterator $iterator, callable $callback) {}
+
+    /**
+     * This method calls the callback with the current value, current key and the inner iterator.
+     * The callback is expected to return TRUE if the current item is to be accepted, or FALSE otherwise.
+     * @link https://secure.php.net/manual/en/callbackfilteriterator.accept.php
+     * @return bool true if the current element is acceptable, otherwise false.
+     * @since 5.4
+     */
+    #[TentativeType]
+    public function accept(): bool {}
+}
+
+/**
+ * The <b>LimitIterator</b> class allows iteration over
+ * a limited subset of items in an <b>Iterator</b>.
+ * @link https://php.net/manual/en/class.limititerator.php
+ */
+class LimitIterator extends IteratorIterator
+{
+    /**
+     * Construct a LimitIterator
+     * @link https://php.net/manual/en/limititerator.construct.php
+     * @param Iterator $iterator The iterator to limit.
+     * @param int $offset [optional] The offset to start at. Must be zero or greater.
+     * @param int $limit [optional] The number of items to iterate. Must be -1 or greater. -1, the default, means no limit.
+     * @throws OutOfBoundsException If the offset is less than 0 or the count is less than -1.
+     */
+    public function __construct(Iterator $iterator, int $offset = 0, int $limit = -1) {}
+
+    /**
+     * Seek to the given position
+     * @link https://php.net/manual/en/limititerator.seek.php
+     * @param int $offset <p>
+     * The position to seek to.
+     * </p>
+     * @return int the offset position after seeking.
+     * @throws OutOfBoundsException If the position is outside of the limits.
+     */
+    #[TentativeType]
+    public function seek(int $offset): int {}
+
+    /**
+     * Return the current position
+     * @link https://php.net/manual/en/limititerator.getposition.php
+     * @return int The current position.
+     */
+    #[TentativeType]
+    public function getPosition(): int {}
+}
+
+/**
+ * This object supports cached iteration over another iterator.
+ * @link https://php.net/manual/en/class.cachingiterator.php
+ */
+class CachingIterator extends IteratorIterator implements ArrayAccess, Countable, Stringable
+{
+    /**
+     * String conversion flag (mutually exclusive): Uses the current element for the iterator's string conversion.
+     * This converts the current element to a string only once, regardless of whether it is needed or not.
+     */
+    public const CALL_TOSTRING = 1;
+
+    /**
+     * String conversion flag (mutually exclusive). Uses the current key for the iterator's string conversion.
+     */
+    public const TOSTRING_USE_KEY = 2;
+
+    /**
+     * String conversion flag (mutually exclusive). Uses the current element for the iterator's string conversion.
+     * This converts the current element to a string only when (and every time) it is needed.
+     */
+    public const TOSTRING_USE_CURRENT = 4;
+
+    /**
+     * String conversion flag (mutually exclusive). Forwards the string conversion to the inner iterator.
+     * This converts the inner iterator to a string only once, regardless of whether it is needed or not.
+     */
+    public const TOSTRING_USE_INNER = 8;
+
+    /**
+     * Ignore exceptions thrown in accessing children. Only used with {@see RecursiveCachingIterator}.
+     */
+    public const CATCH_GET_CHILD = 16;
+
+    /**
+     * Cache all read data. This is needed to use {@see CachingIterator::getCache}, and ArrayAccess and Countable methods.
+     */
+    public const FULL_CACHE = 256;
+
+    /**
+     * Constructs a new CachingIterator.
+     * @link https://php.net/manual/en/cachingiterator.construct.php
+     * @param Iterator $iterator The iterator to cache.
+     * @param int $flags [optional] A bitmask of flags. See CachingIterator class constants for details.
+     */
+    public function __construct(Iterator $iterator, int $flags = CachingIterator::CALL_TOSTRING) {}
+
+    /**
+     * Check whether the inner iterator has a valid next element
+     * @link https://php.net/manual/en/cachingiterator.hasnext.php
+     * @return bool true on success or false on failure.
+     */
+    #[TentativeType]
+    public function hasNext(): bool {}
+
+    /**
+     * Return the string representation of the current element
+     * @link https://php.net/manual/en/cachingiterator.tostring.php
+     * @return string The string representation of the current element.
+     */
+    public function __toString(): string {}
+
+    /**
+     * Get flags used
+     * @link https://php.net/manual/en/cachingiterator.getflags.php
+     * @return int Bitmask of the flags
+     */
+    #[TentativeType]
+    public function getFlags(): int {}
+
+    /**
+     * The setFlags purpose
+     * @link https://php.net/manual/en/cachingiterator.setflags.php
+     * @param int $flags Bitmask of the flags to set.
+     * @return void
+     */
+    #[TentativeType]
+    public function setFlags(#[LanguageLevelTypeAware(['8.0' => 'int'], default: '')] $flags): void {}
+
+    /**
+     * Internal cache array index to retrieve.
+     * @link https://php.net/manual/en/cachingiterator.offsetget.php
+     * @param string $key The index of the element to retrieve.
+     * @return mixed
+     * @throws BadMethodCallException when the {@see CachingIterator::FULL_CACHE} flag is not being used.
+     */
+    #[TentativeType]
+    public function offsetGet($key): mixed {}
+
+    /**
+     * Set an element on the internal cache array.
+     * @link https://php.net/manual/en/cachingiterator.offsetset.php
+     * @param string $key The index of the element to be set.
+     * @param string $value The new value for the <i>index</i>.
+     * @return void
+     * @throws BadMethodCallException when the {@see CachingIterator::FULL_CACHE} flag is not being used.
+     */
+    #[TentativeType]
+    public function offsetSet($key, mixed $value): void {}
+
+    /**
+     * Remove an element from the internal cache array.
+     * @link https://php.net/manual/en/cachingiterator.offsetunset.php
+     * @param string $key The index of the element to be unset.
+     * @return void
+     * @throws BadMethodCallException when the {@see CachingIterator::FULL_CACHE} flag is not being used.
+     */
+    #[TentativeType]
+    public function offsetUnset($key): void {}
+
+    /**
+     * Return whether an element at the index exists in the internal cache array.
+     * @link https://php.net/manual/en/cachingiterator.offsetexists.php
+     * @param string $key The index being checked.
+     * @return bool true if an entry referenced by the offset exists, false otherwise.
+     * @throws BadMethodCallException when the {@see CachingIterator::FULL_CACHE} flag is not being used.
+     */
+    #[TentativeType]
+    public function offsetExists($key): bool {}
+
+    /**
+     * Retrieve the contents of the cache
+     * @link https://php.net/manual/en/cachingiterator.getcache.php
+     * @return array An array containing the cache items.
+     * @throws BadMethodCallException when the {@see CachingIterator::FULL_CACHE} flag is not being used.
+     */
+    #[TentativeType]
+    public function getCache(): array {}
+
+    /**
+     * The number of elements in the iterator
+     * @link https://php.net/manual/en/cachingiterator.count.php
+     * @return int The count of the elements iterated over.
+     * @throws BadMethodCallException when the {@see CachingIterator::FULL_CACHE} flag is not being used.
+     * @since 5.2.2
+     */
+    #[TentativeType]
+    public function count(): int {}
+}
+
+/**
+ * This iterator allows to unset and modify values and keys while iterating
+ * over Arrays and Objects.
+ * @link https://php.net/manual/en/class.arrayiterator.php
+ * @template TKey of array-key
+ * @template TValue
+ * @template-implements SeekableIterator<TKey, TValue>
+ * @template-implements ArrayAccess<TKey, TValue>
+ */
+class ArrayIterator implements SeekableIterator, ArrayAccess, Serializable, Countable
+{
+    public const STD_PROP_LIST = 1;
+    public const ARRAY_AS_PROPS = 2;
+
+    /**
+     * Construct an ArrayIterator
+     * @link https://php.net/manual/en/arrayiterator.construct.php
+     * @param array|object $array The array or object to be iterated on.
+     * @param int $flags Flags to control the behaviour of the ArrayIterator object.
+     * @see ArrayObject::setFlags()
+     */
+    public function __construct(
+        #[LanguageLevelTypeAware(['8.0' => 'array|object'], default: '')] $array = [],
+        #[LanguageLevelTypeAware(['8.0' => 'int'], default: '')] #[PhpStormStubsElementAvailable(from: '7.0')] $flags = 0,
+        #[PhpStormStubsElementAvailable(from: '7.0', to: '7.1')] $iterator_class = null
+    ) {}
+
+    /**
+     * Check if offset exists
+     * @link https://php.net/manual/en/arrayiterator.offsetexists.php
+     * @param string $key <p>
+     * The offset being checked.
+     * </p>
+     * @return bool true if the offset exists, otherwise false
+     */
+    #[TentativeType]
+    public function offsetExists($key): bool {}
+
+    /**
+     * Get value for an offset
+     * @link https://php.net/manual/en/arrayiterator.offsetget.php
+     * @param string $key <p>
+     * The offset to get the value from.
+     * </p>
+     * @return mixed The value at offset <i>index</i>.
+     */
+    #[TentativeType]
+    public function offsetGet($key): mixed {}
+
+    /**
+     * Set value for an offset
+     * @link https://php.net/manual/en/arrayiterator.offsetset.php
+     * @param string $key <p>
+     * The index to set for.
+     * </p>
+     * @param string $value <p>
+     * The new value to store at the index.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function offsetSet($key, #[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $value): void {}
+
+    /**
+     * Unset value for an offset
+     * @link https://php.net/manual/en/arrayiterator.offsetunset.php
+     * @param string $key <p>
+     * The offset to unset.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function offsetUnset($key): void {}
+
+    /**
+     * Append an element
+     * @link https://php.net/manual/en/arrayiterator.append.php
+     * @param mixed $value <p>
+     * The value to append.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function append(#[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $value): void {}
+
+    /**
+     * Get array copy
+     * @link https://php.net/manual/en/arrayiterator.getarraycopy.php
+     * @return array A copy of the array, or array of public properties
+     * if ArrayIterator refers to an object.
+     */
+    #[TentativeType]
+    public function getArrayCopy(): array {}
+
+    /**
+     * Count elements
+     * @link https://php.net/manual/en/arrayiterator.count.php
+     * @return int<0,max> The number of elements or public properties in the associated
+     * array or object, respectively.
+     */
+    #[TentativeType]
+    public function count(): int {}
+
+    /**
+     * Get flags
+     * @link https://php.net/manual/en/arrayiterator.getflags.php
+     * @return int The current flags.
+     */
+    #[TentativeType]
+    public function getFlags(): int {}
+
+    /**
+     * Set behaviour flags
+     * @link https://php.net/manual/en/arrayiterator.setflags.php
+     * @param string $flags <p>
+     * A bitmask as follows:
+     * 0 = Properties of the object have their normal functionality
+     * when accessed as list (var_dump, foreach, etc.).
+     * 1 = Array indices can be accessed as properties in read/write.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function setFlags(#[LanguageLevelTypeAware(['8.0' => 'int'], default: '')] $flags): void {}
+
+    /**
+     * Sort array by values
+     * @link https://php.net/manual/en/arrayiterator.asort.php
+     * @param int $flags [optional]
+     * @return bool
+     */
+    #[LanguageLevelTypeAware(['8.2' => 'true'], default: 'bool')]
+    public function asort(#[PhpStormStubsElementAvailable(from: '8.0')] int $flags = SORT_REGULAR) {}
+
+    /**
+     * Sort with a user-defined comparison function and maintain index association
+     * @link https://php.net/manual/en/arrayiterator.uasort.php
+     * @param callable $callback <p>
+     * The compare function used for the sort.
+     * </p>
+     * @return bool
+     */
+    #[LanguageLevelTypeAware(['8.2' => 'true'], default: 'bool')]
+    public function uasort(#[LanguageLevelTypeAware(['8.0' => 'callable'], default: '')] $callback) {}
+
+    /**
+     * Serialize
+     * @link https://php.net/manual/en/arrayiterator.serialize.php
+     * @return string The serialized <b>ArrayIterator</b>.
+     */
+    #[TentativeType]
+    public function serialize(): string {}
+
+    /**
+     * Unserialize
+     * @link https://php.net/manual/en/arrayiterator.unserialize.php
+     * @param string $data <p>
+     * The serialized ArrayIterator object to be unserialized.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function unserialize(#[LanguageLevelTypeAware(['8.0' => 'string'], default: '')] $data): void {}
+
+    /**
+     * Rewind array back to the start
+     * @link https://php.net/manual/en/arrayiterator.rewind.php
+     * @return void
+     */
+    #[TentativeType]
+    public function rewind(): void {}
+
+    /**
+     * Return current array entry
+     * @link https://php.net/manual/en/arrayiterator.current.php
+     * @return mixed The current array entry.
+     */
+    #[TentativeType]
+    public function current(): mixed {}
+
+    /**
+     * Return current array key
+     * @link https://php.net/manual/en/arrayiterator.key.php
+     * @return string|int|null The current array key.
+     */
+    #[TentativeType]
+    public function key(): string|int|null {}
+
+    /**
+     * Move to next entry
+     * @link https://php.net/manual/en/arrayiterator.next.php
+     * @return void
+     */
+    #[TentativeType]
+    public function next(): void {}
+
+    /**
+     * Check whether array contains more entries
+     * @link https://php.net/manual/en/arrayiterator.valid.php
+     * @return bool
+     */
+    #[TentativeType]
+    public function valid(): bool {}
+
+    /**
+     * Seek to position
+     * @link https://php.net/manual/en/arrayiterator.seek.php
+     * @param int $offset <p>
+     * The position to seek to.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function seek(int $offset): void {}
+
+    /**
+     * @return array
+     * @since 7.4
+     */
+    #[TentativeType]
+    public function __debugInfo(): array {}
+
+    /**
+     * @return array
+     * @since 7.4
+     */
+    #[TentativeType]
+    public function __serialize(): array {}
+
+    /**
+     * @param array $data
+     * @since 7.4
+     */
+    #[TentativeType]
+    public function __unserialize(array $data): void {}
+}
+
+/**
+ * The SplDoublyLinkedList class provides the main functionalities of a doubly linked list.
+ * @link https://php.net/manual/en/class.spldoublylinkedlist.php
+ * @template TValue
+ * @template-implements Iterator<int, TValue>
+ * @template-implements ArrayAccess<int, TValue>
+ */
+class SplDoublyLinkedList implements Iterator, Countable, ArrayAccess, Serializable
+{
+    public const IT_MODE_LIFO = 2;
+    public const IT_MODE_FIFO = 0;
+    public const IT_MODE_DELETE = 1;
+    public const IT_MODE_KEEP = 0;
+
+    /**
+     * Add/insert a new value at the specified index
+     * @param int $index The index where the new value is to be inserted.
+     * @param TValue $value The new value for the index.
+     * @return void
+     * @link https://php.net/spldoublylinkedlist.add
+     * @since 5.5
+     */
+    #[TentativeType]
+    public function add(
+        #[LanguageLevelTypeAware(['8.0' => 'int'], default: '')] $index,
+        #[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $value
+    ): void {}
+
+    /**
+     * Pops a node from the end of the doubly linked list
+     * @link https://php.net/manual/en/spldoublylinkedlist.pop.php
+     * @return TValue The value of the popped node.
+     */
+    #[TentativeType]
+    public function pop(): mixed {}
+
+    /**
+     * Shifts a node from the beginning of the doubly linked list
+     * @link https://php.net/manual/en/spldoublylinkedlist.shift.php
+     * @return TValue The value of the shifted node.
+     */
+    #[TentativeType]
+    public function shift(): mixed {}
+
+    /**
+     * Pushes an element at the end of the doubly linked list
+     * @link https://php.net/manual/en/spldoublylinkedlist.push.php
+     * @param TValue $value <p>
+     * The value to push.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function push(#[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $value): void {}
+
+    /**
+     * Prepends the doubly linked list with an element
+     * @link https://php.net/manual/en/spldoublylinkedlist.unshift.php
+     * @param TValue $value <p>
+     * The value to unshift.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function unshift(#[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $value): void {}
+
+    /**
+     * Peeks at the node from the end of the doubly linked list
+     * @link https://php.net/manual/en/spldoublylinkedlist.top.php
+     * @return TValue The value of the last node.
+     */
+    #[TentativeType]
+    public function top(): mixed {}
+
+    /**
+     * Peeks at the node from the beginning of the doubly linked list
+     * @link https://php.net/manual/en/spldoublylinkedlist.bottom.php
+     * @return TValue The value of the first node.
+     */
+    #[TentativeType]
+    public function bottom(): mixed {}
+
+    /**
+     * Checks whether the doubly linked list is empty.
+     * @link https://php.net/manual/en/spldoublylinkedlist.isempty.php
+     * @return bool whether the doubly linked list is empty.
+     */
+    #[TentativeType]
+    public function isEmpty(): bool {}
+
+    /**
+     * Sets the mode of iteration
+     * @link https://php.net/manual/en/spldoublylinkedlist.setiteratormode.php
+     * @param int $mode <p>
+     * There are two orthogonal sets of modes that can be set:
+     * </p>
+     * The direction of the iteration (either one or the other):
+     * <b>SplDoublyLinkedList::IT_MODE_LIFO</b> (Stack style)
+     * @return int
+     */
+    #[TentativeType]
+    public function setIteratorMode(int $mode): int {}
+
+    /**
+     * Returns the mode of iteration
+     * @link https://php.net/manual/en/spldoublylinkedlist.getiteratormode.php
+     * @return int the different modes and flags that affect the iteration.
+     */
+    #[TentativeType]
+    public function getIteratorMode(): int {}
+
+    /**
+     * Counts the number of elements in the doubly linked list.
+     * @link https://php.net/manual/en/spldoublylinkedlist.count.php
+     * @return int the number of elements in the doubly linked list.
+     */
+    #[TentativeType]
+    public function count(): int {}
+
+    /**
+     * Returns whether the requested $index exists
+     * @link https://php.net/manual/en/spldoublylinkedlist.offsetexists.php
+     * @param int $index <p>
+     * The index being checked.
+     * </p>
+     * @return bool true if the requested <i>index</i> exists, otherwise false
+     */
+    #[TentativeType]
+    public function offsetExists($index): bool {}
+
+    /**
+     * Returns the value at the specified $index
+     * @link https://php.net/manual/en/spldoublylinkedlist.offsetget.php
+     * @param int $index <p>
+     * The index with the value.
+     * </p>
+     * @return TValue The value at the specified <i>index</i>.
+     */
+    #[TentativeType]
+    public function offsetGet($index): mixed {}
+
+    /**
+     * Sets the value at the specified $index to $newval
+     * @link https://php.net/manual/en/spldoublylinkedlist.offsetset.php
+     * @param int $index <p>
+     * The index being set.
+     * </p>
+     * @param TValue $value <p>
+     * The new value for the <i>index</i>.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function offsetSet($index, #[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $value): void {}
+
+    /**
+     * Unsets the value at the specified $index
+     * @link https://php.net/manual/en/spldoublylinkedlist.offsetunset.php
+     * @param int $index <p>
+     * The index being unset.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function offsetUnset($index): void {}
+
+    /**
+     * Rewind iterator back to the start
+     * @link https://php.net/manual/en/spldoublylinkedlist.rewind.php
+     * @return void
+     */
+    #[TentativeType]
+    public function rewind(): void {}
+
+    /**
+     * Return current array entry
+     * @link https://php.net/manual/en/spldoublylinkedlist.current.php
+     * @return TValue The current node value.
+     */
+    #[TentativeType]
+    public function current(): mixed {}
+
+    /**
+     * Return current node index
+     * @link https://php.net/manual/en/spldoublylinkedlist.key.php
+     * @return int The current node index.
+     */
+    #[TentativeType]
+    public function key(): int {}
+
+    /**
+     * Move to next entry
+     * @link https://php.net/manual/en/spldoublylinkedlist.next.php
+     * @return void
+     */
+    #[TentativeType]
+    public function next(): void {}
+
+    /**
+     * Move to previous entry
+     * @link https://php.net/manual/en/spldoublylinkedlist.prev.php
+     * @return void
+     */
+    #[TentativeType]
+    public function prev(): void {}
+
+    /**
+     * Check whether the doubly linked list contains more nodes
+     * @link https://php.net/manual/en/spldoublylinkedlist.valid.php
+     * @return bool true if the doubly linked list contains any more nodes, false otherwise.
+     */
+    #[TentativeType]
+    public function valid(): bool {}
+
+    /**
+     * Unserializes the storage
+     * @link https://php.net/manual/en/spldoublylinkedlist.serialize.php
+     * @param string $data The serialized string.
+     * @return void
+     * @since 5.4
+     */
+    #[TentativeType]
+    public function unserialize(#[LanguageLevelTypeAware(['8.0' => 'string'], default: '')] $data): void {}
+
+    /**
+     * Serializes the storage
+     * @link https://php.net/manual/en/spldoublylinkedlist.unserialize.php
+     * @return string The serialized string.
+     * @since 5.4
+     */
+    #[TentativeType]
+    public function serialize(): string {}
+
+    /**
+     * @return array
+     * @since 7.4
+     */
+    #[TentativeType]
+    public function __serialize(): array {}
+
+    /**
+     * @param array $data
+     * @since 7.4
+     */
+    #[TentativeType]
+    public function __unserialize(array $data): void {}
+
+    /**
+     * @return array
+     * @since 7.4
+     */
+    #[TentativeType]
+    public function __debugInfo(): array {}
+}
+
+/**
+ * The SplQueue class provides the main functionalities of a queue implemented using a doubly linked list.
+ * @link https://php.net/manual/en/class.splqueue.php
+ * @template TValue
+ * @template-extends SplDoublyLinkedList<TValue>
+ */
+class SplQueue extends SplDoublyLinkedList
+{
+    /**
+     * Adds an element to the queue.
+     * @link https://php.net/manual/en/splqueue.enqueue.php
+     * @param TValue $value <p>
+     * The value to enqueue.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function enqueue(#[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $value): void {}
+
+    /**
+     * Dequeues a node from the queue
+     * @link https://php.net/manual/en/splqueue.dequeue.php
+     * @return TValue The value of the dequeued node.
+     */
+    #[TentativeType]
+    public function dequeue(): mixed {}
+}
+
+/**
+ * The SplStack class provides the main functionalities of a stack implemented using a doubly linked list.
+ * @link https://php.net/manual/en/class.splstack.php
+ * @template TValue
+ * @template-extends SplDoublyLinkedList<TValue>
+ */
+class SplStack extends SplDoublyLinkedList {}
+
+/**
+ * The SplHeap class provides the main functionalities of an Heap.
+ * @link https://php.net/manual/en/class.splheap.php
+ * @template TValue
+ * @template-implements Iterator<int, TValue>
+ */
+abstract class SplHeap implements Iterator, Countable
+{
+    /**
+     * Extracts a node from top of the heap and sift up.
+     * @link https://php.net/manual/en/splheap.extract.php
+     * @return TValue The value of the extracted node.
+     */
+    #[TentativeType]
+    public function extract(): mixed {}
+
+    /**
+     * Inserts an element in the heap by sifting it up.
+     * @link https://php.net/manual/en/splheap.insert.php
+     * @param TValue $value <p>
+     * The value to insert.
+     * </p>
+     * @return bool
+     */
+    #[LanguageLevelTypeAware(['8.3' => 'true'], default: 'bool')]
+    public function insert(#[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $value) {}
+
+    /**
+     * Peeks at the node from the top of the heap
+     * @link https://php.net/manual/en/splheap.top.php
+     * @return TValue The value of the node on the top.
+     */
+    #[TentativeType]
+    public function top(): mixed {}
+
+    /**
+     * Counts the number of elements in the heap.
+     * @link https://php.net/manual/en/splheap.count.php
+     * @return int the number of elements in the heap.
+     */
+    #[TentativeType]
+    public function count(): int {}
+
+    /**
+     * Checks whether the heap is empty.
+     * @link https://php.net/manual/en/splheap.isempty.php
+     * @return bool whether the heap is empty.
+     */
+    #[TentativeType]
+    public function isEmpty(): bool {}
+
+    /**
+     * Rewind iterator back to the start (no-op)
+     * @link https://php.net/manual/en/splheap.rewind.php
+     * @return void
+     */
+    #[TentativeType]
+    public function rewind(): void {}
+
+    /**
+     * Return current node pointed by the iterator
+     * @link https://php.net/manual/en/splheap.current.php
+     * @return TValue The current node value.
+     */
+    #[TentativeType]
+    public function current(): mixed {}
+
+    /**
+     * Return current node index
+     * @link https://php.net/manual/en/splheap.key.php
+     * @return int The current node index.
+     */
+    #[TentativeType]
+    public function key(): int {}
+
+    /**
+     * Move to the next node
+     * @link https://php.net/manual/en/splheap.next.php
+     * @return void
+     */
+    #[TentativeType]
+    public function next(): void {}
+
+    /**
+     * Check whether the heap contains more nodes
+     * @link https://php.net/manual/en/splheap.valid.php
+     * @return bool true if the heap contains any more nodes, false otherwise.
+     */
+    #[TentativeType]
+    public function valid(): bool {}
+
+    /**
+     * Recover from the corrupted state and allow further actions on the heap.
+     * @link https://php.net/manual/en/splheap.recoverfromcorruption.php
+     * @return bool
+     */
+    #[LanguageLevelTypeAware(['8.3' => 'true'], default: 'bool')]
+    public function recoverFromCorruption() {}
+
+    /**
+     * Compare elements in order to place them correctly in the heap while sifting up.
+     * @link https://php.net/manual/en/splheap.compare.php
+     * @param TValue $value1 <p>
+     * The value of the first node being compared.
+     * </p>
+     * @param TValue $value2 <p>
+     * The value of the second node being compared.
+     * </p>
+     * @return int Result of the comparison, positive integer if <i>value1</i> is greater than <i>value2</i>, 0 if they are equal, negative integer otherwise.
+     * </p>
+     * <p>
+     * Having multiple elements with the same value in a Heap is not recommended. They will end up in an arbitrary relative position.
+     */
+    #[TentativeType]
+    abstract protected function compare(mixed $value1, mixed $value2): int;
+
+    /**
+     * @return bool
+     */
+    #[TentativeType]
+    public function isCorrupted(): bool {}
+
+    /**
+     * @return array
+     * @since 7.4
+     */
+    #[TentativeType]
+    public function __debugInfo(): array {}
+}
+
+/**
+ * The SplFixedArray class provides the main functionalities of array. The
+ * main differences between a SplFixedArray and a normal PHP array is that
+ * the SplFixedArray is of fixed length and allows only integers within
+ * the range as indexes. The advantage is that it allows a faster array
+ * implementation.
+ * @link https://php.net/manual/en/class.splfixedarray.php
+ * @template TValue
+ * @template-implements ArrayAccess<int, TValue>
+ * @template-implements Iterator<int, TValue>
+ * @template-implements IteratorAggregate<int, TValue>
+ */
+class SplFixedArray implements IteratorAggregate, ArrayAccess, Countable, JsonSerializable
+{
+    /**
+     * Constructs a new fixed array
+     * @link https://php.net/manual/en/splfixedarray.construct.php
+     * @param int $size [optional]
+     */
+    public function __construct(int $size = 0) {}
+
+    /**
+     * Returns the size of the array
+     * @link https://php.net/manual/en/splfixedarray.count.php
+     * @return int the size of the array.
+     */
+    #[TentativeType]
+    public function count(): int {}
+
+    /**
+     * Returns a PHP array from the fixed array
+     * @link https://php.net/manual/en/splfixedarray.toarray.php
+     * @return TValue[] a PHP array, containing the same elements as the fixed array.
+     */
+    #[TentativeType]
+    public function toArray(): array {}
+
+    /**
+     * Import a PHP array in a <b>SplFixedArray</b> instance
+     * @link https://php.net/manual/en/splfixedarray.fromarray.php
+     * @param array $array <p>
+     * The array to import.
+     * </p>
+     * @param bool $preserveKeys [optional] <p>
+     * Try to save the numeric indexes used in the original array.
+     * </p>
+     * @return SplFixedArray an instance of <b>SplFixedArray</b>
+     * containing the array content.
+     */
+    #[TentativeType]
+    public static function fromArray(
+        #[LanguageLevelTypeAware(['8.0' => 'array'], default: '')] $array,
+        #[LanguageLevelTypeAware(['8.0' => 'bool'], default: '')] $preserveKeys = true
+    ): SplFixedArray {}
+
+    /**
+     * Gets the size of the array
+     * @link https://php.net/manual/en/splfixedarray.getsize.php
+     * @return int the size of the array, as an integer.
+     */
+    #[TentativeType]
+    public function getSize(): int {}
+
+    /**
+     * Change the size of an array
+     * @link https://php.net/manual/en/splfixedarray.setsize.php
+     * @param int $size <p>
+     * The new array size.
+     * </p>
+     * @return bool
+     */
+    #[LanguageLevelTypeAware(['8.3' => 'true'], default: 'bool')]
+    public function setSize(#[LanguageLevelTypeAware(['8.0' => 'int'], default: '')] $size) {}
+
+    /**
+     * Returns whether the requested index exists
+     * @link https://php.net/manual/en/splfixedarray.offsetexists.php
+     * @param int $index <p>
+     * The index being checked.
+     * </p>
+     * @return bool true if the requested <i>index</i> exists, otherwise false
+     */
+    #[TentativeType]
+    public function offsetExists($index): bool {}
+
+    /**
+     * Returns the value at the specified index
+     * @link https://php.net/manual/en/splfixedarray.offsetget.php
+     * @param int $index <p>
+     * The index with the value.
+     * </p>
+     * @return TValue The value at the specified <i>index</i>.
+     */
+    #[TentativeType]
+    public function offsetGet($index): mixed {}
+
+    /**
+     * Sets a new value at a specified index
+     * @link https://php.net/manual/en/splfixedarray.offsetset.php
+     * @param int $index <p>
+     * The index being set.
+     * </p>
+     * @param TValue $value <p>
+     * The new value for the <i>index</i>.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function offsetSet($index, #[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $value): void {}
+
+    /**
+     * Unsets the value at the specified $index
+     * @link https://php.net/manual/en/splfixedarray.offsetunset.php
+     * @param int $index <p>
+     * The index being unset.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function offsetUnset($index): void {}
+
+    /**
+     * @return Iterator<int, TValue>
+     */
+    public function getIterator(): Iterator {}
+
+    public function jsonSerialize(): array {}
+
+    #[TentativeType]
+    public function __wakeup(): void {}
+
+    #[PhpStormStubsElementAvailable(from: '8.2')]
+    public function __serialize(): array {}
+
+    #[PhpStormStubsElementAvailable(from: '8.2')]
+    public function __unserialize(array $data): void {}
+}
+
+/**
+ * The SplObserver interface is used alongside
+ * <b>SplSubject</b> to implement the Observer Design Pattern.
+ * @link https://php.net/manual/en/class.splobserver.php
+ */
+interface SplObserver
+{
+    /**
+     * Receive update from subject
+     * @link https://php.net/manual/en/splobserver.update.php
+     * @param SplSubject $subject <p>
+     * The <b>SplSubject</b> notifying the observer of an update.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function update(SplSubject $subject): void;
+}
+
+/**
+ * The SplSubject interface is used alongside
+ * <b>SplObserver</b> to implement the Observer Design Pattern.
+ * @link https://php.net/manual/en/class.splsubject.php
+ */
+interface SplSubject
+{
+    /**
+     * Attach an SplObserver
+     * @link https://php.net/manual/en/splsubject.attach.php
+     * @param SplObserver $observer <p>
+     * The <b>SplObserver</b> to attach.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function attach(SplObserver $observer): void;
+
+    /**
+     * Detach an observer
+     * @link https://php.net/manual/en/splsubject.detach.php
+     * @param SplObserver $observer <p>
+     * The <b>SplObserver</b> to detach.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function detach(SplObserver $observer): void;
+
+    /**
+     * Notify an observer
+     * @link https://php.net/manual/en/splsubject.notify.php
+     * @return void
+     */
+    #[TentativeType]
+    public function notify(): void;
+}
+
+/**
+ * The SplObjectStorage class provides a map from objects to data or, by
+ * ignoring data, an object set. This dual purpose can be useful in many
+ * cases involving the need to uniquely identify objects.
+ * @link https://php.net/manual/en/class.splobjectstorage.php
+ * @template TObject of object
+ * @template TData
+ * @template-implements Iterator<int, TObject>
+ * @template-implements ArrayAccess<TObject, TData>
+ */
+class SplObjectStorage implements Countable, Iterator, Serializable, ArrayAccess
+{
+    /**
+     * Adds an object in the storage
+     * @link https://php.net/manual/en/splobjectstorage.attach.php
+     * @param TObject $object <p>
+     * The object to add.
+     * </p>
+     * @param TData $info [optional] <p>
+     * The data to associate with the object.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function attach(
+        #[LanguageLevelTypeAware(['8.0' => 'object'], default: '')] $object,
+        #[LanguageLevelTypeAware(['8.0' => 'mixed'], default: '')] $info = null
+    ): void {}
+
+    /**
+     * Removes an object from the storage
+     * @link https://php.net/manual/en/splobjectstorage.detach.php
+     * @param TObject $object <p>
+     * The object to remove.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function detach(#[LanguageLevelTypeAware(['8.0' => 'object'], default: '')] $object): void {}
+
+    /**
+     * Checks if the storage contains a specific object
+     * @link https://php.net/manual/en/splobjectstorage.contains.php
+     * @param TObject $object <p>
+     * The object to look for.
+     * </p>
+     * @return bool true if the object is in the storage, false otherwise.
+     */
+    #[TentativeType]
+    public function contains(#[LanguageLevelTypeAware(['8.0' => 'object'], default: '')] $object): bool {}
+
+    /**
+     * Adds all objects from another storage
+     * @link https://php.net/manual/en/splobjectstorage.addall.php
+     * @param SplObjectStorage<TObject, TData> $storage <p>
+     * The storage you want to import.
+     * </p>
+     * @return int The number of objects in the storage.
+     */
+    #[TentativeType]
+    public function addAll(#[LanguageLevelTypeAware(['8.0' => 'SplObjectStorage'], default: '')] $storage): int {}
+
+    /**
+     * Returns the data associated with the current iterator entry
+     * @link https://php.net/manual/en/splobjectstorage.getinfo.php
+     * @return TData The data associated with the current iterator position.
+     */
+    #[TentativeType]
+    public function getInfo(): mixed {}
+
+    /**
+     * Calculate a unique identifier for the contained objects
+     * @link https://php.net/manual/en/splobjectstorage.gethash.php
+     * @param object $object <p>
+     * object whose identifier is to be calculated.
+     * </p>
+     * @return string A string with the calculated identifier.
+     * @since 5.4
+     */
+    #[TentativeType]
+    public function getHash(#[LanguageLevelTypeAware(['8.0' => 'object'], default: '')] $object): string {}
+
+    /**
+     * Returns the number of objects in the storage
+     * @link https://php.net/manual/en/splobjectstorage.count.php
+     * @param int $mode [optional]
+     * @return int The number of objects in the storage.
+     */
+    #[TentativeType]
+    public function count(#[PhpStormStubsElementAvailable(from: '8.0')] int $mode = COUNT_NORMAL): int {}
+
+    /**
+     * Rewind the iterator to the first storage element
+     * @link https://php.net/manual/en/splobjectstorage.rewind.php
+     * @return void
+     */
+    #[TentativeType]
+    public function rewind(): void {}
+
+    /**
+     * Returns if the current iterator entry is valid
+     * @link https://php.net/manual/en/splobjectstorage.valid.php
+     * @return bool true if the iterator entry is valid, false otherwise.
+     */
+    #[TentativeType]
+    public function valid(): bool {}
+
+    /**
+     * Returns the index at which the iterator currently is
+     * @link https://php.net/manual/en/splobjectstorage.key.php
+     * @return int The index corresponding to the position of the iterator.
+     */
+    #[TentativeType]
+    public function key(): int {}
+
+    /**
+     * Returns the current storage entry
+     * @link https://php.net/manual/en/splobjectstorage.current.php
+     * @return TObject The object at the current iterator position.
+     */
+    #[TentativeType]
+    public function current(): object {}
+
+    /**
+     * Move to the next entry
+     * @link https://php.net/manual/en/splobjectstorage.next.php
+     * @return void
+     */
+    #[TentativeType]
+    public function next(): void {}
+
+    /**
+     * Unserializes a storage from its string representation
+     * @link https://php.net/manual/en/splobjectstorage.unserialize.php
+     * @param string $data <p>
+     * The serialized representation of a storage.
+     * </p>
+     * @return void
+     * @since 5.2.2
+     */
+    #[TentativeType]
+    public function unserialize(#[LanguageLevelTypeAware(['8.0' => 'string'], default: '')] $data): void {}
+
+    /**
+     * Serializes the storage
+     * @link https://php.net/manual/en/splobjectstorage.serialize.php
+     * @return string A string representing the storage.
+     * @since 5.2.2
+     */
+    #[TentativeType]
+    public function serialize(): string {}
+
+    /**
+     * Checks whether an object exists in the storage
+     * @link https://php.net/manual/en/splobjectstorage.offsetexists.php
+     * @param TObject $object <p>
+     * The object to look for.
+     * </p>
+     * @return bool true if the object exists in the storage,
+     * and false otherwise.
+     */
+    #[TentativeType]
+    public function offsetExists($object): bool {}
+
+    /**
+     * Associates data to an object in the storage
+     * @link https://php.net/manual/en/splobjectstorage.offsetset.php
+     * @param TObject $object <p>
+     * The object to associate data with.
+     * </p>
+     * @param TData $info [optional] <p>
+     * The data to associate with the object.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function offsetSet(
+        #[LanguageLevelTypeAware(['8.1' => 'mixed'], default: '')] $object,
+        #[LanguageLevelTypeAware(['8.1' => 'mixed'], default: '')] $info = null
+    ): void {}
+
+    /**
+     * Removes an object from the storage
+     * @link https://php.net/manual/en/splobjectstorage.offsetunset.php
+     * @param TObject $object <p>
+     * The object to remove.
+     * </p>
+     * @return void
+     */
+    #[TentativeType]
+    public function offsetUnset($object): void {}
+
+    /**
+     * Returns the data associated with an <type>object</type>
+     * @link https://php.net/manual/en/splobjectstorage.offsetget.php
+     * @param TObject $object <p>
+     * The object to look for.
+     * </p>
+     * @return TData The data previously associated with the object in the storage.
+     */
+    #[TentativeType]
+    public function offsetGet($object): mixed {}
+
+    /**
+     * @return array
+     * @since 7.4
+     */
+    #[TentativeType]
+    public function __serialize(): array {}
+
+    /**
+     * @param array $data
+     * @since 7.4
+     */
+    #[TentativeType]
+    public function __unserialize(array $data): void {}
+
+    /**
+     * @return array
+     * @since 7.4
+     */
+    #[TentativeType]
+    public function __debugInfo(): array {}
+
+    /**
+     * Returns the object that the iterator currently points to
+     * @return TObject
+     * @since 8.4
+     */
+    #[PhpStormStubsElementAvailable(from: '8.4')]
+    public function seek(int $offset): void {}
+}
+
+/**
+ * Return the interfaces which are implemented by the given class or interface
+ * @link https://php.net/manual/en/function.class-implements.php
+ * @param object|string $object_or_class <p>
+ * An object (class instance) or a string (class or interface name).
+ * </p>
+ * @param bool $autoload [optional] <p>
+ * Whether to allow this function to load the class automatically through
+ * the __autoload magic method.
+ * </p>
+ * @return string[]|false An array on success, or false on error.
+ */
+#[Pure]
+function class_implements($object_or_class, bool $autoload = true): array|false {}
+
+/**
+ * Return the traits used by the given class
+ * @param object|string $object_or_class An object (class instance) or a string (class name).
+ * @param bool $autoload Whether to allow this function to load the class automatically through the __autoload() magic method.
+ * @return string[]|false An array on success, or false on error.
+ * @link https://php.net/manual/en/function.class-uses.php
+ * @see class_parents()
+ * @see get_declared_traits()
+ * @since 5.4
+ */
+function class_uses($object_or_class, bool $autoload = true): array|false {}
+
+/**
+ * Register given function as __autoload() implementation
+ * @link https://php.net/manual/en/function.spl-autoload-register.php
+ * @param callable|null $callback [optional] <p>
+ * The autoload function being registered.
+ * If no parameter is provided, then the default implementation of
+ * spl_autoload will be registered.
+ * </p>
+ * @param bool $throw This parameter specifies whether spl_autoload_register() should throw exceptions when the
+ * autoload_function cannot be registered. Ignored since since 8.0.
+ * @param bool $prepend If true, spl_autoload_register() will prepend the autoloader on the autoload stack instead of
+ * appending it.
+ * @return bool true on success or false on failure.
+ * @throws TypeError It throws TypeError since 8.0 if a callback is invalid.
+ * @since 5.1.2
+ */
+#[LanguageLevelTypeAware(["8.3" => "true"], default: "bool")]
+function spl_autoload_register(?callable $callback, bool $throw = true, bool $prepend = false): bool {}
+
+/**
+ * Return hash id for given object
+ * @link https://php.net/manual/en/function.spl-object-hash.php
+ * @param object $object
+ * @return string A string that is unique for each object and is always the same for
+ * the same object.
+ */
+#[Pure]
+function spl_object_hash(object $object): string {}
+
+/**
+ * Return the integer object handle for given object
+ * @link https://php.net/manual/en/function.spl-object-id.php
+ * @param object $object
+ * @return int An integer identifier that is unique for each currently existing object and is always the same for each object.
+ * @since 7.2
+ */
+#[Pure]
+function spl_object_id(object $object): int {}
+
+/**
+ * Copy the iterator into an array
+ * @link https://php.net/manual/en/function.iterator-to-array.php
+ * @param Traversable|array $iterator <p>
+ * The iterator being copied.
+ * </p>
+ * @param bool $preserve_keys [optional] <p>
+ * Whether to use the iterator element keys as index.
+ * </p>
+ * @return array An array containing the elements of the iterator.
+ */
+function iterator_to_array(#[LanguageLevelTypeAware(['8.2' => 'Traversable|array'], default: 'Traversable')] $iterator, bool $preserve_keys = true): array {}
+
+/**
+ * Count the elements in an iterator
+ * @link https://php.net/manual/en/function.iterator-count.php
+ * @param Traversable|array $iterator <p>
+ * The iterator being counted.
+ * </p>
+ * @return int The number of elements in iterator.
+ */
+#[Pure]
+function iterator_count(#[LanguageLevelTypeAware(['8.2' => 'Traversable|array'], default: 'Traversable')] $iterator): int {}
+
+/**
+ * Call a function for every element in an iterator
+ * @link https://php.net/manual/en/function.iterator-apply.php
+ * @param Traversable $iterator <p>
+ * The class to iterate over.
+ * </p>
+ * @param callable $callback <p>
+ * The callback function to call on every element.
+ * The function must return true in order to
+ * continue iterating over the iterator.
+ * </p>
+ * @param array|null $args [optional] <p>
+ * Arguments to pass to the callback function.
+ * </p>
+ * @return int<0,max> the iteration count.
+ */
+function iterator_apply(Traversable $iterator, callable $callback, ?array $args = null): int {}
+
+// End of SPL v.8.2
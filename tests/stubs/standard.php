@@ -0,0 +1,2186 @@
+<?php
+
+// Start of standard v.8.2
+
+use JetBrains\PhpStorm\ArrayShape;
+use JetBrains\PhpStorm\Deprecated;
+use JetBrains\PhpStorm\ExpectedValues;
+use JetBrains\PhpStorm\Internal\LanguageLevelTypeAware;
+use JetBrains\PhpStorm\Internal\PhpStormStubsElementAvailable;
+use JetBrains\PhpStorm\Pure;
+
+/**
+ * Returns the absolute value of a number
+ * @link https://php.net/manual/en/function.abs.php
+ * @param int|float $num <p>
+ * The numeric value to process
+ * </p>
+ * @return float|int The absolute value of number. If the
+ * argument number is
+ * of type float, the return type is also float,
+ * otherwise it is integer (as float usually has a
+ * bigger value range than integer).
+ */
+#[Pure]
+function abs(int|float $num): int|float {}
+
+/**
+ * Round fractions up
+ * @link https://php.net/manual/en/function.ceil.php
+ * @param int|float $num <p>
+ * The value to round
+ * </p>
+ * @return float|false value rounded up to the next highest
+ * integer.
+ * The return value of ceil is still of type
+ * float as the value range of float is
+ * usually bigger than that of integer.
+ */
+#[Pure]
+function ceil(int|float $num): float {}
+
+/**
+ * Round fractions down
+ * @link https://php.net/manual/en/function.floor.php
+ * @param int|float $num <p>
+ * The numeric value to round
+ * </p>
+ * @return float|false value rounded to the next lowest integer.
+ * The return value of floor is still of type
+ * float because the value range of float is
+ * usually bigger than that of integer.
+ */
+#[Pure]
+function floor(int|float $num): float {}
+
+/**
+ * Returns the rounded value of val to specified precision (number of digits after the decimal point).
+ * precision can also be negative or zero (default).
+ * Note: PHP doesn't handle strings like "12,300.2" correctly by default. See converting from strings.
+ * @link https://php.net/manual/en/function.round.php
+ * @param int|float $num <p>
+ * The value to round
+ * </p>
+ * @param int $precision [optional] <p>
+ * The optional number of decimal digits to round to.
+ * </p>
+ * @param int $mode [optional] <p>
+ * One of PHP_ROUND_HALF_UP,
+ * PHP_ROUND_HALF_DOWN,
+ * PHP_ROUND_HALF_EVEN, or
+ * PHP_ROUND_HALF_ODD.
+ * </p>
+ * @return float The rounded value
+ */
+#[Pure]
+function round(
+    int|float $num,
+    int $precision = 0,
+    #[ExpectedValues([PHP_ROUND_HALF_UP, PHP_ROUND_HALF_DOWN, PHP_ROUND_HALF_EVEN, PHP_ROUND_HALF_ODD])]
+    #[PhpStormStubsElementAvailable(from: '5.3')]
+    int $mode = PHP_ROUND_HALF_UP
+): float {}
+
+/**
+ * Sine
+ * @link https://php.net/manual/en/function.sin.php
+ * @param float $num <p>
+ * A value in radians
+ * </p>
+ * @return float The sine of arg
+ */
+#[Pure]
+function sin(float $num): float {}
+
+/**
+ * Cosine
+ * @link https://php.net/manual/en/function.cos.php
+ * @param float $num <p>
+ * An angle in radians
+ * </p>
+ * @return float The cosine of arg
+ */
+#[Pure]
+function cos(float $num): float {}
+
+/**
+ * Tangent
+ * @link https://php.net/manual/en/function.tan.php
+ * @param float $num <p>
+ * The argument to process in radians
+ * </p>
+ * @return float The tangent of arg
+ */
+#[Pure]
+function tan(float $num): float {}
+
+/**
+ * Arc tangent of two variables
+ * @link https://php.net/manual/en/function.atan2.php
+ * @param float $y <p>
+ * Dividend parameter
+ * </p>
+ * @param float $x <p>
+ * Divisor parameter
+ * </p>
+ * @return float The arc tangent of y/x
+ * in radians.
+ */
+#[Pure]
+function atan2(float $y, float $x): float {}
+
+/**
+ * Returns an approximation of pi
+ * @link https://php.net/manual/en/function.pi.php
+ * @return float The value of pi as float.
+ */
+#[Pure]
+function pi(): float {}
+
+/**
+ * Exponential expression
+ * @link https://php.net/manual/en/function.pow.php
+ * @param mixed $num <p>
+ * The base to use
+ * </p>
+ * @param mixed $exponent <p>
+ * The exponent
+ * </p>
+ * @return object|int|float base raised to the power of exp.
+ * If the result can be represented as integer it will be returned as type
+ * integer, else it will be returned as type float.
+ * If the power cannot be computed false will be returned instead.
+ */
+#[Pure]
+function pow(mixed $num, mixed $exponent): object|int|float {}
+
+/**
+ * Natural logarithm
+ * @link https://php.net/manual/en/function.log.php
+ * @param float $num <p>
+ * The value to calculate the logarithm for
+ * </p>
+ * @param float $base [optional] <p>
+ * The optional logarithmic base to use
+ * (defaults to 'e' and so to the natural logarithm).
+ * </p>
+ * @return float The logarithm of arg to
+ * base, if given, or the
+ * natural logarithm.
+ */
+#[Pure]
+function log(float $num, float $base = M_E): float {}
+
+/**
+ * Square root
+ * @link https://php.net/manual/en/function.sqrt.php
+ * @param float $num <p>
+ * The argument to process
+ * </p>
+ * @return float The square root of arg
+ * or the special value NAN for negative numbers.
+ */
+#[Pure]
+function sqrt(float $num): float {}
+
+/**
+ * Calculate the length of the hypotenuse of a right-angle triangle
+ * @link https://php.net/manual/en/function.hypot.php
+ * @param float $x <p>
+ * Length of first side
+ * </p>
+ * @param float $y <p>
+ * Length of second side
+ * </p>
+ * @return float Calculated length of the hypotenuse
+ */
+#[Pure]
+function hypot(float $x, float $y): float {}
+
+/**
+ * Finds whether a value is not a number
+ * @link https://php.net/manual/en/function.is-nan.php
+ * @param float $num <p>
+ * The value to check
+ * </p>
+ * @return bool true if val is 'not a number',
+ * else false.
+ */
+#[Pure]
+function is_nan(float $num): bool {}
+
+/**
+ * Integer division
+ * @link https://php.net/manual/en/function.intdiv.php
+ * @param int $num1 <p>Number to be divided.</p>
+ * @param int $num2 <p>Number which divides the <b><i>dividend</i></b></p>
+ * @return int
+ * @since 7.0
+ * @throws DivisionByZeroError <p>if divisor is 0</p>
+ * @throws ArithmeticError <p>if the <b><i>dividend</i></b> is <b>PHP_INT_MIN</b> and the <b><i>divisor</i></b> is -1</p>
+ */
+#[Pure]
+function intdiv(int $num1, int $num2): int {}
+
+/**
+ * Divides two numbers, according to IEEE 754
+ * @link https://www.php.net/manual/en/function.fdiv.php
+ * @param float $num1 The dividend
+ * @param float $num2 The divisor
+ * @return float The floating point result of num1/num2
+ * @since 8.0
+ */
+#[Pure]
+function fdiv(float $num1, float $num2): float {}
+
+/**
+ * Find highest value
+ * @link https://php.net/manual/en/function.max.php
+ * @param array|mixed $value Array to look through or first value to compare
+ * @param mixed ...$values any comparable value
+ * @return mixed max returns the numerically highest of the
+ * parameter values, either within a arg array or two arguments.
+ */
+#[Pure]
+function max(mixed $value, mixed ...$values): mixed {}
+
+/**
+ * Find lowest value
+ * @link https://php.net/manual/en/function.min.php
+ * @param array|mixed $value Array to look through or first value to compare
+ * @param mixed ...$values any comparable value
+ * @return mixed min returns the numerically lowest of the
+ * parameter values.
+ */
+#[Pure]
+function min(mixed $value, mixed ...$values): mixed {}
+
+/**
+ * Format a number with grouped thousands
+ * @link https://php.net/manual/en/function.number-format.php
+ * @param float $num <p>
+ * The number being formatted.
+ * </p>
+ * @param int $decimals [optional] <p>
+ * Sets the number of decimal points.
+ * </p>
+ * @param string|null $decimal_separator [optional]
+ * @param string|null $thousands_separator [optional]
+ * @return string A formatted version of number.
+ */
+#[Pure]
+function number_format(
+    float $num,
+    int $decimals = 0,
+    ?string $decimal_separator = ".",
+    ?string $thousands_separator = ","
+): string {}
+
+/**
+ * Converts a number between arbitrary bases
+ * @link https://php.net/manual/en/function.base-convert.php
+ * @param string $num <p>
+ * The number to convert
+ * </p>
+ * @param int $frombase <p>
+ * The base number is in
+ * </p>
+ * @param int $tobase <p>
+ * The base to convert number to
+ * </p>
+ * @return string number converted to base tobase
+ */
+#[Pure]
+function base_convert(string $num, int $frombase, int $tobase): string {}
+
+/**
+ * Strip whitespace (or other characters) from the beginning and end of a string
+ * @link https://php.net/manual/en/function.trim.php
+ * @param string $string <p>
+ * The string that will be trimmed.
+ * </p>
+ * @param string $characters [optional] <p>
+ * Optionally, the stripped characters can also be specified using
+ * the charlist parameter.
+ * Simply list all characters that you want to be stripped. With
+ * .. you can specify a range of characters.
+ * </p>
+ * @return string The trimmed string.
+ */
+#[Pure]
+function trim(string $string, string $characters = " \n\r\t\v\0"): string {}
+
+/**
+ * Strip whitespace (or other characters) from the end of a string
+ * @link https://php.net/manual/en/function.rtrim.php
+ * @param string $string <p>
+ * The input string.
+ * </p>
+ * @param string $characters [optional] <p>
+ * You can also specify the characters you want to strip, by means
+ * of the charlist parameter.
+ * Simply list all characters that you want to be stripped. With
+ * .. you can specify a range of characters.
+ * </p>
+ * @return string the modified string.
+ */
+#[Pure]
+function rtrim(string $string, string $characters = " \n\r\t\v\0"): string {}
+
+/**
+ * Strip whitespace (or other characters) from the beginning of a string
+ * @link https://php.net/manual/en/function.ltrim.php
+ * @param string $string <p>
+ * The input string.
+ * </p>
+ * @param string $characters [optional] <p>
+ * You can also specify the characters you want to strip, by means of the
+ * charlist parameter.
+ * Simply list all characters that you want to be stripped. With
+ * .. you can specify a range of characters.
+ * </p>
+ * @return string This function returns a string with whitespace stripped from the
+ * beginning of str.
+ */
+#[Pure]
+function ltrim(string $string, string $characters = " \n\r\t\v\0"): string {}
+
+/**
+ * Alias of <b>rtrim</b>
+ * @link https://php.net/manual/en/function.chop.php
+ * @param string $string The input string.
+ * @param string $characters [optional]
+ * @return string the modified string.
+ */
+#[Pure]
+function chop(string $string, string $characters = " \n\r\t\v\0"): string {}
+
+/**
+ * Split a string by a string
+ * @link https://php.net/manual/en/function.explode.php
+ * @param string $separator <p>
+ * The boundary string.
+ * </p>
+ * @param string $string <p>
+ * The input string.
+ * </p>
+ * @param int $limit [optional] <p>
+ * If limit is set and positive, the returned array will contain
+ * a maximum of limit elements with the last
+ * element containing the rest of string.
+ * </p>
+ * <p>
+ * If the limit parameter is negative, all components
+ * except the last -limit are returned.
+ * </p>
+ * <p>
+ * If the limit parameter is zero, then this is treated as 1.
+ * </p>
+ * @return string[]|false If delimiter is an empty string (""),
+ * explode will return false.
+ * If delimiter contains a value that is not
+ * contained in string and a negative
+ * limit is used, then an empty array will be
+ * returned, otherwise an array containing
+ * string will be returned.
+ * @psalm-return list<string>
+ */
+#[Pure]
+#[LanguageLevelTypeAware(["8.0" => "string[]"], default: "string[]|false")]
+function explode(string $separator, string $string, int $limit = PHP_INT_MAX) {}
+
+/**
+ * Join array elements with a string
+ * @link https://php.net/manual/en/function.implode.php
+ * @param array|string $separator [optional]<p>
+ * Defaults to an empty string. This is not the preferred usage of
+ * implode as glue would be
+ * the second parameter and thus, the bad prototype would be used.
+ * </p>
+ * @param array|null $array <p>
+ * The array of strings to implode.
+ * </p>
+ * @return string a string containing a string representation of all the array
+ * elements in the same order, with the glue string between each element.
+ */
+#[Pure]
+function implode(array|string $separator = "", ?array $array): string {}
+
+/**
+ * Alias of <b>implode</b>
+ * @link https://php.net/manual/en/function.join.php
+ * @param array|string $separator [optional] Defaults to an empty string.
+ * @param array|null $array [optional] The array of strings to implode.
+ * @return string a string containing a string representation of all the array
+ * elements in the same order, with the glue string between each element.
+ */
+#[Pure]
+function join(array|string $separator = "", ?array $array): string {}
+
+/**
+ * Find the position of the first occurrence of a substring in a string
+ * @link https://php.net/manual/en/function.strpos.php
+ * @param string $haystack <p>
+ * The string to search in
+ * </p>
+ * @param string $needle <p>
+ * If <b>needle</b> is not a string, it is converted
+ * to an integer and applied as the ordinal value
+ * of a character.
+ * </p>
+ * @param int<0,max> $offset [optional] <p>
+ * If specified, search will start this number of characters counted from
+ * the beginning of the string. Unlike {@see strrpos()} and {@see strripos()}, the offset cannot be negative.
+ * </p>
+ * @return int<0,max>|false <p>
+ * Returns the position where the needle exists relative to the beginnning of
+ * the <b>haystack</b> string (independent of search direction
+ * or offset).
+ * Also note that string positions start at 0, and not 1.
+ * </p>
+ * <p>
+ * Returns <b>FALSE</b> if the needle was not found.
+ * </p>
+ */
+#[Pure]
+function strpos(string $haystack, string $needle, int $offset = 0): int|false {}
+
+/**
+ * Find the numeric position of the first occurrence of a substring in a string, case-insensitively
+ * @link https://php.net/manual/en/function.stripos.php
+ * @param string $haystack <p>
+ * The string to search in
+ * </p>
+ * @param string $needle <p>
+ * Note that the needle may be a string of one or
+ * more characters.
+ * </p>
+ * @param int $offset [optional] <p>
+ * The optional offset parameter allows you
+ * to specify which character in haystack to
+ * start searching. The position returned is still
+ * relative to the beginning of haystack.
+ * </p>
+ * @return int|false If needle is not found,
+ * stripos will return boolean false.
+ */
+#[Pure]
+function stripos(string $haystack, string $needle, int $offset = 0): int|false {}
+
+/**
+ * Determine if a string contains a given substring
+ * @link https://www.php.net/manual/en/function.str-contains.php
+ * @param string $haystack The string to search in.
+ * @param string $needle The substring to search for in the haystack.
+ * @return bool Returns <b>TRUE</b> if needle is in haystack, <b>FALSE</b> otherwise.
+ * @since 8.0
+ */
+#[Pure]
+function str_contains(string $haystack, string $needle): bool {}
+
+/**
+ * Checks if a string starts with a given substring
+ * @link https://www.php.net/manual/en/function.str-starts-with.php
+ * @param string $haystack The string to search in.
+ * @param string $needle The substring to search for in the haystack.
+ * @return bool Returns <b>TRUE</b> if haystack begins with needle, <b>FALSE</b> otherwise.
+ * @since 8.0
+ */
+#[Pure]
+function str_starts_with(string $haystack, string $needle): bool {}
+
+/**
+ * Checks if a string ends with a given substring
+ * @link https://www.php.net/manual/en/function.str-ends-with.php
+ * @param string $haystack The string to search in.
+ * @param string $needle The substring to search for in the haystack.
+ * @return bool Returns <b>TRUE</b> if haystack ends with needle, <b>FALSE</b> otherwise.
+ * @since 8.0
+ */
+#[Pure]
+function str_ends_with(string $haystack, string $needle): bool {}
+
+/**
+ * Return part of a string
+ * @link https://php.net/manual/en/function.substr.php
+ * @param string $string <p>
+ * The input string. Must be one character or longer.
+ * </p>
+ * @param int $offset <p>
+ * If start is non-negative, the returned string
+ * will start at the start'th position in
+ * string, counting from zero. For instance,
+ * in the string 'abcdef', the character at
+ * position 0 is 'a', the
+ * character at position 2 is
+ * 'c', and so forth.
+ * </p>
+ * <p>
+ * If start is negative, the returned string
+ * will start at the start'th character
+ * from the end of string.
+ * </p>
+ * @param int|null $length [optional] <p>
+ * If length is given and is positive, the string
+ * returned will contain at most length characters
+ * beginning from start (depending on the length of
+ * string).
+ * </p>
+ * @return string the extracted part of string.
+ */
+#[Pure]
+function substr(string $string, int $offset, ?int $length = null): string {}
+
+/**
+ * Replace text within a portion of a string
+ * @link https://php.net/manual/en/function.substr-replace.php
+ * @param string[]|string $string <p>
+ * The input string.
+ * </p>
+ * @param string[]|string $replace <p>
+ * The replacement string.
+ * </p>
+ * @param int[]|int $offset <p>
+ * If start is positive, the replacing will
+ * begin at the start'th offset into
+ * string.
+ * </p>
+ * @param int[]|int $length [optional] <p>
+ * If given and is positive, it represents the length of the portion of
+ * string which is to be replaced.
+ * </p>
+ * @return string|string[] The result string is returned. If string is an
+ * array then array is returned.
+ */
+#[Pure]
+function substr_replace(array|string $string, array|string $replace, array|int $offset, array|int|null $length = null): array|string {}
+
+/**
+ * Make a string uppercase
+ * @link https://php.net/manual/en/function.strtoupper.php
+ * @param string $string <p>
+ * The input string.
+ * </p>
+ * @return string the uppercased string.
+ */
+#[Pure]
+function strtoupper(string $string): string {}
+
+/**
+ * Make a string lowercase
+ * @link https://php.net/manual/en/function.strtolower.php
+ * @param string $string <p>
+ * The input string.
+ * </p>
+ * @return string the lowercased string.
+ */
+#[Pure]
+function strtolower(string $string): string {}
+
+/**
+ * Make a string's first character uppercase
+ * @link https://php.net/manual/en/function.ucfirst.php
+ * @param string $string <p>
+ * The input string.
+ * </p>
+ * @return string the resulting string.
+ */
+#[Pure]
+function ucfirst(string $string): string {}
+
+/**
+ * Uppercase the first character of each word in a string
+ * @link https://php.net/manual/en/function.ucwords.php
+ * @param string $string <p>
+ * The input string.
+ * </p>
+ * @param string $separators [optional] <p>
+ * The optional separators contains the word separator characters.
+ * </p>
+ * @return string the modified string.
+ */
+#[Pure]
+function ucwords(string $string, string $separators = " \t\r\n\f\v"): string {}
+
+/**
+ * Translate characters or replace substrings
+ * @link https://php.net/manual/en/function.strtr.php
+ * @param string $string <p>
+ * The string being translated.
+ * </p>
+ * @param string $from <p>
+ * The string replacing from.
+ * </p>
+ * @param string $to <p>
+ * The string being translated to to.
+ * </p>
+ * @return string This function returns a copy of str,
+ * translating all occurrences of each character in
+ * from to the corresponding character in
+ * to.
+ */
+#[Pure]
+function strtr(string $string, $from, string $to): string {}
+
+/**
+ * Replace all occurrences of the search string with the replacement string
+ * @link https://php.net/manual/en/function.str-replace.php
+ * @param string|string[] $search <p>
+ * The value being searched for, otherwise known as the needle.
+ * An array may be used to designate multiple needles.
+ * </p>
+ * @param string|string[] $replace <p>
+ * The replacement value that replaces found search
+ * values. An array may be used to designate multiple replacements.
+ * </p>
+ * @param string|string[] $subject <p>
+ * The string or array being searched and replaced on,
+ * otherwise known as the haystack.
+ * </p>
+ * <p>
+ * If subject is an array, then the search and
+ * replace is performed with every entry of
+ * subject, and the return value is an array as
+ * well.
+ * </p>
+ * @param int &$count [optional] If passed, this will hold the number of matched and replaced needles.
+ * @return string|string[] This function returns a string or an array with the replaced values.
+ */
+function str_replace(array|string $search, array|string $replace, string|array $subject, &$count = null): string|array {}
+
+/**
+ * Repeat a string
+ * @link https://php.net/manual/en/function.str-repeat.php
+ * @param string $string <p>
+ * The string to be repeated.
+ * </p>
+ * @param int $times <p>
+ * Number of time the input string should be
+ * repeated.
+ * </p>
+ * <p>
+ * multiplier has to be greater than or equal to 0.
+ * If the multiplier is set to 0, the function
+ * will return an empty string.
+ * </p>
+ * @return string the repeated string.
+ */
+#[Pure]
+function str_repeat(string $string, int $times): string {}
+
+/**
+ * Pad a string to a certain length with another string
+ * @link https://php.net/manual/en/function.str-pad.php
+ * @param string $string <p>
+ * The input string.
+ * </p>
+ * @param int $length <p>
+ * If the value of pad_length is negative,
+ * less than, or equal to the length of the input string, no padding
+ * takes place.
+ * </p>
+ * @param string $pad_string [optional] <p>
+ * The pad_string may be truncated if the
+ * required number of padding characters can't be evenly divided by the
+ * pad_string's length.
+ * </p>
+ * @param int $pad_type [optional] <p>
+ * Optional argument pad_type can be
+ * STR_PAD_RIGHT, STR_PAD_LEFT,
+ * or STR_PAD_BOTH. If
+ * pad_type is not specified it is assumed to be
+ * STR_PAD_RIGHT.
+ * </p>
+ * @return string the padded string.
+ */
+#[Pure]
+function str_pad(string $string, int $length, string $pad_string = " ", int $pad_type = STR_PAD_RIGHT): string {}
+
+/**
+ * Convert a string to an array
+ * @link https://php.net/manual/en/function.str-split.php
+ * @param string $string <p>
+ * The input string.
+ * </p>
+ * @param int $length [optional] <p>
+ * Maximum length of the chunk.
+ * </p>
+ * @return string[]|false <p>If the optional split_length parameter is
+ * specified, the returned array will be broken down into chunks with each
+ * being split_length in length, otherwise each chunk
+ * will be one character in length.
+ * </p>
+ * @psalm-return ($string is non-empty-string ? non-empty-list<string> : list<string>)
+ */
+#[Pure]
+#[LanguageLevelTypeAware(["8.2" => "array"], default: "array|false")]
+function str_split(string $string, int $length = 1) {}
+
+/**
+ * Parses input from a string according to a format
+ * @link https://php.net/manual/en/function.sscanf.php
+ * @param string $string <p>
+ * The input string being parsed.
+ * </p>
+ * @param string $format <p>
+ * The interpreted format for str, which is
+ * described in the documentation for sprintf with
+ * following differences:
+ * Function is not locale-aware.
+ * F, g, G and
+ * b are not supported.
+ * D stands for decimal number.
+ * i stands for integer with base detection.
+ * n stands for number of characters processed so far.
+ * </p>
+ * @param mixed &...$vars [optional]
+ * @return array|int|null if only
+ * two parameters were passed to this function, the values parsed
+ * will be returned as an array. Otherwise, if optional parameters are passed,
+ * the function will return the number of assigned values. The optional
+ * parameters must be passed by reference.
+ */
+function sscanf(string $string, string $format, mixed &...$vars): array|int|null {}
+
+/**
+ * Return a formatted string
+ * @link https://php.net/manual/en/function.sprintf.php
+ * @param string $format <p>
+ * The format string is composed of zero or more directives:
+ * ordinary characters (excluding %) that are
+ * copied directly to the result, and conversion
+ * specifications, each of which results in fetching its
+ * own parameter. This applies to both sprintf
+ * and printf.
+ * </p>
+ * @param mixed ...$values [optional] <p>
+ * </p>
+ * @return string a string produced according to the formatting string
+ * format.
+ */
+#[Pure]
+function sprintf(string $format, mixed ...$values): string {}
+
+/**
+ * Output a formatted string
+ * @link https://php.net/manual/en/function.printf.php
+ * @param string $format <p>
+ * See sprintf for a description of
+ * format.
+ * </p>
+ * @param mixed ...$values [optional] <p>
+ * </p>
+ * @return int the length of the outputted string.
+ */
+function printf(string $format, mixed ...$values): int {}
+
+/**
+ * Binary safe string comparison
+ * @link https://php.net/manual/en/function.substr-compare.php
+ * @param string $haystack <p>
+ * The main string being compared.
+ * </p>
+ * @param string $needle <p>
+ * The secondary string being compared.
+ * </p>
+ * @param int $offset <p>
+ * The start position for the comparison. If negative, it starts counting
+ * from the end of the string.
+ * </p>
+ * @param int|null $length [optional] <p>
+ * The length of the comparison.
+ * </p>
+ * @param bool $case_insensitive [optional] <p>
+ * If case_insensitivity is true, comparison is
+ * case insensitive.
+ * </p>
+ * @return int less than 0 if main_str from position
+ * offset is less than str, &gt;
+ * 0 if it is greater than str, and 0 if they are equal.
+ * If offset is equal to or greater than the length of
+ * main_str or length is set and
+ * is less than 1, substr_compare prints a warning and returns
+ * false.
+ */
+#[Pure]
+function substr_compare(string $haystack, string $needle, int $offset, ?int $length = null, bool $case_insensitive = false): int {}
+
+/**
+ * Calculate the md5 hash of a string
+ * @link https://php.net/manual/en/function.md5.php
+ * @param string $string <p>
+ * The string.
+ * </p>
+ * @param bool $binary [optional] <p>
+ * If the optional raw_output is set to true,
+ * then the md5 digest is instead returned in raw binary format with a
+ * length of 16.
+ * </p>
+ * @return string the hash as a 32-character hexadecimal number.
+ */
+#[Pure]
+function md5(string $string, bool $binary = false): string {}
+
+/**
+ * Calculate the sha1 hash of a string
+ * @link https://php.net/manual/en/function.sha1.php
+ * @param string $string <p>
+ * The input string.
+ * </p>
+ * @param bool $binary [optional] <p>
+ * If the optional raw_output is set to true,
+ * then the sha1 digest is instead returned in raw binary format with a
+ * length of 20, otherwise the returned value is a 40-character
+ * hexadecimal number.
+ * </p>
+ * @return string the sha1 hash as a string.
+ */
+#[Pure]
+function sha1(string $string, bool $binary = false): string {}
+
+/**
+ * Convert special characters to HTML entities
+ * @link https://php.net/manual/en/function.htmlspecialchars.php
+ * @param string $string <p>
+ * The {@link https://secure.php.net/manual/en/language.types.string.php string} being converted.
+ * </p>
+ * @param int $flags [optional] <p>
+ * A bitmask of one or more of the following flags, which specify how to handle quotes,
+ * invalid code unit sequences and the used document type. The default is
+ * <em><b>ENT_COMPAT | ENT_HTML401</b></em>.
+ * </p>
+ * @param string|null $encoding [optional] <p>
+ * Defines encoding used in conversion.
+ * </p>
+ * @param bool $double_encode [optional] <p>
+ * When <b>double_encode</b> is turned off PHP will not
+ * encode existing html entities, the default is to convert everything.
+ * </p>
+ * @return string The converted string.
+ */
+#[Pure]
+function htmlspecialchars(string $string, int $flags = ENT_QUOTES|ENT_SUBSTITUTE|ENT_HTML401, ?string $encoding = null, bool $double_encode = true): string {}
+
+/**
+ * Count elements in an array, or properties in an object
+ * @link https://php.net/manual/en/function.count.php
+ * @param array|Countable $value <p>
+ * The array or the object.
+ * </p>
+ * @param int $mode [optional] <p>
+ * If the optional mode parameter is set to
+ * COUNT_RECURSIVE (or 1), count
+ * will recursively count the array. This is particularly useful for
+ * counting all the elements of a multidimensional array. count does not detect infinite recursion.
+ * </p>
+ * @return int<0,max> the number of elements in var, which is
+ * typically an array, since anything else will have one
+ * element.
+ * </p>
+ * <p>
+ * If var is not an array or an object with
+ * implemented Countable interface,
+ * 1 will be returned.
+ * There is one exception, if var is null,
+ * 0 will be returned.
+ * </p>
+ * <p>
+ * Caution: count may return 0 for a variable that isn't set,
+ * but it may also return 0 for a variable that has been initialized with an
+ * empty array. Use isset to test if a variable is set.
+ */
+#[Pure]
+function count(Countable|array $value, #[ExpectedValues([COUNT_NORMAL, COUNT_RECURSIVE])] int $mode = COUNT_NORMAL): int {}
+
+/**
+ * Alias of <b>count</b>
+ * @link https://php.net/manual/en/function.sizeof.php
+ * @param array|Countable $value
+ * @param int $mode [optional]
+ * @return int<0,max>
+ */
+#[Pure]
+function sizeof(Countable|array $value, int $mode = COUNT_NORMAL): int {}
+
+/**
+ * Sort an array by key in descending order
+ * @link https://php.net/manual/en/function.krsort.php
+ * @param array &$array <p>
+ * The input array.
+ * </p>
+ * @param int $flags [optional] <p>
+ * You may modify the behavior of the sort using the optional parameter
+ * sort_flags, for details see
+ * sort.
+ * </p>
+ * @return bool true on success or false on failure.
+ */
+#[LanguageLevelTypeAware(["8.2" => "true"], default: "bool")]
+function krsort(array &$array, int $flags = SORT_REGULAR) {}
+
+/**
+ * Sort an array
+ * @link https://php.net/manual/en/function.sort.php
+ * @param array &$array <p>
+ * The input array.
+ * </p>
+ * @param int $flags [optional] <p>
+ * The optional second parameter sort_flags
+ * may be used to modify the sorting behavior using these values.
+ * </p>
+ * @return bool true on success or false on failure.
+ */
+#[LanguageLevelTypeAware(["8.2" => "true"], default: "bool")]
+function sort(array &$array, #[ExpectedValues([SORT_REGULAR, SORT_NUMERIC, SORT_STRING, SORT_LOCALE_STRING, SORT_NATURAL, SORT_FLAG_CASE])] int $flags = SORT_REGULAR) {}
+
+/**
+ * Sort an array by values using a user-defined comparison function
+ * @link https://php.net/manual/en/function.usort.php
+ * @param array &$array <p>
+ * The input array.
+ * </p>
+ * @param callable $callback <p>
+ * The comparison function must return an integer less than, equal to, or
+ * greater than zero if the first argument is considered to be
+ * respectively less than, equal to, or greater than the second.
+ * </p>
+ * @return bool true on success or false on failure.
+ */
+#[LanguageLevelTypeAware(["8.2" => "true"], default: "bool")]
+function usort(array &$array, callable $callback) {}
+
+/**
+ * Sort multiple or multi-dimensional arrays
+ * @link https://php.net/manual/en/function.array-multisort.php
+ * @param array &$array <p>
+ * An array being sorted.
+ * </p>
+ * @param  &...$rest [optional] <p>
+ * More arrays, optionally followed by sort order and flags.
+ * Only elements corresponding to equivalent elements in previous arrays are compared.
+ * In other words, the sort is lexicographical.
+ * </p>
+ * @return bool true on success or false on failure.
+ */
+function array_multisort(&$array, &...$rest): bool {}
+
+/**
+ * Set the internal pointer of an array to its last element
+ * @link https://php.net/manual/en/function.end.php
+ * @param array|object &$array <p>
+ * The array. This array is passed by reference because it is modified by
+ * the function. This means you must pass it a real variable and not
+ * a function returning an array because only actual variables may be
+ * passed by reference.
+ * </p>
+ * @return mixed|false the value of the last element or false for empty array.
+ * @meta
+ */
+function end(object|array &$array): mixed {}
+
+/**
+ * Fetch a key from an array
+ * @link https://php.net/manual/en/function.key.php
+ * @param array|object $array <p>
+ * The array.
+ * </p>
+ * @return int|string|null The key function simply returns the
+ * key of the array element that's currently being pointed to by the
+ * internal pointer. It does not move the pointer in any way. If the
+ * internal pointer points beyond the end of the elements list or the array is
+ * empty, key returns null.
+ */
+#[Pure]
+function key(object|array $array): string|int|null {}
+
+/**
+ * Checks if a value exists in an array
+ * @link https://php.net/manual/en/function.in-array.php
+ * @param mixed $needle <p>
+ * The searched value.
+ * </p>
+ * <p>
+ * If needle is a string, the comparison is done
+ * in a case-sensitive manner.
+ * </p>
+ * @param array $haystack <p>
+ * The array.
+ * </p>
+ * @param bool $strict [optional] <p>
+ * If the third parameter strict is set to true
+ * then the in_array function will also check the
+ * types of the
+ * needle in the haystack.
+ * </p>
+ * @return bool true if needle is found in the array,
+ * false otherwise.
+ */
+#[Pure]
+function in_array(mixed $needle, array $haystack, bool $strict = false): bool {}
+
+/**
+ * Import variables into the current symbol table from an array
+ * @link https://php.net/manual/en/function.extract.php
+ * @param array &$array <p>
+ * Note that prefix is only required if
+ * extract_type is EXTR_PREFIX_SAME,
+ * EXTR_PREFIX_ALL, EXTR_PREFIX_INVALID
+ * or EXTR_PREFIX_IF_EXISTS. If
+ * the prefixed result is not a valid variable name, it is not
+ * imported into the symbol table. Prefixes are automatically separated from
+ * the array key by an underscore character.
+ * </p>
+ * @param int $flags [optional] <p>
+ * The way invalid/numeric keys and collisions are treated is determined
+ * by the extract_type.
+ * </p>
+ * @param string $prefix [optional] <p>
+ * Only overwrite the variable if it already exists in the symbol table.
+ * </p>
+ * @return int the number of variables successfully imported into the symbol
+ * table.
+ */
+function extract(
+    array &$array,
+    #[ExpectedValues(flags: [
+        EXTR_OVERWRITE,
+        EXTR_SKIP,
+        EXTR_PREFIX_SAME,
+        EXTR_PREFIX_ALL,
+        EXTR_PREFIX_INVALID,
+        EXTR_IF_EXISTS,
+        EXTR_PREFIX_IF_EXISTS,
+        EXTR_REFS
+    ])] int $flags = EXTR_OVERWRITE,
+    string $prefix = ""
+): int {}
+
+/**
+ * Create array containing variables and their values
+ * @link https://php.net/manual/en/function.compact.php
+ * @param mixed $var_name <p>
+ * compact takes a variable number of parameters.
+ * Each parameter can be either a string containing the name of the
+ * variable, or an array of variable names. The array can contain other
+ * arrays of variable names inside it; compact
+ * handles it recursively.
+ * </p>
+ * @param mixed ...$var_names
+ * @return array the output array with all the variables added to it.
+ */
+#[Pure]
+function compact(#[PhpStormStubsElementAvailable(from: '8.0')] $var_name, ...$var_names): array {}
+
+/**
+ * Fill an array with values
+ * @link https://php.net/manual/en/function.array-fill.php
+ * @param int $start_index <p>
+ * The first index of the returned array.
+ * Supports non-negative indexes only.
+ * </p>
+ * @param int $count <p>
+ * Number of elements to insert
+ * </p>
+ * @param mixed $value <p>
+ * Value to use for filling
+ * </p>
+ * @return array the filled array
+ */
+#[Pure]
+function array_fill(int $start_index, int $count, mixed $value): array {}
+
+/**
+ * Create an array containing a range of elements
+ * @link https://php.net/manual/en/function.range.php
+ * @param mixed $start <p>
+ * First value of the sequence.
+ * </p>
+ * @param mixed $end <p>
+ * The sequence is ended upon reaching the
+ * end value.
+ * </p>
+ * @param positive-int|float $step [optional] <p>
+ * If a step value is given, it will be used as the
+ * increment between elements in the sequence. step
+ * should be given as a positive number. If not specified,
+ * step will default to 1.
+ * </p>
+ * @return array an array of elements from start to
+ * end, inclusive.
+ */
+#[Pure]
+function range($start, $end, int|float $step = 1): array {}
+
+/**
+ * Merge one or more arrays
+ * @link https://php.net/manual/en/function.array-merge.php
+ * @param array ...$arrays <p>
+ * Variable list of arrays to merge.
+ * </p>
+ * @return array the resulting array.
+ * @meta
+ */
+#[Pure]
+function array_merge(
+    #[PhpStormStubsElementAvailable(from: '5.3', to: '7.3')] $array,
+    array ...$arrays
+): array {}
+
+/**
+ * Return all the keys or a subset of the keys of an array
+ * @link https://php.net/manual/en/function.array-keys.php
+ * @param array $array <p>
+ * An array containing keys to return.
+ * </p>
+ * @param mixed $filter_value [optional] <p>
+ * If specified, then only keys containing these values are returned.
+ * </p>
+ * @param bool $strict [optional] <p>
+ * Determines if strict comparison (===) should be used during the search.
+ * </p>
+ * @return int[]|string[] an array of all the keys in input.
+ * @psalm-return list<array-key>
+ */
+#[Pure]
+function array_keys(array $array, mixed $filter_value, bool $strict = false): array {}
+
+/**
+ * Applies the callback to the elements of the given arrays
+ * @link https://php.net/manual/en/function.array-map.php
+ * @param callable|null $callback <p>
+ * Callback function to run for each element in each array.
+ * </p>
+ * @param array $array <p>
+ * An array to run through the callback function.
+ * </p>
+ * @param array ...$arrays
+ * @return array an array containing all the elements of arr1
+ * after applying the callback function to each one.
+ * @meta
+ */
+function array_map(?callable $callback, array $array, array ...$arrays): array {}
+
+/**
+ * Filters elements of an array using a callback function
+ * @link https://php.net/manual/en/function.array-filter.php
+ * @param array $array <p>
+ * The array to iterate over
+ * </p>
+ * @param callable|null $callback [optional] <p>
+ * The callback function to use
+ * </p>
+ * <p>
+ * If no callback is supplied, all entries of
+ * input equal to false (see
+ * converting to
+ * boolean) will be removed.
+ * </p>
+ * @param int $mode [optional] <p>
+ * Flag determining what arguments are sent to <i>callback</i>:
+ * </p><ul>
+ * <li>
+ * <b>ARRAY_FILTER_USE_KEY</b> - pass key as the only argument
+ * to <i>callback</i> instead of the value</span>
+ * </li>
+ * <li>
+ * <b>ARRAY_FILTER_USE_BOTH</b> - pass both value and key as
+ * arguments to <i>callback</i> instead of the value</span>
+ * </li>
+ * </ul>
+ * @return array the filtered array.
+ * @meta
+ */
+function array_filter(array $array, ?callable $callback, int $mode = 0): array {}
+
+/**
+ * Iteratively reduce the array to a single value using a callback function
+ * @link https://php.net/manual/en/function.array-reduce.php
+ * @param array $array <p>
+ * The input array.
+ * </p>
+ * @param callable $callback <p>
+ * The callback function. Signature is <pre>callback ( mixed $carry , mixed $item ) : mixed</pre>
+ * <blockquote>mixed <var>$carry</var> <p>The return value of the previous iteration; on the first iteration it holds the value of <var>$initial</var>.</p></blockquote>
+ * <blockquote>mixed <var>$item</var> <p>Holds the current iteration value of the <var>$input</var></p></blockquote>
+ * </p>
+ * @param mixed $initial [optional] <p>
+ * If the optional initial is available, it will
+ * be used at the beginning of the process, or as a final result in case
+ * the array is empty.
+ * </p>
+ * @return mixed the resulting value.
+ * </p>
+ * <p>
+ * If the array is empty and initial is not passed,
+ * array_reduce returns null.
+ * </p>
+ * <br/>
+ * <p>
+ * Example use:
+ * <blockquote><pre>array_reduce(['2', '3', '4'], function($ax, $dx) { return $ax . ", {$dx}"; }, '1')  // Returns '1, 2, 3, 4'</pre></blockquote>
+ * <blockquote><pre>array_reduce(['2', '3', '4'], function($ax, $dx) { return $ax + (int)$dx; }, 1)  // Returns 10</pre></blockquote>
+ * <br/>
+ * </p>
+ * @meta
+ */
+function array_reduce(array $array, callable $callback, mixed $initial = null): mixed {}
+
+/**
+ * Checks if the given key or index exists in the array
+ * @link https://php.net/manual/en/function.array-key-exists.php
+ * @param int|string $key <p>
+ * Value to check.
+ * </p>
+ * @param array|ArrayObject $array <p>
+ * An array with keys to check.
+ * </p>
+ * @return bool true on success or false on failure.
+ */
+#[Pure]
+function array_key_exists($key, #[LanguageLevelTypeAware(["8.0" => "array"], default: "array|ArrayObject")] $array): bool {}
+
+/**
+ * Gets the first key of an array
+ *
+ * Get the first key of the given array without affecting the internal array pointer.
+ *
+ * @link https://secure.php.net/array_key_first
+ * @param array $array An array
+ * @return string|int|null Returns the first key of array if the array is not empty; NULL otherwise.
+ * @since 7.3
+ */
+#[Pure]
+function array_key_first(array $array): string|int|null {}
+
+/**
+ * Checks whether a given array is a list
+ *
+ * @param array $array The array being evaluated.
+ * @return bool Returns true if array is a list, false otherwise.
+ * @link https://www.php.net/manual/en/function.array-is-list.php
+ * @since 8.1
+ */
+#[Pure]
+function array_is_list(array $array): bool {}
+
+/**
+ * Call the callback given by the first parameter
+ * @link https://php.net/manual/en/function.call-user-func.php
+ * @param callable $callback <p>
+ * The function to be called. Class methods may also be invoked
+ * statically using this function by passing
+ * array($classname, $methodname) to this parameter.
+ * Additionally class methods of an object instance may be called by passing
+ * array($objectinstance, $methodname) to this parameter.
+ * </p>
+ * @param mixed ...$args [optional] <p>
+ * Zero or more parameters to be passed to the function.
+ * </p>
+ * @return mixed the function result, or false on error.
+ */
+function call_user_func(callable $callback, mixed ...$args): mixed {}
+
+/**
+ * Call a callback with an array of parameters
+ * @link https://php.net/manual/en/function.call-user-func-array.php
+ * @param callable $callback <p>
+ * The function to be called.
+ * </p>
+ * @param array $args <p>
+ * The parameters to be passed to the function, as an indexed array.
+ * </p>
+ * @return mixed the function result, or false on error.
+ */
+function call_user_func_array(callable $callback, array $args): mixed {}
+
+/**
+ * Register a function for execution on shutdown
+ * @link https://php.net/manual/en/function.register-shutdown-function.php
+ * @param callable $callback <p>
+ * The shutdown function to register.
+ * </p>
+ * <p>
+ * The shutdown functions are called as the part of the request so that
+ * it's possible to send the output from them. There is currently no way
+ * to process the data with output buffering functions in the shutdown
+ * function.
+ * </p>
+ * <p>
+ * Shutdown functions are called after closing all opened output buffers
+ * thus, for example, its output will not be compressed if zlib.output_compression is
+ * enabled.
+ * </p>
+ * @param mixed ...$args [optional] <p>
+ * It is possible to pass parameters to the shutdown function by passing
+ * additional parameters.
+ * </p>
+ * @return bool|null
+ */
+#[LanguageLevelTypeAware(["8.2" => "void"], default: "bool|null")]
+function register_shutdown_function(callable $callback, mixed ...$args): ?bool {}
+
+/**
+ * Prints human-readable information about a variable
+ * @link https://php.net/manual/en/function.print-r.php
+ * @param mixed $value <p>
+ * The expression to be printed.
+ * </p>
+ * @param bool $return [optional] <p>
+ * If you would like to capture the output of print_r,
+ * use the return parameter. If this parameter is set
+ * to true, print_r will return its output, instead of
+ * printing it (which it does by default).
+ * </p>
+ * @return string|true If given a string, integer or float,
+ * the value itself will be printed. If given an array, values
+ * will be presented in a format that shows keys and elements. Similar
+ * notation is used for objects.
+ */
+function print_r(mixed $value, bool $return = false): string|bool {}
+
+/**
+ * Dumps information about a variable
+ * @link https://php.net/manual/en/function.var-dump.php
+ * @param mixed $value <p>
+ * The variable you want to export.
+ * </p>
+ * @param mixed ...$values [optional]
+ * @return void
+ */
+function var_dump(mixed $value, mixed ...$values): void {}
+
+/**
+ * Outputs or returns a parsable string representation of a variable
+ * @link https://php.net/manual/en/function.var-export.php
+ * @param mixed $value <p>
+ * The variable you want to export.
+ * </p>
+ * @param bool $return [optional] <p>
+ * If used and set to true, var_export will return
+ * the variable representation instead of outputting it.
+ * </p>
+ * @return string|null the variable representation when the return
+ * parameter is used and evaluates to true. Otherwise, this function will
+ * return null.
+ */
+function var_export(mixed $value, bool $return = false): ?string {}
+
+/**
+ * Generates a storable representation of a value
+ * @link https://php.net/manual/en/function.serialize.php
+ * @param mixed $value <p>
+ * The value to be serialized. serialize
+ * handles all types, except the resource-type.
+ * You can even serialize arrays that contain
+ * references to itself. Circular references inside the array/object you
+ * are serializing will also be stored. Any other
+ * reference will be lost.
+ * </p>
+ * @return string a string containing a byte-stream representation of
+ * value that can be stored anywhere.
+ */
+function serialize(mixed $value): string {}
+
+/**
+ * Creates a PHP value from a stored representation
+ * @link https://php.net/manual/en/function.unserialize.php
+ * @param string $data <p>
+ * The serialized string.
+ * </p>
+ * @param array $options [optional]
+ * <p>Any options to be provided to unserialize(), as an associative array.</p>
+ * @return mixed The converted value is returned, and can be a boolean,
+ * integer, float, string,
+ * array or object.
+ * </p>
+ * <p>
+ * In case the passed string is not unserializeable, false is returned and
+ * E_NOTICE is issued.
+ */
+function unserialize(string $data, #[PhpStormStubsElementAvailable(from: '7.0')] array $options = []): mixed {}
+
+/**
+ * Returns the JSON representation of a value
+ * @link https://php.net/manual/en/function.json-encode.php
+ * @param mixed $value <p>
+ * The value being encoded. Can be any type except
+ * a resource.
+ * </p>
+ * @param int $flags [optional] <p>
+ * Bitmask consisting of <b>JSON_HEX_QUOT</b>,
+ * <b>JSON_HEX_TAG</b>,
+ * <b>JSON_HEX_AMP</b>,
+ * <b>JSON_HEX_APOS</b>,
+ * <b>JSON_NUMERIC_CHECK</b>,
+ * <b>JSON_PRETTY_PRINT</b>,
+ * <b>JSON_UNESCAPED_SLASHES</b>,
+ * <b>JSON_FORCE_OBJECT</b>,
+ * <b>JSON_UNESCAPED_UNICODE</b>.
+ * <b>JSON_THROW_ON_ERROR</b> The behaviour of these
+ * constants is described on
+ * the JSON constants page.
+ * </p>
+ * @param int $depth [optional] <p>
+ * Set the maximum depth. Must be greater than zero.
+ * </p>
+ * @return string|false a JSON encoded string on success or <b>FALSE</b> on failure.
+ */
+function json_encode(mixed $value, int $flags = 0, int $depth = 512): string|false {}
+
+/**
+ * Decodes a JSON string
+ * @link https://php.net/manual/en/function.json-decode.php
+ * @param string $json <p>
+ * The <i>json</i> string being decoded.
+ * </p>
+ * @param bool|null $associative <p>
+ * When <b>TRUE</b>, returned objects will be converted into
+ * associative arrays.
+ * </p>
+ * @param int $depth [optional] <p>
+ * User specified recursion depth.
+ * </p>
+ * @param int $flags [optional] <p>
+ * Bitmask of JSON decode options.
+ * </p>
+ * @return mixed the value encoded in <i>json</i> in appropriate
+ * PHP type. Values true, false and
+ * null (case-insensitive) are returned as <b>TRUE</b>, <b>FALSE</b>
+ * and <b>NULL</b> respectively. <b>NULL</b> is returned if the
+ * <i>json</i> cannot be decoded or if the encoded
+ * data is deeper than the recursion limit.
+ */
+function json_decode(string $json, ?bool $associative = null, int $depth = 512, int $flags = 0): mixed {}
+
+/**
+ * Gets information about a file using an open file pointer
+ * @link https://php.net/manual/en/function.fstat.php
+ * @param resource $stream &fs.file.pointer;
+ * @return array|false an array with the statistics of the file; the format of the array
+ * is described in detail on the stat manual page.
+ */
+#[Pure(true)]
+#[ArrayShape([
+    "dev" => "int",
+    "ino" => "int",
+    "mode" => "int",
+    "nlink" => "int",
+    "uid" => "int",
+    "gid" => "int",
+    "rdev" => "int",
+    "size" => "int",
+    "atime" => "int",
+    "mtime" => "int",
+    "ctime" => "int",
+    "blksize" => "int",
+    "blocks" => "int"
+])]
+function fstat($stream): array|false {}
+
+/**
+ * Opens file or URL
+ * @link https://php.net/manual/en/function.fopen.php
+ * @param string $filename <p>
+ * If filename is of the form "scheme://...", it
+ * is assumed to be a URL and PHP will search for a protocol handler
+ * (also known as a wrapper) for that scheme.
+ * </p>
+ * @param string $mode <p>
+ * The mode parameter specifies the type of access
+ * you require to the stream.
+ * </p>
+ * @param bool $use_include_path [optional] <p>
+ * The optional third use_include_path parameter
+ * can be set to '1' or true if you want to search for the file in the
+ * include_path, too.
+ * </p>
+ * @param resource $context [optional]
+ * @return resource|false a file pointer resource on success, or false on error.
+ */
+function fopen(string $filename, string $mode, bool $use_include_path = false, $context) {}
+
+/**
+ * Binary-safe file read
+ * @link https://php.net/manual/en/function.fread.php
+ * @param resource $stream &fs.file.pointer;
+ * @param int $length <p>
+ * Up to length number of bytes read.
+ * </p>
+ * @return string|false the read string or false on failure.
+ */
+function fread($stream, int $length): string|false {}
+
+/**
+ * Binary-safe file write
+ * @link https://php.net/manual/en/function.fwrite.php
+ * @param resource $stream &fs.file.pointer;
+ * @param string $data <p>
+ * The string that is to be written.
+ * </p>
+ * @param int|null $length [optional] <p>
+ * If the length argument is given, writing will
+ * stop after length bytes have been written or
+ * the end of string is reached, whichever comes
+ * first.
+ * </p>
+ * @return int|false the number of bytes written, or <b>FALSE</b> on error.
+ */
+function fwrite($stream, string $data, ?int $length): int|false {}
+
+/**
+ * Closes an open file pointer
+ * @link https://php.net/manual/en/function.fclose.php
+ * @param resource $stream <p>
+ * The file pointer must be valid, and must point to a file successfully
+ * opened by fopen or fsockopen.
+ * </p>
+ * @return bool true on success or false on failure.
+ */
+function fclose($stream): bool {}
+
+/**
+ * Reads entire file into a string
+ * @link https://php.net/manual/en/function.file-get-contents.php
+ * @param string $filename <p>
+ * Name of the file to read.
+ * </p>
+ * @param bool $use_include_path [optional] <p>
+ * Note: As of PHP 5 the FILE_USE_INCLUDE_PATH constant can be
+ * used to trigger include path search.
+ * </p>
+ * @param resource $context [optional] <p>
+ * A valid context resource created with
+ * stream_context_create. If you don't need to use a
+ * custom context, you can skip this parameter by null.
+ * </p>
+ * @param int $offset [optional] <p>
+ * The offset where the reading starts.
+ * </p>
+ * @param int|null $length [optional] <p>
+ * Maximum length of data read. The default is to read until end
+ * of file is reached.
+ * </p>
+ * @return string|false The function returns the read data or false on failure.
+ */
+function file_get_contents(string $filename, bool $use_include_path = false, $context = null, int $offset = 0, ?int $length = null): string|false {}
+
+/**
+ * Write a string to a file
+ * @link https://php.net/manual/en/function.file-put-contents.php
+ * @param string $filename <p>
+ * Path to the file where to write the data.
+ * </p>
+ * @param mixed $data <p>
+ * The data to write. Can be either a string, an
+ * array or a stream resource.
+ * </p>
+ * @param int $flags [optional] <p>
+ * The value of flags can be any combination of
+ * the following flags (with some restrictions), joined with the binary OR
+ * (|) operator.
+ * </p>
+ * @param resource $context [optional] <p>
+ * A valid context resource created with
+ * stream_context_create.
+ * </p>
+ * @return int|false The function returns the number of bytes that were written to the file, or
+ * false on failure.
+ */
+function file_put_contents(string $filename, mixed $data, int $flags = 0, $context = null): int|false {}
+
+/**
+ * Sleep for a given number of seconds and nanoseconds
+ * @link https://php.net/manual/en/function.time-nanosleep.php
+ * @param int $seconds <p>
+ * Must be a positive integer.
+ * </p>
+ * @param int $nanoseconds <p>
+ * Must be a positive integer less than 1 billion.
+ * </p>
+ * @return bool|array true on success or false on failure.
+ * </p>
+ * <p>
+ * If the delay was interrupted by a signal, an associative array will be
+ * returned with the components:
+ * seconds - number of seconds remaining in
+ * the delay
+ * nanoseconds - number of nanoseconds
+ * remaining in the delay
+ */
+#[ArrayShape(["seconds" => "int", "nanoseconds" => "int"])]
+function time_nanosleep(int $seconds, int $nanoseconds): array|bool {}
+
+/**
+ * Generate a cryptographically secure random integer
+ * @link https://php.net/manual/en/function.random-int.php
+ * @param int $min The lowest value to be returned, which must be PHP_INT_MIN or higher.
+ * @param int $max The highest value to be returned, which must be less than or equal to PHP_INT_MAX.
+ * @return int Returns a cryptographically secure random integer in the range min to max, inclusive.
+ * @throws Exception if it was not possible to gather sufficient entropy.
+ * @since 7.0
+ */
+function random_int(int $min, int $max): int {}
+
+/**
+ * Generate a random integer
+ * @link https://php.net/manual/en/function.rand.php
+ * @param int $min [optional]
+ * @param int $max [optional]
+ * @return int A pseudo random value between min
+ * (or 0) and max (or getrandmax, inclusive).
+ */
+function rand(int $min = null, int $max = null): int {}
+
+/**
+ * Find whether the type of a variable is integer
+ * @link https://php.net/manual/en/function.is-int.php
+ * @param mixed $value <p>
+ * The variable being evaluated.
+ * </p>
+ * @return bool true if var is an integer,
+ * false otherwise.
+ */
+#[Pure]
+function is_int(mixed $value): bool {}
+
+/**
+ * Verify that the contents of a variable can be called as a function
+ * @link https://php.net/manual/en/function.is-callable.php
+ * @param mixed $value <p>
+ * Can be either the name of a function stored in a string variable, or
+ * an object and the name of a method within the object, like this:
+ * array($SomeObject, 'MethodName')
+ * </p>
+ * @param bool $syntax_only [optional] <p>
+ * If set to true the function only verifies that
+ * name might be a function or method. It will only
+ * reject simple variables that are not strings, or an array that does
+ * not have a valid structure to be used as a callback. The valid ones
+ * are supposed to have only 2 entries, the first of which is an object
+ * or a string, and the second a string.
+ * </p>
+ * @param string &$callable_name [optional] <p>
+ * Receives the "callable name". In the example below it is
+ * "someClass::someMethod". Note, however, that despite the
+ * implication that someClass::SomeMethod() is a callable static method,
+ * this is not the case.
+ * </p>
+ * @return bool true if name is callable, false
+ * otherwise.
+ */
+function is_callable(mixed $value, bool $syntax_only = false, &$callable_name = null): bool {}
+
+/**
+ * Verify that the contents of a variable is a countable value
+ * @link https://secure.php.net/is_countable
+ *
+ * @param mixed $value The value to check
+ * @return bool Returns <b>TRUE</b> if var is countable, <b>FALSE</b> otherwise.
+ * @since 7.3
+ */
+#[Pure]
+function is_countable(mixed $value): bool {}
+
+/**
+ * Set the type of a variable
+ * @link https://php.net/manual/en/function.settype.php
+ * @param mixed &$var <p>
+ * The variable being converted.
+ * </p>
+ * @param string $type <p>
+ * Possibles values of type are:
+ * "boolean" (or, since PHP 4.2.0, "bool")
+ * "integer" (or, since PHP 4.2.0, "int")
+ * "float" (only possible since PHP 4.2.0, for older versions use the
+ * deprecated variant "double")
+ * "string"
+ * "array"
+ * "object"
+ * "null" (since PHP 4.2.0)
+ * </p>
+ * @return bool true on success or false on failure.
+ */
+function settype(mixed &$var, #[ExpectedValues(values: ["bool", "boolean", "int", "integer", "float", "double", "string", "array", "object", "null"])] string $type): bool {}
+
+/**
+ * Get the boolean value of a variable
+ * @param mixed $value the scalar value being converted to a boolean.
+ * @return bool The boolean value of var.
+ * @since 5.5
+ */
+#[Pure]
+function boolval(mixed $value): bool {}
+
+/**
+ * Returns directory name component of path
+ * @link https://php.net/manual/en/function.dirname.php
+ * @param string $path <p>
+ * A path.
+ * </p>
+ * <p>
+ * On Windows, both slash (/) and backslash
+ * (\) are used as directory separator character. In
+ * other environments, it is the forward slash (/).
+ * </p>
+ * @param int $levels <p>
+ * The number of parent directories to go up.
+ * This must be an integer greater than 0.
+ * </p>
+ * @return string the name of the directory. If there are no slashes in
+ * path, a dot ('.') is returned,
+ * indicating the current directory. Otherwise, the returned string is
+ * path with any trailing
+ * /component removed.
+ */
+#[Pure]
+function dirname(string $path, #[PhpStormStubsElementAvailable(from: '7.0')] int $levels = 1): string {}
+
+/**
+ * Returns information about a file path
+ * @link https://php.net/manual/en/function.pathinfo.php
+ * @param string $path <p>
+ * The path being checked.
+ * </p>
+ * @param int $flags [optional] <p>
+ * You can specify which elements are returned with optional parameter
+ * options. It composes from
+ * PATHINFO_DIRNAME,
+ * PATHINFO_BASENAME,
+ * PATHINFO_EXTENSION and
+ * PATHINFO_FILENAME. It
+ * defaults to return all elements.
+ * </p>
+ * @return string|array The following associative array elements are returned:
+ * dirname, basename,
+ * extension (if any), and filename.
+ * </p>
+ * <p>
+ * If options is used, this function will return a
+ * string if not all elements are requested.
+ */
+#[Pure(true)]
+#[ArrayShape(['dirname' => 'string', 'basename' => 'string', 'extension' => 'string', 'filename' => 'string'])]
+function pathinfo(string $path, #[ExpectedValues(flags: [
+    PATHINFO_DIRNAME,
+    PATHINFO_BASENAME,
+    PATHINFO_EXTENSION,
+    PATHINFO_FILENAME
+])] int $flags = PATHINFO_ALL): array|string {}
+
+/**
+ * Returns a parsed version of the given url
+ * @link https://php.net/manual/en/function.parse-url.php
+ * @param string $url <p>
+ * The URL to parse. Invalid characters are replaced by
+ * _.
+ * </p>
+ * @param int $component [optional] <p>
+ * Specify one of PHP_URL_SCHEME,
+ * PHP_URL_HOST, PHP_URL_PORT,
+ * PHP_URL_USER, PHP_URL_PASS,
+ * PHP_URL_PATH, PHP_URL_QUERY
+ * or PHP_URL_FRAGMENT to retrieve just a specific
+ * URL component as a string.
+ * </p>
+ * @return int|string|array|null|false On seriously malformed URLs, parse_url may return false.
+ */
+#[Pure]
+#[ArrayShape([
+    "scheme" => "string",
+    "host" => "string",
+    "port" => "int",
+    "user" => "string",
+    "pass" => "string",
+    "query" => "string",
+    "path" => "string",
+    "fragment" => "string",
+])]
+function parse_url(string $url, int $component = -1): array|string|int|false|null {}
+
+/**
+ * Alias of <b>stream_set_write_buffer</b>
+ * <p>Sets the buffering for write operations on the given stream to buffer bytes.
+ * Output using fwrite() is normally buffered at 8K.
+ * This means that if there are two processes wanting to write to the same output stream (a file),
+ * each is paused after 8K of data to allow the other to write.
+ * @link https://php.net/manual/en/function.set-file-buffer.php
+ * @param resource $stream The file pointer.
+ * @param int $size The number of bytes to buffer. If buffer is 0 then write operations are unbuffered.
+ * This ensures that all writes with fwrite() are completed before other processes are allowed to write to that output stream.
+ * @return int
+ */
+function set_file_buffer($stream, int $size): int {}
+
+/**
+ * Sets the current active configuration setting of magic_quotes_runtime
+ * @link https://php.net/manual/en/function.set-magic-quotes-runtime.php
+ * @param bool $new_setting <p>
+ * false for off, true for on.
+ * </p>
+ * @return bool true on success or false on failure.
+ * @removed 7.0
+ */
+#[Deprecated(reason: "This function has been DEPRECATED as of PHP 5.4.0. Raises an E_CORE_ERROR", since: "5.3")]
+function set_magic_quotes_runtime($new_setting) {}
+
+/**
+ * Alias of <b>set_magic_quotes_runtime</b>
+ * @link https://php.net/manual/en/function.magic-quotes-runtime.php
+ * @param bool $new_setting
+ * @return bool
+ * @removed 7.0
+ */
+#[Deprecated(since: '5.3')]
+function magic_quotes_runtime($new_setting) {}
+
+/**
+ * Get the current active configuration setting of magic_quotes_runtime
+ * @link https://php.net/manual/en/function.get-magic-quotes-runtime.php
+ * @return bool 0 if magic quotes runtime is off, 1 otherwise.
+ */
+#[Deprecated(since: '7.4')]
+function get_magic_quotes_runtime(): bool {}
+
+/**
+ * Return the current Unix timestamp with microseconds
+ * @link https://php.net/manual/en/function.microtime.php
+ * @param bool $as_float [optional] <p>
+ * When called without the optional argument, this function returns the string
+ * "msec sec" where sec is the current time measured in the number of
+ * seconds since the Unix Epoch (0:00:00 January 1, 1970 GMT), and
+ * msec is the microseconds part.
+ * Both portions of the string are returned in units of seconds.
+ * </p>
+ * <p>
+ * If the optional get_as_float is set to
+ * true then a float (in seconds) is returned.
+ * </p>
+ * @return string|float
+ */
+function microtime(#[TypeAware(['8.0' => 'bool'], default: '')] $as_float = false): string|float {}
+
+/**
+ * Get current time
+ * @link https://php.net/manual/en/function.gettimeofday.php
+ * @param bool $as_float [optional] <p>
+ * When set to true, a float instead of an array is returned.
+ * </p>
+ * @return int[]|float By default an array is returned. If return_float
+ * is set, then a float is returned.
+ */
+#[ArrayShape(["sec" => "int", "usec" => "int", "minuteswest" => "int", "dsttime" => "int"])]
+function gettimeofday(bool $as_float = false): array|float {}
+
+/**
+ * Gets the current resource usages
+ * @link https://php.net/manual/en/function.getrusage.php
+ * @param int $mode <p>
+ * If who is 1, getrusage will be called with
+ * RUSAGE_CHILDREN.
+ * </p>
+ * @return array|false an associative array containing the data returned from the system
+ * call. All entries are accessible by using their documented field names.
+ */
+function getrusage(int $mode = 0): array|false {}
+
+/**
+ * Generate a unique ID
+ * @link https://php.net/manual/en/function.uniqid.php
+ * @param string $prefix [optional] <p>
+ * Can be useful, for instance, if you generate identifiers
+ * simultaneously on several hosts that might happen to generate the
+ * identifier at the same microsecond.
+ * </p>
+ * <p>
+ * With an empty prefix, the returned string will
+ * be 13 characters long. If more_entropy is
+ * true, it will be 23 characters.
+ * </p>
+ * @param bool $more_entropy [optional] <p>
+ * If set to true, uniqid will add additional
+ * entropy (using the combined linear congruential generator) at the end
+ * of the return value, which should make the results more unique.
+ * </p>
+ * @return string the unique identifier, as a string.
+ */
+function uniqid(string $prefix = "", bool $more_entropy = false): string {}
+
+/**
+ * Halts the execution of the compiler. This can be useful to embed data in PHP scripts, like the installation files.
+ * Byte position of the data start can be determined by the __COMPILER_HALT_OFFSET__ constant
+ * which is defined only if there is a __halt_compiler() presented in the file.
+ * <p> Note: __halt_compiler() can only be used from the outermost scope.
+ * @link https://php.net/manual/en/function.halt-compiler.php
+ * @return void
+ */
+function PS_UNRESERVE_PREFIX___halt_compiler() {}
+
+/**
+ * (PHP 5.1)<br/>
+ * Return an array with elements in reverse order
+ * @link https://php.net/manual/en/function.array-reverse.php
+ * @param array $array <p>
+ * The input array.
+ * </p>
+ * @param bool $preserve_keys [optional] <p>
+ * If set to true keys are preserved.
+ * </p>
+ * @return array the reversed array.
+ * @meta
+ */
+#[Pure]
+function array_reverse(array $array, bool $preserve_keys = false): array {}
+
+/**
+ * Output a message and terminate the current script
+ * @link https://php.net/manual/en/function.exit.php
+ * @param int|string $status [optional] If status is a string, this function prints the status just before exiting.
+ * If status is an integer, that value will be used as the exit status and not printed.
+ * @return never
+ */
+function PS_UNRESERVE_PREFIX_exit($status = "") {}
+
+/**
+ * Equivalent to exit
+ * @link https://php.net/manual/en/function.die.php
+ * @param int|string $status [optional]
+ * @return never
+ */
+function PS_UNRESERVE_PREFIX_die($status = "") {}
+
+/**
+ * Determine whether a variable is considered to be empty.
+ * @link https://php.net/manual/en/function.empty.php
+ * @param mixed $var Variable to be checked
+ * @return bool false if var has a non-empty and non-zero value.
+ */
+function PS_UNRESERVE_PREFIX_empty($var) {}
+
+/**
+ * Determine if a variable is set and is not NULL.
+ * @link https://php.net/manual/en/function.isset.php
+ * @param mixed $var The variable to be checked.
+ * @param mixed ...$rest [optional] Another variable ...
+ * @return bool true if var exists and has value other than null, false otherwise.
+ */
+function PS_UNRESERVE_PREFIX_isset($var, ...$rest) {}
+
+/**
+ * Assigns a list of variables in one operation.
+ * @link https://php.net/manual/en/function.list.php
+ * @param mixed $var A variable.
+ * @param mixed ...$vars [optional] Another variable ...
+ * @return array the assigned array.
+ */
+function PS_UNRESERVE_PREFIX_list($var, ...$vars) {}
+
+/**
+ * Creates an array.
+ * @link https://php.net/manual/en/function.array.php
+ * @param mixed ...$_ [optional] <p>
+ * Syntax "index => values", separated by commas, define index and values.
+ * index may be of type string or integer. When index is omitted, an integer index is automatically generated,
+ * starting at 0. If index is an integer, next generated index will be the biggest integer index + 1.
+ * Note that when two identical index are defined, the last overwrite the first.
+ * </p>
+ * <p>
+ * Having a trailing comma after the last defined array entry, while unusual, is a valid syntax.
+ * </p>
+ * @return array an array of the parameters. The parameters can be given an index with the => operator.
+ */
+function PS_UNRESERVE_PREFIX_array(...$_) {}
+
+/**
+ * The eval language construct.
+ * @link https://php.net/manual/en/function.eval.php
+ * @param string $code <p>
+ * Valid PHP code to be evaluated.
+ * </p>
+ * @return mixed NULL unless return is called in the evaluated code.
+ */
+function PS_UNRESERVE_PREFIX_eval($code) {}
+
+define('CONNECTION_ABORTED', 1);
+define('CONNECTION_NORMAL', 0);
+define('CONNECTION_TIMEOUT', 2);
+define('INI_USER', 1);
+define('INI_PERDIR', 2);
+define('INI_SYSTEM', 4);
+define('INI_ALL', 7);
+
+/**
+ * Normal INI scanner mode (since PHP 5.3).
+ * @link https://php.net/manual/en/filesystem.constants.php
+ */
+define('INI_SCANNER_NORMAL', 0);
+
+/**
+ * Raw INI scanner mode (since PHP 5.3).
+ * @link https://php.net/manual/en/filesystem.constants.php
+ */
+define('INI_SCANNER_RAW', 1);
+
+/**
+ * Typed INI scanner mode (since PHP 5.6.1).
+ * @link https://php.net/manual/en/filesystem.constants.php
+ */
+define('INI_SCANNER_TYPED', 2);
+
+define('PHP_URL_SCHEME', 0);
+define('PHP_URL_HOST', 1);
+define('PHP_URL_PORT', 2);
+define('PHP_URL_USER', 3);
+define('PHP_URL_PASS', 4);
+define('PHP_URL_PATH', 5);
+define('PHP_URL_QUERY', 6);
+define('PHP_URL_FRAGMENT', 7);
+define('PHP_QUERY_RFC1738', 1);
+define('PHP_QUERY_RFC3986', 2);
+define('M_E', 2.718281828459045);
+define('M_LOG2E', 1.4426950408889634074);
+define('M_LOG10E', 0.43429448190325182765);
+define('M_LN2', 0.69314718055994530942);
+define('M_LN10', 2.30258509299404568402);
+define('M_PI', 3.14159265358979323846);
+define('M_PI_2', 1.57079632679489661923);
+define('M_PI_4', 0.78539816339744830962);
+define('M_1_PI', 0.31830988618379067154);
+define('M_2_PI', 0.63661977236758134308);
+define('M_SQRTPI', 1.772453850905516);
+define('M_2_SQRTPI', 1.12837916709551257390);
+define('M_LNPI', 1.1447298858494002);
+define('M_EULER', 0.5772156649015329);
+define('M_SQRT2', 1.41421356237309504880);
+define('M_SQRT1_2', 0.70710678118654752440);
+define('M_SQRT3', 1.7320508075688772);
+define('INF', (float)"INF");
+define('NAN', (float)"NAN");
+define('PHP_ROUND_HALF_UP', 1);
+define('PHP_ROUND_HALF_DOWN', 2);
+define('PHP_ROUND_HALF_EVEN', 3);
+define('PHP_ROUND_HALF_ODD', 4);
+define('MT_RAND_MT19937', 0);
+define('MT_RAND_PHP', 1);
+define('INFO_GENERAL', 1);
+define('INFO_CREDITS', 2);
+define('INFO_CONFIGURATION', 4);
+define('INFO_MODULES', 8);
+define('INFO_ENVIRONMENT', 16);
+define('INFO_VARIABLES', 32);
+define('INFO_LICENSE', 64);
+define('INFO_ALL', 4294967295);
+define('CREDITS_GROUP', 1);
+define('CREDITS_GENERAL', 2);
+define('CREDITS_SAPI', 4);
+define('CREDITS_MODULES', 8);
+define('CREDITS_DOCS', 16);
+define('CREDITS_FULLPAGE', 32);
+define('CREDITS_QA', 64);
+define('CREDITS_ALL', 4294967295);
+define('HTML_SPECIALCHARS', 0);
+define('HTML_ENTITIES', 1);
+define('ENT_COMPAT', 2);
+define('ENT_QUOTES', 3);
+define('ENT_NOQUOTES', 0);
+define('ENT_IGNORE', 4);
+define('ENT_SUBSTITUTE', 8);
+define('ENT_DISALLOWED', 128);
+define('ENT_HTML401', 0);
+define('ENT_XML1', 16);
+define('ENT_XHTML', 32);
+define('ENT_HTML5', 48);
+define('STR_PAD_LEFT', 0);
+define('STR_PAD_RIGHT', 1);
+define('STR_PAD_BOTH', 2);
+define('PATHINFO_DIRNAME', 1);
+define('PATHINFO_BASENAME', 2);
+define('PATHINFO_EXTENSION', 4);
+define('PATHINFO_FILENAME', 8);
+define('PATHINFO_ALL', 15);
+define('CHAR_MAX', 127);
+define('LC_CTYPE', 0);
+define('LC_NUMERIC', 1);
+define('LC_TIME', 2);
+define('LC_COLLATE', 3);
+define('LC_MONETARY', 4);
+define('LC_MESSAGES', 5);
+define('LC_ALL', 6);
+define('SEEK_SET', 0);
+define('SEEK_CUR', 1);
+define('SEEK_END', 2);
+define('LOCK_SH', 1);
+define('LOCK_EX', 2);
+define('LOCK_UN', 3);
+define('LOCK_NB', 4);
+define('EXTR_OVERWRITE', 0);
+define('EXTR_SKIP', 1);
+define('EXTR_PREFIX_SAME', 2);
+define('EXTR_PREFIX_ALL', 3);
+define('EXTR_PREFIX_INVALID', 4);
+define('EXTR_PREFIX_IF_EXISTS', 5);
+define('EXTR_IF_EXISTS', 6);
+define('EXTR_REFS', 256);
+define('SORT_ASC', 4);
+define('SORT_DESC', 3);
+define('SORT_REGULAR', 0);
+define('SORT_NUMERIC', 1);
+define('SORT_STRING', 2);
+define('SORT_LOCALE_STRING', 5);
+define('SORT_NATURAL', 6);
+define('SORT_FLAG_CASE', 8);
+define('CASE_LOWER', 0);
+define('CASE_UPPER', 1);
+define('COUNT_NORMAL', 0);
+define('COUNT_RECURSIVE', 1);
+define('ARRAY_FILTER_USE_BOTH', 1);
+define('ARRAY_FILTER_USE_KEY', 2);
+define('ASSERT_ACTIVE', 1);
+define('ASSERT_CALLBACK', 2);
+define('ASSERT_BAIL', 3);
+define('ASSERT_WARNING', 4);
+define('ASSERT_QUIET_EVAL', 5);
+define('ASSERT_EXCEPTION', 5);
+define('STREAM_USE_PATH', 1);
+define('STREAM_IGNORE_URL', 2);
+define('STREAM_REPORT_ERRORS', 8);
+define('STREAM_MUST_SEEK', 16);
+define('FILE_USE_INCLUDE_PATH', 1);
+define('FILE_IGNORE_NEW_LINES', 2);
+define('FILE_SKIP_EMPTY_LINES', 4);
+define('FILE_APPEND', 8);
+define('FILE_NO_DEFAULT_CONTEXT', 16);
+define('FILE_TEXT', 0);
+define('FILE_BINARY', 0);
+define('FNM_NOESCAPE', 2);
+define('FNM_PATHNAME', 1);
+define('FNM_PERIOD', 4);
+define('FNM_CASEFOLD', 16);
+define('PSFS_PASS_ON', 2);
+define('PSFS_FEED_ME', 1);
+define('PSFS_ERR_FATAL', 0);
+define('PSFS_FLAG_NORMAL', 0);
+define('PSFS_FLAG_FLUSH_INC', 1);
+define('PSFS_FLAG_FLUSH_CLOSE', 2);
+define('PASSWORD_DEFAULT', "2y");
+define('PASSWORD_BCRYPT', "2y");
+define('PASSWORD_BCRYPT_DEFAULT_COST', 10);
+define('PASSWORD_ARGON2I', 'argon2i');
+define('PASSWORD_ARGON2ID', 'argon2id');
+define('PASSWORD_ARGON2_DEFAULT_MEMORY_COST', 65536);
+define('PASSWORD_ARGON2_DEFAULT_TIME_COST', 4);
+define('PASSWORD_ARGON2_DEFAULT_THREADS', 1);
+define('DIRECTORY_SEPARATOR', "/");
+define('PATH_SEPARATOR', ":");
+define('SCANDIR_SORT_ASCENDING', 0);
+define('SCANDIR_SORT_DESCENDING', 1);
+define('SCANDIR_SORT_NONE', 2);
+define('GLOB_BRACE', 1024);
+define('GLOB_MARK', 2);
+define('GLOB_NOSORT', 4);
+define('GLOB_NOCHECK', 16);
+define('GLOB_NOESCAPE', 64);
+define('GLOB_ERR', 1);
+define('GLOB_ONLYDIR', 1073741824);
+define('GLOB_AVAILABLE_FLAGS', 1073741911);
+
+// End of standard v.8.2
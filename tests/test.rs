@@ -5,11 +5,33 @@ use std::path::PathBuf;
 
 use pretty_assertions::assert_str_eq;
 
+use php_parser_rs::downcast::downcast;
+use php_parser_rs::lexer::Lexer;
+use php_parser_rs::node::Node;
+use php_parser_rs::parser::ast::compare::diff_ignoring_spans;
+use php_parser_rs::parser::ast::debug_tree;
+use php_parser_rs::parser::ast::node_at_offset;
+use php_parser_rs::parser::ast::parent_map;
+use php_parser_rs::parser::ast::to_php_parser_json;
+use php_parser_rs::parser::ast::Expression;
+use php_parser_rs::parser::ast::Program;
+use php_parser_rs::parser::ast::SpanRange;
+use php_parser_rs::parser::ast::Spanned;
+use php_parser_rs::parser::ast::Statement;
+use php_parser_rs::parser::parse_lossless;
+use php_parser_rs::printer::print;
+use php_parser_rs::printer::print_exact;
+use php_parser_rs::source::SourceFile;
+
 struct TestFixture {
     fixture: String,
     code_file: PathBuf,
     ast_file: PathBuf,
     error_file: PathBuf,
+    php_parser_file: PathBuf,
+    tree_file: PathBuf,
+    json_file: PathBuf,
+    offsets_file: PathBuf,
 }
 
 struct ExpectedTestResult {
@@ -24,6 +46,10 @@ impl TestFixture {
             code_file: entry.join("code.php"),
             ast_file: entry.join("ast.txt"),
             error_file: entry.join("error.txt"),
+            php_parser_file: entry.join("php-parser.json"),
+            tree_file: entry.join("tree.txt"),
+            json_file: entry.join("ast.json"),
+            offsets_file: entry.join("offsets.txt"),
         }
     }
 
@@ -87,10 +113,46 @@ fn test_fixtures() -> io::Result<()> {
     Ok(())
 }
 
+// The stubs are declarations of PHP's functions, classes and constants in the
+// format used by IDEs, with empty bodies, long docblocks and attributes. They
+// are too large for an `ast.txt`, so they are only checked to parse without
+// errors and to print back to the same AST.
+#[test]
+fn test_stubs() -> io::Result<()> {
+    let manifest = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    let mut entries = read_dir(manifest.join("tests/stubs"))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|entry| {
+            entry
+                .extension()
+                .is_some_and(|extension| extension == "php")
+        })
+        .collect::<Vec<PathBuf>>();
+
+    entries.sort();
+
+    for entry in entries {
+        let stub = entry.to_string_lossy().to_string();
+        let code = std::fs::read(&entry)?;
+        let ast = php_parser_rs::parse(&code).unwrap_or_else(|error| {
+            panic!("failed to parse stub `{}`: {}", stub, error);
+        });
+
+        assert_prints(&stub, &ast);
+        assert_prints_exactly(&stub, &code);
+    }
+
+    Ok(())
+}
+
 fn run_test(test_fixture: &TestFixture) -> io::Result<()> {
     let code = test_fixture.code();
     let expected = test_fixture.expected();
 
+    assert_prints_exactly(&test_fixture.fixture, code.as_bytes());
+
     if !expected.ast.is_empty() {
         let ast = php_parser_rs::parse(&code).unwrap();
         assert_str_eq!(
@@ -99,6 +161,94 @@ fn run_test(test_fixture: &TestFixture) -> io::Result<()> {
             "ast mismatch for fixture `{}`",
             test_fixture.fixture
         );
+
+        let json = serde_json::to_string(&ast).unwrap();
+        let deserialized: Program = serde_json::from_str(&json).unwrap_or_else(|error| {
+            panic!(
+                "failed to deserialize the ast of fixture `{}`: {}",
+                test_fixture.fixture, error
+            )
+        });
+
+        assert!(
+            deserialized == ast,
+            "serde round trip mismatch for fixture `{}`:\n{}",
+            test_fixture.fixture,
+            diff_ignoring_spans(&ast, &deserialized).unwrap_or_else(|| "spans differ".into())
+        );
+
+        assert_prints(&test_fixture.fixture, &ast);
+        assert_spans(&test_fixture.fixture, code.as_bytes(), &ast);
+    }
+
+    // `php-parser.json` is dumped by php-parser itself, with
+    // `tests/php-parser/dump.php`. The fields of its objects are compared
+    // regardless of their order, which has no meaning in JSON.
+    if test_fixture.php_parser_file.exists() {
+        let ast = php_parser_rs::parse(&code).unwrap();
+        let expected: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&test_fixture.php_parser_file)?)?;
+        let json = to_php_parser_json(&ast);
+
+        if expected != json {
+            assert_str_eq!(
+                serde_json::to_string_pretty(&expected)?,
+                serde_json::to_string_pretty(&json)?,
+                "php-parser json mismatch for fixture `{}`",
+                test_fixture.fixture
+            );
+        }
+    }
+
+    if test_fixture.tree_file.exists() {
+        let ast = php_parser_rs::parse(&code).unwrap();
+
+        assert_str_eq!(
+            std::fs::read_to_string(&test_fixture.tree_file)?,
+            debug_tree(&ast),
+            "debug tree mismatch for fixture `{}`",
+            test_fixture.fixture
+        );
+    }
+
+    // The serialized AST is compared byte for byte, so that it can be relied
+    // on to be the same on every platform, for example as a cache key.
+    if test_fixture.json_file.exists() {
+        let ast = php_parser_rs::parse(&code).unwrap();
+
+        assert_str_eq!(
+            std::fs::read_to_string(&test_fixture.json_file)?,
+            format!("{}\n", serde_json::to_string_pretty(&ast)?),
+            "json mismatch for fixture `{}`",
+            test_fixture.fixture
+        );
+    }
+
+    // Each line of `offsets.txt` holds an offset into the code and the name of
+    // the innermost node there, or `-` if there is none.
+    if test_fixture.offsets_file.exists() {
+        let ast = php_parser_rs::parse(&code).unwrap();
+        let parents = parent_map(&ast);
+
+        for line in std::fs::read_to_string(&test_fixture.offsets_file)?.lines() {
+            let (offset, expected) = line.split_once(' ').unwrap();
+            let path = node_at_offset(&ast, offset.parse().unwrap());
+            let found = path
+                .as_ref()
+                .and_then(|path| path.innermost())
+                .map_or("-", |node| node.name);
+
+            assert_eq!(
+                expected, found,
+                "node at offset {} mismatch for fixture `{}`: {:?}",
+                offset, test_fixture.fixture, path
+            );
+
+            // Each node on the path is the parent of the next.
+            for pair in path.iter().flat_map(|path| path.nodes.windows(2)) {
+                assert_eq!(parents.parent_of(pair[1].id), Some(pair[0].id));
+            }
+        }
     }
 
     if !expected.error.is_empty() {
@@ -116,3 +266,189 @@ fn run_test(test_fixture: &TestFixture) -> io::Result<()> {
 
     Ok(())
 }
+
+// Printing a program and parsing the output gives back the same AST, spans
+// aside. The alternative syntax of control structures is printed with braces,
+// which changes the AST, so programs using it are only checked to print the
+// same way once printed.
+fn assert_prints(fixture: &str, ast: &Program) {
+    let printed = print(ast);
+    let reparsed = php_parser_rs::parse(&printed).unwrap_or_else(|error| {
+        panic!(
+            "failed to parse the printed fixture `{}`: {}\n\n{}",
+            fixture, error, printed
+        )
+    });
+
+    if uses_alternative_syntax(&serde_json::to_value(ast).unwrap()) {
+        assert_str_eq!(
+            printed,
+            print(&reparsed),
+            "printing is not stable for fixture `{}`",
+            fixture
+        );
+    } else if let Some(diff) = diff_ignoring_spans(ast, &reparsed) {
+        panic!(
+            "printing changed the ast of fixture `{}`:\n\n{}\n{}",
+            fixture, printed, diff
+        );
+    }
+}
+
+// The statements of the program follow each other with only whitespace and
+// comments between them, and every statement and expression lies within the
+// statement or expression that contains it.
+fn assert_spans(fixture: &str, code: &[u8], ast: &Program) {
+    let file = SourceFile::from_bytes(fixture, code);
+
+    let mut previous = 0;
+    let mut echo_tag = false;
+    for statement in ast {
+        // `<?=` is also the `echo` of the next statement, so it and any
+        // comments before that statement lie within it.
+        match statement {
+            Statement::EchoOpeningTag(_) => echo_tag = true,
+            Statement::Comment(_) if echo_tag => {}
+            _ => echo_tag = false,
+        }
+
+        if echo_tag {
+            continue;
+        }
+
+        let range = statement.span();
+        assert!(
+            previous <= range.start.offset(),
+            "statements overlap at {:?} in fixture `{}`",
+            range.start,
+            fixture
+        );
+
+        let gap = String::from_utf8_lossy(&code[previous..range.start.offset()]);
+        let gap = gap.trim();
+
+        assert!(
+            gap.is_empty()
+                || gap.starts_with("//")
+                || gap.starts_with('#')
+                || gap.starts_with("/*"),
+            "`{}` comes before a statement in fixture `{}`",
+            gap,
+            fixture
+        );
+
+        assert_eq!(
+            file.text(range).len(),
+            range.end.offset() - range.start.offset(),
+            "{:?} is out of bounds in fixture `{}`",
+            range,
+            fixture
+        );
+
+        previous = range.end.offset();
+    }
+
+    assert_nested_spans(fixture, ast, None);
+}
+
+fn assert_nested_spans(fixture: &str, node: &dyn Node, parent: Option<SpanRange>) {
+    let range = downcast::<Statement>(node)
+        .map(Spanned::span)
+        .or_else(|| downcast::<Expression>(node).map(Spanned::span));
+
+    if let (Some(range), Some(parent)) = (range, parent) {
+        assert!(
+            range.start.position < range.end.position
+                && parent.start.position <= range.start.position
+                && range.end.position <= parent.end.position,
+            "{:?} is not within {:?} in fixture `{}`",
+            range,
+            parent,
+            fixture
+        );
+    }
+
+    let parent = range.or(parent);
+    for child in node.children() {
+        assert_nested_spans(fixture, child, parent);
+    }
+}
+
+// The tokens of any source that can be split into tokens write it back byte
+// for byte, and a lossless parse gives the same AST as a normal one.
+fn assert_prints_exactly(fixture: &str, code: &[u8]) {
+    let Ok(tokens) = Lexer::new().tokenize_lossless(code) else {
+        return;
+    };
+
+    let mut output = Vec::new();
+    for token in &tokens {
+        for trivia in token.leading.iter().chain(&token.trailing) {
+            assert!(
+                code[trivia.span.offset()..].starts_with(&trivia.value),
+                "trivia {:?} is not at its span in fixture `{}`",
+                trivia,
+                fixture
+            );
+        }
+
+        assert!(
+            code[token.token.span.offset()..].starts_with(&token.text),
+            "token {:?} is not at its span in fixture `{}`",
+            token,
+            fixture
+        );
+
+        // Whitespace that the lexer reads along with a token, such as after
+        // a variable in a heredoc, is in its text but not in its range.
+        let length = token.token.end.offset() - token.token.span.offset();
+        let (text, rest) = token.text.split_at(length.min(token.text.len()));
+        assert!(
+            token.token.end == token.token.span.after(text)
+                && rest.iter().all(u8::is_ascii_whitespace),
+            "token {:?} does not end after its text in fixture `{}`",
+            token,
+            fixture
+        );
+
+        token.write(&mut output);
+    }
+
+    assert_str_eq!(
+        String::from_utf8_lossy(code),
+        String::from_utf8_lossy(&output),
+        "tokens do not print back exactly for fixture `{}`",
+        fixture
+    );
+
+    if let Ok(program) = parse_lossless(code) {
+        assert!(
+            php_parser_rs::parse(code).ok().as_ref() == Some(&program.program),
+            "lossless parse differs for fixture `{}`",
+            fixture
+        );
+        assert!(
+            print_exact(&program) == code,
+            "program does not print back exactly for fixture `{}`",
+            fixture
+        );
+    }
+}
+
+fn uses_alternative_syntax(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Object(object) => {
+            let kind = object.get("type").and_then(|kind| kind.as_str());
+            let is_block = kind == Some("Block")
+                && object
+                    .get("value")
+                    .is_some_and(|value| value.get("colon").is_some());
+            let is_switch = kind == Some("SwitchStatement")
+                && object.get("ending").is_some_and(|ending| !ending.is_null());
+
+            is_block || is_switch || object.values().any(uses_alternative_syntax)
+        }
+        serde_json::Value::Array(values) => values.iter().any(uses_alternative_syntax),
+        _ => false,
+    }
+}
@@ -497,10 +497,8 @@ fn read_directory(
             .to_str()
             .unwrap();
 
-        if name != "php-standard-library" {
-            if let Some(_) = ignored_prefixes.iter().find(|p| path.starts_with(*p)) {
-                continue;
-            }
+        if name != "php-standard-library" && ignored_prefixes.iter().any(|p| path.starts_with(*p)) {
+            continue;
         }
 
         if entry.is_dir() {
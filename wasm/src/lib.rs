@@ -0,0 +1,15 @@
+//! A crate depending on the parser with its default features turned off, for
+//! it to be built and tested on `wasm32-unknown-unknown`, as it is to parse
+//! code in a browser.
+//!
+//! The tests run in a WebAssembly runtime, with `wasm-bindgen-test-runner`
+//! from `wasm-bindgen-cli` as the runner set in `.cargo/config.toml`:
+//!
+//! ```sh
+//! cargo test -p php-parser-rs-wasm --target wasm32-unknown-unknown
+//! ```
+//!
+//! The `std` feature, which brings error reports, the JSON schema and the
+//! binaries, is not supported there.
+
+#![no_std]
@@ -0,0 +1,24 @@
+#![cfg(target_arch = "wasm32")]
+
+use php_parser_rs::parser::ast::Statement;
+use php_parser_rs::Error;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_parse() {
+    let program = php_parser_rs::parse("<?php function add($a, $b) { return $a + $b; }").unwrap();
+
+    assert!(matches!(
+        program.as_slice(),
+        [Statement::FullOpeningTag(_), Statement::Function(_)]
+    ));
+}
+
+#[wasm_bindgen_test]
+fn test_parse_error() {
+    let Err(Error::Parse(stack)) = php_parser_rs::parse("<?php echo 1") else {
+        panic!("expected a parse error");
+    };
+
+    assert_eq!(stack.errors.len(), 1);
+}